@@ -3,16 +3,21 @@
 use clap::{Args, ValueEnum};
 use ignore::Walk;
 use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 use vize_atelier_sfc::{
-    compile_sfc, parse_sfc, ScriptCompileOptions, SfcCompileOptions, SfcParseOptions,
+    compile_sfc, generate_dts, hash_scope_id, parse_sfc, preprocess_styles, resolve_src_blocks,
+    rewrite_default::rewrite_default, CompileCache, CustomBlockTransforms, NativeFileSystem,
+    PreprocessOptions, ScriptCompileOptions, SfcCompileOptions, SfcParseOptions,
     StyleCompileOptions, TemplateCompileOptions,
 };
 
+use super::watch::Watcher;
+
 #[derive(Debug, Clone, Copy, ValueEnum, Default)]
 pub enum OutputFormat {
     /// Output compiled JavaScript
@@ -33,6 +38,27 @@ pub enum ScriptExtension {
     Downcompile,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum BuildMode {
+    /// Keep dev-only component metadata (`__file`, `__hmrId`) and prop validation
+    #[default]
+    Development,
+    /// Production output: content-hashed scope IDs, no dev metadata, minimal props
+    Production,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CssMode {
+    /// Emit a .css file per component and import it from the component
+    #[default]
+    Import,
+    /// Inject styles into the document at runtime (no .css files)
+    Inject,
+    /// Emit a single style.css for the whole library (not imported by components)
+    Bundle,
+}
+
 #[derive(Args, Default)]
 pub struct BuildArgs {
     /// Glob pattern(s) to match .vue files (default: ./**/*.vue)
@@ -51,6 +77,14 @@ pub struct BuildArgs {
     #[arg(long)]
     pub ssr: bool,
 
+    /// Compile every component for defineCustomElement (always on for *.ce.vue)
+    #[arg(long)]
+    pub custom_element: bool,
+
+    /// Compile <i18n> and <docs> custom blocks into the component
+    #[arg(long)]
+    pub custom_blocks: bool,
+
     /// Script extension handling: 'preserve' keeps original extension (.ts/.tsx/.jsx), 'downcompile' converts to .js
     #[arg(long, value_enum, default_value = "downcompile")]
     pub script_ext: ScriptExtension,
@@ -70,6 +104,42 @@ pub struct BuildArgs {
     /// Continue on errors (collect all errors and show at end)
     #[arg(long)]
     pub continue_on_error: bool,
+
+    /// Library mode: mirror the source tree, emit CSS and a manifest, and rewrite .vue imports
+    #[arg(long, conflicts_with = "format")]
+    pub lib: bool,
+
+    /// Source root mirrored in library mode (default: common ancestor of the input files)
+    #[arg(long, requires = "lib")]
+    pub root: Option<PathBuf>,
+
+    /// How library mode outputs component styles
+    #[arg(long, value_enum, default_value = "import", requires = "lib")]
+    pub css: CssMode,
+
+    /// Emit `.vue.d.ts` type declarations next to the compiled components
+    #[arg(long)]
+    pub dts: bool,
+
+    /// Build mode
+    #[arg(long, value_enum, default_value = "development")]
+    pub mode: BuildMode,
+
+    /// Code prepended to every SCSS/Sass style block (e.g. `@use "src/styles/vars" as *;`)
+    #[arg(long, value_name = "CODE")]
+    pub scss_additional_data: Option<String>,
+
+    /// Code prepended to every Less style block
+    #[arg(long, value_name = "CODE")]
+    pub less_additional_data: Option<String>,
+
+    /// Ignore and don't update the compile cache in node_modules/.vize/cache
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Rebuild changed files and their dependents until interrupted
+    #[arg(short, long)]
+    pub watch: bool,
 }
 
 #[derive(Debug)]
@@ -117,6 +187,11 @@ struct CompileOutput {
     errors: Vec<String>,
     warnings: Vec<String>,
     script_lang: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dts: Option<String>,
+    /// Files loaded through `src` attributes
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dependencies: Vec<String>,
 }
 
 /// Detailed timing information for a single file
@@ -199,7 +274,6 @@ impl std::fmt::Display for ErrorPhase {
 
 pub fn run(args: BuildArgs) {
     let start = Instant::now();
-    let slow_threshold = Duration::from_millis(args.slow_threshold);
 
     if let Some(threads) = args.threads {
         rayon::ThreadPoolBuilder::new()
@@ -215,6 +289,85 @@ pub fn run(args: BuildArgs) {
         std::process::exit(1);
     }
 
+    // Components are identified by their project-relative path (the library
+    // root in library mode), which also seeds their scope IDs
+    let mut session = BuildSession {
+        root: args
+            .lib
+            .then(|| args.root.clone().unwrap_or_else(|| common_root(&files))),
+        cwd: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+        options: FileCompileOptions {
+            ssr: args.ssr,
+            custom_element: args.custom_element,
+            custom_blocks: args.custom_blocks,
+            script_ext: args.script_ext,
+            dts: args.dts,
+            is_prod: args.mode == BuildMode::Production,
+            preprocess: PreprocessOptions {
+                scss_additional_data: args.scss_additional_data.clone(),
+                less_additional_data: args.less_additional_data.clone(),
+                ..Default::default()
+            },
+            cache: (!args.no_cache)
+                .then(|| CompileCache::open(&CompileCache::project_root(Path::new(".")))),
+        },
+        library: BTreeMap::new(),
+    };
+
+    let summary = build_files(&args, &mut session, &files, start);
+    if !args.watch {
+        if !summary.success {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut watcher = match Watcher::new(&args.patterns, || collect_files(&args.patterns)) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("\x1b[31mError:\x1b[0m failed to start watching: {}", e);
+            std::process::exit(1);
+        }
+    };
+    for (path, dependencies) in &summary.dependencies {
+        watcher.set_dependencies(path, dependencies);
+    }
+    while let Some(changed) = watcher.next_change() {
+        let files = watcher.files();
+        session.library.retain(|path, _| files.contains(path));
+        let summary = build_files(&args, &mut session, &changed, Instant::now());
+        for (path, dependencies) in &summary.dependencies {
+            watcher.set_dependencies(path, dependencies);
+        }
+    }
+}
+
+/// State shared by the builds of one `vize build` run
+struct BuildSession {
+    /// Source root mirrored in library mode
+    root: Option<PathBuf>,
+    cwd: PathBuf,
+    options: FileCompileOptions,
+    /// Latest output of every component in library mode, where import
+    /// rewriting and the manifest need the whole library on rebuilds
+    library: BTreeMap<PathBuf, CompileOutput>,
+}
+
+/// Outcome of compiling a set of files
+struct BuildSummary {
+    success: bool,
+    /// Files loaded while compiling each component
+    dependencies: Vec<(PathBuf, Vec<String>)>,
+}
+
+/// Compile `files`, write their outputs and report the results
+fn build_files(
+    args: &BuildArgs,
+    session: &mut BuildSession,
+    files: &[PathBuf],
+    start: Instant,
+) -> BuildSummary {
+    let slow_threshold = Duration::from_millis(args.slow_threshold);
     let stats = CompileStats::new(files.len());
     let collect_elapsed = start.elapsed();
 
@@ -233,6 +386,11 @@ pub fn run(args: BuildArgs) {
     let slow_files: Mutex<Vec<FileProfile>> = Mutex::new(Vec::new());
     let profiles: Mutex<Vec<FileProfile>> = Mutex::new(Vec::new());
 
+    let root = session.root.as_deref();
+    let cwd = &session.cwd;
+    let options = &session.options;
+    let hits_before = options.cache.as_ref().map_or(0, CompileCache::hits);
+
     let compile_start = Instant::now();
     let results: Vec<_> = files
         .par_iter()
@@ -240,7 +398,12 @@ pub fn run(args: BuildArgs) {
             let source_size = fs::metadata(path).map(|m| m.len() as usize).unwrap_or(0);
             stats.total_bytes.fetch_add(source_size, Ordering::Relaxed);
 
-            match compile_file_with_profile(path, args.ssr, args.script_ext, &stats) {
+            // Files outside the library root are reported by `write_library`
+            let id = root
+                .or(Some(cwd))
+                .and_then(|root| relative_path(path, root))
+                .unwrap_or_else(|| file_name(path));
+            match compile_file_with_profile(path, id, options, &stats) {
                 Ok((output, profile)) => {
                     stats.success.fetch_add(1, Ordering::Relaxed);
                     stats
@@ -276,13 +439,30 @@ pub fn run(args: BuildArgs) {
         .collect();
     let compile_elapsed = compile_start.elapsed();
 
+    let results: Vec<_> = results.into_iter().flatten().collect();
+    let dependencies = results
+        .iter()
+        .map(|(path, output)| (path.clone(), output.dependencies.clone()))
+        .collect();
+
     let io_start = Instant::now();
+    let mut write_failed = false;
     match args.format {
         OutputFormat::Stats => {}
+        OutputFormat::Js if args.lib => {
+            let root = root.unwrap_or(Path::new(".")).to_path_buf();
+            let written: HashSet<PathBuf> = results.iter().map(|(path, _)| path.clone()).collect();
+            session.library.extend(results);
+            if let Err(e) = write_library(args, &root, &session.library, &written) {
+                eprintln!("\x1b[31mError:\x1b[0m {}", e);
+                write_failed = true;
+            }
+        }
         OutputFormat::Js | OutputFormat::Json => {
             fs::create_dir_all(&args.output).expect("Failed to create output directory");
 
-            for (path, output) in results.into_iter().flatten() {
+            let mut written = HashSet::new();
+            for (path, output) in results {
                 let ext = match args.format {
                     OutputFormat::Js => get_output_extension(&output.script_lang, args.script_ext),
                     OutputFormat::Json => "json",
@@ -294,6 +474,13 @@ pub fn run(args: BuildArgs) {
                     .map(|f| PathBuf::from(f).with_extension(ext))
                     .unwrap_or_else(|| PathBuf::from("output").with_extension(ext));
                 let out_path = args.output.join(filename);
+                if !written.insert(out_path.clone()) {
+                    eprintln!(
+                        "\x1b[33mWarning:\x1b[0m {} overwrites {} (use --lib to mirror the source tree)",
+                        path.display(),
+                        out_path.display()
+                    );
+                }
 
                 if let Some(parent) = out_path.parent() {
                     fs::create_dir_all(parent).expect("Failed to create output subdirectory");
@@ -308,6 +495,13 @@ pub fn run(args: BuildArgs) {
                 fs::write(&out_path, content).unwrap_or_else(|e| {
                     eprintln!("Failed to write {}: {}", out_path.display(), e);
                 });
+
+                if let (OutputFormat::Js, Some(dts)) = (args.format, &output.dts) {
+                    let dts_path = args.output.join(format!("{}.d.ts", output.filename));
+                    fs::write(&dts_path, dts).unwrap_or_else(|e| {
+                        eprintln!("Failed to write {}: {}", dts_path.display(), e);
+                    });
+                }
             }
         }
     }
@@ -479,23 +673,39 @@ pub fn run(args: BuildArgs) {
     }
 
     // Final summary
+    let cached = session
+        .options
+        .cache
+        .as_ref()
+        .map_or(0, |cache| cache.hits() - hits_before);
+    let cached = if cached > 0 {
+        format!(" ({} cached)", cached)
+    } else {
+        String::new()
+    };
     if failed > 0 {
         eprintln!(
-            "\x1b[31m✗ {} file(s) failed\x1b[0m, {} compiled in {:.4}s",
+            "\x1b[31m✗ {} file(s) failed\x1b[0m, {} compiled{} in {:.4}s",
             failed,
             success,
+            cached,
             total_elapsed.as_secs_f64()
         );
-        std::process::exit(1);
     } else {
         let file_word = if success == 1 { "file" } else { "files" };
         eprintln!(
-            "\x1b[32m✓ {} {} compiled in {:.4}s\x1b[0m",
+            "\x1b[32m✓ {} {} compiled{} in {:.4}s\x1b[0m",
             success,
             file_word,
+            cached,
             total_elapsed.as_secs_f64()
         );
     }
+
+    BuildSummary {
+        success: failed == 0 && !write_failed,
+        dependencies,
+    }
 }
 
 fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
@@ -520,7 +730,7 @@ fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
     files
 }
 
-fn parse_pattern(pattern: &str) -> (String, String) {
+pub(crate) fn parse_pattern(pattern: &str) -> (String, String) {
     if let Some(pos) = pattern.find(['*', '?']) {
         let root_part = &pattern[..pos];
         if let Some(last_slash) = root_part.rfind('/') {
@@ -575,23 +785,22 @@ fn pattern_matches(path: &std::path::Path, pattern: &str) -> bool {
     path_str.ends_with(".vue")
 }
 
-fn detect_script_lang(source: &str) -> String {
-    let script_pattern = regex_lite::Regex::new(r#"<script[^>]*\blang\s*=\s*["']([^"']+)["']"#)
-        .expect("Invalid regex");
-
-    if let Some(captures) = script_pattern.captures(source) {
-        if let Some(lang) = captures.get(1) {
-            return lang.as_str().to_string();
-        }
-    }
-
-    "js".to_string()
+/// Options shared by every file of a build
+struct FileCompileOptions {
+    ssr: bool,
+    custom_element: bool,
+    custom_blocks: bool,
+    script_ext: ScriptExtension,
+    dts: bool,
+    is_prod: bool,
+    preprocess: PreprocessOptions,
+    cache: Option<CompileCache>,
 }
 
 fn compile_file_with_profile(
     path: &PathBuf,
-    ssr: bool,
-    script_ext: ScriptExtension,
+    id: String,
+    options: &FileCompileOptions,
     stats: &CompileStats,
 ) -> Result<(CompileOutput, FileProfile), CompileError> {
     let file_start = Instant::now();
@@ -611,20 +820,33 @@ fn compile_file_with_profile(
         .unwrap_or("anonymous.vue")
         .to_string();
 
-    let script_lang = detect_script_lang(&source);
+    let scope_id = hash_scope_id(&id, &source, options.is_prod);
 
-    // Parse
+    // Parse, with the full path so `src` attributes resolve next to the file
     let parse_start = Instant::now();
     let parse_opts = SfcParseOptions {
-        filename: filename.clone(),
+        filename: path.to_string_lossy().to_string(),
         ..Default::default()
     };
 
-    let descriptor = parse_sfc(&source, parse_opts).map_err(|e| CompileError {
+    let mut descriptor = parse_sfc(&source, parse_opts).map_err(|e| CompileError {
         path: path.clone(),
         error: e.message,
         phase: ErrorPhase::Parse,
     })?;
+    let mut dependencies =
+        resolve_src_blocks(&mut descriptor, &NativeFileSystem).map_err(|e| CompileError {
+            path: path.clone(),
+            error: e.message,
+            phase: ErrorPhase::Read,
+        })?;
+    let script_lang = descriptor
+        .script_setup
+        .as_ref()
+        .or(descriptor.script.as_ref())
+        .and_then(|script| script.lang.as_deref())
+        .unwrap_or("js")
+        .to_string();
     let parse_time = parse_start.elapsed();
     stats.add_parse_time(parse_time);
 
@@ -648,37 +870,92 @@ fn compile_file_with_profile(
 
     // Compile
     let compile_start = Instant::now();
-    let has_scoped = descriptor.styles.iter().any(|s| s.scoped);
-    let is_ts = matches!(script_ext, ScriptExtension::Preserve);
+    // Custom elements are isolated by their shadow root, not a scope ID
+    let custom_element = options.custom_element || filename.ends_with(".ce.vue");
+    let has_scoped = !custom_element && descriptor.styles.iter().any(|s| s.scoped);
+    let is_ts = matches!(options.script_ext, ScriptExtension::Preserve);
     let compile_opts = SfcCompileOptions {
         parse: SfcParseOptions {
             filename: filename.clone(),
             ..Default::default()
         },
         script: ScriptCompileOptions {
-            id: Some(filename.clone()),
+            id: Some(id.clone()),
             is_ts,
+            is_prod: options.is_prod,
             ..Default::default()
         },
         template: TemplateCompileOptions {
-            id: Some(filename.clone()),
+            id: Some(id.clone()),
             scoped: has_scoped,
-            ssr,
+            ssr: options.ssr,
+            is_prod: options.is_prod,
             is_ts,
             ..Default::default()
         },
         style: StyleCompileOptions {
-            id: filename.clone(),
+            id: id.clone(),
             scoped: has_scoped,
             ..Default::default()
         },
+        scope_id: Some(scope_id.clone()),
+        custom_element,
+        custom_blocks: if options.custom_blocks {
+            CustomBlockTransforms::builtin()
+        } else {
+            CustomBlockTransforms::new()
+        },
     };
 
-    let result = compile_sfc(&descriptor, compile_opts).map_err(|e| CompileError {
-        path: path.clone(),
-        error: e.message,
-        phase: ErrorPhase::Compile,
-    })?;
+    // Unchanged files (and `src`/style dependencies) reuse the cached result
+    let preprocess = format!("{:?}", options.preprocess);
+    let cache_key = options
+        .cache
+        .as_ref()
+        .map(|_| CompileCache::key(&source, &compile_opts, &[&preprocess]));
+    let cached = options
+        .cache
+        .as_ref()
+        .zip(cache_key.as_deref())
+        .and_then(|(cache, key)| cache.get(key));
+    let result = match cached {
+        Some(hit) => {
+            for dependency in hit.dependencies {
+                if !dependencies.contains(&dependency) {
+                    dependencies.push(dependency);
+                }
+            }
+            hit.result
+        }
+        None => {
+            // SCSS/Sass/Less blocks become plain CSS before scoping
+            let imports =
+                preprocess_styles(&mut descriptor, &options.preprocess, &NativeFileSystem)
+                    .map_err(|e| CompileError {
+                        path: path.clone(),
+                        error: e.message,
+                        phase: ErrorPhase::Compile,
+                    })?;
+            for import in imports {
+                if !dependencies.contains(&import) {
+                    dependencies.push(import);
+                }
+            }
+            let result = compile_sfc(&descriptor, compile_opts).map_err(|e| CompileError {
+                path: path.clone(),
+                error: e.message,
+                phase: ErrorPhase::Compile,
+            })?;
+            if let (Some(cache), Some(key)) = (&options.cache, &cache_key) {
+                if let Err(e) = cache.put(key, &result, &dependencies) {
+                    eprintln!("Failed to cache {}: {}", path.display(), e);
+                }
+            }
+            result
+        }
+    };
+    let code = attach_component_metadata(result.code, &id, &scope_id, has_scoped, options.is_prod);
+    let dts = options.dts.then(|| generate_dts(&descriptor));
     let compile_time = compile_start.elapsed();
     stats.add_compile_time(compile_time);
 
@@ -697,16 +974,56 @@ fn compile_file_with_profile(
 
     let output = CompileOutput {
         filename,
-        code: result.code,
+        code,
         css: result.css,
         errors: result.errors.into_iter().map(|e| e.message).collect(),
         warnings: result.warnings.into_iter().map(|e| e.message).collect(),
         script_lang,
+        dts,
+        dependencies,
     };
 
     Ok((output, profile))
 }
 
+/// Attach `__scopeId` to scoped components and, in development, the
+/// `__file` and `__hmrId` metadata used by devtools, as `@vitejs/plugin-vue` does
+fn attach_component_metadata(
+    code: String,
+    id: &str,
+    scope_id: &str,
+    has_scoped: bool,
+    is_prod: bool,
+) -> String {
+    let quote = |value: &str| serde_json::to_string(value).unwrap_or_default();
+    let mut metadata = String::new();
+    if has_scoped {
+        metadata.push_str(&format!(
+            "_sfc_main.__scopeId = {}\n",
+            quote(&format!("data-v-{}", scope_id))
+        ));
+    }
+    if !is_prod {
+        metadata.push_str(&format!("_sfc_main.__hmrId = {}\n", quote(scope_id)));
+        metadata.push_str(&format!("_sfc_main.__file = {}\n", quote(id)));
+    }
+    if metadata.is_empty() {
+        return code;
+    }
+
+    // Downcompiled output can still contain TypeScript syntax, so parse as TS
+    let (mut rewritten, has_default) = rewrite_default(&code, "_sfc_main", true);
+    if !has_default {
+        return code;
+    }
+    if !rewritten.ends_with('\n') {
+        rewritten.push('\n');
+    }
+    rewritten.push_str(&metadata);
+    rewritten.push_str("export default _sfc_main\n");
+    rewritten
+}
+
 fn get_output_extension(script_lang: &str, script_ext: ScriptExtension) -> &'static str {
     match script_ext {
         ScriptExtension::Downcompile => "js",
@@ -718,3 +1035,592 @@ fn get_output_extension(script_lang: &str, script_ext: ScriptExtension) -> &'sta
         },
     }
 }
+
+/// Library manifest entry for one component, paths relative to the output directory
+#[derive(Debug, serde::Serialize)]
+struct ManifestEntry {
+    js: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    css: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dts: Option<String>,
+}
+
+/// `manifest.json` written in library mode
+#[derive(Debug, serde::Serialize)]
+struct Manifest {
+    root: String,
+    css: CssMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bundle: Option<String>,
+    /// Source path (relative to the root) -> outputs
+    files: BTreeMap<String, ManifestEntry>,
+}
+
+/// Write library output: the source tree mirrored under the output directory,
+/// styles according to `--css`, `.vue` imports pointing at the emitted files,
+/// and a manifest mapping sources to outputs.
+///
+/// Only the outputs of `written` are written again; the others are still
+/// needed for import extensions, the style bundle and the manifest.
+fn write_library(
+    args: &BuildArgs,
+    root: &Path,
+    outputs: &BTreeMap<PathBuf, CompileOutput>,
+    written: &HashSet<PathBuf>,
+) -> Result<(), String> {
+    // Every component must have a place in the mirrored tree
+    let sources = outputs
+        .keys()
+        .map(|path| {
+            relative_path(path, root).ok_or_else(|| {
+                format!(
+                    "{} is outside the library root {}",
+                    path.display(),
+                    root.display()
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let extensions: HashMap<PathBuf, &'static str> = outputs
+        .iter()
+        .map(|(path, output)| {
+            let ext = get_output_extension(&output.script_lang, args.script_ext);
+            (normalize_path(path), ext)
+        })
+        .collect();
+    let vue_import = vue_import_regex();
+
+    let mut manifest = Manifest {
+        root: root.to_string_lossy().replace('\\', "/"),
+        css: args.css,
+        bundle: None,
+        files: BTreeMap::new(),
+    };
+    let mut bundle = String::new();
+
+    for ((path, output), source) in outputs.iter().zip(sources) {
+        let write = written.contains(path);
+        let stem = Path::new(&source).with_extension("");
+        let ext = extensions[&normalize_path(path)];
+        let dir = path.parent().unwrap_or(Path::new("."));
+
+        let mut code = rewrite_vue_imports(&output.code, dir, &extensions, &vue_import);
+
+        let mut css_file = None;
+        if let Some(css) = output.css.as_deref().filter(|css| !css.trim().is_empty()) {
+            match args.css {
+                CssMode::Import => {
+                    let css_path = stem.with_extension("css");
+                    if write {
+                        write_output(&args.output.join(&css_path), css)?;
+                    }
+                    let name = css_path.file_name().unwrap_or_default().to_string_lossy();
+                    code = format!("import \"./{}\"\n{}", name, code);
+                    css_file = Some(css_path.to_string_lossy().replace('\\', "/"));
+                }
+                CssMode::Inject => code.push_str(&style_injection(&source, css)),
+                CssMode::Bundle => {
+                    bundle.push_str(&format!("/* {} */\n{}\n", source, css.trim()));
+                }
+            }
+        }
+
+        let js_path = stem.with_extension(ext);
+        if write {
+            write_output(&args.output.join(&js_path), &code)?;
+        }
+
+        let mut dts_file = None;
+        if let Some(dts) = &output.dts {
+            let dts_path = format!("{}.vue.d.ts", stem.to_string_lossy().replace('\\', "/"));
+            if write {
+                write_output(&args.output.join(&dts_path), dts)?;
+            }
+            dts_file = Some(dts_path);
+        }
+
+        manifest.files.insert(
+            source,
+            ManifestEntry {
+                js: js_path.to_string_lossy().replace('\\', "/"),
+                css: css_file,
+                dts: dts_file,
+            },
+        );
+    }
+
+    if !bundle.is_empty() {
+        write_output(&args.output.join("style.css"), &bundle)?;
+        manifest.bundle = Some("style.css".to_string());
+    }
+
+    let manifest = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+    write_output(&args.output.join("manifest.json"), &manifest)
+}
+
+fn vue_import_regex() -> regex_lite::Regex {
+    regex_lite::Regex::new(r#"(\bfrom\s*|\bimport\s*\(?\s*)(["'])(\.\.?/[^"'\n]*?)\.vue(["'])"#)
+        .expect("Invalid regex")
+}
+
+/// Point relative `.vue` imports of a component in `dir` at the emitted
+/// files (`./Icon.vue` -> `./Icon.js`), using the extension each target was
+/// emitted with. Imports of components outside the build are left as is.
+fn rewrite_vue_imports(
+    code: &str,
+    dir: &Path,
+    extensions: &HashMap<PathBuf, &str>,
+    vue_import: &regex_lite::Regex,
+) -> String {
+    vue_import
+        .replace_all(code, |caps: &regex_lite::Captures| {
+            let target = normalize_path(&dir.join(format!("{}.vue", &caps[3])));
+            match extensions.get(&target) {
+                Some(ext) if caps[2] == caps[4] => {
+                    format!("{}{}{}.{}{}", &caps[1], &caps[2], &caps[3], ext, &caps[4])
+                }
+                _ => caps[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// Code appended to a component to add its styles to the document once.
+fn style_injection(id: &str, css: &str) -> String {
+    let id = serde_json::to_string(id).unwrap_or_default();
+    let css = serde_json::to_string(css).unwrap_or_default();
+    format!(
+        r#"
+;(function () {{
+  if (typeof document === "undefined") return
+  var id = {id}
+  for (var el of document.head.querySelectorAll("style[data-vize-id]")) {{
+    if (el.dataset.vizeId === id) return
+  }}
+  var style = document.createElement("style")
+  style.dataset.vizeId = id
+  style.textContent = {css}
+  document.head.appendChild(style)
+}})()
+"#
+    )
+}
+
+fn write_output(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Deepest directory containing all files
+fn common_root(files: &[PathBuf]) -> PathBuf {
+    let mut dirs = files
+        .iter()
+        .map(|file| normalize_path(file.parent().unwrap_or(Path::new("."))));
+    let Some(mut root) = dirs.next() else {
+        return PathBuf::from(".");
+    };
+    for dir in dirs {
+        while !dir.starts_with(&root) {
+            if !root.pop() {
+                break;
+            }
+        }
+    }
+    if root.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        root
+    }
+}
+
+/// Path relative to `root` with forward slashes, or `None` outside of it
+fn relative_path(path: &Path, root: &Path) -> Option<String> {
+    let absolute = |path: &Path| normalize_path(&std::path::absolute(path).unwrap_or_default());
+    let path = absolute(path);
+    let rel = path
+        .strip_prefix(absolute(root))
+        .ok()
+        .filter(|rel| !rel.as_os_str().is_empty())?;
+    Some(rel.to_string_lossy().replace('\\', "/"))
+}
+
+/// File name of a path, or the whole path if it has none
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Resolve `.` and `..` components without touching the file system
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUTTON: &str = r#"<script setup lang="ts">
+defineProps<{ label: string }>()
+</script>
+<template><button class="btn">{{ label }}</button></template>
+<style scoped>.btn { color: red; }</style>
+"#;
+
+    const FIELD: &str = r#"<script setup>
+import Button from '../Button.vue'
+</script>
+<template><label class="field"><Button label="ok" /></label></template>
+<style>.field { display: block; }</style>
+"#;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vize-build-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn file_options(is_prod: bool) -> FileCompileOptions {
+        FileCompileOptions {
+            ssr: false,
+            custom_element: false,
+            custom_blocks: false,
+            script_ext: ScriptExtension::Downcompile,
+            dts: false,
+            is_prod,
+            preprocess: PreprocessOptions::default(),
+            cache: None,
+        }
+    }
+
+    /// Compile `files` under `root` as `build_files` does in library mode
+    fn compile_library(
+        root: &Path,
+        files: &[(&str, &str)],
+        is_prod: bool,
+    ) -> BTreeMap<PathBuf, CompileOutput> {
+        let options = file_options(is_prod);
+        let stats = CompileStats::new(files.len());
+        files
+            .iter()
+            .map(|(name, source)| {
+                let path = root.join(name);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, source).unwrap();
+                let id = relative_path(&path, root).unwrap();
+                let (output, _) = compile_file_with_profile(&path, id, &options, &stats)
+                    .unwrap_or_else(|e| panic!("{}: {}", e.path.display(), e.error));
+                (path, output)
+            })
+            .collect()
+    }
+
+    fn read(path: PathBuf) -> String {
+        fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+    }
+
+    #[test]
+    fn test_write_library() {
+        let dir = temp_dir("lib");
+        let root = dir.join("src");
+        let outputs = compile_library(
+            &root,
+            &[("Button.vue", BUTTON), ("form/Field.vue", FIELD)],
+            false,
+        );
+        let written: HashSet<PathBuf> = outputs.keys().cloned().collect();
+        let root_str = root.to_string_lossy().to_string();
+
+        let build = |css: CssMode| {
+            let output = dir.join(format!("dist-{:?}", css).to_lowercase());
+            let args = BuildArgs {
+                lib: true,
+                css,
+                output: output.clone(),
+                ..Default::default()
+            };
+            write_library(&args, &root, &outputs, &written).unwrap();
+            let manifest: serde_json::Value =
+                serde_json::from_str(&read(output.join("manifest.json"))).unwrap();
+            (output, manifest)
+        };
+
+        // Import: a .css file next to each component, imported by it
+        let (out, manifest) = build(CssMode::Import);
+        assert_eq!(
+            manifest,
+            serde_json::json!({
+                "root": root_str,
+                "css": "import",
+                "files": {
+                    "Button.vue": { "js": "Button.js", "css": "Button.css" },
+                    "form/Field.vue": { "js": "form/Field.js", "css": "form/Field.css" },
+                },
+            })
+        );
+        let button = read(out.join("Button.js"));
+        assert!(button.starts_with("import \"./Button.css\"\n"));
+        assert!(read(out.join("Button.css")).contains(".btn[data-v-"));
+        let field = read(out.join("form/Field.js"));
+        assert!(field.starts_with("import \"./Field.css\"\n"));
+        assert!(field.contains("from '../Button.js'"));
+        assert!(!field.contains("Button.vue"));
+        assert!(read(out.join("form/Field.css")).contains(".field"));
+        assert!(!out.join("style.css").exists());
+
+        // Inject: styles are added to the document by the component itself
+        let (out, manifest) = build(CssMode::Inject);
+        assert_eq!(
+            manifest,
+            serde_json::json!({
+                "root": root_str,
+                "css": "inject",
+                "files": {
+                    "Button.vue": { "js": "Button.js" },
+                    "form/Field.vue": { "js": "form/Field.js" },
+                },
+            })
+        );
+        let button = read(out.join("Button.js"));
+        assert!(!button.contains("import \"./Button.css\""));
+        assert!(button.contains("var id = \"Button.vue\""));
+        assert!(button.contains("style.textContent = \".btn[data-v-"));
+        assert!(read(out.join("form/Field.js")).contains("var id = \"form/Field.vue\""));
+        assert!(!out.join("Button.css").exists());
+        assert!(!out.join("style.css").exists());
+
+        // Bundle: one style.css for the library, not imported by components
+        let (out, manifest) = build(CssMode::Bundle);
+        assert_eq!(
+            manifest,
+            serde_json::json!({
+                "root": root_str,
+                "css": "bundle",
+                "bundle": "style.css",
+                "files": {
+                    "Button.vue": { "js": "Button.js" },
+                    "form/Field.vue": { "js": "form/Field.js" },
+                },
+            })
+        );
+        let bundle = read(out.join("style.css"));
+        let button_at = bundle.find("/* Button.vue */\n.btn[data-v-").unwrap();
+        let field_at = bundle.find("/* form/Field.vue */\n.field").unwrap();
+        assert!(button_at < field_at);
+        assert!(!read(out.join("Button.js")).contains("vizeId"));
+        assert!(!read(out.join("form/Field.js")).contains(".css"));
+        assert!(!out.join("Button.css").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_write_library_rebuild() {
+        let dir = temp_dir("lib-rebuild");
+        let root = dir.join("src");
+        let outputs = compile_library(
+            &root,
+            &[("Button.vue", BUTTON), ("form/Field.vue", FIELD)],
+            false,
+        );
+        let output = dir.join("dist");
+        let args = BuildArgs {
+            lib: true,
+            output: output.clone(),
+            ..Default::default()
+        };
+
+        // Only the changed component is written, the manifest lists both
+        let written: HashSet<PathBuf> = [root.join("form/Field.vue")].into_iter().collect();
+        write_library(&args, &root, &outputs, &written).unwrap();
+        assert!(output.join("form/Field.js").exists());
+        assert!(!output.join("Button.js").exists());
+        assert!(read(output.join("form/Field.js")).contains("from '../Button.js'"));
+        let manifest: serde_json::Value =
+            serde_json::from_str(&read(output.join("manifest.json"))).unwrap();
+        assert_eq!(manifest["files"]["Button.vue"]["js"], "Button.js");
+
+        // Components outside the root cannot be mirrored
+        let err = write_library(&args, &root.join("form"), &outputs, &written).unwrap_err();
+        assert!(err.contains("is outside the library root"), "{}", err);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_attach_component_metadata() {
+        let code = "const _sfc_main = { name: 'A' }\nexport default _sfc_main\n";
+        assert_eq!(
+            attach_component_metadata(code.to_string(), "src/A.vue", "1a2b3c4d", true, false),
+            "const _sfc_main = { name: 'A' }\n\n\
+             _sfc_main.__scopeId = \"data-v-1a2b3c4d\"\n\
+             _sfc_main.__hmrId = \"1a2b3c4d\"\n\
+             _sfc_main.__file = \"src/A.vue\"\n\
+             export default _sfc_main\n"
+        );
+        assert_eq!(
+            attach_component_metadata(code.to_string(), "src/A.vue", "1a2b3c4d", true, true),
+            "const _sfc_main = { name: 'A' }\n\n\
+             _sfc_main.__scopeId = \"data-v-1a2b3c4d\"\n\
+             export default _sfc_main\n"
+        );
+        // Unscoped production components are left untouched
+        assert_eq!(
+            attach_component_metadata(code.to_string(), "src/A.vue", "1a2b3c4d", false, true),
+            code
+        );
+        // Without a default export there is nothing to attach to
+        let code = "export const a = 1\n";
+        assert_eq!(
+            attach_component_metadata(code.to_string(), "src/A.vue", "1a2b3c4d", true, false),
+            code
+        );
+    }
+
+    #[test]
+    fn test_component_metadata_modes() {
+        let dir = temp_dir("metadata");
+        let scope_line = |is_prod: bool| {
+            format!(
+                "_sfc_main.__scopeId = \"data-v-{}\"\n",
+                hash_scope_id("Button.vue", BUTTON, is_prod)
+            )
+        };
+
+        let outputs = compile_library(&dir, &[("Button.vue", BUTTON)], false);
+        let code = &outputs[&dir.join("Button.vue")].code;
+        assert!(code.contains(&scope_line(false)), "{}", code);
+        assert!(code.contains(&format!(
+            "_sfc_main.__hmrId = \"{}\"\n",
+            hash_scope_id("Button.vue", BUTTON, false)
+        )));
+        assert!(code.contains("_sfc_main.__file = \"Button.vue\"\n"));
+        assert!(code.ends_with("export default _sfc_main\n"));
+
+        let outputs = compile_library(&dir, &[("Button.vue", BUTTON)], true);
+        let output = &outputs[&dir.join("Button.vue")];
+        assert!(output.code.contains(&scope_line(true)), "{}", output.code);
+        assert!(!output.code.contains("__hmrId"));
+        assert!(!output.code.contains("__file"));
+        // The scoped CSS uses the same content-hashed ID
+        let scope_id = hash_scope_id("Button.vue", BUTTON, true);
+        assert!(output
+            .css
+            .as_deref()
+            .unwrap()
+            .contains(&format!(".btn[data-v-{}]", scope_id)));
+        assert_ne!(scope_id, hash_scope_id("Button.vue", BUTTON, false));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_style_injection() {
+        let code = style_injection("a/B.vue", ".b { content: \"x\"; }\n");
+        assert!(code.contains("var id = \"a/B.vue\""));
+        assert!(code.contains("style.textContent = \".b { content: \\\"x\\\"; }\\n\""));
+        assert!(code.contains("if (typeof document === \"undefined\") return"));
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(
+            normalize_path(Path::new("./src/components/../App.vue")),
+            PathBuf::from("src/App.vue")
+        );
+        assert_eq!(
+            normalize_path(Path::new("../lib/./Button.vue")),
+            PathBuf::from("../lib/Button.vue")
+        );
+        assert_eq!(
+            normalize_path(Path::new("/app/src/../../x")),
+            PathBuf::from("/x")
+        );
+    }
+
+    #[test]
+    fn test_common_root() {
+        let files = [
+            PathBuf::from("src/components/Button.vue"),
+            PathBuf::from("./src/components/form/Input.vue"),
+            PathBuf::from("src/App.vue"),
+        ];
+        assert_eq!(common_root(&files), PathBuf::from("src"));
+        assert_eq!(common_root(&files[..1]), PathBuf::from("src/components"));
+        assert_eq!(
+            common_root(&[PathBuf::from("App.vue"), PathBuf::from("lib/Icon.vue")]),
+            PathBuf::from(".")
+        );
+        assert_eq!(common_root(&[]), PathBuf::from("."));
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let root = Path::new("src");
+        assert_eq!(
+            relative_path(Path::new("./src/components/Button.vue"), root).as_deref(),
+            Some("components/Button.vue")
+        );
+        assert_eq!(
+            relative_path(Path::new("src/a/../App.vue"), root).as_deref(),
+            Some("App.vue")
+        );
+        // Relative and absolute paths resolve against the working directory
+        let absolute = std::path::absolute("src/App.vue").unwrap();
+        assert_eq!(relative_path(&absolute, root).as_deref(), Some("App.vue"));
+
+        assert_eq!(relative_path(Path::new("lib/Icon.vue"), root), None);
+        assert_eq!(relative_path(Path::new("../src/App.vue"), root), None);
+    }
+
+    #[test]
+    fn test_rewrite_vue_imports() {
+        let extensions: HashMap<PathBuf, &str> = [
+            (PathBuf::from("src/Icon.vue"), "js"),
+            (PathBuf::from("src/form/Input.vue"), "ts"),
+        ]
+        .into_iter()
+        .collect();
+        let code = r#"import Icon from "./Icon.vue"
+import Input from './form/Input.vue'
+import Missing from "./Missing.vue"
+import Vendor from "../vendor/Chart.vue"
+import Mixed from "./Icon.vue'
+const Lazy = () => import("./form/Input.vue")
+"#;
+
+        assert_eq!(
+            rewrite_vue_imports(code, Path::new("src"), &extensions, &vue_import_regex()),
+            r#"import Icon from "./Icon.js"
+import Input from './form/Input.ts'
+import Missing from "./Missing.vue"
+import Vendor from "../vendor/Chart.vue"
+import Mixed from "./Icon.vue'
+const Lazy = () => import("./form/Input.ts")
+"#
+        );
+    }
+}
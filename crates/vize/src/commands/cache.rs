@@ -0,0 +1,55 @@
+//! Cache command - Inspect and clear the compile cache
+
+use clap::{Args, Subcommand};
+use std::path::Path;
+use vize_atelier_sfc::{CacheStats, CompileCache};
+
+#[derive(Args)]
+pub struct CacheArgs {
+    #[command(subcommand)]
+    pub command: CacheCommands,
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// Remove all cached compile results
+    Clean,
+    /// Show how many compile results are cached
+    Stats,
+}
+
+pub fn run(args: CacheArgs) {
+    let cache = CompileCache::open(&CompileCache::project_root(Path::new(".")));
+    let dir = cache.dir().display().to_string();
+
+    match args.command {
+        CacheCommands::Clean => match cache.clean() {
+            Ok(stats) => println!("Removed {} from {}", describe(stats), dir),
+            Err(e) => {
+                eprintln!("\x1b[31mError:\x1b[0m failed to clean {}: {}", dir, e);
+                std::process::exit(1);
+            }
+        },
+        CacheCommands::Stats => match cache.stats() {
+            Ok(stats) => println!("{}: {}", dir, describe(stats)),
+            Err(e) => {
+                eprintln!("\x1b[31mError:\x1b[0m failed to read {}: {}", dir, e);
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+fn describe(stats: CacheStats) -> String {
+    let entry_word = if stats.entries == 1 {
+        "entry"
+    } else {
+        "entries"
+    };
+    format!(
+        "{} {} ({:.2} KB)",
+        stats.entries,
+        entry_word,
+        stats.bytes as f64 / 1024.0
+    )
+}
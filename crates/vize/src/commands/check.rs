@@ -2,6 +2,10 @@
 //!
 //! Generates Virtual TypeScript from Vue SFCs and uses tsgo LSP for type checking.
 //! Can connect to a running check-server via Unix socket for faster repeated checks.
+//!
+//! Generated Virtual TypeScript and per-file diagnostics are cached by content hash
+//! in `node_modules/.vize/canon/.cache`; only changed files and their dependents are
+//! sent to tsgo on subsequent runs.
 
 use clap::Args;
 use ignore::WalkBuilder;
@@ -9,6 +13,11 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 use std::time::Instant;
+use vize_canon::batch::{
+    CachedDiagnostic, CachedModule, CachedVirtualTs, CheckCache, ModuleState, PathAliases,
+};
+use vize_carton::{FxHashMap, FxHashSet};
+use vize_patina::{OutputFormat, ReportDiagnostic, ReportFile, Severity};
 
 #[derive(Args)]
 pub struct CheckArgs {
@@ -25,7 +34,8 @@ pub struct CheckArgs {
     #[arg(long)]
     pub tsconfig: Option<PathBuf>,
 
-    /// Output format (text, json)
+    /// Output format (text, json, sarif, junit, checkstyle, github).
+    /// Watch mode always prints text.
     #[arg(short, long, default_value = "text")]
     pub format: String,
 
@@ -49,6 +59,14 @@ pub struct CheckArgs {
     /// Overrides vize.config.json check.globals. Use "none" to disable all globals.
     #[arg(long)]
     pub globals: Option<String>,
+
+    /// Watch for changes and re-check, keeping the tsgo session alive between runs
+    #[arg(long, short)]
+    pub watch: bool,
+
+    /// Ignore and don't update the check cache in node_modules/.vize/canon/.cache
+    #[arg(long)]
+    pub no_cache: bool,
 }
 
 /// JSON output structure
@@ -74,6 +92,7 @@ struct GeneratedFile {
     virtual_ts: String,
     source_map: Vec<vize_canon::virtual_ts::VizeMapping>,
     original_content: String,
+    module: ModuleState,
 }
 
 /// Server response for check method
@@ -112,40 +131,47 @@ struct JsonRpcError {
     message: String,
 }
 
-/// Convert a line/column position in the virtual TS to a line/column in the original SFC.
+/// Convert a range in the virtual TS to 1-based line/column positions in the original SFC.
 ///
 /// Steps:
-/// 1. Convert virtual TS line/col to byte offset in virtual TS
-/// 2. Find matching source mapping
-/// 3. Compute byte offset in original SFC
-/// 4. Convert SFC byte offset to line/col
-fn map_diagnostic_position(
+/// 1. Convert virtual TS line/col to byte offsets in virtual TS
+/// 2. Find the source mapping containing the start
+/// 3. Compute byte offsets in original SFC, keeping the end inside that mapping
+/// 4. Convert SFC byte offsets to line/col
+fn map_diagnostic_range(
     virtual_ts: &str,
     source_map: &[vize_canon::virtual_ts::VizeMapping],
     original_content: &str,
-    vts_line: u32,
-    vts_character: u32,
-) -> (u32, u32) {
-    // Step 1: line/col -> byte offset in virtual TS
-    let vts_offset = line_col_to_offset(virtual_ts, vts_line, vts_character);
+    range: &vize_canon::lsp_client::LspRange,
+) -> ((u32, u32), (u32, u32)) {
+    // Step 1: line/col -> byte offsets in virtual TS
+    let start = line_col_to_offset(virtual_ts, range.start.line, range.start.character);
+    let end = line_col_to_offset(virtual_ts, range.end.line, range.end.character).max(start);
 
     // Step 2: Find matching source mapping
-    for mapping in source_map {
-        if vts_offset >= mapping.gen_range.start && vts_offset < mapping.gen_range.end {
-            // Step 3: Compute corresponding offset in original SFC
-            let delta = vts_offset - mapping.gen_range.start;
-            let src_offset = mapping.src_range.start + delta;
-            // Clamp to source range
-            let src_offset = src_offset.min(mapping.src_range.end.saturating_sub(1));
+    let Some(mapping) = source_map
+        .iter()
+        .find(|m| start >= m.gen_range.start && start < m.gen_range.end)
+    else {
+        // Fallback: return virtual TS positions (1-based)
+        return (
+            (range.start.line + 1, range.start.character + 1),
+            (range.end.line + 1, range.end.character + 1),
+        );
+    };
 
-            // Step 4: Convert SFC offset to line/col (1-based)
-            let (line, col) = offset_to_line_col(original_content, src_offset);
-            return (line + 1, col + 1);
-        }
-    }
+    // Step 3: Compute corresponding offsets in original SFC, clamped to the source range
+    let src_start = (mapping.src_range.start + (start - mapping.gen_range.start))
+        .min(mapping.src_range.end.saturating_sub(1));
+    let src_end = (mapping.src_range.start + (end - mapping.gen_range.start))
+        .clamp(src_start, mapping.src_range.end);
 
-    // Fallback: return virtual TS position (1-based)
-    (vts_line + 1, vts_character + 1)
+    // Step 4: Convert SFC offsets to line/col (1-based)
+    let position = |offset| {
+        let (line, col) = offset_to_line_col(original_content, offset);
+        (line + 1, col + 1)
+    };
+    (position(src_start), position(src_end))
 }
 
 /// Convert line/column (0-based) to byte offset in content.
@@ -187,20 +213,29 @@ fn offset_to_line_col(content: &str, offset: usize) -> (u32, u32) {
 }
 
 pub fn run(args: CheckArgs) {
+    let Some(format) = OutputFormat::parse(&args.format) else {
+        eprintln!(
+            "Unknown output format '{}'. Expected one of: {}",
+            args.format,
+            OutputFormat::NAMES.join(", ")
+        );
+        std::process::exit(1);
+    };
+
     // If socket is specified, use socket client mode (Unix only)
     #[cfg(unix)]
     if let Some(ref socket_path) = args.socket {
-        run_with_socket(&args, socket_path);
+        run_with_socket(&args, socket_path, format);
         return;
     }
 
     // Otherwise, fall back to direct tsgo execution
-    run_direct(&args);
+    run_direct(&args, format);
 }
 
 /// Run type checking via Unix socket connection to check-server
 #[cfg(unix)]
-fn run_with_socket(args: &CheckArgs, socket_path: &str) {
+fn run_with_socket(args: &CheckArgs, socket_path: &str, format: OutputFormat) {
     use std::io::{BufRead, BufReader, Write};
     use std::os::unix::net::UnixStream;
 
@@ -303,6 +338,34 @@ fn run_with_socket(args: &CheckArgs, socket_path: &str) {
 
     let total_time = start.elapsed();
 
+    let reports: Vec<ReportFile> = results
+        .iter()
+        .map(|(filename, result)| ReportFile {
+            path: report_path(filename),
+            diagnostics: result
+                .diagnostics
+                .iter()
+                .map(|d| ReportDiagnostic {
+                    rule: d.code.clone().unwrap_or_else(|| "tsgo".to_string()),
+                    severity: parse_severity(&d.severity),
+                    message: d.message.clone(),
+                    help: None,
+                    line: d.line,
+                    column: d.column,
+                    end_line: d.line,
+                    end_column: d.column,
+                })
+                .collect(),
+        })
+        .collect();
+    if let Some(report) = format.render_reports("vize-canon", &reports) {
+        print!("{}", report);
+        if total_errors > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Print results
     if !args.quiet {
         for (filename, result) in &results {
@@ -425,25 +488,16 @@ fn parse_globals_str(globals_str: &str) -> Vec<vize_canon::virtual_ts::TemplateG
         .collect()
 }
 
-/// Run type checking directly with tsgo LSP (no file I/O)
-fn run_direct(args: &CheckArgs) {
-    use rayon::prelude::*;
-    use vize_atelier_core::parser::parse;
-    use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
-    use vize_canon::lsp_client::TsgoLspClient;
-    use vize_canon::virtual_ts::{generate_virtual_ts_with_offsets, VirtualTsOptions};
-    use vize_carton::Bump;
-    use vize_croquis::{Analyzer, AnalyzerOptions};
-
-    let start = Instant::now();
-
-    // Load vize.config.json and write JSON Schema
-    let config = crate::config::load_config(None);
-    crate::config::write_schema(None);
-
-    // Build VirtualTsOptions from CLI args or config.
-    // Priority: CLI --globals > vize.config.json check.globals > default (empty)
-    let vts_options = if let Some(ref globals_str) = args.globals {
+/// Build virtual TS options from CLI args or config.
+///
+/// Priority: CLI --globals > vize.config.json check.globals > default (empty)
+fn virtual_ts_options(
+    args: &CheckArgs,
+    config: &crate::config::VizeConfig,
+) -> vize_canon::virtual_ts::VirtualTsOptions {
+    use vize_canon::virtual_ts::VirtualTsOptions;
+
+    if let Some(ref globals_str) = args.globals {
         if globals_str == "none" {
             VirtualTsOptions {
                 template_globals: vec![],
@@ -459,113 +513,569 @@ fn run_direct(args: &CheckArgs) {
         }
     } else {
         VirtualTsOptions::default()
+    }
+}
+
+/// Hash everything besides file contents that affects check results.
+fn config_hash(
+    vts_options: &vize_canon::virtual_ts::VirtualTsOptions,
+    project_root: Option<&str>,
+) -> String {
+    let globals: Vec<String> = vts_options
+        .template_globals
+        .iter()
+        .map(|g| format!("{}:{}", g.name, g.type_annotation))
+        .collect();
+    let tsconfig = project_root
+        .and_then(|root| fs::read_to_string(std::path::Path::new(root).join("tsconfig.json")).ok())
+        .unwrap_or_default();
+    vize_carton::hash::content_hash(&format!("{}\0{}", globals.join(","), tsconfig))
+}
+
+/// Virtual document URI for a generated file.
+fn virtual_uri(file: &GeneratedFile) -> String {
+    format!("file://{}.mts", file.original)
+}
+
+/// Generate Virtual TypeScript for an SFC, reusing cached output when the
+/// content hash matches.
+fn generate_file(
+    path: &std::path::Path,
+    vts_options: &vize_canon::virtual_ts::VirtualTsOptions,
+    aliases: &PathAliases,
+    cache: Option<&CheckCache>,
+) -> Option<GeneratedFile> {
+    use vize_atelier_core::parser::parse;
+    use vize_atelier_sfc::{parse_sfc, resolve_src_blocks, NativeFileSystem, SfcParseOptions};
+    use vize_canon::batch::script_imports;
+    use vize_canon::virtual_ts::generate_virtual_ts_with_offsets;
+    use vize_carton::Bump;
+    use vize_croquis::{Analyzer, AnalyzerOptions};
+
+    let source = fs::read_to_string(path).ok()?;
+    // Use absolute path for proper file:// URI
+    let abs_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let filename = abs_path.to_string_lossy().to_string();
+
+    // Parse SFC and load `src` blocks, which are part of the generated code
+    // and therefore of the cache key
+    let parse_opts = SfcParseOptions {
+        filename: filename.clone(),
+        ..Default::default()
+    };
+    let mut descriptor = parse_sfc(&source, parse_opts).ok()?;
+    let dependencies = match resolve_src_blocks(&mut descriptor, &NativeFileSystem) {
+        Ok(dependencies) => dependencies,
+        Err(e) => {
+            eprintln!("{}: {}", filename, e.message);
+            return None;
+        }
+    };
+    let hash = if dependencies.is_empty() {
+        vize_carton::hash::content_hash(&source)
+    } else {
+        let external = [
+            descriptor.template.as_ref().map(|t| &t.content),
+            descriptor.script.as_ref().map(|s| &s.content),
+        ];
+        let mut key = source.clone();
+        for content in external.into_iter().flatten() {
+            key.push('\0');
+            key.push_str(content);
+        }
+        vize_carton::hash::content_hash(&key)
     };
 
-    // Collect .vue files
-    let collect_start = Instant::now();
-    let files = collect_vue_files(&args.patterns);
-    let collect_time = collect_start.elapsed();
+    if let Some(cached) = cache.and_then(|c| c.virtual_ts(&hash)) {
+        return Some(GeneratedFile {
+            original: filename,
+            virtual_ts: cached.code,
+            source_map: cached.mappings,
+            original_content: source,
+            module: ModuleState::new(abs_path, hash, cached.specifiers, aliases),
+        });
+    }
 
-    if files.is_empty() {
-        eprintln!("No .vue files found matching patterns: {:?}", args.patterns);
-        return;
+    // Get script content (combine both script and script setup if both exist)
+    let (script_content, script_offset): (Option<String>, u32) =
+        match (descriptor.script.as_ref(), descriptor.script_setup.as_ref()) {
+            (Some(script), Some(script_setup)) => {
+                // Both exist: combine them (plain script first, then script setup)
+                (
+                    Some(format!("{}\n{}", script.content, script_setup.content)),
+                    script.loc.start as u32,
+                )
+            }
+            (None, Some(script_setup)) => (
+                Some(script_setup.content.to_string()),
+                script_setup.loc.start as u32,
+            ),
+            (Some(script), None) => (Some(script.content.to_string()), script.loc.start as u32),
+            (None, None) => (None, 0),
+        };
+    let script_content_ref = script_content.as_deref();
+
+    // Create allocator
+    let allocator = Bump::new();
+
+    // Analyze - need to analyze both script and script_setup if both exist
+    let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+
+    // Analyze plain script first (exports types, interfaces, etc.)
+    if let Some(ref script) = descriptor.script {
+        analyzer.analyze_script_plain(&script.content);
     }
 
-    if !args.quiet {
-        eprintln!("Generating Virtual TypeScript for {} files...", files.len());
+    // Then analyze script setup (reactive bindings, macros, etc.)
+    if let Some(ref script_setup) = descriptor.script_setup {
+        analyzer.analyze_script_setup(&script_setup.content);
     }
 
-    let gen_start = Instant::now();
+    let template_offset: u32 = descriptor
+        .template
+        .as_ref()
+        .map(|t| t.loc.start as u32)
+        .unwrap_or(0);
+
+    let template_ast = if let Some(ref template) = descriptor.template {
+        let (root, _) = parse(&allocator, &template.content);
+        analyzer.analyze_template(&root);
+        Some(root)
+    } else {
+        None
+    };
+
+    let summary = analyzer.finish();
+
+    // Generate Virtual TS using canon's implementation
+    let output = generate_virtual_ts_with_offsets(
+        &summary,
+        script_content_ref,
+        template_ast.as_ref(),
+        script_offset,
+        template_offset,
+        vts_options,
+    );
+
+    let specifiers = script_content_ref
+        .map(|script| script_imports(script, oxc_span::SourceType::ts()))
+        .unwrap_or_default();
+
+    if let Some(cache) = cache {
+        let cached = CachedVirtualTs {
+            code: output.code.clone(),
+            mappings: output.mappings.clone(),
+            specifiers: specifiers.clone(),
+        };
+        if let Err(e) = cache.store_virtual_ts(&hash, &cached) {
+            eprintln!("Failed to cache virtual TS for {}: {}", filename, e);
+        }
+    }
 
-    // Generate Virtual TypeScript for each file (in parallel)
-    let generated: Vec<GeneratedFile> = files
+    Some(GeneratedFile {
+        original: filename,
+        virtual_ts: output.code,
+        source_map: output.mappings,
+        original_content: source.clone(),
+        module: ModuleState::new(abs_path, hash, specifiers, aliases),
+    })
+}
+
+/// Generate Virtual TypeScript for each file (in parallel).
+fn generate_files(
+    files: &[PathBuf],
+    vts_options: &vize_canon::virtual_ts::VirtualTsOptions,
+    aliases: &PathAliases,
+    cache: Option<&CheckCache>,
+) -> Vec<GeneratedFile> {
+    use rayon::prelude::*;
+
+    files
         .par_iter()
-        .filter_map(|path| {
-            let source = fs::read_to_string(path).ok()?;
-            let original_content = source.clone();
-            // Use absolute path for proper file:// URI
-            let abs_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
-            let filename = abs_path.to_string_lossy().to_string();
-
-            // Parse SFC
-            let parse_opts = SfcParseOptions {
-                filename: filename.clone(),
-                ..Default::default()
-            };
-            let descriptor = parse_sfc(&source, parse_opts).ok()?;
-
-            // Get script content (combine both script and script setup if both exist)
-            let (script_content, script_offset): (Option<String>, u32) =
-                match (descriptor.script.as_ref(), descriptor.script_setup.as_ref()) {
-                    (Some(script), Some(script_setup)) => {
-                        // Both exist: combine them (plain script first, then script setup)
-                        (
-                            Some(format!("{}\n{}", script.content, script_setup.content)),
-                            script.loc.start as u32,
-                        )
-                    }
-                    (None, Some(script_setup)) => (
-                        Some(script_setup.content.to_string()),
-                        script_setup.loc.start as u32,
-                    ),
-                    (Some(script), None) => {
-                        (Some(script.content.to_string()), script.loc.start as u32)
-                    }
-                    (None, None) => (None, 0),
-                };
-            let script_content_ref = script_content.as_deref();
+        .filter_map(|path| generate_file(path, vts_options, aliases, cache))
+        .collect()
+}
+
+/// Track the SFCs and every script module reachable from them through
+/// relative or tsconfig-aliased imports, so changes to shared `.ts` files
+/// invalidate importers.
+fn track_modules(
+    generated: &[GeneratedFile],
+    aliases: &PathAliases,
+    cache: &CheckCache,
+) -> Vec<ModuleState> {
+    use vize_canon::batch::script_imports;
+
+    let mut modules: Vec<ModuleState> = generated.iter().map(|g| g.module.clone()).collect();
+    let mut seen: FxHashSet<PathBuf> = modules.iter().map(|m| m.path.clone()).collect();
+    let mut queue: Vec<PathBuf> = modules
+        .iter()
+        .flat_map(|m| m.resolved.iter().cloned())
+        .collect();
+
+    while let Some(path) = queue.pop() {
+        if !seen.insert(path.clone()) {
+            continue;
+        }
+        let Ok(source) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let hash = vize_carton::hash::content_hash(&source);
+
+        let specifiers = match cache.module(&path) {
+            Some(cached) if cached.content_hash == hash => cached.specifiers.clone(),
+            // SFCs outside the checked patterns are tracked by content only
+            _ if path.extension().is_some_and(|ext| ext == "vue") => Vec::new(),
+            _ => script_imports(
+                &source,
+                oxc_span::SourceType::from_path(&path).unwrap_or_default(),
+            ),
+        };
 
-            // Create allocator
-            let allocator = Bump::new();
+        let module = ModuleState::new(path, hash, specifiers, aliases);
+        queue.extend(
+            module
+                .resolved
+                .iter()
+                .filter(|p| !seen.contains(*p))
+                .cloned(),
+        );
+        modules.push(module);
+    }
 
-            // Analyze - need to analyze both script and script_setup if both exist
-            let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+    modules
+}
 
-            // Analyze plain script first (exports types, interfaces, etc.)
-            if let Some(ref script) = descriptor.script {
-                analyzer.analyze_script_plain(&script.content);
+/// Indices of generated files that must be re-checked.
+fn dirty_indices(
+    generated: &[GeneratedFile],
+    modules: &[ModuleState],
+    cache: &CheckCache,
+) -> Vec<usize> {
+    let checked: FxHashSet<PathBuf> = generated.iter().map(|g| g.module.path.clone()).collect();
+    let invalid = cache.invalidated(modules, &checked);
+
+    (0..generated.len())
+        .filter(|i| invalid.contains(&generated[*i].module.path))
+        .collect()
+}
+
+/// Find the project root (for tsconfig resolution) from the first file.
+///
+/// Skips .nuxt, .out, node_modules directories when looking for the main tsconfig.
+fn find_project_root(p: &std::path::Path) -> Option<String> {
+    // Walk up to find directory containing tsconfig.json
+    // that is NOT in a generated/hidden directory
+    let mut dir = p.parent();
+    let mut best_tsconfig: Option<std::path::PathBuf> = None;
+
+    while let Some(d) = dir {
+        let dir_name = d.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let is_generated_dir = dir_name.starts_with('.')
+            || dir_name == "node_modules"
+            || dir_name == "dist"
+            || dir_name == "build";
+
+        if d.join("tsconfig.json").exists() {
+            if is_generated_dir {
+                // Keep looking for a better one
+                if best_tsconfig.is_none() {
+                    best_tsconfig = Some(d.to_path_buf());
+                }
+            } else {
+                // Found a tsconfig in a non-generated directory - use it
+                return Some(d.to_string_lossy().to_string());
             }
+        }
+        dir = d.parent();
+    }
+
+    // Use the best found tsconfig (even if in generated dir) or fallback
+    if let Some(d) = best_tsconfig {
+        return Some(d.to_string_lossy().to_string());
+    }
+
+    // Fallback: use directory of the first file
+    p.parent().map(|d| d.to_string_lossy().to_string())
+}
 
-            // Then analyze script setup (reactive bindings, macros, etc.)
-            if let Some(ref script_setup) = descriptor.script_setup {
-                analyzer.analyze_script_setup(&script_setup.content);
+/// Request diagnostics for the given files from a tsgo LSP client that has
+/// them open, returning SFC-mapped diagnostics and the error count per file.
+fn request_file_diagnostics(
+    lsp_client: &mut vize_canon::lsp_client::TsgoLspClient,
+    generated: &[GeneratedFile],
+    indices: &[usize],
+) -> Vec<CheckedFile> {
+    // tsgo doesn't publish diagnostics automatically - we must request them
+    let uris: Vec<String> = indices
+        .iter()
+        .map(|i| virtual_uri(&generated[*i]))
+        .collect();
+    let batch_results = lsp_client.request_diagnostics_batch(&uris);
+
+    // Build a map from URI to diagnostics
+    let diag_map: std::collections::HashMap<_, _> = batch_results.into_iter().collect();
+
+    let mut checked = Vec::with_capacity(indices.len());
+    for idx in indices {
+        let g = &generated[*idx];
+
+        // Get diagnostics from batch result
+        let diagnostics = diag_map.get(&virtual_uri(g)).cloned().unwrap_or_default();
+
+        // Filter diagnostics and map them back to the SFC
+        let mut file_diags: Vec<CachedDiagnostic> = Vec::new();
+        let mut errors = 0;
+        for diag in &diagnostics {
+            let code_num = diag.code.as_ref().and_then(|c| match c {
+                serde_json::Value::Number(n) => n.as_u64(),
+                serde_json::Value::String(s) => {
+                    // Handle both "2307" and "TS2307" formats
+                    let stripped = s.strip_prefix("TS").unwrap_or(s);
+                    stripped.parse::<u64>().ok()
+                }
+                _ => None,
+            });
+
+            // Module resolution: fundamental limitation of single-file mode.
+            // tsgo cannot resolve .vue imports, path aliases, or npm packages
+            // without a full project context. This is NOT a virtual TS bug.
+            if matches!(code_num, Some(2307) | Some(2666)) {
+                continue;
             }
 
-            let template_offset: u32 = descriptor
-                .template
-                .as_ref()
-                .map(|t| t.loc.start as u32)
-                .unwrap_or(0);
+            let severity = match diag.severity {
+                Some(2) => 2,
+                _ => {
+                    errors += 1;
+                    1
+                }
+            };
+            let code = diag.code.as_ref().and_then(|c| match c {
+                serde_json::Value::Number(n) => Some(format!("TS{}", n)),
+                serde_json::Value::String(s) => Some(s.clone()),
+                _ => None,
+            });
+            // Map virtual TS range -> SFC range
+            let ((line, column), (end_line, end_column)) = map_diagnostic_range(
+                &g.virtual_ts,
+                &g.source_map,
+                &g.original_content,
+                &diag.range,
+            );
+            file_diags.push(CachedDiagnostic {
+                severity,
+                code,
+                message: diag.message.clone(),
+                line,
+                column,
+                end_line,
+                end_column,
+            });
+        }
+
+        checked.push(CheckedFile {
+            index: *idx,
+            diagnostics: file_diags,
+            errors,
+        });
+    }
 
-            let template_ast = if let Some(ref template) = descriptor.template {
-                let (root, _) = parse(&allocator, &template.content);
-                analyzer.analyze_template(&root);
-                Some(root)
+    checked
+}
+
+/// Combine fresh and cached diagnostics, and record them in the cache.
+///
+/// Files that were neither checked nor cached (e.g. tsgo failed to start)
+/// are left without cached diagnostics so the next run re-checks them.
+fn merge_results(
+    generated: &[GeneratedFile],
+    modules: &[ModuleState],
+    checked: Vec<CheckedFile>,
+    cache: &mut CheckCache,
+) -> (Vec<(String, Vec<CachedDiagnostic>)>, usize) {
+    let mut fresh: FxHashMap<usize, CheckedFile> =
+        checked.into_iter().map(|c| (c.index, c)).collect();
+
+    let mut all_diagnostics = Vec::new();
+    let mut total_errors = 0;
+    let mut results: FxHashMap<PathBuf, (Vec<CachedDiagnostic>, usize)> = FxHashMap::default();
+
+    for (index, g) in generated.iter().enumerate() {
+        let result = match fresh.remove(&index) {
+            Some(checked) => Some((checked.diagnostics, checked.errors)),
+            None => cache
+                .module(&g.module.path)
+                .filter(|m| m.content_hash == g.module.content_hash)
+                .and_then(|m| m.diagnostics.clone().map(|d| (d, m.error_count))),
+        };
+        let Some((diagnostics, errors)) = result else {
+            continue;
+        };
+
+        total_errors += errors;
+        if !diagnostics.is_empty() {
+            all_diagnostics.push((g.original.clone(), diagnostics.clone()));
+        }
+        results.insert(g.module.path.clone(), (diagnostics, errors));
+    }
+
+    for module in modules {
+        let mut entry = CachedModule::new(module);
+        if let Some((diagnostics, errors)) = results.remove(&module.path) {
+            entry = entry.with_diagnostics(diagnostics, errors);
+        }
+        cache.update(module.path.clone(), entry);
+    }
+    let tracked: FxHashSet<&std::path::Path> = modules.iter().map(|m| m.path.as_path()).collect();
+    cache.retain(|path| tracked.contains(path));
+
+    (all_diagnostics, total_errors)
+}
+
+/// Format a diagnostic as `error:12:5 [TS2322] message`.
+fn format_diagnostic(diag: &CachedDiagnostic) -> String {
+    let severity = if diag.severity == 2 {
+        "warning"
+    } else {
+        "error"
+    };
+    let code = diag
+        .code
+        .as_ref()
+        .map(|code| format!(" [{}]", code))
+        .unwrap_or_default();
+    format!(
+        "{}:{}:{}{} {}",
+        severity, diag.line, diag.column, code, diag.message
+    )
+}
+
+/// Print diagnostics grouped by file.
+fn print_diagnostics(all_diagnostics: &[(String, Vec<CachedDiagnostic>)]) {
+    for (filename, diags) in all_diagnostics {
+        println!("\n\x1b[4m{}\x1b[0m", filename);
+        for diag in diags {
+            let color = if diag.severity == 2 {
+                "\x1b[33m"
             } else {
-                None
+                "\x1b[31m"
             };
+            println!("  {}{}\x1b[0m", color, format_diagnostic(diag));
+        }
+    }
+}
 
-            let summary = analyzer.finish();
+/// Map a diagnostic severity name to the lint severity used by the report emitters.
+fn parse_severity(severity: &str) -> Severity {
+    if severity == "warning" {
+        Severity::Warning
+    } else {
+        Severity::Error
+    }
+}
 
-            // Generate Virtual TS using canon's implementation
-            let output = generate_virtual_ts_with_offsets(
-                &summary,
-                script_content_ref,
-                template_ast.as_ref(),
-                script_offset,
-                template_offset,
-                &vts_options,
-            );
+/// Convert a diagnostic for the report emitters.
+fn report_diagnostic(diag: &CachedDiagnostic) -> ReportDiagnostic {
+    ReportDiagnostic {
+        rule: diag.code.clone().unwrap_or_else(|| "tsgo".to_string()),
+        severity: if diag.severity == 2 {
+            Severity::Warning
+        } else {
+            Severity::Error
+        },
+        message: diag.message.clone(),
+        help: None,
+        line: diag.line,
+        column: diag.column,
+        end_line: diag.end_line,
+        end_column: diag.end_column,
+    }
+}
 
-            Some(GeneratedFile {
-                original: filename,
-                virtual_ts: output.code,
-                source_map: output.mappings,
-                original_content,
-            })
+/// Path of a file as shown in reports: relative to the working directory
+/// when inside it, so CI annotations point at repository files.
+fn report_path(path: &str) -> String {
+    let cwd = std::env::current_dir().and_then(|cwd| cwd.canonicalize());
+    let path = std::path::Path::new(path);
+    cwd.ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Build per-file reports for every checked file, including clean ones.
+fn report_files(
+    generated: &[GeneratedFile],
+    all_diagnostics: &[(String, Vec<CachedDiagnostic>)],
+) -> Vec<ReportFile> {
+    generated
+        .iter()
+        .map(|g| ReportFile {
+            path: report_path(&g.original),
+            diagnostics: all_diagnostics
+                .iter()
+                .find(|(f, _)| f == &g.original)
+                .map(|(_, diags)| diags.iter().map(report_diagnostic).collect())
+                .unwrap_or_default(),
         })
-        .collect();
+        .collect()
+}
+
+/// Run type checking directly with tsgo LSP (no file I/O)
+fn run_direct(args: &CheckArgs, format: OutputFormat) {
+    use vize_canon::lsp_client::TsgoLspClient;
+
+    if args.watch {
+        run_watch(args);
+        return;
+    }
+
+    let start = Instant::now();
+
+    // Load vize.config.json and write JSON Schema
+    let config = crate::config::load_config(None);
+    crate::config::write_schema(None);
+
+    let vts_options = virtual_ts_options(args, &config);
 
+    // Collect .vue files
+    let collect_start = Instant::now();
+    let files = collect_vue_files(&args.patterns);
+    let collect_time = collect_start.elapsed();
+
+    if files.is_empty() {
+        eprintln!("No .vue files found matching patterns: {:?}", args.patterns);
+        return;
+    }
+
+    let first_file = files[0].canonicalize().unwrap_or_else(|_| files[0].clone());
+    let project_root = find_project_root(&first_file);
+    let aliases = project_root
+        .as_deref()
+        .map(|root| PathAliases::load(std::path::Path::new(root)))
+        .unwrap_or_default();
+    let mut cache = if args.no_cache {
+        CheckCache::new(std::path::Path::new("."), "")
+    } else {
+        CheckCache::open(
+            std::path::Path::new("."),
+            config_hash(&vts_options, project_root.as_deref()),
+        )
+    };
+
+    if !args.quiet {
+        eprintln!("Generating Virtual TypeScript for {} files...", files.len());
+    }
+
+    let gen_start = Instant::now();
+    let generated = generate_files(
+        &files,
+        &vts_options,
+        &aliases,
+        (!args.no_cache).then_some(&cache),
+    );
     let gen_time = gen_start.elapsed();
 
     if generated.is_empty() {
@@ -603,60 +1113,29 @@ fn run_direct(args: &CheckArgs) {
         }
     }
 
-    if !args.quiet {
-        eprintln!("Running tsgo LSP on {} files...", generated.len());
+    // Only changed files and their transitive dependents need tsgo
+    let modules = track_modules(&generated, &aliases, &cache);
+    let dirty = dirty_indices(&generated, &modules, &cache);
+    let cached_count = generated.len() - dirty.len();
+
+    if !args.quiet && !dirty.is_empty() {
+        if cached_count > 0 {
+            eprintln!(
+                "Running tsgo LSP on {} changed files ({} cached)...",
+                dirty.len(),
+                cached_count
+            );
+        } else {
+            eprintln!("Running tsgo LSP on {} files...", dirty.len());
+        }
     }
 
     let check_start = Instant::now();
 
-    // Find project root from first generated file (for tsconfig resolution)
-    // Skip .nuxt, .out, node_modules directories when looking for the main tsconfig
-    let project_root = generated
-        .first()
-        .map(|g| std::path::Path::new(&g.original))
-        .and_then(|p| {
-            // Walk up to find directory containing tsconfig.json
-            // that is NOT in a generated/hidden directory
-            let mut dir = p.parent();
-            let mut best_tsconfig: Option<std::path::PathBuf> = None;
-
-            while let Some(d) = dir {
-                let dir_name = d.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                let is_generated_dir = dir_name.starts_with('.')
-                    || dir_name == "node_modules"
-                    || dir_name == "dist"
-                    || dir_name == "build";
-
-                if d.join("tsconfig.json").exists() {
-                    if is_generated_dir {
-                        // Keep looking for a better one
-                        if best_tsconfig.is_none() {
-                            best_tsconfig = Some(d.to_path_buf());
-                        }
-                    } else {
-                        // Found a tsconfig in a non-generated directory - use it
-                        return Some(d.to_string_lossy().to_string());
-                    }
-                }
-                dir = d.parent();
-            }
-
-            // Use the best found tsconfig (even if in generated dir) or fallback
-            if let Some(d) = best_tsconfig {
-                return Some(d.to_string_lossy().to_string());
-            }
-
-            // Fallback: use directory of the first file
-            p.parent().map(|d| d.to_string_lossy().to_string())
-        });
-
     // Build shared URI map for all files (so imports can be resolved across servers)
     let uri_map: Vec<(String, String)> = generated
         .iter()
-        .map(|g| {
-            let virtual_uri = format!("file://{}.mts", g.original);
-            (virtual_uri, g.virtual_ts.clone())
-        })
+        .map(|g| (virtual_uri(g), g.virtual_ts.clone()))
         .collect();
 
     // Determine number of parallel LSP servers
@@ -665,26 +1144,20 @@ fn run_direct(args: &CheckArgs) {
     let num_cpus = std::thread::available_parallelism()
         .map(|n| n.get())
         .unwrap_or(4);
-    let num_servers = if generated.len() < 30 {
+    let num_servers = if dirty.len() < 30 {
         1 // Single server for small projects (less overhead)
     } else {
         // Use at most 4 servers (diminishing returns beyond that)
-        num_cpus.min(4).min(generated.len() / 10).max(1)
+        num_cpus.min(4).min(dirty.len() / 10).max(1)
     };
 
     // Partition INDICES for diagnostics collection (each server checks a subset)
-    let chunk_size = generated.len().div_ceil(num_servers);
-    let index_chunks: Vec<_> = (0..generated.len())
-        .collect::<Vec<_>>()
-        .chunks(chunk_size)
-        .map(|c| c.to_vec())
-        .collect();
+    let chunk_size = dirty.len().div_ceil(num_servers).max(1);
+    let index_chunks: Vec<_> = dirty.chunks(chunk_size).map(|c| c.to_vec()).collect();
 
     // Run type checking in parallel across multiple LSP servers
-    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
     use std::sync::Mutex;
-    let total_errors = AtomicUsize::new(0);
-    let all_diagnostics: Mutex<Vec<(String, Vec<String>)>> = Mutex::new(Vec::new());
+    let all_checked: Mutex<Vec<CheckedFile>> = Mutex::new(Vec::new());
 
     std::thread::scope(|s| {
         let handles: Vec<_> = index_chunks
@@ -692,8 +1165,7 @@ fn run_direct(args: &CheckArgs) {
             .map(|indices| {
                 let project_root = project_root.clone();
                 let tsgo_path = args.tsgo_path.clone();
-                let total_errors = &total_errors;
-                let all_diagnostics = &all_diagnostics;
+                let all_checked = &all_checked;
                 let uri_map = &uri_map;
                 let generated = &generated;
 
@@ -725,85 +1197,8 @@ fn run_direct(args: &CheckArgs) {
                     lsp_client.wait_for_diagnostics(files_to_open.len());
 
                     // PHASE 2: Request diagnostics in batch (pipelined)
-                    // tsgo doesn't publish diagnostics automatically - we must request them
-                    let uris: Vec<String> = indices
-                        .iter()
-                        .map(|i| format!("file://{}.mts", generated[*i].original))
-                        .collect();
-
-                    let batch_results = lsp_client.request_diagnostics_batch(&uris);
-
-                    // Build a map from URI to diagnostics
-                    let diag_map: std::collections::HashMap<_, _> =
-                        batch_results.into_iter().collect();
-
-                    let mut chunk_diagnostics: Vec<(String, Vec<String>)> = Vec::new();
-
-                    for idx in &indices {
-                        let g = &generated[*idx];
-                        let virtual_uri = format!("file://{}.mts", g.original);
-
-                        // Get diagnostics from batch result
-                        let diagnostics = diag_map.get(&virtual_uri).cloned().unwrap_or_default();
-
-                        // Filter and format diagnostics
-                        let mut file_diags: Vec<String> = Vec::new();
-                        for diag in &diagnostics {
-                            let code_num = diag.code.as_ref().and_then(|c| match c {
-                                serde_json::Value::Number(n) => n.as_u64(),
-                                serde_json::Value::String(s) => {
-                                    // Handle both "2307" and "TS2307" formats
-                                    let stripped = s.strip_prefix("TS").unwrap_or(s);
-                                    stripped.parse::<u64>().ok()
-                                }
-                                _ => None,
-                            });
-
-                            // Module resolution: fundamental limitation of single-file mode.
-                            // tsgo cannot resolve .vue imports, path aliases, or npm packages
-                            // without a full project context. This is NOT a virtual TS bug.
-                            if matches!(code_num, Some(2307) | Some(2666)) {
-                                continue;
-                            }
-
-                            let severity = match diag.severity {
-                                Some(1) => {
-                                    total_errors.fetch_add(1, AtomicOrdering::Relaxed);
-                                    "error"
-                                }
-                                Some(2) => "warning",
-                                _ => {
-                                    total_errors.fetch_add(1, AtomicOrdering::Relaxed);
-                                    "error"
-                                }
-                            };
-                            let code_str = diag
-                                .code
-                                .as_ref()
-                                .map(|c| match c {
-                                    serde_json::Value::Number(n) => format!(" [TS{}]", n),
-                                    serde_json::Value::String(s) => format!(" [{}]", s),
-                                    _ => String::new(),
-                                })
-                                .unwrap_or_default();
-                            // Map virtual TS position -> SFC position
-                            let (line, col) = map_diagnostic_position(
-                                &g.virtual_ts,
-                                &g.source_map,
-                                &g.original_content,
-                                diag.range.start.line,
-                                diag.range.start.character,
-                            );
-                            file_diags.push(format!(
-                                "{}:{}:{}{} {}",
-                                severity, line, col, code_str, diag.message
-                            ));
-                        }
-
-                        if !file_diags.is_empty() {
-                            chunk_diagnostics.push((g.original.clone(), file_diags));
-                        }
-                    }
+                    let chunk_checked =
+                        request_file_diagnostics(&mut lsp_client, generated, &indices);
 
                     // PHASE 3: Close files that were opened
                     for (uri, _) in &files_to_open {
@@ -811,8 +1206,8 @@ fn run_direct(args: &CheckArgs) {
                     }
 
                     // Merge diagnostics into shared state
-                    if let Ok(mut diags) = all_diagnostics.lock() {
-                        diags.extend(chunk_diagnostics);
+                    if let Ok(mut checked) = all_checked.lock() {
+                        checked.extend(chunk_checked);
                     }
                 })
             })
@@ -824,14 +1219,23 @@ fn run_direct(args: &CheckArgs) {
         }
     });
 
-    let total_errors = total_errors.load(AtomicOrdering::Relaxed);
-    let all_diagnostics = all_diagnostics.into_inner().unwrap();
+    let (all_diagnostics, total_errors) = merge_results(
+        &generated,
+        &modules,
+        all_checked.into_inner().unwrap(),
+        &mut cache,
+    );
+    if !args.no_cache {
+        if let Err(e) = cache.save() {
+            eprintln!("Failed to write check cache: {}", e);
+        }
+    }
 
     let check_time = check_start.elapsed();
     let total_time = start.elapsed();
 
     // JSON output
-    if args.format == "json" {
+    if format == OutputFormat::Json {
         let json_output = JsonOutput {
             files: generated
                 .iter()
@@ -839,7 +1243,7 @@ fn run_direct(args: &CheckArgs) {
                     let diags = all_diagnostics
                         .iter()
                         .find(|(f, _)| f == &g.original)
-                        .map(|(_, d)| d.clone())
+                        .map(|(_, d)| d.iter().map(format_diagnostic).collect())
                         .unwrap_or_default();
                     JsonFileResult {
                         file: g.original.clone(),
@@ -855,19 +1259,20 @@ fn run_direct(args: &CheckArgs) {
         return;
     }
 
+    // SARIF / JUnit / Checkstyle / GitHub output
+    if let Some(report) =
+        format.render_reports("vize-canon", &report_files(&generated, &all_diagnostics))
+    {
+        print!("{}", report);
+        if total_errors > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Print diagnostics
     if !args.quiet {
-        for (filename, diags) in &all_diagnostics {
-            println!("\n\x1b[4m{}\x1b[0m", filename);
-            for diag in diags {
-                let color = if diag.starts_with("error") {
-                    "\x1b[31m"
-                } else {
-                    "\x1b[33m"
-                };
-                println!("  {}{}\x1b[0m", color, diag);
-            }
-        }
+        print_diagnostics(&all_diagnostics);
     }
 
     // Print summary
@@ -877,10 +1282,16 @@ fn run_direct(args: &CheckArgs) {
         "\x1b[32m✓\x1b[0m"
     };
 
+    let cached_str = if cached_count > 0 {
+        format!(" ({} cached)", cached_count)
+    } else {
+        String::new()
+    };
     println!(
-        "\n{} Type checked {} files in {:.2?} (collect: {:.2?}, gen: {:.2?}, lsp: {:.2?})",
+        "\n{} Type checked {} files{} in {:.2?} (collect: {:.2?}, gen: {:.2?}, lsp: {:.2?})",
         status,
         generated.len(),
+        cached_str,
         total_time,
         collect_time,
         gen_time,
@@ -902,6 +1313,7 @@ fn run_direct(args: &CheckArgs) {
                 .map(|d| d.as_secs())
                 .unwrap_or(0),
             "files": generated.len(),
+            "cached": cached_count,
             "errors": total_errors,
             "timing": {
                 "total_ms": total_time.as_secs_f64() * 1000.0,
@@ -912,7 +1324,7 @@ fn run_direct(args: &CheckArgs) {
                 serde_json::json!({
                     "file": file,
                     "count": diags.len(),
-                    "messages": diags,
+                    "messages": diags.iter().map(format_diagnostic).collect::<Vec<_>>(),
                 })
             }).collect::<Vec<_>>(),
         });
@@ -934,3 +1346,413 @@ fn run_direct(args: &CheckArgs) {
         std::process::exit(1);
     }
 }
+
+/// Script modules each SFC depends on, directly or transitively, as
+/// reported to the watcher.
+fn module_dependencies(
+    generated: &[GeneratedFile],
+    modules: &[ModuleState],
+) -> Vec<(PathBuf, Vec<String>)> {
+    let by_path: FxHashMap<&std::path::Path, &ModuleState> =
+        modules.iter().map(|m| (m.path.as_path(), m)).collect();
+
+    generated
+        .iter()
+        .map(|g| {
+            let mut seen: FxHashSet<&std::path::Path> = FxHashSet::default();
+            let mut queue: Vec<&std::path::Path> =
+                g.module.resolved.iter().map(PathBuf::as_path).collect();
+            while let Some(path) = queue.pop() {
+                if !seen.insert(path) {
+                    continue;
+                }
+                if let Some(module) = by_path.get(path) {
+                    queue.extend(module.resolved.iter().map(PathBuf::as_path));
+                }
+            }
+            let dependencies = seen
+                .into_iter()
+                .map(|path| path.to_string_lossy().to_string())
+                .collect();
+            (g.module.path.clone(), dependencies)
+        })
+        .collect()
+}
+
+/// Watch mode: keep a single tsgo session alive and re-check the files
+/// affected by each change.
+fn run_watch(args: &CheckArgs) {
+    use super::watch::Watcher;
+    use vize_canon::lsp_client::TsgoLspClient;
+
+    let config = crate::config::load_config(None);
+    crate::config::write_schema(None);
+    let vts_options = virtual_ts_options(args, &config);
+
+    let mut watcher = match Watcher::new(&args.patterns, || collect_vue_files(&args.patterns)) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("\x1b[31mError:\x1b[0m failed to start watching: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let files = watcher.files();
+    if files.is_empty() {
+        eprintln!("No .vue files found matching patterns: {:?}", args.patterns);
+        return;
+    }
+
+    let first_file = files[0].canonicalize().unwrap_or_else(|_| files[0].clone());
+    let project_root = find_project_root(&first_file);
+    let aliases = project_root
+        .as_deref()
+        .map(|root| PathAliases::load(std::path::Path::new(root)))
+        .unwrap_or_default();
+    let mut cache = if args.no_cache {
+        CheckCache::new(std::path::Path::new("."), "")
+    } else {
+        CheckCache::open(
+            std::path::Path::new("."),
+            config_hash(&vts_options, project_root.as_deref()),
+        )
+    };
+
+    let mut lsp_client =
+        match TsgoLspClient::new(args.tsgo_path.as_deref(), project_root.as_deref()) {
+            Ok(client) => client,
+            Err(e) => {
+                eprintln!("\x1b[31mError:\x1b[0m Failed to start tsgo LSP: {}", e);
+                std::process::exit(1);
+            }
+        };
+    let mut opened: FxHashMap<String, String> = FxHashMap::default();
+
+    loop {
+        let start = Instant::now();
+        let generated = generate_files(
+            &watcher.files(),
+            &vts_options,
+            &aliases,
+            (!args.no_cache).then_some(&cache),
+        );
+
+        // Sync the tsgo session with the current virtual files
+        let current: FxHashSet<String> = generated.iter().map(virtual_uri).collect();
+        let stale: Vec<String> = opened
+            .keys()
+            .filter(|uri| !current.contains(*uri))
+            .cloned()
+            .collect();
+        for uri in stale {
+            let _ = lsp_client.did_close(&uri);
+            opened.remove(&uri);
+        }
+        let mut newly_opened = 0;
+        for g in &generated {
+            let uri = virtual_uri(g);
+            match opened.get(&uri) {
+                Some(content) if *content == g.virtual_ts => {}
+                Some(_) => {
+                    let _ = lsp_client.did_change(&uri, &g.virtual_ts);
+                }
+                None => {
+                    let _ = lsp_client.did_open_fast(&uri, &g.virtual_ts);
+                    newly_opened += 1;
+                }
+            }
+            opened.insert(uri, g.virtual_ts.clone());
+        }
+        if newly_opened > 0 {
+            lsp_client.wait_for_diagnostics(newly_opened);
+        }
+
+        let modules = track_modules(&generated, &aliases, &cache);
+        let dirty = dirty_indices(&generated, &modules, &cache);
+        let checked = request_file_diagnostics(&mut lsp_client, &generated, &dirty);
+        let (all_diagnostics, total_errors) =
+            merge_results(&generated, &modules, checked, &mut cache);
+        if !args.no_cache {
+            if let Err(e) = cache.save() {
+                eprintln!("Failed to write check cache: {}", e);
+            }
+        }
+
+        if !args.quiet {
+            print_diagnostics(&all_diagnostics);
+        }
+        let status = if total_errors > 0 {
+            "\x1b[31m✗\x1b[0m"
+        } else {
+            "\x1b[32m✓\x1b[0m"
+        };
+        println!(
+            "\n{} Type checked {} files ({} re-checked) in {:.2?}: {} error(s)",
+            status,
+            generated.len(),
+            dirty.len(),
+            start.elapsed(),
+            total_errors
+        );
+
+        // The watcher's own scan does not follow aliased imports
+        for (path, dependencies) in module_dependencies(&generated, &modules) {
+            watcher.set_dependencies(&path, &dependencies);
+        }
+        if watcher.next_change().is_none() {
+            break;
+        }
+
+        // tsgo reads imported script modules from disk
+        let scripts: Vec<String> = watcher
+            .changed()
+            .iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext != "vue"))
+            .map(|path| format!("file://{}", path.display()))
+            .collect();
+        if !scripts.is_empty() {
+            let _ = lsp_client.did_change_watched_files(&scripts);
+        }
+    }
+}
+
+/// A file checked by tsgo in this run.
+struct CheckedFile {
+    index: usize,
+    diagnostics: Vec<CachedDiagnostic>,
+    errors: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn temp_project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("vize-check-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        root.canonicalize().unwrap()
+    }
+
+    /// An app importing a composable through a `@/` alias, which imports a
+    /// shared module, which imports the types; `Other.vue` imports nothing.
+    fn alias_project(name: &str) -> (PathBuf, PathAliases) {
+        let root = temp_project(
+            name,
+            &[
+                (
+                    "tsconfig.json",
+                    r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#,
+                ),
+                (
+                    "src/App.vue",
+                    "<script setup lang=\"ts\">\nimport { ref } from 'vue'\nimport { useCount } from '@/composables/useCount'\nconst count = useCount(ref(0))\n</script>\n<template>{{ count }}</template>\n",
+                ),
+                ("src/Other.vue", "<template><p>other</p></template>\n"),
+                (
+                    "src/composables/useCount.ts",
+                    "export { useCount } from './shared'\n",
+                ),
+                (
+                    "src/composables/shared.ts",
+                    "import type { Count } from '../types'\nexport function useCount(c: Count) { return c }\n",
+                ),
+                ("src/types.ts", "export type Count = { value: number }\n"),
+            ],
+        );
+        let aliases = PathAliases::load(&root);
+        (root, aliases)
+    }
+
+    fn generate(root: &Path, names: &[&str], aliases: &PathAliases) -> Vec<GeneratedFile> {
+        let files: Vec<PathBuf> = names.iter().map(|name| root.join(name)).collect();
+        generate_files(&files, &Default::default(), aliases, None)
+    }
+
+    fn diagnostic(message: &str) -> CachedDiagnostic {
+        CachedDiagnostic {
+            severity: 1,
+            code: Some("TS2322".to_string()),
+            message: message.to_string(),
+            line: 2,
+            column: 5,
+            end_line: 2,
+            end_column: 10,
+        }
+    }
+
+    fn checked(index: usize, diagnostics: Vec<CachedDiagnostic>) -> CheckedFile {
+        let errors = diagnostics.len();
+        CheckedFile {
+            index,
+            diagnostics,
+            errors,
+        }
+    }
+
+    #[test]
+    fn test_track_modules() {
+        let (root, aliases) = alias_project("track");
+        let generated = generate(&root, &["src/App.vue", "src/Other.vue"], &aliases);
+        assert_eq!(
+            generated[0].module.resolved,
+            vec![root.join("src/composables/useCount.ts")]
+        );
+
+        let cache = CheckCache::new(&root, "config");
+        let modules = track_modules(&generated, &aliases, &cache);
+        let mut paths: Vec<PathBuf> = modules.iter().map(|m| m.path.clone()).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                root.join("src/App.vue"),
+                root.join("src/Other.vue"),
+                root.join("src/composables/shared.ts"),
+                root.join("src/composables/useCount.ts"),
+                root.join("src/types.ts"),
+            ]
+        );
+
+        // Unchanged modules reuse their cached specifiers instead of re-parsing
+        let shared = modules
+            .iter()
+            .find(|m| m.path == root.join("src/composables/shared.ts"))
+            .unwrap();
+        let mut cache = CheckCache::new(&root, "config");
+        let mut entry = CachedModule::new(shared);
+        entry.specifiers.clear();
+        cache.update(shared.path.clone(), entry);
+        let modules = track_modules(&generated, &aliases, &cache);
+        assert!(!modules.iter().any(|m| m.path == root.join("src/types.ts")));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_dirty_indices() {
+        let (root, aliases) = alias_project("dirty");
+        let names = ["src/App.vue", "src/Other.vue"];
+        let mut cache = CheckCache::new(&root, "config");
+
+        // Nothing is cached yet
+        let generated = generate(&root, &names, &aliases);
+        let modules = track_modules(&generated, &aliases, &cache);
+        assert_eq!(dirty_indices(&generated, &modules, &cache), vec![0, 1]);
+
+        let results = vec![checked(0, vec![]), checked(1, vec![])];
+        merge_results(&generated, &modules, results, &mut cache);
+        assert!(dirty_indices(&generated, &modules, &cache).is_empty());
+
+        // A change three imports away re-checks the app only
+        fs::write(root.join("src/types.ts"), "export type Count = number\n").unwrap();
+        let generated = generate(&root, &names, &aliases);
+        let modules = track_modules(&generated, &aliases, &cache);
+        assert_eq!(dirty_indices(&generated, &modules, &cache), vec![0]);
+
+        // Editing an SFC re-checks it even though nothing imports it
+        let results = vec![checked(0, vec![])];
+        merge_results(&generated, &modules, results, &mut cache);
+        fs::write(
+            root.join("src/Other.vue"),
+            "<template><p>edited</p></template>\n",
+        )
+        .unwrap();
+        let generated = generate(&root, &names, &aliases);
+        let modules = track_modules(&generated, &aliases, &cache);
+        assert_eq!(dirty_indices(&generated, &modules, &cache), vec![1]);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_merge_results() {
+        let root = temp_project(
+            "merge",
+            &[
+                ("A.vue", "<template>{{ a }}</template>\n"),
+                ("B.vue", "<template>{{ b }}</template>\n"),
+                ("C.vue", "<template>{{ c }}</template>\n"),
+            ],
+        );
+        let aliases = PathAliases::default();
+        let generated = generate(&root, &["A.vue", "B.vue", "C.vue"], &aliases);
+        let modules = track_modules(&generated, &aliases, &CheckCache::new(&root, "config"));
+
+        let mut cache = CheckCache::new(&root, "config");
+        // B was checked in an earlier run; a module that no longer exists is stale
+        cache.update(
+            generated[1].module.path.clone(),
+            CachedModule::new(&generated[1].module).with_diagnostics(vec![diagnostic("b")], 1),
+        );
+        cache.update(
+            root.join("Removed.vue"),
+            CachedModule::new(&generated[2].module).with_diagnostics(vec![], 0),
+        );
+
+        // A is checked now; C was neither checked nor cached (tsgo failed)
+        let (all, errors) = merge_results(
+            &generated,
+            &modules,
+            vec![checked(0, vec![diagnostic("a")])],
+            &mut cache,
+        );
+        assert_eq!(errors, 2);
+        assert_eq!(
+            all,
+            vec![
+                (generated[0].original.clone(), vec![diagnostic("a")]),
+                (generated[1].original.clone(), vec![diagnostic("b")]),
+            ]
+        );
+
+        let a = cache.module(&root.join("A.vue")).unwrap();
+        assert_eq!(a.diagnostics, Some(vec![diagnostic("a")]));
+        assert_eq!(a.error_count, 1);
+        assert!(cache
+            .module(&root.join("C.vue"))
+            .unwrap()
+            .diagnostics
+            .is_none());
+        assert!(cache.module(&root.join("Removed.vue")).is_none());
+        assert_eq!(cache.len(), 3);
+
+        // Cached diagnostics of a changed file are not reused
+        fs::write(root.join("B.vue"), "<template>{{ b2 }}</template>\n").unwrap();
+        let generated = generate(&root, &["A.vue", "B.vue", "C.vue"], &aliases);
+        let (all, errors) = merge_results(&generated, &modules, vec![], &mut cache);
+        assert_eq!(errors, 1);
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].0, generated[0].original);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_module_dependencies() {
+        let (root, aliases) = alias_project("deps");
+        let generated = generate(&root, &["src/App.vue", "src/Other.vue"], &aliases);
+        let modules = track_modules(&generated, &aliases, &CheckCache::new(&root, "config"));
+
+        let dependencies = module_dependencies(&generated, &modules);
+        assert_eq!(dependencies[0].0, root.join("src/App.vue"));
+        let mut app: Vec<String> = dependencies[0].1.clone();
+        app.sort();
+        let expected: Vec<String> = [
+            "src/composables/shared.ts",
+            "src/composables/useCount.ts",
+            "src/types.ts",
+        ]
+        .iter()
+        .map(|path| root.join(path).to_string_lossy().to_string())
+        .collect();
+        assert_eq!(app, expected);
+        assert!(dependencies[1].1.is_empty());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,260 @@
+//! Codemod command - Apply template codemods to Vue SFC files
+
+use clap::Args;
+use glob::glob;
+use ignore::Walk;
+use rayon::prelude::*;
+use similar::TextDiff;
+use std::fs;
+use std::path::PathBuf;
+use vize_armature::codemod::{self, Codemod, RenameComponent, VBindShorthand, BUILTIN_CODEMODS};
+use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
+
+#[derive(Args)]
+pub struct CodemodArgs {
+    /// Codemod to apply (see --list)
+    #[arg(required_unless_present = "list")]
+    pub codemod: Option<String>,
+
+    /// Glob pattern(s) to match .vue files
+    #[arg(default_value = "./**/*.vue")]
+    pub patterns: Vec<String>,
+
+    /// Write changes to files instead of printing a diff
+    #[arg(short, long)]
+    pub write: bool,
+
+    /// List available codemods
+    #[arg(long)]
+    pub list: bool,
+
+    /// Component to rename (rename-component)
+    #[arg(long)]
+    pub from: Option<String>,
+
+    /// New component name (rename-component)
+    #[arg(long)]
+    pub to: Option<String>,
+}
+
+pub fn run(args: CodemodArgs) {
+    if args.list {
+        for (name, description) in BUILTIN_CODEMODS {
+            println!("{:<20} {}", name, description);
+        }
+        return;
+    }
+
+    let codemod = match resolve_codemod(&args) {
+        Ok(codemod) => codemod,
+        Err(e) => {
+            eprintln!("\x1b[31mError:\x1b[0m {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let files = collect_files(&args.patterns);
+    if files.is_empty() {
+        eprintln!("No .vue files found matching patterns: {:?}", args.patterns);
+        return;
+    }
+
+    let results: Vec<_> = files
+        .par_iter()
+        .filter_map(|path| {
+            let source = match fs::read_to_string(path) {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Failed to read {}: {}", path.display(), e);
+                    return None;
+                }
+            };
+            match transform_sfc(&source, path, codemod.as_ref()) {
+                Ok(Some(output)) => Some((path, source, output)),
+                Ok(None) => None,
+                Err(e) => {
+                    eprintln!("Skipping {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    for (path, source, output) in &results {
+        if args.write {
+            if let Err(e) = fs::write(path, output) {
+                eprintln!("Failed to write {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        } else {
+            let name = path.display().to_string();
+            print!(
+                "{}",
+                TextDiff::from_lines(source, output)
+                    .unified_diff()
+                    .header(&name, &name)
+            );
+        }
+    }
+
+    let verb = if args.write {
+        "Changed"
+    } else {
+        "Would change"
+    };
+    eprintln!(
+        "{} {} of {} files with {}",
+        verb,
+        results.len(),
+        files.len(),
+        codemod.name()
+    );
+}
+
+fn resolve_codemod(args: &CodemodArgs) -> Result<Box<dyn Codemod>, String> {
+    match args.codemod.as_deref().unwrap_or_default() {
+        "rename-component" => match (&args.from, &args.to) {
+            (Some(from), Some(to)) => Ok(Box::new(RenameComponent::new(from, to))),
+            _ => Err("rename-component requires --from and --to".to_string()),
+        },
+        "v-bind-shorthand" => Ok(Box::new(VBindShorthand)),
+        other => Err(format!(
+            "Unknown codemod '{}'. Expected one of: {}",
+            other,
+            BUILTIN_CODEMODS
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Apply a codemod to the template block, leaving the rest of the file as is
+fn transform_sfc(
+    source: &str,
+    path: &std::path::Path,
+    codemod: &dyn Codemod,
+) -> Result<Option<String>, String> {
+    let descriptor = parse_sfc(
+        source,
+        SfcParseOptions {
+            filename: path.to_string_lossy().to_string(),
+            ..Default::default()
+        },
+    )
+    .map_err(|e| e.message)?;
+    let Some(template) = descriptor.template else {
+        return Ok(None);
+    };
+
+    let output = codemod::apply(&template.content, &[codemod]).map_err(|e| e.to_string())?;
+    Ok(output.map(|content| {
+        let mut result = String::with_capacity(source.len());
+        result.push_str(&source[..template.loc.start]);
+        result.push_str(&content);
+        result.push_str(&source[template.loc.end..]);
+        result
+    }))
+}
+
+/// .vue files matching glob patterns or under directories
+fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = patterns
+        .iter()
+        .flat_map(|pattern| {
+            if pattern.contains('*') || pattern.contains('?') || pattern.contains('[') {
+                glob(pattern)
+                    .ok()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|r| r.ok())
+                    .filter(|p| {
+                        p.extension().is_some_and(|ext| ext == "vue")
+                            && !p.components().any(|c| c.as_os_str() == "node_modules")
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                Walk::new(pattern)
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.path().extension().is_some_and(|ext| ext == "vue"))
+                    .map(|e| e.path().to_path_buf())
+                    .collect::<Vec<_>>()
+            }
+        })
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn args(codemod: &str, from: Option<&str>, to: Option<&str>) -> CodemodArgs {
+        CodemodArgs {
+            codemod: Some(codemod.to_string()),
+            patterns: Vec::new(),
+            write: false,
+            list: false,
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_resolve_codemod() {
+        let codemod = resolve_codemod(&args("rename-component", Some("A"), Some("B"))).unwrap();
+        assert_eq!(codemod.name(), "rename-component");
+        let codemod = resolve_codemod(&args("v-bind-shorthand", None, None)).unwrap();
+        assert_eq!(codemod.name(), "v-bind-shorthand");
+
+        let err = resolve_codemod(&args("rename-component", Some("A"), None)).err();
+        assert_eq!(
+            err.as_deref(),
+            Some("rename-component requires --from and --to")
+        );
+        let err = resolve_codemod(&args("rename", None, None)).err().unwrap();
+        assert!(err.starts_with("Unknown codemod 'rename'. Expected one of: "));
+        assert!(err.contains("rename-component") && err.contains("v-bind-shorthand"));
+    }
+
+    #[test]
+    fn test_transform_sfc() {
+        let source = "<script setup lang=\"ts\">\n\
+            import MyButton from './MyButton.vue'\n\
+            const label = '<MyButton>'\n\
+            </script>\n\n\
+            <template>\n  <MyButton v-bind:title=\"label\">Go</MyButton>\n</template>\n\n\
+            <style>\nMyButton { color: red }\n</style>\n";
+        let path = Path::new("Card.vue");
+        let rename = RenameComponent::new("MyButton", "BaseButton");
+
+        let output = transform_sfc(source, path, &rename).unwrap();
+        assert_eq!(
+            output.as_deref(),
+            Some(
+                "<script setup lang=\"ts\">\n\
+                import MyButton from './MyButton.vue'\n\
+                const label = '<MyButton>'\n\
+                </script>\n\n\
+                <template>\n  <BaseButton v-bind:title=\"label\">Go</BaseButton>\n</template>\n\n\
+                <style>\nMyButton { color: red }\n</style>\n"
+            )
+        );
+
+        let output = transform_sfc(source, path, &VBindShorthand)
+            .unwrap()
+            .unwrap();
+        assert!(output.contains("<MyButton :title=\"label\">"));
+        assert!(output.starts_with("<script setup lang=\"ts\">\nimport MyButton"));
+
+        // Nothing to rewrite, or no template at all
+        let other = RenameComponent::new("Missing", "Found");
+        assert_eq!(transform_sfc(source, path, &other).unwrap(), None);
+        let script_only = "<script setup>\nconst a = 1\n</script>\n";
+        assert_eq!(transform_sfc(script_only, path, &rename).unwrap(), None);
+    }
+}
@@ -8,6 +8,8 @@ use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use vize_glyph::{format_sfc_with_allocator, Allocator, FormatOptions};
 
+use super::watch::Watcher;
+
 #[derive(Args)]
 pub struct FmtArgs {
     /// Glob pattern(s) to match .vue files
@@ -45,6 +47,10 @@ pub struct FmtArgs {
     /// Do not print semicolons at the ends of statements
     #[arg(long)]
     pub no_semi: bool,
+
+    /// Re-check (or re-format) changed files until interrupted
+    #[arg(long)]
+    pub watch: bool,
 }
 
 pub fn run(args: FmtArgs) {
@@ -60,72 +66,92 @@ pub fn run(args: FmtArgs) {
 
     eprintln!("Found {} .vue file(s)", files.len());
 
-    let has_errors = AtomicBool::new(false);
-    let files_changed = AtomicUsize::new(0);
-    let files_unchanged = AtomicUsize::new(0);
-    let files_errored = AtomicUsize::new(0);
-
-    // Process files in parallel, each thread gets its own allocator for maximum performance
-    files.par_iter().for_each(|path| {
-        // Create per-thread allocator with estimated capacity
-        let allocator = Allocator::with_capacity(64 * 1024); // 64KB initial capacity
-
-        match process_file(path, &options, &allocator, args.check, args.write) {
-            Ok(changed) => {
-                if changed {
-                    files_changed.fetch_add(1, Ordering::Relaxed);
-                    if args.check {
-                        has_errors.store(true, Ordering::Relaxed);
+    // Format a set of files and print a summary; true when any failed the check
+    let format_files = |files: &[PathBuf]| -> bool {
+        let has_errors = AtomicBool::new(false);
+        let files_changed = AtomicUsize::new(0);
+        let files_unchanged = AtomicUsize::new(0);
+        let files_errored = AtomicUsize::new(0);
+
+        // Process files in parallel, each thread gets its own allocator for maximum performance
+        files.par_iter().for_each(|path| {
+            // Create per-thread allocator with estimated capacity
+            let allocator = Allocator::with_capacity(64 * 1024); // 64KB initial capacity
+
+            match process_file(path, &options, &allocator, args.check, args.write) {
+                Ok(changed) => {
+                    if changed {
+                        files_changed.fetch_add(1, Ordering::Relaxed);
+                        if args.check {
+                            has_errors.store(true, Ordering::Relaxed);
+                        }
+                    } else {
+                        files_unchanged.fetch_add(1, Ordering::Relaxed);
                     }
-                } else {
-                    files_unchanged.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(err) => {
+                    eprintln!("Error formatting {}: {}", path.display(), err);
+                    files_errored.fetch_add(1, Ordering::Relaxed);
+                    has_errors.store(true, Ordering::Relaxed);
                 }
             }
-            Err(err) => {
-                eprintln!("Error formatting {}: {}", path.display(), err);
-                files_errored.fetch_add(1, Ordering::Relaxed);
-                has_errors.store(true, Ordering::Relaxed);
+        });
+
+        // Print summary
+        let changed = files_changed.load(Ordering::Relaxed);
+        let unchanged = files_unchanged.load(Ordering::Relaxed);
+        let errored = files_errored.load(Ordering::Relaxed);
+
+        eprintln!();
+        if args.check {
+            eprintln!("Checked {} file(s)", files.len());
+            if changed > 0 {
+                eprintln!("  {} file(s) would be reformatted", changed);
+            }
+            if unchanged > 0 {
+                eprintln!("  {} file(s) already formatted", unchanged);
+            }
+        } else if args.write {
+            eprintln!("Formatted {} file(s)", files.len());
+            if changed > 0 {
+                eprintln!("  {} file(s) reformatted", changed);
+            }
+            if unchanged > 0 {
+                eprintln!("  {} file(s) unchanged", unchanged);
+            }
+        } else {
+            eprintln!(
+                "Checked {} file(s) (use --write to apply changes)",
+                files.len()
+            );
+            if changed > 0 {
+                eprintln!("  {} file(s) would be reformatted", changed);
             }
         }
-    });
-
-    // Print summary
-    let changed = files_changed.load(Ordering::Relaxed);
-    let unchanged = files_unchanged.load(Ordering::Relaxed);
-    let errored = files_errored.load(Ordering::Relaxed);
-
-    eprintln!();
-    if args.check {
-        eprintln!("Checked {} file(s)", files.len());
-        if changed > 0 {
-            eprintln!("  {} file(s) would be reformatted", changed);
-        }
-        if unchanged > 0 {
-            eprintln!("  {} file(s) already formatted", unchanged);
-        }
-    } else if args.write {
-        eprintln!("Formatted {} file(s)", files.len());
-        if changed > 0 {
-            eprintln!("  {} file(s) reformatted", changed);
-        }
-        if unchanged > 0 {
-            eprintln!("  {} file(s) unchanged", unchanged);
-        }
-    } else {
-        eprintln!(
-            "Checked {} file(s) (use --write to apply changes)",
-            files.len()
-        );
-        if changed > 0 {
-            eprintln!("  {} file(s) would be reformatted", changed);
+
+        if errored > 0 {
+            eprintln!("  {} file(s) had errors", errored);
         }
-    }
 
-    if errored > 0 {
-        eprintln!("  {} file(s) had errors", errored);
+        has_errors.load(Ordering::Relaxed)
+    };
+
+    let has_errors = format_files(&files);
+    if args.watch {
+        let mut watcher = match Watcher::new(&args.patterns, || collect_files(&args.patterns)) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                eprintln!("\x1b[31mError:\x1b[0m failed to start watching: {}", e);
+                std::process::exit(1);
+            }
+        };
+        while let Some(changed) = watcher.next_change() {
+            format_files(&changed);
+        }
+        return;
     }
 
-    if has_errors.load(Ordering::Relaxed) {
+    if has_errors {
         std::process::exit(1);
     }
 }
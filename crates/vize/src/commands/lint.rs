@@ -8,7 +8,12 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
-use vize_patina::{format_results, format_summary, HelpLevel, Linter, OutputFormat};
+use vize_atelier_sfc::resolve::resolve_src_path;
+use vize_atelier_sfc::{parse_sfc, NativeFileSystem, SfcFileSystem, SfcParseOptions};
+use vize_patina::rules::script::{MaxProps, ScriptLinter};
+use vize_patina::{format_results, format_summary, HelpLevel, Linter, OutputFormat, RuleRegistry};
+
+use super::watch::Watcher;
 
 #[derive(Args)]
 pub struct LintArgs {
@@ -20,11 +25,11 @@ pub struct LintArgs {
     #[arg(long)]
     pub fix: bool,
 
-    /// Config file path (not yet implemented)
+    /// Config file path (defaults to ./vize.config.json)
     #[arg(short, long)]
     pub config: Option<PathBuf>,
 
-    /// Output format (text, json)
+    /// Output format (text, json, sarif, junit, checkstyle, github)
     #[arg(short, long, default_value = "text")]
     pub format: String,
 
@@ -39,39 +44,28 @@ pub struct LintArgs {
     /// Help display level: full (default), short, none
     #[arg(long, default_value = "full")]
     pub help_level: String,
+
+    /// Rule preset: recommended (default), essential, all, nuxt, migration
+    /// (recommended plus Vue 2 → Vue 3 migration rules)
+    #[arg(long, default_value = "recommended")]
+    pub preset: String,
+
+    /// Re-lint changed files and their dependents until interrupted
+    #[arg(short, long)]
+    pub watch: bool,
 }
 
 pub fn run(args: LintArgs) {
-    let start = Instant::now();
+    let Some(format) = OutputFormat::parse(&args.format) else {
+        eprintln!(
+            "Unknown output format '{}'. Expected one of: {}",
+            args.format,
+            OutputFormat::NAMES.join(", ")
+        );
+        std::process::exit(1);
+    };
 
-    // Collect .vue files using glob patterns or directory walking
-    let files: Vec<PathBuf> = args
-        .patterns
-        .iter()
-        .flat_map(|pattern| {
-            // Check if pattern contains glob characters
-            if pattern.contains('*') || pattern.contains('?') || pattern.contains('[') {
-                // Use glob for pattern matching
-                glob(pattern)
-                    .ok()
-                    .into_iter()
-                    .flatten()
-                    .filter_map(|r| r.ok())
-                    .filter(|p| {
-                        p.extension().is_some_and(|ext| ext == "vue")
-                            && !p.components().any(|c| c.as_os_str() == "node_modules")
-                    })
-                    .collect::<Vec<_>>()
-            } else {
-                // Use directory walking for paths (respects .gitignore)
-                Walk::new(pattern)
-                    .filter_map(|e| e.ok())
-                    .filter(|e| e.path().extension().is_some_and(|ext| ext == "vue"))
-                    .map(|e| e.path().to_path_buf())
-                    .collect::<Vec<_>>()
-            }
-        })
-        .collect();
+    let files = collect_files(&args.patterns);
 
     if files.is_empty() {
         eprintln!("No .vue files found matching patterns: {:?}", args.patterns);
@@ -83,63 +77,152 @@ pub fn run(args: LintArgs) {
         "short" => HelpLevel::Short,
         _ => HelpLevel::Full,
     };
-    let linter = Linter::new().with_help_level(help_level);
-    let error_count = AtomicUsize::new(0);
-    let warning_count = AtomicUsize::new(0);
-
-    // Lint all files in parallel and collect results
-    let results: Vec<_> = files
-        .par_iter()
-        .filter_map(|path| {
-            let source = match fs::read_to_string(path) {
-                Ok(s) => s,
-                Err(e) => {
-                    eprintln!("Failed to read {}: {}", path.display(), e);
-                    return None;
-                }
-            };
+    let registry = match args.preset.as_str() {
+        "essential" => RuleRegistry::with_essential(),
+        "all" => RuleRegistry::with_all(),
+        "nuxt" => RuleRegistry::with_nuxt(),
+        "migration" => RuleRegistry::with_migration(),
+        _ => RuleRegistry::with_recommended(),
+    };
+    let config = match &args.config {
+        Some(path) if !path.is_file() => {
+            eprintln!(
+                "\x1b[31mError:\x1b[0m config file not found: {}",
+                path.display()
+            );
+            std::process::exit(1);
+        }
+        Some(path) => crate::config::load_config_file(path),
+        None => crate::config::load_config(None),
+    };
+    let thresholds = config.lint.thresholds;
+    let linter = match Linter::with_registry(registry)
+        .with_help_level(help_level)
+        .with_thresholds(&thresholds)
+        .with_restricted_syntax(config.lint.restricted_syntax)
+    {
+        Ok(linter) => linter,
+        Err(e) => {
+            eprintln!("\x1b[31mError:\x1b[0m lint.restrictedSyntax: {}", e);
+            std::process::exit(1);
+        }
+    };
+    // `script/max-props` needs the script setup block, which `lint_sfc` skips
+    let script_linter = thresholds.max_props.map(|max| {
+        let mut script_linter = ScriptLinter::new();
+        script_linter.add_rule(Box::new(MaxProps::new(max)));
+        script_linter
+    });
 
-            let filename = path.to_string_lossy().to_string();
-            let result = linter.lint_sfc(&source, &filename);
+    // Lint a set of files and print their results and a summary
+    let lint_files = |files: &[PathBuf]| -> (usize, usize) {
+        let start = Instant::now();
+        let error_count = AtomicUsize::new(0);
+        let warning_count = AtomicUsize::new(0);
 
-            error_count.fetch_add(result.error_count, Ordering::Relaxed);
-            warning_count.fetch_add(result.warning_count, Ordering::Relaxed);
+        // Lint all files in parallel and collect results. Templates loaded
+        // through `<template src>` are reported as files of their own.
+        let results: Vec<_> = files
+            .par_iter()
+            .flat_map_iter(|path| {
+                let source = match fs::read_to_string(path) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        eprintln!("Failed to read {}: {}", path.display(), e);
+                        return Vec::new();
+                    }
+                };
 
-            Some((filename, source, result))
-        })
-        .collect();
+                let filename = path.to_string_lossy().to_string();
+                let mut result = linter.lint_sfc(&source, &filename);
+                let descriptor = parse_sfc(
+                    &source,
+                    SfcParseOptions {
+                        filename: filename.clone(),
+                        ..Default::default()
+                    },
+                )
+                .ok();
+                if let Some(script_linter) = &script_linter {
+                    if let Some(block) = descriptor.as_ref().and_then(|d| d.script_setup.as_ref()) {
+                        let script_result = script_linter.lint(&block.content, block.loc.start);
+                        result.diagnostics.extend(script_result.diagnostics);
+                        result.error_count += script_result.error_count;
+                        result.warning_count += script_result.warning_count;
+                    }
+                }
 
-    let total_errors = error_count.load(Ordering::Relaxed);
-    let total_warnings = warning_count.load(Ordering::Relaxed);
+                let template_path = descriptor
+                    .as_ref()
+                    .and_then(|d| d.template.as_ref())
+                    .and_then(|t| t.src.as_deref())
+                    .map(|src| resolve_src_path(&filename, src));
+                drop(descriptor);
 
-    // Determine output format
-    let format = match args.format.as_str() {
-        "json" => OutputFormat::Json,
-        _ => OutputFormat::Text,
-    };
+                let mut results = vec![(filename, source, result)];
+                if let Some(template_path) = template_path {
+                    match NativeFileSystem.read_file(&template_path) {
+                        Ok(template) => {
+                            let result = linter.lint_template(&template, &template_path);
+                            results.push((template_path, template, result));
+                        }
+                        Err(e) => eprintln!("Failed to read {}: {}", template_path, e),
+                    }
+                }
+
+                for (_, _, result) in &results {
+                    error_count.fetch_add(result.error_count, Ordering::Relaxed);
+                    warning_count.fetch_add(result.warning_count, Ordering::Relaxed);
+                }
 
-    // Format and print results
-    if !args.quiet || total_errors > 0 || total_warnings > 0 {
-        let lint_results: Vec<_> = results.iter().map(|(_, _, r)| r).cloned().collect();
-        let sources: Vec<_> = results
-            .iter()
-            .map(|(f, s, _)| (f.clone(), s.clone()))
+                results
+            })
             .collect();
 
-        let output = format_results(&lint_results, &sources, format);
-        if !output.trim().is_empty() {
-            print!("{}", output);
+        let total_errors = error_count.load(Ordering::Relaxed);
+        let total_warnings = warning_count.load(Ordering::Relaxed);
+
+        // Format and print results. Machine-readable reports are always written,
+        // since CI consumers expect a document even when nothing was found.
+        if !args.quiet || format.is_machine_readable() || total_errors > 0 || total_warnings > 0 {
+            let lint_results: Vec<_> = results.iter().map(|(_, _, r)| r).cloned().collect();
+            let sources: Vec<_> = results
+                .iter()
+                .map(|(f, s, _)| (f.clone(), s.clone()))
+                .collect();
+
+            let output = format_results(&lint_results, &sources, format);
+            if !output.trim().is_empty() {
+                print!("{}", output);
+            }
         }
-    }
 
-    // Print summary
-    let elapsed = start.elapsed();
-    if format == OutputFormat::Text {
-        println!(
-            "\n{}",
-            format_summary(total_errors, total_warnings, files.len())
-        );
-        println!("Linted {} files in {:.4?}", files.len(), elapsed);
+        // Print summary
+        let elapsed = start.elapsed();
+        if format == OutputFormat::Text {
+            println!(
+                "\n{}",
+                format_summary(total_errors, total_warnings, files.len())
+            );
+            println!("Linted {} files in {:.4?}", files.len(), elapsed);
+        }
+
+        (total_errors, total_warnings)
+    };
+
+    let (total_errors, total_warnings) = lint_files(&files);
+    if args.watch {
+        let mut watcher = match Watcher::new(&args.patterns, || collect_files(&args.patterns)) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                eprintln!("\x1b[31mError:\x1b[0m failed to start watching: {}", e);
+                std::process::exit(1);
+            }
+        };
+        while let Some(changed) = watcher.next_change() {
+            lint_files(&changed);
+        }
+        return;
     }
 
     // Fix mode warning
@@ -159,3 +242,33 @@ pub fn run(args: LintArgs) {
         }
     }
 }
+
+/// Collect .vue files using glob patterns or directory walking
+fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
+    patterns
+        .iter()
+        .flat_map(|pattern| {
+            // Check if pattern contains glob characters
+            if pattern.contains('*') || pattern.contains('?') || pattern.contains('[') {
+                // Use glob for pattern matching
+                glob(pattern)
+                    .ok()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|r| r.ok())
+                    .filter(|p| {
+                        p.extension().is_some_and(|ext| ext == "vue")
+                            && !p.components().any(|c| c.as_os_str() == "node_modules")
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                // Use directory walking for paths (respects .gitignore)
+                Walk::new(pattern)
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.path().extension().is_some_and(|ext| ext == "vue"))
+                    .map(|e| e.path().to_path_buf())
+                    .collect::<Vec<_>>()
+            }
+        })
+        .collect()
+}
@@ -1,9 +1,13 @@
 pub mod build;
+pub mod cache;
 pub mod check;
 #[cfg(unix)]
 pub mod check_server;
+pub mod codemod;
 pub mod fmt;
 pub mod ide;
 pub mod lint;
 pub mod lsp;
 pub mod musea;
+pub mod stats;
+pub mod watch;
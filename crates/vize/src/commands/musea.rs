@@ -1,8 +1,16 @@
 //! Musea command - Component gallery server
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+use vize_musea::audit;
+use vize_musea::autogen::{self, AutogenConfig};
+use vize_musea::docs::{generate_catalog, CatalogEntry, DocOptions};
+use vize_musea::gallery::{self, GalleryOptions, VueRuntime};
+use vize_musea::types::ArtParseOptions;
+use vize_musea::vrt::{self, DiffStyle, VrtStatus};
+use vize_musea::{parse_art, Bump};
+use vize_patina::{OutputFormat, Severity};
 
 #[derive(Args)]
 pub struct MuseaArgs {
@@ -17,6 +25,18 @@ pub enum MuseaCommand {
 
     /// Create a new story project
     New(NewArgs),
+
+    /// Generate an `.art.vue` file from a component's props
+    Generate(GenerateArgs),
+
+    /// Visual regression testing
+    Vrt(VrtArgs),
+
+    /// Export the gallery as a static site
+    Build(BuildArgs),
+
+    /// Check variants against the accessibility rules
+    Audit(AuditArgs),
 }
 
 #[derive(Args, Default)]
@@ -48,10 +68,133 @@ pub struct NewArgs {
     pub path: Option<PathBuf>,
 }
 
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Component to generate variants for
+    pub component: PathBuf,
+
+    /// Output art file (defaults to `<Component>.art.vue` next to the component)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Merge variants for new props into an existing art file
+    #[arg(short, long)]
+    pub update: bool,
+
+    /// Maximum number of variants to generate
+    #[arg(long, default_value = "20")]
+    pub max_variants: usize,
+}
+
+#[derive(Args)]
+pub struct BuildArgs {
+    /// Glob pattern(s) to match art files
+    #[arg(default_value = "./**/*.art.vue")]
+    pub patterns: Vec<String>,
+
+    /// Output directory
+    #[arg(short, long, default_value = "./musea-dist")]
+    pub out_dir: PathBuf,
+
+    /// Gallery title
+    #[arg(long, default_value = "Musea")]
+    pub title: String,
+
+    /// Vue browser build to copy (file) or reference (URL); defaults to
+    /// `vue/dist/vue.esm-browser.prod.js` from node_modules
+    #[arg(long)]
+    pub vue: Option<String>,
+}
+
+#[derive(Args)]
+pub struct AuditArgs {
+    /// Glob pattern(s) to match art files
+    #[arg(default_value = "./**/*.art.vue")]
+    pub patterns: Vec<String>,
+
+    /// Output format (text, json, sarif, junit, checkstyle, github)
+    #[arg(short, long, default_value = "text")]
+    pub format: String,
+
+    /// Write a component catalog with per-component violation counts
+    #[arg(long)]
+    pub catalog: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct VrtArgs {
+    #[command(subcommand)]
+    pub command: VrtCommand,
+}
+
+#[derive(Subcommand)]
+pub enum VrtCommand {
+    /// Compare screenshots against baselines (no browser needed)
+    Compare(CompareArgs),
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DiffStyleArg {
+    /// Changes on top of the faded baseline
+    Overlay,
+    /// Baseline, overlay and actual next to each other
+    SideBySide,
+    /// Changes only, on a transparent background
+    DiffOnly,
+    /// Animated PNG flipping between baseline and actual
+    Animated,
+}
+
+impl From<DiffStyleArg> for DiffStyle {
+    fn from(style: DiffStyleArg) -> Self {
+        match style {
+            DiffStyleArg::Overlay => DiffStyle::Overlay,
+            DiffStyleArg::SideBySide => DiffStyle::SideBySide,
+            DiffStyleArg::DiffOnly => DiffStyle::DiffOnly,
+            DiffStyleArg::Animated => DiffStyle::Animated,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct CompareArgs {
+    /// Directory with baseline snapshots
+    pub baseline_dir: PathBuf,
+
+    /// Directory with the screenshots to check
+    pub actual_dir: PathBuf,
+
+    /// Directory for diff images (defaults to `<baseline-dir>/diff`)
+    #[arg(long)]
+    pub diff_dir: Option<PathBuf>,
+
+    /// Path of the JSON report (defaults to `<diff-dir>/report.json`)
+    #[arg(long)]
+    pub report: Option<PathBuf>,
+
+    /// Maximum allowed difference percentage (0-100)
+    #[arg(long)]
+    pub threshold: Option<f64>,
+
+    /// Maximum allowed number of different pixels
+    #[arg(long)]
+    pub max_pixels: Option<u32>,
+
+    /// Diff image style
+    #[arg(long, value_enum)]
+    pub diff_style: Option<DiffStyleArg>,
+}
+
 pub fn run(args: MuseaArgs) {
     match args.command {
         Some(MuseaCommand::Serve(serve_args)) => run_serve(serve_args),
         Some(MuseaCommand::New(new_args)) => run_new(new_args),
+        Some(MuseaCommand::Generate(generate_args)) => run_generate(generate_args),
+        Some(MuseaCommand::Vrt(vrt_args)) => match vrt_args.command {
+            VrtCommand::Compare(compare_args) => run_vrt_compare(compare_args),
+        },
+        Some(MuseaCommand::Build(build_args)) => run_build(build_args),
+        Some(MuseaCommand::Audit(audit_args)) => run_audit(audit_args),
         None => {
             // Default to serve
             run_serve(ServeArgs::default());
@@ -68,6 +211,380 @@ fn run_serve(args: ServeArgs) {
     vize_musea::serve();
 }
 
+fn run_generate(args: GenerateArgs) {
+    let source = match fs::read_to_string(&args.component) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("Error reading {}: {}", args.component.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    let filename = args.component.to_string_lossy();
+    let props = match autogen::props_from_sfc(&source, &filename) {
+        Ok(props) => props,
+        Err(e) => {
+            eprintln!("Error parsing {}: {}", args.component.display(), e.message);
+            std::process::exit(1);
+        }
+    };
+
+    let output_path = args
+        .output
+        .unwrap_or_else(|| args.component.with_extension("art.vue"));
+    let component_path = relative_import(&output_path, &args.component);
+    let config = AutogenConfig {
+        max_variants: args.max_variants,
+        ..Default::default()
+    };
+
+    let output = if output_path.exists() {
+        if !args.update {
+            eprintln!(
+                "Error: {} already exists (use --update to merge new props)",
+                output_path.display()
+            );
+            std::process::exit(1);
+        }
+        let existing = match fs::read_to_string(&output_path) {
+            Ok(existing) => existing,
+            Err(e) => {
+                eprintln!("Error reading {}: {}", output_path.display(), e);
+                std::process::exit(1);
+            }
+        };
+        match autogen::update_art_file(&existing, &component_path, &props, &config) {
+            Ok(output) if output.variants.is_empty() => {
+                eprintln!("{} is up to date", output_path.display());
+                return;
+            }
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Error parsing {}: {}", output_path.display(), e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        autogen::generate_art_file(&component_path, &props, &config)
+    };
+
+    if let Some(parent) = output_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        if let Err(e) = fs::create_dir_all(parent) {
+            eprintln!("Error creating {}: {}", parent.display(), e);
+            std::process::exit(1);
+        }
+    }
+
+    if let Err(e) = fs::write(&output_path, &output.art_file_content) {
+        eprintln!("Error writing {}: {}", output_path.display(), e);
+        std::process::exit(1);
+    }
+
+    eprintln!(
+        "vize musea generate: {} props, {} variants written to {}",
+        props.len(),
+        output.variants.len(),
+        output_path.display()
+    );
+    for variant in &output.variants {
+        eprintln!("  + {}", variant.name);
+    }
+}
+
+fn run_vrt_compare(args: CompareArgs) {
+    let mut config = crate::config::load_config(None).musea.vrt;
+    if let Some(threshold) = args.threshold {
+        config.threshold.percentage = threshold;
+    }
+    if let Some(max_pixels) = args.max_pixels {
+        config.threshold.pixels = Some(max_pixels);
+    }
+    if let Some(diff_style) = args.diff_style {
+        config.comparison.diff_style = diff_style.into();
+    }
+
+    let diff_dir = args
+        .diff_dir
+        .unwrap_or_else(|| args.baseline_dir.join("diff"));
+    let report = match vrt::compare_dirs(
+        &args.baseline_dir,
+        &args.actual_dir,
+        Some(&diff_dir),
+        &config,
+    ) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    for result in &report.results {
+        let label = match result.status {
+            VrtStatus::Passed => "\x1b[32mpass\x1b[0m",
+            VrtStatus::Failed => "\x1b[31mfail\x1b[0m",
+            VrtStatus::New => "\x1b[36mnew\x1b[0m ",
+            VrtStatus::Missing => "\x1b[33mmiss\x1b[0m",
+            VrtStatus::Error => "\x1b[31merr\x1b[0m ",
+        };
+        match (result.diff_percentage, &result.error) {
+            (_, Some(error)) => eprintln!("  {} {} ({})", label, result.snapshot, error),
+            (Some(percentage), _) if result.status == VrtStatus::Failed => eprintln!(
+                "  {} {} ({} px, {:.3}%{})",
+                label,
+                result.snapshot,
+                result.diff_pixels.unwrap_or_default(),
+                percentage,
+                if result.size_mismatch {
+                    ", size mismatch"
+                } else {
+                    ""
+                }
+            ),
+            _ => eprintln!("  {} {}", label, result.snapshot),
+        }
+    }
+
+    if args.report.is_some() || config.ci.json_report {
+        let report_path = args.report.unwrap_or_else(|| diff_dir.join("report.json"));
+        if let Some(parent) = report_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Err(e) = fs::write(&report_path, report.to_json()) {
+            eprintln!("Error writing {}: {}", report_path.display(), e);
+            std::process::exit(1);
+        }
+        eprintln!();
+        eprintln!("Report written to {}", report_path.display());
+    }
+
+    let summary = &report.summary;
+    eprintln!(
+        "{} snapshots: {} passed, {} failed, {} new, {} missing, {} errors",
+        summary.total, summary.passed, summary.failed, summary.new, summary.missing, summary.errors
+    );
+
+    if !report.passed(&config.ci) {
+        std::process::exit(1);
+    }
+}
+
+fn run_build(args: BuildArgs) {
+    let root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let art_files = collect_art_files(&args.patterns);
+    if art_files.is_empty() {
+        eprintln!("No art files found matching {:?}", args.patterns);
+        std::process::exit(1);
+    }
+
+    let vue = match args.vue {
+        Some(vue) if vue.contains("://") => VueRuntime::Url(vue),
+        Some(vue) => VueRuntime::File(PathBuf::from(vue)),
+        None => match find_vue_runtime(&root) {
+            Some(path) => VueRuntime::File(path),
+            None => {
+                eprintln!("Error: Vue browser build not found in node_modules");
+                eprintln!("  Install `vue` or pass --vue <path-or-url>");
+                std::process::exit(1);
+            }
+        },
+    };
+
+    let options = GalleryOptions {
+        root,
+        out_dir: args.out_dir,
+        title: args.title,
+        vue,
+    };
+    let build = match gallery::build_gallery(&art_files, &options) {
+        Ok(build) => build,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    for warning in &build.warnings {
+        eprintln!("  \x1b[33mwarning\x1b[0m {}", warning);
+    }
+    let variant_count: usize = build
+        .manifest
+        .components
+        .iter()
+        .map(|c| c.variants.len())
+        .sum();
+    eprintln!(
+        "vize musea build: {} components, {} variants written to {}",
+        build.manifest.components.len(),
+        variant_count,
+        options.out_dir.display()
+    );
+}
+
+fn run_audit(args: AuditArgs) {
+    let Some(format) = OutputFormat::parse(&args.format) else {
+        eprintln!(
+            "Unknown output format '{}'. Expected one of: {}",
+            args.format,
+            OutputFormat::NAMES.join(", ")
+        );
+        std::process::exit(1);
+    };
+
+    let art_files = collect_art_files(&args.patterns);
+    if art_files.is_empty() {
+        eprintln!("No art files found matching {:?}", args.patterns);
+        std::process::exit(1);
+    }
+
+    let linter = audit::a11y_linter();
+    let mut results = Vec::with_capacity(art_files.len());
+    let mut catalog = Vec::with_capacity(art_files.len());
+
+    for art_file in &art_files {
+        let source = match fs::read_to_string(art_file) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("Error reading {}: {}", art_file.display(), e);
+                std::process::exit(1);
+            }
+        };
+        let filename = art_file.display().to_string();
+        let allocator = Bump::new();
+        let art = match parse_art(
+            &allocator,
+            &source,
+            ArtParseOptions {
+                filename: filename.clone(),
+            },
+        ) {
+            Ok(art) => art,
+            Err(e) => {
+                eprintln!("Error parsing {}: {}", filename, e);
+                std::process::exit(1);
+            }
+        };
+
+        let component = art.metadata.component.and_then(|component| {
+            let path = art_file.parent().unwrap_or(Path::new("")).join(component);
+            let name = Path::new(component).file_stem()?.to_string_lossy();
+            let source = fs::read_to_string(&path).ok()?;
+            match audit::AuditComponent::from_sfc(&source, &path.display().to_string(), &name) {
+                Ok(component) => Some(component),
+                Err(e) => {
+                    eprintln!("  \x1b[33mwarning\x1b[0m {}: {}", path.display(), e.message);
+                    None
+                }
+            }
+        });
+
+        let result = audit::audit_art(&art, component.as_ref(), &linter);
+        let mut entry = CatalogEntry::from_descriptor(&art, "");
+        entry.a11y_violations = Some(result.violation_count());
+        catalog.push(entry);
+        results.push(result);
+    }
+
+    let report = audit::AuditReport::new(results);
+
+    if let Some(path) = &args.catalog {
+        let mut markdown = generate_catalog(&catalog, &DocOptions::default()).markdown;
+        markdown.push('\n');
+        markdown.push_str(&report.to_markdown());
+        if let Err(e) = fs::write(path, markdown) {
+            eprintln!("Error writing {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    }
+
+    if format == OutputFormat::Json {
+        println!("{}", report.to_json());
+    } else if let Some(output) =
+        format.render_reports("vize-musea-audit", &report.to_report_files())
+    {
+        println!("{}", output);
+    } else {
+        for art in &report.results {
+            for variant in art.variants.iter().filter(|v| !v.violations.is_empty()) {
+                eprintln!(
+                    "{}:{} {} / {}",
+                    art.path, variant.line, art.title, variant.variant
+                );
+                for violation in &variant.violations {
+                    let label = match violation.severity {
+                        Severity::Error => "\x1b[31merror\x1b[0m",
+                        Severity::Warning => "\x1b[33mwarning\x1b[0m",
+                    };
+                    eprintln!("  {} {} ({})", label, violation.message, violation.rule);
+                    if !violation.snippet.is_empty() {
+                        eprintln!("    {}", violation.snippet);
+                    }
+                }
+            }
+        }
+        let summary = &report.summary;
+        eprintln!(
+            "vize musea audit: {} of {} variants pass, {} errors, {} warnings",
+            summary.clean_variants, summary.variants, summary.errors, summary.warnings
+        );
+    }
+
+    if report.has_errors() {
+        std::process::exit(1);
+    }
+}
+
+/// Art files matching the patterns, in a stable order.
+fn collect_art_files(patterns: &[String]) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = patterns
+        .iter()
+        .filter_map(|pattern| glob::glob(pattern).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|path| {
+            path.is_file()
+                && path.to_string_lossy().ends_with(".art.vue")
+                && !path.components().any(|c| c.as_os_str() == "node_modules")
+        })
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Locate Vue's browser ESM build in `node_modules` of `dir` or its ancestors.
+fn find_vue_runtime(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join("node_modules/vue/dist/vue.esm-browser.prod.js"))
+        .find(|path| path.is_file())
+}
+
+/// Import specifier for the component relative to the art file's directory.
+fn relative_import(art_file: &Path, component: &Path) -> String {
+    let from: Vec<_> = art_file
+        .parent()
+        .unwrap_or(Path::new(""))
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .collect();
+    let to: Vec<_> = component
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .collect();
+
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    if parts.is_empty() {
+        parts.push(".".to_string());
+    }
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+    parts.join("/")
+}
+
 fn run_new(args: NewArgs) {
     let target_dir = args.path.unwrap_or_else(|| PathBuf::from("."));
     let project_name = args.name.unwrap_or_else(|| {
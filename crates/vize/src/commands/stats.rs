@@ -0,0 +1,489 @@
+//! Stats command - Report template and script complexity metrics
+
+use clap::{Args, ValueEnum};
+use glob::glob;
+use ignore::Walk;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
+use vize_patina::rules::script::{MaxProps, ScriptLinter};
+use vize_patina::{
+    LintDiagnostic, Linter, RuleRegistry, ScriptMetrics, Severity, SfcMetrics, TemplateMetrics,
+    Thresholds,
+};
+
+#[derive(Args)]
+pub struct StatsArgs {
+    /// Glob pattern(s) to match .vue files
+    #[arg(default_value = "./**/*.vue")]
+    pub patterns: Vec<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "text")]
+    pub format: StatsFormat,
+
+    /// Metric to sort files by, largest first
+    #[arg(long, value_enum, default_value = "nodes")]
+    pub sort: SortKey,
+
+    /// Only show the N largest files
+    #[arg(long)]
+    pub top: Option<usize>,
+
+    /// Maximum template depth (overrides `lint.thresholds.maxTemplateDepth`)
+    #[arg(long)]
+    pub max_template_depth: Option<usize>,
+
+    /// Maximum number of props (overrides `lint.thresholds.maxProps`)
+    #[arg(long)]
+    pub max_props: Option<usize>,
+
+    /// Maximum number of template bindings (overrides `lint.thresholds.maxBindings`)
+    #[arg(long)]
+    pub max_bindings: Option<usize>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum StatsFormat {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SortKey {
+    Depth,
+    Nodes,
+    Bindings,
+    Unhoistable,
+    Props,
+    Loc,
+    Complexity,
+}
+
+impl SortKey {
+    fn value(self, metrics: &SfcMetrics) -> usize {
+        let template = metrics.template.as_ref();
+        let script = metrics.script.as_ref();
+        match self {
+            Self::Depth => template.map_or(0, |t| t.depth),
+            Self::Nodes => template.map_or(0, |t| t.node_count),
+            Self::Bindings => template.map_or(0, |t| t.dynamic_bindings),
+            Self::Unhoistable => template.map_or(0, |t| t.unhoistable_nodes),
+            Self::Props => script.map_or(0, |s| s.props),
+            Self::Loc => script.map_or(0, |s| s.loc),
+            Self::Complexity => script.and_then(|s| s.setup_complexity).unwrap_or(0),
+        }
+    }
+}
+
+/// Threshold rule violation with its position in the SFC
+#[derive(Serialize)]
+struct Violation {
+    rule: &'static str,
+    severity: Severity,
+    message: String,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Serialize)]
+struct FileStats {
+    #[serde(flatten)]
+    metrics: SfcMetrics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    violations: Vec<Violation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsSummary {
+    files: usize,
+    nodes: usize,
+    dynamic_bindings: usize,
+    unhoistable_nodes: usize,
+    script_loc: usize,
+    patch_flags: BTreeMap<String, usize>,
+    violations: usize,
+}
+
+#[derive(Serialize)]
+struct StatsReport {
+    summary: StatsSummary,
+    files: Vec<FileStats>,
+}
+
+pub fn run(args: StatsArgs) {
+    let files = collect_files(&args.patterns);
+    if files.is_empty() {
+        eprintln!("No .vue files found matching patterns: {:?}", args.patterns);
+        return;
+    }
+
+    let config = crate::config::load_config(None);
+    let thresholds = Thresholds {
+        max_template_depth: args
+            .max_template_depth
+            .or(config.lint.thresholds.max_template_depth),
+        max_props: args.max_props.or(config.lint.thresholds.max_props),
+        max_bindings: args.max_bindings.or(config.lint.thresholds.max_bindings),
+    };
+    let linter = Linter::with_registry(RuleRegistry::new()).with_thresholds(&thresholds);
+    let mut script_linter = ScriptLinter::new();
+    if let Some(max) = thresholds.max_props {
+        script_linter.add_rule(Box::new(MaxProps::new(max)));
+    }
+
+    let mut results: Vec<FileStats> = files
+        .par_iter()
+        .filter_map(|path| {
+            let source = match fs::read_to_string(path) {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Failed to read {}: {}", path.display(), e);
+                    return None;
+                }
+            };
+            let filename = path.to_string_lossy().to_string();
+            match file_stats(&source, filename, &linter, &script_linter) {
+                Ok(stats) => Some(stats),
+                Err(e) => {
+                    eprintln!("Failed to parse {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    results.sort_by(|a, b| {
+        args.sort
+            .value(&b.metrics)
+            .cmp(&args.sort.value(&a.metrics))
+            .then_with(|| a.metrics.filename.cmp(&b.metrics.filename))
+    });
+
+    let summary = summarize(&results);
+    if let Some(top) = args.top {
+        results.truncate(top);
+    }
+    let report = StatsReport {
+        summary,
+        files: results,
+    };
+
+    match args.format {
+        StatsFormat::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Failed to serialize stats: {}", e);
+                std::process::exit(1);
+            }
+        },
+        StatsFormat::Text => print_text(&report),
+    }
+
+    if report.summary.violations > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Totals over every file, computed before `--top` truncates the list
+fn summarize(files: &[FileStats]) -> StatsSummary {
+    let mut summary = StatsSummary {
+        files: files.len(),
+        nodes: 0,
+        dynamic_bindings: 0,
+        unhoistable_nodes: 0,
+        script_loc: 0,
+        patch_flags: BTreeMap::new(),
+        violations: 0,
+    };
+    for file in files {
+        if let Some(template) = &file.metrics.template {
+            summary.nodes += template.node_count;
+            summary.dynamic_bindings += template.dynamic_bindings;
+            summary.unhoistable_nodes += template.unhoistable_nodes;
+            for (flag, count) in &template.patch_flags {
+                *summary.patch_flags.entry(flag.clone()).or_default() += count;
+            }
+        }
+        if let Some(script) = &file.metrics.script {
+            summary.script_loc += script.loc;
+        }
+        summary.violations += file.violations.len();
+    }
+    summary
+}
+
+fn file_stats(
+    source: &str,
+    filename: String,
+    linter: &Linter,
+    script_linter: &ScriptLinter,
+) -> Result<FileStats, String> {
+    let descriptor = parse_sfc(
+        source,
+        SfcParseOptions {
+            filename: filename.clone(),
+            ..Default::default()
+        },
+    )
+    .map_err(|e| e.message)?;
+
+    let mut diagnostics: Vec<LintDiagnostic> = Vec::new();
+
+    let template = descriptor.template.as_ref().map(|template| {
+        let result = linter.lint_template(&template.content, &filename);
+        let offset = template.loc.start as u32;
+        diagnostics.extend(result.diagnostics.into_iter().map(|mut d| {
+            d.start += offset;
+            d.end += offset;
+            d
+        }));
+        TemplateMetrics::from_template(&template.content)
+    });
+
+    let script_block = descriptor
+        .script_setup
+        .as_ref()
+        .map(|block| (block, true))
+        .or_else(|| descriptor.script.as_ref().map(|block| (block, false)));
+    let script = script_block.map(|(block, is_setup)| {
+        if is_setup {
+            let result = script_linter.lint(&block.content, block.loc.start);
+            diagnostics.extend(result.diagnostics);
+        }
+        let is_ts = matches!(block.lang.as_deref(), Some("ts" | "tsx"));
+        ScriptMetrics::from_script(&block.content, is_setup, is_ts)
+    });
+
+    let violations = diagnostics
+        .into_iter()
+        .map(|d| {
+            let (line, column) = line_column(source, d.start as usize);
+            Violation {
+                rule: d.rule_name,
+                severity: d.severity,
+                message: d.message.to_string(),
+                line,
+                column,
+            }
+        })
+        .collect();
+
+    Ok(FileStats {
+        metrics: SfcMetrics {
+            filename,
+            template,
+            script,
+        },
+        violations,
+    })
+}
+
+/// 1-based line and column of a byte offset
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    (line, column)
+}
+
+fn print_text(report: &StatsReport) {
+    let dash = |value: Option<usize>| value.map_or("-".to_string(), |v| v.to_string());
+
+    println!(
+        "{:<48} {:>5} {:>6} {:>8} {:>11} {:>5} {:>5} {:>10}",
+        "File", "Depth", "Nodes", "Bindings", "Unhoistable", "Props", "LOC", "Complexity"
+    );
+    for file in &report.files {
+        let template = file.metrics.template.as_ref();
+        let script = file.metrics.script.as_ref();
+        println!(
+            "{:<48} {:>5} {:>6} {:>8} {:>11} {:>5} {:>5} {:>10}",
+            file.metrics.filename,
+            dash(template.map(|t| t.depth)),
+            dash(template.map(|t| t.node_count)),
+            dash(template.map(|t| t.dynamic_bindings)),
+            dash(template.map(|t| t.unhoistable_nodes)),
+            dash(script.map(|s| s.props)),
+            dash(script.map(|s| s.loc)),
+            dash(script.and_then(|s| s.setup_complexity)),
+        );
+    }
+
+    let summary = &report.summary;
+    if !summary.patch_flags.is_empty() {
+        let flags: Vec<String> = summary
+            .patch_flags
+            .iter()
+            .map(|(flag, count)| format!("{} {}", flag, count))
+            .collect();
+        println!("\nPatch flags (estimated): {}", flags.join(", "));
+    }
+
+    for file in report.files.iter().filter(|f| !f.violations.is_empty()) {
+        println!("\n{}", file.metrics.filename);
+        for violation in &file.violations {
+            println!(
+                "  {}:{}  \x1b[33mwarning\x1b[0m  {}  {}",
+                violation.line, violation.column, violation.message, violation.rule
+            );
+        }
+    }
+
+    println!(
+        "\n{} files, {} nodes, {} bindings, {} unhoistable, {} script lines, {} threshold violations",
+        summary.files,
+        summary.nodes,
+        summary.dynamic_bindings,
+        summary.unhoistable_nodes,
+        summary.script_loc,
+        summary.violations
+    );
+}
+
+/// .vue files matching glob patterns or under directories
+fn collect_files(patterns: &[String]) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = patterns
+        .iter()
+        .flat_map(|pattern| {
+            if pattern.contains('*') || pattern.contains('?') || pattern.contains('[') {
+                glob(pattern)
+                    .ok()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|r| r.ok())
+                    .filter(|p| {
+                        p.extension().is_some_and(|ext| ext == "vue")
+                            && !p.components().any(|c| c.as_os_str() == "node_modules")
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                Walk::new(pattern)
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.path().extension().is_some_and(|ext| ext == "vue"))
+                    .map(|e| e.path().to_path_buf())
+                    .collect::<Vec<_>>()
+            }
+        })
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CARD: &str = r#"<script setup lang="ts">
+import { ref, computed } from 'vue'
+const props = defineProps<{ title: string; size: number; open: boolean }>()
+const emit = defineEmits<{ change: [] }>()
+const count = ref(0)
+const double = computed(() => count.value * 2)
+</script>
+
+<template>
+  <div class="card">
+    <p v-if="count > 0">{{ double }}</p>
+    <ul>
+      <li v-for="n in count" :key="n" @click="emit('change')">{{ n }}</li>
+    </ul>
+  </div>
+</template>
+"#;
+
+    fn card_stats(thresholds: &Thresholds) -> FileStats {
+        let linter = Linter::with_registry(RuleRegistry::new()).with_thresholds(thresholds);
+        let mut script_linter = ScriptLinter::new();
+        if let Some(max) = thresholds.max_props {
+            script_linter.add_rule(Box::new(MaxProps::new(max)));
+        }
+        file_stats(CARD, "Card.vue".into(), &linter, &script_linter).unwrap()
+    }
+
+    #[test]
+    fn test_file_stats() {
+        let stats = card_stats(&Thresholds::default());
+        assert_eq!(stats.metrics.filename, "Card.vue");
+        assert!(stats.violations.is_empty());
+
+        let template = stats.metrics.template.as_ref().unwrap();
+        assert_eq!(template.depth, 3);
+        assert_eq!(template.node_count, 6);
+        assert_eq!(template.element_count, 4);
+        assert_eq!(template.dynamic_bindings, 4);
+        assert_eq!(template.hoistable_nodes, 0);
+        assert_eq!(template.unhoistable_nodes, 6);
+        assert_eq!(template.patch_flags.get("TEXT"), Some(&2));
+        assert_eq!(template.patch_flags.get("KEYED_FRAGMENT"), Some(&1));
+        assert_eq!(template.blocks, 3);
+        assert_eq!(template.cached_handlers, 1);
+        assert_eq!(template.conditionals, 1);
+        assert_eq!(template.loops, 1);
+
+        let script = stats.metrics.script.as_ref().unwrap();
+        assert_eq!(script.loc, 5);
+        assert_eq!(script.reactive_sources, 2);
+        assert_eq!(script.props, 3);
+        assert_eq!(script.emits, 1);
+        assert_eq!(script.models, 0);
+    }
+
+    #[test]
+    fn test_file_stats_violations() {
+        let stats = card_stats(&Thresholds {
+            max_template_depth: Some(2),
+            max_props: Some(2),
+            ..Default::default()
+        });
+        let found: Vec<_> = stats
+            .violations
+            .iter()
+            .map(|v| (v.rule, v.line, v.column))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("vue/max-template-depth", 13, 7),
+                ("script/max-props", 3, 15)
+            ]
+        );
+        assert_eq!(
+            stats.violations[1].message,
+            "Component declares 3 props (maximum 2)"
+        );
+    }
+
+    #[test]
+    fn test_summarize() {
+        let linter = Linter::with_registry(RuleRegistry::new());
+        let script_linter = ScriptLinter::new();
+        let plain = file_stats(
+            "<template><span>{{ msg }}</span></template>",
+            "Plain.vue".into(),
+            &linter,
+            &script_linter,
+        )
+        .unwrap();
+        assert!(plain.metrics.script.is_none());
+        let card = card_stats(&Thresholds {
+            max_props: Some(2),
+            ..Default::default()
+        });
+
+        let summary = summarize(&[card, plain]);
+        assert_eq!(summary.files, 2);
+        assert_eq!(summary.nodes, 8);
+        assert_eq!(summary.dynamic_bindings, 5);
+        assert_eq!(summary.unhoistable_nodes, 8);
+        assert_eq!(summary.script_loc, 5);
+        assert_eq!(summary.patch_flags.get("TEXT"), Some(&3));
+        assert_eq!(summary.patch_flags.get("KEYED_FRAGMENT"), Some(&1));
+        assert_eq!(summary.violations, 1);
+    }
+}
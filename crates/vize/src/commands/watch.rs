@@ -0,0 +1,654 @@
+//! Watch mode shared by `build`, `lint` and `fmt`
+//!
+//! [`Watcher`] waits for (debounced) file system changes and reports the
+//! SFCs to process again: the changed SFCs, and every SFC that depends on a
+//! changed file through imports, template component usage, `src` blocks or
+//! dependencies reported by the command (e.g. style imports). Dependents are
+//! found with a [`DependencyGraph`] rebuilt from per-file scans, so only the
+//! changed files are re-read.
+
+use ignore::WalkBuilder;
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::{Duration, SystemTime};
+use vize_atelier_sfc::resolve::resolve_src_path;
+use vize_atelier_sfc::{parse_sfc, SfcParseOptions};
+use vize_croquis::cross_file::{DependencyEdge, DependencyGraph, FileId, ModuleNode};
+
+/// How long the file system has to be quiet before a batch is processed
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Extensions tried for extensionless relative imports
+const SCRIPT_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "js", "jsx", "mjs", "vue"];
+
+/// What a module depends on, from scanning its source
+#[derive(Debug, Default)]
+struct Scan {
+    /// Imported files and `src` blocks
+    imports: Vec<PathBuf>,
+    /// Tags used in the template, in PascalCase
+    components: Vec<String>,
+}
+
+/// Watches the files matched by a command's patterns
+pub struct Watcher<C> {
+    /// Re-collects the command's SFCs when files are added or removed
+    collect: C,
+    /// Directories searched for SFCs
+    roots: Vec<PathBuf>,
+    /// Watched SFCs, by absolute path, with the path as collected
+    files: BTreeMap<PathBuf, PathBuf>,
+    scans: HashMap<PathBuf, Scan>,
+    /// Dependencies reported by the command, per SFC
+    reported: HashMap<PathBuf, Vec<PathBuf>>,
+    /// Last seen modification time and size of known files, to skip
+    /// events that did not change them (e.g. reads)
+    stamps: HashMap<PathBuf, Option<(SystemTime, u64)>>,
+    watched_dirs: HashSet<PathBuf>,
+    /// Paths modified in the last change
+    changed: Vec<PathBuf>,
+    debouncer: Debouncer<RecommendedWatcher>,
+    events: Receiver<DebounceEventResult>,
+}
+
+impl<C: Fn() -> Vec<PathBuf>> Watcher<C> {
+    /// Start watching the roots of `patterns`, with `collect` returning the
+    /// SFCs the patterns currently match.
+    pub fn new(patterns: &[String], collect: C) -> Result<Self, String> {
+        let (tx, events) = channel();
+        let debouncer = new_debouncer(DEBOUNCE, tx).map_err(|e| e.to_string())?;
+        let roots = patterns
+            .iter()
+            .map(|pattern| watch_key(Path::new(&super::build::parse_pattern(pattern).0)))
+            .collect();
+
+        let mut watcher = Self {
+            collect,
+            roots,
+            files: BTreeMap::new(),
+            scans: HashMap::new(),
+            reported: HashMap::new(),
+            stamps: HashMap::new(),
+            watched_dirs: HashSet::new(),
+            changed: Vec::new(),
+            debouncer,
+            events,
+        };
+        watcher.refresh_files();
+        let files: Vec<_> = watcher.files.keys().cloned().collect();
+        watcher.rescan(files);
+        watcher.sync_watches(true);
+        Ok(watcher)
+    }
+
+    /// The SFCs currently matched, as collected
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.files.values().cloned().collect()
+    }
+
+    /// Absolute paths of the files modified in the last change, including
+    /// dependencies that are not SFCs
+    pub fn changed(&self) -> &[PathBuf] {
+        &self.changed
+    }
+
+    /// Record files an SFC was processed with, in addition to what scanning
+    /// its source finds (e.g. files loaded by style preprocessors).
+    pub fn set_dependencies(&mut self, sfc: &Path, dependencies: &[String]) {
+        let dependencies: Vec<_> = dependencies
+            .iter()
+            .map(|dependency| watch_key(Path::new(dependency)))
+            .collect();
+        for dependency in &dependencies {
+            self.stamps.insert(dependency.clone(), stamp(dependency));
+        }
+        self.reported.insert(watch_key(sfc), dependencies);
+    }
+
+    /// Block until a change affects at least one SFC, and return the
+    /// affected SFCs as collected. Returns `None` once the watcher stops.
+    pub fn next_change(&mut self) -> Option<Vec<PathBuf>> {
+        self.sync_watches(false);
+        eprintln!("\n\x1b[90mWatching for changes... (Ctrl+C to stop)\x1b[0m");
+
+        loop {
+            let mut changed = BTreeSet::new();
+            let mut batch = Some(self.events.recv().ok()?);
+            while let Some(result) = batch {
+                match result {
+                    Ok(events) => changed.extend(events.into_iter().map(|e| watch_key(&e.path))),
+                    Err(e) => eprintln!("\x1b[33mWatch error:\x1b[0m {}", e),
+                }
+                batch = self.events.try_recv().ok();
+            }
+            // Reading a file produces events too: skip known files that
+            // were not modified
+            changed.retain(|path| {
+                let stamp = stamp(path);
+                match self.stamps.get_mut(path) {
+                    Some(old) if *old == stamp => false,
+                    Some(old) => {
+                        *old = stamp;
+                        true
+                    }
+                    None => true,
+                }
+            });
+
+            let affected = self.apply(&changed);
+            if affected.is_empty() {
+                continue;
+            }
+            self.changed = changed.into_iter().collect();
+
+            let names: Vec<_> = affected
+                .iter()
+                .take(5)
+                .map(|path| path.display().to_string())
+                .collect();
+            let more = affected.len().saturating_sub(names.len());
+            eprintln!(
+                "\n\x1b[36m↻ {} file(s) affected:\x1b[0m {}{}",
+                affected.len(),
+                names.join(", "),
+                if more > 0 {
+                    format!(" and {} more", more)
+                } else {
+                    String::new()
+                }
+            );
+            return Some(affected);
+        }
+    }
+
+    /// Update the project for a batch of changed paths and return the
+    /// affected SFCs
+    fn apply(&mut self, changed: &BTreeSet<PathBuf>) -> Vec<PathBuf> {
+        let before = self.graph();
+
+        let structure_changed = changed.iter().any(|path| {
+            let known = self.files.contains_key(path);
+            path.is_dir() || (known && !path.exists()) || (!known && is_vue(path))
+        });
+        let previous: Vec<_> = self.files.keys().cloned().collect();
+        if structure_changed {
+            self.refresh_files();
+            self.sync_watches(true);
+        }
+        let added = self.files.keys().filter(|path| !previous.contains(path));
+
+        let rescan: Vec<_> = changed
+            .iter()
+            .filter(|path| self.files.contains_key(*path) || self.scans.contains_key(*path))
+            .chain(added)
+            .cloned()
+            .collect();
+        self.rescan(rescan);
+        let after = self.graph();
+
+        let mut affected = BTreeSet::new();
+        for path in changed {
+            if self.files.contains_key(path) {
+                affected.insert(path.clone());
+            }
+            for (graph, paths) in [&before, &after] {
+                let Some(id) = paths.iter().position(|p| p == path) else {
+                    continue;
+                };
+                for dependent in graph.transitive_dependents(FileId::new(id as u32)) {
+                    let dependent = &paths[dependent.as_u32() as usize];
+                    if self.files.contains_key(dependent) {
+                        affected.insert(dependent.clone());
+                    }
+                }
+            }
+        }
+
+        affected
+            .into_iter()
+            .filter_map(|path| self.files.get(&path).cloned())
+            .collect()
+    }
+
+    /// Re-collect the SFCs, dropping what is known about removed ones
+    fn refresh_files(&mut self) {
+        self.files = (self.collect)()
+            .into_iter()
+            .map(|path| (watch_key(&path), path))
+            .collect();
+        let files = &self.files;
+        self.reported.retain(|path, _| files.contains_key(path));
+    }
+
+    /// Scan modules and, transitively, the script files they import
+    fn rescan(&mut self, mut pending: Vec<PathBuf>) {
+        let import = import_regex();
+        let tag = tag_regex();
+        let mut visited = HashSet::new();
+        while let Some(path) = pending.pop() {
+            if !visited.insert(path.clone()) {
+                continue;
+            }
+            let Ok(source) = fs::read_to_string(&path) else {
+                self.scans.remove(&path);
+                continue;
+            };
+            let scan = if is_vue(&path) {
+                scan_sfc(&path, &source, &import, &tag)
+            } else {
+                Scan {
+                    imports: scan_imports(&path, &source, &import),
+                    components: Vec::new(),
+                }
+            };
+            self.stamps.insert(path.clone(), stamp(&path));
+            for import in &scan.imports {
+                self.stamps.insert(import.clone(), stamp(import));
+            }
+            pending.extend(
+                scan.imports
+                    .iter()
+                    .filter(|dep| is_script(dep) && !self.scans.contains_key(*dep))
+                    .cloned(),
+            );
+            self.scans.insert(path, scan);
+        }
+    }
+
+    /// Dependency graph of the project. `FileId`s index the returned paths.
+    fn graph(&self) -> (DependencyGraph, Vec<PathBuf>) {
+        let mut graph = DependencyGraph::new();
+        let mut paths: Vec<PathBuf> = Vec::new();
+        let mut ids: HashMap<PathBuf, FileId> = HashMap::new();
+        let mut node = |graph: &mut DependencyGraph, path: &Path| -> FileId {
+            if let Some(&id) = ids.get(path) {
+                return id;
+            }
+            let id = FileId::new(paths.len() as u32);
+            let mut module = ModuleNode::new(id, path.to_string_lossy().as_ref());
+            if is_vue(path) {
+                module.component_name = path
+                    .file_stem()
+                    .map(|stem| pascal_case(&stem.to_string_lossy()).into());
+            }
+            graph.add_node(module);
+            paths.push(path.to_path_buf());
+            ids.insert(path.to_path_buf(), id);
+            id
+        };
+
+        for path in self.files.keys() {
+            node(&mut graph, path);
+        }
+        let mut usages = Vec::new();
+        for (path, scan) in &self.scans {
+            let from = node(&mut graph, path);
+            for import in &scan.imports {
+                let to = node(&mut graph, import);
+                graph.add_edge(from, to, DependencyEdge::Import);
+            }
+            usages.extend(scan.components.iter().map(|name| (from, name)));
+        }
+        for (path, dependencies) in &self.reported {
+            let from = node(&mut graph, path);
+            for dependency in dependencies {
+                let to = node(&mut graph, dependency);
+                graph.add_edge(from, to, DependencyEdge::Import);
+            }
+        }
+        for (from, name) in usages {
+            if let Some(to) = graph.find_by_component(name) {
+                graph.add_edge(from, to, DependencyEdge::ComponentUsage);
+            }
+        }
+
+        (graph, paths)
+    }
+
+    /// Watch the directories under the roots (with `walk`, respecting
+    /// .gitignore) and those holding dependencies outside of them
+    fn sync_watches(&mut self, walk: bool) {
+        let mut dirs: HashSet<PathBuf> = if walk {
+            self.roots
+                .iter()
+                .flat_map(|root| WalkBuilder::new(root).build().flatten())
+                .filter(|entry| entry.file_type().is_some_and(|t| t.is_dir()))
+                .map(|entry| watch_key(entry.path()))
+                .collect()
+        } else {
+            self.watched_dirs.clone()
+        };
+        let dependencies = self
+            .scans
+            .iter()
+            .flat_map(|(path, scan)| std::iter::once(path).chain(&scan.imports))
+            .chain(self.reported.values().flatten());
+        for dependency in dependencies {
+            if let Some(parent) = dependency.parent().filter(|dir| dir.is_dir()) {
+                dirs.insert(parent.to_path_buf());
+            }
+        }
+
+        let watcher = self.debouncer.watcher();
+        for dir in self.watched_dirs.difference(&dirs) {
+            let _ = watcher.unwatch(dir);
+        }
+        for dir in dirs.difference(&self.watched_dirs) {
+            if let Err(e) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+                eprintln!(
+                    "\x1b[33mWarning:\x1b[0m cannot watch {}: {}",
+                    dir.display(),
+                    e
+                );
+            }
+        }
+        self.watched_dirs = dirs;
+    }
+}
+
+/// Absolute, symlink-free path used to match events with files. Falls back
+/// to the parent directory for files that no longer exist.
+fn watch_key(path: &Path) -> PathBuf {
+    if let Ok(path) = fs::canonicalize(path) {
+        return path;
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) if !parent.as_os_str().is_empty() => {
+            watch_key(parent).join(name)
+        }
+        _ => std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+    }
+}
+
+/// Modification time and size of a file, `None` when it does not exist
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+fn is_vue(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "vue")
+}
+
+fn is_script(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        matches!(
+            ext.to_str(),
+            Some("ts" | "tsx" | "mts" | "js" | "jsx" | "mjs")
+        )
+    })
+}
+
+fn import_regex() -> regex_lite::Regex {
+    regex_lite::Regex::new(r#"(?:\bfrom|\bimport)\s*\(?\s*["'](\.\.?/[^"'\n]+)["']"#)
+        .expect("Invalid regex")
+}
+
+/// Opening tags of a template
+fn tag_regex() -> regex_lite::Regex {
+    regex_lite::Regex::new(r"<([A-Za-z][\w.-]*)").expect("Invalid regex")
+}
+
+/// Scan the blocks of an SFC
+fn scan_sfc(
+    path: &Path,
+    source: &str,
+    import: &regex_lite::Regex,
+    tag: &regex_lite::Regex,
+) -> Scan {
+    let filename = path.to_string_lossy().to_string();
+    let options = SfcParseOptions {
+        filename: filename.clone(),
+        ..Default::default()
+    };
+    let Ok(descriptor) = parse_sfc(source, options) else {
+        return Scan::default();
+    };
+
+    let mut scan = Scan::default();
+    let scripts = descriptor.script.iter().chain(&descriptor.script_setup);
+    for script in scripts {
+        scan.imports
+            .extend(scan_imports(path, &script.content, import));
+    }
+
+    let sources = descriptor
+        .template
+        .iter()
+        .filter_map(|t| t.src.as_deref())
+        .chain(descriptor.script.iter().filter_map(|s| s.src.as_deref()))
+        .chain(descriptor.styles.iter().filter_map(|s| s.src.as_deref()))
+        .chain(
+            descriptor
+                .custom_blocks
+                .iter()
+                .filter_map(|b| b.attrs.get("src").map(|src| &**src)),
+        );
+    for src in sources {
+        scan.imports
+            .push(watch_key(Path::new(&resolve_src_path(&filename, src))));
+    }
+
+    if let Some(template) = &descriptor.template {
+        let mut components: Vec<_> = tag
+            .captures_iter(&template.content)
+            .map(|caps| pascal_case(&caps[1]))
+            .collect();
+        components.sort();
+        components.dedup();
+        scan.components = components;
+    }
+
+    scan
+}
+
+/// Relative imports of a script, resolved to files
+fn scan_imports(path: &Path, source: &str, import: &regex_lite::Regex) -> Vec<PathBuf> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let mut imports: Vec<_> = import
+        .captures_iter(source)
+        .filter_map(|caps| resolve_import(&dir.join(&caps[1])))
+        .collect();
+    imports.sort();
+    imports.dedup();
+    imports
+}
+
+/// Resolve an import to a file, trying script extensions and index files
+fn resolve_import(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(watch_key(path));
+    }
+    let name = path.file_name()?.to_string_lossy();
+    SCRIPT_EXTENSIONS
+        .iter()
+        .map(|ext| path.with_file_name(format!("{}.{}", name, ext)))
+        .chain(
+            SCRIPT_EXTENSIONS
+                .iter()
+                .map(|ext| path.join(format!("index.{}", ext))),
+        )
+        .find(|candidate| candidate.is_file())
+        .map(|candidate| watch_key(&candidate))
+}
+
+/// `my-card` -> `MyCard`
+fn pascal_case(name: &str) -> String {
+    name.split(['-', '_'])
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("vize-watch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        watch_key(&root)
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(pascal_case("my-card"), "MyCard");
+        assert_eq!(pascal_case("my_card"), "MyCard");
+        assert_eq!(pascal_case("MyCard"), "MyCard");
+        assert_eq!(pascal_case("div"), "Div");
+    }
+
+    #[test]
+    fn test_resolve_import() {
+        let root = temp_project(
+            "resolve",
+            &[
+                ("utils.ts", ""),
+                ("lib/index.js", ""),
+                ("Card.vue", ""),
+                ("data.json", ""),
+            ],
+        );
+
+        assert_eq!(
+            resolve_import(&root.join("utils")),
+            Some(root.join("utils.ts"))
+        );
+        assert_eq!(
+            resolve_import(&root.join("lib")),
+            Some(root.join("lib/index.js"))
+        );
+        assert_eq!(
+            resolve_import(&root.join("Card.vue")),
+            Some(root.join("Card.vue"))
+        );
+        assert_eq!(
+            resolve_import(&root.join("data.json")),
+            Some(root.join("data.json"))
+        );
+        assert_eq!(resolve_import(&root.join("missing")), None);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_scan_sfc() {
+        let root = temp_project(
+            "scan",
+            &[("utils.ts", ""), ("style.css", ""), ("MyCard.vue", "")],
+        );
+        let source = r#"<script setup lang="ts">
+import { format } from './utils'
+import MyCard from './MyCard.vue'
+import { ref } from 'vue'
+</script>
+
+<template>
+  <div><my-card /><MyCard /><router-link /></div>
+</template>
+
+<style src="./style.css"></style>
+"#;
+
+        let scan = scan_sfc(&root.join("App.vue"), source, &import_regex(), &tag_regex());
+        assert_eq!(
+            scan.imports,
+            vec![
+                root.join("MyCard.vue"),
+                root.join("utils.ts"),
+                root.join("style.css"),
+            ]
+        );
+        assert_eq!(scan.components, vec!["Div", "MyCard", "RouterLink"]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_apply_affects_dependents() {
+        let root = temp_project(
+            "apply",
+            &[
+                (
+                    "App.vue",
+                    "<script setup>\nimport { format } from './format'\n</script>\n<template><my-card /></template>\n",
+                ),
+                ("MyCard.vue", "<template><div /></template>\n"),
+                ("Other.vue", "<template><span /></template>\n"),
+                ("format.ts", "export * from './shared'\n"),
+                ("shared.ts", "export const x = 1\n"),
+            ],
+        );
+        let dir = root.clone();
+        let mut watcher = Watcher::new(&[root.to_string_lossy().to_string()], move || {
+            fs::read_dir(&dir)
+                .unwrap()
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| is_vue(path))
+                .collect()
+        })
+        .unwrap();
+        let apply = |watcher: &mut Watcher<_>, paths: &[&str]| {
+            let changed = paths.iter().map(|path| root.join(path)).collect();
+            let mut affected = watcher.apply(&changed);
+            affected.sort();
+            affected
+        };
+
+        // Through a transitive script import
+        assert_eq!(
+            apply(&mut watcher, &["shared.ts"]),
+            vec![root.join("App.vue")]
+        );
+        // Through template component usage
+        assert_eq!(
+            apply(&mut watcher, &["MyCard.vue"]),
+            vec![root.join("App.vue"), root.join("MyCard.vue")]
+        );
+        assert_eq!(
+            apply(&mut watcher, &["Other.vue"]),
+            vec![root.join("Other.vue")]
+        );
+
+        // A dependency dropped by an edit no longer affects the SFC
+        fs::write(root.join("App.vue"), "<template><div /></template>\n").unwrap();
+        assert_eq!(
+            apply(&mut watcher, &["App.vue"]),
+            vec![root.join("App.vue")]
+        );
+        assert!(apply(&mut watcher, &["shared.ts"]).is_empty());
+
+        // Added SFCs are picked up along with their dependencies
+        fs::write(
+            root.join("New.vue"),
+            "<script setup>\nimport './shared'\n</script>\n",
+        )
+        .unwrap();
+        assert_eq!(
+            apply(&mut watcher, &["New.vue"]),
+            vec![root.join("New.vue")]
+        );
+        assert_eq!(
+            apply(&mut watcher, &["shared.ts"]),
+            vec![root.join("New.vue")]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
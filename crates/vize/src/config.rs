@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::path::Path;
+use vize_musea::vrt::VrtConfig;
+use vize_patina::{RestrictedSyntax, Thresholds};
 
 /// Top-level vize configuration.
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -16,6 +18,14 @@ pub struct VizeConfig {
     /// Type checking configuration.
     #[serde(default)]
     pub check: CheckConfig,
+
+    /// Lint configuration.
+    #[serde(default)]
+    pub lint: LintConfig,
+
+    /// Musea configuration.
+    #[serde(default)]
+    pub musea: MuseaConfig,
 }
 
 /// Configuration for the `check` command.
@@ -32,6 +42,32 @@ pub struct CheckConfig {
     pub globals: Option<Vec<String>>,
 }
 
+/// Configuration for the `lint` command.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintConfig {
+    /// Template syntax to report, as `vue/no-restricted-syntax` entries.
+    ///
+    /// Each entry has a `selector` over the template AST (e.g.
+    /// `"element[tag=router-link]"`), an optional `message` and an optional
+    /// `severity` (`"error"` by default).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub restricted_syntax: Vec<RestrictedSyntax>,
+
+    /// Limits of the threshold rules (`maxTemplateDepth`, `maxProps`,
+    /// `maxBindings`), also applied by `vize stats`.
+    #[serde(default, skip_serializing_if = "Thresholds::is_empty")]
+    pub thresholds: Thresholds,
+}
+
+/// Configuration for the `musea` command.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct MuseaConfig {
+    /// Visual regression testing options (`threshold`, `comparison`, `ci`, ...).
+    #[serde(default)]
+    pub vrt: VrtConfig,
+}
+
 /// Load `vize.config.json` from the given directory (or CWD if None).
 pub fn load_config(dir: Option<&Path>) -> VizeConfig {
     let base = dir
@@ -43,7 +79,13 @@ pub fn load_config(dir: Option<&Path>) -> VizeConfig {
         return VizeConfig::default();
     }
 
-    match std::fs::read_to_string(&config_path) {
+    load_config_file(&config_path)
+}
+
+/// Load a config file, falling back to the default config if it cannot be
+/// read or parsed.
+pub fn load_config_file(config_path: &Path) -> VizeConfig {
+    match std::fs::read_to_string(config_path) {
         Ok(content) => match serde_json::from_str(&content) {
             Ok(config) => config,
             Err(e) => {
@@ -95,6 +137,138 @@ pub const VIZE_CONFIG_SCHEMA: &str = r#"{
         }
       },
       "additionalProperties": false
+    },
+    "lint": {
+      "type": "object",
+      "description": "Lint configuration",
+      "properties": {
+        "restrictedSyntax": {
+          "type": "array",
+          "description": "Template syntax reported by vue/no-restricted-syntax, matched with template AST selectors.",
+          "items": {
+            "type": "object",
+            "properties": {
+              "selector": {
+                "type": "string",
+                "description": "Template AST selector, e.g. \"element[tag=img]:not([alt])\", \"directive[name=html]\" or \"element[tag=/^El/] > text\""
+              },
+              "message": {
+                "type": "string",
+                "description": "Message reported for each match"
+              },
+              "severity": {
+                "type": "string",
+                "enum": ["error", "warning", "warn"],
+                "default": "error"
+              }
+            },
+            "required": ["selector"],
+            "additionalProperties": false
+          },
+          "examples": [
+            [{ "selector": "element[tag=router-link]", "message": "Use <AppLink> instead of <router-link>" }]
+          ]
+        },
+        "thresholds": {
+          "type": "object",
+          "description": "Limits reported by the threshold rules and `vize stats`. Unset limits are not checked.",
+          "properties": {
+            "maxTemplateDepth": {
+              "type": "integer",
+              "minimum": 1,
+              "description": "vue/max-template-depth: maximum element nesting depth"
+            },
+            "maxProps": {
+              "type": "integer",
+              "minimum": 0,
+              "description": "script/max-props: maximum number of props declared with defineProps"
+            },
+            "maxBindings": {
+              "type": "integer",
+              "minimum": 0,
+              "description": "vue/max-bindings: maximum number of dynamic bindings in a template"
+            }
+          },
+          "additionalProperties": false
+        }
+      },
+      "additionalProperties": false
+    },
+    "musea": {
+      "type": "object",
+      "description": "Musea configuration",
+      "properties": {
+        "vrt": {
+          "type": "object",
+          "description": "Visual regression testing configuration",
+          "properties": {
+            "threshold": {
+              "type": "object",
+              "properties": {
+                "percentage": {
+                  "type": "number",
+                  "description": "Maximum allowed difference percentage (0-100)",
+                  "default": 0.1
+                },
+                "pixels": {
+                  "type": "integer",
+                  "description": "Maximum allowed different pixels"
+                },
+                "colorSensitivity": {
+                  "type": "number",
+                  "description": "YIQ color distance below which pixels match (0-1, lower = more strict)",
+                  "default": 0.1
+                }
+              }
+            },
+            "comparison": {
+              "type": "object",
+              "properties": {
+                "antiAliasing": {
+                  "type": "boolean",
+                  "description": "Ignore pixels that only differ by anti-aliasing",
+                  "default": true
+                },
+                "alpha": {
+                  "type": "boolean",
+                  "description": "Compare the alpha channel",
+                  "default": true
+                },
+                "diffStyle": {
+                  "type": "string",
+                  "enum": ["overlay", "sideBySide", "diffOnly", "animated"],
+                  "default": "overlay"
+                },
+                "diffColor": {
+                  "type": "object",
+                  "properties": {
+                    "r": { "type": "integer", "minimum": 0, "maximum": 255 },
+                    "g": { "type": "integer", "minimum": 0, "maximum": 255 },
+                    "b": { "type": "integer", "minimum": 0, "maximum": 255 }
+                  },
+                  "required": ["r", "g", "b"]
+                }
+              }
+            },
+            "ci": {
+              "type": "object",
+              "properties": {
+                "failOnDiff": {
+                  "type": "boolean",
+                  "description": "Exit with an error when snapshots differ",
+                  "default": true
+                },
+                "jsonReport": {
+                  "type": "boolean",
+                  "description": "Write a JSON report next to the diff images",
+                  "default": true
+                }
+              }
+            }
+          }
+        }
+      },
+      "additionalProperties": false
     }
   },
   "additionalProperties": false
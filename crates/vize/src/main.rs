@@ -32,6 +32,9 @@ enum Commands {
     #[command(visible_alias = "atelier")]
     Build(commands::build::BuildArgs),
 
+    /// Inspect or clear the compile cache
+    Cache(commands::cache::CacheArgs),
+
     /// Format Vue SFC files
     #[command(visible_alias = "glyph")]
     Fmt(commands::fmt::FmtArgs),
@@ -40,6 +43,12 @@ enum Commands {
     #[command(visible_alias = "patina")]
     Lint(commands::lint::LintArgs),
 
+    /// Apply template codemods to Vue SFC files
+    Codemod(commands::codemod::CodemodArgs),
+
+    /// Report template and script complexity metrics
+    Stats(commands::stats::StatsArgs),
+
     /// Type check Vue SFC files
     Check(commands::check::CheckArgs),
 
@@ -63,8 +72,11 @@ fn main() {
 
     match cli.command {
         Some(Commands::Build(args)) => commands::build::run(args),
+        Some(Commands::Cache(args)) => commands::cache::run(args),
         Some(Commands::Fmt(args)) => commands::fmt::run(args),
         Some(Commands::Lint(args)) => commands::lint::run(args),
+        Some(Commands::Codemod(args)) => commands::codemod::run(args),
+        Some(Commands::Stats(args)) => commands::stats::run(args),
         Some(Commands::Check(args)) => commands::check::run(args),
         #[cfg(unix)]
         Some(Commands::CheckServer(args)) => commands::check_server::run(args),
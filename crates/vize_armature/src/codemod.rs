@@ -0,0 +1,152 @@
+//! Template codemods.
+//!
+//! A [`Codemod`] mutates a parsed template; [`apply`] parses the source
+//! with whitespace preserved, runs the codemods and prints the result with
+//! the lossless [`print_template`], so untouched markup keeps its formatting.
+
+use vize_carton::general::{camelize, capitalize, hyphenate};
+use vize_carton::{Bump, String};
+use vize_relief::ast::*;
+use vize_relief::{CompilerError, ParserOptions, WhitespaceStrategy};
+
+use crate::parse_with_options;
+use crate::printer::print_template;
+
+/// A template transformation.
+pub trait Codemod: Send + Sync {
+    /// Codemod name, e.g. `rename-component`
+    fn name(&self) -> &'static str;
+
+    /// Transform the template, returning whether anything changed.
+    /// New nodes must be allocated in `allocator`.
+    fn transform<'a>(&self, allocator: &'a Bump, root: &mut RootNode<'a>) -> bool;
+}
+
+/// Names and descriptions of the built-in codemods.
+pub const BUILTIN_CODEMODS: &[(&str, &str)] = &[
+    (
+        "rename-component",
+        "Rename a component in templates, in both PascalCase and kebab-case usages",
+    ),
+    ("v-bind-shorthand", "Convert `v-bind:foo` to `:foo`"),
+];
+
+/// Apply codemods to template source.
+///
+/// Returns `Ok(None)` when no codemod changed anything. Templates with parse
+/// errors are not transformed.
+pub fn apply(
+    source: &str,
+    codemods: &[&dyn Codemod],
+) -> Result<Option<std::string::String>, CompilerError> {
+    let allocator = Bump::new();
+    let options = ParserOptions {
+        whitespace: WhitespaceStrategy::Preserve,
+        ..Default::default()
+    };
+    let (mut root, errors) = parse_with_options(&allocator, source, options);
+    if let Some(error) = errors.into_iter().next() {
+        return Err(error);
+    }
+
+    let mut changed = false;
+    for codemod in codemods {
+        changed |= codemod.transform(&allocator, &mut root);
+    }
+    Ok(changed
+        .then(|| print_template(&root))
+        .filter(|output| output != source))
+}
+
+/// Rename a component, e.g. `MyButton` to `BaseButton`.
+///
+/// `<MyButton>` becomes `<BaseButton>` and `<my-button>` becomes
+/// `<base-button>`.
+pub struct RenameComponent {
+    pascal: (String, String),
+    kebab: (String, String),
+}
+
+impl RenameComponent {
+    pub fn new(from: &str, to: &str) -> Self {
+        let pascal = |name: &str| capitalize(&camelize(name));
+        let kebab = |name: &str| hyphenate(&pascal(name)).to_ascii_lowercase();
+        Self {
+            pascal: (pascal(from), pascal(to)),
+            kebab: (kebab(from), kebab(to)),
+        }
+    }
+}
+
+impl Codemod for RenameComponent {
+    fn name(&self) -> &'static str {
+        "rename-component"
+    }
+
+    fn transform<'a>(&self, _allocator: &'a Bump, root: &mut RootNode<'a>) -> bool {
+        let mut changed = false;
+        root.for_each_element_mut(&mut |el| {
+            if el.tag == self.pascal.0 {
+                el.rename(self.pascal.1.clone());
+                changed = true;
+            } else if el.tag == self.kebab.0 {
+                el.rename(self.kebab.1.clone());
+                changed = true;
+            }
+        });
+        changed
+    }
+}
+
+/// Convert `v-bind:foo` to `:foo`. Object spreads (`v-bind="obj"`) have no
+/// shorthand and are kept.
+pub struct VBindShorthand;
+
+impl Codemod for VBindShorthand {
+    fn name(&self) -> &'static str {
+        "v-bind-shorthand"
+    }
+
+    fn transform<'a>(&self, _allocator: &'a Bump, root: &mut RootNode<'a>) -> bool {
+        let mut changed = false;
+        root.for_each_element_mut(&mut |el| {
+            for prop in el.props.iter_mut() {
+                if let PropNode::Directive(dir) = prop {
+                    if dir.name == "bind" {
+                        changed |= dir.use_shorthand();
+                    }
+                }
+            }
+        });
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_component() {
+        let source = "<div>\n  <MyButton size=\"sm\">Go</MyButton>\n  <my-button/>\n</div>";
+        let output = apply(source, &[&RenameComponent::new("MyButton", "BaseButton")]).unwrap();
+        assert_eq!(
+            output.as_deref(),
+            Some("<div>\n  <BaseButton size=\"sm\">Go</BaseButton>\n  <base-button/>\n</div>")
+        );
+    }
+
+    #[test]
+    fn test_v_bind_shorthand() {
+        let source = "<a v-bind:href = 'url'  v-bind=\"attrs\" v-bind:[key].camel=\"v\">x</a>";
+        let output = apply(source, &[&VBindShorthand]).unwrap();
+        assert_eq!(
+            output.as_deref(),
+            Some("<a :href = 'url'  v-bind=\"attrs\" :[key].camel=\"v\">x</a>")
+        );
+        assert_eq!(
+            apply("<a :href=\"url\">x</a>", &[&VBindShorthand]).unwrap(),
+            None
+        );
+    }
+}
@@ -15,11 +15,15 @@
 //! - State machine-based parsing
 //! - Full Vue directive and interpolation support
 //! - Error recovery and detailed error reporting
+//! - Lossless printing of modified templates and codemods
 
+pub mod codemod;
 pub mod parser;
+pub mod printer;
 pub mod tokenizer;
 
 pub use parser::*;
+pub use printer::print_template;
 pub use tokenizer::*;
 
 // Re-export from vize_relief for convenience
@@ -307,6 +307,15 @@ impl<'a> Parser<'a> {
             if self.stack[i].element.tag.eq_ignore_ascii_case(tag) {
                 found = true;
 
+                // Record the content span between the tags. Only positions are
+                // kept, copying the content of every element would be quadratic.
+                let inner_start = self.stack[i].element.loc.end.offset as usize;
+                self.stack[i].element.inner_loc = Some(SourceLocation::new(
+                    self.get_pos(inner_start),
+                    self.get_pos(start - 2),
+                    "",
+                ));
+
                 // Pop all elements up to and including the match
                 let mut elements: Vec<'a, ParserStackEntry<'a>> = Vec::new_in(self.allocator);
                 while self.stack.len() > i {
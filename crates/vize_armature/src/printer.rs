@@ -0,0 +1,443 @@
+//! Lossless template printer.
+//!
+//! Prints a parsed (and possibly modified) template back to source. Nodes
+//! whose fields still match the source they were parsed from are copied
+//! verbatim, keeping quotes, attribute layout and whitespace; only nodes
+//! changed since parsing, or created without a location, are printed from
+//! their fields.
+//!
+//! Parse with [`WhitespaceStrategy::Preserve`](vize_relief::WhitespaceStrategy)
+//! for a byte-identical round trip: condensed whitespace text nodes no
+//! longer match their source and are printed condensed.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use vize_relief::ast::*;
+
+/// Print a template parsed from `root.source`.
+pub fn print_template(root: &RootNode<'_>) -> std::string::String {
+    let source = root.source.as_str();
+    let mut printer = Printer {
+        source,
+        out: std::string::String::with_capacity(source.len()),
+    };
+    printer.children(&root.children, Some(0..source.len()));
+    printer.out
+}
+
+struct Printer<'s> {
+    source: &'s str,
+    out: std::string::String,
+}
+
+/// Source span of an attribute or directive.
+struct PropSpan {
+    /// End of the attribute name
+    name_end: usize,
+    /// Value between the quotes
+    value: Option<Range<usize>>,
+    /// End including the closing quote
+    end: usize,
+}
+
+impl Printer<'_> {
+    fn children(&mut self, children: &[TemplateChildNode<'_>], content: Option<Range<usize>>) {
+        let mut cursor = content.as_ref().map(|c| c.start);
+        for child in children {
+            if let Some(span) = self.node_span(child) {
+                if let Some(from) = cursor.filter(|&from| from <= span.start) {
+                    self.gap(from, span.start);
+                }
+                cursor = Some(span.end);
+            }
+            self.child(child);
+        }
+        if let (Some(from), Some(content)) = (cursor, content) {
+            if from <= content.end {
+                self.gap(from, content.end);
+            }
+        }
+    }
+
+    /// Whitespace between two original nodes. Whatever a removed node
+    /// occupied is dropped, keeping the indentation before the next one
+    /// unless a preserved whitespace node already provides it.
+    fn gap(&mut self, from: usize, to: usize) {
+        if self.out.ends_with(|c: char| c.is_ascii_whitespace()) {
+            return;
+        }
+        let text = &self.source[from..to];
+        self.out.push_str(&text[text.trim_end().len()..]);
+    }
+
+    fn child(&mut self, child: &TemplateChildNode<'_>) {
+        match child {
+            TemplateChildNode::Element(el) => self.element(el),
+            TemplateChildNode::Text(text) => match span(&text.loc) {
+                Some(span) if self.source[span.clone()] == text.content => {
+                    self.out.push_str(&self.source[span])
+                }
+                _ => self.out.push_str(&text.content),
+            },
+            TemplateChildNode::Comment(comment) => match span(&comment.loc) {
+                Some(span)
+                    if self.source.get(span.start + 4..span.end - 3)
+                        == Some(comment.content.as_str()) =>
+                {
+                    self.out.push_str(&self.source[span])
+                }
+                _ => {
+                    self.out.push_str("<!--");
+                    self.out.push_str(&comment.content);
+                    self.out.push_str("-->");
+                }
+            },
+            TemplateChildNode::Interpolation(interp) => self.interpolation(interp),
+            // Transformed nodes are only printed where they were parsed from
+            other => {
+                if let Some(span) = span(other.loc()) {
+                    self.out.push_str(&self.source[span]);
+                }
+            }
+        }
+    }
+
+    fn interpolation(&mut self, interp: &InterpolationNode<'_>) {
+        let (content, inner) = match &interp.content {
+            ExpressionNode::Simple(exp) => (exp.content.as_str(), span(&exp.loc)),
+            ExpressionNode::Compound(exp) => (exp.loc.source.as_str(), span(&exp.loc)),
+        };
+        match (span(&interp.loc), inner) {
+            (Some(outer), Some(inner)) if outer.start <= inner.start && inner.end <= outer.end => {
+                self.out.push_str(&self.source[outer.start..inner.start]);
+                self.out.push_str(content);
+                self.out.push_str(&self.source[inner.end..outer.end]);
+            }
+            _ => {
+                self.out.push_str("{{ ");
+                self.out.push_str(content);
+                self.out.push_str(" }}");
+            }
+        }
+    }
+
+    fn element(&mut self, el: &ElementNode<'_>) {
+        let Some(open) = span(&el.loc) else {
+            self.new_element(el);
+            return;
+        };
+        let source = self.source;
+        let open_text = &source[open.clone()];
+        let tag_len = open_text[1..]
+            .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+            .unwrap_or(open_text.len() - 1);
+        let original_tag = &open_text[1..1 + tag_len];
+
+        self.out.push('<');
+        self.out.push_str(&el.tag);
+        for prop in el.props.iter() {
+            match span(prop.loc()) {
+                Some(prop_loc) => {
+                    let start = prop_loc.start;
+                    let ws = source[open.start..start].trim_end().len() + open.start;
+                    self.out.push_str(&source[ws..start]);
+                    self.original_prop(prop, start);
+                }
+                None => {
+                    self.out.push(' ');
+                    self.prop(prop);
+                }
+            }
+        }
+
+        // Whitespace before `>` or `/>`
+        let body = open_text.strip_suffix('>').unwrap_or(open_text);
+        let body = body.strip_suffix('/').unwrap_or(body);
+        let tail = &open_text[body.trim_end().len()..];
+
+        match &el.inner_loc {
+            Some(inner) => {
+                self.out.push_str(tail);
+                let content = inner.start.offset as usize..inner.end.offset as usize;
+                self.children(&el.children, Some(content.clone()));
+                let close = &source[content.end..close_tag_end(source, content.end)];
+                if el.tag != original_tag && close.len() >= 2 + tag_len {
+                    self.out.push_str("</");
+                    self.out.push_str(&el.tag);
+                    self.out.push_str(&close[2 + tag_len..]);
+                } else {
+                    self.out.push_str(close);
+                }
+            }
+            // Self-closing or void element that gained children
+            None if !el.children.is_empty() => {
+                let tail = tail.trim_end_matches('>').trim_end_matches('/').trim_end();
+                self.out.push_str(tail);
+                self.out.push('>');
+                let end = self.element_end(el);
+                self.children(&el.children, Some(open.end..end));
+                self.close_tag(el);
+            }
+            None => self.out.push_str(tail),
+        }
+    }
+
+    fn new_element(&mut self, el: &ElementNode<'_>) {
+        self.out.push('<');
+        self.out.push_str(&el.tag);
+        for prop in el.props.iter() {
+            self.out.push(' ');
+            self.prop(prop);
+        }
+        if el.is_self_closing && el.children.is_empty() {
+            self.out.push_str(" />");
+            return;
+        }
+        self.out.push('>');
+        self.children(&el.children, None);
+        self.close_tag(el);
+    }
+
+    fn close_tag(&mut self, el: &ElementNode<'_>) {
+        self.out.push_str("</");
+        self.out.push_str(&el.tag);
+        self.out.push('>');
+    }
+
+    /// Print a parsed prop, keeping its original name and value text
+    /// (spacing around `=`, quotes) for the parts that did not change.
+    fn original_prop(&mut self, prop: &PropNode<'_>, start: usize) {
+        let span = prop_span(self.source, start);
+        let source_name = &self.source[start..span.name_end];
+        let source_value = span.value.clone().map(|v| &self.source[v]);
+        let (name, value) = prop_parts(prop);
+
+        if name == source_name {
+            self.out.push_str(source_name);
+        } else {
+            self.out.push_str(&name);
+        }
+        if value == source_value {
+            self.out.push_str(&self.source[span.name_end..span.end]);
+        } else if let Some(value) = value {
+            self.value(value);
+        }
+    }
+
+    fn prop(&mut self, prop: &PropNode<'_>) {
+        let (name, value) = prop_parts(prop);
+        self.out.push_str(&name);
+        if let Some(value) = value {
+            self.value(value);
+        }
+    }
+
+    fn value(&mut self, value: &str) {
+        self.out.push('=');
+        if value.contains('"') && !value.contains('\'') {
+            self.out.push('\'');
+            self.out.push_str(value);
+            self.out.push('\'');
+        } else {
+            self.out.push('"');
+            self.out.push_str(&value.replace('"', "&quot;"));
+            self.out.push('"');
+        }
+    }
+
+    fn node_span(&self, node: &TemplateChildNode<'_>) -> Option<Range<usize>> {
+        match node {
+            TemplateChildNode::Element(el) => {
+                span(&el.loc).map(|open| open.start..self.element_end(el))
+            }
+            other => span(other.loc()),
+        }
+    }
+
+    /// Original end of an element, including its closing tag.
+    fn element_end(&self, el: &ElementNode<'_>) -> usize {
+        if let Some(inner) = &el.inner_loc {
+            return close_tag_end(self.source, inner.end.offset as usize);
+        }
+        // Self-closing, void or unclosed
+        el.children
+            .iter()
+            .filter_map(|child| self.node_span(child))
+            .map(|span| span.end)
+            .fold(el.loc.end.offset as usize, usize::max)
+    }
+}
+
+/// Attribute name and value of a prop as they should be printed.
+fn prop_parts<'p>(prop: &'p PropNode<'_>) -> (Cow<'p, str>, Option<&'p str>) {
+    match prop {
+        PropNode::Attribute(attr) => (
+            Cow::Borrowed(attr.name.as_str()),
+            attr.value.as_ref().map(|v| v.content.as_str()),
+        ),
+        PropNode::Directive(dir) => (
+            Cow::Owned(dir.attribute_name().to_string()),
+            dir.exp_content(),
+        ),
+    }
+}
+
+/// Byte range of a location, `None` for nodes created without one.
+fn span(loc: &SourceLocation) -> Option<Range<usize>> {
+    let (start, end) = (loc.start.offset as usize, loc.end.offset as usize);
+    (start < end).then_some(start..end)
+}
+
+/// End of the closing tag starting at `start`.
+fn close_tag_end(source: &str, start: usize) -> usize {
+    source[start..]
+        .find('>')
+        .map_or(source.len(), |i| start + i + 1)
+}
+
+/// Scan an attribute starting at `start` the way the tokenizer reads it.
+/// Parsed locations stop before the closing quote, and boolean attributes
+/// may include trailing whitespace, so spans are recomputed from source.
+fn prop_span(source: &str, start: usize) -> PropSpan {
+    let bytes = source.as_bytes();
+    let skip_ws = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut name_end = start;
+    while name_end < bytes.len()
+        && !bytes[name_end].is_ascii_whitespace()
+        && !matches!(bytes[name_end], b'=' | b'>' | b'/')
+    {
+        name_end += 1;
+    }
+
+    let eq = skip_ws(name_end);
+    if bytes.get(eq) != Some(&b'=') {
+        return PropSpan {
+            name_end,
+            value: None,
+            end: name_end,
+        };
+    }
+
+    let value_start = skip_ws(eq + 1);
+    match bytes.get(value_start) {
+        Some(&quote @ (b'"' | b'\'')) => {
+            let inner = value_start + 1;
+            let close = bytes[inner..]
+                .iter()
+                .position(|&b| b == quote)
+                .map_or(bytes.len(), |i| inner + i);
+            PropSpan {
+                name_end,
+                value: Some(inner..close),
+                end: (close + 1).min(bytes.len()),
+            }
+        }
+        _ => {
+            let mut end = value_start;
+            while end < bytes.len() && !bytes[end].is_ascii_whitespace() && bytes[end] != b'>' {
+                end += 1;
+            }
+            PropSpan {
+                name_end,
+                value: Some(value_start..end),
+                end,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_with_options;
+    use vize_carton::Bump;
+    use vize_relief::{ParserOptions, WhitespaceStrategy};
+
+    fn round_trip(source: &str, f: impl for<'a> FnOnce(&'a Bump, &mut RootNode<'a>)) -> String {
+        let allocator = Bump::new();
+        let options = ParserOptions {
+            whitespace: WhitespaceStrategy::Preserve,
+            ..Default::default()
+        };
+        let (mut root, _) = parse_with_options(&allocator, source, options);
+        f(&allocator, &mut root);
+        print_template(&root)
+    }
+
+    #[test]
+    fn test_unchanged_is_identical() {
+        let source = r#"
+<div  id="app"
+      :class = 'cls' disabled data-x=raw >
+  <!-- note -->
+  <MyComp v-for="item in items" :key="item.id" @click.stop="go(item)" />
+  <p>{{  msg  }} &amp; text</p>
+  <br>
+  <input v-model="value"/>
+</div >
+"#;
+        assert_eq!(round_trip(source, |_, _| {}), source);
+    }
+
+    #[test]
+    fn test_rename_element() {
+        let source = "<Foo a=\"1\">\n  <Foo/>\n</Foo>";
+        let output = round_trip(source, |_, root| {
+            root.for_each_element_mut(&mut |el| el.rename("BarBaz"));
+        });
+        assert_eq!(output, "<BarBaz a=\"1\">\n  <BarBaz/>\n</BarBaz>");
+    }
+
+    #[test]
+    fn test_edit_props() {
+        let source = "<div\n  id='a'\n  v-bind:title=\"msg\"\n  hidden\n></div>";
+        let output = round_trip(source, |allocator, root| {
+            root.for_each_element_mut(&mut |el| {
+                el.set_attribute(allocator, "id", Some("b"));
+                el.directive_mut("bind", Some("title"))
+                    .unwrap()
+                    .use_shorthand();
+                el.remove_props(|p| matches!(p, PropNode::Attribute(a) if a.name == "hidden"));
+                el.add_directive(
+                    allocator,
+                    DirectiveNode::with_arg(allocator, "on", "click", Some("go")),
+                );
+            });
+        });
+        assert_eq!(
+            output,
+            "<div\n  id=\"b\"\n  :title=\"msg\" v-on:click=\"go\"\n></div>"
+        );
+    }
+
+    #[test]
+    fn test_remove_and_add_children() {
+        let source = "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>";
+        let output = round_trip(source, |allocator, root| {
+            let TemplateChildNode::Element(ul) = &mut root.children[0] else {
+                unreachable!()
+            };
+            // [ws, li, ws, li, ws]
+            ul.children.remove(1);
+            ul.children.remove(1);
+            let mut li = ElementNode::new(allocator, "li", SourceLocation::STUB);
+            li.children
+                .push(TemplateChildNode::Text(vize_carton::Box::new_in(
+                    TextNode::new("c", SourceLocation::STUB),
+                    allocator,
+                )));
+            ul.children
+                .push(TemplateChildNode::Element(vize_carton::Box::new_in(
+                    li, allocator,
+                )));
+        });
+        assert_eq!(output, "<ul>\n  <li>b</li>\n<li>c</li></ul>");
+    }
+}
@@ -0,0 +1,435 @@
+//! Persistent compilation cache.
+//!
+//! [`CompileCache`] stores [`SfcCompileResult`]s under
+//! `node_modules/.vize/cache`, keyed by a hash of the compiler version, the
+//! compile options and the source ([`CompileCache::key`]). An entry also
+//! records the files the compilation loaded (`src` blocks, style imports)
+//! with their content hash, and is only used while those are unchanged.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::{Deserialize, Serialize};
+use vize_carton::hash::content_hash;
+
+use crate::compile::compile_sfc;
+use crate::types::{SfcCompileOptions, SfcCompileResult, SfcDescriptor, SfcError};
+
+/// Cache format version. Bumped whenever the entry layout changes.
+const CACHE_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "+1");
+
+/// Directory of the entries inside the cache directory.
+const ENTRIES_DIR: &str = "sfc";
+
+/// A file loaded by a cached compilation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedDependency {
+    path: String,
+    content_hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    result: SfcCompileResult,
+    #[serde(default)]
+    dependencies: Vec<CachedDependency>,
+}
+
+/// A cached compilation whose dependencies are unchanged.
+#[derive(Debug, Clone)]
+pub struct CacheHit {
+    /// The stored compile result.
+    pub result: SfcCompileResult,
+    /// Files the compilation loaded.
+    pub dependencies: Vec<String>,
+}
+
+/// Size of the cache on disk.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CacheStats {
+    /// Number of stored compile results.
+    pub entries: usize,
+    /// Total size of the entries in bytes.
+    pub bytes: u64,
+}
+
+/// Content-addressed on-disk cache of SFC compile results.
+///
+/// Safe to share between threads; entries are written atomically.
+#[derive(Debug)]
+pub struct CompileCache {
+    dir: PathBuf,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl CompileCache {
+    /// Get the cache directory for a project.
+    pub fn dir_for(project_root: &Path) -> PathBuf {
+        project_root
+            .join("node_modules")
+            .join(".vize")
+            .join("cache")
+    }
+
+    /// Create a cache stored in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Open the cache of a project.
+    pub fn open(project_root: &Path) -> Self {
+        Self::new(Self::dir_for(project_root))
+    }
+
+    /// Find the root of the project containing `dir`: the nearest ancestor
+    /// with a `package.json` or `node_modules`, or `dir` itself.
+    pub fn project_root(dir: &Path) -> PathBuf {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        dir.ancestors()
+            .find(|d| d.join("package.json").is_file() || d.join("node_modules").is_dir())
+            .unwrap_or(&dir)
+            .to_path_buf()
+    }
+
+    /// Get the cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cache key of a compilation.
+    ///
+    /// `extra` holds anything else that affects the result, e.g. the
+    /// preprocessor options of a build.
+    pub fn key(source: &str, options: &SfcCompileOptions, extra: &[&str]) -> String {
+        let mut input = format!("{}\0{}\0", CACHE_VERSION, options_fingerprint(options));
+        for part in extra {
+            input.push_str(part);
+            input.push('\0');
+        }
+        input.push_str(source);
+        content_hash(&input)
+    }
+
+    /// Look up a compilation, ignoring entries whose dependencies changed.
+    pub fn get(&self, key: &str) -> Option<CacheHit> {
+        let hit = self.read(key);
+        let counter = if hit.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        hit
+    }
+
+    /// Store a compilation and the files it loaded.
+    ///
+    /// Nothing is stored when a dependency cannot be read, since the entry
+    /// could never be validated.
+    pub fn put(
+        &self,
+        key: &str,
+        result: &SfcCompileResult,
+        dependencies: &[String],
+    ) -> io::Result<()> {
+        let mut cached = Vec::with_capacity(dependencies.len());
+        for path in dependencies {
+            let Ok(content) = fs::read_to_string(path) else {
+                return Ok(());
+            };
+            cached.push(CachedDependency {
+                path: path.clone(),
+                content_hash: content_hash(&content),
+            });
+        }
+        let entry = CacheEntry {
+            result: result.clone(),
+            dependencies: cached,
+        };
+
+        let path = self.entry_path(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first so concurrent readers never see a
+        // partial entry
+        static NEXT_TMP: AtomicUsize = AtomicUsize::new(0);
+        let tmp = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            NEXT_TMP.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&tmp, serde_json::to_string(&entry)?)?;
+        fs::rename(&tmp, &path).inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        })
+    }
+
+    /// Compile a descriptor parsed from `source`, reusing a cached result.
+    ///
+    /// Descriptors with external (`src`) blocks or preprocessor styles are
+    /// compiled without the cache, since the files they load are not part of
+    /// the key. Use [`CompileCache::put`] with the loaded files for those.
+    pub fn compile(
+        &self,
+        source: &str,
+        descriptor: &SfcDescriptor<'_>,
+        options: SfcCompileOptions,
+    ) -> Result<SfcCompileResult, SfcError> {
+        if has_external_inputs(descriptor) {
+            return compile_sfc(descriptor, options);
+        }
+        let key = Self::key(source, &options, &[]);
+        if let Some(hit) = self.get(&key) {
+            return Ok(hit.result);
+        }
+        let result = compile_sfc(descriptor, options)?;
+        // A failed write only costs a recompile next time
+        let _ = self.put(&key, &result, &[]);
+        Ok(result)
+    }
+
+    /// Number of lookups answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of lookups that had to compile.
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Count the stored entries.
+    pub fn stats(&self) -> io::Result<CacheStats> {
+        let mut stats = CacheStats::default();
+        let entries = match fs::read_dir(self.dir.join(ENTRIES_DIR)) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(stats),
+            Err(e) => return Err(e),
+        };
+        for entry in entries {
+            let entry = entry?;
+            if entry.path().extension().is_some_and(|ext| ext == "json") {
+                stats.entries += 1;
+                stats.bytes += entry.metadata()?.len();
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Remove every entry, returning what was removed.
+    pub fn clean(&self) -> io::Result<CacheStats> {
+        let stats = self.stats()?;
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(stats),
+        }
+    }
+
+    fn read(&self, key: &str) -> Option<CacheHit> {
+        let content = fs::read_to_string(self.entry_path(key)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        let unchanged = entry.dependencies.iter().all(|dependency| {
+            fs::read_to_string(&dependency.path)
+                .is_ok_and(|content| content_hash(&content) == dependency.content_hash)
+        });
+        unchanged.then(|| CacheHit {
+            result: entry.result,
+            dependencies: entry
+                .dependencies
+                .into_iter()
+                .map(|dependency| dependency.path)
+                .collect(),
+        })
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(ENTRIES_DIR).join(format!("{}.json", key))
+    }
+}
+
+/// Everything in the options that affects the output.
+///
+/// Spelled out because the template parser options hold function pointers,
+/// whose `Debug` output changes between runs. Custom block transforms are
+/// identified by their tags only.
+fn options_fingerprint(options: &SfcCompileOptions) -> String {
+    let SfcCompileOptions {
+        parse,
+        script,
+        template,
+        style,
+        scope_id,
+        custom_element,
+        custom_blocks,
+    } = options;
+    let parser = parse
+        .template_parse_options
+        .as_ref()
+        .map(|p| (p.mode, p.whitespace, &p.delimiters, p.comments));
+    let compiler = template.compiler_options.as_ref().map(|c| {
+        (
+            serde_json::to_string(c).unwrap_or_default(),
+            format!("{:?}", c.binding_metadata),
+        )
+    });
+    format!(
+        "{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}",
+        (
+            &parse.filename,
+            parse.source_map,
+            parse.pad,
+            parse.ignore_empty
+        ),
+        parser,
+        script,
+        (
+            &template.id,
+            template.ssr,
+            &template.ssr_css_vars,
+            template.scoped,
+            template.is_prod,
+            template.is_ts
+        ),
+        compiler,
+        style,
+        scope_id,
+        custom_element,
+        custom_blocks.tags(),
+    )
+}
+
+/// Whether compiling a descriptor depends on files other than its source.
+fn has_external_inputs(descriptor: &SfcDescriptor<'_>) -> bool {
+    descriptor
+        .template
+        .as_ref()
+        .is_some_and(|t| t.src.is_some())
+        || descriptor.script.as_ref().is_some_and(|s| s.src.is_some())
+        || descriptor
+            .script_setup
+            .as_ref()
+            .is_some_and(|s| s.src.is_some())
+        || descriptor.styles.iter().any(|style| {
+            style.src.is_some()
+                || style.lang.as_deref().is_some_and(|lang| {
+                    matches!(lang, "scss" | "sass" | "less" | "styl" | "stylus")
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_sfc, SfcParseOptions};
+
+    fn temp_cache(name: &str) -> CompileCache {
+        let dir = std::env::temp_dir().join(format!("vize-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        CompileCache::new(dir)
+    }
+
+    #[test]
+    fn test_compile_cached() {
+        let cache = temp_cache("compile");
+        let source = "<template><div>{{ msg }}</div></template>";
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+
+        let first = cache
+            .compile(source, &descriptor, SfcCompileOptions::default())
+            .unwrap();
+        let second = cache
+            .compile(source, &descriptor, SfcCompileOptions::default())
+            .unwrap();
+        assert_eq!(first.code, second.code);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert_eq!(cache.stats().unwrap().entries, 1);
+
+        let stats = cache.clean().unwrap();
+        assert_eq!(stats.entries, 1);
+        assert!(stats.bytes > 0);
+        assert_eq!(cache.stats().unwrap(), CacheStats::default());
+    }
+
+    #[test]
+    fn test_compile_skips_external_inputs() {
+        let cache = temp_cache("external");
+        for source in [
+            "<template><div /></template><style src=\"./theme.css\"></style>",
+            "<template><div /></template><style lang=\"scss\">@use \"vars\";</style>",
+        ] {
+            let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+            cache
+                .compile(source, &descriptor, SfcCompileOptions::default())
+                .unwrap();
+        }
+        assert_eq!((cache.hits(), cache.misses()), (0, 0));
+        assert_eq!(cache.stats().unwrap(), CacheStats::default());
+    }
+
+    #[test]
+    fn test_project_root() {
+        let root = std::env::temp_dir().join(format!("vize-cache-root-{}", std::process::id()));
+        let nested = root.join("src").join("components");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join("package.json"), "{}").unwrap();
+
+        let root = root.canonicalize().unwrap();
+        assert_eq!(CompileCache::project_root(&nested), root);
+        assert_eq!(CompileCache::project_root(&root), root);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_key() {
+        let source = "<template><div /></template>";
+        let options = SfcCompileOptions::default();
+        let key = CompileCache::key(source, &options, &[]);
+        assert_eq!(key, CompileCache::key(source, &options, &[]));
+        assert_ne!(
+            key,
+            CompileCache::key("<template><p /></template>", &options, &[])
+        );
+        assert_ne!(
+            key,
+            CompileCache::key(source, &options, &["$primary: red;"])
+        );
+
+        let mut prod = options.clone();
+        prod.template.is_prod = true;
+        assert_ne!(key, CompileCache::key(source, &prod, &[]));
+    }
+
+    #[test]
+    fn test_dependency_invalidation() {
+        let cache = temp_cache("deps");
+        fs::create_dir_all(cache.dir()).unwrap();
+        let dependency = cache.dir().join("theme.css");
+        fs::write(&dependency, ".a { color: red }").unwrap();
+        let dependency = dependency.to_string_lossy().to_string();
+
+        let source = "<template><div /></template><style src=\"./theme.css\"></style>";
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        let result = compile_sfc(&descriptor, SfcCompileOptions::default()).unwrap();
+        cache
+            .put("entry", &result, std::slice::from_ref(&dependency))
+            .unwrap();
+
+        let hit = cache.get("entry").unwrap();
+        assert_eq!(hit.dependencies, vec![dependency.clone()]);
+
+        fs::write(&dependency, ".a { color: blue }").unwrap();
+        assert!(cache.get("entry").is_none());
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        cache.clean().unwrap();
+    }
+}
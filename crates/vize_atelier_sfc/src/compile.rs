@@ -20,17 +20,90 @@ pub use crate::compile_script::ScriptCompileResult;
 pub fn compile_sfc(
     descriptor: &SfcDescriptor,
     options: SfcCompileOptions,
+) -> Result<SfcCompileResult, SfcError> {
+    if descriptor.custom_blocks.is_empty() || options.custom_blocks.is_empty() {
+        return compile_component(descriptor, options);
+    }
+
+    let filename = options
+        .script
+        .id
+        .clone()
+        .unwrap_or_else(|| "anonymous.vue".to_string());
+    let scope_id = options
+        .scope_id
+        .clone()
+        .unwrap_or_else(|| generate_scope_id(&filename));
+    let is_prod = options.script.is_prod || options.template.is_prod;
+    let transforms = options.custom_blocks.clone();
+
+    let mut result = compile_component(descriptor, options)?;
+    crate::custom_block::append_custom_blocks(
+        descriptor,
+        &transforms,
+        crate::custom_block::CustomBlockContext {
+            filename: &filename,
+            id: &scope_id,
+            index: 0,
+            is_prod,
+        },
+        &mut result,
+    );
+    Ok(result)
+}
+
+/// Compile the template, script and style blocks
+fn compile_component(
+    descriptor: &SfcDescriptor,
+    options: SfcCompileOptions,
 ) -> Result<SfcCompileResult, SfcError> {
     let mut errors = Vec::new();
     let mut warnings = Vec::new();
     let mut code = String::new();
-    let mut css = None;
 
     let filename = options.script.id.as_deref().unwrap_or("anonymous.vue");
 
-    // Generate scope ID from filename
-    let scope_id = generate_scope_id(filename);
-    let has_scoped = descriptor.styles.iter().any(|s| s.scoped);
+    // External blocks compile as empty unless loaded beforehand
+    for (tag, src, loc) in crate::resolve::unresolved_src_blocks(descriptor) {
+        warnings.push(SfcError {
+            message: format!(
+                "<{}> src \"{}\" was not resolved and compiles as an empty block. \
+                 Load it with `resolve_src_blocks` before compiling.",
+                tag, src
+            ),
+            code: Some("UNRESOLVED_SRC".to_string()),
+            loc: Some(loc.clone()),
+        });
+    }
+
+    // Preprocessor languages must be compiled to CSS beforehand
+    for style in &descriptor.styles {
+        if let Some(lang) = style
+            .lang
+            .as_deref()
+            .filter(|lang| matches!(*lang, "scss" | "sass" | "less" | "styl" | "stylus"))
+        {
+            warnings.push(SfcError {
+                message: format!(
+                    "<style lang=\"{}\"> was not preprocessed and compiles as plain CSS. \
+                     Compile it with `preprocess_styles` (`preprocess` feature) before compiling.",
+                    lang
+                ),
+                code: Some("UNPREPROCESSED_STYLE".to_string()),
+                loc: Some(style.loc.clone()),
+            });
+        }
+    }
+
+    // Use the given scope ID, or generate one from the filename
+    let scope_id = options
+        .scope_id
+        .clone()
+        .unwrap_or_else(|| generate_scope_id(filename));
+    // Custom elements are styled inside their shadow root instead of with
+    // scope attributes
+    let custom_element = is_custom_element(descriptor, &options);
+    let has_scoped = !custom_element && descriptor.styles.iter().any(|s| s.scoped);
 
     // Detect vapor mode from script attrs
     let is_vapor = descriptor
@@ -105,10 +178,14 @@ pub fn compile_sfc(
         }
 
         // Compile styles
-        let all_css = compile_styles(&descriptor.styles, &scope_id, &options.style, &mut warnings);
-        if !all_css.is_empty() {
-            css = Some(all_css);
-        }
+        let styles = compile_styles(
+            &descriptor.styles,
+            &scope_id,
+            &options.style,
+            custom_element,
+            &mut warnings,
+        );
+        let css = emit_styles(&mut code, styles, custom_element);
 
         return Ok(SfcCompileResult {
             code,
@@ -202,10 +279,14 @@ pub fn compile_sfc(
         }
 
         // Compile styles
-        let all_css = compile_styles(&descriptor.styles, &scope_id, &options.style, &mut warnings);
-        if !all_css.is_empty() {
-            css = Some(all_css);
-        }
+        let styles = compile_styles(
+            &descriptor.styles,
+            &scope_id,
+            &options.style,
+            custom_element,
+            &mut warnings,
+        );
+        let css = emit_styles(&mut code, styles, custom_element);
 
         return Ok(SfcCompileResult {
             code,
@@ -346,6 +427,8 @@ pub fn compile_sfc(
         &component_name,
         is_ts,
         source_is_ts,
+        options.script.is_prod || options.template.is_prod,
+        custom_element,
         TemplateParts {
             imports: &template_imports,
             hoisted: &template_hoisted,
@@ -359,12 +442,27 @@ pub fn compile_sfc(
     // including imports, hoisted vars, and `export default { ... }` with inline render
     code.push_str(&script_result.code);
 
-    // Compile styles
-    let all_css = compile_styles(&descriptor.styles, &scope_id, &options.style, &mut warnings);
-    if !all_css.is_empty() {
-        css = Some(all_css);
+    // Styles and custom blocks are attached to the component after its
+    // definition, so it needs a name
+    let attaches_to_main = (custom_element && !descriptor.styles.is_empty())
+        || descriptor
+            .custom_blocks
+            .iter()
+            .any(|block| options.custom_blocks.get(&block.block_type).is_some());
+    if attaches_to_main {
+        bind_sfc_main(&mut code);
     }
 
+    // Compile styles
+    let styles = compile_styles(
+        &descriptor.styles,
+        &scope_id,
+        &options.style,
+        custom_element,
+        &mut warnings,
+    );
+    let css = emit_styles(&mut code, styles, custom_element);
+
     Ok(SfcCompileResult {
         code,
         css,
@@ -380,9 +478,10 @@ fn compile_styles(
     styles: &[SfcStyleBlock],
     scope_id: &str,
     base_opts: &StyleCompileOptions,
+    custom_element: bool,
     warnings: &mut Vec<SfcError>,
-) -> String {
-    let mut all_css = String::new();
+) -> Vec<String> {
+    let mut compiled = Vec::with_capacity(styles.len());
     for style in styles {
         let style_opts = StyleCompileOptions {
             id: {
@@ -394,17 +493,74 @@ fn compile_styles(
             scoped: style.scoped,
             ..base_opts.clone()
         };
-        match crate::style::compile_style(style, &style_opts) {
-            Ok(style_css) => {
-                if !all_css.is_empty() {
-                    all_css.push('\n');
-                }
-                all_css.push_str(&style_css);
-            }
+        let style_css = if custom_element {
+            crate::style::compile_shadow_style(style, &style_opts)
+        } else {
+            crate::style::compile_style(style, &style_opts)
+        };
+        match style_css {
+            Ok(style_css) => compiled.push(style_css),
             Err(e) => warnings.push(e),
         }
     }
-    all_css
+    compiled
+}
+
+/// Join compiled styles into the CSS output, or inline them in the code as
+/// the `styles` option read by `defineCustomElement`
+fn emit_styles(code: &mut String, styles: Vec<String>, custom_element: bool) -> Option<String> {
+    if !custom_element {
+        let all_css = styles.join("\n");
+        return (!all_css.is_empty()).then_some(all_css);
+    }
+    if !styles.is_empty() {
+        let styles: Vec<String> = styles
+            .iter()
+            .map(|css| serde_json::to_string(css).unwrap_or_default())
+            .collect();
+        attach_to_sfc_main(
+            code,
+            &format!("_sfc_main.styles = [{}]\n", styles.join(", ")),
+        );
+    }
+    None
+}
+
+/// Insert statements that modify `_sfc_main` before its trailing export
+pub(crate) fn attach_to_sfc_main(code: &mut String, statements: &str) {
+    let body = code.trim_end().trim_end_matches(';');
+    let at = body
+        .strip_suffix("export default _sfc_main")
+        .filter(|before| before.is_empty() || before.ends_with('\n'))
+        .map_or(code.len(), str::len);
+    if at == code.len() && !code.is_empty() && !code.ends_with('\n') {
+        code.push('\n');
+    }
+    code.insert_str(at, statements);
+}
+
+/// Rewrite the `export default` of script setup output to a `_sfc_main`
+/// binding that is exported at the end
+fn bind_sfc_main(code: &mut String) {
+    // TypeScript output keeps type annotations, so parse as TS
+    let (rewritten, has_default) = rewrite_default(code, "_sfc_main", true);
+    if has_default {
+        *code = rewritten;
+        if !code.ends_with('\n') {
+            code.push('\n');
+        }
+        code.push_str("export default _sfc_main\n");
+    }
+}
+
+/// Whether to compile for `defineCustomElement`
+fn is_custom_element(descriptor: &SfcDescriptor, options: &SfcCompileOptions) -> bool {
+    let filenames = [
+        descriptor.filename.as_ref(),
+        options.parse.filename.as_str(),
+        options.script.id.as_deref().unwrap_or_default(),
+    ];
+    options.custom_element || filenames.iter().any(|name| name.ends_with(".ce.vue"))
 }
 
 /// Generate scope ID from filename
@@ -422,6 +578,25 @@ fn generate_scope_id(filename: &str) -> String {
     out
 }
 
+/// Scope ID matching `@vitejs/plugin-vue`: the first 8 hex digits of the
+/// SHA-256 of the project-relative path, plus the source in production so
+/// IDs change with content.
+pub fn hash_scope_id(relative_path: &str, source: &str, is_prod: bool) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(relative_path.replace('\\', "/").as_bytes());
+    if is_prod {
+        hasher.update(source.as_bytes());
+    }
+    let digest = hasher.finalize();
+    let mut out = String::with_capacity(8);
+    for byte in &digest[..4] {
+        use std::fmt::Write as _;
+        let _ = write!(out, "{:02x}", byte);
+    }
+    out
+}
+
 /// Extract component name from filename
 fn extract_component_name(filename: &str) -> String {
     std::path::Path::new(filename)
@@ -589,6 +764,60 @@ mod tests {
         assert_eq!(id.len(), 8);
     }
 
+    #[test]
+    fn test_unpreprocessed_style_warning() {
+        let source = r#"<template><div class="a" /></template>
+<style lang="scss">.a { .b { color: red; } }</style>
+<style lang="postcss">.c { color: blue; }</style>"#;
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        let result = compile_sfc(&descriptor, SfcCompileOptions::default()).unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            result.warnings[0].code.as_deref(),
+            Some("UNPREPROCESSED_STYLE")
+        );
+        assert!(result.warnings[0].message.contains("lang=\"scss\""));
+    }
+
+    #[test]
+    fn test_type_reference_props_read_from_props() {
+        // Props from referenced interfaces are props bindings, so the inline
+        // template reads them from `__props` instead of the render context
+        let source = r#"<script setup lang="ts">
+defineProps<BaseProps & { label?: string }>()
+interface BaseProps { id: string }
+</script>
+<template><div>{{ id }} {{ label }}</div></template>"#;
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        let result = compile_sfc(&descriptor, SfcCompileOptions::default()).unwrap();
+        assert_eq!(
+            result.code,
+            r#"import { defineComponent as _defineComponent, type PropType } from 'vue'
+import { openBlock as _openBlock, createElementBlock as _createElementBlock, toDisplayString as _toDisplayString } from "vue"
+
+
+interface BaseProps { id: string }
+
+export default /*@__PURE__*/_defineComponent({
+  __name: 'anonymous',
+  props: {
+    id: { type: String as PropType<string>, required: true },
+    label: { type: String as PropType<string>, required: false }
+  },
+  setup(__props) {
+
+
+return (_ctx: any,_cache: any) => {
+  return (_openBlock(), _createElementBlock("div", null, _toDisplayString(__props.id) + " " + _toDisplayString(__props.label), 1 /* TEXT */))
+}
+}
+
+})
+"#
+        );
+    }
+
     #[test]
     fn test_extract_component_name() {
         assert_eq!(extract_component_name("src/App.vue"), "App");
@@ -1237,4 +1466,191 @@ export default {
             result.code
         );
     }
+
+    #[test]
+    fn test_hash_scope_id() {
+        // Same IDs as @vitejs/plugin-vue for the same relative path and source
+        assert_eq!(hash_scope_id("src/App.vue", "", false), "7a7a37b1");
+        assert_eq!(hash_scope_id("src\\App.vue", "", false), "7a7a37b1");
+        assert_eq!(
+            hash_scope_id("src/App.vue", "<template><div/></template>", true),
+            "a4826647"
+        );
+
+        let source = "<template><div class=\"a\" /></template><style scoped>.a {}</style>";
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        let opts = SfcCompileOptions {
+            scope_id: Some("7a7a37b1".to_string()),
+            ..Default::default()
+        };
+        let result = compile_sfc(&descriptor, opts).unwrap();
+        assert!(result.code.contains("data-v-7a7a37b1"));
+        assert!(result.css.unwrap().contains(".a[data-v-7a7a37b1]"));
+    }
+
+    #[test]
+    fn test_production_props() {
+        let source = r#"<script setup lang="ts">
+const props = withDefaults(defineProps<{
+    title: string
+    disabled?: boolean
+    onClose?: () => void
+    size?: number
+}>(), { size: 1 })
+</script>
+<template><div>{{ title }}</div></template>"#;
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+
+        let dev = compile_sfc(&descriptor, SfcCompileOptions::default()).unwrap();
+        assert!(dev
+            .code
+            .contains("title: { type: String as PropType<string>, required: true }"));
+
+        let opts = SfcCompileOptions {
+            script: ScriptCompileOptions {
+                is_prod: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let prod = compile_sfc(&descriptor, opts).unwrap();
+        assert!(prod.code.contains("title: {}"), "{}", prod.code);
+        assert!(prod.code.contains("disabled: { type: Boolean }"));
+        assert!(prod.code.contains("onClose: {}"));
+        assert!(prod.code.contains("size: { default: 1 }"));
+        assert!(!prod.code.contains("required:"));
+    }
+
+    #[test]
+    fn test_custom_element() {
+        let source = r#"<script setup>
+const label = 'Click'
+</script>
+<template><button class="btn">{{ label }}</button></template>
+<style scoped>
+:host { display: inline-block; }
+.btn :deep(svg) { width: 1em; }
+</style>
+<style>:slotted(span) { color: red; }</style>"#;
+        let parse = |filename: &str| {
+            parse_sfc(
+                source,
+                SfcParseOptions {
+                    filename: filename.to_string(),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let result = compile_sfc(&parse("MyButton.ce.vue"), SfcCompileOptions::default()).unwrap();
+        assert!(result.css.is_none());
+        assert_eq!(
+            result.code,
+            r#"import { openBlock as _openBlock, createElementBlock as _createElementBlock, toDisplayString as _toDisplayString } from "vue"
+
+
+const _hoisted_1 = { class: "btn" }
+const label = 'Click'
+
+const _sfc_main = {
+  __name: 'anonymous',
+  setup(__props) {
+
+
+return (_ctx, _cache) => {
+  return (_openBlock(), _createElementBlock("button", _hoisted_1, _toDisplayString(label)))
+}
+}
+
+}
+_sfc_main.styles = ["\n:host { display: inline-block; }\n.btn svg { width: 1em; }\n", "::slotted(span) { color: red; }"]
+export default _sfc_main
+"#
+        );
+
+        let ts_source = source.replace("<script setup>", r#"<script setup lang="ts">"#);
+        let ts_descriptor = parse_sfc(
+            &ts_source,
+            SfcParseOptions {
+                filename: "MyButton.ce.vue".to_string(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let ts = compile_sfc(&ts_descriptor, SfcCompileOptions::default()).unwrap();
+        assert!(ts.code.contains("const _sfc_main = _defineComponent({"));
+        assert_eq!(ts.code.matches("export default").count(), 1);
+        assert!(ts.code.ends_with(
+            "_sfc_main.styles = [\"\\n:host { display: inline-block; }\\n.btn svg { width: 1em; }\\n\", \"::slotted(span) { color: red; }\"]\nexport default _sfc_main\n"
+        ));
+
+        let regular = compile_sfc(&parse("MyButton.vue"), SfcCompileOptions::default()).unwrap();
+        assert!(regular.code.contains("data-v-"));
+        assert!(!regular.code.contains("_sfc_main.styles"));
+
+        let opts = SfcCompileOptions {
+            custom_element: true,
+            ..Default::default()
+        };
+        let forced = compile_sfc(&parse("MyButton.vue"), opts).unwrap();
+        assert_eq!(forced.code, result.code);
+    }
+
+    #[test]
+    fn test_custom_element_production_props() {
+        let source = r#"<script setup lang="ts">
+defineProps<{ count: number; label?: string; open?: boolean }>()
+</script>
+<template><span>{{ count }}</span></template>"#;
+        let compile = |filename: &str| {
+            let descriptor = parse_sfc(
+                source,
+                SfcParseOptions {
+                    filename: filename.to_string(),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            let opts = SfcCompileOptions {
+                script: ScriptCompileOptions {
+                    is_prod: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            compile_sfc(&descriptor, opts).unwrap().code
+        };
+
+        // defineCustomElement casts attributes with the prop types
+        assert_eq!(
+            compile("Counter.ce.vue"),
+            r#"import { defineComponent as _defineComponent, type PropType } from 'vue'
+import { openBlock as _openBlock, createElementBlock as _createElementBlock, toDisplayString as _toDisplayString } from "vue"
+
+
+export default /*@__PURE__*/_defineComponent({
+  __name: 'anonymous',
+  props: {
+    count: { type: Number },
+    label: { type: String },
+    open: { type: Boolean }
+  },
+  setup(__props) {
+
+
+return (_ctx: any,_cache: any) => {
+  return (_openBlock(), _createElementBlock("span", null, _toDisplayString(__props.count), 1 /* TEXT */))
+}
+}
+
+})
+"#
+        );
+
+        let regular = compile("Counter.vue");
+        assert!(regular.contains(
+            "  props: {\n    count: {},\n    label: {},\n    open: { type: Boolean }\n  },"
+        ));
+    }
 }
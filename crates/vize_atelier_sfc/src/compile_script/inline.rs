@@ -17,11 +17,14 @@ use super::typescript::transform_typescript_to_js;
 use super::{ScriptCompileResult, TemplateParts};
 
 /// Compile script setup with inline template (Vue's inline template mode)
+#[allow(clippy::too_many_arguments)]
 pub fn compile_script_setup_inline(
     content: &str,
     component_name: &str,
     is_ts: bool,
     source_is_ts: bool,
+    is_prod: bool,
+    custom_element: bool,
     template: TemplateParts<'_>,
     normal_script_content: Option<&str>,
 ) -> Result<ScriptCompileResult, SfcError> {
@@ -613,49 +616,59 @@ pub fn compile_script_setup_inline(
                 let mut item_idx = 0;
                 for (name, prop_type) in &prop_types {
                     item_idx += 1;
-                    props_emits_buf.extend_from_slice(b"    ");
-                    props_emits_buf.extend_from_slice(name.as_bytes());
-                    props_emits_buf.extend_from_slice(b": { type: ");
-                    props_emits_buf.extend_from_slice(prop_type.js_type.as_bytes());
-                    if needs_prop_type {
-                        if let Some(ref ts_type) = prop_type.ts_type {
-                            if prop_type.js_type == "null" {
-                                props_emits_buf.extend_from_slice(b" as unknown as PropType<");
-                            } else {
-                                props_emits_buf.extend_from_slice(b" as PropType<");
-                            }
-                            // Normalize multi-line types to single line
-                            let normalized: String =
-                                ts_type.split_whitespace().collect::<Vec<_>>().join(" ");
-                            props_emits_buf.extend_from_slice(normalized.as_bytes());
-                            props_emits_buf.push(b'>');
-                        }
-                    }
-                    props_emits_buf.extend_from_slice(b", required: ");
-                    props_emits_buf.extend_from_slice(if prop_type.optional {
-                        b"false"
+                    let default_val = with_defaults_args
+                        .as_ref()
+                        .and_then(|defaults| defaults.get(name.as_str()))
+                        .map(|default_val| default_val.as_str())
+                        .or_else(|| {
+                            ctx.macros
+                                .props_destructure
+                                .as_ref()
+                                .and_then(|destructure| destructure.bindings.get(name.as_str()))
+                                .and_then(|binding| binding.default.as_deref())
+                        });
+                    // Custom elements cast attribute strings with the full type
+                    let js_type = if is_prod && !custom_element {
+                        prod_prop_type(&prop_type.js_type, default_val.is_some())
                     } else {
-                        b"true"
-                    });
-                    let mut has_default = false;
-                    if let Some(ref defaults) = with_defaults_args {
-                        if let Some(default_val) = defaults.get(name.as_str()) {
-                            props_emits_buf.extend_from_slice(b", default: ");
-                            props_emits_buf.extend_from_slice(default_val.as_bytes());
-                            has_default = true;
-                        }
-                    }
-                    if !has_default {
-                        if let Some(ref destructure) = ctx.macros.props_destructure {
-                            if let Some(binding) = destructure.bindings.get(name.as_str()) {
-                                if let Some(ref default_val) = binding.default {
-                                    props_emits_buf.extend_from_slice(b", default: ");
-                                    props_emits_buf.extend_from_slice(default_val.as_bytes());
+                        Some(prop_type.js_type.clone())
+                    };
+
+                    let mut fields: Vec<String> = Vec::with_capacity(3);
+                    if let Some(js_type) = js_type {
+                        let mut type_field = format!("type: {}", js_type);
+                        if needs_prop_type && !is_prod {
+                            if let Some(ref ts_type) = prop_type.ts_type {
+                                if prop_type.js_type == "null" {
+                                    type_field.push_str(" as unknown as PropType<");
+                                } else {
+                                    type_field.push_str(" as PropType<");
                                 }
+                                // Normalize multi-line types to single line
+                                let normalized: String =
+                                    ts_type.split_whitespace().collect::<Vec<_>>().join(" ");
+                                type_field.push_str(&normalized);
+                                type_field.push('>');
                             }
                         }
+                        fields.push(type_field);
+                    }
+                    if !is_prod {
+                        fields.push(format!("required: {}", !prop_type.optional));
+                    }
+                    if let Some(default_val) = default_val {
+                        fields.push(format!("default: {}", default_val));
+                    }
+
+                    props_emits_buf.extend_from_slice(b"    ");
+                    props_emits_buf.extend_from_slice(name.as_bytes());
+                    if fields.is_empty() {
+                        props_emits_buf.extend_from_slice(b": {}");
+                    } else {
+                        props_emits_buf.extend_from_slice(b": { ");
+                        props_emits_buf.extend_from_slice(fields.join(", ").as_bytes());
+                        props_emits_buf.extend_from_slice(b" }");
                     }
-                    props_emits_buf.extend_from_slice(b" }");
                     if item_idx < total_items {
                         props_emits_buf.push(b',');
                     }
@@ -1072,6 +1085,37 @@ pub fn compile_script_setup_inline(
     })
 }
 
+/// Minimal runtime `type` of a prop in production, where props are not
+/// validated: only the constructors the runtime reads are kept. `Boolean`
+/// enables boolean casting (with `String`, when listed before it, keeping
+/// `""` as a string), and `Function` stops a default from being called as a
+/// factory. Returns `None` when the type is not needed.
+fn prod_prop_type(js_type: &str, has_default: bool) -> Option<String> {
+    let types: Vec<&str> = js_type
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .collect();
+    let boolean = types.iter().position(|t| *t == "Boolean");
+    let kept: Vec<&str> = types
+        .iter()
+        .enumerate()
+        .filter(|(i, t)| match **t {
+            "Boolean" => true,
+            "String" => boolean.is_some_and(|boolean| *i < boolean),
+            "Function" => has_default,
+            _ => false,
+        })
+        .map(|(_, t)| *t)
+        .collect();
+    match kept.as_slice() {
+        [] => None,
+        [single] => Some(single.to_string()),
+        _ => Some(format!("[{}]", kept.join(", "))),
+    }
+}
+
 /// Extract the variable name from a const declaration line.
 /// e.g., "const msg = 'hello'" -> Some("msg")
 /// e.g., "const count = ref(0)" -> Some("count")
@@ -1112,7 +1156,13 @@ fn resolve_type_args(
         let parts: Vec<&str> = content.split('&').collect();
         let mut merged_props = Vec::new();
         for part in parts {
-            let resolved = resolve_single_type_ref(part.trim(), interfaces, type_aliases);
+            let part = part.trim();
+            // Inline members (`Base & { label?: string }`) are merged as written
+            let resolved = if part.starts_with('{') {
+                Some(part.to_string())
+            } else {
+                resolve_single_type_ref(part, interfaces, type_aliases)
+            };
             if let Some(body) = resolved {
                 let body = body.trim();
                 let inner = if body.starts_with('{') && body.ends_with('}') {
@@ -1158,6 +1208,29 @@ fn resolve_type_args(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_prod_prop_type() {
+        assert_eq!(prod_prop_type("String", false), None);
+        assert_eq!(prod_prop_type("Boolean", false).as_deref(), Some("Boolean"));
+        assert_eq!(
+            prod_prop_type("[Boolean, String, Number]", false).as_deref(),
+            Some("Boolean")
+        );
+        assert_eq!(
+            prod_prop_type("[Number, String, Boolean]", false).as_deref(),
+            Some("[String, Boolean]")
+        );
+        assert_eq!(prod_prop_type("Function", false), None);
+        assert_eq!(
+            prod_prop_type("Function", true).as_deref(),
+            Some("Function")
+        );
+        assert_eq!(
+            prod_prop_type("[Boolean, Function]", true).as_deref(),
+            Some("[Boolean, Function]")
+        );
+    }
+
     /// Helper to compile a minimal script setup and return the output code
     fn compile_setup(script_content: &str) -> String {
         let empty_template = TemplateParts {
@@ -1171,6 +1244,8 @@ mod tests {
             "TestComponent",
             false, // is_ts = false (JS output, strip TS)
             true,  // source_is_ts = true
+            false, // is_prod = false
+            false, // custom_element = false
             empty_template,
             None,
         )
@@ -1189,8 +1264,10 @@ mod tests {
         let result = compile_script_setup_inline(
             script_content,
             "TestComponent",
-            true, // is_ts = true (TS output)
-            true, // source_is_ts = true
+            true,  // is_ts = true (TS output)
+            true,  // source_is_ts = true
+            false, // is_prod = false
+            false, // custom_element = false
             empty_template,
             None,
         )
@@ -10,9 +10,12 @@ use lightningcss::printer::PrinterOptions;
 use lightningcss::stylesheet::{ParserOptions, StyleSheet};
 #[cfg(feature = "native")]
 use lightningcss::targets::{Browsers, Targets};
+#[cfg(feature = "native")]
+use parcel_sourcemap::SourceMap;
 use serde::{Deserialize, Serialize};
 use vize_carton::{Bump, BumpVec};
 
+use crate::resolve::SfcFileSystem;
 use crate::types::SfcStyleBlock;
 
 /// CSS compilation options
@@ -42,6 +45,14 @@ pub struct CssCompileOptions {
     /// Filename for error reporting
     #[serde(default)]
     pub filename: Option<String>,
+
+    /// Code prepended to SCSS, Sass and Less blocks before preprocessing
+    #[serde(default)]
+    pub additional_data: Option<String>,
+
+    /// Source map (JSON) of the input, chained into the output map
+    #[serde(default)]
+    pub input_source_map: Option<String>,
 }
 
 /// Browser targets for CSS autoprefixing
@@ -118,6 +129,10 @@ pub struct CssCompileResult {
     /// Warnings during compilation
     #[serde(default)]
     pub warnings: Vec<String>,
+
+    /// Files imported by the preprocessor
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
 }
 
 /// Compile CSS using LightningCSS (native feature enabled)
@@ -148,14 +163,15 @@ pub fn compile_css(css: &str, options: &CssCompileOptions) -> CssCompileResult {
         .unwrap_or_default();
 
     // Parse and process CSS
-    let (code, errors) = compile_css_internal(scoped_css, filename, options.minify, targets);
+    let (code, map, errors) = compile_css_internal(scoped_css, filename, options, targets);
 
     CssCompileResult {
         code,
-        map: None,
+        map,
         css_vars,
         errors,
         warnings: vec![],
+        dependencies: vec![],
     }
 }
 
@@ -184,6 +200,7 @@ pub fn compile_css(css: &str, options: &CssCompileOptions) -> CssCompileResult {
         css_vars,
         errors: vec![],
         warnings: vec![],
+        dependencies: vec![],
     }
 }
 
@@ -192,9 +209,9 @@ pub fn compile_css(css: &str, options: &CssCompileOptions) -> CssCompileResult {
 fn compile_css_internal(
     css: &str,
     filename: &str,
-    minify: bool,
+    options: &CssCompileOptions,
     targets: Targets,
-) -> (String, Vec<String>) {
+) -> (String, Option<String>, Vec<String>) {
     let parser_options = ParserOptions {
         filename: filename.to_string(),
         ..Default::default()
@@ -207,12 +224,12 @@ fn compile_css_internal(
             let mut message = String::from("CSS parse error: ");
             message.push_str(&e.to_string());
             errors.push(message);
-            return (css.to_string(), errors);
+            return (css.to_string(), None, errors);
         }
     };
 
     // Minify if requested
-    if minify {
+    if options.minify {
         if let Err(e) = stylesheet.minify(lightningcss::stylesheet::MinifyOptions {
             targets,
             ..Default::default()
@@ -222,34 +239,113 @@ fn compile_css_internal(
             use std::fmt::Write as _;
             let _ = write!(&mut message, "{:?}", e);
             errors.push(message);
-            return (css.to_string(), errors);
+            return (css.to_string(), None, errors);
         }
     }
 
     // Print the CSS
+    let mut source_map = options.source_map.then(|| SourceMap::new("/"));
     let printer_options = PrinterOptions {
-        minify,
+        minify: options.minify,
         targets,
+        source_map: source_map.as_mut(),
         ..Default::default()
     };
 
     match stylesheet.to_css(printer_options) {
-        Ok(result) => (result.code, vec![]),
+        Ok(result) => {
+            let map = source_map.and_then(|mut map| {
+                finish_source_map(&mut map, css, options.input_source_map.as_deref())
+            });
+            (result.code, map, vec![])
+        }
         Err(e) => {
             let mut errors = Vec::with_capacity(1);
             let mut message = String::from("CSS print error: ");
             use std::fmt::Write as _;
             let _ = write!(&mut message, "{:?}", e);
             errors.push(message);
-            (css.to_string(), errors)
+            (css.to_string(), None, errors)
         }
     }
 }
 
+/// Serialize the output map, chaining it through the input map (e.g. from a
+/// preprocessor) so positions point at the original source
+#[cfg(feature = "native")]
+fn finish_source_map(map: &mut SourceMap, css: &str, input: Option<&str>) -> Option<String> {
+    if !map.get_sources().is_empty() {
+        map.set_source_content(0, css).ok()?;
+    }
+    if let Some(input) = input {
+        let mut original = SourceMap::from_json("/", input).ok()?;
+        map.extends(&mut original).ok()?;
+    }
+    map.to_json(None).ok()
+}
+
 /// Compile a style block
+///
+/// With the `preprocess` feature, SCSS, Sass and Less blocks are compiled to
+/// CSS first; imports are read from the local file system (native builds).
 pub fn compile_style_block(style: &SfcStyleBlock, options: &CssCompileOptions) -> CssCompileResult {
+    #[cfg(feature = "native")]
+    let fs = crate::resolve::NativeFileSystem;
+    #[cfg(not(feature = "native"))]
+    let fs = |path: &str| Err(format!("Cannot read \"{}\" without a file system", path));
+    compile_style_block_with_fs(style, options, &fs)
+}
+
+/// Compile a style block, reading preprocessor imports through `fs`
+#[cfg_attr(not(feature = "preprocess"), allow(unused_variables))]
+pub fn compile_style_block_with_fs(
+    style: &SfcStyleBlock,
+    options: &CssCompileOptions,
+    fs: &dyn SfcFileSystem,
+) -> CssCompileResult {
     let mut opts = options.clone();
     opts.scoped = style.scoped || opts.scoped;
+
+    #[cfg(feature = "preprocess")]
+    if let Some(lang) = style
+        .lang
+        .as_deref()
+        .and_then(crate::preprocess::StyleLang::from_lang)
+    {
+        let preprocess_options = crate::preprocess::PreprocessOptions {
+            filename: opts
+                .filename
+                .clone()
+                .unwrap_or_else(|| "style.css".to_string()),
+            scss_additional_data: opts.additional_data.clone(),
+            less_additional_data: opts.additional_data.clone(),
+            source_map: opts.source_map,
+        };
+        return match crate::preprocess::preprocess_style(
+            &style.content,
+            lang,
+            &preprocess_options,
+            fs,
+        ) {
+            Ok(preprocessed) => {
+                if preprocessed.map.is_some() {
+                    opts.input_source_map = preprocessed.map;
+                }
+                let mut result = compile_css(&preprocessed.code, &opts);
+                result.dependencies = preprocessed.dependencies;
+                result
+            }
+            Err(e) => CssCompileResult {
+                code: style.content.to_string(),
+                map: None,
+                css_vars: vec![],
+                errors: vec![e.message],
+                warnings: vec![],
+                dependencies: vec![],
+            },
+        };
+    }
+
     compile_css(&style.content, &opts)
 }
 
@@ -833,4 +929,63 @@ mod tests {
         );
         assert!(result.contains("monospace"));
     }
+
+    #[test]
+    #[cfg(all(feature = "native", feature = "preprocess"))]
+    fn test_compile_preprocessed_style_block() {
+        use crate::{parse_sfc, SfcParseOptions};
+
+        let source = "<style lang=\"less\" scoped>\n.card {\n  .title { color: v-bind(color); margin: @gap; }\n}\n</style>";
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        let fs = |path: &str| match path {
+            "src/tokens.less" => Ok("@gap: 4px;".to_string()),
+            _ => Err("not found".to_string()),
+        };
+        let result = compile_style_block_with_fs(
+            &descriptor.styles[0],
+            &CssCompileOptions {
+                scope_id: Some("data-v-123".to_string()),
+                filename: Some("src/Card.vue".to_string()),
+                additional_data: Some("@import \"./tokens\";".to_string()),
+                source_map: true,
+                ..Default::default()
+            },
+            &fs,
+        );
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(result.code.contains(".card .title[data-v-123]"));
+        assert!(result.code.contains("margin: 4px"));
+        assert_eq!(result.css_vars, vec!["color"]);
+        assert_eq!(result.dependencies, vec!["src/tokens.less"]);
+
+        // The lightningcss map is chained back to the Less source
+        let map: serde_json::Value = serde_json::from_str(&result.map.unwrap()).unwrap();
+        assert_eq!(map["sources"], serde_json::json!(["src/Card.vue"]));
+        assert!(map["sourcesContent"][0]
+            .as_str()
+            .unwrap()
+            .contains("v-bind(color)"));
+
+        // SCSS output is mapped back the same way
+        let source = "<style lang=\"scss\">\n.card {\n  .title { margin: $gap; }\n}\n</style>";
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        let result = compile_style_block_with_fs(
+            &descriptor.styles[0],
+            &CssCompileOptions {
+                filename: Some("src/Card.vue".to_string()),
+                additional_data: Some("$gap: 4px;".to_string()),
+                source_map: true,
+                ..Default::default()
+            },
+            &fs,
+        );
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let map: serde_json::Value = serde_json::from_str(&result.map.unwrap()).unwrap();
+        assert_eq!(map["sources"], serde_json::json!(["src/Card.vue"]));
+        assert!(map["sourcesContent"][0]
+            .as_str()
+            .unwrap()
+            .contains("margin: $gap"));
+    }
 }
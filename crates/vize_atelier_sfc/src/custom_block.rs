@@ -0,0 +1,274 @@
+//! Custom block transforms.
+//!
+//! Blocks such as `<i18n>`, `<docs>` or `<route>` mean nothing to Vue
+//! itself. A [`CustomBlockTransform`] registered for the tag in
+//! [`SfcCompileOptions::custom_blocks`](crate::SfcCompileOptions) turns
+//! each block into a JavaScript function expression that receives the
+//! component, like the `export default function (Component) {}` modules
+//! Vite plugins generate for custom blocks. `compile_sfc` appends a call
+//! of each function after the component definition; blocks without a
+//! transform are dropped.
+//!
+//! [`CustomBlockTransforms::builtin`] handles JSON/YAML `<i18n>` blocks
+//! and Markdown `<docs>` blocks.
+
+mod i18n;
+mod yaml;
+
+use std::fmt;
+use std::sync::Arc;
+
+use vize_carton::FxHashMap;
+
+use crate::compile::attach_to_sfc_main;
+use crate::types::{SfcCompileResult, SfcCustomBlock, SfcDescriptor, SfcError};
+
+pub use i18n::{compile_i18n_block, compile_message};
+pub use yaml::parse_yaml;
+
+/// Information about the component a custom block belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomBlockContext<'a> {
+    /// Component filename
+    pub filename: &'a str,
+    /// Scope ID of the component (without the `data-v-` prefix)
+    pub id: &'a str,
+    /// Index of the block among the custom blocks
+    pub index: usize,
+    /// Whether this is a production build
+    pub is_prod: bool,
+}
+
+/// Turns a custom block into code.
+///
+/// Implemented by any
+/// `Fn(&SfcCustomBlock, &CustomBlockContext) -> Result<String, String>`.
+pub trait CustomBlockTransform: Send + Sync {
+    /// Return a JavaScript function expression called with the component
+    /// object, e.g. `(Component) => { Component.__route = {} }`.
+    fn transform(
+        &self,
+        block: &SfcCustomBlock<'_>,
+        context: &CustomBlockContext<'_>,
+    ) -> Result<String, String>;
+}
+
+impl<F> CustomBlockTransform for F
+where
+    F: Fn(&SfcCustomBlock<'_>, &CustomBlockContext<'_>) -> Result<String, String> + Send + Sync,
+{
+    fn transform(
+        &self,
+        block: &SfcCustomBlock<'_>,
+        context: &CustomBlockContext<'_>,
+    ) -> Result<String, String> {
+        self(block, context)
+    }
+}
+
+/// Registry of custom block transforms by tag name.
+#[derive(Clone, Default)]
+pub struct CustomBlockTransforms {
+    transforms: FxHashMap<String, Arc<dyn CustomBlockTransform>>,
+}
+
+impl CustomBlockTransforms {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with the `<i18n>` and `<docs>` transforms.
+    pub fn builtin() -> Self {
+        let mut transforms = Self::new();
+        transforms.register("i18n", compile_i18n_block);
+        transforms.register("docs", compile_docs_block);
+        transforms
+    }
+
+    /// Register the transform of a tag, replacing any previous one.
+    pub fn register(
+        &mut self,
+        tag: impl Into<String>,
+        transform: impl CustomBlockTransform + 'static,
+    ) -> &mut Self {
+        self.transforms.insert(tag.into(), Arc::new(transform));
+        self
+    }
+
+    /// Get the transform of a tag.
+    pub fn get(&self, tag: &str) -> Option<&dyn CustomBlockTransform> {
+        self.transforms.get(tag).map(|transform| transform.as_ref())
+    }
+
+    /// Registered tags, sorted.
+    pub fn tags(&self) -> Vec<&str> {
+        let mut tags: Vec<&str> = self.transforms.keys().map(String::as_str).collect();
+        tags.sort_unstable();
+        tags
+    }
+
+    /// Whether no transform is registered.
+    pub fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+}
+
+impl fmt::Debug for CustomBlockTransforms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.tags()).finish()
+    }
+}
+
+/// Expose a Markdown `<docs>` block as `Component.__docs`.
+pub fn compile_docs_block(
+    block: &SfcCustomBlock<'_>,
+    _context: &CustomBlockContext<'_>,
+) -> Result<String, String> {
+    let docs = serde_json::to_string(block.content.trim()).map_err(|e| e.to_string())?;
+    Ok(format!(
+        "(Component) => {{\n  Component.__docs = {};\n}}",
+        docs
+    ))
+}
+
+/// Append the calls of the transformed custom blocks to the compiled code.
+pub(crate) fn append_custom_blocks(
+    descriptor: &SfcDescriptor<'_>,
+    transforms: &CustomBlockTransforms,
+    context: CustomBlockContext<'_>,
+    result: &mut SfcCompileResult,
+) {
+    for (index, block) in descriptor.custom_blocks.iter().enumerate() {
+        let Some(transform) = transforms.get(&block.block_type) else {
+            continue;
+        };
+        let context = CustomBlockContext { index, ..context };
+        match transform.transform(block, &context) {
+            Ok(code) => attach_to_sfc_main(
+                &mut result.code,
+                &format!(
+                    "/* <{}> */\nconst _sfc_block{} = {};\n_sfc_block{}(_sfc_main);\n",
+                    block.block_type, index, code, index
+                ),
+            ),
+            Err(message) => result.errors.push(SfcError {
+                message: format!(
+                    "Failed to transform <{}> block: {}",
+                    block.block_type, message
+                ),
+                code: Some("CUSTOM_BLOCK_TRANSFORM".to_string()),
+                loc: Some(block.loc.clone()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compile_sfc, parse_sfc, SfcCompileOptions, SfcParseOptions};
+
+    fn compile(source: &str, transforms: CustomBlockTransforms) -> SfcCompileResult {
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        compile_sfc(
+            &descriptor,
+            SfcCompileOptions {
+                custom_blocks: transforms,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_custom_transform() {
+        let mut transforms = CustomBlockTransforms::new();
+        transforms.register(
+            "route",
+            |block: &SfcCustomBlock<'_>, context: &CustomBlockContext<'_>| {
+                Ok(format!(
+                    "(Component) => {{ Component.__route = {}; Component.__routeIndex = {} }}",
+                    block.content.trim(),
+                    context.index
+                ))
+            },
+        );
+        assert_eq!(transforms.tags(), vec!["route"]);
+
+        let result = compile(
+            r#"<template><div /></template>
+<docs>Ignored</docs>
+<route>{ "meta": { "auth": true } }</route>"#,
+            transforms,
+        );
+        assert!(result.errors.is_empty());
+        assert!(result.code.contains(
+            "const _sfc_block1 = (Component) => { Component.__route = { \"meta\": { \"auth\": true } }; Component.__routeIndex = 1 };\n_sfc_block1(_sfc_main);"
+        ));
+        assert!(!result.code.contains("Ignored"));
+    }
+
+    #[test]
+    fn test_unregistered_blocks_dropped() {
+        let source = "<script setup>\nconst a = 1\n</script>\n<i18n>{}</i18n>";
+        let result = compile(source, CustomBlockTransforms::new());
+        assert!(!result.code.contains("_sfc_block"));
+    }
+
+    #[test]
+    fn test_script_setup_bound_to_sfc_main() {
+        let result = compile(
+            "<script setup>\nconst a = 1\n</script>\n<docs>Counter</docs>",
+            CustomBlockTransforms::builtin(),
+        );
+        assert_eq!(
+            result.code,
+            r#"const a = 1
+
+const _sfc_main = {
+  __name: 'anonymous',
+  setup(__props) {
+
+
+}
+
+}
+/* <docs> */
+const _sfc_block0 = (Component) => {
+  Component.__docs = "Counter";
+};
+_sfc_block0(_sfc_main);
+export default _sfc_main
+"#
+        );
+    }
+
+    #[test]
+    fn test_builtin_docs() {
+        let result = compile(
+            "<script>\nexport default {}\n</script>\n<docs>\n# Button\n\nA \"primary\" button.\n</docs>",
+            CustomBlockTransforms::builtin(),
+        );
+        assert!(result
+            .code
+            .contains(r##"Component.__docs = "# Button\n\nA \"primary\" button.";"##));
+        assert!(result.code.contains("_sfc_block0(_sfc_main);"));
+    }
+
+    #[test]
+    fn test_transform_error() {
+        let result = compile(
+            "<template><div /></template>\n<i18n>{ \"en\": </i18n>",
+            CustomBlockTransforms::builtin(),
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].code.as_deref(),
+            Some("CUSTOM_BLOCK_TRANSFORM")
+        );
+        assert!(result.errors[0]
+            .message
+            .starts_with("Failed to transform <i18n> block"));
+    }
+}
@@ -0,0 +1,363 @@
+//! `<i18n>` blocks.
+//!
+//! Locale messages are precompiled to the message functions vue-i18n
+//! evaluates at runtime (the output of `@intlify/message-compiler`), so
+//! applications can ship the runtime-only build of vue-i18n.
+
+use serde_json::Value;
+
+use super::{parse_yaml, CustomBlockContext};
+use crate::types::SfcCustomBlock;
+
+/// Compile an `<i18n>` block into `Component.__i18n` resources.
+///
+/// The content is JSON, or YAML with `lang="yaml"`. A `locale` attribute
+/// scopes the messages to one locale, and `global` registers them as
+/// global messages (`Component.__i18nGlobal`).
+pub fn compile_i18n_block(
+    block: &SfcCustomBlock<'_>,
+    context: &CustomBlockContext<'_>,
+) -> Result<String, String> {
+    let lang = block
+        .attrs
+        .get("lang")
+        .map(|l| l.as_ref())
+        .unwrap_or("json");
+    let content = block.content.trim();
+    let resource = match lang {
+        _ if content.is_empty() => Value::Object(Default::default()),
+        "json" => serde_json::from_str(content).map_err(|e| format!("invalid JSON: {}", e))?,
+        "yaml" | "yml" => parse_yaml(content).map_err(|e| format!("invalid YAML: {}", e))?,
+        lang => return Err(format!("unsupported lang \"{}\"", lang)),
+    };
+
+    let locale = block.attrs.get("locale").map(|l| l.as_ref()).unwrap_or("");
+    let property = if block.attrs.contains_key("global") {
+        "__i18nGlobal"
+    } else {
+        "__i18n"
+    };
+    let mut code = String::new();
+    write_resource(&resource, "", context.is_prod, 4, &mut code)?;
+
+    Ok(format!(
+        "(Component) => {{\n  const _Component = Component;\n  _Component.{property} = _Component.{property} || [];\n  _Component.{property}.push({{\n    \"locale\": {locale},\n    \"resource\": {code}\n  }});\n  delete _Component._Ctor;\n}}",
+        property = property,
+        locale = json_string(locale),
+        code = code,
+    ))
+}
+
+/// Compile a vue-i18n message to a message function expression.
+///
+/// Supports named (`{name}`), list (`{0}`) and literal (`{'@'}`)
+/// interpolations, linked messages (`@:key`, `@.upper:key`) and plural
+/// cases separated by `|`. Outside production the function keeps its
+/// `source` for devtools.
+pub fn compile_message(source: &str, is_prod: bool) -> Result<String, String> {
+    let cases = split_cases(source);
+    let mut bodies = Vec::with_capacity(cases.len());
+    for case in &cases {
+        let case = if cases.len() > 1 { case.trim() } else { case };
+        bodies.push(format!("_normalize([{}])", message_parts(case)?.join(", ")));
+    }
+    let body = if bodies.len() == 1 {
+        bodies.remove(0)
+    } else {
+        format!("_plural([{}])", bodies.join(", "))
+    };
+
+    let function = format!(
+        "(ctx) => {{ const {{ normalize: _normalize, interpolate: _interpolate, named: _named, list: _list, linked: _linked, plural: _plural, type: _type }} = ctx; return {}; }}",
+        body
+    );
+    if is_prod {
+        Ok(function)
+    } else {
+        Ok(format!(
+            "(() => {{ const fn = {}; fn.source = {}; return fn; }})()",
+            function,
+            json_string(source)
+        ))
+    }
+}
+
+fn write_resource(
+    value: &Value,
+    path: &str,
+    is_prod: bool,
+    indent: usize,
+    out: &mut String,
+) -> Result<(), String> {
+    let pad = " ".repeat(indent + 2);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            out.push_str("{\n");
+            for (i, (key, value)) in map.iter().enumerate() {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                out.push_str(&pad);
+                out.push_str(&json_string(key));
+                out.push_str(": ");
+                write_resource(value, &path, is_prod, indent + 2, out)?;
+                out.push_str(if i + 1 < map.len() { ",\n" } else { "\n" });
+            }
+            out.push_str(&" ".repeat(indent));
+            out.push('}');
+        }
+        Value::Array(items) if !items.is_empty() => {
+            out.push_str("[\n");
+            for (i, value) in items.iter().enumerate() {
+                out.push_str(&pad);
+                write_resource(value, &format!("{}[{}]", path, i), is_prod, indent + 2, out)?;
+                out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+            }
+            out.push_str(&" ".repeat(indent));
+            out.push(']');
+        }
+        Value::String(message) => {
+            let function = compile_message(message, is_prod)
+                .map_err(|e| format!("message \"{}\": {}", path, e))?;
+            out.push_str(&function);
+        }
+        value => out.push_str(&value.to_string()),
+    }
+    Ok(())
+}
+
+/// Split a message into its plural cases.
+fn split_cases(source: &str) -> Vec<&str> {
+    let mut cases = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in source.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => {
+                cases.push(&source[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    cases.push(&source[start..]);
+    cases
+}
+
+/// Compile one plural case into `_normalize` elements.
+fn message_parts(source: &str) -> Result<Vec<String>, String> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut chars = source.char_indices().peekable();
+
+    let flush = |text: &mut String, parts: &mut Vec<String>| {
+        if !text.is_empty() {
+            parts.push(json_string(text));
+            text.clear();
+        }
+    };
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' => {
+                let end = source[i..]
+                    .find('}')
+                    .map(|end| i + end)
+                    .ok_or_else(|| format!("unterminated placeholder at {}", i))?;
+                match placeholder(&source[i + 1..end])? {
+                    Placeholder::Literal(literal) => text.push_str(&literal),
+                    Placeholder::Expression(expression) => {
+                        flush(&mut text, &mut parts);
+                        parts.push(format!("_interpolate({})", expression));
+                    }
+                }
+                while chars.peek().is_some_and(|&(j, _)| j <= end) {
+                    chars.next();
+                }
+            }
+            '@' if matches!(chars.peek(), Some((_, '.' | ':'))) => {
+                let rest = &source[i + 1..];
+                let (modifier, key_start) = match rest.strip_prefix('.') {
+                    Some(after) => {
+                        let len = after
+                            .find(|c: char| !c.is_ascii_alphanumeric())
+                            .unwrap_or(after.len());
+                        if !after[len..].starts_with(':') {
+                            return Err(format!("invalid linked message at {}", i));
+                        }
+                        (Some(&after[..len]), 1 + len + 1)
+                    }
+                    None => (None, 1),
+                };
+                let (key, consumed) = linked_key(&rest[key_start..])
+                    .ok_or_else(|| format!("missing linked message key at {}", i))?;
+                flush(&mut text, &mut parts);
+                parts.push(format!(
+                    "_linked({}, {}, _type)",
+                    key,
+                    modifier.map_or("undefined".to_string(), json_string)
+                ));
+                let end = i + 1 + key_start + consumed;
+                while chars.peek().is_some_and(|&(j, _)| j < end) {
+                    chars.next();
+                }
+            }
+            '}' => return Err(format!("unbalanced \"}}\" at {}", i)),
+            c => text.push(c),
+        }
+    }
+    flush(&mut text, &mut parts);
+    Ok(parts)
+}
+
+enum Placeholder {
+    Literal(String),
+    Expression(String),
+}
+
+fn placeholder(inner: &str) -> Result<Placeholder, String> {
+    let inner = inner.trim();
+    if let Some(literal) = inner
+        .strip_prefix('\'')
+        .and_then(|rest| rest.strip_suffix('\''))
+    {
+        return Ok(Placeholder::Literal(
+            literal.replace("\\'", "'").replace("\\\\", "\\"),
+        ));
+    }
+    if !inner.is_empty() && inner.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(Placeholder::Expression(format!("_list({})", inner)));
+    }
+    let is_name = inner
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && inner
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '-'));
+    if is_name {
+        return Ok(Placeholder::Expression(format!(
+            "_named({})",
+            json_string(inner)
+        )));
+    }
+    Err(format!("invalid placeholder \"{{{}}}\"", inner))
+}
+
+/// Key of a linked message: `key.path`, `(key.path)` or a placeholder.
+/// Returns the key expression and the number of bytes consumed.
+fn linked_key(rest: &str) -> Option<(String, usize)> {
+    if let Some(inner) = rest.strip_prefix('(') {
+        let end = inner.find(')')?;
+        return Some((json_string(&inner[..end]), end + 2));
+    }
+    if rest.starts_with('{') {
+        let end = rest.find('}')?;
+        return match placeholder(&rest[1..end]).ok()? {
+            Placeholder::Expression(expression) => {
+                Some((format!("_interpolate({})", expression), end + 1))
+            }
+            Placeholder::Literal(literal) => Some((json_string(&literal), end + 1)),
+        };
+    }
+    let len = rest
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.')))
+        .unwrap_or(rest.len());
+    // A trailing period ends the sentence rather than the key
+    let key = rest[..len].trim_end_matches('.');
+    (!key.is_empty()).then(|| (json_string(key), key.len()))
+}
+
+fn json_string(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_sfc, SfcParseOptions};
+
+    fn body(source: &str) -> String {
+        let function = compile_message(source, true).unwrap();
+        let start = function.find("return ").unwrap() + "return ".len();
+        function[start..function.rfind("; }").unwrap()].to_string()
+    }
+
+    #[test]
+    fn test_compile_message() {
+        assert_eq!(body("Hello"), r#"_normalize(["Hello"])"#);
+        assert_eq!(
+            body("Hello {name}, you have {0} items"),
+            r#"_normalize(["Hello ", _interpolate(_named("name")), ", you have ", _interpolate(_list(0)), " items"])"#
+        );
+        assert_eq!(body("{'@'}vize {'{'}"), r#"_normalize(["@vize {"])"#);
+        assert_eq!(
+            body("no apples | one apple | {count} apples"),
+            r#"_plural([_normalize(["no apples"]), _normalize(["one apple"]), _normalize([_interpolate(_named("count")), " apples"])])"#
+        );
+        assert_eq!(
+            body("See @:message.dio. And @.upper:(name)!"),
+            r#"_normalize(["See ", _linked("message.dio", undefined, _type), ". And ", _linked("name", "upper", _type), "!"])"#
+        );
+        assert_eq!(
+            body("mail@example.com"),
+            r#"_normalize(["mail@example.com"])"#
+        );
+        assert_eq!(body(""), "_normalize([])");
+
+        let dev = compile_message("Hi {name}", false).unwrap();
+        assert!(dev.ends_with(r#"fn.source = "Hi {name}"; return fn; })()"#));
+
+        assert!(compile_message("Hello {name", true).is_err());
+        assert!(compile_message("Hello {first name}", true).is_err());
+        assert!(compile_message("Hello }", true).is_err());
+    }
+
+    fn compile_block(source: &str) -> Result<String, String> {
+        let descriptor = parse_sfc(source, SfcParseOptions::default()).unwrap();
+        let context = CustomBlockContext {
+            filename: "App.vue",
+            id: "7a7a37b1",
+            index: 0,
+            is_prod: true,
+        };
+        compile_i18n_block(&descriptor.custom_blocks[0], &context)
+    }
+
+    #[test]
+    fn test_compile_i18n_block() {
+        let code = compile_block(
+            r#"<i18n>
+{ "en": { "hello": "Hello {name}!", "count": 3 }, "ja": { "hello": "こんにちは" } }
+</i18n>"#,
+        )
+        .unwrap();
+        assert!(code.starts_with("(Component) => {\n  const _Component = Component;\n  _Component.__i18n = _Component.__i18n || [];"));
+        assert!(code.contains("\"locale\": \"\","));
+        assert!(code.contains(r#""hello": (ctx) => {"#));
+        assert!(code.contains(r#"_normalize(["Hello ", _interpolate(_named("name")), "!"])"#));
+        assert!(code.contains(r#""count": 3"#));
+        assert!(code.contains(r#"_normalize(["こんにちは"])"#));
+        assert!(code.ends_with("  delete _Component._Ctor;\n}"));
+
+        let code = compile_block(
+            "<i18n lang=\"yaml\" locale=\"en\" global>\ngreeting:\n  morning: Good morning\n</i18n>",
+        )
+        .unwrap();
+        assert!(code.contains("_Component.__i18nGlobal.push({"));
+        assert!(code.contains("\"locale\": \"en\","));
+        assert!(code.contains(r#"_normalize(["Good morning"])"#));
+    }
+
+    #[test]
+    fn test_i18n_block_errors() {
+        let err = compile_block(r#"<i18n>{ "en": { "a": { "b": "{oops" } } }</i18n>"#).unwrap_err();
+        assert!(err.starts_with("message \"en.a.b\""), "{}", err);
+        let err = compile_block("<i18n lang=\"json5\">{}</i18n>").unwrap_err();
+        assert_eq!(err, "unsupported lang \"json5\"");
+    }
+}
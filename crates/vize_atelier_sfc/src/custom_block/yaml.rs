@@ -0,0 +1,369 @@
+//! Minimal YAML reader for `<i18n lang="yaml">` blocks.
+//!
+//! Covers the block-style subset locale files are written in: nested
+//! mappings and sequences, plain and quoted scalars, `|`/`>` block
+//! scalars, empty flow collections and comments. Anchors, tags, multiple
+//! documents and non-empty flow collections are rejected.
+
+use serde_json::{Map, Number, Value};
+
+/// A non-blank line with its indentation.
+#[derive(Debug, Clone)]
+struct Line<'a> {
+    number: usize,
+    indent: usize,
+    text: &'a str,
+}
+
+/// Parse a YAML document into a JSON value.
+pub fn parse_yaml(source: &str) -> Result<Value, String> {
+    let mut lines = Vec::new();
+    for (i, raw) in source.lines().enumerate() {
+        let number = i + 1;
+        let content = raw.trim_start_matches(' ');
+        if content.starts_with('\t') {
+            return Err(format!(
+                "line {}: tabs are not allowed for indentation",
+                number
+            ));
+        }
+        let text = strip_comment(content).trim_end();
+        if text.is_empty() || (lines.is_empty() && text == "---") {
+            continue;
+        }
+        lines.push(Line {
+            number,
+            indent: raw.len() - content.len(),
+            text,
+        });
+    }
+
+    let Some(indent) = lines.first().map(|line| line.indent) else {
+        return Ok(Value::Null);
+    };
+    let mut parser = Parser {
+        source_lines: source.lines().collect(),
+        lines,
+        pos: 0,
+    };
+    let value = parser.block(indent)?;
+    match parser.lines.get(parser.pos) {
+        Some(line) => Err(format!("line {}: unexpected indentation", line.number)),
+        None => Ok(value),
+    }
+}
+
+struct Parser<'a> {
+    source_lines: Vec<&'a str>,
+    lines: Vec<Line<'a>>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn block(&mut self, indent: usize) -> Result<Value, String> {
+        let line = &self.lines[self.pos];
+        if is_sequence_item(line.text) {
+            self.sequence(indent)
+        } else {
+            self.mapping(indent)
+        }
+    }
+
+    fn sequence(&mut self, indent: usize) -> Result<Value, String> {
+        let mut items = Vec::new();
+        while let Some(line) = self.lines.get(self.pos).cloned() {
+            if line.indent != indent || !is_sequence_item(line.text) {
+                break;
+            }
+            let rest = line.text[1..].trim_start();
+            if rest.is_empty() {
+                self.pos += 1;
+                items.push(self.nested(indent, line.number)?);
+            } else if split_key(rest).is_some() {
+                // `- key: value` starts a mapping indented past the dash
+                let offset = line.text.len() - rest.len();
+                self.lines[self.pos] = Line {
+                    indent: indent + offset,
+                    text: rest,
+                    ..line
+                };
+                items.push(self.mapping(indent + offset)?);
+            } else {
+                self.pos += 1;
+                items.push(self.value(rest, indent, line.number)?);
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn mapping(&mut self, indent: usize) -> Result<Value, String> {
+        let mut map = Map::new();
+        while let Some(line) = self.lines.get(self.pos).cloned() {
+            if line.indent != indent || is_sequence_item(line.text) {
+                break;
+            }
+            let (key, rest) = split_key(line.text)
+                .ok_or_else(|| format!("line {}: expected \"key: value\"", line.number))?;
+            let key = match scalar(key, line.number)? {
+                Value::String(key) => key,
+                value => value.to_string(),
+            };
+            self.pos += 1;
+            let value = if rest.is_empty() {
+                // A sequence may start at the indentation of its key
+                match self.lines.get(self.pos) {
+                    Some(next) if next.indent == indent && is_sequence_item(next.text) => {
+                        self.sequence(indent)?
+                    }
+                    _ => self.nested(indent, line.number)?,
+                }
+            } else {
+                self.value(rest, indent, line.number)?
+            };
+            if map.insert(key.clone(), value).is_some() {
+                return Err(format!("line {}: duplicate key \"{}\"", line.number, key));
+            }
+        }
+        Ok(Value::Object(map))
+    }
+
+    /// The block indented under a line, or null.
+    fn nested(&mut self, indent: usize, number: usize) -> Result<Value, String> {
+        match self.lines.get(self.pos) {
+            Some(next) if next.indent > indent => self.block(next.indent),
+            Some(next) if next.number == number => {
+                Err(format!("line {}: unexpected value", number))
+            }
+            _ => Ok(Value::Null),
+        }
+    }
+
+    /// An inline value, or a block scalar introduced by `|` or `>`.
+    fn value(&mut self, text: &str, indent: usize, number: usize) -> Result<Value, String> {
+        let Some(style) = text.chars().next().filter(|c| matches!(c, '|' | '>')) else {
+            return scalar(text, number);
+        };
+        let chomping = &text[1..];
+        if !matches!(chomping, "" | "-" | "+") {
+            return Err(format!("line {}: unsupported block scalar header", number));
+        }
+
+        // Block scalars keep blank lines and `#`, so read the raw source
+        let mut raw = Vec::new();
+        let mut next_number = number + 1;
+        while let Some(source) = self.source_lines.get(next_number - 1) {
+            let line_indent = source.len() - source.trim_start_matches(' ').len();
+            if !source.trim().is_empty() && line_indent <= indent {
+                break;
+            }
+            raw.push(*source);
+            next_number += 1;
+        }
+        while self
+            .lines
+            .get(self.pos)
+            .is_some_and(|line| line.number < next_number)
+        {
+            self.pos += 1;
+        }
+        while raw.last().is_some_and(|line| line.trim().is_empty()) && chomping != "+" {
+            raw.pop();
+        }
+
+        let block_indent = raw
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start_matches(' ').len())
+            .min()
+            .unwrap_or(0);
+        let lines: Vec<&str> = raw
+            .iter()
+            .map(|line| line.get(block_indent..).unwrap_or(""))
+            .collect();
+        let mut content = if style == '|' {
+            lines.join("\n")
+        } else {
+            fold(&lines)
+        };
+        if chomping != "-" && !content.is_empty() {
+            content.push('\n');
+        }
+        Ok(Value::String(content))
+    }
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Split `key: value` at the first colon outside quotes.
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) if i == 0 => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            (':', None) => {
+                let rest = &text[i + 1..];
+                if rest.is_empty() || rest.starts_with(' ') {
+                    return Some((text[..i].trim(), rest.trim()));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Remove a `#` comment that is outside quotes.
+fn strip_comment(text: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in text.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) if matches!(previous, ' ' | ':' | '-' | '[' | '{' | ',') => {
+                quote = Some(c)
+            }
+            ('"', Some('"')) if previous == '\\' => {}
+            (c, Some(q)) if c == q => quote = None,
+            ('#', None) if previous == ' ' => return &text[..i],
+            _ => {}
+        }
+        previous = c;
+    }
+    text
+}
+
+fn scalar(text: &str, number: usize) -> Result<Value, String> {
+    let text = text.trim();
+    if let Some(inner) = text.strip_prefix('"') {
+        let inner = inner
+            .strip_suffix('"')
+            .ok_or_else(|| format!("line {}: unterminated string", number))?;
+        return serde_json::from_str(&format!("\"{}\"", inner))
+            .map_err(|_| format!("line {}: invalid escape in string", number));
+    }
+    if let Some(inner) = text.strip_prefix('\'') {
+        let inner = inner
+            .strip_suffix('\'')
+            .ok_or_else(|| format!("line {}: unterminated string", number))?;
+        return Ok(Value::String(inner.replace("''", "'")));
+    }
+    match text {
+        "{}" => return Ok(Value::Object(Map::new())),
+        "[]" => return Ok(Value::Array(Vec::new())),
+        "~" | "null" | "Null" | "NULL" => return Ok(Value::Null),
+        "true" | "True" | "TRUE" => return Ok(Value::Bool(true)),
+        "false" | "False" | "FALSE" => return Ok(Value::Bool(false)),
+        _ => {}
+    }
+    if text.starts_with(['{', '[', '&', '*', '!', '%', '@', '`']) {
+        return Err(format!(
+            "line {}: unsupported YAML syntax \"{}\"",
+            number, text
+        ));
+    }
+    if let Ok(int) = text.parse::<i64>() {
+        return Ok(Value::Number(int.into()));
+    }
+    if text.contains(['.', 'e', 'E']) {
+        if let Some(float) = text.parse::<f64>().ok().and_then(Number::from_f64) {
+            return Ok(Value::Number(float));
+        }
+    }
+    Ok(Value::String(text.to_string()))
+}
+
+/// Fold the lines of a `>` block scalar.
+fn fold(lines: &[&str]) -> String {
+    let mut folded = String::new();
+    let mut previous_blank = true;
+    for line in lines {
+        if line.is_empty() {
+            folded.push('\n');
+            previous_blank = true;
+        } else {
+            if !previous_blank {
+                folded.push(' ');
+            }
+            folded.push_str(line);
+            previous_blank = false;
+        }
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_yaml() {
+        let value = parse_yaml(
+            r#"---
+# Greetings
+en:
+  hello: Hello {name}!   # trailing comment
+  quoted: "Say \"hi\" # not a comment"
+  single: 'It''s'
+  count: 3
+  ratio: 0.5
+  enabled: true
+  missing: ~
+  items:
+    - one
+    - 'two'
+  list:
+  - a: 1
+    b: 2
+  - plain
+  literal: |
+    Line one
+    Line two
+  folded: >-
+    Folded
+    text
+  empty: {}
+ja:
+  hello: こんにちは
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "en": {
+                    "hello": "Hello {name}!",
+                    "quoted": "Say \"hi\" # not a comment",
+                    "single": "It's",
+                    "count": 3,
+                    "ratio": 0.5,
+                    "enabled": true,
+                    "missing": null,
+                    "items": ["one", "two"],
+                    "list": [{ "a": 1, "b": 2 }, "plain"],
+                    "literal": "Line one\nLine two\n",
+                    "folded": "Folded text",
+                    "empty": {}
+                },
+                "ja": { "hello": "こんにちは" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_yaml_errors() {
+        assert!(parse_yaml("a:\n\tb: 1").unwrap_err().contains("tabs"));
+        assert!(parse_yaml("a: 1\na: 2")
+            .unwrap_err()
+            .contains("duplicate key"));
+        assert!(parse_yaml("a: [1, 2]").unwrap_err().contains("unsupported"));
+        assert!(parse_yaml("a: 1\n   b: 2")
+            .unwrap_err()
+            .contains("unexpected indentation"));
+        assert!(parse_yaml("just text").unwrap_err().contains("key: value"));
+        assert_eq!(parse_yaml("# only a comment").unwrap(), Value::Null);
+    }
+}
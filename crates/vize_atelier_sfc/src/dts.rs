@@ -0,0 +1,699 @@
+//! Type declaration (`.d.ts`) generation.
+//!
+//! Builds the public type of a component from the `<script setup>` macro
+//! analysis of `vize_croquis`, in the shape `vue-tsc --declaration` emits:
+//! a `DefineComponent` wrapped with `__VLS_WithTemplateSlots`, or a generic
+//! functional signature for `<script setup generic="...">`.
+//!
+//! Type text is copied from the source as written, together with the
+//! imports and local type declarations it refers to, so user type
+//! references stay intact.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    BindingPatternKind, Declaration, Expression, FormalParameters, Statement, TSTypeAnnotation,
+    TSTypeParameterDeclaration, TSTypeParameterInstantiation,
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use vize_carton::FxHashMap;
+use vize_croquis::macros::{MacroKind, MacroTracker};
+
+use crate::script::is_valid_identifier;
+use crate::types::SfcDescriptor;
+
+/// Generate the `.d.ts` declaration of a component.
+///
+/// Components without `<script setup>` are declared with untyped props, as
+/// their options object is not analyzed.
+pub fn generate_dts(descriptor: &SfcDescriptor<'_>) -> String {
+    let mut scope = ModuleScope::default();
+    if let Some(script) = &descriptor.script {
+        scope.collect(&script.content);
+    }
+
+    let template_slots = descriptor
+        .template
+        .as_ref()
+        .map(|template| template_slots(&template.content))
+        .unwrap_or_default();
+
+    let Some(script_setup) = &descriptor.script_setup else {
+        let api = ComponentTypes {
+            props: "{}".into(),
+            slots: slots_from_template(&template_slots),
+            ..ComponentTypes::default()
+        };
+        return render(&scope, &api, None);
+    };
+
+    scope.collect(&script_setup.content);
+    let result = vize_croquis::script_parser::parse_script_setup(&script_setup.content);
+    let macros = &result.macros;
+
+    let mut api = ComponentTypes {
+        props: props_type(macros),
+        emits: emits(macros),
+        slots: match macros.define_slots().and_then(|c| c.type_args.as_deref()) {
+            Some(type_args) => Some(strip_angle_brackets(type_args).to_string()),
+            None => slots_from_template(&template_slots),
+        },
+        exposed: None,
+    };
+    if macros.define_expose().is_some() {
+        let members: Vec<String> = macros
+            .exposes()
+            .iter()
+            .map(|expose| {
+                let ty = expose
+                    .expose_type
+                    .as_deref()
+                    .or_else(|| {
+                        scope
+                            .binding_types
+                            .get(expose.name.as_str())
+                            .map(String::as_str)
+                    })
+                    .unwrap_or("any");
+                format!("{}: {}", property_key(&expose.name), ty)
+            })
+            .collect();
+        api.exposed = Some(type_literal(&members));
+    }
+
+    let generic = script_setup
+        .attrs
+        .get("generic")
+        .map(|g| g.trim())
+        .filter(|g| !g.is_empty());
+    render(&scope, &api, generic)
+}
+
+/// Public types of a component, as TypeScript source.
+#[derive(Debug, Default)]
+struct ComponentTypes {
+    props: String,
+    emits: Vec<EmitType>,
+    slots: Option<String>,
+    exposed: Option<String>,
+}
+
+/// A declared event and its payload type.
+#[derive(Debug)]
+struct EmitType {
+    name: String,
+    payload: Option<String>,
+}
+
+/// Props type: the `defineProps` type argument as written, or a type
+/// literal built from runtime declarations, plus `defineModel` props.
+fn props_type(macros: &MacroTracker) -> String {
+    let declared = match macros.define_props().and_then(|c| c.type_args.as_deref()) {
+        Some(type_args) => Some(strip_angle_brackets(type_args).to_string()),
+        None if !macros.props().is_empty() => {
+            let members: Vec<String> = macros
+                .props()
+                .iter()
+                .map(|prop| {
+                    format!(
+                        "{}{}: {}",
+                        property_key(&prop.name),
+                        if prop.required { "" } else { "?" },
+                        prop.prop_type.as_deref().unwrap_or("any")
+                    )
+                })
+                .collect();
+            Some(type_literal(&members))
+        }
+        None => None,
+    };
+
+    let models: Vec<String> = model_types(macros)
+        .map(|(name, ty)| format!("{}?: {}", property_key(name), ty))
+        .collect();
+
+    match (declared, models.is_empty()) {
+        (Some(declared), true) => declared,
+        (Some(declared), false) => format!("{} & {}", declared, type_literal(&models)),
+        (None, false) => type_literal(&models),
+        (None, true) => "{}".to_string(),
+    }
+}
+
+/// Declared events, including the `update:*` events of `defineModel`.
+fn emits(macros: &MacroTracker) -> Vec<EmitType> {
+    let mut emits: Vec<EmitType> = macros
+        .emits()
+        .iter()
+        .map(|emit| EmitType {
+            name: emit.name.to_string(),
+            payload: emit.payload_type.as_ref().map(|p| p.to_string()),
+        })
+        .collect();
+    emits.extend(model_types(macros).map(|(name, ty)| EmitType {
+        name: format!("update:{}", name),
+        payload: Some(ty.to_string()),
+    }));
+    emits
+}
+
+/// `defineModel` names with their type argument, in declaration order.
+fn model_types(macros: &MacroTracker) -> impl Iterator<Item = (&str, &str)> {
+    let calls = macros
+        .all_calls()
+        .iter()
+        .filter(|call| call.kind == MacroKind::DefineModel);
+    macros.models().iter().zip(calls).map(|(model, call)| {
+        let ty = model
+            .model_type
+            .as_deref()
+            .or_else(|| call.type_args.as_deref().map(strip_angle_brackets))
+            .unwrap_or("any");
+        (model.name.as_str(), ty)
+    })
+}
+
+/// Slot names rendered by `<slot>` elements of the template.
+fn template_slots(template: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = template;
+    while let Some(pos) = rest.find("<slot") {
+        rest = &rest[pos + 5..];
+        if !rest.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+            continue;
+        }
+        let tag = &rest[..rest.find('>').unwrap_or(rest.len())];
+        let name = tag
+            .split_whitespace()
+            .find_map(|attr| attr.strip_prefix("name="))
+            .map(|value| value.trim_end_matches('/').trim_matches(['"', '\'']))
+            .unwrap_or("default");
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Slots type for slots only known from the template.
+fn slots_from_template(names: &[String]) -> Option<String> {
+    if names.is_empty() {
+        return None;
+    }
+    let members: Vec<String> = names
+        .iter()
+        .map(|name| format!("{}?: (props: any) => any", property_key(name)))
+        .collect();
+    Some(type_literal(&members))
+}
+
+/// Render the declaration file.
+fn render(scope: &ModuleScope, api: &ComponentTypes, generic: Option<&str>) -> String {
+    let (params, args) = match generic {
+        Some(generic) => (
+            format!("<{}>", generic),
+            format!("<{}>", generic_names(generic)),
+        ),
+        None => (String::new(), String::new()),
+    };
+
+    let mut body = String::with_capacity(1024);
+    body.push_str(&format!("type __VLS_Props{} = {};\n", params, api.props));
+    let emit_handlers: Vec<String> = api
+        .emits
+        .iter()
+        .map(|emit| {
+            format!(
+                "{}: {}",
+                property_key(&emit.name),
+                handler_type(emit.payload.as_deref())
+            )
+        })
+        .collect();
+    body.push_str(&format!(
+        "type __VLS_Emits{} = {};\n",
+        params,
+        type_literal(&emit_handlers)
+    ));
+    if let Some(slots) = &api.slots {
+        body.push_str(&format!("type __VLS_Slots{} = {};\n", params, slots));
+    }
+    body.push_str(&format!(
+        "type __VLS_Exposed{} = {};\n",
+        params,
+        api.exposed.as_deref().unwrap_or("{}")
+    ));
+    body.push('\n');
+
+    if generic.is_some() {
+        render_generic(&mut body, api, &params, &args);
+    } else {
+        render_define_component(&mut body, api);
+    }
+    body.push_str("export default _default;\n");
+
+    let mut out = String::with_capacity(body.len() + 256);
+    for import in &scope.imports {
+        if import
+            .locals
+            .iter()
+            .any(|local| mentions(&body, local) || scope.types.iter().any(|ty| mentions(ty, local)))
+        {
+            out.push_str(&import.text);
+            out.push('\n');
+        }
+    }
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    for ty in &scope.types {
+        out.push_str(ty);
+        out.push('\n');
+    }
+    if !scope.types.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(&body);
+    out
+}
+
+/// `DefineComponent` declaration, with template slots on the instance type.
+fn render_define_component(out: &mut String, api: &ComponentTypes) {
+    out.push_str(
+        "declare const __VLS_component: import('vue').DefineComponent<__VLS_Props, __VLS_Exposed, {}, {}, {}, \
+         import('vue').ComponentOptionsMixin, import('vue').ComponentOptionsMixin, __VLS_Emits>;\n",
+    );
+    if api.slots.is_some() {
+        out.push_str(
+            "declare const _default: __VLS_WithTemplateSlots<typeof __VLS_component, __VLS_Slots>;\n\n\
+             type __VLS_WithTemplateSlots<T, S> = T & {\n    new (): {\n        $slots: S;\n    };\n};\n",
+        );
+    } else {
+        out.push_str("declare const _default: typeof __VLS_component;\n");
+    }
+}
+
+/// Generic components are declared as a function of their props, since a
+/// `DefineComponent` cannot carry type parameters.
+fn render_generic(out: &mut String, api: &ComponentTypes, params: &str, args: &str) {
+    let emit_props: Vec<String> = api
+        .emits
+        .iter()
+        .map(|emit| {
+            format!(
+                "{}?: {}",
+                property_key(&format!("on{}", capitalize(&emit.name))),
+                handler_type(emit.payload.as_deref())
+            )
+        })
+        .collect();
+    let emit_signatures: Vec<String> = api
+        .emits
+        .iter()
+        .map(|emit| match &emit.payload {
+            Some(payload) => format!("(e: '{}', payload: {}): void", emit.name, payload),
+            None => format!("(e: '{}', ...args: any[]): void", emit.name),
+        })
+        .collect();
+    let slots = match api.slots {
+        Some(_) => format!("__VLS_Slots{}", args),
+        None => "{}".to_string(),
+    };
+    let exposed = format!("import('vue').ShallowUnwrapRef<__VLS_Exposed{}>", args);
+    let emit = type_literal(&emit_signatures);
+
+    out.push_str(&format!(
+        "declare const _default: {params}(\n    \
+         __VLS_props: __VLS_Props{args} & {emit_props} & import('vue').PublicProps,\n    \
+         __VLS_ctx?: {{ attrs: any; slots: {slots}; emit: {emit} }},\n    \
+         __VLS_expose?: (exposed: {exposed}) => void,\n\
+         ) => import('vue').VNode & {{\n    \
+         __ctx?: {{\n        \
+         props: __VLS_Props{args} & {emit_props};\n        \
+         expose(exposed: {exposed}): void;\n        \
+         attrs: any;\n        \
+         slots: {slots};\n        \
+         emit: {emit};\n    \
+         }};\n\
+         }};\n",
+        emit_props = type_literal(&emit_props),
+    ));
+}
+
+/// Imports and type declarations of the component's scripts, and the types
+/// of local bindings that may be exposed.
+#[derive(Debug, Default)]
+struct ModuleScope {
+    imports: Vec<ImportText>,
+    types: Vec<String>,
+    binding_types: FxHashMap<String, String>,
+}
+
+/// An import statement with the local names it binds.
+#[derive(Debug)]
+struct ImportText {
+    text: String,
+    locals: Vec<String>,
+}
+
+impl ModuleScope {
+    fn collect(&mut self, source: &str) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::ts()).parse();
+        if ret.panicked {
+            return;
+        }
+
+        for stmt in ret.program.body.iter() {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    let Some(specifiers) = decl.specifiers.as_ref().filter(|s| !s.is_empty())
+                    else {
+                        continue;
+                    };
+                    self.imports.push(ImportText {
+                        text: text(source, decl.span).to_string(),
+                        locals: specifiers
+                            .iter()
+                            .map(|s| s.local().name.to_string())
+                            .collect(),
+                    });
+                }
+                Statement::TSInterfaceDeclaration(_)
+                | Statement::TSTypeAliasDeclaration(_)
+                | Statement::TSEnumDeclaration(_) => {
+                    self.types.push(declared(text(source, stmt.span())));
+                }
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(
+                        Declaration::TSInterfaceDeclaration(_)
+                        | Declaration::TSTypeAliasDeclaration(_)
+                        | Declaration::TSEnumDeclaration(_),
+                    ) => self.types.push(declared(text(source, export.span))),
+                    Some(Declaration::FunctionDeclaration(func)) => {
+                        self.function_binding(source, func)
+                    }
+                    Some(Declaration::VariableDeclaration(decl)) => {
+                        self.variable_bindings(source, decl)
+                    }
+                    _ => {}
+                },
+                Statement::FunctionDeclaration(func) => self.function_binding(source, func),
+                Statement::VariableDeclaration(decl) => self.variable_bindings(source, decl),
+                _ => {}
+            }
+        }
+    }
+
+    fn function_binding(&mut self, source: &str, func: &oxc_ast::ast::Function<'_>) {
+        if let Some(id) = &func.id {
+            self.binding_types.insert(
+                id.name.to_string(),
+                function_type(
+                    source,
+                    func.type_parameters.as_deref(),
+                    &func.params,
+                    func.return_type.as_deref(),
+                ),
+            );
+        }
+    }
+
+    fn variable_bindings(&mut self, source: &str, decl: &oxc_ast::ast::VariableDeclaration<'_>) {
+        for declarator in decl.declarations.iter() {
+            let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                continue;
+            };
+            let ty = match (&declarator.id.type_annotation, &declarator.init) {
+                (Some(annotation), _) => {
+                    Some(text(source, annotation.type_annotation.span()).to_string())
+                }
+                (None, Some(init)) => expression_type(source, init),
+                (None, None) => None,
+            };
+            if let Some(ty) = ty {
+                self.binding_types.insert(id.name.to_string(), ty);
+            }
+        }
+    }
+}
+
+/// Type of an initializer, when it is evident from the source.
+fn expression_type(source: &str, init: &Expression<'_>) -> Option<String> {
+    match init {
+        Expression::ArrowFunctionExpression(arrow) => Some(function_type(
+            source,
+            arrow.type_parameters.as_deref(),
+            &arrow.params,
+            arrow.return_type.as_deref(),
+        )),
+        Expression::FunctionExpression(func) => Some(function_type(
+            source,
+            func.type_parameters.as_deref(),
+            &func.params,
+            func.return_type.as_deref(),
+        )),
+        Expression::CallExpression(call) => {
+            let Expression::Identifier(callee) = &call.callee else {
+                return None;
+            };
+            let wrapper = match callee.name.as_str() {
+                "ref" => "Ref",
+                "shallowRef" => "ShallowRef",
+                "computed" => "ComputedRef",
+                _ => return None,
+            };
+            let arg = type_argument(source, call.type_parameters.as_deref())?;
+            Some(format!("import('vue').{}<{}>", wrapper, arg))
+        }
+        Expression::TSAsExpression(ts_as) => {
+            Some(text(source, ts_as.type_annotation.span()).to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Function type from a function's signature, `any` for a missing return type.
+fn function_type(
+    source: &str,
+    type_parameters: Option<&TSTypeParameterDeclaration<'_>>,
+    params: &FormalParameters<'_>,
+    return_type: Option<&TSTypeAnnotation<'_>>,
+) -> String {
+    format!(
+        "{}{} => {}",
+        type_parameters.map_or("", |tp| text(source, tp.span)),
+        text(source, params.span),
+        return_type.map_or("any", |ret| text(source, ret.type_annotation.span()))
+    )
+}
+
+/// Source of the single type argument of a call.
+fn type_argument<'s>(
+    source: &'s str,
+    args: Option<&TSTypeParameterInstantiation<'_>>,
+) -> Option<&'s str> {
+    match args?.params.as_slice() {
+        [arg] => Some(text(source, arg.span())),
+        _ => None,
+    }
+}
+
+/// Make a type declaration ambient (`enum` needs `declare` in a `.d.ts`).
+fn declared(text: &str) -> String {
+    match text.strip_prefix("export enum ") {
+        Some(rest) => format!("export declare enum {}", rest),
+        None => match text.strip_prefix("enum ") {
+            Some(rest) => format!("declare enum {}", rest),
+            None => text.to_string(),
+        },
+    }
+}
+
+/// Handler type for an event payload.
+fn handler_type(payload: Option<&str>) -> String {
+    match payload {
+        Some(payload) => format!("(payload: {}) => any", payload),
+        None => "(...args: any[]) => any".to_string(),
+    }
+}
+
+/// `{ a: A; b: B }`, or `{}` with no members.
+fn type_literal(members: &[String]) -> String {
+    if members.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", members.join("; "))
+    }
+}
+
+/// Object type key, quoted unless it is an identifier.
+fn property_key(name: &str) -> String {
+    if is_valid_identifier(name) {
+        name.to_string()
+    } else {
+        format!("'{}'", name.replace('\'', "\\'"))
+    }
+}
+
+/// Names of the parameters in a `generic` attribute
+/// (`T extends Item, K = keyof T` -> `T, K`).
+fn generic_names(generic: &str) -> String {
+    let mut names = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in generic.char_indices() {
+        match c {
+            '<' | '(' | '[' | '{' => depth += 1,
+            '>' if i > 0 && generic[..i].ends_with('=') => {}
+            '>' | ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                names.push(&generic[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    names.push(&generic[start..]);
+    names
+        .iter()
+        .filter_map(|param| {
+            param
+                .trim()
+                .trim_start_matches("const ")
+                .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                .next()
+                .filter(|name| !name.is_empty())
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Whether `source` refers to the identifier `name`.
+fn mentions(source: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    source.match_indices(name).any(|(pos, _)| {
+        !source[..pos].ends_with(is_ident) && !source[pos + name.len()..].starts_with(is_ident)
+    })
+}
+
+fn strip_angle_brackets(type_args: &str) -> &str {
+    type_args
+        .trim()
+        .strip_prefix('<')
+        .and_then(|s| s.strip_suffix('>'))
+        .unwrap_or(type_args)
+        .trim()
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn text(source: &str, span: Span) -> &str {
+    source
+        .get(span.start as usize..span.end as usize)
+        .unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_sfc;
+
+    fn dts(source: &str) -> String {
+        let descriptor = parse_sfc(source, Default::default()).unwrap();
+        generate_dts(&descriptor)
+    }
+
+    #[test]
+    fn test_dts_type_based_component() {
+        let out = dts(r#"<script setup lang="ts">
+import type { Item } from './types'
+import { ref } from 'vue'
+
+export interface Props {
+  items: Item[]
+  size?: 'sm' | 'lg'
+}
+
+const props = defineProps<Props>()
+const emit = defineEmits<{ (e: 'select', item: Item): void; (e: 'close'): void }>()
+const open = ref<boolean>(false)
+function toggle(force?: boolean): void {}
+defineExpose({ open, toggle })
+</script>
+<template><slot name="header" /><slot :items="items" /></template>"#);
+
+        assert!(out.contains("import type { Item } from './types'"));
+        assert!(!out.contains("import { ref }"));
+        assert!(out.contains("export interface Props {"));
+        assert!(out.contains("type __VLS_Props = Props;"));
+        assert!(out.contains(
+            "type __VLS_Emits = { select: (payload: Item) => any; close: (...args: any[]) => any };"
+        ));
+        assert!(out.contains(
+            "type __VLS_Slots = { header?: (props: any) => any; default?: (props: any) => any };"
+        ));
+        assert!(out.contains(
+            "type __VLS_Exposed = { open: import('vue').Ref<boolean>; toggle: (force?: boolean) => void };"
+        ));
+        assert!(out.contains(
+            "declare const _default: __VLS_WithTemplateSlots<typeof __VLS_component, __VLS_Slots>;"
+        ));
+        assert!(out.ends_with("export default _default;\n"));
+    }
+
+    #[test]
+    fn test_dts_runtime_props_and_models() {
+        let out = dts(r#"<script setup>
+defineProps({ title: { type: String, required: true }, count: Number })
+const model = defineModel<string>()
+defineEmits(['change'])
+</script>"#);
+
+        assert!(out.contains(
+            "type __VLS_Props = { title: string; count?: number } & { modelValue?: string };"
+        ));
+        assert!(out.contains("'update:modelValue': (payload: string) => any"));
+        assert!(out.contains("declare const _default: typeof __VLS_component;"));
+        assert!(!out.contains("__VLS_Slots"));
+    }
+
+    #[test]
+    fn test_dts_generic_component() {
+        let out = dts(
+            r#"<script setup lang="ts" generic="T extends { id: string }, K = keyof T">
+defineProps<{ items: T[]; by: K }>()
+defineEmits<{ pick: [item: T] }>()
+defineSlots<{ item(props: { item: T }): any }>()
+</script>"#,
+        );
+
+        assert!(out.contains(
+            "type __VLS_Props<T extends { id: string }, K = keyof T> = { items: T[]; by: K };"
+        ));
+        assert!(out.contains(
+            "type __VLS_Slots<T extends { id: string }, K = keyof T> = { item(props: { item: T }): any };"
+        ));
+        assert!(out.contains(
+            "__VLS_props: __VLS_Props<T, K> & { onPick?: (payload: T) => any } & import('vue').PublicProps,"
+        ));
+        assert!(out.contains("emit: { (e: 'pick', payload: T): void }"));
+        assert!(!out.contains("DefineComponent"));
+    }
+
+    #[test]
+    fn test_generic_names() {
+        assert_eq!(generic_names("T"), "T");
+        assert_eq!(
+            generic_names("T extends Record<string, number>, U = () => void"),
+            "T, U"
+        );
+        assert_eq!(generic_names("const T extends readonly string[]"), "T");
+    }
+}
@@ -0,0 +1,295 @@
+//! Hot update classification.
+//!
+//! [`diff_descriptors`] compares two parses of the same SFC and decides how
+//! a dev server can apply the edit, following the rules of
+//! `@vitejs/plugin-vue`'s `handleHotUpdate`: template edits rerender, style
+//! edits are swapped per block, and anything that changes the component's
+//! JavaScript (script, template bindings, `v-bind()` in styles, CSS modules,
+//! block layout) reloads it.
+
+use serde::Serialize;
+use vize_carton::{Bump, FxHashSet};
+
+use crate::script::{analyze_script_setup, resolve_template_used_identifiers};
+use crate::style::extract_css_vars;
+use crate::types::{
+    BindingMetadata, SfcDescriptor, SfcScriptBlock, SfcStyleBlock, SfcTemplateBlock,
+};
+
+/// Why an edit needs a full component reload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReloadReason {
+    /// `<script>` or `<script setup>` changed
+    Script,
+    /// The template uses a different set of `<script setup>` bindings
+    Bindings,
+    /// The `v-bind()` expressions in styles changed
+    CssVars,
+    /// Styles became scoped or stopped being scoped
+    Scoped,
+    /// A style block was added or removed
+    StyleBlocks,
+    /// A CSS module changed, so its class mapping may differ
+    CssModules,
+    /// A custom block was added or removed
+    CustomBlocks,
+}
+
+/// Summary of an update, from most to least disruptive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HmrUpdateKind {
+    /// Reload the component
+    FullReload,
+    /// Swap the render function (changed styles may be swapped as well)
+    TemplateOnly,
+    /// Swap the changed style blocks
+    StyleOnly,
+    /// Reload the changed custom blocks
+    CustomBlock,
+    /// Nothing that affects the output changed
+    None,
+}
+
+/// Update needed to apply an edit to a running component.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmrUpdate {
+    /// Why the component must be reloaded, if it must
+    pub reload: Option<ReloadReason>,
+    /// Whether the template changed
+    pub rerender: bool,
+    /// Indices of the changed style blocks
+    pub styles: Vec<usize>,
+    /// Indices of the changed custom blocks
+    pub custom_blocks: Vec<usize>,
+}
+
+impl HmrUpdate {
+    /// The most disruptive update required.
+    pub fn kind(&self) -> HmrUpdateKind {
+        if self.reload.is_some() {
+            HmrUpdateKind::FullReload
+        } else if self.rerender {
+            HmrUpdateKind::TemplateOnly
+        } else if !self.styles.is_empty() {
+            HmrUpdateKind::StyleOnly
+        } else if !self.custom_blocks.is_empty() {
+            HmrUpdateKind::CustomBlock
+        } else {
+            HmrUpdateKind::None
+        }
+    }
+}
+
+/// Classify the edit from `prev` to `next`.
+///
+/// Blocks are compared by content and attributes, so external `src` blocks
+/// should be resolved in both descriptors or in neither.
+pub fn diff_descriptors(prev: &SfcDescriptor<'_>, next: &SfcDescriptor<'_>) -> HmrUpdate {
+    let mut update = HmrUpdate::default();
+    let mut reasons = Vec::new();
+
+    let script_changed = !same_script(prev.script.as_ref(), next.script.as_ref())
+        || !same_script(prev.script_setup.as_ref(), next.script_setup.as_ref());
+    if script_changed {
+        reasons.push(ReloadReason::Script);
+    }
+
+    update.rerender = !same_template(prev.template.as_ref(), next.template.as_ref());
+    // The render function is inlined into `setup()`, which only exposes the
+    // bindings the template uses
+    if update.rerender && !script_changed {
+        if let Some(script_setup) = &next.script_setup {
+            let bindings = analyze_script_setup(&script_setup.content);
+            if template_bindings(prev, &bindings) != template_bindings(next, &bindings) {
+                reasons.push(ReloadReason::Bindings);
+            }
+        }
+    }
+
+    if css_vars(&prev.styles) != css_vars(&next.styles) {
+        reasons.push(ReloadReason::CssVars);
+    }
+    if prev.styles.iter().any(|s| s.scoped) != next.styles.iter().any(|s| s.scoped) {
+        reasons.push(ReloadReason::Scoped);
+    }
+    if prev.styles.len() != next.styles.len() {
+        reasons.push(ReloadReason::StyleBlocks);
+    }
+    for (index, style) in next.styles.iter().enumerate() {
+        let Some(prev_style) = prev.styles.get(index) else {
+            continue;
+        };
+        if !same_style(prev_style, style) {
+            update.styles.push(index);
+            if prev_style.module.is_some() || style.module.is_some() {
+                reasons.push(ReloadReason::CssModules);
+            }
+        }
+    }
+
+    if prev.custom_blocks.len() != next.custom_blocks.len() {
+        reasons.push(ReloadReason::CustomBlocks);
+    } else {
+        for (index, (prev_block, next_block)) in prev
+            .custom_blocks
+            .iter()
+            .zip(&next.custom_blocks)
+            .enumerate()
+        {
+            if prev_block.block_type != next_block.block_type
+                || prev_block.content != next_block.content
+                || prev_block.attrs != next_block.attrs
+            {
+                update.custom_blocks.push(index);
+            }
+        }
+    }
+
+    update.reload = reasons.first().copied();
+    update
+}
+
+fn same_template(a: Option<&SfcTemplateBlock<'_>>, b: Option<&SfcTemplateBlock<'_>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            a.content == b.content && a.lang == b.lang && a.src == b.src && a.attrs == b.attrs
+        }
+        (a, b) => a.is_none() && b.is_none(),
+    }
+}
+
+fn same_script(a: Option<&SfcScriptBlock<'_>>, b: Option<&SfcScriptBlock<'_>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            a.content == b.content
+                && a.lang == b.lang
+                && a.src == b.src
+                && a.setup == b.setup
+                && a.attrs == b.attrs
+        }
+        (a, b) => a.is_none() && b.is_none(),
+    }
+}
+
+fn same_style(a: &SfcStyleBlock<'_>, b: &SfcStyleBlock<'_>) -> bool {
+    a.content == b.content
+        && a.lang == b.lang
+        && a.src == b.src
+        && a.scoped == b.scoped
+        && a.module == b.module
+        && a.attrs == b.attrs
+}
+
+fn css_vars(styles: &[SfcStyleBlock<'_>]) -> Vec<String> {
+    styles
+        .iter()
+        .flat_map(|style| extract_css_vars(&style.content))
+        .collect()
+}
+
+/// Setup bindings referenced by the template.
+fn template_bindings<'b>(
+    descriptor: &SfcDescriptor<'_>,
+    bindings: &'b BindingMetadata,
+) -> FxHashSet<&'b str> {
+    let Some(template) = &descriptor.template else {
+        return FxHashSet::default();
+    };
+    let allocator = Bump::new();
+    let (root, _) = vize_atelier_core::parser::parse(&allocator, &template.content);
+    let used = resolve_template_used_identifiers(&root).used_ids;
+    bindings
+        .bindings
+        .keys()
+        .filter(|name| used.contains(name.as_str()))
+        .map(String::as_str)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_sfc, SfcParseOptions};
+
+    fn diff(prev: &str, next: &str) -> HmrUpdate {
+        let prev = parse_sfc(prev, SfcParseOptions::default()).unwrap();
+        let next = parse_sfc(next, SfcParseOptions::default()).unwrap();
+        diff_descriptors(&prev, &next)
+    }
+
+    const SFC: &str = r#"<script setup>
+import { ref } from 'vue'
+import Badge from './Badge.vue'
+const count = ref(0)
+</script>
+<template><button @click="count++">{{ count }}</button></template>
+<style scoped>.a { color: red; }</style>
+<style>.b { color: blue; }</style>
+<i18n>{ "en": {} }</i18n>"#;
+
+    #[test]
+    fn test_unchanged() {
+        let update = diff(SFC, SFC);
+        assert_eq!(update, HmrUpdate::default());
+        assert_eq!(update.kind(), HmrUpdateKind::None);
+    }
+
+    #[test]
+    fn test_template_only() {
+        let update = diff(SFC, &SFC.replace("{{ count }}", "Count: {{ count }}"));
+        assert_eq!(update.kind(), HmrUpdateKind::TemplateOnly);
+        assert!(update.rerender);
+        assert!(update.styles.is_empty());
+    }
+
+    #[test]
+    fn test_style_only() {
+        let update = diff(SFC, &SFC.replace("color: blue", "color: green"));
+        assert_eq!(update.kind(), HmrUpdateKind::StyleOnly);
+        assert_eq!(update.styles, vec![1]);
+    }
+
+    #[test]
+    fn test_custom_block() {
+        let update = diff(SFC, &SFC.replace(r#""en": {}"#, r#""ja": {}"#));
+        assert_eq!(update.kind(), HmrUpdateKind::CustomBlock);
+        assert_eq!(update.custom_blocks, vec![0]);
+    }
+
+    #[test]
+    fn test_full_reload() {
+        let reload = |next: String| diff(SFC, &next).reload;
+
+        assert_eq!(
+            reload(SFC.replace("ref(0)", "ref(1)")),
+            Some(ReloadReason::Script)
+        );
+        assert_eq!(
+            reload(SFC.replace("</button>", "</button><Badge />")),
+            Some(ReloadReason::Bindings)
+        );
+        assert_eq!(
+            reload(SFC.replace("color: red", "color: v-bind(color)")),
+            Some(ReloadReason::CssVars)
+        );
+        assert_eq!(
+            reload(SFC.replace("<style scoped>", "<style>")),
+            Some(ReloadReason::Scoped)
+        );
+        assert_eq!(
+            reload(SFC.replace("<style>.b { color: blue; }</style>", "")),
+            Some(ReloadReason::StyleBlocks)
+        );
+        assert_eq!(
+            reload(SFC.replace("<style>.b", "<style module>.b")),
+            Some(ReloadReason::CssModules)
+        );
+        assert_eq!(
+            reload(format!("{}\n<docs>Counter</docs>", SFC)),
+            Some(ReloadReason::CustomBlocks)
+        );
+    }
+}
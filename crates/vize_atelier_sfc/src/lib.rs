@@ -9,6 +9,12 @@
 //! - `compile` - Main SFC compilation orchestration
 //! - `style` - Style block compilation with scoped CSS
 //! - `css` - Low-level CSS compilation with LightningCSS
+//! - `custom_block` - Custom block transforms (`<i18n>`, `<docs>`, ...)
+//! - `dts` - Type declaration (`.d.ts`) generation
+//! - `hmr` - Hot update classification
+//! - `resolve` - External `src` block resolution
+//! - `preprocess` - SCSS, Sass and Less compilation (`preprocess` feature)
+//! - `cache` - Persistent compilation cache (`native` feature)
 //!
 //! # Example
 //!
@@ -38,20 +44,41 @@
 #![allow(clippy::only_used_in_recursion)]
 
 // Core modules - following Vue.js compiler-sfc structure
+#[cfg(feature = "native")]
+pub mod cache;
 pub mod compile;
 pub mod compile_script;
 pub mod compile_template;
 pub mod css;
+pub mod custom_block;
+pub mod dts;
+pub mod hmr;
 pub mod parse;
+#[cfg(feature = "preprocess")]
+pub mod preprocess;
+pub mod resolve;
 pub mod rewrite_default;
 pub mod script;
 pub mod style;
 pub mod types;
 
 // Re-exports for public API
+#[cfg(feature = "native")]
+pub use cache::{CacheStats, CompileCache};
 pub use compile::*;
-pub use css::{compile_css, compile_style_block, CssCompileOptions, CssCompileResult, CssTargets};
+pub use css::{
+    compile_css, compile_style_block, compile_style_block_with_fs, CssCompileOptions,
+    CssCompileResult, CssTargets,
+};
+pub use custom_block::{CustomBlockContext, CustomBlockTransform, CustomBlockTransforms};
+pub use dts::generate_dts;
+pub use hmr::{diff_descriptors, HmrUpdate, HmrUpdateKind, ReloadReason};
 pub use parse::*;
+#[cfg(feature = "preprocess")]
+pub use preprocess::{preprocess_styles, PreprocessOptions};
+#[cfg(feature = "native")]
+pub use resolve::NativeFileSystem;
+pub use resolve::{resolve_src_blocks, SfcFileSystem};
 pub use types::*;
 
 // Re-export key types from dependencies
@@ -0,0 +1,1459 @@
+//! Style preprocessing (`preprocess` feature).
+//!
+//! Compiles `<style lang="scss">`, `lang="sass"` and `lang="less"` blocks to
+//! plain CSS before scoping and `v-bind()` extraction run:
+//!
+//! - SCSS and indented Sass are compiled by [grass](https://docs.rs/grass)
+//! - Less is compiled by a built-in subset: variables (including `@{name}`
+//!   interpolation and `~"..."` escapes), nesting with `&`, `@media`
+//!   bubbling, mixins without parameters and `@import`. Operations and color
+//!   functions are passed through untouched.
+//!
+//! `@use` and `@import` paths resolve relative to the SFC (or to the external
+//! file for `<style src>`) through an [`SfcFileSystem`], and every loaded
+//! file is returned as a dependency. Output carries a source map back to the
+//! block content. grass does not emit maps, so SCSS and Sass maps are built
+//! per line by matching each output selector and property back to the block;
+//! lines that come from imported files stay unmapped.
+//!
+//! [`compile_sfc`](crate::compile_sfc) does not preprocess; blocks it sees
+//! with a preprocessor `lang` are reported as `UNPREPROCESSED_STYLE` warnings.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io;
+use std::path::Path;
+
+use vize_carton::FxHashMap;
+
+use crate::resolve::{resolve_src_path, SfcFileSystem};
+use crate::types::{SfcDescriptor, SfcError};
+
+/// Preprocessor language of a style block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StyleLang {
+    Scss,
+    Sass,
+    Less,
+}
+
+impl StyleLang {
+    /// Get the preprocessor for a `lang` attribute, if it has one
+    pub fn from_lang(lang: &str) -> Option<Self> {
+        match lang {
+            "scss" => Some(Self::Scss),
+            "sass" => Some(Self::Sass),
+            "less" => Some(Self::Less),
+            _ => None,
+        }
+    }
+}
+
+/// Style preprocessing options
+#[derive(Debug, Clone, Default)]
+pub struct PreprocessOptions {
+    /// Path of the file the style comes from; imports resolve relative to it
+    pub filename: String,
+
+    /// Code prepended to SCSS and Sass blocks (e.g. `@use "@/styles/vars" as *;`)
+    pub scss_additional_data: Option<String>,
+
+    /// Code prepended to Less blocks
+    pub less_additional_data: Option<String>,
+
+    /// Whether to generate a source map
+    pub source_map: bool,
+}
+
+impl PreprocessOptions {
+    fn additional_data(&self, lang: StyleLang) -> Option<&str> {
+        match lang {
+            StyleLang::Scss | StyleLang::Sass => self.scss_additional_data.as_deref(),
+            StyleLang::Less => self.less_additional_data.as_deref(),
+        }
+        .filter(|data| !data.is_empty())
+    }
+}
+
+/// Style preprocessing result
+#[derive(Debug, Clone, Default)]
+pub struct PreprocessResult {
+    /// Compiled CSS
+    pub code: String,
+
+    /// Source map (JSON) from the CSS back to the block content
+    pub map: Option<String>,
+
+    /// Imported files, in load order
+    pub dependencies: Vec<String>,
+}
+
+/// Compile the content of a preprocessor style block to CSS.
+pub fn preprocess_style(
+    source: &str,
+    lang: StyleLang,
+    options: &PreprocessOptions,
+    fs: &dyn SfcFileSystem,
+) -> Result<PreprocessResult, SfcError> {
+    let result = match lang {
+        StyleLang::Scss | StyleLang::Sass => compile_sass(source, lang, options, fs),
+        StyleLang::Less => compile_less(source, options, fs),
+    };
+    result.map_err(|message| SfcError {
+        message,
+        code: Some("PREPROCESS_ERROR".into()),
+        loc: None,
+    })
+}
+
+/// Compile every SCSS, Sass and Less style block of a descriptor in place.
+///
+/// Compiled blocks have their `lang` cleared, so scoping, `v-bind()` and
+/// CSS modules see plain CSS. Run after [`crate::resolve_src_blocks`] so
+/// external styles are preprocessed too. Returns the imported files.
+pub fn preprocess_styles(
+    descriptor: &mut SfcDescriptor<'_>,
+    options: &PreprocessOptions,
+    fs: &dyn SfcFileSystem,
+) -> Result<Vec<String>, SfcError> {
+    let filename = descriptor.filename.to_string();
+    let mut dependencies = Vec::new();
+
+    for style in &mut descriptor.styles {
+        let Some(lang) = style.lang.as_deref().and_then(StyleLang::from_lang) else {
+            continue;
+        };
+        let style_options = PreprocessOptions {
+            filename: match style.src.as_deref() {
+                Some(src) => resolve_src_path(&filename, src),
+                None => filename.clone(),
+            },
+            ..options.clone()
+        };
+        let result =
+            preprocess_style(&style.content, lang, &style_options, fs).map_err(|e| SfcError {
+                loc: Some(style.loc.clone()),
+                ..e
+            })?;
+
+        style.content = Cow::Owned(result.code);
+        style.lang = None;
+        for dependency in result.dependencies {
+            if !dependencies.contains(&dependency) {
+                dependencies.push(dependency);
+            }
+        }
+    }
+
+    Ok(dependencies)
+}
+
+/// Normalize a path for lookups (`/` separators, `.` and `..` folded).
+fn normalize_path(path: &str) -> String {
+    resolve_src_path("", &path.replace('\\', "/"))
+}
+
+// ============================================================================
+// SCSS / Sass (grass)
+// ============================================================================
+
+fn compile_sass(
+    source: &str,
+    lang: StyleLang,
+    options: &PreprocessOptions,
+    fs: &dyn SfcFileSystem,
+) -> Result<PreprocessResult, String> {
+    let entry = normalize_path(options.filename.as_str());
+    let entry = if entry.is_empty() {
+        "style.scss".to_string()
+    } else {
+        entry
+    };
+    let block = source;
+    let source = match options.additional_data(lang) {
+        Some(data) => format!("{}\n{}", data, block),
+        None => block.to_string(),
+    };
+
+    let files = SassFileSystem {
+        entry: &entry,
+        source: &source,
+        fs,
+        loaded: RefCell::default(),
+    };
+    let syntax = match lang {
+        StyleLang::Sass => grass::InputSyntax::Sass,
+        _ => grass::InputSyntax::Scss,
+    };
+    let grass_options = grass::Options::default()
+        .fs(&files)
+        .quiet(true)
+        .input_syntax(syntax);
+
+    let code = grass::from_path(&entry, &grass_options).map_err(|e| e.to_string())?;
+    let map = options
+        .source_map
+        .then(|| sass_source_map(&code, block, &entry));
+    Ok(PreprocessResult {
+        code,
+        map,
+        dependencies: files.dependencies(),
+    })
+}
+
+/// Line-level source map from grass output back to the block content.
+///
+/// Each output line is anchored by its last selector compound, at-rule name
+/// or property name and matched against the block, preferring matches after
+/// the previous one. Lines without a match (closing braces, declarations from
+/// imported mixins) are left unmapped.
+fn sass_source_map(code: &str, block: &str, filename: &str) -> String {
+    let mut mappings = Vec::new();
+    let mut cursor = 0;
+    for (gen_line, line) in code.lines().enumerate() {
+        let text = line.trim_start();
+        let found = sass_anchors(text).into_iter().find_map(|anchor| {
+            find_anchor(block, anchor, cursor).or_else(|| find_anchor(block, anchor, 0))
+        });
+        if let Some(offset) = found {
+            let (orig_line, orig_col) = line_col(block, offset);
+            let col = (line.len() - text.len()) as u32;
+            mappings.push((gen_line as u32, col, 0, orig_line, orig_col));
+            cursor = offset;
+        }
+    }
+    encode_source_map(&[filename], &[block], &mappings)
+}
+
+/// Candidate source texts for an output line, most specific first
+fn sass_anchors(text: &str) -> Vec<&str> {
+    if text.is_empty() || text.starts_with('}') || text.starts_with("/*") {
+        return Vec::new();
+    }
+    if text.starts_with('@') {
+        let end = text
+            .find(|c: char| c.is_whitespace() || c == ';' || c == '{')
+            .unwrap_or(text.len());
+        return vec![&text[..end]];
+    }
+    if let Some(selector) = text.strip_suffix('{').or_else(|| text.strip_suffix(',')) {
+        // `.card .title:hover` comes from `.title` or `&:hover` in the block
+        let selector = selector.trim_end();
+        let compound = selector
+            .rsplit(|c: char| c.is_whitespace() || matches!(c, ',' | '>' | '+' | '~'))
+            .next()
+            .unwrap_or(selector);
+        let bytes = compound.as_bytes();
+        return (0..compound.len())
+            .filter(|&i| {
+                i == 0 || (matches!(bytes[i], b'.' | b':' | b'#' | b'[') && bytes[i - 1] != b':')
+            })
+            .map(|i| &compound[i..])
+            .filter(|anchor| !anchor.is_empty())
+            .collect();
+    }
+    match text.find(':') {
+        Some(colon) => vec![&text[..=colon]],
+        None => Vec::new(),
+    }
+}
+
+/// Offset of `anchor` in `block` at or after `from`, on identifier boundaries
+fn find_anchor(block: &str, anchor: &str, from: usize) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | '$');
+    let starts_ident = anchor.starts_with(is_ident);
+    let ends_ident = anchor.ends_with(is_ident);
+    block[from..]
+        .match_indices(anchor)
+        .map(|(i, _)| from + i)
+        .find(|&offset| {
+            let before = block[..offset].chars().next_back();
+            let after = block[offset + anchor.len()..].chars().next();
+            let joins_before = starts_ident && before.is_some_and(is_ident);
+            let joins_after = ends_ident && after.is_some_and(is_ident);
+            !joins_before && !joins_after
+        })
+}
+
+/// Zero-based line and column of a byte offset
+fn line_col(text: &str, offset: usize) -> (u32, u32) {
+    let before = &text[..offset];
+    let line = before.matches('\n').count() as u32;
+    let col = before.len() - before.rfind('\n').map_or(0, |i| i + 1);
+    (line, col as u32)
+}
+
+/// Serves the block content as the entry file and forwards imports to the
+/// [`SfcFileSystem`], remembering every lookup.
+struct SassFileSystem<'a> {
+    entry: &'a str,
+    source: &'a str,
+    fs: &'a dyn SfcFileSystem,
+    loaded: RefCell<Vec<(String, Option<String>)>>,
+}
+
+impl SassFileSystem<'_> {
+    fn load(&self, path: &Path) -> Option<String> {
+        let path = normalize_path(&path.to_string_lossy());
+        if path == self.entry {
+            return Some(self.source.to_string());
+        }
+        if let Some((_, content)) = self.loaded.borrow().iter().find(|(p, _)| *p == path) {
+            return content.clone();
+        }
+        let content = self.fs.read_file(&path).ok();
+        self.loaded.borrow_mut().push((path, content.clone()));
+        content
+    }
+
+    fn dependencies(&self) -> Vec<String> {
+        self.loaded
+            .borrow()
+            .iter()
+            .filter(|(_, content)| content.is_some())
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for SassFileSystem<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SassFileSystem")
+            .field("entry", &self.entry)
+            .finish_non_exhaustive()
+    }
+}
+
+impl grass::Fs for SassFileSystem<'_> {
+    fn is_dir(&self, _path: &Path) -> bool {
+        false
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.load(path).is_some()
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.load(path).map(String::into_bytes).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} not found", path.display()),
+            )
+        })
+    }
+}
+
+// ============================================================================
+// Less subset
+// ============================================================================
+
+/// Maximum nesting of mixin calls and variable references
+const MAX_LESS_DEPTH: usize = 64;
+
+fn compile_less(
+    source: &str,
+    options: &PreprocessOptions,
+    fs: &dyn SfcFileSystem,
+) -> Result<PreprocessResult, String> {
+    let mut parser = LessParser {
+        fs,
+        sources: Vec::new(),
+        dependencies: Vec::new(),
+    };
+
+    let filename = if options.filename.is_empty() {
+        "style.less".to_string()
+    } else {
+        normalize_path(&options.filename)
+    };
+    let mut nodes = Vec::new();
+    // Additional data resolves imports like the block itself but is not mapped
+    if let Some(data) = options.additional_data(StyleLang::Less) {
+        nodes.extend(parser.parse_source(filename.clone(), data.to_string(), false)?);
+    }
+    nodes.extend(parser.parse_source(filename, source.to_string(), true)?);
+
+    let mut evaluator = LessEvaluator::new(&nodes);
+    evaluator.eval_block(&nodes, &LessContext::default(), None)?;
+
+    let mut printer = LessPrinter::default();
+    printer.print(&evaluator.out, &parser.sources);
+    let map = options
+        .source_map
+        .then(|| printer.source_map(&parser.sources));
+
+    Ok(PreprocessResult {
+        code: printer.code,
+        map,
+        dependencies: parser.dependencies,
+    })
+}
+
+/// A parsed Less file
+struct LessSource {
+    path: String,
+    content: String,
+    /// Line start offsets
+    lines: Vec<usize>,
+    /// Whether output positions map back to this source
+    mapped: bool,
+}
+
+impl LessSource {
+    fn line_col(&self, offset: usize) -> (u32, u32) {
+        let line = self.lines.partition_point(|&start| start <= offset) - 1;
+        let col = self.content[self.lines[line]..offset].chars().count();
+        (line as u32, col as u32)
+    }
+}
+
+/// Position of a node in a [`LessSource`]
+#[derive(Debug, Clone, Copy)]
+struct Pos {
+    source: usize,
+    offset: usize,
+}
+
+#[derive(Debug)]
+enum LessNode {
+    /// `@name: value;`
+    Variable { name: String, value: String },
+    /// `property: value;`
+    Declaration { text: String, pos: Pos },
+    /// `selector { ... }`
+    Rule {
+        selector: String,
+        children: Vec<LessNode>,
+        pos: Pos,
+    },
+    /// `@media ... { ... }` or `@charset ...;`
+    AtRule {
+        prelude: String,
+        children: Option<Vec<LessNode>>,
+        pos: Pos,
+    },
+    /// `.mixin;` or `.mixin();`
+    MixinCall { name: String },
+}
+
+struct LessParser<'a> {
+    fs: &'a dyn SfcFileSystem,
+    sources: Vec<LessSource>,
+    dependencies: Vec<String>,
+}
+
+impl LessParser<'_> {
+    fn parse_source(
+        &mut self,
+        path: String,
+        content: String,
+        mapped: bool,
+    ) -> Result<Vec<LessNode>, String> {
+        let text = strip_less_comments(&content);
+        let lines = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let index = self.sources.len();
+        self.sources.push(LessSource {
+            path,
+            content,
+            lines,
+            mapped,
+        });
+
+        let mut pos = 0;
+        self.parse_block(index, &text, &mut pos, false)
+    }
+
+    fn parse_block(
+        &mut self,
+        source: usize,
+        text: &str,
+        pos: &mut usize,
+        nested: bool,
+    ) -> Result<Vec<LessNode>, String> {
+        let mut nodes = Vec::new();
+        loop {
+            let start = *pos + (text.len() - *pos - text[*pos..].trim_start().len());
+            let (end, delimiter) = scan_less_statement(text, start);
+            let statement = text[start..end].trim();
+            let at = Pos {
+                source,
+                offset: start,
+            };
+            *pos = (end + 1).min(text.len());
+
+            match delimiter {
+                Some(b'{') => {
+                    let children = self.parse_block(source, text, pos, true)?;
+                    nodes.push(if statement.starts_with('@') {
+                        LessNode::AtRule {
+                            prelude: statement.to_string(),
+                            children: Some(children),
+                            pos: at,
+                        }
+                    } else {
+                        LessNode::Rule {
+                            selector: statement.to_string(),
+                            children,
+                            pos: at,
+                        }
+                    });
+                }
+                Some(b';') => self.push_statement(&mut nodes, statement, at)?,
+                Some(_) => {
+                    self.push_statement(&mut nodes, statement, at)?;
+                    if !nested {
+                        return Err(format!(
+                            "Unexpected \"}}\" in {}",
+                            self.sources[source].path
+                        ));
+                    }
+                    return Ok(nodes);
+                }
+                None => {
+                    self.push_statement(&mut nodes, statement, at)?;
+                    if nested {
+                        return Err(format!("Unclosed block in {}", self.sources[source].path));
+                    }
+                    return Ok(nodes);
+                }
+            }
+        }
+    }
+
+    fn push_statement(
+        &mut self,
+        nodes: &mut Vec<LessNode>,
+        statement: &str,
+        pos: Pos,
+    ) -> Result<(), String> {
+        if statement.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(import) = statement.strip_prefix("@import") {
+            if let Some(path) = less_import_path(import) {
+                let base = self.sources[pos.source].path.clone();
+                let path = resolve_src_path(&base, &path);
+                if self.dependencies.contains(&path) {
+                    return Ok(());
+                }
+                let content = self
+                    .fs
+                    .read_file(&path)
+                    .map_err(|e| format!("Failed to import \"{}\": {}", path, e))?;
+                self.dependencies.push(path.clone());
+                let mapped = self.sources[pos.source].mapped;
+                nodes.extend(self.parse_source(path, content, mapped)?);
+                return Ok(());
+            }
+        }
+
+        if let Some((name, value)) = less_variable(statement) {
+            nodes.push(LessNode::Variable {
+                name: name.to_string(),
+                value: value.to_string(),
+            });
+        } else if statement.starts_with('@') {
+            nodes.push(LessNode::AtRule {
+                prelude: statement.to_string(),
+                children: None,
+                pos,
+            });
+        } else if statement.starts_with(['.', '#']) && find_top_level(statement, b':').is_none() {
+            nodes.push(LessNode::MixinCall {
+                name: mixin_name(statement).to_string(),
+            });
+        } else {
+            nodes.push(LessNode::Declaration {
+                text: statement.to_string(),
+                pos,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Blank out `/* */` and `//` comments, keeping offsets and line breaks.
+fn strip_less_comments(content: &str) -> String {
+    let bytes = content.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut quote = None;
+    let mut parens = 0u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if let Some(q) = quote {
+            if c == b'\\' {
+                i += 1;
+            } else if c == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+        let comment_end = match (c, bytes.get(i + 1)) {
+            (b'/', Some(b'*')) => Some(
+                memchr::memmem::find(&bytes[i + 2..], b"*/").map_or(bytes.len(), |end| i + end + 4),
+            ),
+            (b'/', Some(b'/')) if parens == 0 => {
+                Some(memchr::memchr(b'\n', &bytes[i..]).map_or(bytes.len(), |end| i + end))
+            }
+            _ => None,
+        };
+        if let Some(end) = comment_end {
+            for byte in &mut out[i..end] {
+                if *byte != b'\n' {
+                    *byte = b' ';
+                }
+            }
+            i = end;
+            continue;
+        }
+        match c {
+            b'"' | b'\'' => quote = Some(c),
+            b'(' => parens += 1,
+            b')' => parens = parens.saturating_sub(1),
+            _ => {}
+        }
+        i += 1;
+    }
+    // Only ASCII bytes were replaced, and only whole comments
+    String::from_utf8(out).unwrap_or_else(|_| content.to_string())
+}
+
+/// Find the end of the statement starting at `start`: the offset of the
+/// first top-level `;`, `{` or `}`, skipping strings, parentheses and
+/// `@{name}` interpolation.
+fn scan_less_statement(text: &str, start: usize) -> (usize, Option<u8>) {
+    let bytes = text.as_bytes();
+    let mut quote = None;
+    let mut parens = 0u32;
+    let mut i = start;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if let Some(q) = quote {
+            if c == b'\\' {
+                i += 1;
+            } else if c == q {
+                quote = None;
+            }
+        } else {
+            match c {
+                b'"' | b'\'' => quote = Some(c),
+                b'(' => parens += 1,
+                b')' => parens = parens.saturating_sub(1),
+                b'@' if bytes.get(i + 1) == Some(&b'{') => {
+                    i = memchr::memchr(b'}', &bytes[i..]).map_or(bytes.len(), |end| i + end);
+                }
+                b';' | b'{' | b'}' if parens == 0 => return (i, Some(c)),
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    (bytes.len(), None)
+}
+
+/// Offset of the first `needle` outside strings and parentheses
+fn find_top_level(text: &str, needle: u8) -> Option<usize> {
+    let mut quote = None;
+    let mut parens = 0u32;
+    for (i, c) in text.bytes().enumerate() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            b'"' | b'\'' => quote = Some(c),
+            b'(' => parens += 1,
+            b')' => parens = parens.saturating_sub(1),
+            c if c == needle && parens == 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split on top-level commas
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some(comma) = find_top_level(rest, b',') {
+        parts.push(rest[..comma].trim());
+        rest = &rest[comma + 1..];
+    }
+    parts.push(rest.trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+fn is_less_ident(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Parse `@name: value` into its parts
+fn less_variable(statement: &str) -> Option<(&str, &str)> {
+    let rest = statement.strip_prefix('@')?;
+    let name_len = rest.find(|c: char| !is_less_ident(c)).unwrap_or(rest.len());
+    let value = rest[name_len..].trim_start().strip_prefix(':')?;
+    (name_len > 0).then(|| (&rest[..name_len], value.trim()))
+}
+
+/// Path of an `@import` that should be inlined, or `None` for CSS imports
+fn less_import_path(import: &str) -> Option<String> {
+    let mut import = import.trim();
+    if let Some(rest) = import.strip_prefix('(') {
+        let (options, rest) = rest.split_once(')')?;
+        if options.split(',').any(|option| option.trim() == "css") {
+            return None;
+        }
+        import = rest.trim_start();
+    }
+    let path = import
+        .strip_prefix('"')
+        .and_then(|rest| rest.split_once('"'))
+        .or_else(|| {
+            import
+                .strip_prefix('\'')
+                .and_then(|rest| rest.split_once('\''))
+        })
+        .map(|(path, _)| path)?;
+    if path.ends_with(".css") || path.contains("://") {
+        return None;
+    }
+    let file = path.rsplit('/').next().unwrap_or(path);
+    Some(if file.contains('.') {
+        path.to_string()
+    } else {
+        format!("{}.less", path)
+    })
+}
+
+/// Mixin name of a call or definition: `.bordered()` -> `.bordered`
+fn mixin_name(selector: &str) -> &str {
+    let selector = selector.trim();
+    let selector = selector
+        .strip_suffix("!important")
+        .map_or(selector, str::trim_end);
+    selector.strip_suffix("()").map_or(selector, str::trim_end)
+}
+
+/// Whether a rule selector can be called as a mixin
+fn is_mixin_selector(selector: &str) -> bool {
+    let name = mixin_name(selector);
+    name.len() > 1 && name.starts_with(['.', '#']) && name[1..].chars().all(is_less_ident)
+}
+
+/// Output of the Less evaluator, printed in order
+#[derive(Debug)]
+enum LessOut {
+    /// A rule (or an at-rule body when `selector` is `None`)
+    Rule {
+        wrappers: Vec<String>,
+        selector: Option<Vec<String>>,
+        decls: Vec<(String, Pos)>,
+        pos: Pos,
+    },
+    /// A statement at-rule such as `@charset`
+    Statement {
+        wrappers: Vec<String>,
+        text: String,
+        pos: Pos,
+    },
+}
+
+impl LessOut {
+    fn wrappers(&self) -> &[String] {
+        match self {
+            Self::Rule { wrappers, .. } | Self::Statement { wrappers, .. } => wrappers,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct LessContext {
+    selectors: Vec<String>,
+    wrappers: Vec<String>,
+}
+
+struct LessEvaluator<'n> {
+    mixins: FxHashMap<&'n str, Vec<&'n [LessNode]>>,
+    scopes: Vec<FxHashMap<&'n str, &'n str>>,
+    out: Vec<LessOut>,
+    depth: usize,
+}
+
+impl<'n> LessEvaluator<'n> {
+    fn new(nodes: &'n [LessNode]) -> Self {
+        let mut evaluator = Self {
+            mixins: FxHashMap::default(),
+            scopes: Vec::new(),
+            out: Vec::new(),
+            depth: 0,
+        };
+        evaluator.collect_mixins(nodes);
+        evaluator
+    }
+
+    fn collect_mixins(&mut self, nodes: &'n [LessNode]) {
+        for node in nodes {
+            match node {
+                LessNode::Rule {
+                    selector, children, ..
+                } => {
+                    if is_mixin_selector(selector) {
+                        self.mixins
+                            .entry(mixin_name(selector))
+                            .or_default()
+                            .push(children);
+                    }
+                    self.collect_mixins(children);
+                }
+                LessNode::AtRule {
+                    children: Some(children),
+                    ..
+                } => self.collect_mixins(children),
+                _ => {}
+            }
+        }
+    }
+
+    /// Evaluate a block. Declarations go to the output rule at `target`;
+    /// nested rules are appended after it.
+    fn eval_block(
+        &mut self,
+        nodes: &'n [LessNode],
+        ctx: &LessContext,
+        target: Option<usize>,
+    ) -> Result<(), String> {
+        // Less variables are hoisted within their block
+        let scope = nodes
+            .iter()
+            .filter_map(|node| match node {
+                LessNode::Variable { name, value } => Some((name.as_str(), value.as_str())),
+                _ => None,
+            })
+            .collect();
+        self.scopes.push(scope);
+
+        for node in nodes {
+            match node {
+                LessNode::Variable { .. } => {}
+                LessNode::Declaration { text, pos } => {
+                    let decl = self.interpolate(text, true)?;
+                    match target.and_then(|index| self.out.get_mut(index)) {
+                        Some(LessOut::Rule { decls, .. }) => decls.push((decl, *pos)),
+                        _ => return Err(format!("Declaration \"{}\" is outside of a rule", text)),
+                    }
+                }
+                LessNode::MixinCall { name } => {
+                    let bodies = self
+                        .mixins
+                        .get(name.as_str())
+                        .cloned()
+                        .ok_or_else(|| format!("{} is undefined", name))?;
+                    self.depth += 1;
+                    if self.depth > MAX_LESS_DEPTH {
+                        return Err(format!("Mixin {} calls itself recursively", name));
+                    }
+                    for body in bodies {
+                        self.eval_block(body, ctx, target)?;
+                    }
+                    self.depth -= 1;
+                }
+                LessNode::Rule {
+                    selector,
+                    children,
+                    pos,
+                } => {
+                    // `.name()` only defines a mixin
+                    if selector.trim_end().ends_with("()") {
+                        continue;
+                    }
+                    let selector = self.interpolate(selector, false)?;
+                    let nested = LessContext {
+                        selectors: resolve_less_selectors(&ctx.selectors, &selector),
+                        wrappers: ctx.wrappers.clone(),
+                    };
+                    self.eval_rule(children, nested, *pos)?;
+                }
+                LessNode::AtRule {
+                    prelude,
+                    children,
+                    pos,
+                } => {
+                    let keyword_len = prelude
+                        .find(|c: char| c.is_whitespace() || c == '(')
+                        .unwrap_or(prelude.len());
+                    let keyword = &prelude[..keyword_len];
+                    let rest = self.interpolate(&prelude[keyword_len..], true)?;
+                    let prelude = format!("{}{}", keyword, rest);
+                    match children {
+                        Some(children) => {
+                            // Conditional rules bubble up around the current
+                            // selectors; everything else starts a new context
+                            let bubbles = matches!(
+                                keyword,
+                                "@media" | "@supports" | "@container" | "@layer" | "@document"
+                            );
+                            let mut wrappers = ctx.wrappers.clone();
+                            wrappers.push(prelude);
+                            let nested = LessContext {
+                                selectors: if bubbles {
+                                    ctx.selectors.clone()
+                                } else {
+                                    Vec::new()
+                                },
+                                wrappers,
+                            };
+                            self.eval_rule(children, nested, *pos)?;
+                        }
+                        None => self.out.push(LessOut::Statement {
+                            wrappers: ctx.wrappers.clone(),
+                            text: prelude,
+                            pos: *pos,
+                        }),
+                    }
+                }
+            }
+        }
+
+        self.scopes.pop();
+        Ok(())
+    }
+
+    /// Evaluate the body of a rule or at-rule, reserving its output slot
+    /// ahead of nested rules
+    fn eval_rule(
+        &mut self,
+        children: &'n [LessNode],
+        ctx: LessContext,
+        pos: Pos,
+    ) -> Result<(), String> {
+        let slot = self.out.len();
+        self.out.push(LessOut::Rule {
+            wrappers: ctx.wrappers.clone(),
+            selector: (!ctx.selectors.is_empty()).then(|| ctx.selectors.clone()),
+            decls: Vec::new(),
+            pos,
+        });
+        self.eval_block(children, &ctx, Some(slot))?;
+
+        if matches!(&self.out[slot], LessOut::Rule { decls, .. } if decls.is_empty()) {
+            self.out.remove(slot);
+        }
+        Ok(())
+    }
+
+    fn lookup(&self, name: &str) -> Result<&'n str, String> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+            .ok_or_else(|| format!("Variable @{} is undefined", name))
+    }
+
+    /// Replace `@{name}` (and, when `bare` is set, `@name` outside strings)
+    /// with variable values, and unwrap `~"..."` escapes.
+    fn interpolate(&self, text: &str, bare: bool) -> Result<String, String> {
+        self.interpolate_depth(text, bare, 0)
+    }
+
+    fn interpolate_depth(&self, text: &str, bare: bool, depth: usize) -> Result<String, String> {
+        if depth > MAX_LESS_DEPTH {
+            return Err(format!("Variable reference in \"{}\" is recursive", text));
+        }
+        if !text.contains(['@', '~']) {
+            return Ok(text.to_string());
+        }
+
+        let mut out = String::with_capacity(text.len());
+        let mut quote = None;
+        let mut chars = text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let rest = &text[i + c.len_utf8()..];
+            if c == '@' && rest.starts_with('{') {
+                if let Some(end) = rest.find('}') {
+                    let value = self.lookup(&rest[1..end])?;
+                    let value = self.interpolate_depth(value, true, depth + 1)?;
+                    out.push_str(unquote(&value));
+                    while chars.peek().is_some_and(|&(j, _)| j <= i + end + 1) {
+                        chars.next();
+                    }
+                    continue;
+                }
+            }
+            if let Some(q) = quote {
+                if c == q {
+                    quote = None;
+                }
+                out.push(c);
+                continue;
+            }
+            match c {
+                '"' | '\'' => {
+                    quote = Some(c);
+                    out.push(c);
+                }
+                '~' if rest.starts_with(['"', '\'']) => {
+                    // `~"..."` outputs the string content as is
+                    let q = rest.as_bytes()[0] as char;
+                    if let Some(end) = rest[1..].find(q) {
+                        out.push_str(&self.interpolate_depth(&rest[1..end + 1], bare, depth)?);
+                        while chars.peek().is_some_and(|&(j, _)| j <= i + end + 2) {
+                            chars.next();
+                        }
+                    } else {
+                        out.push(c);
+                    }
+                }
+                '@' if bare && rest.starts_with(is_less_ident) => {
+                    let end = rest.find(|c: char| !is_less_ident(c)).unwrap_or(rest.len());
+                    let value = self.lookup(&rest[..end])?;
+                    out.push_str(&self.interpolate_depth(value, true, depth + 1)?);
+                    while chars.peek().is_some_and(|&(j, _)| j <= i + end) {
+                        chars.next();
+                    }
+                }
+                _ => out.push(c),
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Strip one pair of surrounding quotes
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    for q in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(q).and_then(|v| v.strip_suffix(q)) {
+            return inner;
+        }
+    }
+    value
+}
+
+/// Combine parent selectors with a nested selector, replacing `&`
+fn resolve_less_selectors(parents: &[String], selector: &str) -> Vec<String> {
+    let children = split_top_level(selector);
+    if parents.is_empty() {
+        return children
+            .iter()
+            .map(|child| child.replace('&', "").trim().to_string())
+            .collect();
+    }
+    parents
+        .iter()
+        .flat_map(|parent| {
+            children.iter().map(move |child| {
+                if child.contains('&') {
+                    child.replace('&', parent)
+                } else {
+                    format!("{} {}", parent, child)
+                }
+            })
+        })
+        .collect()
+}
+
+/// Prints evaluated Less output and records line mappings
+#[derive(Default)]
+struct LessPrinter {
+    code: String,
+    line: u32,
+    /// (generated line, generated column, source, original line, original column)
+    mappings: Vec<(u32, u32, usize, u32, u32)>,
+}
+
+impl LessPrinter {
+    fn print(&mut self, out: &[LessOut], sources: &[LessSource]) {
+        let mut open: Vec<&str> = Vec::new();
+        for item in out {
+            let wrappers = item.wrappers();
+            let common = open
+                .iter()
+                .zip(wrappers)
+                .take_while(|(a, b)| **a == b.as_str())
+                .count();
+            while open.len() > common {
+                open.pop();
+                self.line(open.len(), "}", None, sources);
+            }
+            for wrapper in &wrappers[common..] {
+                let line = format!("{} {{", wrapper);
+                self.line(open.len(), &line, None, sources);
+                open.push(wrapper);
+            }
+
+            let indent = open.len();
+            match item {
+                LessOut::Statement { text, pos, .. } => {
+                    self.line(indent, &format!("{};", text), Some(*pos), sources);
+                }
+                LessOut::Rule {
+                    selector: Some(selectors),
+                    decls,
+                    pos,
+                    ..
+                } => {
+                    let separator = format!(",\n{}", "  ".repeat(indent));
+                    let header = format!("{} {{", selectors.join(&separator));
+                    self.line(indent, &header, Some(*pos), sources);
+                    for (decl, pos) in decls {
+                        self.line(indent + 1, &format!("{};", decl), Some(*pos), sources);
+                    }
+                    self.line(indent, "}", None, sources);
+                }
+                LessOut::Rule {
+                    selector: None,
+                    decls,
+                    ..
+                } => {
+                    for (decl, pos) in decls {
+                        self.line(indent, &format!("{};", decl), Some(*pos), sources);
+                    }
+                }
+            }
+        }
+        while !open.is_empty() {
+            open.pop();
+            self.line(open.len(), "}", None, sources);
+        }
+    }
+
+    fn line(&mut self, indent: usize, text: &str, pos: Option<Pos>, sources: &[LessSource]) {
+        if let Some(pos) = pos.filter(|pos| sources[pos.source].mapped) {
+            let (line, col) = sources[pos.source].line_col(pos.offset);
+            self.mappings
+                .push((self.line, indent as u32 * 2, pos.source, line, col));
+        }
+        for _ in 0..indent {
+            self.code.push_str("  ");
+        }
+        self.code.push_str(text);
+        self.code.push('\n');
+        self.line += 1 + text.matches('\n').count() as u32;
+    }
+
+    /// Source map v3 JSON for the recorded mappings
+    fn source_map(&self, sources: &[LessSource]) -> String {
+        let mut indices = vec![0; sources.len()];
+        let mut names = Vec::new();
+        let mut contents = Vec::new();
+        for (i, source) in sources.iter().enumerate().filter(|(_, s)| s.mapped) {
+            indices[i] = names.len();
+            names.push(source.path.as_str());
+            contents.push(source.content.as_str());
+        }
+        let mappings: Vec<_> = self
+            .mappings
+            .iter()
+            .map(|&(line, col, source, orig_line, orig_col)| {
+                (line, col, indices[source], orig_line, orig_col)
+            })
+            .collect();
+        encode_source_map(&names, &contents, &mappings)
+    }
+}
+
+/// Source map v3 JSON for sorted
+/// `(generated line, generated column, source, original line, original column)` mappings
+fn encode_source_map(
+    sources: &[&str],
+    contents: &[&str],
+    mappings: &[(u32, u32, usize, u32, u32)],
+) -> String {
+    let mut encoded = String::new();
+    let (mut gen_line, mut prev_source, mut prev_line, mut prev_col) = (0, 0i64, 0i64, 0i64);
+    let mut first_in_line = true;
+    for &(line, col, source, orig_line, orig_col) in mappings {
+        while gen_line < line {
+            encoded.push(';');
+            gen_line += 1;
+            first_in_line = true;
+        }
+        if !first_in_line {
+            encoded.push(',');
+        }
+        let source = source as i64;
+        // Generated columns restart on each line; the rest are relative
+        // to the previous segment
+        write_vlq(&mut encoded, col as i64);
+        write_vlq(&mut encoded, source - prev_source);
+        write_vlq(&mut encoded, orig_line as i64 - prev_line);
+        write_vlq(&mut encoded, orig_col as i64 - prev_col);
+        (prev_source, prev_line, prev_col) = (source, orig_line as i64, orig_col as i64);
+        first_in_line = false;
+    }
+
+    serde_json::json!({
+        "version": 3,
+        "sources": sources,
+        "sourcesContent": contents,
+        "names": [],
+        "mappings": encoded,
+    })
+    .to_string()
+}
+
+/// Append a base64 VLQ value
+fn write_vlq(out: &mut String, value: i64) {
+    const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut value = if value < 0 {
+        ((-value as u64) << 1) | 1
+    } else {
+        (value as u64) << 1
+    };
+    loop {
+        let mut digit = (value & 31) as usize;
+        value >>= 5;
+        if value > 0 {
+            digit |= 32;
+        }
+        out.push(BASE64[digit] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_sfc, SfcParseOptions};
+
+    fn no_files(path: &str) -> Result<String, String> {
+        Err(format!("{} not found", path))
+    }
+
+    fn less(source: &str) -> String {
+        preprocess_style(source, StyleLang::Less, &Default::default(), &no_files)
+            .unwrap()
+            .code
+    }
+
+    #[test]
+    fn test_scss_imports_and_additional_data() {
+        let fs = |path: &str| match path {
+            "src/styles/_vars.scss" => Ok("$primary: #42b883;".to_string()),
+            "src/styles/_mixins.scss" => {
+                Ok("@mixin center { display: flex; align-items: center; }".to_string())
+            }
+            _ => Err("not found".to_string()),
+        };
+        let options = PreprocessOptions {
+            filename: "src/components/Card.vue".to_string(),
+            scss_additional_data: Some("@use \"../styles/mixins\" as *;".to_string()),
+            ..Default::default()
+        };
+        let result = preprocess_style(
+            "@use \"../styles/vars\";\n.card { color: vars.$primary; .title { @include center; } }",
+            StyleLang::Scss,
+            &options,
+            &fs,
+        )
+        .unwrap();
+
+        assert!(result.code.contains("color: #42b883;"));
+        assert!(result.code.contains(".card .title {\n  display: flex;"));
+        assert_eq!(
+            result.dependencies,
+            vec!["src/styles/_mixins.scss", "src/styles/_vars.scss"]
+        );
+
+        let err = preprocess_style(".a { color: $missing; }", StyleLang::Scss, &options, &fs)
+            .unwrap_err();
+        assert_eq!(err.code.as_deref(), Some("PREPROCESS_ERROR"));
+        assert!(err.message.contains("Undefined variable"));
+    }
+
+    #[test]
+    fn test_sass_indented() {
+        let result = preprocess_style(
+            "$size: 4px\n.a\n  margin: $size\n  &:hover\n    color: red\n",
+            StyleLang::Sass,
+            &Default::default(),
+            &no_files,
+        )
+        .unwrap();
+        assert!(result.code.contains("margin: 4px;"));
+        assert!(result.code.contains(".a:hover {"));
+    }
+
+    #[test]
+    fn test_less_subset() {
+        let css = less(
+            r#"// line comment
+@primary: #42b883;
+@name: card;
+@import (css) url("https://example.com/theme.css");
+.bordered() { border: 1px solid @primary; }
+.@{name} {
+  .bordered;
+  color: @primary;
+  &:hover { color: darken(@primary, 10%); }
+  .title, .subtitle { font-weight: bold; }
+  @media (min-width: 768px) { padding: @gap; }
+  @gap: 8px;
+  background: url(//cdn.example.com/bg.png);
+  content: ~"@{name}";
+}"#,
+        );
+        assert_eq!(
+            css,
+            r#"@import (css) url("https://example.com/theme.css");
+.card {
+  border: 1px solid #42b883;
+  color: #42b883;
+  background: url(//cdn.example.com/bg.png);
+  content: card;
+}
+.card:hover {
+  color: darken(#42b883, 10%);
+}
+.card .title,
+.card .subtitle {
+  font-weight: bold;
+}
+@media (min-width: 768px) {
+  .card {
+    padding: 8px;
+  }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_less_keyframes_and_errors() {
+        let css = less("@d: 1s;\n@keyframes spin { from { opacity: 0; } to { opacity: 1; } }\n.a { animation: spin @d; }");
+        assert!(css.starts_with("@keyframes spin {\n  from {\n    opacity: 0;\n  }\n  to {"));
+        assert!(css.contains("animation: spin 1s;"));
+
+        let err = preprocess_style(
+            ".a { color: @missing; }",
+            StyleLang::Less,
+            &Default::default(),
+            &no_files,
+        )
+        .unwrap_err();
+        assert!(err.message.contains("@missing"));
+        let err = preprocess_style(
+            ".a { .undefined; }",
+            StyleLang::Less,
+            &Default::default(),
+            &no_files,
+        )
+        .unwrap_err();
+        assert!(err.message.contains(".undefined"));
+    }
+
+    #[test]
+    fn test_less_source_map() {
+        let options = PreprocessOptions {
+            filename: "App.vue".to_string(),
+            less_additional_data: Some("@c: red;".to_string()),
+            source_map: true,
+            ..Default::default()
+        };
+        let result = preprocess_style(
+            "\n.a {\n  .b { color: @c; }\n}",
+            StyleLang::Less,
+            &options,
+            &no_files,
+        )
+        .unwrap();
+        assert_eq!(result.code, ".a .b {\n  color: red;\n}\n");
+        let map: serde_json::Value = serde_json::from_str(&result.map.unwrap()).unwrap();
+        assert_eq!(map["sources"], serde_json::json!(["App.vue"]));
+        // `.a .b {` -> 2:2 and `color: red;` -> 2:7 (0-based), additionalData unmapped
+        assert_eq!(map["mappings"], "AAEE;EAAK");
+    }
+
+    #[test]
+    fn test_sass_source_map() {
+        let options = PreprocessOptions {
+            filename: "App.vue".to_string(),
+            scss_additional_data: Some("$c: red;".to_string()),
+            source_map: true,
+            ..Default::default()
+        };
+        let result = preprocess_style(
+            "\n.a {\n  .b { color: $c; }\n}",
+            StyleLang::Scss,
+            &options,
+            &no_files,
+        )
+        .unwrap();
+        assert_eq!(result.code, ".a .b {\n  color: red;\n}\n");
+        let map: serde_json::Value = serde_json::from_str(&result.map.unwrap()).unwrap();
+        assert_eq!(map["sources"], serde_json::json!(["App.vue"]));
+        assert_eq!(
+            map["sourcesContent"],
+            serde_json::json!(["\n.a {\n  .b { color: $c; }\n}"])
+        );
+        // Same mappings as the Less subset produces for the equivalent block
+        assert_eq!(map["mappings"], "AAEE;EAAK");
+
+        // `&:hover` and `$border-color:` do not anchor `.a:hover` / `color:` wrongly
+        let result = preprocess_style(
+            "$border-color: blue\n.a\n  border-color: $border-color\n  &:hover\n    color: red\n",
+            StyleLang::Sass,
+            &PreprocessOptions {
+                source_map: true,
+                ..Default::default()
+            },
+            &no_files,
+        )
+        .unwrap();
+        assert_eq!(
+            result.code,
+            ".a {\n  border-color: blue;\n}\n.a:hover {\n  color: red;\n}\n"
+        );
+        let map: serde_json::Value = serde_json::from_str(&result.map.unwrap()).unwrap();
+        // .a -> 1:0, border-color -> 2:2, .a:hover -> 3:3 (`:hover`), color -> 4:4
+        assert_eq!(map["mappings"], "AACA;EACE;;AACC;EACC");
+    }
+
+    #[test]
+    fn test_preprocess_styles() {
+        let source = r#"<template><div class="card" /></template>
+<style lang="scss" scoped>
+@import "tokens";
+.card { color: v-bind(color); .icon { width: $size; } }
+</style>
+<style lang="less" src="./theme.less"></style>
+<style>.plain { color: red; }</style>"#;
+        let mut descriptor = parse_sfc(
+            source,
+            SfcParseOptions {
+                filename: "src/Card.vue".to_string(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let fs = |path: &str| match path {
+            "src/_tokens.scss" => Ok("$size: 16px;".to_string()),
+            "src/theme.less" => Ok("@import \"base\";\n.theme { color: @base; }".to_string()),
+            "src/base.less" => Ok("@base: blue;".to_string()),
+            _ => Err("not found".to_string()),
+        };
+        crate::resolve_src_blocks(&mut descriptor, &fs).unwrap();
+        let dependencies = preprocess_styles(&mut descriptor, &Default::default(), &fs).unwrap();
+
+        assert_eq!(dependencies, vec!["src/_tokens.scss", "src/base.less"]);
+        let styles = &descriptor.styles;
+        assert!(styles.iter().all(|style| style.lang.is_none()));
+        assert!(styles[0].content.contains(".card .icon {\n  width: 16px;"));
+        assert!(styles[1].content.contains("color: blue;"));
+        assert_eq!(styles[2].content, ".plain { color: red; }");
+
+        let result = crate::compile_sfc(&descriptor, Default::default()).unwrap();
+        assert!(result.warnings.is_empty());
+        assert!(result.css.unwrap().contains(".card .icon[data-v-"));
+    }
+}
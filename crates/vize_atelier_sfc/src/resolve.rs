@@ -0,0 +1,358 @@
+//! External `src` block resolution.
+//!
+//! `<template src="./view.html">`, `<script src="./logic.ts">` and
+//! `<style src="./theme.css" scoped>` reference their content instead of
+//! inlining it. [`resolve_src_blocks`] loads that content through an
+//! [`SfcFileSystem`] before compilation, so the blocks are compiled as if
+//! they were inline (including the component's scope ID), and returns the
+//! loaded files as dependencies for watch mode.
+
+use std::borrow::Cow;
+
+use crate::types::{BlockLocation, SfcDescriptor, SfcError};
+
+/// File access used to load external blocks.
+///
+/// Implemented natively by [`NativeFileSystem`], and by any
+/// `Fn(&str) -> Result<String, String>` so bindings can forward reads to a
+/// host callback.
+pub trait SfcFileSystem {
+    /// Read the file at a path resolved by [`resolve_src_path`].
+    fn read_file(&self, path: &str) -> Result<String, String>;
+}
+
+impl<F> SfcFileSystem for F
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    fn read_file(&self, path: &str) -> Result<String, String> {
+        self(path)
+    }
+}
+
+/// Reads external blocks from the local file system.
+#[cfg(feature = "native")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NativeFileSystem;
+
+#[cfg(feature = "native")]
+impl SfcFileSystem for NativeFileSystem {
+    fn read_file(&self, path: &str) -> Result<String, String> {
+        std::fs::read_to_string(path).map_err(|e| e.to_string())
+    }
+}
+
+/// Resolve a `src` attribute against the directory of the SFC.
+///
+/// Absolute paths are kept; `.` and `..` segments are folded.
+pub fn resolve_src_path(filename: &str, src: &str) -> String {
+    let joined = if src.starts_with('/') {
+        src.to_string()
+    } else {
+        let filename = filename.replace('\\', "/");
+        match filename.rfind('/') {
+            Some(slash) => format!("{}/{}", &filename[..slash], src),
+            None => src.to_string(),
+        }
+    };
+
+    let absolute = joined.starts_with('/');
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in joined.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if segments.last().is_some_and(|s| *s != "..") => {
+                segments.pop();
+            }
+            ".." if absolute => {}
+            segment => segments.push(segment),
+        }
+    }
+    let path = segments.join("/");
+    if absolute {
+        format!("/{}", path)
+    } else {
+        path
+    }
+}
+
+/// Load the content of every block with a `src` attribute.
+///
+/// Blocks without an explicit `lang` take it from the file extension
+/// (`./theme.scss` compiles as SCSS). Returns the resolved paths of the
+/// loaded files, in block order.
+pub fn resolve_src_blocks(
+    descriptor: &mut SfcDescriptor<'_>,
+    fs: &dyn SfcFileSystem,
+) -> Result<Vec<String>, SfcError> {
+    let filename = descriptor.filename.to_string();
+    let mut dependencies = Vec::new();
+
+    let mut load = |tag: &str,
+                    src: &str,
+                    loc: &BlockLocation,
+                    content: &mut Cow<'_, str>,
+                    lang: Option<&mut Option<Cow<'_, str>>>|
+     -> Result<(), SfcError> {
+        let path = resolve_src_path(&filename, src);
+        let external = fs.read_file(&path).map_err(|e| SfcError {
+            message: format!("Failed to load <{}> src \"{}\": {}", tag, src, e),
+            code: Some("SRC_NOT_FOUND".into()),
+            loc: Some(loc.clone()),
+        })?;
+        if let Some(lang) = lang.filter(|lang| lang.is_none()) {
+            *lang = lang_from_extension(&path).map(Cow::Owned);
+        }
+        *content = Cow::Owned(external);
+        if !dependencies.contains(&path) {
+            dependencies.push(path);
+        }
+        Ok(())
+    };
+
+    if let Some(template) = &mut descriptor.template {
+        if let Some(src) = template.src.clone() {
+            load(
+                "template",
+                &src,
+                &template.loc,
+                &mut template.content,
+                Some(&mut template.lang),
+            )?;
+        }
+    }
+
+    if let Some(script_setup) = &descriptor.script_setup {
+        if script_setup.src.is_some() {
+            return Err(SfcError {
+                message: "<script setup> cannot use the \"src\" attribute because its syntax \
+                          will be ambiguous outside of the component."
+                    .into(),
+                code: Some("SCRIPT_SETUP_SRC".into()),
+                loc: Some(script_setup.loc.clone()),
+            });
+        }
+    }
+
+    if let Some(script) = &mut descriptor.script {
+        if let Some(src) = script.src.clone() {
+            load(
+                "script",
+                &src,
+                &script.loc,
+                &mut script.content,
+                Some(&mut script.lang),
+            )?;
+        }
+    }
+
+    for style in &mut descriptor.styles {
+        if let Some(src) = style.src.clone() {
+            load(
+                "style",
+                &src,
+                &style.loc,
+                &mut style.content,
+                Some(&mut style.lang),
+            )?;
+        }
+    }
+
+    for block in &mut descriptor.custom_blocks {
+        if let Some(src) = block.attrs.get("src").cloned() {
+            let tag = block.block_type.to_string();
+            load(&tag, &src, &block.loc, &mut block.content, None)?;
+        }
+    }
+
+    descriptor.src_resolved = true;
+    Ok(dependencies)
+}
+
+/// Blocks that reference external content which has not been loaded.
+///
+/// Returns `(tag, src, loc)` for each block with a `src` attribute, unless
+/// [`resolve_src_blocks`] loaded them (even when a loaded file is empty).
+pub fn unresolved_src_blocks<'d>(
+    descriptor: &'d SfcDescriptor<'_>,
+) -> Vec<(&'d str, &'d str, &'d BlockLocation)> {
+    let mut unresolved = Vec::new();
+    if descriptor.src_resolved {
+        return unresolved;
+    }
+
+    if let Some(template) = &descriptor.template {
+        if let Some(src) = template.src.as_deref() {
+            unresolved.push(("template", src, &template.loc));
+        }
+    }
+    if let Some(script) = &descriptor.script {
+        if let Some(src) = script.src.as_deref() {
+            unresolved.push(("script", src, &script.loc));
+        }
+    }
+    for style in &descriptor.styles {
+        if let Some(src) = style.src.as_deref() {
+            unresolved.push(("style", src, &style.loc));
+        }
+    }
+    unresolved
+}
+
+/// Block language implied by a file extension.
+fn lang_from_extension(path: &str) -> Option<String> {
+    let file = path.rsplit('/').next().unwrap_or(path);
+    let (_, ext) = file.rsplit_once('.')?;
+    match ext {
+        "ts" | "tsx" | "jsx" | "pug" | "scss" | "sass" | "less" | "styl" | "stylus" => {
+            Some(ext.to_string())
+        }
+        "mts" | "cts" => Some("ts".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_sfc, SfcParseOptions};
+
+    fn parse(source: &str) -> SfcDescriptor<'_> {
+        parse_sfc(
+            source,
+            SfcParseOptions {
+                filename: "src/components/Card.vue".to_string(),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_src_path() {
+        assert_eq!(
+            resolve_src_path("src/components/Card.vue", "./card.html"),
+            "src/components/card.html"
+        );
+        assert_eq!(
+            resolve_src_path("src/components/Card.vue", "../styles/theme.css"),
+            "src/styles/theme.css"
+        );
+        assert_eq!(
+            resolve_src_path("/app/Card.vue", "/abs/x.css"),
+            "/abs/x.css"
+        );
+        assert_eq!(resolve_src_path("Card.vue", "../x.css"), "../x.css");
+        assert_eq!(
+            resolve_src_path("C:\\app\\Card.vue", "./x.css"),
+            "C:/app/x.css"
+        );
+    }
+
+    #[test]
+    fn test_resolve_src_blocks() {
+        let mut descriptor = parse(
+            r#"<template src="./card.html"></template>
+<script src="./card.ts"></script>
+<style src="../styles/theme.scss" scoped></style>
+<style>.inline { color: red; }</style>"#,
+        );
+        assert_eq!(unresolved_src_blocks(&descriptor).len(), 3);
+
+        let fs = |path: &str| match path {
+            "src/components/card.html" => Ok("<div class=\"card\"><slot /></div>".to_string()),
+            "src/components/card.ts" => Ok("export default { name: 'Card' }".to_string()),
+            "src/styles/theme.scss" => Ok(".card { color: $primary; }".to_string()),
+            _ => Err("not found".to_string()),
+        };
+        let dependencies = resolve_src_blocks(&mut descriptor, &fs).unwrap();
+
+        assert_eq!(
+            dependencies,
+            vec![
+                "src/components/card.html",
+                "src/components/card.ts",
+                "src/styles/theme.scss",
+            ]
+        );
+        assert_eq!(
+            descriptor.template.as_ref().unwrap().content,
+            "<div class=\"card\"><slot /></div>"
+        );
+        let script = descriptor.script.as_ref().unwrap();
+        assert_eq!(script.lang.as_deref(), Some("ts"));
+        assert_eq!(descriptor.styles[0].lang.as_deref(), Some("scss"));
+        assert!(descriptor.styles[0].scoped);
+        assert_eq!(descriptor.styles[1].content, ".inline { color: red; }");
+        assert!(unresolved_src_blocks(&descriptor).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_empty_src() {
+        let mut descriptor = parse(r#"<style src="./empty.css"></style>"#);
+        assert_eq!(unresolved_src_blocks(&descriptor).len(), 1);
+
+        let fs = |_: &str| Ok(String::new());
+        resolve_src_blocks(&mut descriptor, &fs).unwrap();
+        assert!(descriptor.src_resolved);
+        assert_eq!(descriptor.styles[0].content, "");
+        assert!(unresolved_src_blocks(&descriptor).is_empty());
+    }
+
+    #[test]
+    fn test_compile_resolved_scoped_style() {
+        use crate::{compile_sfc, SfcCompileOptions, StyleCompileOptions, TemplateCompileOptions};
+
+        let mut descriptor = parse(
+            r#"<template src="./card.html"></template>
+<style src="./card.css" scoped></style>"#,
+        );
+        let options = || SfcCompileOptions {
+            template: TemplateCompileOptions {
+                id: Some("Card.vue".to_string()),
+                scoped: true,
+                ..Default::default()
+            },
+            style: StyleCompileOptions {
+                id: "Card.vue".to_string(),
+                scoped: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let unresolved = compile_sfc(&descriptor, options()).unwrap();
+        assert_eq!(unresolved.warnings.len(), 2);
+        assert_eq!(
+            unresolved.warnings[0].code.as_deref(),
+            Some("UNRESOLVED_SRC")
+        );
+
+        let fs = |path: &str| match path {
+            "src/components/card.html" => Ok("<div class=\"card\">Card</div>".to_string()),
+            "src/components/card.css" => Ok(".card { color: red; }".to_string()),
+            _ => Err("not found".to_string()),
+        };
+        resolve_src_blocks(&mut descriptor, &fs).unwrap();
+        let result = compile_sfc(&descriptor, options()).unwrap();
+        assert!(result.warnings.is_empty());
+        assert!(result.code.contains("Card"));
+        let css = result.css.unwrap();
+        let scope = &css[css.find("[data-v-").unwrap() + 1..css.find(']').unwrap()];
+        assert!(result.code.contains(scope));
+    }
+
+    #[test]
+    fn test_resolve_src_errors() {
+        let mut descriptor = parse(r#"<template src="./missing.html"></template>"#);
+        let fs = |_: &str| Err("No such file".to_string());
+        let err = resolve_src_blocks(&mut descriptor, &fs).unwrap_err();
+        assert_eq!(err.code.as_deref(), Some("SRC_NOT_FOUND"));
+        assert!(err.message.contains("./missing.html"));
+
+        let mut descriptor =
+            parse(r#"<script setup src="./setup.ts"></script><template><div /></template>"#);
+        let err = resolve_src_blocks(&mut descriptor, &fs).unwrap_err();
+        assert_eq!(err.code.as_deref(), Some("SCRIPT_SETUP_SRC"));
+    }
+}
@@ -101,6 +101,9 @@ pub fn rewrite_default(input: &str, as_name: &str, is_ts: bool) -> (String, bool
                             output.push_str(&input[func_start..decl.span.end as usize]);
                         }
                     }
+                    ExportDefaultDeclarationKind::Identifier(ident) if ident.name == as_name => {
+                        // export default as_name -> already bound, drop the export
+                    }
                     _ => {
                         // export default {...} -> const as_name = {...}
                         output.push_str("const ");
@@ -348,4 +351,12 @@ export default {
         assert!(has_default);
         assert!(result.contains("const _sfc_main = a"));
     }
+
+    #[test]
+    fn test_default_export_of_target() {
+        let input = "const _sfc_main = {}\nexport default _sfc_main\n";
+        let (result, has_default) = rewrite_default(input, "_sfc_main", false);
+        assert!(has_default);
+        assert_eq!(result.trim(), "const _sfc_main = {}");
+    }
 }
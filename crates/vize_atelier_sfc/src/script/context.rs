@@ -128,6 +128,7 @@ impl ScriptCompileContext {
                         required: false, // We don't track this in the current implementation
                         prop_type: None,
                         default_value: props_call.binding_name.clone().map(CompactString::new),
+                        description: None,
                     });
                 }
             }
@@ -149,6 +150,7 @@ impl ScriptCompileContext {
                         summary.macros.add_emit(EmitDefinition {
                             name: CompactString::new(name),
                             payload_type: None,
+                            description: None,
                         });
                     }
                 }
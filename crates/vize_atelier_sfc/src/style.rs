@@ -22,6 +22,68 @@ pub fn compile_style(
     Ok(output)
 }
 
+/// Compile a style block for the shadow root of a custom element.
+///
+/// The shadow root already isolates the styles, so `scoped` is ignored and
+/// `:host` selectors are kept as written. Scoping pseudo-classes lower to
+/// plain CSS: `:deep(x)` and `:global(x)` to `x`, `:slotted(x)` to the
+/// native `::slotted(x)`.
+pub fn compile_shadow_style(
+    style: &SfcStyleBlock,
+    options: &StyleCompileOptions,
+) -> Result<String, SfcError> {
+    let output = lower_scoping_pseudos(&style.content);
+    if options.trim {
+        Ok(output.trim().to_string())
+    } else {
+        Ok(output)
+    }
+}
+
+/// Replace `:deep()`, `:global()` and `:slotted()` with their shadow DOM
+/// equivalents
+fn lower_scoping_pseudos(css: &str) -> String {
+    const PSEUDOS: [(&str, &str); 3] = [
+        (":deep(", ""),
+        (":global(", ""),
+        (":slotted(", "::slotted("),
+    ];
+
+    let mut output = String::with_capacity(css.len());
+    let mut rest = css;
+    'scan: while let Some(c) = rest.chars().next() {
+        // `::slotted(` is already native
+        if c == ':' && !output.ends_with(':') {
+            for (pseudo, replacement) in PSEUDOS {
+                let Some(after) = rest.strip_prefix(pseudo) else {
+                    continue;
+                };
+                let mut depth = 1;
+                let close = after.find(|c| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                });
+                if let Some(close) = close {
+                    output.push_str(replacement);
+                    output.push_str(&lower_scoping_pseudos(&after[..close]));
+                    if !replacement.is_empty() {
+                        output.push(')');
+                    }
+                    rest = &after[close + 1..];
+                    continue 'scan;
+                }
+            }
+        }
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    output
+}
+
 /// Apply scoped CSS transformation
 pub fn apply_scoped_css(css: &str, scope_id: &str) -> String {
     let mut attr_selector = String::with_capacity(scope_id.len() + 2);
@@ -384,6 +446,20 @@ mod tests {
         assert_eq!(result, ".foo");
     }
 
+    #[test]
+    fn test_lower_scoping_pseudos() {
+        let css = ":host(.active) .a :deep(.b:not(.c)) { color: red; }\n\
+                   :slotted(p), ::slotted(span) { margin: 0; }\n\
+                   :global(body) .d:hover { display: block; }";
+        assert_eq!(
+            lower_scoping_pseudos(css),
+            ":host(.active) .a .b:not(.c) { color: red; }\n\
+             ::slotted(p), ::slotted(span) { margin: 0; }\n\
+             body .d:hover { display: block; }"
+        );
+        assert_eq!(lower_scoping_pseudos(".a :deep(.b"), ".a :deep(.b");
+    }
+
     #[test]
     fn test_extract_css_vars() {
         let css = ".foo { color: v-bind(color); background: v-bind('bgColor'); }";
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use vize_carton::FxHashMap;
 
+use crate::custom_block::CustomBlockTransforms;
+
 // Re-export from vize_relief to avoid duplication
 pub use vize_atelier_core::options::{BindingMetadata, BindingType};
 
@@ -48,6 +50,11 @@ pub struct SfcDescriptor<'a> {
     /// Whether the component should inherit attrs
     #[serde(default)]
     pub should_force_reload: bool,
+
+    /// Whether the content of `src` blocks was loaded by
+    /// [`resolve_src_blocks`](crate::resolve_src_blocks)
+    #[serde(default)]
+    pub src_resolved: bool,
 }
 
 impl<'a> Default for SfcDescriptor<'a> {
@@ -63,6 +70,7 @@ impl<'a> Default for SfcDescriptor<'a> {
             css_vars: Vec::new(),
             slotted: false,
             should_force_reload: false,
+            src_resolved: false,
         }
     }
 }
@@ -89,6 +97,7 @@ impl<'a> SfcDescriptor<'a> {
                 .collect(),
             slotted: self.slotted,
             should_force_reload: self.should_force_reload,
+            src_resolved: self.src_resolved,
         }
     }
 
@@ -389,6 +398,19 @@ pub struct SfcCompileOptions {
 
     /// Style compile options
     pub style: StyleCompileOptions,
+
+    /// Scope ID (without the `data-v-` prefix), e.g. from [`crate::hash_scope_id`].
+    /// Derived from the script `id` when unset.
+    pub scope_id: Option<String>,
+
+    /// Compile for `defineCustomElement`: styles are inlined as the `styles`
+    /// option and isolated by the shadow root instead of scope attributes.
+    /// Always enabled for `*.ce.vue` files.
+    pub custom_element: bool,
+
+    /// Transforms of custom blocks such as `<i18n>` (none by default); other
+    /// custom blocks are dropped
+    pub custom_blocks: CustomBlockTransforms,
 }
 
 /// Script compile options
@@ -411,6 +433,10 @@ pub struct ScriptCompileOptions {
 
     /// Define model options
     pub define_model: bool,
+
+    /// Production mode: type-based props keep only what the runtime needs
+    /// (`type` for Boolean casting, `default`), without validation
+    pub is_prod: bool,
 }
 
 /// Props destructure mode
@@ -0,0 +1,675 @@
+//! Persistent cache for incremental type checking.
+//!
+//! Generated virtual TypeScript and per-file diagnostics are stored under
+//! `node_modules/.vize/canon/.cache`, keyed by content hash. On the next run
+//! only modules whose content (or resolved imports) changed, plus their
+//! transitive dependents in the [`DependencyGraph`], need to be re-checked.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Statement;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde::{Deserialize, Serialize};
+use vize_carton::hash::content_hash;
+use vize_carton::{FxHashMap, FxHashSet};
+use vize_croquis::cross_file::{DependencyEdge, DependencyGraph, FileId, ModuleNode};
+
+use crate::virtual_ts::VizeMapping;
+
+/// Cache format version. Bumped whenever the layout or the virtual TS output changes.
+const CACHE_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "+2");
+
+/// Manifest file name inside the cache directory.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Extensions tried when resolving extensionless relative imports.
+const RESOLVE_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "d.ts", "js", "mjs", "vue"];
+
+/// A module as it exists on disk for the current run.
+#[derive(Debug, Clone)]
+pub struct ModuleState {
+    /// Absolute module path.
+    pub path: PathBuf,
+    /// Content hash of the module source.
+    pub content_hash: String,
+    /// Import specifiers as written in the source.
+    pub specifiers: Vec<String>,
+    /// Imports that resolved to a file on disk.
+    pub resolved: Vec<PathBuf>,
+}
+
+impl ModuleState {
+    /// Create a module state, resolving its relative and aliased import
+    /// specifiers.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        content_hash: String,
+        specifiers: Vec<String>,
+        aliases: &PathAliases,
+    ) -> Self {
+        let path = path.into();
+        let resolved = specifiers
+            .iter()
+            .filter_map(|specifier| resolve_import(&path, specifier, aliases))
+            .collect();
+        Self {
+            path,
+            content_hash,
+            specifiers,
+            resolved,
+        }
+    }
+}
+
+/// A cached module entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedModule {
+    /// Content hash when the entry was written.
+    pub content_hash: String,
+    /// Import specifiers as written in the source.
+    #[serde(default)]
+    pub specifiers: Vec<String>,
+    /// Imports that resolved to a file on disk.
+    #[serde(default)]
+    pub resolved: Vec<PathBuf>,
+    /// Diagnostics, for modules that were type checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagnostics: Option<Vec<CachedDiagnostic>>,
+    /// Number of errors among the diagnostics.
+    #[serde(default)]
+    pub error_count: usize,
+}
+
+impl CachedModule {
+    /// Create an entry for a module state without diagnostics.
+    pub fn new(state: &ModuleState) -> Self {
+        Self {
+            content_hash: state.content_hash.clone(),
+            specifiers: state.specifiers.clone(),
+            resolved: state.resolved.clone(),
+            diagnostics: None,
+            error_count: 0,
+        }
+    }
+
+    /// Attach diagnostics to the entry.
+    pub fn with_diagnostics(
+        mut self,
+        diagnostics: Vec<CachedDiagnostic>,
+        error_count: usize,
+    ) -> Self {
+        self.diagnostics = Some(diagnostics);
+        self.error_count = error_count;
+        self
+    }
+}
+
+/// A diagnostic mapped back to its SFC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedDiagnostic {
+    /// Severity (1=Error, 2=Warning, 3=Info, 4=Hint).
+    pub severity: u8,
+    /// Diagnostic code (e.g. `TS2322`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Error message.
+    pub message: String,
+    /// Start line (1-based).
+    pub line: u32,
+    /// Start column (1-based).
+    pub column: u32,
+    /// End line (1-based).
+    pub end_line: u32,
+    /// End column (1-based).
+    pub end_column: u32,
+}
+
+/// Cached virtual TypeScript for a single SFC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedVirtualTs {
+    /// Generated TypeScript code.
+    pub code: String,
+    /// Mappings from the generated code back to the SFC.
+    pub mappings: Vec<VizeMapping>,
+    /// Import specifiers of the SFC's script blocks.
+    pub specifiers: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    version: String,
+    config_hash: String,
+    modules: BTreeMap<PathBuf, CachedModule>,
+}
+
+/// Persistent check cache under `node_modules/.vize/canon/.cache`.
+pub struct CheckCache {
+    dir: PathBuf,
+    config_hash: String,
+    manifest: Manifest,
+}
+
+impl CheckCache {
+    /// Get the cache directory for a project.
+    pub fn dir_for(project_root: &Path) -> PathBuf {
+        project_root
+            .join("node_modules")
+            .join(".vize")
+            .join("canon")
+            .join(".cache")
+    }
+
+    /// Create an empty cache for a project without reading the stored manifest.
+    pub fn new(project_root: &Path, config_hash: impl Into<String>) -> Self {
+        Self {
+            dir: Self::dir_for(project_root),
+            config_hash: config_hash.into(),
+            manifest: Manifest::default(),
+        }
+    }
+
+    /// Open the cache for a project.
+    ///
+    /// `config_hash` identifies everything besides file contents that affects
+    /// the result (tsconfig, template globals, ...). A mismatch with the stored
+    /// manifest discards all cached diagnostics.
+    pub fn open(project_root: &Path, config_hash: impl Into<String>) -> Self {
+        let dir = Self::dir_for(project_root);
+        let config_hash = config_hash.into();
+
+        let manifest = std::fs::read_to_string(dir.join(MANIFEST_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Manifest>(&content).ok())
+            .filter(|m| m.version == CACHE_VERSION && m.config_hash == config_hash)
+            .unwrap_or_default();
+
+        Self {
+            dir,
+            config_hash,
+            manifest,
+        }
+    }
+
+    /// Get the cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Get a cached module entry.
+    pub fn module(&self, path: &Path) -> Option<&CachedModule> {
+        self.manifest.modules.get(path)
+    }
+
+    /// Number of modules in the manifest.
+    pub fn len(&self) -> usize {
+        self.manifest.modules.len()
+    }
+
+    /// Whether the manifest is empty.
+    pub fn is_empty(&self) -> bool {
+        self.manifest.modules.is_empty()
+    }
+
+    /// Load cached virtual TypeScript for an SFC with the given content hash.
+    pub fn virtual_ts(&self, content_hash: &str) -> Option<CachedVirtualTs> {
+        let content = std::fs::read_to_string(self.virtual_ts_path(content_hash)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Store generated virtual TypeScript for an SFC with the given content hash.
+    pub fn store_virtual_ts(
+        &self,
+        content_hash: &str,
+        virtual_ts: &CachedVirtualTs,
+    ) -> std::io::Result<()> {
+        let path = self.virtual_ts_path(content_hash);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(virtual_ts)?)
+    }
+
+    /// Compute the modules that must be re-checked.
+    ///
+    /// A module is changed if it is new, its content hash differs, or its
+    /// imports now resolve differently. Every transitive dependent of a
+    /// changed module is invalidated as well. Modules in `checked` must also
+    /// have cached diagnostics to be considered up to date.
+    pub fn invalidated(
+        &self,
+        modules: &[ModuleState],
+        checked: &FxHashSet<PathBuf>,
+    ) -> FxHashSet<PathBuf> {
+        let graph = build_graph(modules);
+
+        let mut invalid = FxHashSet::default();
+        for (index, module) in modules.iter().enumerate() {
+            let changed = match self.manifest.modules.get(&module.path) {
+                Some(cached) => {
+                    cached.content_hash != module.content_hash
+                        || cached.resolved != module.resolved
+                        || (checked.contains(&module.path) && cached.diagnostics.is_none())
+                }
+                None => true,
+            };
+            if !changed {
+                continue;
+            }
+
+            invalid.insert(module.path.clone());
+            for dependent in graph.transitive_dependents(FileId::new(index as u32)) {
+                invalid.insert(modules[dependent.as_u32() as usize].path.clone());
+            }
+        }
+
+        invalid
+    }
+
+    /// Insert or replace a module entry.
+    pub fn update(&mut self, path: impl Into<PathBuf>, module: CachedModule) {
+        self.manifest.modules.insert(path.into(), module);
+    }
+
+    /// Drop entries for modules that no longer exist.
+    pub fn retain(&mut self, mut keep: impl FnMut(&Path) -> bool) {
+        self.manifest.modules.retain(|path, _| keep(path));
+    }
+
+    /// Write the manifest to disk and prune unreferenced virtual TS files.
+    pub fn save(&mut self) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+
+        self.manifest.version = CACHE_VERSION.to_string();
+        self.manifest.config_hash = self.config_hash.clone();
+        std::fs::write(
+            self.dir.join(MANIFEST_FILE),
+            serde_json::to_string(&self.manifest)?,
+        )?;
+
+        let referenced: FxHashSet<PathBuf> = self
+            .manifest
+            .modules
+            .values()
+            .map(|m| self.virtual_ts_path(&m.content_hash))
+            .collect();
+        if let Ok(entries) = std::fs::read_dir(self.dir.join("vts")) {
+            for entry in entries.flatten() {
+                if !referenced.contains(&entry.path()) {
+                    let _ = std::fs::remove_file(entry.path());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn virtual_ts_path(&self, hash: &str) -> PathBuf {
+        // Virtual TS also depends on the generator version and the check
+        // configuration
+        let key = content_hash(&format!(
+            "{}\0{}\0{}",
+            CACHE_VERSION, hash, self.config_hash
+        ));
+        self.dir.join("vts").join(format!("{}.json", key))
+    }
+}
+
+/// Build a dependency graph over the given modules, using their indices as file IDs.
+fn build_graph(modules: &[ModuleState]) -> DependencyGraph {
+    let mut graph = DependencyGraph::new();
+    let mut ids: FxHashMap<&Path, FileId> = FxHashMap::default();
+
+    for (index, module) in modules.iter().enumerate() {
+        let id = FileId::new(index as u32);
+        ids.insert(module.path.as_path(), id);
+        graph.add_node(ModuleNode::new(id, module.path.to_string_lossy().as_ref()));
+    }
+    for (index, module) in modules.iter().enumerate() {
+        for import in &module.resolved {
+            if let Some(&target) = ids.get(import.as_path()) {
+                graph.add_edge(FileId::new(index as u32), target, DependencyEdge::Import);
+            }
+        }
+    }
+
+    graph
+}
+
+/// Collect the module specifiers imported or re-exported by a script.
+///
+/// Scripts that fail to parse yield the specifiers found before the error.
+pub fn script_imports(source: &str, source_type: SourceType) -> Vec<String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+
+    let mut specifiers = Vec::new();
+    for stmt in &ret.program.body {
+        let source = match stmt {
+            Statement::ImportDeclaration(decl) => Some(&decl.source),
+            Statement::ExportNamedDeclaration(decl) => decl.source.as_ref(),
+            Statement::ExportAllDeclaration(decl) => Some(&decl.source),
+            _ => None,
+        };
+        if let Some(source) = source {
+            let specifier = source.value.to_string();
+            if !specifiers.contains(&specifier) {
+                specifiers.push(specifier);
+            }
+        }
+    }
+    specifiers
+}
+
+/// `compilerOptions.paths` of a project's `tsconfig.json`.
+#[derive(Debug, Clone, Default)]
+pub struct PathAliases {
+    /// Directory the alias targets are relative to (`baseUrl`).
+    base_url: PathBuf,
+    /// Alias patterns and their targets, e.g. `@/*` -> `["src/*"]`.
+    paths: Vec<(String, Vec<String>)>,
+}
+
+impl PathAliases {
+    /// Read the path aliases of the `tsconfig.json` in a project root.
+    ///
+    /// A missing or unparsable tsconfig yields no aliases.
+    pub fn load(project_root: &Path) -> Self {
+        std::fs::read_to_string(project_root.join("tsconfig.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|config| config.get("compilerOptions").cloned())
+            .map(|options| Self::from_compiler_options(project_root, &options))
+            .unwrap_or_default()
+    }
+
+    fn from_compiler_options(project_root: &Path, options: &serde_json::Value) -> Self {
+        let base_url = options
+            .get("baseUrl")
+            .and_then(|base| base.as_str())
+            .map_or_else(
+                || project_root.to_path_buf(),
+                |base| project_root.join(base),
+            );
+        let paths = options
+            .get("paths")
+            .and_then(|paths| paths.as_object())
+            .map(|paths| {
+                paths
+                    .iter()
+                    .map(|(pattern, targets)| {
+                        let targets = targets
+                            .as_array()
+                            .map(|targets| {
+                                targets
+                                    .iter()
+                                    .filter_map(|t| t.as_str().map(str::to_string))
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        (pattern.clone(), targets)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            base_url: normalize(&base_url),
+            paths,
+        }
+    }
+
+    /// Resolve an aliased specifier such as `@/types` to a file on disk.
+    pub fn resolve(&self, specifier: &str) -> Option<PathBuf> {
+        for (pattern, targets) in &self.paths {
+            let matched = match pattern.split_once('*') {
+                Some((prefix, suffix)) => specifier
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(suffix)),
+                None => (specifier == pattern).then_some(""),
+            };
+            let Some(matched) = matched else {
+                continue;
+            };
+            for target in targets {
+                let target = self.base_url.join(target.replacen('*', matched, 1));
+                if let Some(resolved) = resolve_file(&target) {
+                    return Some(resolved);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Resolve a relative or aliased import specifier to a file on disk.
+///
+/// Package specifiers are not resolved.
+pub fn resolve_import(from: &Path, specifier: &str, aliases: &PathAliases) -> Option<PathBuf> {
+    if !specifier.starts_with('.') {
+        return aliases.resolve(specifier);
+    }
+    resolve_file(&from.parent()?.join(specifier))
+}
+
+/// Resolve an import target, trying TypeScript extensions and index files.
+fn resolve_file(base: &Path) -> Option<PathBuf> {
+    if base.is_file() {
+        return Some(normalize(base));
+    }
+    // `./foo.js` may refer to `./foo.ts`
+    if base
+        .extension()
+        .is_some_and(|ext| ext == "js" || ext == "mjs")
+    {
+        for ext in ["ts", "mts", "tsx"] {
+            let candidate = base.with_extension(ext);
+            if candidate.is_file() {
+                return Some(normalize(&candidate));
+            }
+        }
+    }
+    for ext in RESOLVE_EXTENSIONS {
+        let candidate = PathBuf::from(format!("{}.{}", base.display(), ext));
+        if candidate.is_file() {
+            return Some(normalize(&candidate));
+        }
+    }
+    for ext in RESOLVE_EXTENSIONS {
+        let candidate = base.join(format!("index.{}", ext));
+        if candidate.is_file() {
+            return Some(normalize(&candidate));
+        }
+    }
+    None
+}
+
+/// Lexically normalize `.` and `..` components.
+fn normalize(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn state(root: &Path, name: &str, specifiers: &[&str]) -> ModuleState {
+        let path = root.join(name);
+        let content = std::fs::read_to_string(&path).unwrap();
+        ModuleState::new(
+            path,
+            content_hash(&content),
+            specifiers.iter().map(|s| s.to_string()).collect(),
+            &PathAliases::default(),
+        )
+    }
+
+    #[test]
+    fn test_script_imports() {
+        let specifiers = script_imports(
+            "import A from './A.vue'\nimport type { T } from './types'\nexport * from './utils'\nimport { ref } from 'vue'\n",
+            SourceType::ts(),
+        );
+        assert_eq!(specifiers, vec!["./A.vue", "./types", "./utils", "vue"]);
+    }
+
+    #[test]
+    fn test_resolve_import() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("lib")).unwrap();
+        std::fs::write(root.join("App.vue"), "").unwrap();
+        std::fs::write(root.join("types.ts"), "").unwrap();
+        std::fs::write(root.join("lib/index.ts"), "").unwrap();
+
+        let from = root.join("App.vue");
+        let aliases = PathAliases::default();
+        assert_eq!(
+            resolve_import(&from, "./types", &aliases),
+            Some(root.join("types.ts"))
+        );
+        assert_eq!(
+            resolve_import(&from, "./types.js", &aliases),
+            Some(root.join("types.ts"))
+        );
+        assert_eq!(
+            resolve_import(&from, "./lib", &aliases),
+            Some(root.join("lib/index.ts"))
+        );
+        assert_eq!(resolve_import(&from, "vue", &aliases), None);
+        assert_eq!(resolve_import(&from, "./missing", &aliases), None);
+    }
+
+    #[test]
+    fn test_resolve_path_alias() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("src/types")).unwrap();
+        std::fs::write(root.join("src/types/index.ts"), "").unwrap();
+        std::fs::write(root.join("src/env.ts"), "").unwrap();
+        std::fs::write(
+            root.join("tsconfig.json"),
+            r##"{ "compilerOptions": { "paths": { "@/*": ["./src/*"], "#env": ["src/env.ts"] } } }"##,
+        )
+        .unwrap();
+
+        let aliases = PathAliases::load(root);
+        let from = root.join("src/App.vue");
+        assert_eq!(
+            resolve_import(&from, "@/types", &aliases),
+            Some(root.join("src/types/index.ts"))
+        );
+        assert_eq!(
+            resolve_import(&from, "#env", &aliases),
+            Some(root.join("src/env.ts"))
+        );
+        assert_eq!(resolve_import(&from, "@/missing", &aliases), None);
+        assert_eq!(resolve_import(&from, "vue", &aliases), None);
+    }
+
+    #[test]
+    fn test_invalidated_transitive_dependents() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(root.join("types.ts"), "export type Id = number").unwrap();
+        std::fs::write(root.join("Child.vue"), "child").unwrap();
+        std::fs::write(root.join("Parent.vue"), "parent").unwrap();
+        std::fs::write(root.join("Other.vue"), "other").unwrap();
+
+        let modules = || {
+            vec![
+                state(root, "types.ts", &[]),
+                state(root, "Child.vue", &["./types"]),
+                state(root, "Parent.vue", &["./Child.vue"]),
+                state(root, "Other.vue", &[]),
+            ]
+        };
+        let checked: FxHashSet<PathBuf> = ["Child.vue", "Parent.vue", "Other.vue"]
+            .iter()
+            .map(|name| root.join(name))
+            .collect();
+
+        // Everything is new
+        let mut cache = CheckCache::open(root, "config");
+        let current = modules();
+        assert_eq!(cache.invalidated(&current, &checked).len(), 4);
+
+        let diagnostic = CachedDiagnostic {
+            severity: 1,
+            code: Some("TS2322".to_string()),
+            message: "Type 'number' is not assignable to type 'string'.".to_string(),
+            line: 3,
+            column: 7,
+            end_line: 3,
+            end_column: 9,
+        };
+        for module in &current {
+            let entry = CachedModule::new(module);
+            let entry = if checked.contains(&module.path) {
+                entry.with_diagnostics(vec![diagnostic.clone()], 1)
+            } else {
+                entry
+            };
+            cache.update(module.path.clone(), entry);
+        }
+        cache.save().unwrap();
+
+        // Nothing changed
+        let cache = CheckCache::open(root, "config");
+        assert!(cache.invalidated(&modules(), &checked).is_empty());
+        let child = cache.module(&root.join("Child.vue")).unwrap();
+        assert_eq!(child.diagnostics, Some(vec![diagnostic]));
+        assert_eq!(child.error_count, 1);
+
+        // A type change invalidates the importer and its parent, but not siblings
+        std::fs::write(root.join("types.ts"), "export type Id = string").unwrap();
+        let invalid = cache.invalidated(&modules(), &checked);
+        assert!(invalid.contains(&root.join("types.ts")));
+        assert!(invalid.contains(&root.join("Child.vue")));
+        assert!(invalid.contains(&root.join("Parent.vue")));
+        assert!(!invalid.contains(&root.join("Other.vue")));
+
+        // A different configuration discards the manifest
+        let cache = CheckCache::open(root, "other-config");
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_virtual_ts_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let mut cache = CheckCache::open(temp.path(), "config");
+        let vts = CachedVirtualTs {
+            code: "const a = 1".to_string(),
+            mappings: vec![VizeMapping {
+                gen_range: 0..5,
+                src_range: 10..15,
+            }],
+            specifiers: vec!["./a".to_string()],
+        };
+
+        cache.store_virtual_ts("abc", &vts).unwrap();
+        let loaded = cache.virtual_ts("abc").unwrap();
+        assert_eq!(loaded.code, vts.code);
+        assert_eq!(loaded.mappings, vts.mappings);
+
+        // Unreferenced virtual TS is pruned on save
+        cache.save().unwrap();
+        assert!(cache.virtual_ts("abc").is_none());
+    }
+}
@@ -4,6 +4,7 @@
 //! It transforms Vue SFC files into pure TypeScript and runs tsgo on
 //! the virtualized project in `node_modules/.vize/canon/`.
 
+mod cache;
 mod error;
 mod executor;
 mod import_rewriter;
@@ -12,6 +13,10 @@ mod type_checker;
 mod virtual_project;
 mod virtual_ts;
 
+pub use cache::{
+    resolve_import, script_imports, CachedDiagnostic, CachedModule, CachedVirtualTs, CheckCache,
+    ModuleState, PathAliases,
+};
 pub use error::{PackageManager, TsgoError, TsgoNotFoundError, TsgoResult};
 pub use executor::TsgoExecutor;
 pub use import_rewriter::{ImportRewriter, ImportSourceMap, OffsetAdjustment, RewriteResult};
@@ -145,25 +145,43 @@ impl VirtualProject {
     }
 
     /// Materialize the virtual project to disk.
+    ///
+    /// Only files whose content changed are rewritten, so tsgo and the
+    /// filesystem see unchanged files as untouched. Stale files from earlier
+    /// runs are removed; the check cache in `.cache` is left alone.
     pub fn materialize(&self) -> TsgoResult<()> {
-        // 1. Create/clean the virtual root
-        if self.virtual_root.exists() {
-            std::fs::remove_dir_all(&self.virtual_root)?;
-        }
+        // 1. Create the virtual root and remove stale files
         std::fs::create_dir_all(&self.virtual_root)?;
+        self.remove_stale_files()?;
 
-        // 2. Write all virtual files
+        // 2. Write changed virtual files
         for (path, file) in &self.virtual_files {
-            if let Some(parent) = path.parent() {
-                std::fs::create_dir_all(parent)?;
-            }
-            std::fs::write(path, &file.content)?;
+            write_if_changed(path, &file.content)?;
         }
 
         // 3. Generate tsconfig.json
         let tsconfig = self.generate_tsconfig()?;
-        std::fs::write(self.virtual_root.join("tsconfig.json"), tsconfig)?;
+        write_if_changed(&self.virtual_root.join("tsconfig.json"), &tsconfig)?;
+
+        Ok(())
+    }
 
+    /// Remove virtual files that are no longer part of the project.
+    fn remove_stale_files(&self) -> TsgoResult<()> {
+        let cache_dir = self.virtual_root.join(".cache");
+        for entry in walkdir::WalkDir::new(&self.virtual_root)
+            .into_iter()
+            .filter_entry(|e| e.path() != cache_dir)
+        {
+            let entry = entry?;
+            let path = entry.path();
+            let is_ts = path
+                .extension()
+                .is_some_and(|ext| ext == "ts" || ext == "tsx");
+            if entry.file_type().is_file() && is_ts && !self.virtual_files.contains_key(path) {
+                std::fs::remove_file(path)?;
+            }
+        }
         Ok(())
     }
 
@@ -303,6 +321,17 @@ impl VirtualProject {
     }
 }
 
+/// Write a file, skipping the write when the content is unchanged.
+fn write_if_changed(path: &Path, content: &str) -> std::io::Result<()> {
+    if std::fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -376,4 +405,20 @@ const message = 'Hello'
             .join("node_modules/.vize/canon/tsconfig.json");
         assert!(tsconfig.exists());
     }
+
+    #[test]
+    fn test_materialize_removes_stale_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let canon = temp_dir.path().join("node_modules/.vize/canon");
+        fs::create_dir_all(canon.join("src")).unwrap();
+        fs::create_dir_all(canon.join(".cache")).unwrap();
+        fs::write(canon.join("src/Removed.vue.ts"), "stale").unwrap();
+        fs::write(canon.join(".cache/manifest.json"), "{}").unwrap();
+
+        let project = VirtualProject::new(temp_dir.path()).unwrap();
+        project.materialize().unwrap();
+
+        assert!(!canon.join("src/Removed.vue.ts").exists());
+        assert!(canon.join(".cache/manifest.json").exists());
+    }
 }
@@ -1,7 +1,17 @@
 //! Type checker for Vue SFC templates.
 
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Expression;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use vize_carton::Bump;
+use vize_relief::ast::{
+    DirectiveNode, ElementNode, ElementType, ExpressionNode, PropNode, TemplateChildNode,
+};
+
 use crate::context::TypeContext;
 use crate::diagnostic::{TypeDiagnostic, TypeErrorCode};
+use crate::infer::{Env, Inferrer, ObjectType, Property, Type};
 use crate::types::{CompletionItem, CompletionKind, TypeInfo};
 use crate::CheckResult;
 
@@ -48,6 +58,10 @@ impl TypeChecker {
 
     /// Check a template against a type context.
     ///
+    /// Template expressions are parsed and their types inferred natively:
+    /// refs are unwrapped, `v-for` aliases get the element type of their
+    /// source, and member access and calls are checked against known types.
+    ///
     /// # Arguments
     /// * `template` - The template content to check
     /// * `ctx` - The type context from the script block
@@ -55,378 +69,32 @@ impl TypeChecker {
     /// # Returns
     /// A CheckResult containing any type errors found
     pub fn check_template(&self, template: &str, ctx: &TypeContext) -> CheckResult {
-        let mut result = CheckResult::new();
-
-        // Find all expression interpolations {{ ... }}
-        self.check_interpolations(template, ctx, &mut result);
-
-        // Find all directive expressions (v-if, v-for, etc.)
-        self.check_directives(template, ctx, &mut result);
-
-        // Find all event handlers (@click, v-on:click)
-        self.check_event_handlers(template, ctx, &mut result);
-
-        // Find all v-bind expressions (:prop, v-bind:prop)
-        self.check_bindings(template, ctx, &mut result);
-
-        result
-    }
-
-    /// Check interpolation expressions {{ expr }}.
-    fn check_interpolations(&self, template: &str, ctx: &TypeContext, result: &mut CheckResult) {
-        let mut pos = 0;
-        while let Some(start) = template[pos..].find("{{") {
-            let abs_start = pos + start;
-            if let Some(end) = template[abs_start..].find("}}") {
-                let expr_start = abs_start + 2;
-                let expr_end = abs_start + end;
-                let expr = template[expr_start..expr_end].trim();
-
-                if !expr.is_empty() {
-                    self.check_expression(expr, expr_start as u32, expr_end as u32, ctx, result);
-                }
-
-                pos = abs_start + end + 2;
-            } else {
-                break;
-            }
-        }
-    }
-
-    /// Check directive expressions.
-    fn check_directives(&self, template: &str, ctx: &TypeContext, result: &mut CheckResult) {
-        // Check v-if, v-else-if, v-show expressions
-        for directive in ["v-if", "v-else-if", "v-show"] {
-            self.check_directive_values(template, directive, ctx, result);
-        }
-
-        // v-for has special syntax: "item in items" or "(item, index) in items"
-        self.check_vfor_expressions(template, ctx, result);
-    }
-
-    /// Check values of a specific directive.
-    fn check_directive_values(
-        &self,
-        template: &str,
-        directive: &str,
-        ctx: &TypeContext,
-        result: &mut CheckResult,
-    ) {
-        let pattern = format!("{}=\"", directive);
-        let mut pos = 0;
-
-        while let Some(start) = template[pos..].find(&pattern) {
-            let abs_start = pos + start + pattern.len();
-            if let Some(end) = template[abs_start..].find('"') {
-                let expr = &template[abs_start..abs_start + end];
-                if !expr.is_empty() {
-                    self.check_expression(
-                        expr,
-                        abs_start as u32,
-                        (abs_start + end) as u32,
-                        ctx,
-                        result,
-                    );
-                }
-                pos = abs_start + end + 1;
-            } else {
-                break;
-            }
-        }
-    }
-
-    /// Check v-for expressions.
-    fn check_vfor_expressions(&self, template: &str, ctx: &TypeContext, result: &mut CheckResult) {
-        let pattern = "v-for=\"";
-        let mut pos = 0;
-
-        while let Some(start) = template[pos..].find(pattern) {
-            let abs_start = pos + start + pattern.len();
-            if let Some(end) = template[abs_start..].find('"') {
-                let expr = &template[abs_start..abs_start + end];
-
-                // Parse "item in items" or "(item, index) in items"
-                if let Some(in_pos) = expr.find(" in ") {
-                    let iterable = expr[in_pos + 4..].trim();
-                    let iterable_start = abs_start + in_pos + 4;
-                    self.check_expression(
-                        iterable,
-                        iterable_start as u32,
-                        (abs_start + end) as u32,
-                        ctx,
-                        result,
-                    );
-                }
-
-                pos = abs_start + end + 1;
-            } else {
-                break;
-            }
-        }
-    }
-
-    /// Check event handlers.
-    fn check_event_handlers(&self, template: &str, ctx: &TypeContext, result: &mut CheckResult) {
-        // Check @event="handler" and v-on:event="handler"
-        let patterns = ["@", "v-on:"];
-
-        for pattern in patterns {
-            let mut pos = 0;
-            while let Some(start) = template[pos..].find(pattern) {
-                let abs_start = pos + start + pattern.len();
-
-                // Find the end of the event name and the ="
-                if let Some(eq_pos) = template[abs_start..].find("=\"") {
-                    let handler_start = abs_start + eq_pos + 2;
-                    if let Some(end) = template[handler_start..].find('"') {
-                        let handler = &template[handler_start..handler_start + end];
-
-                        // Simple handler (just a function name)
-                        if Self::is_simple_identifier(handler) {
-                            self.check_identifier(
-                                handler,
-                                handler_start as u32,
-                                (handler_start + end) as u32,
-                                ctx,
-                                result,
-                            );
-                        } else if !handler.is_empty() {
-                            // Inline handler expression
-                            self.check_expression(
-                                handler,
-                                handler_start as u32,
-                                (handler_start + end) as u32,
-                                ctx,
-                                result,
-                            );
-                        }
-
-                        pos = handler_start + end + 1;
-                    } else {
-                        break;
-                    }
-                } else {
-                    pos = abs_start + 1;
-                }
-            }
-        }
-    }
-
-    /// Check v-bind expressions.
-    fn check_bindings(&self, template: &str, ctx: &TypeContext, result: &mut CheckResult) {
-        // Check :prop="expr" and v-bind:prop="expr"
-        let patterns = [(":", "="), ("v-bind:", "=")];
-
-        for (prefix, suffix) in patterns {
-            let mut pos = 0;
-            while let Some(start) = template[pos..].find(prefix) {
-                // Skip :: (CSS pseudo-selectors)
-                if prefix == ":" && template[pos + start..].starts_with("::") {
-                    pos = pos + start + 2;
-                    continue;
-                }
-
-                let abs_start = pos + start + prefix.len();
-
-                // Find ="
-                if let Some(eq_pos) = template[abs_start..].find(&format!("{suffix}\"")) {
-                    let expr_start = abs_start + eq_pos + 2;
-                    if let Some(end) = template[expr_start..].find('"') {
-                        let expr = &template[expr_start..expr_start + end];
-                        if !expr.is_empty() {
-                            self.check_expression(
-                                expr,
-                                expr_start as u32,
-                                (expr_start + end) as u32,
-                                ctx,
-                                result,
-                            );
-                        }
-                        pos = expr_start + end + 1;
-                    } else {
-                        break;
-                    }
-                } else {
-                    pos = abs_start + 1;
-                }
-            }
-        }
-    }
-
-    /// Check a single expression.
-    fn check_expression(
-        &self,
-        expr: &str,
-        start: u32,
-        _end: u32,
-        ctx: &TypeContext,
-        result: &mut CheckResult,
-    ) {
-        // Extract identifiers from the expression and check each one
-        for (ident, offset) in Self::extract_identifiers(expr) {
-            let ident_start = start + offset as u32;
-            let ident_end = ident_start + ident.len() as u32;
-            self.check_identifier(ident, ident_start, ident_end, ctx, result);
-        }
-    }
-
-    /// Check if an identifier exists in the context.
-    fn check_identifier(
-        &self,
-        ident: &str,
-        start: u32,
-        end: u32,
-        ctx: &TypeContext,
-        result: &mut CheckResult,
-    ) {
-        // Skip keywords and literals
-        if Self::is_keyword_or_literal(ident) {
-            return;
-        }
-
-        // Skip $-prefixed globals ($event, $refs, etc.)
-        if ident.starts_with('$') {
-            return;
-        }
+        let env = template_env(ctx);
+        let mut walker = TemplateWalker::new(self, template, &env);
+        walker.walk();
 
-        // Check if the identifier is defined
-        if !ctx.has_binding(ident) && !ctx.globals.contains_key(ident) {
-            result.add_diagnostic(TypeDiagnostic::error(
-                TypeErrorCode::UnknownIdentifier,
-                format!("Cannot find name '{}'", ident),
-                start,
-                end,
-            ));
+        let mut result = CheckResult::new();
+        for diagnostic in walker.inferrer.diagnostics {
+            result.add_diagnostic(diagnostic);
         }
+        result
     }
 
     /// Get type information at a specific offset.
     ///
-    /// Returns the type of the expression or identifier at the given position.
+    /// Returns the type of the identifier or property name at the given position.
     pub fn get_type_at(&self, template: &str, offset: u32, ctx: &TypeContext) -> Option<TypeInfo> {
-        // Find what's at the offset
-        let offset = offset as usize;
-
-        // Check if we're in an interpolation
-        if let Some((expr, expr_start)) = self.find_expression_at(template, offset) {
-            let relative_offset = offset - expr_start;
-            return self.get_type_in_expression(&expr, relative_offset, ctx);
-        }
-
-        None
-    }
-
-    /// Find the expression containing the given offset.
-    fn find_expression_at(&self, template: &str, offset: usize) -> Option<(String, usize)> {
-        // Check interpolations
-        let mut pos = 0;
-        while let Some(start) = template[pos..].find("{{") {
-            let abs_start = pos + start;
-            if let Some(end) = template[abs_start..].find("}}") {
-                let expr_start = abs_start + 2;
-                let expr_end = abs_start + end;
-
-                if offset >= expr_start && offset <= expr_end {
-                    return Some((
-                        template[expr_start..expr_end].trim().to_string(),
-                        expr_start,
-                    ));
-                }
-
-                pos = abs_start + end + 2;
-            } else {
-                break;
-            }
-        }
-
-        // Check directive values
-        for directive in ["v-if", "v-else-if", "v-show", "v-for"] {
-            if let Some((expr, start)) = self.find_directive_expr_at(template, directive, offset) {
-                return Some((expr, start));
-            }
-        }
-
-        None
-    }
-
-    /// Find a directive expression at offset.
-    fn find_directive_expr_at(
-        &self,
-        template: &str,
-        directive: &str,
-        offset: usize,
-    ) -> Option<(String, usize)> {
-        let pattern = format!("{}=\"", directive);
-        let mut pos = 0;
-
-        while let Some(start) = template[pos..].find(&pattern) {
-            let abs_start = pos + start + pattern.len();
-            if let Some(end) = template[abs_start..].find('"') {
-                if offset >= abs_start && offset <= abs_start + end {
-                    return Some((template[abs_start..abs_start + end].to_string(), abs_start));
-                }
-                pos = abs_start + end + 1;
-            } else {
-                break;
-            }
-        }
-
-        None
-    }
-
-    /// Get type information within an expression.
-    fn get_type_in_expression(
-        &self,
-        expr: &str,
-        offset: usize,
-        ctx: &TypeContext,
-    ) -> Option<TypeInfo> {
-        // Find the identifier at the offset
-        let ident = self.find_identifier_at(expr, offset)?;
-
-        // Look up the type
-        if let Some(binding) = ctx.get_binding(&ident) {
-            return Some(binding.type_info.clone());
-        }
-
-        if let Some(type_info) = ctx.globals.get(&ident) {
-            return Some(type_info.clone());
-        }
-
-        None
-    }
-
-    /// Find identifier at offset within an expression.
-    fn find_identifier_at(&self, expr: &str, offset: usize) -> Option<String> {
-        if offset >= expr.len() {
-            return None;
-        }
-
-        let bytes = expr.as_bytes();
-
-        // Check if we're on an identifier character
-        if !Self::is_ident_char(bytes[offset] as char) {
-            return None;
-        }
-
-        // Find the start of the identifier
-        let mut start = offset;
-        while start > 0 && Self::is_ident_char(bytes[start - 1] as char) {
-            start -= 1;
-        }
-
-        // Find the end of the identifier
-        let mut end = offset;
-        while end < bytes.len() && Self::is_ident_char(bytes[end] as char) {
-            end += 1;
-        }
-
-        // First char must be a valid start char
-        if !Self::is_ident_start(bytes[start] as char) {
-            return None;
-        }
+        let env = template_env(ctx);
+        let mut walker = TemplateWalker::new(self, template, &env);
+        walker.walk();
 
-        Some(expr[start..end].to_string())
+        walker
+            .inferrer
+            .spans
+            .iter()
+            .filter(|(start, end, _)| *start <= offset && offset <= *end)
+            .min_by_key(|(start, end, _)| end - start)
+            .map(|(_, _, ty)| ty.to_type_info())
     }
 
     /// Get completions at a specific offset.
@@ -589,6 +257,392 @@ impl TypeChecker {
     }
 }
 
+/// JavaScript globals accessible in Vue templates.
+const TEMPLATE_GLOBALS: &[&str] = &[
+    "Infinity",
+    "undefined",
+    "NaN",
+    "isFinite",
+    "isNaN",
+    "parseFloat",
+    "parseInt",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Math",
+    "Number",
+    "Date",
+    "Array",
+    "Object",
+    "Boolean",
+    "String",
+    "RegExp",
+    "Map",
+    "Set",
+    "JSON",
+    "Intl",
+    "BigInt",
+    "console",
+    "Error",
+    "Symbol",
+];
+
+/// Build the inference environment for template expressions.
+///
+/// Top-level refs are unwrapped, as they are when accessed in templates.
+fn template_env(ctx: &TypeContext) -> Env {
+    let mut env = Env::default();
+
+    for (name, info) in &ctx.types {
+        env.types.insert(name.clone(), Type::from_type_info(info));
+    }
+
+    for &name in TEMPLATE_GLOBALS {
+        let ty = match name {
+            "undefined" => Type::Undefined,
+            "Infinity" | "NaN" => Type::Number,
+            _ => Type::Any,
+        };
+        env.values.insert(name.to_string(), ty);
+    }
+    for (name, info) in &ctx.globals {
+        env.values
+            .insert(name.clone(), Type::from_type_info(info).unwrap_ref());
+    }
+
+    if !ctx.props.is_empty() {
+        let mut props = Vec::with_capacity(ctx.props.len());
+        for prop in &ctx.props {
+            let ty = Type::from_type_info(&prop.type_info);
+            env.values.insert(prop.name.clone(), ty.clone());
+            props.push(Property {
+                name: prop.name.clone(),
+                ty,
+                optional: !prop.required,
+            });
+        }
+        env.values
+            .insert("$props".to_string(), Type::Object(ObjectType::new(props)));
+    }
+
+    for (name, binding) in &ctx.bindings {
+        env.values.insert(
+            name.clone(),
+            Type::from_type_info(&binding.type_info).unwrap_ref(),
+        );
+    }
+
+    env
+}
+
+/// Walks a template AST, inferring the type of every expression.
+struct TemplateWalker<'c, 'e> {
+    checker: &'c TypeChecker,
+    source: &'c str,
+    inferrer: Inferrer<'e>,
+}
+
+impl<'c, 'e> TemplateWalker<'c, 'e> {
+    fn new(checker: &'c TypeChecker, source: &'c str, env: &'e Env) -> Self {
+        Self {
+            checker,
+            source,
+            inferrer: Inferrer::reporting(env),
+        }
+    }
+
+    fn walk(&mut self) {
+        let allocator = Bump::new();
+        let (root, _errors) = vize_armature::parse(&allocator, self.source);
+        self.walk_children(&root.children);
+    }
+
+    fn walk_children(&mut self, children: &[TemplateChildNode<'_>]) {
+        for child in children {
+            match child {
+                TemplateChildNode::Element(el) => self.walk_element(el),
+                TemplateChildNode::Interpolation(interp) => {
+                    if let Some((content, start)) = self.expression_source(&interp.content) {
+                        self.check_expression(&content, start);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn walk_element(&mut self, el: &ElementNode<'_>) {
+        self.inferrer.push_scope();
+
+        let directives: Vec<&DirectiveNode<'_>> = el
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropNode::Directive(dir) => Some(dir.as_ref()),
+                PropNode::Attribute(_) => None,
+            })
+            .collect();
+
+        // `v-if` is evaluated before `v-for` aliases exist
+        for dir in directives
+            .iter()
+            .filter(|d| matches!(d.name.as_str(), "if" | "else-if"))
+        {
+            self.check_directive(el, dir);
+        }
+        for dir in directives.iter().filter(|d| d.name == "for") {
+            self.bind_for(dir);
+        }
+        for dir in directives.iter().filter(|d| d.name == "slot") {
+            if let Some((content, start)) = dir.exp.as_ref().and_then(|e| self.expression_source(e))
+            {
+                self.bind_params(&content, start, &[]);
+            }
+        }
+        for dir in directives
+            .iter()
+            .filter(|d| !matches!(d.name.as_str(), "if" | "else-if" | "for" | "slot"))
+        {
+            self.check_directive(el, dir);
+        }
+
+        self.walk_children(&el.children);
+        self.inferrer.pop_scope();
+    }
+
+    fn check_directive(&mut self, el: &ElementNode<'_>, dir: &DirectiveNode<'_>) {
+        // Dynamic arguments (`:[key]`, `@[event]`)
+        if let Some(ExpressionNode::Simple(arg)) = &dir.arg {
+            if !arg.is_static {
+                if let Some((content, start)) =
+                    dir.arg.as_ref().and_then(|a| self.expression_source(a))
+                {
+                    self.check_expression(&content, start);
+                }
+            }
+        }
+
+        let Some((content, start)) = dir.exp.as_ref().and_then(|e| self.expression_source(e))
+        else {
+            return;
+        };
+
+        if dir.name == "on" {
+            let event = match &dir.arg {
+                Some(ExpressionNode::Simple(arg)) if arg.is_static => Some(arg.content.as_str()),
+                _ => None,
+            };
+            let native = el.tag_type == ElementType::Element;
+            self.check_handler(&content, start, event, native);
+        } else {
+            self.check_expression(&content, start);
+        }
+    }
+
+    /// Check an expression, returning its type.
+    fn check_expression(&mut self, content: &str, start: u32) -> Option<Type> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("expr.ts").unwrap_or_default();
+
+        self.inferrer.set_base(start);
+        if TypeChecker::is_simple_identifier(content) && !content.starts_with('$') {
+            if let Some(ty) = self.inferrer.lookup(content) {
+                self.inferrer
+                    .spans
+                    .push((start, start + content.len() as u32, ty.clone()));
+                return Some(ty);
+            }
+        }
+        match Parser::new(&allocator, content, source_type).parse_expression() {
+            Ok(expr) => Some(self.inferrer.infer(&expr)),
+            Err(_) => {
+                self.check_identifiers(content, start);
+                None
+            }
+        }
+    }
+
+    /// Check a `v-on` handler.
+    ///
+    /// Method handlers (`@click="onClick"`) receive the event payload, so on
+    /// native elements they must not require more than one argument.
+    fn check_handler(&mut self, content: &str, start: u32, event: Option<&str>, native: bool) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("expr.ts").unwrap_or_default();
+        self.inferrer.set_base(start);
+
+        let Ok(expr) = Parser::new(&allocator, content, source_type).parse_expression() else {
+            // Inline statements (`count++; emit('change')`)
+            let ret = Parser::new(&allocator, content, source_type).parse();
+            if ret.errors.is_empty() {
+                self.inferrer.push_scope();
+                self.inferrer.statements(&ret.program.body);
+                self.inferrer.pop_scope();
+            } else {
+                self.check_identifiers(content, start);
+            }
+            return;
+        };
+
+        let is_method = matches!(
+            expr,
+            Expression::Identifier(_)
+                | Expression::StaticMemberExpression(_)
+                | Expression::ComputedMemberExpression(_)
+                | Expression::ArrowFunctionExpression(_)
+                | Expression::FunctionExpression(_)
+        );
+        if !is_method {
+            self.inferrer.infer(&expr);
+            return;
+        }
+
+        let ty = self.inferrer.infer(&expr);
+        if !(self.checker.vue_checks && native) {
+            return;
+        }
+        if let Type::Function(func) = self.inferrer.resolve(&ty) {
+            let required = func.min_args();
+            if required > 1 {
+                let event = event.unwrap_or("event");
+                self.inferrer.diagnostics.push(TypeDiagnostic::error(
+                    TypeErrorCode::TypeNotAssignable,
+                    format!(
+                        "Type '{}' is not assignable to type '(event: Event) => void'. \
+                         Target signature provides too few arguments. Expected {} or more, \
+                         but got 1 (handler for '{}')",
+                        Type::Function(func),
+                        required,
+                        event
+                    ),
+                    start,
+                    start + content.len() as u32,
+                ));
+            }
+        }
+    }
+
+    /// Bind `v-for` aliases to the element type of the source.
+    fn bind_for(&mut self, dir: &DirectiveNode<'_>) {
+        let Some((content, start)) = dir.exp.as_ref().and_then(|e| self.expression_source(e))
+        else {
+            return;
+        };
+        let Some((alias, source_offset)) = split_for_expression(&content) else {
+            return;
+        };
+
+        let source = &content[source_offset..];
+        let source_ty = self
+            .check_expression(source, start + source_offset as u32)
+            .map(|ty| self.inferrer.resolve(&ty).non_nullable())
+            .unwrap_or(Type::Any);
+
+        let types = match source_ty {
+            Type::Array(element) => vec![*element, Type::Number],
+            Type::Tuple(items) => vec![Type::union(items), Type::Number],
+            Type::Number | Type::NumberLiteral(_) => vec![Type::Number, Type::Number],
+            Type::String | Type::StringLiteral(_) => vec![Type::String, Type::Number],
+            Type::Object(obj) => {
+                let mut values: Vec<Type> = obj.properties.into_iter().map(|p| p.ty).collect();
+                values.extend(obj.index.map(|index| *index));
+                let value = if values.is_empty() {
+                    Type::Any
+                } else {
+                    Type::union(values)
+                };
+                vec![value, Type::String, Type::Number]
+            }
+            _ => Vec::new(),
+        };
+
+        let alias = alias.trim();
+        let alias = alias
+            .strip_prefix('(')
+            .and_then(|a| a.strip_suffix(')'))
+            .unwrap_or(alias);
+        self.bind_params(alias, start, &types);
+    }
+
+    /// Bind a parameter list (`item, index` or `{ item }`) in the current scope.
+    fn bind_params(&mut self, params: &str, start: u32, types: &[Type]) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("expr.ts").unwrap_or_default();
+        let source = format!("({}) => 0", params);
+
+        self.inferrer.set_base(start);
+        let Ok(Expression::ArrowFunctionExpression(arrow)) =
+            Parser::new(&allocator, &source, source_type).parse_expression()
+        else {
+            return;
+        };
+        for (i, param) in arrow.params.items.iter().enumerate() {
+            let ty = types.get(i).cloned().unwrap_or(Type::Any);
+            self.inferrer.bind_pattern(&param.pattern, ty);
+        }
+    }
+
+    /// Fallback for expressions that fail to parse.
+    fn check_identifiers(&mut self, content: &str, start: u32) {
+        for (ident, offset) in TypeChecker::extract_identifiers(content) {
+            if TypeChecker::is_keyword_or_literal(ident) || ident.starts_with('$') {
+                continue;
+            }
+            if self.inferrer.lookup(ident).is_none() {
+                let ident_start = start + offset as u32;
+                self.inferrer.diagnostics.push(TypeDiagnostic::error(
+                    TypeErrorCode::UnknownIdentifier,
+                    format!("Cannot find name '{}'", ident),
+                    ident_start,
+                    ident_start + ident.len() as u32,
+                ));
+            }
+        }
+    }
+
+    /// Get an expression's content and its offset in the template.
+    fn expression_source(&self, expr: &ExpressionNode<'_>) -> Option<(String, u32)> {
+        let ExpressionNode::Simple(simple) = expr else {
+            return None;
+        };
+        let content = simple.content.trim();
+        if content.is_empty() {
+            return None;
+        }
+
+        let loc = &simple.loc;
+        let start = loc.start.offset as usize;
+        let end = (loc.end.offset as usize).min(self.source.len());
+        let relative = self
+            .source
+            .get(start..end)
+            .and_then(|raw| raw.find(content))
+            .unwrap_or(0);
+        Some((content.to_string(), (start + relative) as u32))
+    }
+}
+
+/// Split a `v-for` expression into its alias and the offset of its source.
+fn split_for_expression(expr: &str) -> Option<(&str, usize)> {
+    let bytes = expr.as_bytes();
+    let mut i = 0;
+    while i + 2 < bytes.len() {
+        // Compare bytes: `i` need not be a char boundary in non-ASCII aliases
+        let is_keyword = bytes[i..i + 2] == *b"in" || bytes[i..i + 2] == *b"of";
+        if is_keyword
+            && i > 0
+            && bytes[i - 1].is_ascii_whitespace()
+            && bytes[i + 2].is_ascii_whitespace()
+        {
+            let source_start = i + 2 + (expr[i + 2..].len() - expr[i + 2..].trim_start().len());
+            return Some((&expr[..i], source_start));
+        }
+        i += 1;
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -807,4 +861,84 @@ mod tests {
         let result = checker.check_template(template, &ctx);
         assert!(!result.has_errors());
     }
+
+    fn check_with_script(script: &str, template: &str) -> CheckResult {
+        let ctx = TypeContext::from_script_setup(script);
+        TypeChecker::new().check_template(template, &ctx)
+    }
+
+    #[test]
+    fn test_check_property_not_found() {
+        let result = check_with_script(
+            "const user = { name: 'Ada', age: 36 }",
+            "<div>{{ user.nmae }}</div>",
+        );
+
+        assert_eq!(result.error_count, 1);
+        assert_eq!(result.diagnostics[0].code, TypeErrorCode::PropertyNotFound);
+        assert!(result.diagnostics[0].message.contains("'nmae'"));
+    }
+
+    #[test]
+    fn test_check_ref_unwrapped() {
+        let script = "import { ref } from 'vue'\nconst count = ref(0)";
+
+        assert!(!check_with_script(script, "<p>{{ count.toFixed(2) }}</p>").has_errors());
+        assert!(check_with_script(script, "<p>{{ count.value }}</p>").has_errors());
+    }
+
+    #[test]
+    fn test_check_define_props() {
+        let script = "const props = defineProps<{ title: string; count?: number }>()";
+
+        assert!(!check_with_script(script, "<h1>{{ title.toUpperCase() }}</h1>").has_errors());
+        let result = check_with_script(script, "<h1>{{ title.push(1) }}</h1>");
+        assert_eq!(result.diagnostics[0].code, TypeErrorCode::PropertyNotFound);
+    }
+
+    #[test]
+    fn test_check_v_for_item() {
+        let script = "const items = [{ id: 1, label: 'a' }]";
+
+        let ok = r#"<li v-for="(item, i) in items" :key="item.id">{{ item.label }} {{ i }}</li>"#;
+        assert!(!check_with_script(script, ok).has_errors());
+
+        let bad = r#"<li v-for="item in items">{{ item.lable }}</li>"#;
+        let result = check_with_script(script, bad);
+        assert_eq!(result.error_count, 1);
+        assert!(result.diagnostics[0].message.contains("'lable'"));
+    }
+
+    #[test]
+    fn test_check_v_for_non_ascii_alias() {
+        let script = "const items = [{ id: 1, label: 'a' }]";
+
+        let template = r#"<li v-for="é in items">{{ é.label }}</li>"#;
+        assert!(!check_with_script(script, template).has_errors());
+        assert_eq!(
+            split_for_expression("(ß, ü) of items"),
+            Some(("(ß, ü) ", 12))
+        );
+    }
+
+    #[test]
+    fn test_check_handler_arity() {
+        let script = "function onTwo(a: number, b: number) {}\nfunction handleClick() {}";
+
+        let result = check_with_script(script, r#"<button @click="onTwo">x</button>"#);
+        assert_eq!(result.diagnostics[0].code, TypeErrorCode::TypeNotAssignable);
+
+        let result = check_with_script(script, r#"<button @click="handleClick(1)">x</button>"#);
+        assert_eq!(result.diagnostics[0].code, TypeErrorCode::TooManyArguments);
+    }
+
+    #[test]
+    fn test_get_type_at_inferred() {
+        let ctx = TypeContext::from_script_setup("const user = { name: 'Ada' }");
+        let template = "<div>{{ user.name }}</div>";
+        let offset = template.find("name").unwrap() as u32;
+
+        let ty = TypeChecker::new().get_type_at(template, offset, &ctx);
+        assert_eq!(ty.map(|t| t.display), Some("string".to_string()));
+    }
 }
@@ -24,6 +24,8 @@ pub struct TypeContext {
     pub slots: Vec<Slot>,
     /// Global properties (e.g., $router, $store).
     pub globals: FxHashMap<String, TypeInfo>,
+    /// Interfaces and type aliases declared in the script, by name.
+    pub types: FxHashMap<String, TypeInfo>,
 }
 
 impl TypeContext {
@@ -32,6 +34,15 @@ impl TypeContext {
         Self::default()
     }
 
+    /// Create a type context from a `<script setup>` block.
+    ///
+    /// Binding, prop, and emit types are inferred natively without tsgo.
+    pub fn from_script_setup(source: &str) -> Self {
+        let mut ctx = Self::new();
+        crate::infer::collect_script_setup(source, &mut ctx);
+        ctx
+    }
+
     /// Add a binding to the context.
     pub fn add_binding(&mut self, name: impl Into<String>, binding: Binding) {
         self.bindings.insert(name.into(), binding);
@@ -362,4 +373,19 @@ mod tests {
         assert!(prop.required);
         assert_eq!(prop.default, Some("\"hello\"".to_string()));
     }
+
+    #[test]
+    fn test_from_script_setup() {
+        let ctx = TypeContext::from_script_setup(
+            "import { ref } from 'vue'\n\
+             const props = defineProps<{ msg: string }>()\n\
+             const emit = defineEmits(['change'])\n\
+             const count = ref(0)",
+        );
+
+        assert_eq!(ctx.bindings["count"].type_info.display, "Ref<number>");
+        assert_eq!(ctx.props.len(), 1);
+        assert!(ctx.props[0].required);
+        assert_eq!(ctx.emits[0].name, "change");
+    }
 }
@@ -0,0 +1,1065 @@
+//! Expression type inference.
+//!
+//! The [`Inferrer`] walks an oxc expression, computing a [`Type`] for each
+//! node. When reporting is enabled it records diagnostics for unknown names,
+//! missing properties on closed object types, call arity mismatches, and
+//! calls to values that are not functions.
+
+use oxc_ast::ast::{
+    Argument, ArrayExpressionElement, AssignmentTarget, BinaryOperator, BindingPattern,
+    BindingPatternKind, ChainElement, ComputedMemberExpression, Expression, FormalParameters,
+    FunctionBody, LogicalOperator, ObjectPropertyKind, PropertyKind, SimpleAssignmentTarget,
+    Statement, StaticMemberExpression, UnaryOperator,
+};
+use oxc_span::{GetSpan, Span};
+use vize_carton::FxHashMap;
+
+use super::ty::{params_from_formal, FunctionType, ObjectType, Param, Property, Type};
+use crate::diagnostic::{TypeDiagnostic, TypeErrorCode};
+
+/// Maximum depth when resolving named type references.
+const MAX_RESOLVE_DEPTH: usize = 8;
+
+/// Array methods whose first callback parameter receives an element.
+const ARRAY_CALLBACK_METHODS: &[&str] = &[
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "flatMap",
+    "forEach",
+    "map",
+    "some",
+];
+
+/// Values and named types visible to an expression.
+#[derive(Debug, Clone, Default)]
+pub struct Env {
+    /// Value bindings by name.
+    pub values: FxHashMap<String, Type>,
+    /// Type aliases and interfaces by name.
+    pub types: FxHashMap<String, Type>,
+}
+
+impl Env {
+    /// Resolve named type references to their definitions.
+    pub fn resolve(&self, ty: &Type) -> Type {
+        let mut current = ty.clone();
+        for _ in 0..MAX_RESOLVE_DEPTH {
+            match &current {
+                Type::Named(name, _) => match self.types.get(name) {
+                    Some(def) => current = def.clone(),
+                    None => return current,
+                },
+                _ => return current,
+            }
+        }
+        Type::Any
+    }
+}
+
+/// Infers expression types against an [`Env`].
+pub struct Inferrer<'e> {
+    env: &'e Env,
+    /// Block scopes introduced by `v-for`, slots, and arrow functions.
+    scopes: Vec<FxHashMap<String, Type>>,
+    /// Whether to report diagnostics.
+    report: bool,
+    /// Offset added to every span.
+    base: u32,
+    /// Collected diagnostics.
+    pub diagnostics: Vec<TypeDiagnostic>,
+    /// Types of identifiers and property names, by span.
+    pub spans: Vec<(u32, u32, Type)>,
+}
+
+impl<'e> Inferrer<'e> {
+    /// Create an inferrer that silently infers types.
+    pub fn new(env: &'e Env) -> Self {
+        Self {
+            env,
+            scopes: Vec::new(),
+            report: false,
+            base: 0,
+            diagnostics: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Create an inferrer that reports diagnostics.
+    pub fn reporting(env: &'e Env) -> Self {
+        Self {
+            report: true,
+            ..Self::new(env)
+        }
+    }
+
+    /// Set the offset of the expression source.
+    pub fn set_base(&mut self, base: u32) {
+        self.base = base;
+    }
+
+    /// Resolve named type references.
+    pub fn resolve(&self, ty: &Type) -> Type {
+        self.env.resolve(ty)
+    }
+
+    /// Enter a new block scope.
+    pub fn push_scope(&mut self) {
+        self.scopes.push(FxHashMap::default());
+    }
+
+    /// Leave the innermost block scope.
+    pub fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Declare a name in the innermost scope.
+    pub fn declare(&mut self, name: impl Into<String>, ty: Type) {
+        if self.scopes.is_empty() {
+            self.push_scope();
+        }
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.into(), ty);
+        }
+    }
+
+    /// Look up a name through scopes and the environment.
+    pub fn lookup(&self, name: &str) -> Option<Type> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .or_else(|| self.env.values.get(name))
+            .cloned()
+    }
+
+    /// Bind the names in a pattern, destructuring `ty`.
+    pub fn bind_pattern(&mut self, pattern: &BindingPattern<'_>, ty: Type) {
+        let ty = match &pattern.type_annotation {
+            Some(ann) => Type::from_ts_type(&ann.type_annotation),
+            None => ty,
+        };
+
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(id) => self.declare(id.name.as_str(), ty),
+            BindingPatternKind::AssignmentPattern(assign) => {
+                let default = self.infer(&assign.right);
+                let ty = if ty.is_any() {
+                    default.widen()
+                } else {
+                    ty.non_nullable()
+                };
+                self.bind_pattern(&assign.left, ty);
+            }
+            BindingPatternKind::ObjectPattern(obj) => {
+                let resolved = self.resolve(&ty);
+                for prop in obj.properties.iter() {
+                    let prop_ty = prop
+                        .key
+                        .static_name()
+                        .and_then(|name| self.property(&resolved, &name))
+                        .unwrap_or(Type::Any);
+                    self.bind_pattern(&prop.value, prop_ty);
+                }
+                if let Some(rest) = &obj.rest {
+                    self.bind_pattern(&rest.argument, Type::Object(ObjectType::open()));
+                }
+            }
+            BindingPatternKind::ArrayPattern(arr) => {
+                let resolved = self.resolve(&ty);
+                for (i, element) in arr.elements.iter().enumerate() {
+                    if let Some(element) = element {
+                        let element_ty = match &resolved {
+                            Type::Array(inner) => (**inner).clone(),
+                            Type::Tuple(items) => items.get(i).cloned().unwrap_or(Type::Undefined),
+                            _ => Type::Any,
+                        };
+                        self.bind_pattern(element, element_ty);
+                    }
+                }
+                if let Some(rest) = &arr.rest {
+                    self.bind_pattern(&rest.argument, Type::Array(Box::new(Type::Any)));
+                }
+            }
+        }
+    }
+
+    /// Infer the type of an expression.
+    pub fn infer(&mut self, expr: &Expression<'_>) -> Type {
+        match expr {
+            Expression::StringLiteral(s) => Type::StringLiteral(s.value.to_string()),
+            Expression::NumericLiteral(n) => Type::NumberLiteral(
+                n.raw
+                    .as_ref()
+                    .map(|raw| raw.to_string())
+                    .unwrap_or_else(|| n.value.to_string()),
+            ),
+            Expression::BooleanLiteral(b) => Type::BooleanLiteral(b.value),
+            Expression::NullLiteral(_) => Type::Null,
+            Expression::BigIntLiteral(_) => Type::BigInt,
+            Expression::RegExpLiteral(_) => Type::Named("RegExp".to_string(), Vec::new()),
+            Expression::TemplateLiteral(tpl) => {
+                for e in tpl.expressions.iter() {
+                    self.infer(e);
+                }
+                Type::String
+            }
+            Expression::Identifier(id) => self.identifier(id.name.as_str(), id.span),
+            Expression::ThisExpression(_) => Type::Any,
+            Expression::StaticMemberExpression(member) => self.static_member(member),
+            Expression::ComputedMemberExpression(member) => self.computed_member(member),
+            Expression::PrivateFieldExpression(field) => {
+                self.infer(&field.object);
+                Type::Any
+            }
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => {
+                    self.call(&call.callee, &call.arguments, call.span)
+                }
+                ChainElement::TSNonNullExpression(e) => self.infer(&e.expression).non_nullable(),
+                ChainElement::StaticMemberExpression(member) => self.static_member(member),
+                ChainElement::ComputedMemberExpression(member) => self.computed_member(member),
+                ChainElement::PrivateFieldExpression(field) => {
+                    self.infer(&field.object);
+                    Type::Any
+                }
+            },
+            Expression::CallExpression(call) => self.call(&call.callee, &call.arguments, call.span),
+            Expression::NewExpression(new) => {
+                self.infer(&new.callee);
+                self.arguments(&new.arguments, &[]);
+                match &new.callee {
+                    Expression::Identifier(id) if id.name == "Date" => {
+                        Type::Named("Date".to_string(), Vec::new())
+                    }
+                    _ => Type::Any,
+                }
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                self.infer(&tagged.tag);
+                for e in tagged.quasi.expressions.iter() {
+                    self.infer(e);
+                }
+                Type::Any
+            }
+            Expression::ArrayExpression(arr) => {
+                let mut elements = Vec::new();
+                for element in arr.elements.iter() {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            let spread_ty = self.infer(&spread.argument);
+                            match self.resolve(&spread_ty) {
+                                Type::Array(inner) => elements.push(*inner),
+                                Type::Tuple(items) => elements.extend(items),
+                                _ => elements.push(Type::Any),
+                            }
+                        }
+                        ArrayExpressionElement::Elision(_) => elements.push(Type::Undefined),
+                        other => {
+                            if let Some(e) = other.as_expression() {
+                                let ty = self.infer(e).widen();
+                                elements.push(ty);
+                            }
+                        }
+                    }
+                }
+                let element = if elements.is_empty() {
+                    Type::Any
+                } else {
+                    Type::union(elements)
+                };
+                Type::Array(Box::new(element))
+            }
+            Expression::ObjectExpression(obj) => {
+                let mut object = ObjectType::default();
+                for prop in obj.properties.iter() {
+                    match prop {
+                        ObjectPropertyKind::ObjectProperty(p) => {
+                            if p.computed {
+                                if let Some(key) = p.key.as_expression() {
+                                    self.infer(key);
+                                }
+                            }
+                            let ty = self.infer(&p.value);
+                            let ty = match p.kind {
+                                PropertyKind::Get => match ty {
+                                    Type::Function(func) => *func.ret,
+                                    other => other,
+                                },
+                                _ => ty.widen(),
+                            };
+                            match p.key.static_name() {
+                                Some(name) if !p.computed => {
+                                    object.insert(Property::new(name.to_string(), ty))
+                                }
+                                _ => object.index = Some(Box::new(Type::Any)),
+                            }
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            let spread_ty = self.infer(&spread.argument);
+                            match self.resolve(&spread_ty) {
+                                Type::Object(inner) => {
+                                    for property in inner.properties {
+                                        object.insert(property);
+                                    }
+                                    if inner.index.is_some() {
+                                        object.index = inner.index;
+                                    }
+                                }
+                                _ => object.index = Some(Box::new(Type::Any)),
+                            }
+                        }
+                    }
+                }
+                Type::Object(object)
+            }
+            Expression::ArrowFunctionExpression(arrow) => {
+                let ret = arrow
+                    .return_type
+                    .as_ref()
+                    .map(|ann| Type::from_ts_type(&ann.type_annotation));
+                self.function(&arrow.params, Some(&arrow.body), arrow.expression, ret, &[])
+            }
+            Expression::FunctionExpression(func) => {
+                let ret = func
+                    .return_type
+                    .as_ref()
+                    .map(|ann| Type::from_ts_type(&ann.type_annotation));
+                self.function(&func.params, func.body.as_deref(), false, ret, &[])
+            }
+            Expression::AssignmentExpression(assign) => {
+                self.assignment_target(&assign.left);
+                self.infer(&assign.right)
+            }
+            Expression::UpdateExpression(update) => {
+                self.simple_target(&update.argument);
+                Type::Number
+            }
+            Expression::UnaryExpression(unary) => {
+                let arg = self.infer(&unary.argument);
+                match unary.operator {
+                    UnaryOperator::LogicalNot | UnaryOperator::Delete => Type::Boolean,
+                    UnaryOperator::Typeof => Type::String,
+                    UnaryOperator::Void => Type::Undefined,
+                    UnaryOperator::UnaryNegation
+                    | UnaryOperator::UnaryPlus
+                    | UnaryOperator::BitwiseNot => match arg {
+                        Type::BigInt => Type::BigInt,
+                        _ => Type::Number,
+                    },
+                }
+            }
+            Expression::BinaryExpression(binary) => {
+                let left = self.infer(&binary.left);
+                let right = self.infer(&binary.right);
+                self.binary(binary.operator, left, right)
+            }
+            Expression::PrivateInExpression(e) => {
+                self.infer(&e.right);
+                Type::Boolean
+            }
+            Expression::LogicalExpression(logical) => {
+                let left = self.infer(&logical.left);
+                let right = self.infer(&logical.right);
+                match logical.operator {
+                    LogicalOperator::And => right,
+                    LogicalOperator::Or | LogicalOperator::Coalesce => {
+                        Type::union(vec![left.non_nullable(), right])
+                    }
+                }
+            }
+            Expression::ConditionalExpression(cond) => {
+                self.infer(&cond.test);
+                let consequent = self.infer(&cond.consequent);
+                let alternate = self.infer(&cond.alternate);
+                Type::union(vec![consequent, alternate])
+            }
+            Expression::SequenceExpression(seq) => {
+                let mut last = Type::Undefined;
+                for e in seq.expressions.iter() {
+                    last = self.infer(e);
+                }
+                last
+            }
+            Expression::ParenthesizedExpression(paren) => self.infer(&paren.expression),
+            Expression::AwaitExpression(e) => {
+                self.infer(&e.argument);
+                Type::Any
+            }
+            Expression::TSAsExpression(e) => {
+                self.infer(&e.expression);
+                Type::from_ts_type(&e.type_annotation)
+            }
+            Expression::TSTypeAssertion(e) => {
+                self.infer(&e.expression);
+                Type::from_ts_type(&e.type_annotation)
+            }
+            Expression::TSSatisfiesExpression(e) => self.infer(&e.expression),
+            Expression::TSNonNullExpression(e) => self.infer(&e.expression).non_nullable(),
+            Expression::TSInstantiationExpression(e) => self.infer(&e.expression),
+            _ => Type::Any,
+        }
+    }
+
+    /// Check statements of a function body or inline handler.
+    pub fn statements(&mut self, statements: &[Statement<'_>]) -> Option<Type> {
+        let mut returns = Vec::new();
+        for statement in statements {
+            self.statement(statement, &mut returns);
+        }
+        (!returns.is_empty()).then(|| Type::union(returns))
+    }
+
+    fn statement(&mut self, statement: &Statement<'_>, returns: &mut Vec<Type>) {
+        match statement {
+            Statement::ExpressionStatement(s) => {
+                self.infer(&s.expression);
+            }
+            Statement::ReturnStatement(r) => {
+                let ty = match &r.argument {
+                    Some(arg) => self.infer(arg),
+                    None => Type::Undefined,
+                };
+                returns.push(ty);
+            }
+            Statement::IfStatement(s) => {
+                self.infer(&s.test);
+                self.statement(&s.consequent, returns);
+                if let Some(alternate) = &s.alternate {
+                    self.statement(alternate, returns);
+                }
+            }
+            Statement::BlockStatement(block) => {
+                self.push_scope();
+                for s in block.body.iter() {
+                    self.statement(s, returns);
+                }
+                self.pop_scope();
+            }
+            Statement::VariableDeclaration(decl) => {
+                for declarator in decl.declarations.iter() {
+                    let ty = match &declarator.init {
+                        Some(init) => self.infer(init).widen(),
+                        None => Type::Any,
+                    };
+                    self.bind_pattern(&declarator.id, ty);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Infer a function, checking its body in a new scope.
+    ///
+    /// `hints` gives contextual types for unannotated parameters.
+    fn function(
+        &mut self,
+        params: &FormalParameters<'_>,
+        body: Option<&FunctionBody<'_>>,
+        is_expression: bool,
+        ret: Option<Type>,
+        hints: &[Type],
+    ) -> Type {
+        let mut signature = params_from_formal(params);
+
+        self.push_scope();
+        for (i, param) in params.items.iter().enumerate() {
+            let hint = hints.get(i).cloned().unwrap_or(Type::Any);
+            if param.pattern.type_annotation.is_none() {
+                if let Some(p) = signature.get_mut(i) {
+                    p.ty = hint.clone();
+                }
+            }
+            self.bind_pattern(&param.pattern, hint);
+        }
+        if let Some(rest) = &params.rest {
+            self.bind_pattern(&rest.argument, Type::Array(Box::new(Type::Any)));
+        }
+
+        let inferred = body.map(|body| {
+            if is_expression {
+                match body.statements.first() {
+                    Some(Statement::ExpressionStatement(s)) => self.infer(&s.expression),
+                    _ => Type::Any,
+                }
+            } else {
+                self.statements(&body.statements).unwrap_or(Type::Void)
+            }
+        });
+        self.pop_scope();
+
+        Type::Function(FunctionType {
+            params: signature,
+            ret: Box::new(ret.or(inferred).unwrap_or(Type::Any)),
+        })
+    }
+
+    fn identifier(&mut self, name: &str, span: Span) -> Type {
+        let ty = match self.lookup(name) {
+            Some(ty) => ty,
+            None => {
+                if !name.starts_with('$') {
+                    self.error(
+                        TypeErrorCode::UnknownIdentifier,
+                        format!("Cannot find name '{}'", name),
+                        span,
+                    );
+                }
+                Type::Any
+            }
+        };
+        self.record(span, &ty);
+        ty
+    }
+
+    fn static_member(&mut self, member: &StaticMemberExpression<'_>) -> Type {
+        let object = self.infer(&member.object);
+        self.member(&object, member.property.name.as_str(), member.property.span)
+    }
+
+    fn computed_member(&mut self, member: &ComputedMemberExpression<'_>) -> Type {
+        let object = self.infer(&member.object);
+        let key = self.infer(&member.expression);
+        let resolved = self.resolve(&object).non_nullable();
+
+        match (&resolved, &key) {
+            (Type::Tuple(items), Type::NumberLiteral(n)) => n
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| items.get(i).cloned())
+                .unwrap_or(Type::Undefined),
+            (Type::Object(_), Type::StringLiteral(name)) => {
+                self.member(&object, name, member.expression.span())
+            }
+            (Type::Array(inner), _) => (**inner).clone(),
+            (Type::Tuple(items), _) => Type::union(items.clone()),
+            (Type::Object(obj), _) => obj.index.as_deref().cloned().unwrap_or(Type::Any),
+            (Type::String | Type::StringLiteral(_), _) => Type::String,
+            _ => Type::Any,
+        }
+    }
+
+    /// Access a property, reporting it if it does not exist.
+    fn member(&mut self, object: &Type, name: &str, span: Span) -> Type {
+        let ty = match self.property(object, name) {
+            Some(ty) => ty,
+            None => {
+                self.error(
+                    TypeErrorCode::PropertyNotFound,
+                    format!(
+                        "Property '{}' does not exist on type '{}'",
+                        name,
+                        self.resolve(object).non_nullable()
+                    ),
+                    span,
+                );
+                Type::Any
+            }
+        };
+        self.record(span, &ty);
+        ty
+    }
+
+    /// Get the type of a property, or `None` if it definitely does not exist.
+    pub fn property(&self, object: &Type, name: &str) -> Option<Type> {
+        match self.resolve(object).non_nullable() {
+            Type::Object(obj) => obj
+                .get(name)
+                .map(|p| p.ty.clone())
+                .or_else(|| obj.index.as_deref().cloned())
+                .or_else(|| object_member(name)),
+            Type::Array(element) => array_member(&element, name),
+            Type::Tuple(items) => match name.parse::<usize>() {
+                Ok(i) => items.get(i).cloned(),
+                Err(_) => array_member(&Type::union(items), name),
+            },
+            Type::String | Type::StringLiteral(_) => string_member(name),
+            Type::Number | Type::NumberLiteral(_) => number_member(name),
+            Type::Boolean | Type::BooleanLiteral(_) => match name {
+                "toString" => Some(method(Type::String)),
+                "valueOf" => Some(method(Type::Boolean)),
+                _ => None,
+            },
+            Type::Function(_) => Some(match name {
+                "length" => Type::Number,
+                "name" => Type::String,
+                _ => Type::Any,
+            }),
+            Type::Ref(inner) | Type::ComputedRef(inner) => match name {
+                "value" => Some(*inner),
+                _ => None,
+            },
+            Type::Union(members) => {
+                let mut types = Vec::with_capacity(members.len());
+                for member in &members {
+                    types.push(self.property(member, name)?);
+                }
+                Some(Type::union(types))
+            }
+            _ => Some(Type::Any),
+        }
+    }
+
+    fn call(&mut self, callee: &Expression<'_>, args: &[Argument<'_>], span: Span) -> Type {
+        // Array callbacks get the element type as their first parameter
+        let (callee_ty, hints) = match callee {
+            Expression::StaticMemberExpression(member) => {
+                let object = self.infer(&member.object);
+                let name = member.property.name.as_str();
+                let hints = match self.resolve(&object).non_nullable() {
+                    Type::Array(element) if ARRAY_CALLBACK_METHODS.contains(&name) => {
+                        vec![*element, Type::Number, Type::Array(Box::new(Type::Any))]
+                    }
+                    _ => Vec::new(),
+                };
+                (self.member(&object, name, member.property.span), hints)
+            }
+            _ => (self.infer(callee), Vec::new()),
+        };
+
+        let resolved = self.resolve(&callee_ty);
+        self.arguments(args, &hints);
+
+        match resolved {
+            Type::Function(func) => {
+                if !args
+                    .iter()
+                    .any(|arg| matches!(arg, Argument::SpreadElement(_)))
+                {
+                    self.check_arity(&func, args.len(), span);
+                }
+                *func.ret
+            }
+            Type::String
+            | Type::Number
+            | Type::Boolean
+            | Type::StringLiteral(_)
+            | Type::NumberLiteral(_)
+            | Type::BooleanLiteral(_)
+            | Type::Array(_)
+            | Type::Tuple(_)
+            | Type::Ref(_)
+            | Type::ComputedRef(_) => {
+                self.error(
+                    TypeErrorCode::NotCallable,
+                    format!(
+                        "This expression is not callable. Type '{}' has no call signatures",
+                        resolved
+                    ),
+                    callee.span(),
+                );
+                Type::Any
+            }
+            Type::Object(obj) if obj.index.is_none() => {
+                self.error(
+                    TypeErrorCode::NotCallable,
+                    format!(
+                        "This expression is not callable. Type '{}' has no call signatures",
+                        Type::Object(obj)
+                    ),
+                    callee.span(),
+                );
+                Type::Any
+            }
+            _ => Type::Any,
+        }
+    }
+
+    fn arguments(&mut self, args: &[Argument<'_>], hints: &[Type]) {
+        for (i, arg) in args.iter().enumerate() {
+            match arg {
+                Argument::SpreadElement(spread) => {
+                    self.infer(&spread.argument);
+                }
+                Argument::ArrowFunctionExpression(arrow) if i == 0 && !hints.is_empty() => {
+                    let ret = arrow
+                        .return_type
+                        .as_ref()
+                        .map(|ann| Type::from_ts_type(&ann.type_annotation));
+                    self.function(
+                        &arrow.params,
+                        Some(&arrow.body),
+                        arrow.expression,
+                        ret,
+                        hints,
+                    );
+                }
+                other => {
+                    if let Some(e) = other.as_expression() {
+                        self.infer(e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Report a call with the wrong number of arguments.
+    pub fn check_arity(&mut self, func: &FunctionType, count: usize, span: Span) {
+        let min = func.min_args();
+        let max = func.max_args();
+
+        let expected = match max {
+            Some(max) if max == min => min.to_string(),
+            Some(max) => format!("{}-{}", min, max),
+            None => format!("at least {}", min),
+        };
+
+        if count < min {
+            self.error(
+                TypeErrorCode::ExpectedArguments,
+                format!("Expected {} arguments, but got {}", expected, count),
+                span,
+            );
+        } else if max.is_some_and(|max| count > max) {
+            self.error(
+                TypeErrorCode::TooManyArguments,
+                format!("Expected {} arguments, but got {}", expected, count),
+                span,
+            );
+        }
+    }
+
+    fn assignment_target(&mut self, target: &AssignmentTarget<'_>) {
+        match target {
+            AssignmentTarget::AssignmentTargetIdentifier(id) => {
+                self.identifier(id.name.as_str(), id.span);
+            }
+            AssignmentTarget::StaticMemberExpression(member) => {
+                self.static_member(member);
+            }
+            AssignmentTarget::ComputedMemberExpression(member) => {
+                self.computed_member(member);
+            }
+            _ => {}
+        }
+    }
+
+    fn simple_target(&mut self, target: &SimpleAssignmentTarget<'_>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(id) => {
+                self.identifier(id.name.as_str(), id.span);
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                self.static_member(member);
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                self.computed_member(member);
+            }
+            _ => {}
+        }
+    }
+
+    fn binary(&self, operator: BinaryOperator, left: Type, right: Type) -> Type {
+        match operator {
+            BinaryOperator::Addition => {
+                let is_string = |ty: &Type| matches!(ty, Type::String | Type::StringLiteral(_));
+                let is_number = |ty: &Type| matches!(ty, Type::Number | Type::NumberLiteral(_));
+                let left = self.resolve(&left);
+                let right = self.resolve(&right);
+                if is_string(&left) || is_string(&right) {
+                    Type::String
+                } else if is_number(&left) && is_number(&right) {
+                    Type::Number
+                } else {
+                    Type::Any
+                }
+            }
+            BinaryOperator::Equality
+            | BinaryOperator::Inequality
+            | BinaryOperator::StrictEquality
+            | BinaryOperator::StrictInequality
+            | BinaryOperator::LessThan
+            | BinaryOperator::LessEqualThan
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::GreaterEqualThan
+            | BinaryOperator::In
+            | BinaryOperator::Instanceof => Type::Boolean,
+            _ => Type::Number,
+        }
+    }
+
+    fn record(&mut self, span: Span, ty: &Type) {
+        if self.report {
+            self.spans
+                .push((self.base + span.start, self.base + span.end, ty.clone()));
+        }
+    }
+
+    fn error(&mut self, code: TypeErrorCode, message: String, span: Span) {
+        if self.report {
+            self.diagnostics.push(TypeDiagnostic::error(
+                code,
+                message,
+                self.base + span.start,
+                self.base + span.end,
+            ));
+        }
+    }
+}
+
+/// A method taking any arguments and returning `ret`.
+fn method(ret: Type) -> Type {
+    Type::Function(FunctionType::variadic(ret))
+}
+
+/// A method taking exactly one callback.
+fn callback_method(ret: Type) -> Type {
+    Type::Function(FunctionType {
+        params: vec![
+            Param {
+                name: "callbackfn".to_string(),
+                ty: Type::Any,
+                optional: false,
+                rest: false,
+            },
+            Param {
+                name: "thisArg".to_string(),
+                ty: Type::Any,
+                optional: true,
+                rest: false,
+            },
+        ],
+        ret: Box::new(ret),
+    })
+}
+
+fn object_member(name: &str) -> Option<Type> {
+    match name {
+        "toString" | "toLocaleString" => Some(method(Type::String)),
+        "hasOwnProperty" | "isPrototypeOf" | "propertyIsEnumerable" => Some(method(Type::Boolean)),
+        "valueOf" | "constructor" => Some(Type::Any),
+        _ => None,
+    }
+}
+
+fn array_member(element: &Type, name: &str) -> Option<Type> {
+    let array = || Type::Array(Box::new(element.clone()));
+    Some(match name {
+        "length" => Type::Number,
+        "filter" => callback_method(array()),
+        "map" | "flatMap" => callback_method(Type::Array(Box::new(Type::Any))),
+        "find" | "findLast" => callback_method(element.clone()),
+        "findIndex" | "findLastIndex" => callback_method(Type::Number),
+        "every" | "some" => callback_method(Type::Boolean),
+        "forEach" => callback_method(Type::Void),
+        "slice" | "sort" | "toSorted" | "reverse" | "toReversed" | "concat" | "splice"
+        | "toSpliced" | "with" | "fill" | "copyWithin" => method(array()),
+        "at" | "pop" | "shift" => method(element.clone()),
+        "includes" => method(Type::Boolean),
+        "indexOf" | "lastIndexOf" | "push" | "unshift" => method(Type::Number),
+        "join" | "toString" | "toLocaleString" => method(Type::String),
+        "reduce" | "reduceRight" | "flat" | "entries" | "keys" | "values" => method(Type::Any),
+        _ => return None,
+    })
+}
+
+fn string_member(name: &str) -> Option<Type> {
+    Some(match name {
+        "length" => Type::Number,
+        "at" | "charAt" | "concat" | "normalize" | "padEnd" | "padStart" | "repeat" | "replace"
+        | "replaceAll" | "slice" | "substring" | "substr" | "toLowerCase" | "toUpperCase"
+        | "toLocaleLowerCase" | "toLocaleUpperCase" | "trim" | "trimStart" | "trimEnd"
+        | "toString" | "valueOf" | "toWellFormed" => method(Type::String),
+        "charCodeAt" | "codePointAt" | "indexOf" | "lastIndexOf" | "localeCompare" | "search" => {
+            method(Type::Number)
+        }
+        "endsWith" | "includes" | "startsWith" | "isWellFormed" => method(Type::Boolean),
+        "split" => method(Type::Array(Box::new(Type::String))),
+        "match" | "matchAll" => method(Type::Any),
+        _ => return None,
+    })
+}
+
+fn number_member(name: &str) -> Option<Type> {
+    Some(match name {
+        "toFixed" | "toPrecision" | "toExponential" | "toString" | "toLocaleString" => {
+            method(Type::String)
+        }
+        "valueOf" => method(Type::Number),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn env(values: &[(&str, &str)], types: &[(&str, &str)]) -> Env {
+        let parse = |entries: &[(&str, &str)]| {
+            entries
+                .iter()
+                .map(|(name, ty)| (name.to_string(), Type::parse(ty)))
+                .collect()
+        };
+        Env {
+            values: parse(values),
+            types: parse(types),
+        }
+    }
+
+    fn infer_with(inferrer: &mut Inferrer<'_>, source: &str) -> String {
+        let allocator = Allocator::default();
+        let expr = Parser::new(&allocator, source, SourceType::ts())
+            .parse_expression()
+            .unwrap();
+        inferrer.infer(&expr).to_string()
+    }
+
+    fn infer(env: &Env, source: &str) -> String {
+        infer_with(&mut Inferrer::new(env), source)
+    }
+
+    fn user_env() -> Env {
+        env(
+            &[
+                ("user", "User"),
+                ("count", "Ref<number>"),
+                ("items", "{ id: number; label: string }[]"),
+                ("format", "(n: number, digits?: number) => string"),
+                ("ok", "boolean"),
+            ],
+            &[(
+                "User",
+                "{ name: string; tags: string[]; address?: { city: string } }",
+            )],
+        )
+    }
+
+    #[test]
+    fn test_infer_literals() {
+        let env = Env::default();
+        assert_eq!(infer(&env, "'a'"), "\"a\"");
+        assert_eq!(infer(&env, "1.50"), "1.50");
+        assert_eq!(infer(&env, "true"), "true");
+        assert_eq!(infer(&env, "null"), "null");
+        assert_eq!(infer(&env, "10n"), "bigint");
+        assert_eq!(infer(&env, "`n = ${1}`"), "string");
+        assert_eq!(infer(&env, "[1, 'a', 2]"), "(number | string)[]");
+        assert_eq!(infer(&env, "[]"), "any[]");
+        assert_eq!(
+            infer(&env, "{ id: 1, name: 'a', tags: ['x'] }"),
+            "{ id: number; name: string; tags: string[] }"
+        );
+        assert_eq!(infer(&env, "!0"), "boolean");
+        assert_eq!(infer(&env, "typeof 1"), "string");
+        assert_eq!(infer(&env, "1 + 2"), "number");
+        assert_eq!(infer(&env, "'a' + 1"), "string");
+        assert_eq!(infer(&env, "new Date()"), "Date");
+    }
+
+    #[test]
+    fn test_infer_member() {
+        let env = user_env();
+        assert_eq!(infer(&env, "user.name"), "string");
+        assert_eq!(infer(&env, "user.tags.length"), "number");
+        assert_eq!(infer(&env, "user.tags[0]"), "string");
+        assert_eq!(infer(&env, "user.address?.city"), "string");
+        assert_eq!(infer(&env, "count.value"), "number");
+        assert_eq!(infer(&env, "items[0].label"), "string");
+        // Unknown names are `any`, not errors, when not reporting
+        assert_eq!(infer(&env, "missing.deep"), "any");
+    }
+
+    #[test]
+    fn test_infer_call() {
+        let env = user_env();
+        assert_eq!(infer(&env, "format(1)"), "string");
+        assert_eq!(infer(&env, "user.name.toUpperCase()"), "string");
+        assert_eq!(infer(&env, "user.name.split(',')"), "string[]");
+        assert_eq!(
+            infer(&env, "items.filter(item => item.id > 1)"),
+            "{ id: number; label: string }[]"
+        );
+        assert_eq!(
+            infer(&env, "items.find(item => item.label === 'a')"),
+            "{ id: number; label: string }"
+        );
+        assert_eq!(infer(&env, "((n: number) => n * 2)(1)"), "number");
+    }
+
+    #[test]
+    fn test_infer_ternary_and_logical() {
+        let env = user_env();
+        assert_eq!(infer(&env, "ok ? 1 : 'a'"), "1 | \"a\"");
+        assert_eq!(infer(&env, "ok ? user.name : null"), "string | null");
+        assert_eq!(
+            infer(&env, "user.address ?? 'none'"),
+            "{ city: string } | \"none\""
+        );
+        assert_eq!(infer(&env, "ok && user.name"), "string");
+    }
+
+    #[test]
+    fn test_bind_v_for_alias() {
+        // `v-for="({ id, label }, index) in items"` binds like arrow parameters
+        let env = user_env();
+        let mut inferrer = Inferrer::new(&env);
+        let allocator = Allocator::default();
+        let Ok(Expression::ArrowFunctionExpression(arrow)) = Parser::new(
+            &allocator,
+            "({ id, label }, index, [first] = []) => 0",
+            SourceType::ts(),
+        )
+        .parse_expression() else {
+            panic!("expected arrow function");
+        };
+        let types = [
+            Type::parse("{ id: number; label: string }"),
+            Type::Number,
+            Type::parse("[string, number]"),
+        ];
+        inferrer.push_scope();
+        for (param, ty) in arrow.params.items.iter().zip(types) {
+            inferrer.bind_pattern(&param.pattern, ty);
+        }
+
+        assert_eq!(inferrer.lookup("id"), Some(Type::Number));
+        assert_eq!(inferrer.lookup("label"), Some(Type::String));
+        assert_eq!(inferrer.lookup("index"), Some(Type::Number));
+        assert_eq!(inferrer.lookup("first"), Some(Type::String));
+        assert_eq!(infer_with(&mut inferrer, "label.length + id"), "number");
+
+        // Aliases leave scope with the element
+        inferrer.pop_scope();
+        assert_eq!(inferrer.lookup("label"), None);
+        assert_eq!(inferrer.lookup("user"), Some(Type::parse("User")));
+    }
+
+    #[test]
+    fn test_reporting_diagnostics() {
+        let env = user_env();
+        let codes = |source: &str| {
+            let mut inferrer = Inferrer::reporting(&env);
+            infer_with(&mut inferrer, source);
+            inferrer
+                .diagnostics
+                .iter()
+                .map(|d| d.code)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(codes("user.name.trim()").is_empty());
+        assert_eq!(codes("user.nmae"), vec![TypeErrorCode::PropertyNotFound]);
+        assert_eq!(codes("format()"), vec![TypeErrorCode::ExpectedArguments]);
+        assert_eq!(
+            codes("format(1, 2, 3)"),
+            vec![TypeErrorCode::TooManyArguments]
+        );
+        assert_eq!(codes("user.name()"), vec![TypeErrorCode::NotCallable]);
+
+        // Spans are offset by the expression base and typed identifiers are recorded
+        let mut inferrer = Inferrer::reporting(&env);
+        inferrer.set_base(100);
+        infer_with(&mut inferrer, "user.name");
+        assert!(inferrer
+            .spans
+            .iter()
+            .any(|(start, end, ty)| (*start, *end) == (105, 109) && *ty == Type::String));
+    }
+}
@@ -0,0 +1,14 @@
+//! Native type inference for template expressions.
+//!
+//! A lightweight alternative to running tsgo, covering the common cases:
+//! literal types, `ref`/`computed` unwrapping, `defineProps` types, member
+//! access on object types, and `v-for` item types. Anything it cannot model
+//! is treated as `any`, so it only reports errors it is sure about.
+
+mod expr;
+mod script;
+mod ty;
+
+pub use expr::{Env, Inferrer};
+pub use script::collect_script_setup;
+pub use ty::{ObjectType, Property, Type};
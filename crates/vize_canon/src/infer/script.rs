@@ -0,0 +1,789 @@
+//! Binding and prop type collection from `<script setup>`.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, BindingPattern, BindingPatternKind, CallExpression, Declaration, Expression,
+    ImportDeclarationSpecifier, ObjectPropertyKind, Statement, TSInterfaceDeclaration, TSSignature,
+    TSType, TSTypeAliasDeclaration, VariableDeclarationKind, VariableDeclarator,
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+
+use super::expr::{Env, Inferrer};
+use super::ty::{
+    object_from_signatures, params_from_formal, FunctionType, ObjectType, Property, Type,
+};
+use crate::context::{Binding, BindingKind, Emit, Prop, TypeContext};
+
+/// Collect bindings, props, emits, and declared types from a `<script setup>` block.
+pub fn collect_script_setup(source: &str, ctx: &mut TypeContext) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path("script.ts").unwrap_or_default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+
+    let mut collector = Collector {
+        source,
+        env: Env::default(),
+        ctx,
+    };
+
+    // Type declarations are hoisted
+    for statement in ret.program.body.iter() {
+        collector.collect_types(statement);
+    }
+    for statement in ret.program.body.iter() {
+        collector.collect_statement(statement);
+    }
+}
+
+struct Collector<'s, 'c> {
+    source: &'s str,
+    env: Env,
+    ctx: &'c mut TypeContext,
+}
+
+/// Props declared by `defineProps`.
+#[derive(Default)]
+struct PropsInfo {
+    object: ObjectType,
+    defaults: Vec<(String, String)>,
+}
+
+impl Collector<'_, '_> {
+    fn collect_types(&mut self, statement: &Statement<'_>) {
+        match statement {
+            Statement::TSInterfaceDeclaration(decl) => self.collect_interface(decl),
+            Statement::TSTypeAliasDeclaration(decl) => self.collect_type_alias(decl),
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::TSInterfaceDeclaration(decl)) => self.collect_interface(decl),
+                Some(Declaration::TSTypeAliasDeclaration(decl)) => self.collect_type_alias(decl),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn collect_interface(&mut self, decl: &TSInterfaceDeclaration<'_>) {
+        let mut object = object_from_signatures(&decl.body.body);
+        for heritage in decl.extends.iter().flatten() {
+            let base = match &heritage.expression {
+                Expression::Identifier(id) => self.env.types.get(id.name.as_str()),
+                _ => None,
+            };
+            match base {
+                Some(Type::Object(base)) => {
+                    for property in &base.properties {
+                        if object.get(&property.name).is_none() {
+                            object.properties.push(property.clone());
+                        }
+                    }
+                    if object.index.is_none() {
+                        object.index = base.index.clone();
+                    }
+                }
+                // Unknown bases may add any member
+                _ => object.index = Some(Box::new(Type::Any)),
+            }
+        }
+        self.declare_type(decl.id.name.as_str(), Type::Object(object));
+    }
+
+    fn collect_type_alias(&mut self, decl: &TSTypeAliasDeclaration<'_>) {
+        // Generic aliases depend on their arguments
+        let ty = if decl.type_parameters.is_some() {
+            Type::Any
+        } else {
+            Type::from_ts_type(&decl.type_annotation)
+        };
+        self.declare_type(decl.id.name.as_str(), ty);
+    }
+
+    fn declare_type(&mut self, name: &str, ty: Type) {
+        self.ctx.types.insert(name.to_string(), ty.to_type_info());
+        self.env.types.insert(name.to_string(), ty);
+    }
+
+    fn collect_statement(&mut self, statement: &Statement<'_>) {
+        match statement {
+            Statement::ImportDeclaration(import) => {
+                if import.import_kind.is_type() {
+                    return;
+                }
+                for specifier in import.specifiers.iter().flatten() {
+                    let local = match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                            if s.import_kind.is_type() {
+                                continue;
+                            }
+                            &s.local
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => &s.local,
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => &s.local,
+                    };
+                    self.bind(
+                        local.name.as_str(),
+                        Type::Any,
+                        BindingKind::Import,
+                        local.span.start,
+                    );
+                }
+            }
+            Statement::VariableDeclaration(decl) => {
+                for declarator in decl.declarations.iter() {
+                    self.collect_declarator(decl.kind, declarator);
+                }
+            }
+            Statement::FunctionDeclaration(func) => {
+                if let Some(id) = &func.id {
+                    let ret = func
+                        .return_type
+                        .as_ref()
+                        .map(|ann| Type::from_ts_type(&ann.type_annotation))
+                        .unwrap_or(Type::Any);
+                    let ty = Type::Function(FunctionType {
+                        params: params_from_formal(&func.params),
+                        ret: Box::new(ret),
+                    });
+                    self.bind(id.name.as_str(), ty, BindingKind::Function, id.span.start);
+                }
+            }
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &class.id {
+                    self.bind(
+                        id.name.as_str(),
+                        Type::Any,
+                        BindingKind::Class,
+                        id.span.start,
+                    );
+                }
+            }
+            Statement::TSEnumDeclaration(decl) => {
+                self.bind(
+                    decl.id.name.as_str(),
+                    Type::Any,
+                    BindingKind::Const,
+                    decl.id.span.start,
+                );
+            }
+            Statement::ExpressionStatement(stmt) => {
+                if let Expression::CallExpression(call) = &stmt.expression {
+                    self.collect_macro(call);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_declarator(
+        &mut self,
+        kind: VariableDeclarationKind,
+        declarator: &VariableDeclarator<'_>,
+    ) {
+        let Some(init) = &declarator.init else {
+            let ty = declarator
+                .id
+                .type_annotation
+                .as_ref()
+                .map(|ann| Type::from_ts_type(&ann.type_annotation))
+                .unwrap_or(Type::Any);
+            self.bind_pattern(&declarator.id, ty, binding_kind(kind));
+            return;
+        };
+
+        let (ty, binding) = match unwrap_ts(init) {
+            Expression::CallExpression(call) => match callee_name(call) {
+                Some("defineProps" | "withDefaults") => {
+                    let props = self.collect_macro(call).unwrap_or_else(ObjectType::open);
+                    // Destructured props are reactive prop bindings
+                    let binding = match declarator.id.kind {
+                        BindingPatternKind::ObjectPattern(_) => BindingKind::Prop,
+                        _ => BindingKind::Const,
+                    };
+                    (Type::Object(props), binding)
+                }
+                Some("defineModel") => {
+                    self.collect_macro(call);
+                    (self.infer_call(call), BindingKind::Ref)
+                }
+                Some("defineEmits") => {
+                    self.collect_macro(call);
+                    (
+                        Type::Function(FunctionType::variadic(Type::Void)),
+                        BindingKind::Const,
+                    )
+                }
+                Some(name) => (self.infer_call(call), call_binding_kind(name, kind)),
+                None => (self.infer(init), binding_kind(kind)),
+            },
+            _ => (self.infer(init), binding_kind(kind)),
+        };
+
+        let ty = match &declarator.id.type_annotation {
+            Some(ann) => Type::from_ts_type(&ann.type_annotation),
+            None if kind == VariableDeclarationKind::Const => ty,
+            None => ty.widen(),
+        };
+        let binding = match declarator.id.kind {
+            BindingPatternKind::BindingIdentifier(_) => binding,
+            _ if binding == BindingKind::Prop => BindingKind::Prop,
+            _ => BindingKind::Destructure,
+        };
+        self.bind_pattern(&declarator.id, ty, binding);
+    }
+
+    /// Handle compiler macros, returning declared props for `defineProps`.
+    fn collect_macro(&mut self, call: &CallExpression<'_>) -> Option<ObjectType> {
+        match callee_name(call)? {
+            "defineProps" | "withDefaults" => {
+                let props = self.props_from_macro(call)?;
+                for property in &props.object.properties {
+                    let mut prop = Prop::new(property.name.clone(), property.ty.to_type_info());
+                    if !property.optional {
+                        prop = prop.required();
+                    }
+                    if let Some((_, default)) =
+                        props.defaults.iter().find(|(k, _)| *k == property.name)
+                    {
+                        prop = prop.with_default(default.clone());
+                    }
+                    self.ctx.add_prop(prop);
+                }
+                Some(props.object)
+            }
+            "defineEmits" => {
+                for name in self.emits_from_macro(call) {
+                    self.ctx.add_emit(Emit::new(name));
+                }
+                None
+            }
+            "defineModel" => {
+                let name = match call.arguments.first() {
+                    Some(Argument::StringLiteral(s)) => s.value.to_string(),
+                    _ => "modelValue".to_string(),
+                };
+                let ty = call
+                    .type_parameters
+                    .as_ref()
+                    .and_then(|params| params.params.first())
+                    .map(Type::from_ts_type)
+                    .unwrap_or(Type::Any);
+                self.ctx
+                    .add_prop(Prop::new(name.clone(), ty.to_type_info()));
+                self.ctx.add_emit(Emit::new(format!("update:{}", name)));
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn props_from_macro(&mut self, call: &CallExpression<'_>) -> Option<PropsInfo> {
+        if callee_name(call)? == "withDefaults" {
+            let Some(Argument::CallExpression(inner)) = call.arguments.first() else {
+                return None;
+            };
+            let mut props = self.props_from_macro(inner)?;
+            if let Some(Argument::ObjectExpression(defaults)) = call.arguments.get(1) {
+                for prop in defaults.properties.iter() {
+                    let ObjectPropertyKind::ObjectProperty(p) = prop else {
+                        continue;
+                    };
+                    let Some(name) = p.key.static_name() else {
+                        continue;
+                    };
+                    if let Some(property) = props
+                        .object
+                        .properties
+                        .iter_mut()
+                        .find(|prop| prop.name == name)
+                    {
+                        property.optional = false;
+                    }
+                    let span = p.value.span();
+                    let text = &self.source[span.start as usize..span.end as usize];
+                    props.defaults.push((name.to_string(), text.to_string()));
+                }
+            }
+            return Some(props);
+        }
+
+        // Type-based declaration
+        if let Some(params) = &call.type_parameters {
+            let ty = params.params.first().map(Type::from_ts_type)?;
+            let object = match self.env.resolve(&ty) {
+                Type::Object(object) => object,
+                _ => ObjectType::open(),
+            };
+            return Some(PropsInfo {
+                object,
+                defaults: Vec::new(),
+            });
+        }
+
+        // Runtime declaration
+        let mut info = PropsInfo::default();
+        match call.arguments.first() {
+            None => {}
+            Some(Argument::ArrayExpression(arr)) => {
+                for element in arr.elements.iter() {
+                    if let Some(Expression::StringLiteral(s)) = element.as_expression() {
+                        info.object.insert(Property {
+                            name: s.value.to_string(),
+                            ty: Type::Any,
+                            optional: true,
+                        });
+                    }
+                }
+            }
+            Some(Argument::ObjectExpression(obj)) => {
+                for prop in obj.properties.iter() {
+                    let ObjectPropertyKind::ObjectProperty(p) = prop else {
+                        info.object.index = Some(Box::new(Type::Any));
+                        continue;
+                    };
+                    let Some(name) = p.key.static_name() else {
+                        info.object.index = Some(Box::new(Type::Any));
+                        continue;
+                    };
+                    let (ty, required, default) = self.runtime_prop(&p.value);
+                    if let Some(default) = default {
+                        info.defaults.push((name.to_string(), default));
+                    }
+                    info.object.insert(Property {
+                        name: name.to_string(),
+                        optional: !required,
+                        ty,
+                    });
+                }
+            }
+            Some(_) => info.object = ObjectType::open(),
+        }
+        Some(info)
+    }
+
+    /// Get the type, required flag, and default of a runtime prop option.
+    fn runtime_prop(&self, value: &Expression<'_>) -> (Type, bool, Option<String>) {
+        let Expression::ObjectExpression(options) = value else {
+            let ty = prop_constructor_type(value);
+            let required = ty == Type::Boolean;
+            return (ty, required, None);
+        };
+
+        let mut ty = Type::Any;
+        let mut required = false;
+        let mut default = None;
+        for prop in options.properties.iter() {
+            let ObjectPropertyKind::ObjectProperty(p) = prop else {
+                continue;
+            };
+            match p.key.static_name().as_deref() {
+                Some("type") => ty = prop_constructor_type(&p.value),
+                Some("required") => {
+                    required = matches!(&p.value, Expression::BooleanLiteral(b) if b.value)
+                }
+                Some("default") => {
+                    let span = p.value.span();
+                    default = Some(self.source[span.start as usize..span.end as usize].to_string());
+                }
+                _ => {}
+            }
+        }
+        // Absent booleans are cast to `false`
+        let required = required || default.is_some() || ty == Type::Boolean;
+        (ty, required, default)
+    }
+
+    fn emits_from_macro(&self, call: &CallExpression<'_>) -> Vec<String> {
+        let mut names = Vec::new();
+
+        if let Some(params) = &call.type_parameters {
+            if let Some(TSType::TSTypeLiteral(lit)) = params.params.first() {
+                for member in lit.members.iter() {
+                    match member {
+                        // `{ change: [id: number] }`
+                        TSSignature::TSPropertySignature(prop) => {
+                            if let Some(name) = prop.key.static_name() {
+                                names.push(name.to_string());
+                            }
+                        }
+                        // `{ (e: 'change', id: number): void }`
+                        TSSignature::TSCallSignatureDeclaration(sig) => {
+                            let event = sig
+                                .params
+                                .items
+                                .first()
+                                .and_then(|param| param.pattern.type_annotation.as_ref())
+                                .map(|ann| Type::from_ts_type(&ann.type_annotation));
+                            match event {
+                                Some(Type::StringLiteral(name)) => names.push(name),
+                                Some(Type::Union(members)) => {
+                                    names.extend(members.into_iter().filter_map(|m| match m {
+                                        Type::StringLiteral(name) => Some(name),
+                                        _ => None,
+                                    }))
+                                }
+                                _ => {}
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            return names;
+        }
+
+        match call.arguments.first() {
+            Some(Argument::ArrayExpression(arr)) => {
+                for element in arr.elements.iter() {
+                    if let Some(Expression::StringLiteral(s)) = element.as_expression() {
+                        names.push(s.value.to_string());
+                    }
+                }
+            }
+            Some(Argument::ObjectExpression(obj)) => {
+                for prop in obj.properties.iter() {
+                    if let ObjectPropertyKind::ObjectProperty(p) = prop {
+                        if let Some(name) = p.key.static_name() {
+                            names.push(name.to_string());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        names
+    }
+
+    /// Infer a call to a Vue reactivity API or a known function.
+    fn infer_call(&mut self, call: &CallExpression<'_>) -> Type {
+        let type_arg = call
+            .type_parameters
+            .as_ref()
+            .and_then(|params| params.params.first())
+            .map(Type::from_ts_type);
+        let first_arg = call.arguments.first().and_then(|arg| arg.as_expression());
+
+        match callee_name(call) {
+            Some("ref" | "shallowRef" | "customRef" | "toRef" | "defineModel") => {
+                let inner = match (type_arg, first_arg) {
+                    (Some(ty), _) => ty,
+                    (None, Some(arg)) if callee_name(call) != Some("defineModel") => {
+                        self.infer(arg).widen()
+                    }
+                    _ => Type::Any,
+                };
+                Type::Ref(Box::new(inner))
+            }
+            Some("computed") => {
+                let inner = match (type_arg, first_arg) {
+                    (Some(ty), _) => ty,
+                    (None, Some(getter)) => match self.infer(getter) {
+                        Type::Function(func) => func.ret.widen(),
+                        _ => Type::Any,
+                    },
+                    _ => Type::Any,
+                };
+                Type::ComputedRef(Box::new(inner))
+            }
+            Some("reactive" | "shallowReactive" | "readonly" | "shallowReadonly") => {
+                match (type_arg, first_arg) {
+                    (Some(ty), _) => ty,
+                    (None, Some(arg)) => unwrap_nested_refs(self.infer(arg).widen()),
+                    _ => Type::Any,
+                }
+            }
+            _ => match Inferrer::new(&self.env).infer(&call.callee) {
+                Type::Function(func) => *func.ret,
+                _ => Type::Any,
+            },
+        }
+    }
+
+    fn infer(&mut self, expr: &Expression<'_>) -> Type {
+        if let Expression::CallExpression(call) = unwrap_ts(expr) {
+            if callee_name(call).is_some() {
+                return self.infer_call(call);
+            }
+        }
+        Inferrer::new(&self.env).infer(expr)
+    }
+
+    fn bind_pattern(&mut self, pattern: &BindingPattern<'_>, ty: Type, kind: BindingKind) {
+        let mut inferrer = Inferrer::new(&self.env);
+        inferrer.bind_pattern(pattern, ty);
+        let mut names: Vec<(String, Type)> = Vec::new();
+        collect_names(pattern, &mut |name| {
+            if let Some(ty) = inferrer.lookup(name) {
+                names.push((name.to_string(), ty));
+            }
+        });
+        drop(inferrer);
+
+        let start = pattern.kind.span().start;
+        for (name, ty) in names {
+            self.bind(&name, ty, kind, start);
+        }
+    }
+
+    fn bind(&mut self, name: &str, ty: Type, kind: BindingKind, offset: u32) {
+        self.ctx.add_binding(
+            name,
+            Binding::new(name, ty.to_type_info(), kind).with_offset(offset),
+        );
+        self.env.values.insert(name.to_string(), ty);
+    }
+}
+
+/// Visit binding names in a pattern.
+fn collect_names(pattern: &BindingPattern<'_>, f: &mut impl FnMut(&str)) {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(id) => f(id.name.as_str()),
+        BindingPatternKind::AssignmentPattern(assign) => collect_names(&assign.left, f),
+        BindingPatternKind::ObjectPattern(obj) => {
+            for prop in obj.properties.iter() {
+                collect_names(&prop.value, f);
+            }
+            if let Some(rest) = &obj.rest {
+                collect_names(&rest.argument, f);
+            }
+        }
+        BindingPatternKind::ArrayPattern(arr) => {
+            for element in arr.elements.iter().flatten() {
+                collect_names(element, f);
+            }
+            if let Some(rest) = &arr.rest {
+                collect_names(&rest.argument, f);
+            }
+        }
+    }
+}
+
+/// Get the name of a call's callee if it is a plain identifier.
+fn callee_name<'a>(call: &'a CallExpression<'_>) -> Option<&'a str> {
+    match &call.callee {
+        Expression::Identifier(id) => Some(id.name.as_str()),
+        _ => None,
+    }
+}
+
+/// Strip `as` / `satisfies` / `!` wrappers.
+fn unwrap_ts<'a, 'b>(expr: &'b Expression<'a>) -> &'b Expression<'a> {
+    match expr {
+        Expression::TSSatisfiesExpression(e) => unwrap_ts(&e.expression),
+        Expression::TSNonNullExpression(e) => unwrap_ts(&e.expression),
+        Expression::ParenthesizedExpression(e) => unwrap_ts(&e.expression),
+        _ => expr,
+    }
+}
+
+fn binding_kind(kind: VariableDeclarationKind) -> BindingKind {
+    match kind {
+        VariableDeclarationKind::Let => BindingKind::Let,
+        VariableDeclarationKind::Var => BindingKind::Var,
+        _ => BindingKind::Const,
+    }
+}
+
+fn call_binding_kind(callee: &str, kind: VariableDeclarationKind) -> BindingKind {
+    match callee {
+        "ref" | "shallowRef" | "customRef" | "toRef" => BindingKind::Ref,
+        "computed" => BindingKind::Computed,
+        "reactive" | "shallowReactive" => BindingKind::Reactive,
+        _ => binding_kind(kind),
+    }
+}
+
+/// Reactive objects unwrap refs stored in their properties.
+fn unwrap_nested_refs(ty: Type) -> Type {
+    match ty {
+        Type::Object(mut obj) => {
+            for property in &mut obj.properties {
+                property.ty = std::mem::replace(&mut property.ty, Type::Any).unwrap_ref();
+            }
+            Type::Object(obj)
+        }
+        other => other,
+    }
+}
+
+/// Map a runtime prop `type` option to a type.
+fn prop_constructor_type(expr: &Expression<'_>) -> Type {
+    match expr {
+        Expression::Identifier(id) => match id.name.as_str() {
+            "String" => Type::String,
+            "Number" => Type::Number,
+            "Boolean" => Type::Boolean,
+            "Array" => Type::Array(Box::new(Type::Any)),
+            "Object" => Type::Object(ObjectType::open()),
+            "Function" => Type::Function(FunctionType::variadic(Type::Any)),
+            "Symbol" => Type::Symbol,
+            "BigInt" => Type::BigInt,
+            "null" => Type::Any,
+            name => Type::Named(name.to_string(), Vec::new()),
+        },
+        Expression::ArrayExpression(arr) => Type::union(
+            arr.elements
+                .iter()
+                .filter_map(|element| element.as_expression())
+                .map(prop_constructor_type)
+                .collect(),
+        ),
+        Expression::TSAsExpression(e) => Type::from_ts_type(&e.type_annotation),
+        _ => Type::Any,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(source: &str) -> TypeContext {
+        let mut ctx = TypeContext::default();
+        collect_script_setup(source, &mut ctx);
+        ctx
+    }
+
+    fn binding(ctx: &TypeContext, name: &str) -> (String, BindingKind) {
+        let binding = ctx
+            .bindings
+            .get(name)
+            .unwrap_or_else(|| panic!("no binding `{}`", name));
+        (binding.type_info.display.clone(), binding.kind)
+    }
+
+    #[test]
+    fn test_binding_types() {
+        let ctx = collect(
+            r#"import { ref, computed, reactive } from 'vue'
+import type { Item } from './types'
+import Child from './Child.vue'
+
+const count = ref(0)
+const title = ref<string | null>(null)
+const double = computed(() => count.value * 2)
+const state = reactive({ count, items: [] as string[] })
+let label = 'a'
+const fixed = 'b'
+const [first, second] = [1, 'x'] as [number, string]
+function format(n: number, digits?: number): string { return n.toFixed(digits) }
+class Store {}
+const total: number = compute()
+"#,
+        );
+
+        assert_eq!(
+            binding(&ctx, "ref"),
+            ("any".to_string(), BindingKind::Import)
+        );
+        assert_eq!(binding(&ctx, "Child").1, BindingKind::Import);
+        assert!(!ctx.bindings.contains_key("Item"));
+
+        assert_eq!(
+            binding(&ctx, "count"),
+            ("Ref<number>".to_string(), BindingKind::Ref)
+        );
+        assert_eq!(binding(&ctx, "title").0, "Ref<string | null>");
+        assert_eq!(
+            binding(&ctx, "double"),
+            ("ComputedRef<number>".to_string(), BindingKind::Computed)
+        );
+        // Refs nested in reactive objects are unwrapped
+        assert_eq!(
+            binding(&ctx, "state"),
+            (
+                "{ count: number; items: string[] }".to_string(),
+                BindingKind::Reactive
+            )
+        );
+        assert_eq!(
+            binding(&ctx, "label"),
+            ("string".to_string(), BindingKind::Let)
+        );
+        assert_eq!(
+            binding(&ctx, "fixed"),
+            ("\"b\"".to_string(), BindingKind::Const)
+        );
+        assert_eq!(
+            binding(&ctx, "first"),
+            ("number".to_string(), BindingKind::Destructure)
+        );
+        assert_eq!(binding(&ctx, "second").0, "string");
+        assert_eq!(
+            binding(&ctx, "format"),
+            (
+                "(n: number, digits?: number) => string".to_string(),
+                BindingKind::Function
+            )
+        );
+        assert_eq!(binding(&ctx, "Store").1, BindingKind::Class);
+        assert_eq!(binding(&ctx, "total").0, "number");
+    }
+
+    #[test]
+    fn test_type_based_props_and_emits() {
+        let ctx = collect(
+            r#"interface Base { id: number }
+interface Props extends Base { title: string; size?: 'sm' | 'lg'; tags?: string[] }
+const props = withDefaults(defineProps<Props>(), { size: 'sm' })
+const emit = defineEmits<{ (e: 'change', id: number): void; (e: 'close'): void }>()
+const model = defineModel<string>('query')
+"#,
+        );
+
+        assert!(ctx.types.contains_key("Props"));
+        let prop = |name: &str| ctx.props.iter().find(|p| p.name == name).unwrap();
+        assert_eq!(prop("id").type_info.display, "number");
+        assert!(prop("id").required);
+        assert!(prop("title").required);
+        assert_eq!(prop("size").type_info.display, "\"sm\" | \"lg\"");
+        assert_eq!(prop("size").default.as_deref(), Some("'sm'"));
+        assert!(!prop("tags").required);
+        assert_eq!(prop("query").type_info.display, "string");
+
+        let emits: Vec<&str> = ctx.emits.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(emits, vec!["change", "close", "update:query"]);
+
+        assert_eq!(binding(&ctx, "props").1, BindingKind::Const);
+        assert!(binding(&ctx, "props").0.contains("title: string"));
+        assert_eq!(binding(&ctx, "emit").0, "(...args: any[]) => void");
+        assert_eq!(
+            binding(&ctx, "model"),
+            ("Ref<string>".to_string(), BindingKind::Ref)
+        );
+    }
+
+    #[test]
+    fn test_runtime_props_and_destructure() {
+        let ctx = collect(
+            r#"const { label, count = 1, disabled } = defineProps({
+  label: String,
+  count: { type: Number, default: 1 },
+  disabled: Boolean,
+  kind: { type: String as PropType<'a' | 'b'>, required: true },
+  value: [String, Number],
+})
+"#,
+        );
+
+        let prop = |name: &str| ctx.props.iter().find(|p| p.name == name).unwrap();
+        assert_eq!(prop("label").type_info.display, "string");
+        assert!(!prop("label").required);
+        assert_eq!(prop("count").default.as_deref(), Some("1"));
+        assert!(prop("count").required);
+        // Absent booleans are cast to `false`
+        assert!(prop("disabled").required);
+        assert_eq!(prop("kind").type_info.display, "\"a\" | \"b\"");
+        assert!(prop("kind").required);
+        assert_eq!(prop("value").type_info.display, "string | number");
+
+        assert_eq!(
+            binding(&ctx, "label"),
+            ("string".to_string(), BindingKind::Prop)
+        );
+        assert_eq!(
+            binding(&ctx, "count"),
+            ("number".to_string(), BindingKind::Prop)
+        );
+        assert_eq!(binding(&ctx, "disabled").0, "boolean");
+        assert!(!ctx.bindings.contains_key("kind"));
+    }
+}
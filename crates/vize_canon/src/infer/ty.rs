@@ -0,0 +1,749 @@
+//! Structural type representation for native inference.
+//!
+//! Types are rendered with TypeScript syntax so they can round-trip through
+//! [`TypeInfo::display`] strings stored in a [`TypeContext`](crate::TypeContext).
+
+use std::fmt;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    BindingPatternKind, FormalParameters, Statement, TSLiteral, TSMethodSignatureKind, TSSignature,
+    TSTupleElement, TSType, TSTypeName, TSTypeOperatorOperator,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::types::{TypeInfo, TypeKind};
+
+/// An inferred type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    /// `any`, also used whenever inference gives up.
+    Any,
+    /// `unknown`.
+    Unknown,
+    /// `never`.
+    Never,
+    /// `void`.
+    Void,
+    /// `null`.
+    Null,
+    /// `undefined`.
+    Undefined,
+    /// `string`.
+    String,
+    /// `number`.
+    Number,
+    /// `boolean`.
+    Boolean,
+    /// `bigint`.
+    BigInt,
+    /// `symbol`.
+    Symbol,
+    /// String literal type (`"foo"`).
+    StringLiteral(String),
+    /// Number literal type (`42`), stored as written.
+    NumberLiteral(String),
+    /// Boolean literal type (`true`).
+    BooleanLiteral(bool),
+    /// `T[]`.
+    Array(Box<Type>),
+    /// `[A, B]`.
+    Tuple(Vec<Type>),
+    /// Object type with known members.
+    Object(ObjectType),
+    /// Function type.
+    Function(FunctionType),
+    /// `A | B`.
+    Union(Vec<Type>),
+    /// `Ref<T>` (also `ShallowRef`, `ModelRef`, ...).
+    Ref(Box<Type>),
+    /// `ComputedRef<T>`.
+    ComputedRef(Box<Type>),
+    /// Reference to a named type that may be resolved later.
+    Named(String, Vec<Type>),
+}
+
+/// Members of an object type.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ObjectType {
+    /// Named properties, in declaration order.
+    pub properties: Vec<Property>,
+    /// Index signature value type (`[key: string]: T`).
+    ///
+    /// Objects with an index signature accept any property name.
+    pub index: Option<Box<Type>>,
+}
+
+/// A property of an object type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    /// Property name.
+    pub name: String,
+    /// Property type.
+    pub ty: Type,
+    /// Whether the property is optional.
+    pub optional: bool,
+}
+
+/// A function signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionType {
+    /// Parameters.
+    pub params: Vec<Param>,
+    /// Return type.
+    pub ret: Box<Type>,
+}
+
+/// A function parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    /// Parameter name (for display).
+    pub name: String,
+    /// Parameter type.
+    pub ty: Type,
+    /// Whether the parameter is optional or has a default.
+    pub optional: bool,
+    /// Whether this is a rest parameter.
+    pub rest: bool,
+}
+
+impl ObjectType {
+    /// Create an object type from properties.
+    pub fn new(properties: Vec<Property>) -> Self {
+        Self {
+            properties,
+            index: None,
+        }
+    }
+
+    /// Create an object type that accepts any property.
+    pub fn open() -> Self {
+        Self {
+            properties: Vec::new(),
+            index: Some(Box::new(Type::Any)),
+        }
+    }
+
+    /// Find a property by name.
+    pub fn get(&self, name: &str) -> Option<&Property> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+
+    /// Add or replace a property.
+    pub fn insert(&mut self, property: Property) {
+        match self.properties.iter_mut().find(|p| p.name == property.name) {
+            Some(existing) => *existing = property,
+            None => self.properties.push(property),
+        }
+    }
+}
+
+impl Property {
+    /// Create a required property.
+    pub fn new(name: impl Into<String>, ty: Type) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+            optional: false,
+        }
+    }
+}
+
+impl FunctionType {
+    /// Number of arguments that must be passed.
+    pub fn min_args(&self) -> usize {
+        self.params
+            .iter()
+            .take_while(|p| !p.optional && !p.rest)
+            .count()
+    }
+
+    /// Maximum number of arguments, or `None` with a rest parameter.
+    pub fn max_args(&self) -> Option<usize> {
+        if self.params.iter().any(|p| p.rest) {
+            None
+        } else {
+            Some(self.params.len())
+        }
+    }
+
+    /// A function accepting anything and returning `ret`.
+    pub fn variadic(ret: Type) -> Self {
+        Self {
+            params: vec![Param {
+                name: "args".to_string(),
+                ty: Type::Array(Box::new(Type::Any)),
+                optional: false,
+                rest: true,
+            }],
+            ret: Box::new(ret),
+        }
+    }
+}
+
+impl Type {
+    /// Parse a TypeScript type expression.
+    ///
+    /// Returns `any` if the text is not a valid type.
+    pub fn parse(text: &str) -> Type {
+        let allocator = Allocator::default();
+        let source = format!("type __VizeType = {};", text);
+        let source_type = SourceType::from_path("type.ts").unwrap_or_default();
+        let ret = Parser::new(&allocator, &source, source_type).parse();
+
+        if !ret.errors.is_empty() {
+            return Type::Any;
+        }
+        match ret.program.body.first() {
+            Some(Statement::TSTypeAliasDeclaration(alias)) => {
+                Type::from_ts_type(&alias.type_annotation)
+            }
+            _ => Type::Any,
+        }
+    }
+
+    /// Convert the type stored in a [`TypeInfo`].
+    pub fn from_type_info(info: &TypeInfo) -> Type {
+        match info.kind {
+            TypeKind::Any | TypeKind::Unknown => Type::Any,
+            _ => Type::parse(&info.display),
+        }
+    }
+
+    /// Convert an oxc TypeScript type node.
+    pub fn from_ts_type(ty: &TSType<'_>) -> Type {
+        match ty {
+            TSType::TSAnyKeyword(_) => Type::Any,
+            TSType::TSUnknownKeyword(_) => Type::Unknown,
+            TSType::TSNeverKeyword(_) => Type::Never,
+            TSType::TSVoidKeyword(_) => Type::Void,
+            TSType::TSNullKeyword(_) => Type::Null,
+            TSType::TSUndefinedKeyword(_) => Type::Undefined,
+            TSType::TSStringKeyword(_) => Type::String,
+            TSType::TSNumberKeyword(_) => Type::Number,
+            TSType::TSBooleanKeyword(_) => Type::Boolean,
+            TSType::TSBigIntKeyword(_) => Type::BigInt,
+            TSType::TSSymbolKeyword(_) => Type::Symbol,
+            TSType::TSObjectKeyword(_) => Type::Object(ObjectType::open()),
+            TSType::TSLiteralType(lit) => match &lit.literal {
+                TSLiteral::StringLiteral(s) => Type::StringLiteral(s.value.to_string()),
+                TSLiteral::NumericLiteral(n) => Type::NumberLiteral(
+                    n.raw
+                        .as_ref()
+                        .map(|raw| raw.to_string())
+                        .unwrap_or_else(|| n.value.to_string()),
+                ),
+                TSLiteral::BooleanLiteral(b) => Type::BooleanLiteral(b.value),
+                TSLiteral::NullLiteral(_) => Type::Null,
+                TSLiteral::TemplateLiteral(_) => Type::String,
+                TSLiteral::UnaryExpression(_) => Type::Number,
+                TSLiteral::BigIntLiteral(_) => Type::BigInt,
+                TSLiteral::RegExpLiteral(_) => Type::Named("RegExp".to_string(), Vec::new()),
+            },
+            TSType::TSTemplateLiteralType(_) => Type::String,
+            TSType::TSArrayType(arr) => {
+                Type::Array(Box::new(Type::from_ts_type(&arr.element_type)))
+            }
+            TSType::TSTupleType(tuple) => Type::Tuple(
+                tuple
+                    .element_types
+                    .iter()
+                    .map(Type::from_tuple_element)
+                    .collect(),
+            ),
+            TSType::TSUnionType(union) => {
+                Type::union(union.types.iter().map(Type::from_ts_type).collect())
+            }
+            TSType::TSIntersectionType(intersection) => {
+                Type::intersection(intersection.types.iter().map(Type::from_ts_type).collect())
+            }
+            TSType::TSParenthesizedType(paren) => Type::from_ts_type(&paren.type_annotation),
+            TSType::TSTypeOperatorType(op) => match op.operator {
+                TSTypeOperatorOperator::Readonly => Type::from_ts_type(&op.type_annotation),
+                TSTypeOperatorOperator::Keyof => Type::String,
+                TSTypeOperatorOperator::Unique => Type::Symbol,
+            },
+            TSType::TSTypeLiteral(lit) => Type::Object(object_from_signatures(&lit.members)),
+            TSType::TSFunctionType(func) => Type::Function(FunctionType {
+                params: params_from_formal(&func.params),
+                ret: Box::new(Type::from_ts_type(&func.return_type.type_annotation)),
+            }),
+            TSType::TSTypeReference(reference) => {
+                let name = type_name(&reference.type_name);
+                let args: Vec<Type> = reference
+                    .type_parameters
+                    .as_ref()
+                    .map(|params| params.params.iter().map(Type::from_ts_type).collect())
+                    .unwrap_or_default();
+                Type::reference(name, args)
+            }
+            _ => Type::Any,
+        }
+    }
+
+    fn from_tuple_element(element: &TSTupleElement<'_>) -> Type {
+        match element {
+            TSTupleElement::TSOptionalType(opt) => Type::from_ts_type(&opt.type_annotation),
+            TSTupleElement::TSRestType(rest) => Type::from_ts_type(&rest.type_annotation),
+            other => other
+                .as_ts_type()
+                .map(Type::from_ts_type)
+                .unwrap_or(Type::Any),
+        }
+    }
+
+    /// Build a reference to a named type, mapping well-known generics.
+    pub fn reference(name: String, mut args: Vec<Type>) -> Type {
+        let first = |args: &mut Vec<Type>| {
+            if args.is_empty() {
+                Type::Any
+            } else {
+                args.swap_remove(0)
+            }
+        };
+
+        match name.as_str() {
+            "Ref" | "ShallowRef" | "ModelRef" | "WritableComputedRef" | "CustomRef" => {
+                Type::Ref(Box::new(first(&mut args)))
+            }
+            "ComputedRef" => Type::ComputedRef(Box::new(first(&mut args))),
+            "Array" | "ReadonlyArray" => Type::Array(Box::new(first(&mut args))),
+            "Readonly" | "DeepReadonly" | "UnwrapRef" | "UnwrapNestedRefs" | "Reactive"
+            | "NonNullable" | "Required" | "Partial" => first(&mut args),
+            "Record" => Type::Object(ObjectType {
+                properties: Vec::new(),
+                index: Some(Box::new(args.pop().unwrap_or(Type::Any))),
+            }),
+            "PropType" => first(&mut args),
+            "String" => Type::String,
+            "Number" => Type::Number,
+            "Boolean" => Type::Boolean,
+            "Function" => Type::Function(FunctionType::variadic(Type::Any)),
+            "Object" => Type::Object(ObjectType::open()),
+            _ => Type::Named(name, args),
+        }
+    }
+
+    /// Build a normalized union type.
+    ///
+    /// Nested unions are flattened, duplicates removed, and `any` absorbs
+    /// everything.
+    pub fn union(types: Vec<Type>) -> Type {
+        let mut members: Vec<Type> = Vec::with_capacity(types.len());
+        for ty in types {
+            let flattened = match ty {
+                Type::Union(inner) => inner,
+                Type::Never => continue,
+                other => vec![other],
+            };
+            for member in flattened {
+                if member == Type::Any {
+                    return Type::Any;
+                }
+                if !members.contains(&member) {
+                    members.push(member);
+                }
+            }
+        }
+
+        match members.len() {
+            0 => Type::Never,
+            1 => members.pop().unwrap_or(Type::Never),
+            _ => Type::Union(members),
+        }
+    }
+
+    /// Merge the members of object types in an intersection.
+    ///
+    /// Intersections involving non-object types are approximated as `any`.
+    pub fn intersection(types: Vec<Type>) -> Type {
+        let mut merged = ObjectType::default();
+        for ty in types {
+            match ty {
+                Type::Object(obj) => {
+                    for property in obj.properties {
+                        merged.insert(property);
+                    }
+                    if obj.index.is_some() {
+                        merged.index = obj.index;
+                    }
+                }
+                _ => return Type::Any,
+            }
+        }
+        Type::Object(merged)
+    }
+
+    /// Widen literal types to their primitive (`"a"` to `string`).
+    pub fn widen(self) -> Type {
+        match self {
+            Type::StringLiteral(_) => Type::String,
+            Type::NumberLiteral(_) => Type::Number,
+            Type::BooleanLiteral(_) => Type::Boolean,
+            Type::Union(members) => Type::union(members.into_iter().map(Type::widen).collect()),
+            other => other,
+        }
+    }
+
+    /// Unwrap `Ref<T>` / `ComputedRef<T>` to `T`.
+    pub fn unwrap_ref(self) -> Type {
+        match self {
+            Type::Ref(inner) | Type::ComputedRef(inner) => *inner,
+            other => other,
+        }
+    }
+
+    /// Remove `null` and `undefined` from a type.
+    pub fn non_nullable(self) -> Type {
+        match self {
+            Type::Union(members) => Type::union(
+                members
+                    .into_iter()
+                    .filter(|m| !matches!(m, Type::Null | Type::Undefined))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    /// Check if the type gives no information (`any` or `unknown`).
+    pub fn is_any(&self) -> bool {
+        matches!(self, Type::Any | Type::Unknown)
+    }
+
+    /// Convert to a [`TypeInfo`].
+    pub fn to_type_info(&self) -> TypeInfo {
+        TypeInfo::new(self.to_string(), self.kind())
+    }
+
+    /// Get the [`TypeKind`] category of this type.
+    pub fn kind(&self) -> TypeKind {
+        match self {
+            Type::Any => TypeKind::Any,
+            Type::Unknown => TypeKind::Unknown,
+            Type::Never => TypeKind::Never,
+            Type::Void => TypeKind::Void,
+            Type::Null => TypeKind::Null,
+            Type::Undefined => TypeKind::Undefined,
+            Type::String | Type::Number | Type::Boolean | Type::BigInt | Type::Symbol => {
+                TypeKind::Primitive
+            }
+            Type::StringLiteral(_) | Type::NumberLiteral(_) | Type::BooleanLiteral(_) => {
+                TypeKind::Literal
+            }
+            Type::Array(_) => TypeKind::Array,
+            Type::Tuple(_) => TypeKind::Tuple,
+            Type::Object(_) => TypeKind::Object,
+            Type::Function(_) => TypeKind::Function,
+            Type::Union(_) => TypeKind::Union,
+            Type::Ref(_) => TypeKind::Ref,
+            Type::ComputedRef(_) => TypeKind::Computed,
+            Type::Named(_, _) => TypeKind::Interface,
+        }
+    }
+}
+
+/// Build an object type from interface or type literal members.
+pub fn object_from_signatures(members: &[TSSignature<'_>]) -> ObjectType {
+    let mut object = ObjectType::default();
+
+    for member in members {
+        match member {
+            TSSignature::TSPropertySignature(prop) => {
+                let Some(name) = prop.key.static_name() else {
+                    object.index = Some(Box::new(Type::Any));
+                    continue;
+                };
+                let ty = prop
+                    .type_annotation
+                    .as_ref()
+                    .map(|ann| Type::from_ts_type(&ann.type_annotation))
+                    .unwrap_or(Type::Any);
+                object.insert(Property {
+                    name: name.to_string(),
+                    ty,
+                    optional: prop.optional,
+                });
+            }
+            TSSignature::TSMethodSignature(method) => {
+                let Some(name) = method.key.static_name() else {
+                    object.index = Some(Box::new(Type::Any));
+                    continue;
+                };
+                let ret = method
+                    .return_type
+                    .as_ref()
+                    .map(|ann| Type::from_ts_type(&ann.type_annotation))
+                    .unwrap_or(Type::Any);
+                let ty = match method.kind {
+                    TSMethodSignatureKind::Method => Type::Function(FunctionType {
+                        params: params_from_formal(&method.params),
+                        ret: Box::new(ret),
+                    }),
+                    TSMethodSignatureKind::Get | TSMethodSignatureKind::Set => ret,
+                };
+                object.insert(Property {
+                    name: name.to_string(),
+                    ty,
+                    optional: method.optional,
+                });
+            }
+            TSSignature::TSIndexSignature(index) => {
+                object.index = Some(Box::new(Type::from_ts_type(
+                    &index.type_annotation.type_annotation,
+                )));
+            }
+            // Call and construct signatures make the object callable; treat
+            // it as open rather than modelling overloads.
+            _ => object.index = Some(Box::new(Type::Any)),
+        }
+    }
+
+    object
+}
+
+/// Convert function parameters, using annotations where present.
+pub fn params_from_formal(params: &FormalParameters<'_>) -> Vec<Param> {
+    let mut result: Vec<Param> = params
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let (name, has_default) = match &param.pattern.kind {
+                BindingPatternKind::BindingIdentifier(id) => (id.name.to_string(), false),
+                BindingPatternKind::AssignmentPattern(assign) => match &assign.left.kind {
+                    BindingPatternKind::BindingIdentifier(id) => (id.name.to_string(), true),
+                    _ => (format!("arg{}", i), true),
+                },
+                _ => (format!("arg{}", i), false),
+            };
+            let ty = param
+                .pattern
+                .type_annotation
+                .as_ref()
+                .map(|ann| Type::from_ts_type(&ann.type_annotation))
+                .unwrap_or(Type::Any);
+            Param {
+                name,
+                ty,
+                optional: param.pattern.optional || has_default,
+                rest: false,
+            }
+        })
+        .collect();
+
+    if let Some(rest) = &params.rest {
+        let name = match &rest.argument.kind {
+            BindingPatternKind::BindingIdentifier(id) => id.name.to_string(),
+            _ => "rest".to_string(),
+        };
+        let ty = rest
+            .argument
+            .type_annotation
+            .as_ref()
+            .map(|ann| Type::from_ts_type(&ann.type_annotation))
+            .unwrap_or_else(|| Type::Array(Box::new(Type::Any)));
+        result.push(Param {
+            name,
+            ty,
+            optional: false,
+            rest: true,
+        });
+    }
+
+    result
+}
+
+/// Render a (possibly qualified) type name.
+fn type_name(name: &TSTypeName<'_>) -> String {
+    match name {
+        TSTypeName::IdentifierReference(id) => id.name.to_string(),
+        TSTypeName::QualifiedName(qualified) => {
+            format!("{}.{}", type_name(&qualified.left), qualified.right.name)
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Any => f.write_str("any"),
+            Type::Unknown => f.write_str("unknown"),
+            Type::Never => f.write_str("never"),
+            Type::Void => f.write_str("void"),
+            Type::Null => f.write_str("null"),
+            Type::Undefined => f.write_str("undefined"),
+            Type::String => f.write_str("string"),
+            Type::Number => f.write_str("number"),
+            Type::Boolean => f.write_str("boolean"),
+            Type::BigInt => f.write_str("bigint"),
+            Type::Symbol => f.write_str("symbol"),
+            Type::StringLiteral(s) => {
+                write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+            Type::NumberLiteral(n) => f.write_str(n),
+            Type::BooleanLiteral(b) => write!(f, "{}", b),
+            Type::Array(element) => match element.as_ref() {
+                Type::Union(_) | Type::Function(_) => write!(f, "({})[]", element),
+                _ => write!(f, "{}[]", element),
+            },
+            Type::Tuple(elements) => {
+                f.write_str("[")?;
+                write_list(f, elements, ", ")?;
+                f.write_str("]")
+            }
+            Type::Object(obj) => {
+                if obj.properties.is_empty() && obj.index.is_none() {
+                    return f.write_str("{}");
+                }
+                f.write_str("{ ")?;
+                let mut first = true;
+                if let Some(index) = &obj.index {
+                    write!(f, "[key: string]: {}", index)?;
+                    first = false;
+                }
+                for prop in &obj.properties {
+                    if !first {
+                        f.write_str("; ")?;
+                    }
+                    first = false;
+                    if is_identifier_name(&prop.name) {
+                        f.write_str(&prop.name)?;
+                    } else {
+                        write!(f, "\"{}\"", prop.name)?;
+                    }
+                    if prop.optional {
+                        f.write_str("?")?;
+                    }
+                    write!(f, ": {}", prop.ty)?;
+                }
+                f.write_str(" }")
+            }
+            Type::Function(func) => {
+                f.write_str("(")?;
+                for (i, param) in func.params.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    if param.rest {
+                        f.write_str("...")?;
+                    }
+                    f.write_str(&param.name)?;
+                    if param.optional {
+                        f.write_str("?")?;
+                    }
+                    write!(f, ": {}", param.ty)?;
+                }
+                write!(f, ") => {}", func.ret)
+            }
+            Type::Union(members) => {
+                for (i, member) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    match member {
+                        Type::Function(_) => write!(f, "({})", member)?,
+                        _ => write!(f, "{}", member)?,
+                    }
+                }
+                Ok(())
+            }
+            Type::Ref(inner) => write!(f, "Ref<{}>", inner),
+            Type::ComputedRef(inner) => write!(f, "ComputedRef<{}>", inner),
+            Type::Named(name, args) => {
+                f.write_str(name)?;
+                if !args.is_empty() {
+                    f.write_str("<")?;
+                    write_list(f, args, ", ")?;
+                    f.write_str(">")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn write_list(f: &mut fmt::Formatter<'_>, types: &[Type], sep: &str) -> fmt::Result {
+    for (i, ty) in types.iter().enumerate() {
+        if i > 0 {
+            f.write_str(sep)?;
+        }
+        write!(f, "{}", ty)?;
+    }
+    Ok(())
+}
+
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_primitives() {
+        assert_eq!(Type::parse("string"), Type::String);
+        assert_eq!(Type::parse("number[]"), Type::Array(Box::new(Type::Number)));
+        assert_eq!(Type::parse("'a'"), Type::StringLiteral("a".to_string()));
+        assert_eq!(Type::parse("not a type"), Type::Any);
+    }
+
+    #[test]
+    fn test_parse_vue_refs() {
+        assert_eq!(
+            Type::parse("Ref<number>"),
+            Type::Ref(Box::new(Type::Number))
+        );
+        assert_eq!(
+            Type::parse("ComputedRef<string>"),
+            Type::ComputedRef(Box::new(Type::String))
+        );
+        assert_eq!(
+            Type::parse("Array<string>"),
+            Type::Array(Box::new(Type::String))
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for text in [
+            "{ id: number; name?: string }",
+            "(a: number, b?: string, ...rest: any[]) => void",
+            "(string | number)[]",
+            "\"a\" | \"b\" | null",
+            "[string, number]",
+            "{ [key: string]: boolean; count: number }",
+            "Ref<{ name: string }>",
+            "Map<string, number>",
+        ] {
+            assert_eq!(Type::parse(text).to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_union_normalization() {
+        assert_eq!(
+            Type::union(vec![Type::String, Type::String, Type::Never]),
+            Type::String
+        );
+        assert_eq!(Type::union(vec![Type::String, Type::Any]), Type::Any);
+        assert_eq!(
+            Type::parse("'a' | 'b'").widen(),
+            Type::String,
+            "literal unions widen to a single primitive"
+        );
+    }
+
+    #[test]
+    fn test_function_arity() {
+        let Type::Function(func) = Type::parse("(a: number, b?: number) => void") else {
+            panic!("expected function type");
+        };
+        assert_eq!(func.min_args(), 1);
+        assert_eq!(func.max_args(), Some(2));
+    }
+}
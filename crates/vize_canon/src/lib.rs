@@ -45,6 +45,7 @@
 mod checker;
 mod context;
 mod diagnostic;
+mod infer;
 pub mod intelligence;
 pub mod sfc_typecheck;
 pub mod source_map;
@@ -24,6 +24,8 @@ pub struct TsgoLspClient {
     request_id: AtomicI64,
     /// Pending diagnostics received via publishDiagnostics
     diagnostics: HashMap<String, Vec<LspDiagnostic>>,
+    /// Document versions for open documents
+    versions: HashMap<String, i32>,
     /// Temporary directory for tsconfig.json (cleaned up on drop)
     temp_dir: Option<std::path::PathBuf>,
 }
@@ -142,6 +144,7 @@ impl TsgoLspClient {
             stdout: BufReader::new(stdout),
             request_id: AtomicI64::new(1),
             diagnostics: HashMap::new(),
+            versions: HashMap::new(),
             temp_dir: Some(temp_dir_path),
         };
 
@@ -212,6 +215,7 @@ impl TsgoLspClient {
         });
 
         self.send_notification("textDocument/didOpen", params)?;
+        self.versions.insert(uri.to_string(), 1);
 
         // Drain any pending messages to prevent pipe buffer from filling up
         self.drain_pending_messages();
@@ -275,6 +279,44 @@ impl TsgoLspClient {
         }
     }
 
+    /// Replace the content of an open virtual document
+    pub fn did_change(&mut self, uri: &str, content: &str) -> Result<(), String> {
+        let version = self.versions.entry(uri.to_string()).or_insert(1);
+        *version += 1;
+
+        let params = serde_json::json!({
+            "textDocument": {
+                "uri": uri,
+                "version": *version
+            },
+            "contentChanges": [{ "text": content }]
+        });
+
+        self.send_notification("textDocument/didChange", params)?;
+
+        // Previously published diagnostics are stale
+        self.diagnostics.remove(uri);
+        self.drain_pending_messages();
+
+        Ok(())
+    }
+
+    /// Tell the server that files it reads from disk (e.g. imported `.ts`
+    /// modules) were modified
+    pub fn did_change_watched_files(&mut self, uris: &[String]) -> Result<(), String> {
+        // FileChangeType.Changed
+        let changes: Vec<Value> = uris
+            .iter()
+            .map(|uri| serde_json::json!({ "uri": uri, "type": 2 }))
+            .collect();
+        self.send_notification(
+            "workspace/didChangeWatchedFiles",
+            serde_json::json!({ "changes": changes }),
+        )?;
+        self.drain_pending_messages();
+        Ok(())
+    }
+
     /// Close a virtual document
     pub fn did_close(&mut self, uri: &str) -> Result<(), String> {
         let params = serde_json::json!({
@@ -287,6 +329,7 @@ impl TsgoLspClient {
 
         // Remove cached diagnostics
         self.diagnostics.remove(uri);
+        self.versions.remove(uri);
 
         Ok(())
     }
@@ -187,6 +187,39 @@ pub fn check_template_bindings(
     }
 }
 
+/// Check template expression types against types inferred from `<script setup>`.
+///
+/// Unknown identifiers are left to [`check_template_bindings`].
+pub fn check_template_types(
+    template: &str,
+    script_setup: &str,
+    template_offset: u32,
+    result: &mut SfcTypeCheckResult,
+) {
+    use crate::{TypeChecker, TypeContext, TypeErrorCode, TypeSeverity};
+
+    let ctx = TypeContext::from_script_setup(script_setup);
+    let checked = TypeChecker::new().check_template(template, &ctx);
+
+    for diag in checked.diagnostics {
+        if diag.code == TypeErrorCode::UnknownIdentifier {
+            continue;
+        }
+        result.add_diagnostic(SfcTypeDiagnostic {
+            severity: match diag.severity {
+                TypeSeverity::Error => SfcTypeSeverity::Error,
+                TypeSeverity::Warning => SfcTypeSeverity::Warning,
+            },
+            message: diag.message,
+            start: diag.start + template_offset,
+            end: diag.end + template_offset,
+            code: Some(diag.code.name().to_string()),
+            help: None,
+            related: Vec::new(),
+        });
+    }
+}
+
 /// Check for reactivity loss patterns.
 pub fn check_reactivity(
     summary: &vize_croquis::Croquis,
@@ -34,6 +34,7 @@
 //! │  - check_props_typing()             │
 //! │  - check_emits_typing()             │
 //! │  - check_template_bindings()        │
+//! │  - check_template_types()           │
 //! │  - generate_virtual_ts_with_scopes()│
 //! └─────────────────────────────────────┘
 //! ```
@@ -46,7 +47,7 @@ use vize_carton::Bump;
 
 use checks::{
     check_emits_typing, check_fallthrough_attrs, check_invalid_exports, check_props_typing,
-    check_reactivity, check_setup_context, check_template_bindings,
+    check_reactivity, check_setup_context, check_template_bindings, check_template_types,
 };
 use virtual_ts::generate_virtual_ts_with_scopes;
 
@@ -150,6 +151,8 @@ pub struct SfcTypeCheckOptions {
     pub check_emits: bool,
     /// Whether to check template bindings
     pub check_template_bindings: bool,
+    /// Whether to check template expression types
+    pub check_template_types: bool,
     /// Whether to check reactivity loss patterns
     pub check_reactivity: bool,
     /// Whether to check setup context violations
@@ -171,6 +174,7 @@ impl SfcTypeCheckOptions {
             check_props: true,
             check_emits: true,
             check_template_bindings: true,
+            check_template_types: true,
             check_reactivity: true,
             check_setup_context: true,
             check_invalid_exports: true,
@@ -285,6 +289,20 @@ pub fn type_check_sfc(source: &str, options: &SfcTypeCheckOptions) -> SfcTypeChe
         check_template_bindings(&summary, template_offset, &mut result, options.strict);
     }
 
+    // Check template expression types
+    if options.check_template_types {
+        if let (Some(template), Some(script_setup)) =
+            (&descriptor.template, &descriptor.script_setup)
+        {
+            check_template_types(
+                &template.content,
+                &script_setup.content,
+                template_offset,
+                &mut result,
+            );
+        }
+    }
+
     // Check reactivity loss
     if options.check_reactivity {
         check_reactivity(&summary, script_offset, &mut result, options.strict);
@@ -487,6 +505,24 @@ const count = ref(0);
         assert!(has_undefined_error);
     }
 
+    #[test]
+    fn test_type_check_template_types() {
+        let source = r#"<script setup lang="ts">
+const user = { name: 'Ada' };
+</script>
+<template>
+    <div>{{ user.nmae }}</div>
+</template>"#;
+        let options = SfcTypeCheckOptions::new("test.vue");
+        let result = type_check_sfc(source, &options);
+        let diag = result
+            .diagnostics
+            .iter()
+            .find(|d| d.code.as_deref() == Some("property-not-found"))
+            .expect("expected property-not-found");
+        assert_eq!(&source[diag.start as usize..diag.end as usize], "nmae");
+    }
+
     #[test]
     fn test_type_check_defined_binding() {
         let source = r#"<script setup>
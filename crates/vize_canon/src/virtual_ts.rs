@@ -7,6 +7,7 @@
 //! Key design: Uses closures from Croquis scope information instead of
 //! `declare const` to properly model Vue's template scoping.
 
+use serde::{Deserialize, Serialize};
 use std::ops::Range;
 use vize_croquis::{
     analysis::ComponentUsage, naming::to_pascal_case, Croquis, EventHandlerScopeData, Scope,
@@ -14,7 +15,7 @@ use vize_croquis::{
 };
 
 /// A mapping from generated virtual TS position to SFC source position.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VizeMapping {
     /// Byte range in the generated virtual TypeScript.
     pub gen_range: Range<usize>,
@@ -609,7 +610,7 @@ pub fn generate_virtual_ts_with_offsets(
         ts.push('\n');
 
         // Props are available in template as variables
-        generate_props_variables(&mut ts, summary, script_content);
+        let declared_props = generate_props_variables(&mut ts, summary, script_content);
 
         // Generate scope closures
         generate_scope_closures(&mut ts, &mut mappings, summary, template_offset);
@@ -640,64 +641,65 @@ pub fn generate_virtual_ts_with_offsets(
 
         // Reference all setup bindings to prevent TS6133 for variables
         // used only in CSS v-bind() or other non-template contexts
-        if !summary.bindings.bindings.is_empty() {
-            ts.push_str("\n  // Reference setup bindings (used in template/CSS v-bind)\n  ");
-            let mut first = true;
-            for name in summary.bindings.bindings.keys() {
-                // Skip bindings that are JS keywords or would cause syntax errors
-                if matches!(
-                    name.as_str(),
-                    "default"
-                        | "class"
-                        | "new"
-                        | "delete"
-                        | "void"
-                        | "typeof"
-                        | "in"
-                        | "instanceof"
-                        | "return"
-                        | "switch"
-                        | "case"
-                        | "break"
-                        | "continue"
-                        | "throw"
-                        | "try"
-                        | "catch"
-                        | "finally"
-                        | "if"
-                        | "else"
-                        | "for"
-                        | "while"
-                        | "do"
-                        | "with"
-                        | "var"
-                        | "let"
-                        | "const"
-                        | "function"
-                        | "this"
-                        | "super"
-                        | "import"
-                        | "export"
-                        | "yield"
-                        | "await"
-                        | "async"
-                        | "static"
-                        | "enum"
-                        | "implements"
-                        | "interface"
-                        | "package"
-                        | "private"
-                        | "protected"
-                        | "public"
-                ) {
-                    continue;
-                }
-                if !first {
-                    ts.push(' ');
-                }
-                ts.push_str(&format!("void {};", name));
-                first = false;
+        let mut references = Vec::new();
+        for name in summary.bindings.bindings.keys() {
+            // Props variables are already declared and referenced above
+            if declared_props.iter().any(|prop| prop == name.as_str()) {
+                continue;
             }
+            // Skip bindings that are JS keywords or would cause syntax errors
+            if matches!(
+                name.as_str(),
+                "default"
+                    | "class"
+                    | "new"
+                    | "delete"
+                    | "void"
+                    | "typeof"
+                    | "in"
+                    | "instanceof"
+                    | "return"
+                    | "switch"
+                    | "case"
+                    | "break"
+                    | "continue"
+                    | "throw"
+                    | "try"
+                    | "catch"
+                    | "finally"
+                    | "if"
+                    | "else"
+                    | "for"
+                    | "while"
+                    | "do"
+                    | "with"
+                    | "var"
+                    | "let"
+                    | "const"
+                    | "function"
+                    | "this"
+                    | "super"
+                    | "import"
+                    | "export"
+                    | "yield"
+                    | "await"
+                    | "async"
+                    | "static"
+                    | "enum"
+                    | "implements"
+                    | "interface"
+                    | "package"
+                    | "private"
+                    | "protected"
+                    | "public"
+            ) {
+                continue;
+            }
+            references.push(format!("void {};", name));
+        }
+        if !references.is_empty() {
+            ts.push_str("\n  // Reference setup bindings (used in template/CSS v-bind)\n  ");
+            ts.push_str(&references.join(" "));
             ts.push('\n');
         }
 
@@ -814,8 +816,13 @@ fn generate_props_type(ts: &mut String, summary: &Croquis) {
     ts.push('\n');
 }
 
-/// Generate props variables inside template closure
-fn generate_props_variables(ts: &mut String, summary: &Croquis, script_content: Option<&str>) {
+/// Generate props variables inside template closure, returning the declared names
+fn generate_props_variables(
+    ts: &mut String,
+    summary: &Croquis,
+    script_content: Option<&str>,
+) -> Vec<String> {
+    let mut declared = Vec::new();
     let props = summary.macros.props();
     let has_props = !props.is_empty();
     let define_props_type_args = summary
@@ -828,6 +835,7 @@ fn generate_props_variables(ts: &mut String, summary: &Croquis, script_content:
         ts.push_str("  // Access via `propName` or `props.propName`\n");
         ts.push_str("  const props: Props = {} as Props;\n");
         ts.push_str("  void props; // Mark as used to avoid TS6133\n");
+        declared.push("props".to_string());
 
         if has_props {
             // Runtime-declared props: generate individual variables
@@ -837,6 +845,7 @@ fn generate_props_variables(ts: &mut String, summary: &Croquis, script_content:
                     prop.name, prop.name
                 ));
                 ts.push_str(&format!("  void {};\n", prop.name));
+                declared.push(prop.name.to_string());
             }
         } else if let Some(type_args) = define_props_type_args {
             // Type-only defineProps<TypeName>(): extract fields
@@ -856,6 +865,7 @@ fn generate_props_variables(ts: &mut String, summary: &Croquis, script_content:
                         prop.name, prop.name
                     ));
                     ts.push_str(&format!("  void {};\n", prop.name));
+                    declared.push(prop.name.to_string());
                 }
             } else if let Some(script) = script_content {
                 // Fallback: extract field names from script text (for local interfaces)
@@ -864,10 +874,12 @@ fn generate_props_variables(ts: &mut String, summary: &Croquis, script_content:
                     ts.push_str(&format!("  const {} = props[\"{}\"];\n", field, field));
                     ts.push_str(&format!("  void {};\n", field));
                 }
+                declared.extend(field_names);
             }
         }
         ts.push('\n');
     }
+    declared
 }
 
 /// Extract field names from an interface or type literal in script content.
@@ -1876,6 +1888,30 @@ function handleHover() {}
         );
     }
 
+    #[test]
+    fn test_props_referenced_once() {
+        use vize_croquis::{Analyzer, AnalyzerOptions};
+
+        let script = r#"interface Props { title: string }
+const props = defineProps<Props>()
+const local = 1
+"#;
+        let template = r#"<div>{{ title }}</div>"#;
+
+        let allocator = vize_carton::Bump::new();
+        let (root, _) = vize_armature::parse(&allocator, template);
+
+        let mut analyzer = Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.analyze_script_setup(script);
+        analyzer.analyze_template(&root);
+        let summary = analyzer.finish();
+
+        let output = generate_virtual_ts(&summary, Some(script), Some(&root), 0);
+        assert_eq!(output.code.matches("void props;").count(), 1);
+        assert_eq!(output.code.matches("void title;").count(), 1);
+        assert!(output.code.contains("  void local;\n"));
+    }
+
     #[test]
     fn test_source_mappings_generated() {
         use vize_croquis::{Analyzer, AnalyzerOptions};
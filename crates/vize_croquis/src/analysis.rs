@@ -39,6 +39,7 @@
 //! ```
 
 use crate::hoist::HoistTracker;
+use crate::hydration::{HydrationTracker, InvalidNesting};
 use crate::macros::MacroTracker;
 use crate::provide::ProvideInjectTracker;
 use crate::reactivity::ReactivityTracker;
@@ -64,6 +65,8 @@ pub struct TemplateInfo {
     pub content_start: u32,
     /// End offset of template content (relative to template block).
     pub content_end: u32,
+    /// Tags of the HTML elements rendered at the root.
+    pub root_tags: SmallVec<[CompactString; 2]>,
 }
 
 impl TemplateInfo {
@@ -104,6 +107,9 @@ pub struct Croquis {
     /// Setup context violation tracking (CSRP/memory leaks)
     pub setup_context: SetupContextTracker,
 
+    /// SSR hydration hazards found in the script
+    pub hydration: HydrationTracker,
+
     /// TypeScript type resolution
     pub types: TypeResolver,
 
@@ -143,6 +149,9 @@ pub struct Croquis {
     /// Element IDs found in template (for cross-file uniqueness checking)
     pub element_ids: Vec<ElementIdInfo>,
 
+    /// Elements the browser re-parents when parsing server-rendered markup
+    pub invalid_nesting: Vec<InvalidNesting>,
+
     /// Definition spans for bindings (name -> (start, end) offset in script)
     /// Used for Go-to-Definition support.
     pub binding_spans: FxHashMap<CompactString, (u32, u32)>,
@@ -278,6 +287,8 @@ pub struct ComponentUsage {
     pub has_spread_attrs: bool,
     /// The scope this component usage is in (for v-for prop checking)
     pub scope_id: crate::scope::ScopeId,
+    /// Tags of the enclosing elements, outermost first
+    pub ancestors: SmallVec<[CompactString; 4]>,
 }
 
 /// A prop passed to a component in template.
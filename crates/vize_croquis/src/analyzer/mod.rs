@@ -106,6 +106,8 @@ pub struct Analyzer {
     pub(crate) script_analyzed: bool,
     /// Current v-if guard stack (for type narrowing in templates)
     pub(crate) vif_guard_stack: Vec<CompactString>,
+    /// Tags of the elements enclosing the current node (for HTML nesting checks)
+    pub(crate) element_stack: Vec<CompactString>,
 }
 
 impl Analyzer {
@@ -123,6 +125,7 @@ impl Analyzer {
             summary: Croquis::new(),
             script_analyzed: false,
             vif_guard_stack: Vec::new(),
+            element_stack: Vec::new(),
         }
     }
 
@@ -181,6 +184,7 @@ impl Analyzer {
         self.summary.provide_inject = result.provide_inject;
         self.summary.binding_spans = result.binding_spans;
         self.summary.setup_context = result.setup_context;
+        self.summary.hydration = result.hydration;
 
         self
     }
@@ -206,6 +210,7 @@ impl Analyzer {
         self.summary.provide_inject = result.provide_inject;
         self.summary.binding_spans = result.binding_spans;
         self.summary.setup_context = result.setup_context;
+        self.summary.hydration = result.hydration;
 
         self
     }
@@ -10,6 +10,7 @@
 use crate::analysis::{
     ComponentUsage, ElementIdInfo, ElementIdKind, EventListener, PassedProp, UndefinedRef,
 };
+use crate::hydration::{nesting_violation, InvalidNesting};
 use crate::scope::{CallbackScopeData, EventHandlerScopeData, VForScopeData, VSlotScopeData};
 use crate::ScopeBinding;
 use vize_carton::{smallvec, CompactString, SmallVec};
@@ -73,6 +74,7 @@ impl Analyzer {
             }
         }
         self.summary.template_info.root_element_count = root_element_count;
+        Self::collect_root_tags(&root.children, &mut self.summary.template_info.root_tags);
 
         // Store template content range
         self.summary.template_info.content_start = root.loc.start.offset;
@@ -100,6 +102,35 @@ impl Analyzer {
         }
     }
 
+    /// Collect the tags of root-level HTML elements, looking through
+    /// v-if/v-for and `<template>` wrappers
+    fn collect_root_tags(
+        children: &[TemplateChildNode<'_>],
+        tags: &mut SmallVec<[CompactString; 2]>,
+    ) {
+        for child in children {
+            match child {
+                TemplateChildNode::Element(el) if el.tag == "template" => {
+                    Self::collect_root_tags(&el.children, tags);
+                }
+                TemplateChildNode::Element(el)
+                    if !is_component_tag(&el.tag) && !tags.contains(&el.tag) =>
+                {
+                    tags.push(el.tag.clone());
+                }
+                TemplateChildNode::If(if_node) => {
+                    for branch in if_node.branches.iter() {
+                        Self::collect_root_tags(&branch.children, tags);
+                    }
+                }
+                TemplateChildNode::For(for_node) => {
+                    Self::collect_root_tags(&for_node.children, tags);
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Visit template child node
     pub(super) fn visit_template_child(
         &mut self,
@@ -161,6 +192,19 @@ impl Analyzer {
             self.summary.used_components.insert(CompactString::new(tag));
         }
 
+        // Check for nesting the browser rewrites when parsing SSR output
+        if self.options.track_usage {
+            if let Some((parent, rewrite)) = nesting_violation(&self.element_stack, tag) {
+                self.summary.invalid_nesting.push(InvalidNesting {
+                    parent,
+                    child: CompactString::new(tag),
+                    rewrite,
+                    start: el.loc.start.offset,
+                    end: el.loc.end.offset,
+                });
+            }
+        }
+
         // Collect detailed component usage
         let mut component_usage = if is_component && self.options.track_usage {
             Some(ComponentUsage {
@@ -172,6 +216,7 @@ impl Analyzer {
                 slots: SmallVec::new(),
                 has_spread_attrs: false,
                 scope_id: crate::scope::ScopeId::ROOT, // Updated after scope entry
+                ancestors: self.element_stack.iter().cloned().collect(),
             })
         } else {
             None
@@ -464,9 +509,11 @@ impl Analyzer {
         };
 
         // Visit children
+        self.element_stack.push(CompactString::new(tag));
         for child in el.children.iter() {
             self.visit_template_child(child, scope_vars);
         }
+        self.element_stack.pop();
 
         // Pop v-if guard after visiting children
         if vif_guard_pushed {
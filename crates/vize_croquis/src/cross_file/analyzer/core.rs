@@ -308,6 +308,12 @@ impl CrossFileAnalyzer {
             result.diagnostics.extend(diags);
         }
 
+        if self.options.hydration {
+            let (issues, diags) = analyzers::analyze_hydration(&self.registry);
+            result.hydration_issues = issues;
+            result.diagnostics.extend(diags);
+        }
+
         // Static validation analyzers
         if self.options.component_resolution {
             let (issues, diags) =
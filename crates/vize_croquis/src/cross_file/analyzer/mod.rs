@@ -817,6 +817,131 @@ const state = inject('globalState')"#,
         assert_eq!(errors.len(), 0, "Should have no provide/inject errors");
     }
 
+    fn analyze_sfc(script: &str, template: &str) -> crate::Croquis {
+        let allocator = vize_carton::Bump::new();
+        let (root, _) = vize_armature::parse(&allocator, template);
+        let mut analyzer = crate::Analyzer::with_options(AnalyzerOptions::full());
+        analyzer.analyze_script_setup(script);
+        analyzer.analyze_template(&root);
+        analyzer.finish()
+    }
+
+    fn hydration_analyzer(files: &[(&str, &str, &str)]) -> CrossFileAnalyzer {
+        let mut analyzer = CrossFileAnalyzer::new(CrossFileOptions::default().with_hydration(true));
+        for (path, script, template) in files {
+            analyzer.add_file_with_analysis(Path::new(path), script, analyze_sfc(script, template));
+        }
+        analyzer.rebuild_component_edges();
+        analyzer
+    }
+
+    #[test]
+    fn test_hydration_prop_flow() {
+        let mut analyzer = hydration_analyzer(&[
+            (
+                "Page.vue",
+                "const now = Date.now()\nconst label = 'x'",
+                "<Card :stamp=\"now\" :label=\"label\" />",
+            ),
+            (
+                "Card.vue",
+                "const props = defineProps<{ stamp: number; label: string }>()",
+                "<div><CardFooter :at=\"props.stamp\" />{{ label }}</div>",
+            ),
+            (
+                "CardFooter.vue",
+                "defineProps<{ at: number }>()",
+                "<footer>{{ new Intl.DateTimeFormat().format(at) }}</footer>",
+            ),
+        ]);
+        let result = analyzer.analyze();
+
+        assert_eq!(result.hydration_issues.len(), 1);
+        let issue = &result.hydration_issues[0];
+        assert_eq!(
+            issue.path,
+            vec![
+                "Page.vue `now` (Date.now)",
+                "Page.vue <Card :stamp=\"now\">",
+                "Card.vue prop `stamp`",
+                "Card.vue <CardFooter :at=\"props.stamp\">",
+                "CardFooter.vue prop `at`",
+                "CardFooter.vue {{ new Intl.DateTimeFormat().format(at) }}",
+            ]
+        );
+        assert_eq!(
+            result.diagnostics[0].code(),
+            "vize:croquis/cf/hydration-prop-flow"
+        );
+    }
+
+    #[test]
+    fn test_hydration_prop_flow_false_positives() {
+        let mut analyzer = hydration_analyzer(&[
+            (
+                "Page.vue",
+                "const handlers = { onClick: () => window.open() }\n\
+                 const subwindow = { width: 1 }\n\
+                 const width = subwindow.width\n\
+                 const hint = 'see document.title'",
+                "<p>{{ width }} {{ hint }}</p>\
+                 <Card :handlers=\"handlers\" :width=\"subwindow.width\" :hint=\"hint\" />",
+            ),
+            (
+                "Card.vue",
+                "defineProps<{ handlers: object; width: number; hint: string }>()",
+                "<div>{{ handlers }} {{ width }} {{ hint }}</div>",
+            ),
+        ]);
+        let result = analyzer.analyze();
+
+        assert!(
+            result.hydration_issues.is_empty(),
+            "{:?}",
+            result.hydration_issues
+        );
+    }
+
+    #[test]
+    fn test_hydration_html_nesting() {
+        let mut analyzer = hydration_analyzer(&[
+            (
+                "Page.vue",
+                "const id = computed(() => useId())",
+                "<p><Card /></p><table><tr><td>x</td></tr></table><a href=\"/\"><span><a>y</a></span></a>",
+            ),
+            ("Card.vue", "", "<div>card</div>"),
+        ]);
+        let result = analyzer.analyze();
+
+        let kinds: Vec<_> = result
+            .hydration_issues
+            .iter()
+            .map(|issue| match &issue.kind {
+                crate::cross_file::HydrationIssueKind::InvalidNesting { parent, child } => {
+                    format!("<{}> in <{}>", child, parent)
+                }
+                other => format!("{:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "UseIdMisuse",
+                "<tr> in <table>",
+                "<a> in <a>",
+                "<div> in <p>"
+            ]
+        );
+        assert_eq!(
+            result.hydration_issues[3].path,
+            vec![
+                "Page.vue <Card> inside <p>",
+                "Card.vue root <div>: the browser closes the <p> before it",
+            ]
+        );
+    }
+
     // === Snapshot Tests ===
 
     #[test]
@@ -25,6 +25,8 @@ pub struct CrossFileOptions {
     pub reactivity_tracking: bool,
     /// Analyze setup context violations (CSRP/memory leaks).
     pub setup_context: bool,
+    /// Analyze SSR hydration mismatches (non-deterministic props, HTML nesting).
+    pub hydration: bool,
     /// Detect circular dependencies.
     pub circular_dependencies: bool,
     /// Maximum depth for dependency chain warnings.
@@ -50,6 +52,7 @@ impl CrossFileOptions {
             error_suspense_boundary: true,
             reactivity_tracking: true,
             setup_context: true,
+            hydration: true,
             circular_dependencies: true,
             max_import_depth: Some(10),
             component_resolution: true,
@@ -155,6 +158,7 @@ impl CrossFileOptions {
             || self.error_suspense_boundary
             || self.reactivity_tracking
             || self.setup_context
+            || self.hydration
             || self.circular_dependencies
             || self.component_resolution
             || self.props_validation
@@ -165,6 +169,12 @@ impl CrossFileOptions {
         self.setup_context = enabled;
         self
     }
+
+    /// Enable SSR hydration mismatch analysis.
+    pub fn with_hydration(mut self, enabled: bool) -> Self {
+        self.hydration = enabled;
+        self
+    }
 }
 
 /// Result of cross-file analysis.
@@ -200,6 +210,9 @@ pub struct CrossFileResult {
     /// Setup context violations (CSRP/memory leaks).
     pub setup_context_issues: Vec<analyzers::SetupContextIssue>,
 
+    /// Hydration mismatch risks.
+    pub hydration_issues: Vec<analyzers::HydrationIssue>,
+
     /// Circular dependencies (as paths of file IDs).
     pub circular_deps: Vec<Vec<FileId>>,
 
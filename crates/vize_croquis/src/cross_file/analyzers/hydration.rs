@@ -0,0 +1,404 @@
+//! SSR hydration mismatch analyzer.
+//!
+//! Follows non-deterministic values (`Date.now()`, `Math.random()`, browser
+//! globals, locale formatting) from the script that creates them through
+//! props into the component that renders them, and checks element nesting
+//! that the browser rewrites when parsing server-rendered markup, both within
+//! a template and where a component's root element lands in its parent.
+//!
+//! Uses the `hydration`, `invalid_nesting`, `component_usages` and
+//! `template_expressions` fields from each file's Croquis analysis.
+
+use crate::analysis::{ComponentUsage, TemplateExpressionKind};
+use crate::cross_file::diagnostics::{
+    CrossFileDiagnostic, CrossFileDiagnosticKind, DiagnosticSeverity,
+};
+use crate::cross_file::registry::{FileId, ModuleEntry, ModuleRegistry};
+use crate::hydration::{nesting_violation, NondeterministicKind};
+use crate::Croquis;
+use vize_carton::general::{camelize, capitalize};
+use vize_carton::{CompactString, FxHashSet};
+
+/// Maximum number of components a value is followed through.
+const MAX_FLOW_DEPTH: usize = 8;
+
+/// Information about a hydration mismatch risk.
+#[derive(Debug, Clone)]
+pub struct HydrationIssue {
+    /// The file where the issue is reported.
+    pub file_id: FileId,
+    /// Source offset in that file.
+    pub offset: u32,
+    /// Kind of issue.
+    pub kind: HydrationIssueKind,
+    /// Steps from the source of the problem to where it is rendered,
+    /// e.g. `["Parent.vue `now` (Date.now)", "Child.vue prop `at`", "Child.vue {{ at }}"]`.
+    pub path: Vec<CompactString>,
+}
+
+/// Kind of hydration mismatch risk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HydrationIssueKind {
+    /// A non-deterministic value reaches rendered output.
+    NondeterministicRender {
+        kind: NondeterministicKind,
+        pattern: CompactString,
+    },
+    /// `useId()` called where its call order is not stable.
+    UseIdMisuse,
+    /// Element nesting the browser rewrites.
+    InvalidNesting {
+        parent: CompactString,
+        child: CompactString,
+    },
+}
+
+/// A value being followed through the component tree.
+struct Flow<'r> {
+    kind: NondeterministicKind,
+    pattern: &'static str,
+    /// Where the flow entered the component tree (parent file and offset).
+    origin: (FileId, u32),
+    path: Vec<CompactString>,
+    visited: FxHashSet<FileId>,
+    registry: &'r ModuleRegistry,
+}
+
+/// Analyze hydration mismatch risks across component boundaries.
+pub fn analyze_hydration(
+    registry: &ModuleRegistry,
+) -> (Vec<HydrationIssue>, Vec<CrossFileDiagnostic>) {
+    let mut issues = Vec::new();
+
+    for entry in registry.vue_components() {
+        analyze_value_flows(registry, entry, &mut issues);
+        analyze_use_id(entry, &mut issues);
+        analyze_nesting(registry, entry, &mut issues);
+    }
+
+    let diagnostics = issues.iter().map(to_diagnostic).collect();
+    (issues, diagnostics)
+}
+
+/// Follow non-deterministic bindings and inline expressions into rendering.
+fn analyze_value_flows(
+    registry: &ModuleRegistry,
+    entry: &ModuleEntry,
+    issues: &mut Vec<HydrationIssue>,
+) {
+    let analysis = &entry.analysis;
+
+    // Bindings rendered by the component itself
+    for source in analysis.hydration.sources() {
+        let mut flow = Flow {
+            kind: source.kind,
+            pattern: source.pattern,
+            origin: (entry.id, source.start),
+            path: vec![CompactString::new(format!(
+                "{} `{}` ({})",
+                entry.filename, source.name, source.pattern
+            ))],
+            visited: FxHashSet::default(),
+            registry,
+        };
+        flow.visited.insert(entry.id);
+        if let Some(step) = rendered_step(entry, &source.name) {
+            flow.path.push(step);
+            issues.push(flow.issue());
+        }
+    }
+
+    // Values passed to children, either bound from a tainted binding or
+    // computed inline
+    for usage in &analysis.component_usages {
+        for prop in usage.props.iter().filter(|p| p.is_dynamic) {
+            let Some(value) = prop.value.as_deref() else {
+                continue;
+            };
+            let (kind, pattern, origin) = match NondeterministicKind::classify(value) {
+                Some((kind, pattern)) => (kind, pattern, None),
+                None => match analysis
+                    .hydration
+                    .sources()
+                    .iter()
+                    .find(|s| references(value, &s.name))
+                {
+                    Some(source) => (source.kind, source.pattern, Some(source)),
+                    None => continue,
+                },
+            };
+
+            let mut path = Vec::new();
+            if let Some(source) = origin {
+                path.push(CompactString::new(format!(
+                    "{} `{}` ({})",
+                    entry.filename, source.name, source.pattern
+                )));
+            }
+            path.push(CompactString::new(format!(
+                "{} <{} :{}=\"{}\">",
+                entry.filename, usage.name, prop.name, value
+            )));
+
+            let mut flow = Flow {
+                kind,
+                pattern,
+                origin: (entry.id, prop.start),
+                path,
+                visited: FxHashSet::default(),
+                registry,
+            };
+            flow.visited.insert(entry.id);
+            if let Some(issue) = flow.follow(usage, &prop.name) {
+                issues.push(issue);
+            }
+        }
+    }
+}
+
+impl Flow<'_> {
+    /// Follow a prop into the child component, returning an issue when the
+    /// value ends up rendered.
+    fn follow(&mut self, usage: &ComponentUsage, prop_name: &str) -> Option<HydrationIssue> {
+        if self.visited.len() > MAX_FLOW_DEPTH {
+            return None;
+        }
+        let child = find_component(self.registry, &usage.name)?;
+        if !self.visited.insert(child.id) {
+            return None;
+        }
+
+        let prop = camelize(prop_name);
+        self.path.push(CompactString::new(format!(
+            "{} prop `{}`",
+            child.filename, prop
+        )));
+
+        if let Some(step) = rendered_step(child, &prop) {
+            self.path.push(step);
+            return Some(self.issue());
+        }
+
+        // Passed on to a grandchild
+        for next in &child.analysis.component_usages {
+            for passed in next.props.iter().filter(|p| p.is_dynamic) {
+                let Some(value) = passed.value.as_deref() else {
+                    continue;
+                };
+                if !references(value, &prop) {
+                    continue;
+                }
+                let depth = self.path.len();
+                self.path.push(CompactString::new(format!(
+                    "{} <{} :{}=\"{}\">",
+                    child.filename, next.name, passed.name, value
+                )));
+                if let Some(issue) = self.follow(next, &passed.name) {
+                    return Some(issue);
+                }
+                self.path.truncate(depth);
+            }
+        }
+
+        self.visited.remove(&child.id);
+        self.path.pop();
+        None
+    }
+
+    fn issue(&self) -> HydrationIssue {
+        HydrationIssue {
+            file_id: self.origin.0,
+            offset: self.origin.1,
+            kind: HydrationIssueKind::NondeterministicRender {
+                kind: self.kind,
+                pattern: CompactString::new(self.pattern),
+            },
+            path: self.path.clone(),
+        }
+    }
+}
+
+/// Describe where a component renders `name`, if it does.
+///
+/// Interpolations, bindings on elements and v-if/v-show conditions change
+/// the markup; bindings on child components are followed separately.
+fn rendered_step(entry: &ModuleEntry, name: &str) -> Option<CompactString> {
+    let analysis = &entry.analysis;
+    analysis
+        .template_expressions
+        .iter()
+        .filter(|expr| references(&expr.content, name))
+        .find_map(|expr| {
+            let step = match expr.kind {
+                TemplateExpressionKind::Interpolation => format!("{{{{ {} }}}}", expr.content),
+                TemplateExpressionKind::VBind if !in_component_usage(analysis, expr.start) => {
+                    format!("v-bind=\"{}\"", expr.content)
+                }
+                TemplateExpressionKind::VIf => format!("v-if=\"{}\"", expr.content),
+                TemplateExpressionKind::VShow => format!("v-show=\"{}\"", expr.content),
+                _ => return None,
+            };
+            Some(CompactString::new(format!("{} {}", entry.filename, step)))
+        })
+}
+
+/// Whether an offset falls inside a component's opening tag.
+fn in_component_usage(analysis: &Croquis, offset: u32) -> bool {
+    analysis
+        .component_usages
+        .iter()
+        .any(|usage| usage.start <= offset && offset < usage.end)
+}
+
+/// Report `useId()` calls whose order differs between server and client.
+fn analyze_use_id(entry: &ModuleEntry, issues: &mut Vec<HydrationIssue>) {
+    for misuse in entry.analysis.hydration.use_id_misuses() {
+        issues.push(HydrationIssue {
+            file_id: entry.id,
+            offset: misuse.start,
+            kind: HydrationIssueKind::UseIdMisuse,
+            path: vec![CompactString::new(format!(
+                "{} useId() in {}",
+                entry.filename, misuse.context
+            ))],
+        });
+    }
+}
+
+/// Report nesting the browser rewrites, within a template and where a
+/// child component's root elements are placed in the parent.
+fn analyze_nesting(
+    registry: &ModuleRegistry,
+    entry: &ModuleEntry,
+    issues: &mut Vec<HydrationIssue>,
+) {
+    for nesting in &entry.analysis.invalid_nesting {
+        issues.push(HydrationIssue {
+            file_id: entry.id,
+            offset: nesting.start,
+            kind: HydrationIssueKind::InvalidNesting {
+                parent: nesting.parent.clone(),
+                child: nesting.child.clone(),
+            },
+            path: vec![CompactString::new(format!(
+                "{} <{}> inside <{}>: {}",
+                entry.filename, nesting.child, nesting.parent, nesting.rewrite
+            ))],
+        });
+    }
+
+    for usage in &entry.analysis.component_usages {
+        let Some(child) = find_component(registry, &usage.name) else {
+            continue;
+        };
+        for root in &child.analysis.template_info.root_tags {
+            let Some((parent, rewrite)) = nesting_violation(&usage.ancestors, root) else {
+                continue;
+            };
+            issues.push(HydrationIssue {
+                file_id: entry.id,
+                offset: usage.start,
+                kind: HydrationIssueKind::InvalidNesting {
+                    parent: parent.clone(),
+                    child: root.clone(),
+                },
+                path: vec![
+                    CompactString::new(format!(
+                        "{} <{}> inside <{}>",
+                        entry.filename, usage.name, parent
+                    )),
+                    CompactString::new(format!("{} root <{}>: {}", child.filename, root, rewrite)),
+                ],
+            });
+        }
+    }
+}
+
+/// Find the component file for a tag, e.g. `user-card` or `UserCard`.
+fn find_component<'r>(registry: &'r ModuleRegistry, tag: &str) -> Option<&'r ModuleEntry> {
+    registry
+        .find_by_component_name(tag)
+        .or_else(|| registry.find_by_component_name(&capitalize(&camelize(tag))))
+}
+
+/// Whether an expression references `name` as a variable or as `props.name`.
+fn references(expression: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    expression.match_indices(name).any(|(start, _)| {
+        let before = &expression[..start];
+        let after = &expression[start + name.len()..];
+        let starts_word = match before.chars().next_back() {
+            None => true,
+            Some('.') => before.ends_with("props."),
+            Some(c) => !is_ident(c),
+        };
+        starts_word && !after.starts_with(is_ident)
+    })
+}
+
+fn to_diagnostic(issue: &HydrationIssue) -> CrossFileDiagnostic {
+    match &issue.kind {
+        HydrationIssueKind::NondeterministicRender { kind, pattern } => CrossFileDiagnostic::new(
+            CrossFileDiagnosticKind::NondeterministicPropFlow {
+                source: pattern.clone(),
+                path: issue.path.clone(),
+            },
+            DiagnosticSeverity::Warning,
+            issue.file_id,
+            issue.offset,
+            format!(
+                "`{}` {} and is rendered: {}",
+                pattern,
+                kind.description(),
+                issue.path.join(" → ")
+            ),
+        )
+        .with_suggestion(
+            "Compute the value in onMounted(), pass it from the server state, or wrap the output in <ClientOnly>",
+        ),
+        HydrationIssueKind::UseIdMisuse => CrossFileDiagnostic::new(
+            CrossFileDiagnosticKind::HydrationMismatchRisk {
+                reason: CompactString::new("useId() called outside synchronous setup"),
+            },
+            DiagnosticSeverity::Warning,
+            issue.file_id,
+            issue.offset,
+            format!(
+                "{}; the generated ID may differ between server and client",
+                issue.path.join(" → ")
+            ),
+        )
+        .with_suggestion("Call useId() unconditionally at the top level of <script setup>"),
+        HydrationIssueKind::InvalidNesting { parent, child } => CrossFileDiagnostic::new(
+            CrossFileDiagnosticKind::InvalidHtmlNesting {
+                parent: parent.clone(),
+                child: child.clone(),
+            },
+            DiagnosticSeverity::Error,
+            issue.file_id,
+            issue.offset,
+            format!(
+                "<{}> cannot be placed inside <{}>: {}",
+                child,
+                parent,
+                issue.path.join(" → ")
+            ),
+        )
+        .with_suggestion("Change the markup so the browser does not restructure it during hydration"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_references() {
+        assert!(references("now", "now"));
+        assert!(references("format(now, 'x')", "now"));
+        assert!(references("props.now + 1", "now"));
+        assert!(!references("nowrap", "now"));
+        assert!(!references("item.now", "now"));
+        assert!(!references("knows", "now"));
+    }
+}
@@ -17,6 +17,7 @@ mod element_id;
 mod emit;
 mod event_bubbling;
 mod fallthrough;
+mod hydration;
 mod props_validation;
 mod provide_inject;
 mod reactivity;
@@ -29,6 +30,7 @@ pub use element_id::{analyze_element_ids, UniqueIdIssue};
 pub use emit::{analyze_emits, EmitFlow};
 pub use event_bubbling::{analyze_event_bubbling, EventBubble};
 pub use fallthrough::{analyze_fallthrough, FallthroughInfo};
+pub use hydration::{analyze_hydration, HydrationIssue, HydrationIssueKind};
 pub use props_validation::{analyze_props_validation, PropsValidationIssue};
 pub use provide_inject::{analyze_provide_inject, ProvideInjectMatch};
 pub use reactivity::{analyze_reactivity, ReactivityIssue, ReactivityIssueKind};
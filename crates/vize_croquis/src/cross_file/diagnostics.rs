@@ -100,6 +100,16 @@ pub enum CrossFileDiagnosticKind {
     AsyncWithoutSuspense { component_name: CompactString },
     /// Hydration mismatch risk (client-only content).
     HydrationMismatchRisk { reason: CompactString },
+    /// Non-deterministic value flows through props into rendered output.
+    NondeterministicPropFlow {
+        source: CompactString,
+        path: Vec<CompactString>,
+    },
+    /// Element nesting the browser rewrites when parsing SSR output.
+    InvalidHtmlNesting {
+        parent: CompactString,
+        child: CompactString,
+    },
 
     // === Error/Suspense Boundaries ===
     /// Error thrown but no onErrorCaptured in ancestors.
@@ -473,6 +483,12 @@ impl CrossFileDiagnostic {
             CrossFileDiagnosticKind::HydrationMismatchRisk { .. } => {
                 "vize:croquis/cf/hydration-risk"
             }
+            CrossFileDiagnosticKind::NondeterministicPropFlow { .. } => {
+                "vize:croquis/cf/hydration-prop-flow"
+            }
+            CrossFileDiagnosticKind::InvalidHtmlNesting { .. } => {
+                "vize:croquis/cf/invalid-html-nesting"
+            }
             // Error/Suspense Boundaries
             CrossFileDiagnosticKind::UncaughtErrorBoundary => "vize:croquis/cf/uncaught-error",
             CrossFileDiagnosticKind::MissingSuspenseBoundary => "vize:croquis/cf/missing-suspense",
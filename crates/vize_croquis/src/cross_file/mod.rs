@@ -16,6 +16,7 @@
 //!   - Unique Element IDs: Detect duplicate ID attributes across components
 //!   - Server/Client Boundaries: Identify SSR hydration boundary issues
 //!   - Error/Suspense Boundaries: Track error and async handling scopes
+//!   - Hydration: Follow non-deterministic values through props and check HTML nesting
 //!
 //! ## Usage
 //!
@@ -64,6 +65,6 @@ pub use suppression::{SuppressionDirective, SuppressionError, SuppressionMap};
 
 // Re-export analyzer types
 pub use analyzers::{
-    BoundaryInfo, BoundaryKind, EmitFlow, EventBubble, FallthroughInfo, ProvideInjectMatch,
-    ReactivityIssue, ReactivityIssueKind, UniqueIdIssue,
+    BoundaryInfo, BoundaryKind, EmitFlow, EventBubble, FallthroughInfo, HydrationIssue,
+    HydrationIssueKind, ProvideInjectMatch, ReactivityIssue, ReactivityIssueKind, UniqueIdIssue,
 };
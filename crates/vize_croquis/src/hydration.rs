@@ -0,0 +1,575 @@
+//! SSR hydration hazard tracking.
+//!
+//! Records what makes server-rendered markup differ from the client's first
+//! render:
+//! - Bindings initialized from non-deterministic values (`Date.now()`,
+//!   `Math.random()`, browser globals, locale formatting)
+//! - `useId()` calls outside synchronous, unconditional setup code
+//! - HTML nesting that browsers rewrite while parsing (`<p><div>`,
+//!   `<table><tr>`, nested `<a>`)
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrowFunctionExpression, CallExpression, Class, Expression, Function, IdentifierReference,
+    MemberExpression, NewExpression, UnaryExpression, UnaryOperator,
+};
+use oxc_ast::visit::walk;
+use oxc_ast::Visit;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_syntax::scope::ScopeFlags;
+use vize_carton::CompactString;
+
+/// Kind of value that differs between server and client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum NondeterministicKind {
+    /// Random values (`Math.random()`, `crypto.randomUUID()`)
+    Random = 0,
+    /// Current time (`Date.now()`, `new Date()`)
+    Time = 1,
+    /// Browser-only globals (`window`, `document`, `navigator`)
+    BrowserGlobal = 2,
+    /// Locale or timezone dependent formatting (`toLocaleString()`, `Intl`)
+    LocaleFormat = 3,
+}
+
+/// Global method calls (`object.method()`) and the kind of value they produce.
+const NONDETERMINISTIC_CALLS: &[(&str, &str, &str, NondeterministicKind)] = &[
+    (
+        "Math",
+        "random",
+        "Math.random",
+        NondeterministicKind::Random,
+    ),
+    (
+        "crypto",
+        "randomUUID",
+        "crypto.randomUUID",
+        NondeterministicKind::Random,
+    ),
+    (
+        "crypto",
+        "getRandomValues",
+        "crypto.getRandomValues",
+        NondeterministicKind::Random,
+    ),
+    ("Date", "now", "Date.now", NondeterministicKind::Time),
+    (
+        "performance",
+        "now",
+        "performance.now",
+        NondeterministicKind::Time,
+    ),
+];
+
+/// Methods formatting values for the current locale, on any object.
+const LOCALE_METHODS: &[&str] = &["toLocaleString", "toLocaleDateString", "toLocaleTimeString"];
+
+/// Globals whose members are non-deterministic.
+const NONDETERMINISTIC_GLOBALS: &[(&str, NondeterministicKind)] = &[
+    ("window", NondeterministicKind::BrowserGlobal),
+    ("document", NondeterministicKind::BrowserGlobal),
+    ("navigator", NondeterministicKind::BrowserGlobal),
+    ("localStorage", NondeterministicKind::BrowserGlobal),
+    ("sessionStorage", NondeterministicKind::BrowserGlobal),
+    ("Intl", NondeterministicKind::LocaleFormat),
+];
+
+impl NondeterministicKind {
+    /// Get display string for the kind
+    #[inline]
+    pub const fn to_display(self) -> &'static str {
+        match self {
+            Self::Random => "random",
+            Self::Time => "time",
+            Self::BrowserGlobal => "browser-global",
+            Self::LocaleFormat => "locale-format",
+        }
+    }
+
+    /// Get description of why the value mismatches
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Random => "produces a different value on the server and the client",
+            Self::Time => "reads the clock, which has moved on by the time the client hydrates",
+            Self::BrowserGlobal => {
+                "is only available in the browser, so the server renders a fallback"
+            }
+            Self::LocaleFormat => "depends on the locale and timezone of the machine rendering it",
+        }
+    }
+
+    /// Classify the source of an expression, returning the first
+    /// non-deterministic value it reads when evaluated.
+    ///
+    /// Returns `None` when the source does not parse as an expression.
+    pub fn classify(expression: &str) -> Option<(Self, &'static str)> {
+        let allocator = Allocator::default();
+        let expression = Parser::new(&allocator, expression, SourceType::ts())
+            .parse_expression()
+            .ok()?;
+        Self::classify_expression(&expression)
+    }
+
+    /// Classify an expression, returning the first non-deterministic value
+    /// it reads when evaluated.
+    ///
+    /// Only global references count (`subwindow.width` does not), and
+    /// function bodies are skipped since they run later (event handlers,
+    /// callbacks), except for `computed` getters, which run during render.
+    pub fn classify_expression(expression: &Expression<'_>) -> Option<(Self, &'static str)> {
+        let mut classifier = Classifier::default();
+        classifier.visit_expression(expression);
+        classifier.found
+    }
+}
+
+/// Finds the first non-deterministic value read by an expression.
+#[derive(Default)]
+struct Classifier {
+    found: Option<(NondeterministicKind, &'static str)>,
+}
+
+impl<'a> Visit<'a> for Classifier {
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if self.found.is_some() {
+            return;
+        }
+        if let Some(member) = it.callee.without_parentheses().as_member_expression() {
+            let property = member.static_property_name();
+            let call = NONDETERMINISTIC_CALLS
+                .iter()
+                .find(|(object, method, _, _)| {
+                    member.object().is_specific_id(object) && property == Some(*method)
+                })
+                .map(|(_, _, pattern, kind)| (*kind, *pattern));
+            let locale = LOCALE_METHODS
+                .iter()
+                .find(|method| property == Some(**method))
+                .map(|method| (NondeterministicKind::LocaleFormat, *method));
+            if let Some(found) = call.or(locale) {
+                self.found = Some(found);
+                return;
+            }
+        }
+
+        if it.callee.is_specific_id("computed") {
+            // The getter runs when the computed is rendered
+            for argument in &it.arguments {
+                match argument.as_expression() {
+                    Some(Expression::ArrowFunctionExpression(getter)) => {
+                        walk::walk_arrow_function_expression(self, getter);
+                    }
+                    Some(Expression::FunctionExpression(getter)) => {
+                        if let Some(body) = &getter.body {
+                            self.visit_function_body(body);
+                        }
+                    }
+                    _ => self.visit_argument(argument),
+                }
+            }
+            return;
+        }
+        walk::walk_call_expression(self, it);
+    }
+
+    fn visit_new_expression(&mut self, it: &NewExpression<'a>) {
+        if self.found.is_some() {
+            return;
+        }
+        if it.callee.is_specific_id("Date") && it.arguments.is_empty() {
+            self.found = Some((NondeterministicKind::Time, "new Date()"));
+            return;
+        }
+        walk::walk_new_expression(self, it);
+    }
+
+    fn visit_unary_expression(&mut self, it: &UnaryExpression<'a>) {
+        if self.found.is_some() {
+            return;
+        }
+        if it.operator == UnaryOperator::Typeof {
+            if it.argument.is_specific_id("window") {
+                self.found = Some((NondeterministicKind::BrowserGlobal, "typeof window"));
+                return;
+            }
+            if it.argument.is_specific_id("document") {
+                self.found = Some((NondeterministicKind::BrowserGlobal, "typeof document"));
+                return;
+            }
+        }
+        walk::walk_unary_expression(self, it);
+    }
+
+    fn visit_member_expression(&mut self, it: &MemberExpression<'a>) {
+        if self.found.is_some() {
+            return;
+        }
+        let global = NONDETERMINISTIC_GLOBALS
+            .iter()
+            .find(|(name, _)| it.object().is_specific_id(name));
+        if let Some((name, kind)) = global {
+            self.found = Some((*kind, *name));
+            return;
+        }
+        walk::walk_member_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        // Web Storage is commonly passed around without a member access
+        if self.found.is_none() && matches!(it.name.as_str(), "localStorage" | "sessionStorage") {
+            self.found = Some((
+                NondeterministicKind::BrowserGlobal,
+                if it.name == "localStorage" {
+                    "localStorage"
+                } else {
+                    "sessionStorage"
+                },
+            ));
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+/// A binding initialized from a non-deterministic value
+#[derive(Debug, Clone)]
+pub struct NondeterministicSource {
+    /// Binding name
+    pub name: CompactString,
+    pub kind: NondeterministicKind,
+    /// The matched pattern, e.g. `Date.now`
+    pub pattern: &'static str,
+    /// Initializer span in the script
+    pub start: u32,
+    pub end: u32,
+}
+
+/// A `useId()` call whose result depends on call order at runtime
+#[derive(Debug, Clone)]
+pub struct UseIdMisuse {
+    /// Where the call is, e.g. "a callback"
+    pub context: &'static str,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Tracks hydration hazards found in the script
+#[derive(Debug, Default)]
+pub struct HydrationTracker {
+    sources: Vec<NondeterministicSource>,
+    use_id_misuses: Vec<UseIdMisuse>,
+}
+
+impl HydrationTracker {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a binding initialized from a non-deterministic value
+    #[inline]
+    pub fn record_source(
+        &mut self,
+        name: CompactString,
+        kind: NondeterministicKind,
+        pattern: &'static str,
+        start: u32,
+        end: u32,
+    ) {
+        self.sources.push(NondeterministicSource {
+            name,
+            kind,
+            pattern,
+            start,
+            end,
+        });
+    }
+
+    /// Record a misplaced `useId()` call
+    #[inline]
+    pub fn record_use_id_misuse(&mut self, context: &'static str, start: u32, end: u32) {
+        self.use_id_misuses.push(UseIdMisuse {
+            context,
+            start,
+            end,
+        });
+    }
+
+    /// Get all non-deterministic bindings
+    #[inline]
+    pub fn sources(&self) -> &[NondeterministicSource] {
+        &self.sources
+    }
+
+    /// Get the non-deterministic source for a binding
+    #[inline]
+    pub fn source(&self, name: &str) -> Option<&NondeterministicSource> {
+        self.sources.iter().find(|s| s.name == name)
+    }
+
+    /// Get all misplaced `useId()` calls
+    #[inline]
+    pub fn use_id_misuses(&self) -> &[UseIdMisuse] {
+        &self.use_id_misuses
+    }
+}
+
+/// An element the browser moves, re-parents or drops when parsing
+/// server-rendered markup
+#[derive(Debug, Clone)]
+pub struct InvalidNesting {
+    /// The ancestor the element cannot be placed in
+    pub parent: CompactString,
+    /// The misplaced element
+    pub child: CompactString,
+    /// What the browser does instead
+    pub rewrite: &'static str,
+    /// Element span in the template
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Elements whose start tag closes an open `<p>`.
+const CLOSES_P: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dd",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "li",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "search",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Elements that stop the search for an open `<p>` ("button scope").
+const P_SCOPE_BOUNDARIES: &[&str] = &[
+    "applet", "button", "caption", "html", "marquee", "object", "table", "td", "th",
+];
+
+/// Vue built-ins that render no element of their own.
+const VUE_BUILTIN_TAGS: &[&str] = &[
+    "component",
+    "keep-alive",
+    "slot",
+    "suspense",
+    "teleport",
+    "template",
+    "transition",
+    "transition-group",
+];
+
+/// Whether a tag renders as a plain HTML element.
+#[inline]
+fn is_html_element(tag: &str) -> bool {
+    !tag.contains('-')
+        && !tag.starts_with(|c: char| c.is_ascii_uppercase())
+        && !VUE_BUILTIN_TAGS.contains(&tag)
+}
+
+/// Whether a tag moves its content elsewhere in the document.
+#[inline]
+fn is_teleport(tag: &str) -> bool {
+    tag.eq_ignore_ascii_case("teleport")
+}
+
+/// Check whether `child` placed inside `ancestors` (outermost first) is
+/// rewritten by the HTML parser.
+///
+/// Returns the offending ancestor and what the browser does instead.
+/// Components are treated as transparent, except that they hide the direct
+/// parent from the table rules.
+pub fn nesting_violation(
+    ancestors: &[CompactString],
+    child: &str,
+) -> Option<(CompactString, &'static str)> {
+    if !is_html_element(child) {
+        return None;
+    }
+
+    // Enclosing elements up to the nearest teleport, innermost first
+    let enclosing = || {
+        ancestors
+            .iter()
+            .rev()
+            .take_while(|tag| !is_teleport(tag))
+            .filter(|tag| tag.as_str() != "template")
+    };
+
+    if let Some(parent) = enclosing().next().filter(|tag| is_html_element(tag)) {
+        if let Some(rewrite) = table_rewrite(parent, child) {
+            return Some((parent.clone(), rewrite));
+        }
+    }
+
+    if CLOSES_P.contains(&child) {
+        let open_p = enclosing()
+            .take_while(|tag| !P_SCOPE_BOUNDARIES.contains(&tag.as_str()))
+            .find(|tag| tag.as_str() == "p");
+        if let Some(p) = open_p {
+            return Some((p.clone(), "the browser closes the <p> before it"));
+        }
+    }
+
+    if child == "a" {
+        if let Some(a) = enclosing().find(|tag| tag.as_str() == "a") {
+            return Some((a.clone(), "the browser closes the outer <a> before it"));
+        }
+    }
+
+    if child == "form" {
+        if let Some(form) = enclosing().find(|tag| tag.as_str() == "form") {
+            return Some((form.clone(), "the browser drops the nested <form> tag"));
+        }
+    }
+
+    None
+}
+
+/// Rewrites for table content in the wrong place.
+fn table_rewrite(parent: &str, child: &str) -> Option<&'static str> {
+    const MOVED_OUT: &str = "the browser moves it out of the table";
+    let always_allowed = matches!(child, "script" | "style" | "template");
+    match parent {
+        "table" if child == "tr" => Some("the browser inserts a <tbody> around the row"),
+        "table" if matches!(child, "td" | "th") => {
+            Some("the browser inserts a <tbody> and <tr> around the cell")
+        }
+        "table" => (!always_allowed
+            && !matches!(child, "caption" | "colgroup" | "thead" | "tbody" | "tfoot"))
+        .then_some(MOVED_OUT),
+        "thead" | "tbody" | "tfoot" if matches!(child, "td" | "th") => {
+            Some("the browser inserts a <tr> around the cell")
+        }
+        "thead" | "tbody" | "tfoot" => (!always_allowed && child != "tr").then_some(MOVED_OUT),
+        "tr" => (!always_allowed && !matches!(child, "td" | "th")).then_some(MOVED_OUT),
+        _ if matches!(child, "tr" | "td" | "th" | "tbody" | "thead" | "tfoot") => {
+            Some("the browser drops the table tag outside of a table")
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(tags: &[&str]) -> Vec<CompactString> {
+        tags.iter().map(|t| CompactString::new(t)).collect()
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(
+            NondeterministicKind::classify("ref(Date.now())"),
+            Some((NondeterministicKind::Time, "Date.now"))
+        );
+        assert_eq!(
+            NondeterministicKind::classify("computed(() => window.innerWidth)"),
+            Some((NondeterministicKind::BrowserGlobal, "window"))
+        );
+        assert_eq!(
+            NondeterministicKind::classify("price.toLocaleString()"),
+            Some((NondeterministicKind::LocaleFormat, "toLocaleString"))
+        );
+        assert_eq!(
+            NondeterministicKind::classify("ref(new Date())"),
+            Some((NondeterministicKind::Time, "new Date()"))
+        );
+        assert_eq!(
+            NondeterministicKind::classify("typeof window !== 'undefined'"),
+            Some((NondeterministicKind::BrowserGlobal, "typeof window"))
+        );
+        assert_eq!(
+            NondeterministicKind::classify("useStorage(localStorage)"),
+            Some((NondeterministicKind::BrowserGlobal, "localStorage"))
+        );
+        assert_eq!(
+            NondeterministicKind::classify("new Intl.NumberFormat().format(n)"),
+            Some((NondeterministicKind::LocaleFormat, "Intl"))
+        );
+        assert_eq!(NondeterministicKind::classify("new Date(props.at)"), None);
+        assert_eq!(NondeterministicKind::classify("ref(0)"), None);
+    }
+
+    #[test]
+    fn test_classify_false_positives() {
+        // Identifiers that merely end with a global's name
+        assert_eq!(NondeterministicKind::classify("subwindow.width"), None);
+        assert_eq!(NondeterministicKind::classify("mydocument.title"), None);
+        // Globals mentioned in strings
+        assert_eq!(NondeterministicKind::classify("'see document.title'"), None);
+        assert_eq!(NondeterministicKind::classify("`Math.random()`"), None);
+        // Functions that only run later
+        assert_eq!(
+            NondeterministicKind::classify("{ onClick: () => window.open() }"),
+            None
+        );
+        assert_eq!(
+            NondeterministicKind::classify("function () { return Date.now() }"),
+            None
+        );
+        assert_eq!(
+            NondeterministicKind::classify("onMounted(() => { width.value = window.innerWidth })"),
+            None
+        );
+        // Member names of other objects
+        assert_eq!(NondeterministicKind::classify("config.window.size"), None);
+        assert_eq!(NondeterministicKind::classify("rng.random()"), None);
+    }
+
+    #[test]
+    fn test_nesting_violation() {
+        let violation = |ancestors: &[&str], child| {
+            nesting_violation(&stack(ancestors), child).map(|(parent, _)| parent)
+        };
+
+        assert_eq!(violation(&["p", "span"], "div").as_deref(), Some("p"));
+        assert_eq!(violation(&["p", "Card"], "ul").as_deref(), Some("p"));
+        assert_eq!(violation(&["p", "button"], "div"), None);
+        assert_eq!(violation(&["p", "Teleport"], "div"), None);
+        assert_eq!(violation(&["table"], "tr").as_deref(), Some("table"));
+        assert_eq!(violation(&["table", "tbody"], "tr"), None);
+        assert_eq!(
+            violation(&["table", "template"], "tr").as_deref(),
+            Some("table")
+        );
+        assert_eq!(violation(&["table", "Row"], "tr"), None);
+        assert_eq!(violation(&["tr"], "div").as_deref(), Some("tr"));
+        assert_eq!(violation(&["a", "span"], "a").as_deref(), Some("a"));
+        assert_eq!(violation(&["div"], "span"), None);
+        assert_eq!(violation(&["p"], "Card"), None);
+    }
+}
@@ -43,6 +43,7 @@ pub mod call_graph;
 pub mod css;
 pub mod display;
 pub mod hoist;
+pub mod hydration;
 pub mod import_resolver;
 pub mod macros;
 pub mod naming;
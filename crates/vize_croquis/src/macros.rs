@@ -125,6 +125,8 @@ pub struct PropDefinition {
     pub prop_type: Option<CompactString>,
     pub required: bool,
     pub default_value: Option<CompactString>,
+    /// JSDoc description
+    pub description: Option<CompactString>,
 }
 
 /// Emit definition from defineEmits
@@ -132,6 +134,8 @@ pub struct PropDefinition {
 pub struct EmitDefinition {
     pub name: CompactString,
     pub payload_type: Option<CompactString>,
+    /// JSDoc description
+    pub description: Option<CompactString>,
 }
 
 /// An actual emit() call in the code
@@ -172,6 +176,8 @@ pub struct ExposeDefinition {
     pub name: CompactString,
     /// Type of the exposed property (if known)
     pub expose_type: Option<CompactString>,
+    /// JSDoc description
+    pub description: Option<CompactString>,
 }
 
 /// Slots definition from defineSlots
@@ -181,6 +187,8 @@ pub struct SlotsDefinition {
     pub name: CompactString,
     /// Slot props type (if known)
     pub props_type: Option<CompactString>,
+    /// JSDoc description
+    pub description: Option<CompactString>,
 }
 
 /// Macro binding kind for props destructure
@@ -302,6 +310,17 @@ impl MacroTracker {
         &self.props
     }
 
+    /// Set the default value of a prop, unless it already has one
+    pub fn set_prop_default(&mut self, name: &str, value: CompactString) {
+        if let Some(prop) = self
+            .props
+            .iter_mut()
+            .find(|p| p.name == name && p.default_value.is_none())
+        {
+            prop.default_value = Some(value);
+        }
+    }
+
     /// Add an emit definition
     #[inline]
     pub fn add_emit(&mut self, emit: EmitDefinition) {
@@ -1,13 +1,15 @@
 //! Extraction functions for props, emits, and reactivity detection.
 
 use oxc_ast::ast::{
-    Argument, CallExpression, Declaration, Expression, ObjectPropertyKind, PropertyKey, TSType,
-    VariableDeclarationKind,
+    Argument, CallExpression, Declaration, Expression, ObjectPropertyKind, PropertyKey, Statement,
+    TSLiteral, TSSignature, TSTupleElement, TSType, TSTypeName, VariableDeclarationKind,
 };
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::analysis::{InvalidExport, InvalidExportKind, TypeExport, TypeExportKind};
-use crate::macros::{EmitDefinition, MacroKind, ModelDefinition, PropDefinition};
+use crate::macros::{
+    EmitDefinition, ExposeDefinition, MacroKind, ModelDefinition, PropDefinition, SlotsDefinition,
+};
 use crate::provide::ProvideKey;
 use crate::reactivity::ReactiveKind;
 use crate::setup_context::SetupContextViolationKind;
@@ -132,6 +134,41 @@ pub fn process_call_expression(
             if let Some(Argument::CallExpression(inner_call)) = call.arguments.first() {
                 process_call_expression(result, inner_call, source);
             }
+            // Defaults are applied once referenced prop types are resolved
+            if let Some(Argument::ObjectExpression(defaults)) = call.arguments.get(1) {
+                for prop in defaults.properties.iter() {
+                    if let ObjectPropertyKind::ObjectProperty(p) = prop {
+                        if let Some(name) = static_property_key(&p.key) {
+                            result.prop_defaults.push((
+                                CompactString::new(name),
+                                CompactString::new(span_text(source, p.value.span())),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        MacroKind::DefineSlots => {
+            if let Some(ref type_params) = call.type_parameters {
+                extract_slots_from_type(result, &type_params.params, source);
+            }
+        }
+
+        MacroKind::DefineExpose => {
+            if let Some(Argument::ObjectExpression(obj)) = call.arguments.first() {
+                for prop in obj.properties.iter() {
+                    if let ObjectPropertyKind::ObjectProperty(p) = prop {
+                        if let Some(name) = static_property_key(&p.key) {
+                            result.macros.add_expose(ExposeDefinition {
+                                name: CompactString::new(name),
+                                expose_type: None,
+                                description: leading_jsdoc(source, p.span.start),
+                            });
+                        }
+                    }
+                }
+            }
         }
 
         _ => {}
@@ -144,25 +181,113 @@ pub fn process_call_expression(
 pub fn extract_props_from_type(
     result: &mut ScriptParseResult,
     type_params: &oxc_allocator::Vec<'_, TSType<'_>>,
-    _source: &str,
+    source: &str,
 ) {
     for tp in type_params.iter() {
-        if let TSType::TSTypeLiteral(lit) = tp {
-            for member in lit.members.iter() {
-                if let oxc_ast::ast::TSSignature::TSPropertySignature(prop) = member {
-                    if let PropertyKey::StaticIdentifier(id) = &prop.key {
-                        let name = id.name.as_str();
-                        result.macros.add_prop(PropDefinition {
-                            name: CompactString::new(name),
-                            required: !prop.optional,
-                            prop_type: None,
-                            default_value: None,
-                        });
-                        result.bindings.add(name, BindingType::Props);
+        extract_props_from_ts_type(result, tp, source);
+    }
+}
+
+/// Extract props from a props type, deferring references to local types
+fn extract_props_from_ts_type(result: &mut ScriptParseResult, ty: &TSType<'_>, source: &str) {
+    match ty {
+        TSType::TSTypeLiteral(lit) => extract_props_from_signatures(result, &lit.members, source),
+        TSType::TSIntersectionType(intersection) => {
+            for ty in intersection.types.iter() {
+                extract_props_from_ts_type(result, ty, source);
+            }
+        }
+        TSType::TSParenthesizedType(paren) => {
+            extract_props_from_ts_type(result, &paren.type_annotation, source)
+        }
+        TSType::TSTypeReference(reference) => {
+            if let TSTypeName::IdentifierReference(id) = &reference.type_name {
+                result
+                    .prop_type_refs
+                    .push(CompactString::new(id.name.as_str()));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extract props from property signatures of a type literal or interface
+fn extract_props_from_signatures(
+    result: &mut ScriptParseResult,
+    members: &oxc_allocator::Vec<'_, TSSignature<'_>>,
+    source: &str,
+) {
+    for member in members.iter() {
+        if let TSSignature::TSPropertySignature(prop) = member {
+            if let Some(name) = static_property_key(&prop.key) {
+                let prop_type = prop
+                    .type_annotation
+                    .as_ref()
+                    .map(|ann| CompactString::new(span_text(source, ann.type_annotation.span())));
+                result.macros.add_prop(PropDefinition {
+                    name: CompactString::new(name),
+                    required: !prop.optional,
+                    prop_type,
+                    default_value: None,
+                    description: leading_jsdoc(source, prop.span.start),
+                });
+                result.bindings.add(name, BindingType::Props);
+            }
+        }
+    }
+}
+
+/// Resolve deferred props type references and apply `withDefaults` /
+/// destructure defaults. Runs after all statements are processed, since
+/// interfaces may be declared after `defineProps`.
+pub fn resolve_props(result: &mut ScriptParseResult, body: &[Statement<'_>], source: &str) {
+    let mut resolved: Vec<CompactString> = Vec::new();
+    while let Some(name) = result.prop_type_refs.pop() {
+        if resolved.contains(&name) {
+            continue;
+        }
+        for stmt in body {
+            let decl = match stmt {
+                Statement::TSInterfaceDeclaration(decl) => Some(Ok(&**decl)),
+                Statement::TSTypeAliasDeclaration(decl) => Some(Err(&**decl)),
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::TSInterfaceDeclaration(decl)) => Some(Ok(&**decl)),
+                    Some(Declaration::TSTypeAliasDeclaration(decl)) => Some(Err(&**decl)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match decl {
+                Some(Ok(interface)) if interface.id.name == name.as_str() => {
+                    extract_props_from_signatures(result, &interface.body.body, source);
+                    for heritage in interface.extends.iter().flatten() {
+                        if let Expression::Identifier(id) = &heritage.expression {
+                            result
+                                .prop_type_refs
+                                .push(CompactString::new(id.name.as_str()));
+                        }
                     }
                 }
+                Some(Err(alias)) if alias.id.name == name.as_str() => {
+                    extract_props_from_ts_type(result, &alias.type_annotation, source);
+                }
+                _ => {}
             }
         }
+        resolved.push(name);
+    }
+
+    let mut defaults = std::mem::take(&mut result.prop_defaults);
+    if let Some(destructure) = result.macros.props_destructure() {
+        defaults.extend(
+            destructure
+                .bindings
+                .iter()
+                .filter_map(|(key, binding)| Some((key.clone(), binding.default.clone()?))),
+        );
+    }
+    for (name, value) in defaults {
+        result.macros.set_prop_default(&name, value);
     }
 }
 
@@ -170,7 +295,7 @@ pub fn extract_props_from_type(
 pub fn extract_props_from_runtime(
     result: &mut ScriptParseResult,
     arg: &Argument<'_>,
-    _source: &str,
+    source: &str,
 ) {
     match arg {
         // Array syntax: ['prop1', 'prop2']
@@ -183,6 +308,7 @@ pub fn extract_props_from_runtime(
                         required: false,
                         prop_type: None,
                         default_value: None,
+                        description: leading_jsdoc(source, s.span.start),
                     });
                     result.bindings.add(name, BindingType::Props);
                 }
@@ -196,11 +322,22 @@ pub fn extract_props_from_runtime(
                     if let PropertyKey::StaticIdentifier(id) = &p.key {
                         let name = id.name.as_str();
                         let required = detect_required_prop(&p.value);
+                        let (prop_type, default_value) = match &p.value {
+                            Expression::ObjectExpression(options) => (
+                                find_option(options, "type")
+                                    .and_then(|ty| runtime_type_to_ts(ty, source)),
+                                find_option(options, "default").map(|value| {
+                                    CompactString::new(span_text(source, value.span()))
+                                }),
+                            ),
+                            ty => (runtime_type_to_ts(ty, source), None),
+                        };
                         result.macros.add_prop(PropDefinition {
                             name: CompactString::new(name),
                             required,
-                            prop_type: None,
-                            default_value: None,
+                            prop_type,
+                            default_value,
+                            description: leading_jsdoc(source, p.span.start),
                         });
                         result.bindings.add(name, BindingType::Props);
                     }
@@ -212,6 +349,70 @@ pub fn extract_props_from_runtime(
     }
 }
 
+/// Find a property value in a runtime prop options object
+fn find_option<'e, 'a>(
+    options: &'e oxc_ast::ast::ObjectExpression<'a>,
+    key: &str,
+) -> Option<&'e Expression<'a>> {
+    options.properties.iter().find_map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(p) if static_property_key(&p.key) == Some(key) => {
+            Some(&p.value)
+        }
+        _ => None,
+    })
+}
+
+/// Convert a runtime prop type (`String`, `[String, Number]`,
+/// `Object as PropType<T>`) to a TypeScript type
+fn runtime_type_to_ts(ty: &Expression<'_>, source: &str) -> Option<CompactString> {
+    match ty {
+        Expression::Identifier(id) => Some(CompactString::new(match id.name.as_str() {
+            "String" => "string",
+            "Number" => "number",
+            "Boolean" => "boolean",
+            "Array" => "unknown[]",
+            "Object" => "Record<string, unknown>",
+            "Function" => "(...args: any[]) => any",
+            "Symbol" => "symbol",
+            "BigInt" => "bigint",
+            other => other,
+        })),
+        Expression::ArrayExpression(arr) => {
+            let types: Vec<CompactString> = arr
+                .elements
+                .iter()
+                .filter_map(|elem| runtime_type_to_ts(elem.as_expression()?, source))
+                .collect();
+            (!types.is_empty()).then(|| CompactString::new(types.join(" | ")))
+        }
+        Expression::TSAsExpression(ts_as) => match &ts_as.type_annotation {
+            TSType::TSTypeReference(reference) => reference
+                .type_parameters
+                .as_ref()
+                .and_then(|args| args.params.first())
+                .map(|param| CompactString::new(span_text(source, param.span()))),
+            other => Some(CompactString::new(span_text(source, other.span()))),
+        },
+        _ => None,
+    }
+}
+
+/// Name of an identifier or string literal property key
+fn static_property_key<'k>(key: &'k PropertyKey<'_>) -> Option<&'k str> {
+    match key {
+        PropertyKey::StaticIdentifier(id) => Some(id.name.as_str()),
+        PropertyKey::StringLiteral(s) => Some(s.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Source text of a span
+fn span_text(source: &str, span: Span) -> &str {
+    source
+        .get(span.start as usize..span.end as usize)
+        .unwrap_or("")
+}
+
 /// Detect if a prop has required: true
 fn detect_required_prop(value: &Expression<'_>) -> bool {
     if let Expression::ObjectExpression(obj) = value {
@@ -231,54 +432,186 @@ fn detect_required_prop(value: &Expression<'_>) -> bool {
 }
 
 /// Extract emits from TypeScript type parameters
+///
+/// Supports call signatures (`(e: 'update', value: string): void`) and the
+/// named tuple syntax (`update: [value: string]`). The payload type is the
+/// type of the first payload argument.
 pub fn extract_emits_from_type(
     result: &mut ScriptParseResult,
     type_params: &oxc_allocator::Vec<'_, TSType<'_>>,
-    _source: &str,
+    source: &str,
 ) {
     for tp in type_params.iter() {
-        if let TSType::TSTypeLiteral(lit) = tp {
-            // Handle call signatures like { (e: 'update', value: string): void }
-            for member in lit.members.iter() {
-                if let oxc_ast::ast::TSSignature::TSCallSignatureDeclaration(call_sig) = member {
-                    // First parameter is usually the event name: (e: 'eventName', ...)
-                    if let Some(first_param) = call_sig.params.items.first() {
-                        if let Some(type_ann) = &first_param.pattern.type_annotation {
-                            if let TSType::TSLiteralType(lit_type) = &type_ann.type_annotation {
-                                if let oxc_ast::ast::TSLiteral::StringLiteral(s) = &lit_type.literal
-                                {
-                                    result.macros.add_emit(EmitDefinition {
-                                        name: CompactString::new(s.value.as_str()),
-                                        payload_type: None,
-                                    });
-                                }
-                            }
-                        }
+        let TSType::TSTypeLiteral(lit) = tp else {
+            continue;
+        };
+        for member in lit.members.iter() {
+            match member {
+                TSSignature::TSCallSignatureDeclaration(call_sig) => {
+                    // First parameter is the event name: (e: 'eventName', ...)
+                    let mut params = call_sig.params.items.iter();
+                    let Some(TSType::TSLiteralType(lit_type)) = params
+                        .next()
+                        .and_then(|p| p.pattern.type_annotation.as_ref())
+                        .map(|ann| &ann.type_annotation)
+                    else {
+                        continue;
+                    };
+                    if let TSLiteral::StringLiteral(s) = &lit_type.literal {
+                        let payload_type = params
+                            .next()
+                            .and_then(|p| p.pattern.type_annotation.as_ref())
+                            .map(|ann| {
+                                CompactString::new(span_text(source, ann.type_annotation.span()))
+                            });
+                        result.macros.add_emit(EmitDefinition {
+                            name: CompactString::new(s.value.as_str()),
+                            payload_type,
+                            description: leading_jsdoc(source, call_sig.span.start),
+                        });
                     }
                 }
+                TSSignature::TSPropertySignature(prop) => {
+                    let Some(name) = static_property_key(&prop.key) else {
+                        continue;
+                    };
+                    let payload_type =
+                        prop.type_annotation
+                            .as_ref()
+                            .and_then(|ann| match &ann.type_annotation {
+                                TSType::TSTupleType(tuple) => {
+                                    tuple.element_types.first().map(|elem| {
+                                        let span = match elem {
+                                            TSTupleElement::TSNamedTupleMember(member) => {
+                                                member.element_type.span()
+                                            }
+                                            other => other.span(),
+                                        };
+                                        CompactString::new(span_text(source, span))
+                                    })
+                                }
+                                _ => None,
+                            });
+                    result.macros.add_emit(EmitDefinition {
+                        name: CompactString::new(name),
+                        payload_type,
+                        description: leading_jsdoc(source, prop.span.start),
+                    });
+                }
+                _ => {}
             }
         }
     }
 }
 
-/// Extract emits from runtime arguments (array)
+/// Extract emits from runtime arguments (array or validator object)
 pub fn extract_emits_from_runtime(
     result: &mut ScriptParseResult,
     arg: &Argument<'_>,
-    _source: &str,
+    source: &str,
+) {
+    match arg {
+        Argument::ArrayExpression(arr) => {
+            for elem in arr.elements.iter() {
+                if let oxc_ast::ast::ArrayExpressionElement::StringLiteral(s) = elem {
+                    result.macros.add_emit(EmitDefinition {
+                        name: CompactString::new(s.value.as_str()),
+                        payload_type: None,
+                        description: leading_jsdoc(source, s.span.start),
+                    });
+                }
+            }
+        }
+        Argument::ObjectExpression(obj) => {
+            for prop in obj.properties.iter() {
+                if let ObjectPropertyKind::ObjectProperty(p) = prop {
+                    if let Some(name) = static_property_key(&p.key) {
+                        result.macros.add_emit(EmitDefinition {
+                            name: CompactString::new(name),
+                            payload_type: None,
+                            description: leading_jsdoc(source, p.span.start),
+                        });
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extract slots from `defineSlots<{ default(props: { item: T }): any }>()`
+fn extract_slots_from_type(
+    result: &mut ScriptParseResult,
+    type_params: &oxc_allocator::Vec<'_, TSType<'_>>,
+    source: &str,
 ) {
-    if let Argument::ArrayExpression(arr) = arg {
-        for elem in arr.elements.iter() {
-            if let oxc_ast::ast::ArrayExpressionElement::StringLiteral(s) = elem {
-                result.macros.add_emit(EmitDefinition {
-                    name: CompactString::new(s.value.as_str()),
-                    payload_type: None,
+    let first_param_type = |params: &oxc_ast::ast::FormalParameters<'_>| {
+        params
+            .items
+            .first()
+            .and_then(|p| p.pattern.type_annotation.as_ref())
+            .map(|ann| CompactString::new(span_text(source, ann.type_annotation.span())))
+    };
+
+    for tp in type_params.iter() {
+        let TSType::TSTypeLiteral(lit) = tp else {
+            continue;
+        };
+        for member in lit.members.iter() {
+            let (key, props_type, start) = match member {
+                TSSignature::TSMethodSignature(method) => (
+                    &method.key,
+                    first_param_type(&method.params),
+                    method.span.start,
+                ),
+                TSSignature::TSPropertySignature(prop) => {
+                    let props_type =
+                        prop.type_annotation
+                            .as_ref()
+                            .and_then(|ann| match &ann.type_annotation {
+                                TSType::TSFunctionType(func) => first_param_type(&func.params),
+                                _ => None,
+                            });
+                    (&prop.key, props_type, prop.span.start)
+                }
+                _ => continue,
+            };
+            if let Some(name) = static_property_key(key) {
+                result.macros.add_slot(SlotsDefinition {
+                    name: CompactString::new(name),
+                    props_type,
+                    description: leading_jsdoc(source, start),
                 });
             }
         }
     }
 }
 
+/// Text of the JSDoc block (`/** ... */`) directly preceding `start`.
+///
+/// Leading `*` gutters are stripped and block tags (`@default`, ...) dropped.
+fn leading_jsdoc(source: &str, start: u32) -> Option<CompactString> {
+    let before = source.get(..start as usize)?.trim_end();
+    let body = before.strip_suffix("*/")?;
+    let open = body.rfind("/**")?;
+    let body = &body[open + 3..];
+    if body.contains("*/") {
+        return None;
+    }
+
+    let lines: Vec<&str> = body
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').map(str::trim_start).unwrap_or(line)
+        })
+        .take_while(|line| !line.starts_with('@'))
+        .collect();
+    let text = lines.join("\n");
+    let text = text.trim();
+    (!text.is_empty()).then(|| CompactString::new(text))
+}
+
 /// Detect reactivity wrappers (ref, computed, reactive, etc.)
 /// Also handles aliases (e.g., const r = ref; const count = r(0))
 pub fn detect_reactivity_call(
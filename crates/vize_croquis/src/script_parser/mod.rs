@@ -24,6 +24,7 @@ use oxc_span::SourceType;
 
 use crate::analysis::BindingMetadata;
 use crate::analysis::{InvalidExport, TypeExport};
+use crate::hydration::HydrationTracker;
 use crate::macros::MacroTracker;
 use crate::provide::ProvideInjectTracker;
 use crate::reactivity::ReactivityTracker;
@@ -59,10 +60,16 @@ pub struct ScriptParseResult {
     pub(crate) reactivity_aliases: FxHashMap<CompactString, CompactString>,
     /// Setup context violation tracking
     pub setup_context: SetupContextTracker,
+    /// SSR hydration hazard tracking
+    pub hydration: HydrationTracker,
     /// Flag to track if we're in a non-setup script context
     pub(crate) is_non_setup_script: bool,
     /// Definition spans for bindings (name -> (start, end) offset in script)
     pub binding_spans: FxHashMap<CompactString, (u32, u32)>,
+    /// Local types referenced by `defineProps<...>()`, resolved after the walk
+    pub(crate) prop_type_refs: Vec<CompactString>,
+    /// `withDefaults` defaults (prop name -> expression source), applied after the walk
+    pub(crate) prop_defaults: Vec<(CompactString, CompactString)>,
 }
 
 /// Setup global scopes hierarchy:
@@ -227,6 +234,8 @@ pub fn parse_script_setup(source: &str) -> ScriptParseResult {
         process::process_statement(&mut result, stmt, source);
     }
 
+    extract::resolve_props(&mut result, &ret.program.body, source);
+
     result
 }
 
@@ -310,6 +319,165 @@ mod tests {
         assert_eq!(result.macros.props().len(), 2);
     }
 
+    #[test]
+    fn test_parse_define_props_type_reference() {
+        let result = parse_script_setup(
+            r#"
+            interface Base { id: string }
+            export interface Props extends Base {
+                size?: 'sm' | 'lg'
+                items?: string[]
+            }
+            const props = withDefaults(defineProps<Props>(), {
+                size: 'sm',
+                items: () => [],
+            })
+        "#,
+        );
+
+        let props = result.macros.props();
+        assert_eq!(props.len(), 3);
+
+        let size = props.iter().find(|p| p.name == "size").unwrap();
+        assert_eq!(size.prop_type.as_deref(), Some("'sm' | 'lg'"));
+        assert_eq!(size.default_value.as_deref(), Some("'sm'"));
+        assert!(!size.required);
+
+        let id = props.iter().find(|p| p.name == "id").unwrap();
+        assert!(id.required);
+
+        let items = props.iter().find(|p| p.name == "items").unwrap();
+        assert_eq!(items.default_value.as_deref(), Some("() => []"));
+    }
+
+    #[test]
+    fn test_parse_define_props_intersection_declared_later() {
+        use vize_relief::BindingType;
+
+        // Props types may be declared after `defineProps`
+        let result = parse_script_setup(
+            r#"
+            const props = defineProps<BaseProps & ExtendedProps & { label?: string }>()
+            interface BaseProps { id: string; name: string }
+            type ExtendedProps = { extra: boolean }
+        "#,
+        );
+
+        let names: Vec<_> = result
+            .macros
+            .props()
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, ["label", "extra", "id", "name"]);
+        for name in names {
+            assert_eq!(result.bindings.get(name), Some(BindingType::Props));
+        }
+    }
+
+    #[test]
+    fn test_parse_define_props_defaults() {
+        let result = parse_script_setup(
+            r#"
+            defineProps({
+                title: String,
+                items: { type: Array, default: () => [] },
+            })
+        "#,
+        );
+        let props = result.macros.props();
+        assert_eq!(props[0].default_value, None);
+        assert_eq!(props[1].default_value.as_deref(), Some("() => []"));
+
+        let result = parse_script_setup(
+            r#"
+            const { size = 'md', label } = defineProps<{ size?: string; label: string }>()
+        "#,
+        );
+        let props = result.macros.props();
+        assert_eq!(props[0].default_value.as_deref(), Some("'md'"));
+        assert_eq!(props[1].default_value, None);
+    }
+
+    #[test]
+    fn test_parse_define_props_runtime_object() {
+        let result = parse_script_setup(
+            r#"
+            defineProps({
+                kind: { type: String as PropType<'a' | 'b'>, default: 'a' },
+                count: [Number, String],
+            })
+        "#,
+        );
+
+        let props = result.macros.props();
+        let kind = props.iter().find(|p| p.name == "kind").unwrap();
+        assert_eq!(kind.prop_type.as_deref(), Some("'a' | 'b'"));
+        assert_eq!(kind.default_value.as_deref(), Some("'a'"));
+
+        let count = props.iter().find(|p| p.name == "count").unwrap();
+        assert_eq!(count.prop_type.as_deref(), Some("number | string"));
+    }
+
+    #[test]
+    fn test_parse_component_api_docs() {
+        let result = parse_script_setup(
+            r#"
+            const props = defineProps<{
+                /**
+                 * Visible label
+                 * @default 'OK'
+                 */
+                label?: string
+                count: number
+            }>()
+            const emit = defineEmits<{
+                /** Fired on click */
+                (e: 'click', event: MouseEvent): void
+                /** Value changed */
+                change: [value: number, old: number]
+            }>()
+            defineSlots<{
+                /** Row content */
+                default(props: { item: string; index: number }): any
+                footer?: () => any
+            }>()
+            defineExpose({
+                /** Focus the input */
+                focus,
+                reset: () => {},
+            })
+        "#,
+        );
+
+        let props = result.macros.props();
+        assert_eq!(props[0].description.as_deref(), Some("Visible label"));
+        assert_eq!(props[1].description, None);
+
+        let emits = result.macros.emits();
+        assert_eq!(emits[0].name, "click");
+        assert_eq!(emits[0].payload_type.as_deref(), Some("MouseEvent"));
+        assert_eq!(emits[0].description.as_deref(), Some("Fired on click"));
+        assert_eq!(emits[1].name, "change");
+        assert_eq!(emits[1].payload_type.as_deref(), Some("number"));
+
+        let slots = result.macros.slots();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(
+            slots[0].props_type.as_deref(),
+            Some("{ item: string; index: number }")
+        );
+        assert_eq!(slots[0].description.as_deref(), Some("Row content"));
+        assert_eq!(slots[1].name, "footer");
+        assert_eq!(slots[1].props_type, None);
+
+        let exposes = result.macros.exposes();
+        assert_eq!(exposes.len(), 2);
+        assert_eq!(exposes[0].name, "focus");
+        assert_eq!(exposes[0].description.as_deref(), Some("Focus the input"));
+        assert_eq!(exposes[1].name, "reset");
+    }
+
     #[test]
     fn test_parse_define_emits() {
         let result = parse_script_setup(
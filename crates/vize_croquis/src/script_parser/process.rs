@@ -13,6 +13,7 @@ use oxc_ast::ast::{
 use oxc_span::GetSpan;
 
 use crate::analysis::{InvalidExport, InvalidExportKind, TypeExport, TypeExportKind};
+use crate::hydration::NondeterministicKind;
 use crate::macros::PropsDestructuredBindings;
 use crate::provide::InjectPattern;
 use crate::scope::{BlockKind, BlockScopeData, ClosureScopeData, ExternalModuleScopeData};
@@ -258,6 +259,20 @@ fn process_variable_declarator(
                 .binding_spans
                 .insert(CompactString::new(name), (id.span.start, id.span.end));
 
+            // Track values that differ between server render and hydration
+            if let Some(init) = declarator.init.as_ref() {
+                if let Some((kind, pattern)) = NondeterministicKind::classify_expression(init) {
+                    let span = init.span();
+                    result.hydration.record_source(
+                        CompactString::new(name),
+                        kind,
+                        pattern,
+                        span.start,
+                        span.end,
+                    );
+                }
+            }
+
             // Check if the init is a macro or reactivity call
             // Use extract_call_expression to handle type assertions (as/satisfies)
             let call_extracted = if let Some(call) =
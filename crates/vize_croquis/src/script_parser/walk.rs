@@ -12,7 +12,7 @@ use oxc_ast::ast::{
 };
 use oxc_span::GetSpan;
 
-use crate::scope::{BlockKind, BlockScopeData, ClientOnlyScopeData, ClosureScopeData};
+use crate::scope::{BlockKind, BlockScopeData, ClientOnlyScopeData, ClosureScopeData, ScopeKind};
 use crate::ScopeBinding;
 use vize_carton::CompactString;
 use vize_relief::BindingType;
@@ -265,6 +265,25 @@ pub(super) fn walk_call_arguments(
     // Check for provide/inject calls
     detect_provide_inject_call(result, call, source);
 
+    // useId() relies on call order during setup; nested calls break it
+    if matches!(&call.callee, Expression::Identifier(id) if id.name == "useId")
+        && !result.is_non_setup_script
+    {
+        let context = match result.scopes.current_scope().kind {
+            ScopeKind::Block => Some("a conditional or loop"),
+            ScopeKind::Closure
+            | ScopeKind::Function
+            | ScopeKind::Callback
+            | ScopeKind::ClientOnly => Some("a callback"),
+            _ => None,
+        };
+        if let Some(context) = context {
+            result
+                .hydration
+                .record_use_id_misuse(context, call.span.start, call.span.end);
+        }
+    }
+
     // Check if this is a client-only lifecycle hook
     let is_lifecycle_hook = if let Expression::Identifier(id) = &call.callee {
         is_client_only_hook(id.name.as_str())
@@ -4,12 +4,14 @@ use compact_str::CompactString;
 
 use crate::layout::FlexStyle;
 use crate::render::{Appearance, NodeKind, RenderNode, TextContent};
-use crate::terminal::Color;
+use crate::terminal::{Color, Style};
+use crate::text::{parse_ansi, StyledSpan};
 
 /// Builder for Text nodes.
 #[derive(Debug, Clone, Default)]
 pub struct TextNode {
     text: CompactString,
+    spans: Vec<StyledSpan>,
     wrap: bool,
     style: FlexStyle,
     appearance: Appearance,
@@ -24,6 +26,34 @@ impl TextNode {
         }
     }
 
+    /// Create a text node builder from styled spans.
+    pub fn spans(spans: impl IntoIterator<Item = StyledSpan>) -> Self {
+        Self::default().append_spans(spans)
+    }
+
+    /// Create a text node builder from text containing ANSI escape sequences.
+    pub fn ansi(text: &str) -> Self {
+        Self::spans(parse_ansi(text, Style::new()))
+    }
+
+    /// Append a styled span.
+    pub fn span(mut self, text: impl Into<CompactString>, style: Style) -> Self {
+        if self.spans.is_empty() && !self.text.is_empty() {
+            self.spans.push(StyledSpan::plain(self.text.clone()));
+        }
+        let span = StyledSpan::new(text, style);
+        self.text.push_str(&span.text);
+        self.spans.push(span);
+        self
+    }
+
+    /// Append multiple styled spans.
+    fn append_spans(self, spans: impl IntoIterator<Item = StyledSpan>) -> Self {
+        spans
+            .into_iter()
+            .fold(self, |node, span| node.span(span.text, span.style))
+    }
+
     /// Enable text wrapping.
     pub fn wrap(mut self) -> Self {
         self.wrap = true;
@@ -82,6 +112,7 @@ impl TextNode {
     pub fn build(self, id: u64) -> RenderNode {
         let content = TextContent {
             text: self.text,
+            spans: self.spans,
             wrap: self.wrap,
         };
         RenderNode::new(id, NodeKind::Text(content))
@@ -142,6 +173,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_text_node_spans() {
+        let node = TextNode::new("Status: ")
+            .span("ok", Style::new().fg(Color::Green))
+            .build(1);
+        if let NodeKind::Text(content) = &node.kind {
+            assert_eq!(content.text.as_str(), "Status: ok");
+            assert_eq!(content.spans.len(), 2);
+            assert_eq!(content.spans[1].style.fg, Some(Color::Green));
+        } else {
+            panic!("Expected Text node");
+        }
+    }
+
     #[test]
     fn test_text_node_styles() {
         let builder = TextNode::new("Test").fg(Color::Red).bold().underline();
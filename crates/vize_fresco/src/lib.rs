@@ -60,9 +60,9 @@ pub mod napi;
 pub use component::{BoxNode, InputNode, TextNode};
 pub use input::{Event, ImeState, KeyEvent, MouseEvent};
 pub use layout::{FlexStyle, LayoutEngine, Rect};
-pub use render::{RenderNode, RenderTree};
+pub use render::{FocusManager, RenderNode, RenderTree};
 pub use terminal::{Backend, Buffer, Cell, Cursor};
-pub use text::{TextSegment, TextWidth, TextWrap};
+pub use text::{StyledSpan, TextSegment, TextWidth, TextWrap};
 
 /// Fresco version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,96 @@
+//! Focus NAPI bindings.
+//!
+//! Focus state persists across `renderTree` calls: each render refreshes the
+//! tab order, and the functions here move focus and report focus/blur events
+//! for the renderer to dispatch to its nodes.
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use std::sync::Mutex;
+
+use super::types::{FocusEventNapi, InputEventNapi};
+use crate::render::{FocusEvent, FocusManager};
+
+/// Focus state shared between renders.
+#[derive(Default)]
+pub(super) struct FocusState {
+    /// Focus manager carried across rebuilt render trees
+    pub manager: FocusManager,
+    /// Events produced during rendering, not yet taken by JS
+    pub pending: Vec<FocusEvent>,
+}
+
+// Global focus state (lazy initialized)
+static FOCUS: Mutex<Option<FocusState>> = Mutex::new(None);
+
+/// Execute a function with the focus state.
+pub(super) fn with_focus<F, R>(f: F) -> Result<R>
+where
+    F: FnOnce(&mut FocusState) -> R,
+{
+    let mut guard = FOCUS
+        .lock()
+        .map_err(|e| Error::new(Status::GenericFailure, format!("Lock error: {}", e)))?;
+
+    Ok(f(guard.get_or_insert_with(FocusState::default)))
+}
+
+fn to_napi(events: Vec<FocusEvent>) -> Vec<FocusEventNapi> {
+    events.into_iter().map(FocusEventNapi::from).collect()
+}
+
+/// Focus a node by ID.
+#[napi(js_name = "focusNode")]
+pub fn focus_node(id: i64) -> Result<Vec<FocusEventNapi>> {
+    with_focus(|state| to_napi(state.manager.focus(id as u64)))
+}
+
+/// Remove focus from the focused node.
+#[napi(js_name = "blurFocus")]
+pub fn blur_focus() -> Result<Vec<FocusEventNapi>> {
+    with_focus(|state| to_napi(state.manager.blur()))
+}
+
+/// Move focus to the next node in tab order.
+#[napi(js_name = "focusNext")]
+pub fn focus_next() -> Result<Vec<FocusEventNapi>> {
+    with_focus(|state| to_napi(state.manager.focus_next()))
+}
+
+/// Move focus to the previous node in tab order.
+#[napi(js_name = "focusPrev")]
+pub fn focus_prev() -> Result<Vec<FocusEventNapi>> {
+    with_focus(|state| to_napi(state.manager.focus_prev()))
+}
+
+/// Get the focused node ID.
+#[napi(js_name = "getFocusedNode")]
+pub fn get_focused_node() -> Result<Option<i64>> {
+    with_focus(|state| state.manager.focused().map(|id| id as i64))
+}
+
+/// Handle Tab / Shift-Tab navigation for an input event.
+///
+/// Returns null if the event is not a navigation key or nothing can be
+/// focused with Tab, so it can be forwarded to the app.
+#[napi(js_name = "handleFocusKey")]
+pub fn handle_focus_key(event: InputEventNapi) -> Result<Option<Vec<FocusEventNapi>>> {
+    if event.event_type != "key" {
+        return Ok(None);
+    }
+
+    let shift = event.modifiers.as_ref().is_some_and(|m| m.shift);
+    with_focus(|state| match event.key.as_deref() {
+        _ if state.manager.tab_order().is_empty() => None,
+        Some("tab") if shift => Some(to_napi(state.manager.focus_prev())),
+        Some("tab") => Some(to_napi(state.manager.focus_next())),
+        Some("backtab") => Some(to_napi(state.manager.focus_prev())),
+        _ => None,
+    })
+}
+
+/// Take focus events produced by `renderTree` (e.g. blur of a removed node).
+#[napi(js_name = "takeFocusEvents")]
+pub fn take_focus_events() -> Result<Vec<FocusEventNapi>> {
+    with_focus(|state| to_napi(std::mem::take(&mut state.pending)))
+}
@@ -2,12 +2,14 @@
 //!
 //! Provides JavaScript/Node.js bindings for the Fresco terminal UI framework.
 
+mod focus;
 mod input;
 mod layout;
 mod render;
 mod terminal;
 mod types;
 
+pub use focus::*;
 pub use input::*;
 pub use layout::*;
 pub use render::*;
@@ -3,6 +3,7 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
+use super::focus::with_focus;
 use super::terminal::with_backend;
 use super::types::{RenderNodeNapi, StyleNapi};
 use crate::layout::Rect;
@@ -132,23 +133,40 @@ pub fn render_tree(nodes: Vec<RenderNodeNapi>) -> Result<()> {
         Appearance, BorderStyle, InputContent, NodeKind, Painter, RenderNode, RenderTree,
         TextContent,
     };
+    use crate::text::StyledSpan;
 
     with_backend(|backend| {
         let mut tree = RenderTree::new();
 
         // Build tree from NAPI nodes
         for node in &nodes {
-            let text_content = node.text.clone().unwrap_or_default();
+            let text = node.text.as_deref().unwrap_or_default();
+            let mut content = if let Some(ref spans) = node.spans {
+                TextContent::from_spans(
+                    spans
+                        .iter()
+                        .map(|span| {
+                            let style = span.style.clone().map(convert_style).unwrap_or_default();
+                            StyledSpan::new(span.text.as_str(), style)
+                        })
+                        .collect(),
+                )
+            } else if node.ansi.unwrap_or(false) {
+                TextContent::from_ansi(text)
+            } else {
+                TextContent::new(text)
+            };
+            content.wrap = node.wrap.unwrap_or(false);
+            let text_content = content.text.to_string();
+
             let kind = match node.node_type.as_str() {
-                "text" => NodeKind::Text(TextContent {
-                    text: text_content.clone().into(),
-                    wrap: node.wrap.unwrap_or(false),
-                }),
+                "text" => NodeKind::Text(content),
                 "input" => NodeKind::Input(InputContent {
                     value: node.value.clone().unwrap_or_default().into(),
                     placeholder: node.placeholder.clone().unwrap_or_default().into(),
                     cursor: node.cursor.unwrap_or(0) as usize,
-                    focused: node.focused.unwrap_or(false),
+                    // Set by the focus manager below
+                    focused: false,
                     mask: node.mask.unwrap_or(false),
                     mask_char: '*',
                 }),
@@ -156,6 +174,7 @@ pub fn render_tree(nodes: Vec<RenderNodeNapi>) -> Result<()> {
             };
 
             let mut render_node = RenderNode::new(node.id as u64, kind);
+            render_node.tab_index = node.tab_index;
 
             // Force all nodes to align to start (workaround for taffy centering)
             render_node.style.align_self = AlignSelf::FlexStart;
@@ -399,6 +418,19 @@ pub fn render_tree(nodes: Vec<RenderNodeNapi>) -> Result<()> {
             }
         }
 
+        // Carry focus over from the previous render; a node flagged as
+        // focused requests focus when mounted or newly flagged, otherwise
+        // Tab navigation drives it
+        let requested = nodes
+            .iter()
+            .find(|node| node.focused.unwrap_or(false))
+            .map(|node| node.id as u64);
+        with_focus(|state| {
+            tree.set_focus_manager(std::mem::take(&mut state.manager));
+            let events = tree.request_focus(requested);
+            state.pending.extend(events);
+        })?;
+
         // Compute layout
         let (width, height) = (backend.width(), backend.height());
         tree.compute_layout(width, height);
@@ -407,45 +439,21 @@ pub fn render_tree(nodes: Vec<RenderNodeNapi>) -> Result<()> {
         let mut painter = Painter::new(backend.buffer_mut());
         painter.paint_tree(&tree);
 
-        // Find focused input and position cursor for IME
-        let mut found_focused = false;
-        for node in &nodes {
-            if node.node_type == "input" && node.focused.unwrap_or(false) {
-                if let Some(render_node) = tree.get(node.id as u64) {
-                    if let Some(layout) = render_node.layout {
-                        // Calculate cursor position considering character widths and wrapping
-                        let value = node.value.as_deref().unwrap_or("");
-                        let cursor_idx = node.cursor.unwrap_or(0) as usize;
-
-                        // Get display width up to cursor position
-                        use crate::text::SegmentedText;
-                        let st = SegmentedText::new(value);
-                        let cursor_col = st.column_at_index(cursor_idx.min(st.grapheme_count));
-                        let area_width = layout.width as usize;
-
-                        // Calculate cursor line and column with text wrapping
-                        let cursor_line = cursor_col / area_width;
-                        let cursor_col_in_line = cursor_col % area_width;
-
-                        let cursor_x = layout.x + cursor_col_in_line as u16;
-                        let cursor_y =
-                            layout.y + (cursor_line as u16).min(layout.height.saturating_sub(1));
-                        backend.cursor_mut().move_to(cursor_x, cursor_y);
-                        backend
-                            .cursor_mut()
-                            .set_shape(crate::terminal::CursorShape::Bar);
-                        backend.cursor_mut().set_blinking(true);
-                        backend.cursor_mut().show();
-                        found_focused = true;
-                        break;
-                    }
-                }
+        // Position cursor at the focused input for IME
+        match tree.focused_cursor_position() {
+            Some((cursor_x, cursor_y)) => {
+                backend.cursor_mut().move_to(cursor_x, cursor_y);
+                backend
+                    .cursor_mut()
+                    .set_shape(crate::terminal::CursorShape::Bar);
+                backend.cursor_mut().set_blinking(true);
+                backend.cursor_mut().show();
             }
+            None => backend.cursor_mut().hide(),
         }
-        if !found_focused {
-            backend.cursor_mut().hide();
-        }
-    })
+
+        with_focus(|state| state.manager = tree.take_focus_manager())
+    })?
 }
 
 /// Parse dimension string to Dimension.
@@ -87,14 +87,21 @@ pub struct RenderNodeNapi {
     pub node_type: String,
     /// Text content (for text nodes)
     pub text: Option<String>,
+    /// Styled text runs (for text nodes, takes precedence over `text`)
+    pub spans: Option<Vec<TextSpanNapi>>,
+    /// Whether `text` contains ANSI escape sequences to render as styles
+    pub ansi: Option<bool>,
     /// Whether text should wrap
     pub wrap: Option<bool>,
     /// Input value (for input nodes)
     pub value: Option<String>,
     /// Placeholder text (for input nodes)
     pub placeholder: Option<String>,
-    /// Whether input is focused
+    /// Whether input is focused (requests focus from the focus manager)
     pub focused: Option<bool>,
+    /// Tab index for focus navigation (inputs default to 0)
+    #[napi(js_name = "tabIndex")]
+    pub tab_index: Option<i32>,
     /// Cursor position in input
     pub cursor: Option<i64>,
     /// Whether to mask input (password)
@@ -109,6 +116,16 @@ pub struct RenderNodeNapi {
     pub children: Option<Vec<i64>>,
 }
 
+/// Styled text span for NAPI.
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextSpanNapi {
+    /// Span text
+    pub text: String,
+    /// Span style, merged over the node appearance
+    pub style: Option<StyleNapi>,
+}
+
 /// Layout result for NAPI.
 #[napi(object)]
 #[derive(Debug, Clone, Default)]
@@ -151,6 +168,25 @@ pub struct InputEventNapi {
     pub text: Option<String>,
 }
 
+/// Focus event for NAPI.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct FocusEventNapi {
+    /// Event type: "focus" | "blur"
+    pub event_type: String,
+    /// Target node ID
+    pub node_id: i64,
+}
+
+impl From<crate::render::FocusEvent> for FocusEventNapi {
+    fn from(event: crate::render::FocusEvent) -> Self {
+        FocusEventNapi {
+            event_type: event.name().to_string(),
+            node_id: event.node_id() as i64,
+        }
+    }
+}
+
 /// Key modifiers for NAPI.
 #[napi(object)]
 #[derive(Debug, Clone, Default)]
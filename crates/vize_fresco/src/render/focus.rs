@@ -0,0 +1,335 @@
+//! Focus management and keyboard navigation.
+//!
+//! Focus follows the DOM `tabIndex` model:
+//! - `None` / negative: not reachable with Tab (negative values can still be
+//!   focused programmatically)
+//! - `0`: reachable with Tab, in tree (document) order
+//! - positive: reachable with Tab before all `0` nodes, in ascending order
+//!
+//! Input nodes are implicitly focusable with a tab index of `0`.
+
+use rustc_hash::FxHashSet;
+
+use super::node::{NodeId, NodeKind, RenderNode};
+use super::tree::RenderTree;
+use crate::input::{Key, KeyEvent};
+
+/// A focus change notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusEvent {
+    /// Node received focus
+    Focus(NodeId),
+    /// Node lost focus
+    Blur(NodeId),
+}
+
+impl FocusEvent {
+    /// Get the target node ID.
+    pub fn node_id(&self) -> NodeId {
+        match self {
+            FocusEvent::Focus(id) | FocusEvent::Blur(id) => *id,
+        }
+    }
+
+    /// Get the event name ("focus" or "blur").
+    pub fn name(&self) -> &'static str {
+        match self {
+            FocusEvent::Focus(_) => "focus",
+            FocusEvent::Blur(_) => "blur",
+        }
+    }
+}
+
+/// Tracks the focused node and the tab order of a render tree.
+#[derive(Debug, Clone, Default)]
+pub struct FocusManager {
+    /// Currently focused node
+    focused: Option<NodeId>,
+    /// Nodes reachable with Tab, in traversal order
+    order: Vec<NodeId>,
+    /// All focusable nodes (including negative tab indices)
+    focusable: FxHashSet<NodeId>,
+    /// Node named by the last focus request
+    requested: Option<NodeId>,
+}
+
+impl FocusManager {
+    /// Create a new focus manager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the focused node ID.
+    pub fn focused(&self) -> Option<NodeId> {
+        self.focused
+    }
+
+    /// Check if a node is focused.
+    pub fn is_focused(&self, id: NodeId) -> bool {
+        self.focused == Some(id)
+    }
+
+    /// Get the tab order.
+    pub fn tab_order(&self) -> &[NodeId] {
+        &self.order
+    }
+
+    /// Check if a node can receive focus.
+    pub fn is_focusable(&self, id: NodeId) -> bool {
+        self.focusable.contains(&id)
+    }
+
+    /// Recompute the tab order from a tree.
+    ///
+    /// If the focused node is gone or no longer focusable, it is blurred.
+    pub fn update(&mut self, tree: &RenderTree) -> Vec<FocusEvent> {
+        self.focusable.clear();
+
+        let mut positive: Vec<(i32, usize, NodeId)> = Vec::new();
+        let mut natural: Vec<NodeId> = Vec::new();
+
+        if let Some(root) = tree.root() {
+            for (position, node) in tree.walk_preorder(root).enumerate() {
+                let Some(tab_index) = effective_tab_index(node) else {
+                    continue;
+                };
+                self.focusable.insert(node.id);
+                if tab_index > 0 {
+                    positive.push((tab_index, position, node.id));
+                } else if tab_index == 0 {
+                    natural.push(node.id);
+                }
+            }
+        }
+
+        positive.sort_unstable();
+        self.order = positive.into_iter().map(|(_, _, id)| id).collect();
+        self.order.extend(natural);
+
+        match self.focused {
+            Some(id) if !self.focusable.contains(&id) => self.blur(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Focus a node.
+    ///
+    /// Returns the emitted events; empty if the node is not focusable or
+    /// already focused.
+    pub fn focus(&mut self, id: NodeId) -> Vec<FocusEvent> {
+        if !self.focusable.contains(&id) || self.focused == Some(id) {
+            return Vec::new();
+        }
+
+        let mut events = self.blur();
+        self.focused = Some(id);
+        events.push(FocusEvent::Focus(id));
+        events
+    }
+
+    /// Apply a declarative focus request, such as a `focused` prop.
+    ///
+    /// The request only takes effect when it first appears or changes, so a
+    /// node that keeps requesting focus does not take it back after Tab
+    /// navigation moved it elsewhere.
+    pub fn request(&mut self, id: Option<NodeId>) -> Vec<FocusEvent> {
+        if self.requested == id {
+            return Vec::new();
+        }
+        self.requested = id;
+        match id {
+            Some(id) => self.focus(id),
+            None => Vec::new(),
+        }
+    }
+
+    /// Remove focus from the focused node.
+    pub fn blur(&mut self) -> Vec<FocusEvent> {
+        match self.focused.take() {
+            Some(id) => vec![FocusEvent::Blur(id)],
+            None => Vec::new(),
+        }
+    }
+
+    /// Move focus to the next node in tab order, wrapping around.
+    pub fn focus_next(&mut self) -> Vec<FocusEvent> {
+        match self.step(1) {
+            Some(id) => self.focus(id),
+            None => Vec::new(),
+        }
+    }
+
+    /// Move focus to the previous node in tab order, wrapping around.
+    pub fn focus_prev(&mut self) -> Vec<FocusEvent> {
+        match self.step(-1) {
+            Some(id) => self.focus(id),
+            None => Vec::new(),
+        }
+    }
+
+    /// Handle Tab / Shift-Tab navigation.
+    ///
+    /// Returns `None` if the key is not a navigation key or nothing can be
+    /// focused with Tab, so callers can forward it to the app instead.
+    pub fn handle_key(&mut self, event: &KeyEvent) -> Option<Vec<FocusEvent>> {
+        if self.order.is_empty() {
+            return None;
+        }
+        match event.key {
+            Key::Tab if event.shift() => Some(self.focus_prev()),
+            Key::Tab => Some(self.focus_next()),
+            Key::BackTab => Some(self.focus_prev()),
+            _ => None,
+        }
+    }
+
+    /// Find the node `delta` steps away from the focused node in tab order.
+    fn step(&self, delta: isize) -> Option<NodeId> {
+        let len = self.order.len() as isize;
+        if len == 0 {
+            return None;
+        }
+
+        let current = self
+            .focused
+            .and_then(|id| self.order.iter().position(|&o| o == id));
+
+        let index = match current {
+            Some(pos) => (pos as isize + delta).rem_euclid(len),
+            None if delta >= 0 => 0,
+            None => len - 1,
+        };
+        Some(self.order[index as usize])
+    }
+}
+
+/// Get the tab index of a node, applying the implicit default for inputs.
+fn effective_tab_index(node: &RenderNode) -> Option<i32> {
+    match (node.tab_index, &node.kind) {
+        (Some(index), _) => Some(index),
+        (None, NodeKind::Input(_)) => Some(0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::KeyModifiers;
+    use crate::render::InputContent;
+
+    fn build_tree(tab_indices: &[Option<i32>]) -> (RenderTree, Vec<NodeId>) {
+        let mut tree = RenderTree::new();
+        let root = tree.next_id();
+        tree.insert_root(RenderNode::box_node(root));
+
+        let mut ids = Vec::new();
+        for &tab_index in tab_indices {
+            let id = tree.next_id();
+            let mut node = RenderNode::box_node(id);
+            node.tab_index = tab_index;
+            tree.insert(node);
+            tree.add_child(root, id);
+            ids.push(id);
+        }
+        (tree, ids)
+    }
+
+    #[test]
+    fn test_tab_order() {
+        let (tree, ids) = build_tree(&[Some(0), Some(2), None, Some(-1), Some(1), Some(0)]);
+        let mut focus = FocusManager::new();
+        focus.update(&tree);
+
+        assert_eq!(focus.tab_order(), &[ids[4], ids[1], ids[0], ids[5]]);
+        assert!(focus.is_focusable(ids[3]));
+        assert!(!focus.is_focusable(ids[2]));
+    }
+
+    #[test]
+    fn test_input_is_focusable_by_default() {
+        let mut tree = RenderTree::new();
+        let root = tree.next_id();
+        tree.insert_root(RenderNode::box_node(root));
+        let input = tree.next_id();
+        tree.insert(RenderNode::new(input, NodeKind::Input(InputContent::new())));
+        tree.add_child(root, input);
+
+        let mut focus = FocusManager::new();
+        focus.update(&tree);
+        assert_eq!(focus.tab_order(), &[input]);
+    }
+
+    #[test]
+    fn test_focus_next_wraps() {
+        let (tree, ids) = build_tree(&[Some(0), Some(0)]);
+        let mut focus = FocusManager::new();
+        focus.update(&tree);
+
+        assert_eq!(focus.focus_next(), vec![FocusEvent::Focus(ids[0])]);
+        assert_eq!(
+            focus.focus_next(),
+            vec![FocusEvent::Blur(ids[0]), FocusEvent::Focus(ids[1])]
+        );
+        assert_eq!(
+            focus.focus_next(),
+            vec![FocusEvent::Blur(ids[1]), FocusEvent::Focus(ids[0])]
+        );
+    }
+
+    #[test]
+    fn test_shift_tab() {
+        let (tree, ids) = build_tree(&[Some(0), Some(0), Some(0)]);
+        let mut focus = FocusManager::new();
+        focus.update(&tree);
+
+        let shift_tab = KeyEvent::new(
+            Key::Tab,
+            KeyModifiers {
+                shift: true,
+                ..KeyModifiers::NONE
+            },
+        );
+        focus.handle_key(&shift_tab);
+        assert_eq!(focus.focused(), Some(ids[2]));
+        focus.handle_key(&KeyEvent::key(Key::BackTab));
+        assert_eq!(focus.focused(), Some(ids[1]));
+        assert!(focus.handle_key(&KeyEvent::char('a')).is_none());
+    }
+
+    #[test]
+    fn test_tab_without_focusable_nodes() {
+        let (tree, _) = build_tree(&[None, Some(-1)]);
+        let mut focus = FocusManager::new();
+        focus.update(&tree);
+
+        assert!(focus.handle_key(&KeyEvent::key(Key::Tab)).is_none());
+        assert_eq!(focus.focused(), None);
+    }
+
+    #[test]
+    fn test_programmatic_focus() {
+        let (tree, ids) = build_tree(&[Some(-1), None]);
+        let mut focus = FocusManager::new();
+        focus.update(&tree);
+
+        assert_eq!(focus.focus(ids[0]), vec![FocusEvent::Focus(ids[0])]);
+        assert!(focus.focus(ids[0]).is_empty());
+        assert!(focus.focus(ids[1]).is_empty());
+        assert_eq!(focus.focused(), Some(ids[0]));
+    }
+
+    #[test]
+    fn test_blur_on_removal() {
+        let (mut tree, ids) = build_tree(&[Some(0), Some(0)]);
+        let mut focus = FocusManager::new();
+        focus.update(&tree);
+        focus.focus(ids[1]);
+
+        tree.remove(ids[1]);
+        let root = tree.root().unwrap();
+        tree.remove_child(root, ids[1]);
+        assert_eq!(focus.update(&tree), vec![FocusEvent::Blur(ids[1])]);
+        assert_eq!(focus.focused(), None);
+    }
+}
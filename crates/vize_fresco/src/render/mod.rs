@@ -4,13 +4,16 @@
 //! - Render tree management
 //! - Node definitions
 //! - Diffing algorithm
+//! - Focus management
 //! - Paint operations
 
 mod diff;
+mod focus;
 mod node;
 mod painter;
 mod tree;
 
+pub use focus::{FocusEvent, FocusManager};
 pub use node::{
     Appearance, BorderStyle, InputContent, NodeId, NodeKind, RawContent, RenderNode, TextContent,
 };
@@ -6,6 +6,7 @@ use smallvec::SmallVec;
 
 use crate::layout::{FlexStyle, Rect};
 use crate::terminal::{Color, Style};
+use crate::text::{parse_ansi, spans_text, StyledSpan};
 
 /// Unique identifier for render nodes.
 pub type NodeId = u64;
@@ -25,6 +26,8 @@ pub struct RenderNode {
     pub children: SmallVec<[NodeId; 4]>,
     /// Computed layout (set after layout calculation)
     pub layout: Option<Rect>,
+    /// Tab index for focus navigation (see [`FocusManager`](super::FocusManager))
+    pub tab_index: Option<i32>,
     /// Whether this node needs re-render
     pub dirty: bool,
 }
@@ -39,6 +42,7 @@ impl RenderNode {
             appearance: Appearance::default(),
             children: SmallVec::new(),
             layout: None,
+            tab_index: None,
             dirty: true,
         }
     }
@@ -65,6 +69,12 @@ impl RenderNode {
         self
     }
 
+    /// Set the tab index.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
     /// Add a child.
     pub fn add_child(&mut self, child_id: NodeId) {
         self.children.push(child_id);
@@ -104,8 +114,10 @@ pub enum NodeKind {
 /// Text content for text nodes.
 #[derive(Debug, Clone, Default)]
 pub struct TextContent {
-    /// The text string
+    /// The text string (plain text of `spans` when styled)
     pub text: CompactString,
+    /// Styled runs; empty means `text` is drawn with the node's appearance
+    pub spans: Vec<StyledSpan>,
     /// Whether text should wrap
     pub wrap: bool,
 }
@@ -115,10 +127,30 @@ impl TextContent {
     pub fn new(text: impl Into<CompactString>) -> Self {
         Self {
             text: text.into(),
+            spans: Vec::new(),
+            wrap: false,
+        }
+    }
+
+    /// Create text content from styled spans.
+    pub fn from_spans(spans: Vec<StyledSpan>) -> Self {
+        Self {
+            text: spans_text(&spans).into(),
+            spans,
             wrap: false,
         }
     }
 
+    /// Create text content from a string containing ANSI escape sequences.
+    pub fn from_ansi(text: &str) -> Self {
+        Self::from_spans(parse_ansi(text, Style::new()))
+    }
+
+    /// Check if the content has styled spans.
+    pub fn is_styled(&self) -> bool {
+        !self.spans.is_empty()
+    }
+
     /// Enable text wrapping.
     pub fn with_wrap(mut self) -> Self {
         self.wrap = true;
@@ -175,7 +207,7 @@ impl InputContent {
 /// Raw content for custom rendering.
 #[derive(Debug, Clone)]
 pub struct RawContent {
-    /// Lines of content
+    /// Lines of content (ANSI SGR sequences are rendered as styles)
     pub lines: SmallVec<[CompactString; 4]>,
 }
 
@@ -327,6 +359,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_text_content_from_ansi() {
+        let content = TextContent::from_ansi("\x1b[1mbold\x1b[0m plain");
+        assert_eq!(content.text.as_str(), "bold plain");
+        assert!(content.is_styled());
+        assert_eq!(content.spans.len(), 2);
+        assert!(content.spans[0].style.bold);
+    }
+
     #[test]
     fn test_appearance() {
         let app = Appearance::new()
@@ -2,7 +2,7 @@
 
 use crate::layout::Rect;
 use crate::terminal::{Buffer, Style};
-use crate::text::{TextWrap, WrapMode};
+use crate::text::{StyledSpan, TextWrap, WrapMode};
 
 use super::{BorderStyle, NodeKind, RenderNode, RenderTree};
 
@@ -60,6 +60,9 @@ impl<'a> Painter<'a> {
             NodeKind::Box => {
                 // Box nodes just provide layout, content is drawn by children
             }
+            NodeKind::Text(text) if text.is_styled() => {
+                self.paint_spans(&text.spans, content_area, style, text.wrap);
+            }
             NodeKind::Text(text) => {
                 self.paint_text(&text.text, content_area, style, text.wrap);
             }
@@ -136,6 +139,28 @@ impl<'a> Painter<'a> {
         }
     }
 
+    /// Paint styled spans, merging each span's style over the node style.
+    fn paint_spans(&mut self, spans: &[StyledSpan], area: Rect, style: Style, wrap: bool) {
+        if area.is_empty() {
+            return;
+        }
+
+        let mode = if wrap {
+            WrapMode::Word
+        } else {
+            WrapMode::NoWrap
+        };
+        let lines = TextWrap::wrap_spans(spans, area.width as usize, mode);
+
+        for (i, line) in lines.iter().enumerate() {
+            if i >= area.height as usize {
+                break;
+            }
+            self.buffer
+                .set_spans(area.x, area.y + i as u16, line, style);
+        }
+    }
+
     /// Paint input field with text wrapping support.
     #[allow(clippy::too_many_arguments)]
     fn paint_input(
@@ -177,14 +202,14 @@ impl<'a> Painter<'a> {
         // Terminal cursor is positioned by render_tree in render.rs
     }
 
-    /// Paint raw content.
+    /// Paint raw content, interpreting ANSI escape sequences.
     fn paint_raw(&mut self, lines: &[compact_str::CompactString], area: Rect, style: Style) {
         for (i, line) in lines.iter().enumerate() {
             if i >= area.height as usize {
                 break;
             }
             self.buffer
-                .set_string(area.x, area.y + i as u16, line, style);
+                .set_ansi_string(area.x, area.y + i as u16, line, style);
         }
     }
 
@@ -216,6 +241,27 @@ mod tests {
         assert_eq!(buffer.get(5, 0).map(|c| c.symbol.as_str()), Some(" "));
     }
 
+    #[test]
+    fn test_paint_spans_wrapped() {
+        use crate::terminal::Color;
+
+        let mut buffer = Buffer::new(10, 3);
+        let mut painter = Painter::new(&mut buffer);
+
+        let spans = [
+            StyledSpan::new("Hello ", Style::new().bold()),
+            StyledSpan::new("World", Style::new().fg(Color::Red)),
+        ];
+        painter.paint_spans(&spans, Rect::new(0, 0, 6, 3), Style::new().italic(), true);
+
+        let cell = buffer.get(0, 0).unwrap();
+        assert_eq!(cell.symbol.as_str(), "H");
+        assert_eq!(cell.style, Style::new().italic().bold());
+        let cell = buffer.get(0, 1).unwrap();
+        assert_eq!(cell.symbol.as_str(), "W");
+        assert_eq!(cell.style, Style::new().italic().fg(Color::Red));
+    }
+
     #[test]
     fn test_paint_border() {
         let mut buffer = Buffer::new(10, 5);
@@ -2,8 +2,11 @@
 
 use rustc_hash::FxHashMap;
 
-use super::node::{NodeId, RenderNode};
+use super::focus::{FocusEvent, FocusManager};
+use super::node::{NodeId, NodeKind, RenderNode};
+use crate::input::KeyEvent;
 use crate::layout::{FlexStyle, LayoutEngine};
+use crate::text::SegmentedText;
 
 /// A tree of render nodes.
 pub struct RenderTree {
@@ -17,6 +20,8 @@ pub struct RenderTree {
     next_id: NodeId,
     /// Mapping from our IDs to layout IDs
     layout_ids: FxHashMap<NodeId, u64>,
+    /// Focus state and tab order
+    focus: FocusManager,
 }
 
 impl RenderTree {
@@ -28,6 +33,7 @@ impl RenderTree {
             layout: LayoutEngine::new(),
             next_id: 0,
             layout_ids: FxHashMap::default(),
+            focus: FocusManager::new(),
         }
     }
 
@@ -192,6 +198,7 @@ impl RenderTree {
         self.layout_ids.clear();
         self.root = None;
         self.next_id = 0;
+        self.focus = FocusManager::new();
     }
 
     /// Iterate over all nodes.
@@ -208,6 +215,130 @@ impl RenderTree {
     pub fn walk_preorder(&self, start: NodeId) -> TreeWalker<'_> {
         TreeWalker::new(self, start)
     }
+
+    /// Get the focus manager.
+    pub fn focus_manager(&self) -> &FocusManager {
+        &self.focus
+    }
+
+    /// Replace the focus manager, e.g. to carry focus across rebuilt trees.
+    pub fn set_focus_manager(&mut self, focus: FocusManager) {
+        self.focus = focus;
+    }
+
+    /// Take the focus manager, leaving a fresh one in its place.
+    pub fn take_focus_manager(&mut self) -> FocusManager {
+        std::mem::take(&mut self.focus)
+    }
+
+    /// Get the focused node ID.
+    pub fn focused(&self) -> Option<NodeId> {
+        self.focus.focused()
+    }
+
+    /// Focus a node.
+    pub fn focus(&mut self, id: NodeId) -> Vec<FocusEvent> {
+        self.with_focus(|focus| focus.focus(id))
+    }
+
+    /// Remove focus from the focused node.
+    pub fn blur(&mut self) -> Vec<FocusEvent> {
+        self.with_focus(FocusManager::blur)
+    }
+
+    /// Move focus to the next node in tab order.
+    pub fn focus_next(&mut self) -> Vec<FocusEvent> {
+        self.with_focus(FocusManager::focus_next)
+    }
+
+    /// Move focus to the previous node in tab order.
+    pub fn focus_prev(&mut self) -> Vec<FocusEvent> {
+        self.with_focus(FocusManager::focus_prev)
+    }
+
+    /// Handle Tab / Shift-Tab navigation.
+    ///
+    /// Returns `None` if the key is not a navigation key.
+    pub fn handle_focus_key(&mut self, event: &KeyEvent) -> Option<Vec<FocusEvent>> {
+        let mut handled = false;
+        let events = self.with_focus(|focus| match focus.handle_key(event) {
+            Some(events) => {
+                handled = true;
+                events
+            }
+            None => Vec::new(),
+        });
+        handled.then_some(events)
+    }
+
+    /// Apply a declarative focus request (see [`FocusManager::request`]).
+    pub fn request_focus(&mut self, id: Option<NodeId>) -> Vec<FocusEvent> {
+        self.with_focus(|focus| focus.request(id))
+    }
+
+    /// Recompute the tab order after structural changes.
+    ///
+    /// Blurs the focused node if it was removed or is no longer focusable.
+    pub fn sync_focus(&mut self) -> Vec<FocusEvent> {
+        self.with_focus(|_| Vec::new())
+    }
+
+    /// Get the terminal cursor position for the focused input node.
+    ///
+    /// Accounts for character widths and wrapping within the node's layout.
+    pub fn focused_cursor_position(&self) -> Option<(u16, u16)> {
+        let node = self.get(self.focus.focused()?)?;
+        let NodeKind::Input(content) = &node.kind else {
+            return None;
+        };
+        let layout = node.layout?;
+        if layout.width == 0 {
+            return None;
+        }
+
+        let st = SegmentedText::new(&content.value);
+        let cursor_col = st.column_at_index(content.cursor.min(st.grapheme_count));
+        let area_width = layout.width as usize;
+
+        let cursor_line = cursor_col / area_width;
+        let cursor_col_in_line = cursor_col % area_width;
+
+        let x = layout.x + cursor_col_in_line as u16;
+        let y = layout.y + (cursor_line as u16).min(layout.height.saturating_sub(1));
+        Some((x, y))
+    }
+
+    /// Run a focus operation against a fresh tab order and sync input state.
+    fn with_focus(
+        &mut self,
+        op: impl FnOnce(&mut FocusManager) -> Vec<FocusEvent>,
+    ) -> Vec<FocusEvent> {
+        let mut focus = std::mem::take(&mut self.focus);
+        let mut events = focus.update(self);
+        events.extend(op(&mut focus));
+        self.focus = focus;
+
+        for event in &events {
+            if let Some(node) = self.nodes.get_mut(&event.node_id()) {
+                if let NodeKind::Input(content) = &mut node.kind {
+                    content.focused = matches!(event, FocusEvent::Focus(_));
+                    node.dirty = true;
+                }
+            }
+        }
+
+        // Nodes of a rebuilt tree start blurred; restore the carried focus
+        if let Some(node) = self.focus.focused().and_then(|id| self.nodes.get_mut(&id)) {
+            if let NodeKind::Input(content) = &mut node.kind {
+                if !content.focused {
+                    content.focused = true;
+                    node.dirty = true;
+                }
+            }
+        }
+
+        events
+    }
 }
 
 impl Default for RenderTree {
@@ -307,4 +438,83 @@ mod tests {
         let ids: Vec<_> = tree.walk_preorder(root_id).map(|n| n.id).collect();
         assert_eq!(ids, vec![root_id, child1_id, child2_id]);
     }
+
+    #[test]
+    fn test_focus_syncs_input_state() {
+        use super::super::node::InputContent;
+        use crate::layout::Rect;
+
+        let mut tree = RenderTree::new();
+
+        let root_id = tree.next_id();
+        tree.insert_root(RenderNode::new(root_id, NodeKind::Box));
+
+        let first_id = tree.next_id();
+        tree.insert(RenderNode::new(
+            first_id,
+            NodeKind::Input(InputContent::new().with_value("abc")),
+        ));
+        tree.add_child(root_id, first_id);
+
+        let second_id = tree.next_id();
+        tree.insert(RenderNode::new(
+            second_id,
+            NodeKind::Input(InputContent::new().with_value("日本語")),
+        ));
+        tree.add_child(root_id, second_id);
+
+        tree.focus_next();
+        tree.focus_next();
+        assert_eq!(tree.focused(), Some(second_id));
+
+        let is_focused = |tree: &RenderTree, id| match &tree.get(id).unwrap().kind {
+            NodeKind::Input(content) => content.focused,
+            _ => false,
+        };
+        assert!(!is_focused(&tree, first_id));
+        assert!(is_focused(&tree, second_id));
+
+        let node = tree.get_mut(second_id).unwrap();
+        node.layout = Some(Rect::new(2, 3, 4, 2));
+        if let NodeKind::Input(content) = &mut node.kind {
+            content.cursor = 3;
+        }
+        // Three double-width graphemes wrap once within a 4-column input
+        assert_eq!(tree.focused_cursor_position(), Some((4, 4)));
+    }
+
+    #[test]
+    fn test_focus_persists_across_rebuilt_trees() {
+        use super::super::node::InputContent;
+
+        // Rebuild the tree from scratch like `renderTree` does, carrying the
+        // focus manager over; the first input is flagged as focused
+        fn render(focus: FocusManager) -> RenderTree {
+            let mut tree = RenderTree::new();
+            tree.insert_root(RenderNode::new(0, NodeKind::Box));
+            for id in [1, 2] {
+                tree.insert(RenderNode::new(id, NodeKind::Input(InputContent::new())));
+                tree.add_child(0, id);
+            }
+            tree.set_focus_manager(focus);
+            tree.request_focus(Some(1));
+            tree
+        }
+        let is_focused = |tree: &RenderTree, id| match &tree.get(id).unwrap().kind {
+            NodeKind::Input(content) => content.focused,
+            _ => false,
+        };
+
+        let mut tree = render(FocusManager::new());
+        assert_eq!(tree.focused(), Some(1));
+        assert!(is_focused(&tree, 1));
+
+        tree.focus_next();
+        assert_eq!(tree.focused(), Some(2));
+
+        let tree = render(tree.take_focus_manager());
+        assert_eq!(tree.focused(), Some(2));
+        assert!(!is_focused(&tree, 1));
+        assert!(is_focused(&tree, 2));
+    }
 }
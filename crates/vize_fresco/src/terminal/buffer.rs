@@ -2,6 +2,7 @@
 
 use super::cell::{Cell, Style};
 use crate::layout::Rect;
+use crate::text::{parse_ansi, StyledSpan};
 
 /// A buffer representing terminal content.
 ///
@@ -152,6 +153,26 @@ impl Buffer {
         col.saturating_sub(x)
     }
 
+    /// Set styled spans starting at the given position.
+    /// Span styles are merged on top of `base`. Returns the number of columns used.
+    pub fn set_spans(&mut self, x: u16, y: u16, spans: &[StyledSpan], base: Style) -> u16 {
+        let mut col = x;
+        for span in spans {
+            if col >= self.width {
+                break;
+            }
+            col += self.set_string(col, y, &span.text, base.merge(&span.style));
+        }
+        col.saturating_sub(x)
+    }
+
+    /// Set a string containing ANSI escape sequences starting at the given position.
+    /// SGR sequences become cell styles. Returns the number of columns used.
+    pub fn set_ansi_string(&mut self, x: u16, y: u16, text: &str, base: Style) -> u16 {
+        let spans = parse_ansi(text, Style::new());
+        self.set_spans(x, y, &spans, base)
+    }
+
     /// Fill a rectangular area with a character.
     pub fn fill(&mut self, area: Rect, ch: char, style: Style) {
         for y in area.y..area.y.saturating_add(area.height) {
@@ -243,6 +264,22 @@ mod tests {
         assert_eq!(buf.get(4, 0).map(|c| c.symbol.as_str()), Some("o"));
     }
 
+    #[test]
+    fn test_buffer_set_ansi_string() {
+        use super::super::cell::Color;
+
+        let mut buf = Buffer::new(20, 1);
+        let cols = buf.set_ansi_string(0, 0, "\x1b[31m日本\x1b[0m!", Style::new().bold());
+        assert_eq!(cols, 5);
+        let cell = buf.get(0, 0).unwrap();
+        assert_eq!(cell.symbol.as_str(), "日");
+        assert_eq!(cell.style, Style::new().bold().fg(Color::Red));
+        assert!(buf.get(1, 0).unwrap().is_continuation);
+        let cell = buf.get(4, 0).unwrap();
+        assert_eq!(cell.symbol.as_str(), "!");
+        assert_eq!(cell.style, Style::new().bold());
+    }
+
     #[test]
     fn test_buffer_wide_char() {
         let mut buf = Buffer::new(20, 1);
@@ -0,0 +1,226 @@
+//! ANSI escape sequence parsing.
+//!
+//! Converts SGR (Select Graphic Rendition) sequences into styled spans so
+//! output from tools that already emit colors can be rendered as cells.
+//! Other CSI and OSC sequences are stripped.
+
+use compact_str::CompactString;
+
+use super::span::StyledSpan;
+use crate::terminal::{Color, Style};
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+
+/// Parse text containing ANSI escapes into styled spans.
+///
+/// `base` is the style that SGR reset (`0`) and default colors (`39`/`49`)
+/// return to.
+pub fn parse_ansi(input: &str, base: Style) -> Vec<StyledSpan> {
+    let mut spans = Vec::new();
+    let mut text = CompactString::default();
+    let mut style = base;
+    let mut chars = input.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            ESC => match chars.next() {
+                // CSI: ESC [ params final
+                Some('[') => {
+                    let mut params = String::new();
+                    let mut final_byte = None;
+                    for c in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&c) {
+                            final_byte = Some(c);
+                            break;
+                        }
+                        params.push(c);
+                    }
+                    if final_byte == Some('m') {
+                        let next = apply_sgr(style, base, &params);
+                        if next != style {
+                            flush(&mut spans, &mut text, style);
+                            style = next;
+                        }
+                    }
+                }
+                // OSC: ESC ] ... (BEL | ESC \)
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == BEL {
+                            break;
+                        }
+                        if c == ESC && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                // Two-character escape, or a trailing ESC
+                _ => {}
+            },
+            '\n' | '\t' => text.push(ch),
+            c if c.is_control() => {}
+            c => text.push(c),
+        }
+    }
+
+    flush(&mut spans, &mut text, style);
+    spans
+}
+
+/// Remove all ANSI escape sequences from text.
+pub fn strip_ansi(input: &str) -> String {
+    parse_ansi(input, Style::new())
+        .into_iter()
+        .map(|span| span.text)
+        .collect()
+}
+
+/// Push pending text as a span.
+fn flush(spans: &mut Vec<StyledSpan>, text: &mut CompactString, style: Style) {
+    if !text.is_empty() {
+        spans.push(StyledSpan::new(std::mem::take(text), style));
+    }
+}
+
+/// Apply SGR parameters to a style.
+fn apply_sgr(mut style: Style, base: Style, params: &str) -> Style {
+    // Colon sub-parameters (38:2::r:g:b) are treated like semicolons
+    let mut codes = params
+        .split([';', ':'])
+        .map(|p| p.parse::<u16>().unwrap_or(0))
+        .peekable();
+
+    if codes.peek().is_none() {
+        return base;
+    }
+
+    while let Some(code) = codes.next() {
+        match code {
+            0 => style = base,
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            5 | 6 => style.blink = true,
+            7 => style.reverse = true,
+            8 => style.hidden = true,
+            9 => style.strikethrough = true,
+            21 | 22 => {
+                style.bold = base.bold;
+                style.dim = base.dim;
+            }
+            23 => style.italic = base.italic,
+            24 => style.underline = base.underline,
+            25 => style.blink = base.blink,
+            27 => style.reverse = base.reverse,
+            28 => style.hidden = base.hidden,
+            29 => style.strikethrough = base.strikethrough,
+            30..=37 => style.fg = Some(basic_color(code - 30)),
+            38 => style.fg = extended_color(&mut codes).or(style.fg),
+            39 => style.fg = base.fg,
+            40..=47 => style.bg = Some(basic_color(code - 40)),
+            48 => style.bg = extended_color(&mut codes).or(style.bg),
+            49 => style.bg = base.bg,
+            90..=97 => style.fg = Some(bright_color(code - 90)),
+            100..=107 => style.bg = Some(bright_color(code - 100)),
+            _ => {}
+        }
+    }
+
+    style
+}
+
+/// Parse `5;n` or `2;r;g;b` after a 38/48 code.
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    match codes.next()? {
+        5 => Some(Color::Indexed(codes.next()?.min(255) as u8)),
+        2 => {
+            let r = codes.next()?.min(255) as u8;
+            let g = codes.next()?.min(255) as u8;
+            let b = codes.next()?.min(255) as u8;
+            Some(Color::Rgb(r, g, b))
+        }
+        _ => None,
+    }
+}
+
+/// Map ANSI 0-7 to a color.
+fn basic_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+/// Map ANSI bright 0-7 to a color.
+fn bright_color(index: u16) -> Color {
+    match index {
+        0 => Color::Gray,
+        1 => Color::LightRed,
+        2 => Color::LightGreen,
+        3 => Color::LightYellow,
+        4 => Color::LightBlue,
+        5 => Color::LightMagenta,
+        6 => Color::LightCyan,
+        _ => Color::LightWhite,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text() {
+        let spans = parse_ansi("hello", Style::new());
+        assert_eq!(spans, vec![StyledSpan::plain("hello")]);
+    }
+
+    #[test]
+    fn test_basic_colors_and_reset() {
+        let spans = parse_ansi("\x1b[1;31merror\x1b[0m: done", Style::new());
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].text, "error");
+        assert_eq!(spans[0].style, Style::new().bold().fg(Color::Red));
+        assert_eq!(spans[1].text, ": done");
+        assert_eq!(spans[1].style, Style::new());
+    }
+
+    #[test]
+    fn test_extended_colors() {
+        let spans = parse_ansi("\x1b[38;5;208ma\x1b[48;2;1;2;3mb", Style::new());
+        assert_eq!(spans[0].style.fg, Some(Color::Indexed(208)));
+        assert_eq!(spans[1].style.bg, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(spans[1].style.fg, Some(Color::Indexed(208)));
+    }
+
+    #[test]
+    fn test_reset_returns_to_base() {
+        let base = Style::new().fg(Color::Blue);
+        let spans = parse_ansi("\x1b[32mok\x1b[39m!", base);
+        assert_eq!(spans[0].style.fg, Some(Color::Green));
+        assert_eq!(spans[1].style.fg, Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_strip_non_sgr_sequences() {
+        let input = "\x1b[2K\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07\r";
+        assert_eq!(strip_ansi(input), "link");
+    }
+
+    #[test]
+    fn test_cjk_text() {
+        let spans = parse_ansi("\x1b[33m日本語\x1b[m", Style::new());
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "日本語");
+        assert_eq!(spans[0].style.fg, Some(Color::Yellow));
+    }
+}
@@ -4,11 +4,16 @@
 //! - Character width calculation (East Asian Width)
 //! - Grapheme segmentation
 //! - Text wrapping
+//! - Styled spans and ANSI escape parsing
 
+mod ansi;
 mod segment;
+mod span;
 mod width;
 mod wrap;
 
+pub use ansi::{parse_ansi, strip_ansi};
 pub use segment::{segment, segment_vec, SegmentedText, TextSegment, TextSegmentIter};
+pub use span::{spans_text, spans_width, SpanLine, StyledSpan};
 pub use width::TextWidth;
 pub use wrap::{TextWrap, WrapMode};
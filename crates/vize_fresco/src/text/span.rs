@@ -0,0 +1,142 @@
+//! Styled text spans for rich inline text.
+
+use compact_str::CompactString;
+use smallvec::SmallVec;
+
+use super::segment::segment;
+use super::width::TextWidth;
+use crate::terminal::Style;
+
+/// A run of text with a single style.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyledSpan {
+    /// The text content
+    pub text: CompactString,
+    /// Style applied on top of the node's appearance
+    pub style: Style,
+}
+
+impl StyledSpan {
+    /// Create a new styled span.
+    pub fn new(text: impl Into<CompactString>, style: Style) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+
+    /// Create an unstyled span.
+    pub fn plain(text: impl Into<CompactString>) -> Self {
+        Self::new(text, Style::new())
+    }
+
+    /// Get the display width.
+    pub fn width(&self) -> usize {
+        TextWidth::width(&self.text)
+    }
+}
+
+/// A line of styled spans.
+pub type SpanLine = SmallVec<[StyledSpan; 4]>;
+
+/// Concatenate the text of all spans.
+pub fn spans_text(spans: &[StyledSpan]) -> String {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+/// Get the total display width of spans.
+pub fn spans_width(spans: &[StyledSpan]) -> usize {
+    spans.iter().map(StyledSpan::width).sum()
+}
+
+/// Append text to a line, extending the last span if the style matches.
+pub(crate) fn push_styled(line: &mut SpanLine, text: &str, style: Style) {
+    if text.is_empty() {
+        return;
+    }
+    match line.last_mut() {
+        Some(last) if last.style == style => last.text.push_str(text),
+        _ => line.push(StyledSpan::new(text, style)),
+    }
+}
+
+/// A single grapheme with its width and style.
+#[derive(Debug, Clone)]
+pub(crate) struct StyledGrapheme {
+    pub grapheme: CompactString,
+    pub width: usize,
+    pub style: Style,
+}
+
+impl StyledGrapheme {
+    /// Check if this grapheme is whitespace.
+    pub fn is_whitespace(&self) -> bool {
+        self.grapheme.chars().all(char::is_whitespace)
+    }
+}
+
+/// Split spans into hard lines (at `\n`) of styled graphemes.
+pub(crate) fn styled_lines(spans: &[StyledSpan]) -> Vec<Vec<StyledGrapheme>> {
+    let mut lines = vec![Vec::new()];
+
+    for span in spans {
+        for seg in segment(&span.text) {
+            if seg.grapheme == "\n" || seg.grapheme == "\r\n" {
+                lines.push(Vec::new());
+                continue;
+            }
+            if let Some(line) = lines.last_mut() {
+                line.push(StyledGrapheme {
+                    grapheme: seg.grapheme,
+                    width: seg.width,
+                    style: span.style,
+                });
+            }
+        }
+    }
+
+    lines
+}
+
+/// Collect styled graphemes into a line of spans.
+pub(crate) fn collect_line(graphemes: &[StyledGrapheme]) -> SpanLine {
+    let mut line = SpanLine::new();
+    for g in graphemes {
+        push_styled(&mut line, &g.grapheme, g.style);
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terminal::Color;
+
+    #[test]
+    fn test_spans_text_and_width() {
+        let spans = [
+            StyledSpan::new("Hello ", Style::new().bold()),
+            StyledSpan::new("世界", Style::new().fg(Color::Red)),
+        ];
+        assert_eq!(spans_text(&spans), "Hello 世界");
+        assert_eq!(spans_width(&spans), 10);
+    }
+
+    #[test]
+    fn test_push_styled_merges_same_style() {
+        let mut line = SpanLine::new();
+        push_styled(&mut line, "a", Style::new());
+        push_styled(&mut line, "b", Style::new());
+        push_styled(&mut line, "c", Style::new().bold());
+        assert_eq!(line.len(), 2);
+        assert_eq!(line[0].text, "ab");
+    }
+
+    #[test]
+    fn test_styled_lines_split_newlines() {
+        let spans = [StyledSpan::plain("a\nb"), StyledSpan::plain("c")];
+        let lines = styled_lines(&spans);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(collect_line(&lines[1])[0].text, "bc");
+    }
+}
@@ -1,6 +1,7 @@
 //! Text wrapping utilities.
 
 use super::segment::segment;
+use super::span::{collect_line, styled_lines, SpanLine, StyledGrapheme, StyledSpan};
 use super::width::TextWidth;
 use compact_str::CompactString;
 
@@ -136,6 +137,155 @@ impl TextWrap {
         lines
     }
 
+    /// Wrap styled spans to fit within max_width columns.
+    ///
+    /// Follows the same rules as [`TextWrap::wrap`], applied per hard line
+    /// (`\n` always breaks), and keeps each grapheme's style.
+    pub fn wrap_spans(spans: &[StyledSpan], max_width: usize, mode: WrapMode) -> Vec<SpanLine> {
+        let mut lines = Vec::new();
+
+        for graphemes in styled_lines(spans) {
+            match mode {
+                WrapMode::NoWrap => lines.push(collect_line(&graphemes)),
+                WrapMode::Word => lines.extend(Self::wrap_styled_word(&graphemes, max_width)),
+                WrapMode::Char => lines.extend(Self::wrap_styled_char(&graphemes, max_width)),
+                WrapMode::Truncate => lines.push(Self::truncate_styled(&graphemes, max_width)),
+            }
+        }
+
+        lines
+    }
+
+    /// Wrap styled graphemes at word boundaries.
+    fn wrap_styled_word(graphemes: &[StyledGrapheme], max_width: usize) -> Vec<SpanLine> {
+        if max_width == 0 {
+            return vec![];
+        }
+
+        let mut lines = Vec::new();
+        let mut current: Vec<StyledGrapheme> = Vec::new();
+        let mut current_width = 0;
+
+        // Words end after each whitespace grapheme, like `split_inclusive`
+        let words = graphemes.split_inclusive(StyledGrapheme::is_whitespace);
+
+        for word in words {
+            let word_width: usize = word.iter().map(|g| g.width).sum();
+            let trimmed = trim_trailing_whitespace(word);
+            let trimmed_width: usize = trimmed.iter().map(|g| g.width).sum();
+
+            // If word doesn't fit on current line
+            if current_width + trimmed_width > max_width {
+                // If current line has content, finish it
+                if current_width > 0 {
+                    lines.push(collect_line(trim_trailing_whitespace(&current)));
+                    current.clear();
+                    current_width = 0;
+                }
+
+                // If word is longer than max_width, use char wrap
+                if trimmed_width > max_width {
+                    let mut sub_lines = Self::char_chunks(trimmed, max_width);
+                    if let Some(last) = sub_lines.pop() {
+                        lines.extend(sub_lines.iter().map(|chunk| collect_line(chunk)));
+                        current_width = last.iter().map(|g| g.width).sum();
+                        current = last;
+                    }
+                    continue;
+                }
+            }
+
+            current.extend_from_slice(word);
+            current_width += word_width;
+        }
+
+        // Don't forget the last line
+        if !current.is_empty() || lines.is_empty() {
+            lines.push(collect_line(trim_trailing_whitespace(&current)));
+        }
+
+        lines
+    }
+
+    /// Wrap styled graphemes at character boundaries.
+    fn wrap_styled_char(graphemes: &[StyledGrapheme], max_width: usize) -> Vec<SpanLine> {
+        if max_width == 0 {
+            return vec![];
+        }
+
+        let mut lines: Vec<SpanLine> = Self::char_chunks(graphemes, max_width)
+            .iter()
+            .map(|chunk| collect_line(chunk))
+            .collect();
+
+        if lines.is_empty() {
+            lines.push(SpanLine::new());
+        }
+
+        lines
+    }
+
+    /// Split styled graphemes into chunks of at most max_width columns.
+    fn char_chunks(graphemes: &[StyledGrapheme], max_width: usize) -> Vec<Vec<StyledGrapheme>> {
+        let mut chunks = Vec::new();
+        let mut current: Vec<StyledGrapheme> = Vec::new();
+        let mut current_width = 0;
+
+        for g in graphemes {
+            if current_width + g.width > max_width {
+                if !current.is_empty() {
+                    chunks.push(std::mem::take(&mut current));
+                }
+                current_width = 0;
+
+                // Wide char that can never fit, use placeholder
+                if g.width > max_width {
+                    chunks.push(vec![StyledGrapheme {
+                        grapheme: CompactString::from("?"),
+                        width: 1,
+                        style: g.style,
+                    }]);
+                    continue;
+                }
+            }
+
+            current.push(g.clone());
+            current_width += g.width;
+        }
+
+        if !current.is_empty() {
+            chunks.push(current);
+        }
+
+        chunks
+    }
+
+    /// Truncate styled graphemes with an ellipsis.
+    fn truncate_styled(graphemes: &[StyledGrapheme], max_width: usize) -> SpanLine {
+        let total: usize = graphemes.iter().map(|g| g.width).sum();
+        if total <= max_width {
+            return collect_line(graphemes);
+        }
+        if max_width <= 3 {
+            return SpanLine::new();
+        }
+
+        let mut kept = Vec::new();
+        let mut width = 0;
+        for g in graphemes {
+            if width + g.width > max_width - 3 {
+                break;
+            }
+            width += g.width;
+            kept.push(g.clone());
+        }
+
+        let style = kept.last().or(graphemes.first()).map(|g| g.style);
+        let mut line = collect_line(&kept);
+        super::span::push_styled(&mut line, "...", style.unwrap_or_default());
+        line
+    }
+
     /// Split text into lines (preserving existing newlines).
     pub fn split_lines(text: &str) -> Vec<&str> {
         text.lines().collect()
@@ -158,6 +308,15 @@ impl TextWrap {
     }
 }
 
+/// Strip trailing whitespace graphemes.
+fn trim_trailing_whitespace(graphemes: &[StyledGrapheme]) -> &[StyledGrapheme] {
+    let end = graphemes
+        .iter()
+        .rposition(|g| !g.is_whitespace())
+        .map_or(0, |i| i + 1);
+    &graphemes[..end]
+}
+
 /// A wrapped line with metadata.
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -242,6 +401,58 @@ mod tests {
         assert_eq!(lines[1], "World");
     }
 
+    fn line_texts(lines: &[SpanLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| super::super::span::spans_text(line))
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap_spans_matches_plain() {
+        let spans = [
+            StyledSpan::plain("Hello World, "),
+            StyledSpan::plain("Supercalifragilistic"),
+        ];
+        let plain = "Hello World, Supercalifragilistic";
+        for width in [5, 6, 8, 13] {
+            let expected = TextWrap::wrap(plain, width, WrapMode::Word);
+            let lines = TextWrap::wrap_spans(&spans, width, WrapMode::Word);
+            assert_eq!(line_texts(&lines), expected, "width {}", width);
+        }
+    }
+
+    #[test]
+    fn test_wrap_spans_keeps_styles() {
+        use crate::terminal::{Color, Style};
+
+        let bold = Style::new().bold();
+        let red = Style::new().fg(Color::Red);
+        let spans = [
+            StyledSpan::new("Hello ", bold),
+            StyledSpan::new("あいうえお", red),
+        ];
+
+        let lines = TextWrap::wrap_spans(&spans, 6, WrapMode::Word);
+        assert_eq!(line_texts(&lines), vec!["Hello", "あいう", "えお"]);
+        assert_eq!(lines[0][0].style, bold);
+        assert_eq!(lines[1][0].style, red);
+    }
+
+    #[test]
+    fn test_wrap_spans_hard_breaks() {
+        let spans = [StyledSpan::plain("one\ntwo three")];
+        let lines = TextWrap::wrap_spans(&spans, 20, WrapMode::NoWrap);
+        assert_eq!(line_texts(&lines), vec!["one", "two three"]);
+    }
+
+    #[test]
+    fn test_wrap_spans_truncate() {
+        let spans = [StyledSpan::plain("Hello"), StyledSpan::plain(" World")];
+        let lines = TextWrap::wrap_spans(&spans, 8, WrapMode::Truncate);
+        assert_eq!(line_texts(&lines), vec!["Hello..."]);
+    }
+
     #[test]
     fn test_line_count() {
         let count = TextWrap::line_count("Hello World", 6, WrapMode::Word);
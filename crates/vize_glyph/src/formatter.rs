@@ -51,6 +51,7 @@ impl<'a> GlyphFormatter<'a> {
                 &script_setup.content,
                 true,
                 &script_setup.lang,
+                script_setup.src.as_deref(),
             )?;
             output.extend_from_slice(newline);
             output.extend_from_slice(newline);
@@ -58,21 +59,38 @@ impl<'a> GlyphFormatter<'a> {
 
         // Format regular script block
         if let Some(script) = &descriptor.script {
-            self.format_script_block_fast(&mut output, &script.content, false, &script.lang)?;
+            self.format_script_block_fast(
+                &mut output,
+                &script.content,
+                false,
+                &script.lang,
+                script.src.as_deref(),
+            )?;
             output.extend_from_slice(newline);
             output.extend_from_slice(newline);
         }
 
         // Format template block
         if let Some(template) = &descriptor.template {
-            self.format_template_block_fast(&mut output, &template.content, &template.lang)?;
+            self.format_template_block_fast(
+                &mut output,
+                &template.content,
+                &template.lang,
+                template.src.as_deref(),
+            )?;
             output.extend_from_slice(newline);
             output.extend_from_slice(newline);
         }
 
         // Format style blocks
         for style in &descriptor.styles {
-            self.format_style_block_fast(&mut output, &style.content, style.scoped, &style.lang)?;
+            self.format_style_block_fast(
+                &mut output,
+                &style.content,
+                style.scoped,
+                &style.lang,
+                style.src.as_deref(),
+            )?;
             output.extend_from_slice(newline);
             output.extend_from_slice(newline);
         }
@@ -128,10 +146,8 @@ impl<'a> GlyphFormatter<'a> {
         content: &str,
         is_setup: bool,
         lang: &Option<std::borrow::Cow<'_, str>>,
+        src: Option<&str>,
     ) -> Result<(), FormatError> {
-        let formatted_content =
-            script::format_script_content(content.trim(), self.options, self.allocator)?;
-
         // Build the opening tag using byte operations
         output.extend_from_slice(b"<script");
         if is_setup {
@@ -142,7 +158,13 @@ impl<'a> GlyphFormatter<'a> {
             output.extend_from_slice(lang.as_bytes());
             output.push(b'"');
         }
+        if write_external_src(output, src, b"script") {
+            return Ok(());
+        }
         output.push(b'>');
+
+        let formatted_content =
+            script::format_script_content(content.trim(), self.options, self.allocator)?;
         output.extend_from_slice(self.options.newline_bytes());
 
         // Add content with indentation if configured
@@ -174,9 +196,8 @@ impl<'a> GlyphFormatter<'a> {
         output: &mut Vec<u8>,
         content: &str,
         lang: &Option<std::borrow::Cow<'_, str>>,
+        src: Option<&str>,
     ) -> Result<(), FormatError> {
-        let formatted_content = template::format_template_content(content, self.options)?;
-
         // Build the opening tag
         output.extend_from_slice(b"<template");
         if let Some(lang) = lang {
@@ -184,7 +205,12 @@ impl<'a> GlyphFormatter<'a> {
             output.extend_from_slice(lang.as_bytes());
             output.push(b'"');
         }
+        if write_external_src(output, src, b"template") {
+            return Ok(());
+        }
         output.push(b'>');
+
+        let formatted_content = template::format_template_content(content, self.options)?;
         output.extend_from_slice(self.options.newline_bytes());
 
         // Template content is always indented by one level from the template tag
@@ -210,6 +236,7 @@ impl<'a> GlyphFormatter<'a> {
         content: &str,
         scoped: bool,
         lang: &Option<std::borrow::Cow<'_, str>>,
+        src: Option<&str>,
     ) -> Result<(), FormatError> {
         let formatted_content = content.trim();
 
@@ -223,6 +250,9 @@ impl<'a> GlyphFormatter<'a> {
             output.extend_from_slice(lang.as_bytes());
             output.push(b'"');
         }
+        if write_external_src(output, src, b"style") {
+            return Ok(());
+        }
         output.push(b'>');
         output.extend_from_slice(self.options.newline_bytes());
 
@@ -267,3 +297,20 @@ impl<'a> GlyphFormatter<'a> {
         Ok(())
     }
 }
+
+/// Finish a block that references its content through `src`: the reference
+/// is kept and the (external) content is left alone.
+///
+/// Returns whether the block was written.
+#[inline]
+fn write_external_src(output: &mut Vec<u8>, src: Option<&str>, tag: &[u8]) -> bool {
+    let Some(src) = src else {
+        return false;
+    };
+    output.extend_from_slice(b" src=\"");
+    output.extend_from_slice(src.as_bytes());
+    output.extend_from_slice(b"\"></");
+    output.extend_from_slice(tag);
+    output.push(b'>');
+    true
+}
@@ -151,6 +151,28 @@ const msg = 'hello'
         assert!(result.code.contains("</style>"));
     }
 
+    #[test]
+    fn test_format_sfc_preserves_external_src() {
+        let source = r#"<template src="./card.html"></template>
+
+<script lang="ts" src="./card.ts"></script>
+
+<style scoped src="./card.css"></style>
+"#;
+        let options = FormatOptions::default();
+        let result = format_sfc(source, &options).unwrap();
+
+        assert!(result
+            .code
+            .contains("<template src=\"./card.html\"></template>"));
+        assert!(result
+            .code
+            .contains("<script lang=\"ts\" src=\"./card.ts\"></script>"));
+        assert!(result
+            .code
+            .contains("<style scoped src=\"./card.css\"></style>"));
+    }
+
     #[test]
     fn test_allocator_reuse() {
         let allocator = Allocator::with_capacity(4096);
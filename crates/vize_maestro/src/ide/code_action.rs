@@ -46,7 +46,10 @@ impl CodeActionService {
         };
 
         // Run linter to get diagnostics with fixes
-        let linter = vize_patina::Linter::new();
+        let linter = ctx
+            .state
+            .lint_config()
+            .linter(&ctx.uri.to_file_path().unwrap_or_default());
         let result = linter.lint_template(&template.content, ctx.uri.path());
 
         // Template block offset in SFC
@@ -143,7 +146,10 @@ impl CodeActionService {
         let descriptor = vize_atelier_sfc::parse_sfc(&ctx.content, options).ok()?;
         let template = descriptor.template.as_ref()?;
 
-        let linter = vize_patina::Linter::new();
+        let linter = ctx
+            .state
+            .lint_config()
+            .linter(&ctx.uri.to_file_path().unwrap_or_default());
         let result = linter.lint_template(&template.content, ctx.uri.path());
 
         let template_start_line = template.loc.start_line as u32;
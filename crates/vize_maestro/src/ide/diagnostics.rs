@@ -107,7 +107,7 @@ impl DiagnosticService {
         diagnostics.extend(template_diags);
 
         // Collect linter diagnostics (vize_patina)
-        let lint_diags = Self::collect_lint_diagnostics(uri, &content);
+        let lint_diags = Self::collect_lint_diagnostics(state, uri, &content);
         tracing::info!("collect: patina lint diagnostics: {}", lint_diags.len());
         diagnostics.extend(lint_diags);
 
@@ -824,7 +824,7 @@ impl DiagnosticService {
     }
 
     /// Collect linter diagnostics from vize_patina.
-    fn collect_lint_diagnostics(uri: &Url, content: &str) -> Vec<Diagnostic> {
+    fn collect_lint_diagnostics(state: &ServerState, uri: &Url, content: &str) -> Vec<Diagnostic> {
         let options = vize_atelier_sfc::SfcParseOptions {
             filename: uri.path().to_string(),
             ..Default::default()
@@ -838,8 +838,10 @@ impl DiagnosticService {
             return vec![];
         };
 
-        // Create linter and lint the template content
-        let linter = vize_patina::Linter::new();
+        // Create linter (with project config) and lint the template content
+        let linter = state
+            .lint_config()
+            .linter(&uri.to_file_path().unwrap_or_default());
         let result = linter.lint_template(&template.content, uri.path());
 
         // Convert lint diagnostics to LSP diagnostics
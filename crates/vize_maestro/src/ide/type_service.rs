@@ -38,6 +38,8 @@ pub struct LspTypeCheckOptions {
     pub check_emits: bool,
     /// Check template bindings
     pub check_template_bindings: bool,
+    /// Check template expression types
+    pub check_template_types: bool,
     /// Check reactivity loss patterns
     pub check_reactivity: bool,
     /// Check setup context violations
@@ -55,6 +57,7 @@ impl Default for LspTypeCheckOptions {
             check_props: true,
             check_emits: true,
             check_template_bindings: true,
+            check_template_types: true,
             check_reactivity: true,
             check_setup_context: true,
             check_invalid_exports: true,
@@ -91,6 +94,7 @@ impl TypeService {
             check_props: lsp_options.check_props,
             check_emits: lsp_options.check_emits,
             check_template_bindings: lsp_options.check_template_bindings,
+            check_template_types: lsp_options.check_template_types,
             check_reactivity: lsp_options.check_reactivity,
             check_setup_context: lsp_options.check_setup_context,
             check_invalid_exports: lsp_options.check_invalid_exports,
@@ -241,6 +241,22 @@ impl LanguageServer for MaestroServer {
     }
 
     async fn initialized(&self, _params: InitializedParams) {
+        // Lint configuration is cached until a `vize.config.json` changes
+        let watchers = DidChangeWatchedFilesRegistrationOptions {
+            watchers: vec![FileSystemWatcher {
+                glob_pattern: GlobPattern::String("**/vize.config.json".to_string()),
+                kind: None,
+            }],
+        };
+        let registration = Registration {
+            id: "vize-config-watcher".to_string(),
+            method: "workspace/didChangeWatchedFiles".to_string(),
+            register_options: serde_json::to_value(watchers).ok(),
+        };
+        if let Err(e) = self.client.register_capability(vec![registration]).await {
+            tracing::warn!("failed to watch vize.config.json: {}", e);
+        }
+
         self.client
             .log_message(MessageType::INFO, "vize_maestro LSP server initialized")
             .await;
@@ -288,6 +304,24 @@ impl LanguageServer for MaestroServer {
         self.publish_diagnostics(&uri).await;
     }
 
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let config_changed = params.changes.iter().any(|change| {
+            change
+                .uri
+                .to_file_path()
+                .is_ok_and(|path| crate::utils::is_lint_config(&path))
+        });
+        if !config_changed {
+            return;
+        }
+
+        // Re-lint open documents with the new configuration
+        self.state.lint_config().invalidate();
+        for uri in self.state.documents.uris() {
+            self.publish_diagnostics(&uri).await;
+        }
+    }
+
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri;
         self.state.documents.close(&uri);
@@ -15,6 +15,7 @@ use std::sync::OnceLock;
 use vize_canon::{BatchTypeChecker, BatchTypeCheckerTrait, TsgoBridge, TsgoBridgeConfig};
 
 use crate::document::DocumentStore;
+use crate::utils::LintConfigCache;
 use crate::virtual_code::{VirtualCodeGenerator, VirtualDocuments};
 
 /// Batch type check result cache.
@@ -81,6 +82,8 @@ pub struct ServerState {
     virtual_gen: RwLock<VirtualCodeGenerator>,
     /// Cached virtual documents per file
     virtual_docs_cache: DashMap<Url, VirtualDocuments>,
+    /// Lint configuration read from `vize.config.json`
+    lint_config: LintConfigCache,
     /// tsgo bridge for TypeScript language features (lazy initialized)
     #[cfg(feature = "native")]
     tsgo_bridge: OnceCell<Arc<TsgoBridge>>,
@@ -111,6 +114,7 @@ impl ServerState {
             documents: DocumentStore::new(),
             virtual_gen: RwLock::new(VirtualCodeGenerator::new()),
             virtual_docs_cache: DashMap::new(),
+            lint_config: LintConfigCache::new(),
             #[cfg(feature = "native")]
             tsgo_bridge: OnceCell::new(),
             #[cfg(feature = "native")]
@@ -124,6 +128,11 @@ impl ServerState {
         }
     }
 
+    /// Get the lint configuration cache.
+    pub fn lint_config(&self) -> &LintConfigCache {
+        &self.lint_config
+    }
+
     /// Set the workspace root path.
     #[cfg(feature = "native")]
     pub fn set_workspace_root(&self, path: PathBuf) {
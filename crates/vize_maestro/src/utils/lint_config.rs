@@ -0,0 +1,144 @@
+//! Lint configuration from `vize.config.json`.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use vize_patina::{Linter, RestrictedSyntax};
+
+/// Create a linter for the document at `path`.
+///
+/// Applies `lint.restrictedSyntax` from the nearest `vize.config.json` in the
+/// document's directory or one of its ancestors. Missing or invalid config
+/// falls back to the default linter.
+pub fn create_linter(path: &Path) -> Linter {
+    build_linter(load_restricted_syntax(path))
+}
+
+/// Lint configuration of a workspace, read once per directory and kept until
+/// a `vize.config.json` changes.
+#[derive(Debug, Default)]
+pub struct LintConfigCache {
+    /// `lint.restrictedSyntax` entries per document directory
+    entries: DashMap<PathBuf, Arc<Vec<RestrictedSyntax>>>,
+}
+
+impl LintConfigCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a linter for the document at `path` (see [`create_linter`]).
+    pub fn linter(&self, path: &Path) -> Linter {
+        let dir = path.parent().unwrap_or(path).to_path_buf();
+        let entries = self
+            .entries
+            .entry(dir)
+            .or_insert_with(|| Arc::new(load_restricted_syntax(path)))
+            .clone();
+        build_linter(entries.to_vec())
+    }
+
+    /// Drop the cached configuration, e.g. after a `vize.config.json` changed.
+    pub fn invalidate(&self) {
+        self.entries.clear();
+    }
+}
+
+/// Whether `path` is a `vize.config.json` file.
+pub fn is_lint_config(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "vize.config.json")
+}
+
+/// Read `lint.restrictedSyntax` from the nearest `vize.config.json`.
+fn load_restricted_syntax(path: &Path) -> Vec<RestrictedSyntax> {
+    path.ancestors()
+        .skip(1)
+        .map(|dir| dir.join("vize.config.json"))
+        .find(|config| config.is_file())
+        .and_then(|config| {
+            let content = std::fs::read_to_string(&config).ok()?;
+            let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+            let entries = value.get("lint")?.get("restrictedSyntax")?.clone();
+            match serde_json::from_value::<Vec<RestrictedSyntax>>(entries) {
+                Ok(entries) => Some(entries),
+                Err(e) => {
+                    tracing::warn!("{}: invalid lint.restrictedSyntax: {}", config.display(), e);
+                    None
+                }
+            }
+        })
+        .unwrap_or_default()
+}
+
+fn build_linter(entries: Vec<RestrictedSyntax>) -> Linter {
+    Linter::new()
+        .with_restricted_syntax(entries)
+        .unwrap_or_else(|e| {
+            tracing::warn!("lint.restrictedSyntax: {}", e);
+            Linter::new()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_config() {
+        let dir = std::env::temp_dir().join(format!("vize-lint-config-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(
+            dir.join("vize.config.json"),
+            r#"{ "lint": { "restrictedSyntax": [{ "selector": "element[tag=router-link]", "message": "Use <AppLink>" }] } }"#,
+        )
+        .unwrap();
+
+        let linter = create_linter(&dir.join("src/App.vue"));
+        let result = linter.lint_template(r#"<router-link to="/">Home</router-link>"#, "App.vue");
+        assert!(result
+            .diagnostics
+            .iter()
+            .any(|d| d.rule_name == "vue/no-restricted-syntax" && d.message == "Use <AppLink>"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cache_until_invalidated() {
+        let dir = std::env::temp_dir().join(format!("vize-lint-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config = dir.join("vize.config.json");
+        let write_config = |tag: &str| {
+            let content = format!(
+                r#"{{ "lint": {{ "restrictedSyntax": [{{ "selector": "element[tag={}]" }}] }} }}"#,
+                tag
+            );
+            std::fs::write(&config, content).unwrap();
+        };
+        let restricted = |cache: &LintConfigCache, template: &str| {
+            cache
+                .linter(&dir.join("App.vue"))
+                .lint_template(template, "App.vue")
+                .diagnostics
+                .iter()
+                .any(|d| d.rule_name == "vue/no-restricted-syntax")
+        };
+
+        let cache = LintConfigCache::new();
+        write_config("marquee");
+        assert!(restricted(&cache, "<marquee />"));
+
+        // Edits are not picked up until the config is invalidated
+        write_config("blink");
+        assert!(restricted(&cache, "<marquee />"));
+        assert!(is_lint_config(&config));
+        cache.invalidate();
+        assert!(!restricted(&cache, "<marquee />"));
+        assert!(restricted(&cache, "<blink />"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
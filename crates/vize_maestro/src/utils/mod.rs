@@ -1,5 +1,7 @@
 //! Utility modules for vize_maestro.
 
+pub mod lint_config;
 pub mod position;
 
+pub use lint_config::{create_linter, is_lint_config, LintConfigCache};
 pub use position::*;
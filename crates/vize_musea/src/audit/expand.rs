@@ -0,0 +1,581 @@
+//! Variant template expansion.
+//!
+//! Inlines the audited component into a variant template, one level deep:
+//! each usage of the component is replaced with the component's own
+//! template, with prop bindings and interpolations resolved from the
+//! usage attributes, the variant args and the prop defaults, slots filled
+//! with the usage's content and remaining attributes falling through to
+//! the root element, as Vue would render them.
+
+use serde_json::Value;
+use vize_atelier_sfc::{parse_sfc, SfcError, SfcParseOptions};
+use vize_carton::general::{camelize, hyphenate};
+use vize_carton::{Bump, CompactString, FxHashMap};
+use vize_relief::ast::{ElementNode, ExpressionNode, PropNode, TemplateChildNode};
+
+use crate::autogen::props::{analyze_script_setup, literal_to_json};
+use crate::docs::escape_html;
+use crate::gallery::replace_self;
+
+/// Value bound to a prop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropValue {
+    /// Statically known value
+    Literal(Value),
+    /// Runtime expression, kept as written
+    Expr(String),
+}
+
+/// A prop declared by the audited component.
+#[derive(Debug, Clone)]
+pub struct ComponentProp {
+    pub name: String,
+    pub prop_type: Option<String>,
+    pub default: Option<PropValue>,
+}
+
+/// The component documented by an art file, as needed for expansion.
+#[derive(Debug, Clone)]
+pub struct AuditComponent {
+    /// Tag name used in variant templates
+    pub name: String,
+    pub template: String,
+    pub props: Vec<ComponentProp>,
+}
+
+impl AuditComponent {
+    /// Read the template and props of a Vue SFC.
+    pub fn from_sfc(source: &str, filename: &str, name: &str) -> Result<Self, SfcError> {
+        let descriptor = parse_sfc(
+            source,
+            SfcParseOptions {
+                filename: filename.to_string(),
+                ..Default::default()
+            },
+        )?;
+        let template = descriptor
+            .template
+            .map(|t| t.content.to_string())
+            .unwrap_or_default();
+
+        let props = analyze_script_setup(source, filename)?
+            .map(|croquis| {
+                croquis
+                    .macros
+                    .props()
+                    .iter()
+                    .map(|prop| ComponentProp {
+                        name: prop.name.to_string(),
+                        prop_type: prop.prop_type.as_ref().map(|t| t.to_string()),
+                        default: prop.default_value.as_deref().map(|default| {
+                            literal_to_json(default)
+                                .map(PropValue::Literal)
+                                .unwrap_or_else(|| PropValue::Expr(default.to_string()))
+                        }),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            name: name.to_string(),
+            template,
+            props,
+        })
+    }
+
+    fn prop(&self, name: &str) -> Option<&ComponentProp> {
+        self.props.iter().find(|p| p.name == name)
+    }
+}
+
+/// Expand a variant template, inlining `component` where it is used.
+///
+/// Without a component the template is returned unchanged.
+pub fn expand_variant(
+    template: &str,
+    args: &FxHashMap<&str, Value>,
+    component: Option<&AuditComponent>,
+) -> String {
+    let Some(component) = component else {
+        return template.to_string();
+    };
+    let template = replace_self(template, &component.name);
+
+    let allocator = Bump::new();
+    let (root, _) = vize_armature::parse(&allocator, &template);
+    let mut edits = Vec::new();
+    collect_usages(&root.children, &template, component, args, &mut edits);
+    apply_edits(&template, edits)
+}
+
+/// A text replacement in a template.
+struct Edit {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+fn collect_usages(
+    children: &[TemplateChildNode<'_>],
+    source: &str,
+    component: &AuditComponent,
+    args: &FxHashMap<&str, Value>,
+    edits: &mut Vec<Edit>,
+) {
+    for child in children {
+        let TemplateChildNode::Element(element) = child else {
+            continue;
+        };
+        let is_usage = element.tag == component.name
+            || (component.name.contains(char::is_uppercase)
+                && hyphenate(&component.name).contains('-')
+                && element.tag == hyphenate(&component.name));
+        if is_usage {
+            edits.push(Edit {
+                start: element.loc.start.offset as usize,
+                end: element_end(element, source),
+                text: expand_usage(element, source, component, args),
+            });
+        } else {
+            collect_usages(&element.children, source, component, args, edits);
+        }
+    }
+}
+
+/// Render the component template for one usage.
+fn expand_usage(
+    usage: &ElementNode<'_>,
+    source: &str,
+    component: &AuditComponent,
+    args: &FxHashMap<&str, Value>,
+) -> String {
+    let mut values: FxHashMap<CompactString, PropValue> = FxHashMap::default();
+    let mut fallthrough: Vec<String> = Vec::new();
+
+    for prop in &usage.props {
+        let (start, end) = prop_range(prop, source);
+        let text = source[start..end].to_string();
+        match prop {
+            PropNode::Attribute(attr) => {
+                let key = camelize(&attr.name);
+                match component.prop(&key) {
+                    Some(declared) => {
+                        let value = attr.value.as_ref().map(|v| v.content.as_str());
+                        let is_flag = value.is_none_or(|v| v.is_empty() || v == attr.name);
+                        let value = if declared.prop_type.as_deref() == Some("boolean") && is_flag {
+                            Value::Bool(true)
+                        } else {
+                            Value::String(value.unwrap_or_default().to_string())
+                        };
+                        values.insert(key, PropValue::Literal(value));
+                    }
+                    None => fallthrough.push(text),
+                }
+            }
+            PropNode::Directive(directive) => {
+                let exp = expression(&directive.exp).unwrap_or_default();
+                match (directive.name.as_str(), expression(&directive.arg)) {
+                    ("bind", Some(arg)) if component.prop(&camelize(arg)).is_some() => {
+                        values.insert(camelize(arg), resolve_expression(exp, args));
+                    }
+                    ("bind", None) if exp.trim() == "args" => {
+                        for (key, value) in args {
+                            values
+                                .entry(camelize(key))
+                                .or_insert_with(|| PropValue::Literal(value.clone()));
+                        }
+                    }
+                    ("slot", _) => {}
+                    _ => fallthrough.push(text),
+                }
+            }
+        }
+    }
+
+    // Args are passed to the component like props; unknown keys fall through
+    for (key, value) in args {
+        let name = camelize(key);
+        if component.prop(&name).is_some() {
+            values
+                .entry(name)
+                .or_insert_with(|| PropValue::Literal(value.clone()));
+        } else if let Some(attr) = render_attribute(key, value) {
+            fallthrough.push(attr);
+        }
+    }
+    for prop in &component.props {
+        let value = match (&prop.default, prop.prop_type.as_deref()) {
+            (Some(default), _) => default.clone(),
+            // Absent boolean props are cast to `false`
+            (None, Some("boolean")) => PropValue::Literal(Value::Bool(false)),
+            (None, _) => continue,
+        };
+        values.entry(prop.name.as_str().into()).or_insert(value);
+    }
+
+    let slots = usage_slots(usage, source);
+
+    let template = component.template.as_str();
+    let allocator = Bump::new();
+    let (root, _) = vize_armature::parse(&allocator, template);
+    let mut edits = Vec::new();
+    substitute(
+        &root.children,
+        template,
+        component,
+        &values,
+        &slots,
+        &mut edits,
+    );
+
+    let mut elements = root.children.iter().filter_map(|child| match child {
+        TemplateChildNode::Element(element) => Some(element),
+        _ => None,
+    });
+    if let (Some(root_element), None) = (elements.next(), elements.next()) {
+        if !fallthrough.is_empty() {
+            let at = root_element.loc.start.offset as usize + 1 + root_element.tag.len();
+            edits.push(Edit {
+                start: at,
+                end: at,
+                text: format!(" {}", fallthrough.join(" ")),
+            });
+        }
+    }
+
+    apply_edits(template, edits)
+}
+
+/// Slot contents passed by a usage, keyed by slot name.
+fn usage_slots(usage: &ElementNode<'_>, source: &str) -> FxHashMap<String, String> {
+    let mut slots = FxHashMap::default();
+    let Some((content_start, content_end)) = content_range(usage, source) else {
+        return slots;
+    };
+
+    let mut default = String::new();
+    let mut last = content_start;
+    for child in &usage.children {
+        let TemplateChildNode::Element(element) = child else {
+            continue;
+        };
+        let Some(name) = (element.tag == "template")
+            .then(|| slot_directive_name(element))
+            .flatten()
+        else {
+            continue;
+        };
+        let start = element.loc.start.offset as usize;
+        default.push_str(&source[last..start]);
+        last = element_end(element, source);
+        if let Some((start, end)) = content_range(element, source) {
+            slots.insert(name, source[start..end].to_string());
+        }
+    }
+    default.push_str(&source[last..content_end]);
+
+    if !default.trim().is_empty() {
+        slots.entry("default".to_string()).or_insert(default);
+    }
+    slots
+}
+
+/// Resolve prop references in the component template.
+fn substitute(
+    children: &[TemplateChildNode<'_>],
+    source: &str,
+    component: &AuditComponent,
+    values: &FxHashMap<CompactString, PropValue>,
+    slots: &FxHashMap<String, String>,
+    edits: &mut Vec<Edit>,
+) {
+    for child in children {
+        match child {
+            TemplateChildNode::Element(element) if element.tag == "slot" => {
+                let name = element
+                    .props
+                    .iter()
+                    .find_map(|prop| match prop {
+                        PropNode::Attribute(attr) if attr.name == "name" => {
+                            attr.value.as_ref().map(|v| v.content.to_string())
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| "default".to_string());
+                let start = element.loc.start.offset as usize;
+                let end = element_end(element, source);
+
+                match (slots.get(&name), content_range(element, source)) {
+                    (Some(content), _) => edits.push(Edit {
+                        start,
+                        end,
+                        text: content.clone(),
+                    }),
+                    // Render the fallback content
+                    (None, Some((content_start, content_end))) => {
+                        edits.push(Edit {
+                            start,
+                            end: content_start,
+                            text: String::new(),
+                        });
+                        substitute(&element.children, source, component, values, slots, edits);
+                        edits.push(Edit {
+                            start: content_end,
+                            end,
+                            text: String::new(),
+                        });
+                    }
+                    (None, None) => edits.push(Edit {
+                        start,
+                        end,
+                        text: String::new(),
+                    }),
+                }
+            }
+            TemplateChildNode::Element(element) => {
+                for prop in &element.props {
+                    let PropNode::Directive(directive) = prop else {
+                        continue;
+                    };
+                    let (Some(arg), Some(exp)) =
+                        (expression(&directive.arg), expression(&directive.exp))
+                    else {
+                        continue;
+                    };
+                    if directive.name != "bind" {
+                        continue;
+                    }
+                    let Some(name) = prop_reference(exp, component) else {
+                        continue;
+                    };
+                    let text = match values.get(name) {
+                        Some(PropValue::Literal(value)) => {
+                            let Some(text) = render_attribute(arg, value) else {
+                                continue;
+                            };
+                            text
+                        }
+                        Some(PropValue::Expr(_)) => continue,
+                        // `undefined` bindings are not rendered
+                        None => String::new(),
+                    };
+                    let (start, end) = prop_range(prop, source);
+                    edits.push(Edit { start, end, text });
+                }
+                substitute(&element.children, source, component, values, slots, edits);
+            }
+            TemplateChildNode::Interpolation(interpolation) => {
+                let ExpressionNode::Simple(exp) = &interpolation.content else {
+                    continue;
+                };
+                let Some(name) = prop_reference(&exp.content, component) else {
+                    continue;
+                };
+                let text = match values.get(name) {
+                    Some(PropValue::Literal(Value::String(s))) => escape_html(s),
+                    Some(PropValue::Literal(Value::Null)) | None => String::new(),
+                    Some(PropValue::Literal(value)) => escape_html(&value.to_string()),
+                    Some(PropValue::Expr(_)) => continue,
+                };
+                edits.push(Edit {
+                    start: interpolation.loc.start.offset as usize,
+                    end: interpolation.loc.end.offset as usize,
+                    text,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Static attribute for a bound value; `None` keeps the binding dynamic.
+fn render_attribute(name: &str, value: &Value) -> Option<String> {
+    let is_aria = name.starts_with("aria-");
+    Some(match value {
+        Value::Null => String::new(),
+        Value::Bool(false) if !is_aria => String::new(),
+        Value::Bool(true) if !is_aria => name.to_string(),
+        Value::Bool(b) => format!("{}=\"{}\"", name, b),
+        Value::String(s) => format!("{}=\"{}\"", name, escape_html(s)),
+        Value::Number(n) => format!("{}=\"{}\"", name, n),
+        Value::Array(_) | Value::Object(_) => return None,
+    })
+}
+
+/// Evaluate a usage binding against the variant args.
+fn resolve_expression(exp: &str, args: &FxHashMap<&str, Value>) -> PropValue {
+    let exp = exp.trim();
+    if let Some(value) = exp.strip_prefix("args.").and_then(|key| args.get(key)) {
+        return PropValue::Literal(value.clone());
+    }
+    literal_to_json(exp)
+        .map(PropValue::Literal)
+        .unwrap_or_else(|| PropValue::Expr(exp.to_string()))
+}
+
+/// Prop name referenced by `name`, `props.name` or `$props.name`.
+fn prop_reference<'c>(exp: &'c str, component: &AuditComponent) -> Option<&'c str> {
+    let exp = exp.trim();
+    let name = exp
+        .strip_prefix("props.")
+        .or_else(|| exp.strip_prefix("$props."))
+        .unwrap_or(exp);
+    component.prop(name).map(|_| name)
+}
+
+/// Name of the slot a `<template v-slot:name>` / `<template #name>` fills.
+fn slot_directive_name(element: &ElementNode<'_>) -> Option<String> {
+    element.props.iter().find_map(|prop| match prop {
+        PropNode::Directive(directive) if directive.name == "slot" => {
+            Some(expression(&directive.arg).unwrap_or("default").to_string())
+        }
+        _ => None,
+    })
+}
+
+fn expression<'e>(exp: &'e Option<ExpressionNode<'_>>) -> Option<&'e str> {
+    match exp {
+        Some(ExpressionNode::Simple(simple)) => Some(simple.content.as_str()),
+        _ => None,
+    }
+}
+
+/// Byte range between an element's opening and closing tags.
+fn content_range(element: &ElementNode<'_>, source: &str) -> Option<(usize, usize)> {
+    let open_end = element.loc.end.offset as usize;
+    let end = element_end(element, source);
+    if end == open_end {
+        return None;
+    }
+    let close_start = source[..end].rfind("</")?;
+    Some((open_end, close_start))
+}
+
+/// End offset of an element including its closing tag.
+///
+/// Element locations from the parser only cover the opening tag.
+fn element_end(element: &ElementNode<'_>, source: &str) -> usize {
+    let open_end = element.loc.end.offset as usize;
+    if element.is_self_closing {
+        return open_end;
+    }
+    let children_end = element
+        .children
+        .iter()
+        .map(|child| match child {
+            TemplateChildNode::Element(child) => element_end(child, source),
+            other => other.loc().end.offset as usize,
+        })
+        .max()
+        .unwrap_or(open_end)
+        .max(open_end);
+
+    let rest = &source[children_end..];
+    let close = rest.trim_start();
+    let is_close_tag = close
+        .get(2..2 + element.tag.len())
+        .is_some_and(|tag| close.starts_with("</") && tag.eq_ignore_ascii_case(&element.tag));
+    match (is_close_tag, close.find('>')) {
+        (true, Some(gt)) => children_end + (rest.len() - close.len()) + gt + 1,
+        _ => open_end,
+    }
+}
+
+/// Source range of an attribute or directive.
+///
+/// Prop locations from the parser stop before a closing quote.
+fn prop_range(prop: &PropNode<'_>, source: &str) -> (usize, usize) {
+    let loc = prop.loc();
+    let start = loc.start.offset as usize;
+    let mut end = loc.end.offset as usize;
+    let quote = source[start..end]
+        .split_once('=')
+        .and_then(|(_, value)| value.trim_start().chars().next())
+        .filter(|c| matches!(c, '"' | '\''));
+    if quote.is_some_and(|quote| source[end..].starts_with(quote)) {
+        end += 1;
+    }
+    (start, end)
+}
+
+/// Apply non-overlapping edits in source order.
+fn apply_edits(source: &str, mut edits: Vec<Edit>) -> String {
+    edits.sort_by_key(|edit| (edit.start, edit.end));
+    let mut output = String::with_capacity(source.len());
+    let mut last = 0;
+    for edit in edits {
+        if edit.start < last {
+            continue;
+        }
+        output.push_str(&source[last..edit.start]);
+        output.push_str(&edit.text);
+        last = edit.end;
+    }
+    output.push_str(&source[last..]);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn avatar() -> AuditComponent {
+        AuditComponent::from_sfc(
+            r#"<script setup lang="ts">
+withDefaults(defineProps<{
+  src: string
+  alt?: string
+  size?: number
+  rounded?: boolean
+}>(), { size: 32 })
+</script>
+<template>
+  <figure class="avatar">
+    <img :src="src" :alt="props.alt" :width="size" :data-rounded="rounded" />
+    <figcaption><slot name="caption">{{ alt }}</slot></figcaption>
+    <slot />
+  </figure>
+</template>"#,
+            "Avatar.vue",
+            "Avatar",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_expand_props_and_defaults() {
+        let args = FxHashMap::default();
+        let expanded = expand_variant(
+            r#"<Self src="/me.png" :rounded="true" aria-label="Me" @click="open" />"#,
+            &args,
+            Some(&avatar()),
+        );
+
+        assert!(expanded.contains(r#"<figure aria-label="Me" @click="open" class="avatar">"#));
+        assert!(expanded.contains(r#"<img src="/me.png"  width="32" data-rounded />"#));
+        assert!(expanded.contains("<figcaption></figcaption>"));
+    }
+
+    #[test]
+    fn test_expand_args_and_slots() {
+        let mut args = FxHashMap::default();
+        args.insert("alt", Value::String("Portrait".to_string()));
+        let expanded = expand_variant(
+            r#"<Avatar :src="args.src" v-bind="args"><b>Hi</b><template #caption>Me</template></Avatar>"#,
+            &args,
+            Some(&avatar()),
+        );
+
+        assert!(expanded.contains(r#":src="src""#));
+        assert!(expanded.contains(r#"alt="Portrait""#));
+        assert!(expanded.contains("<figcaption>Me</figcaption>"));
+        assert!(expanded.contains("<b>Hi</b>"));
+    }
+
+    #[test]
+    fn test_expand_without_component() {
+        let args = FxHashMap::default();
+        assert_eq!(expand_variant("<img>", &args, None), "<img>");
+    }
+}
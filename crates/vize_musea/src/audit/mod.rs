@@ -0,0 +1,282 @@
+//! Accessibility audit of Art variants.
+//!
+//! Each variant template is expanded with its args and the component's
+//! prop defaults, inlining the component template one level deep (see
+//! [`expand_variant`]), and checked with the accessibility rules of
+//! `vize_patina`. Violations are reported per variant.
+
+mod expand;
+
+pub use expand::{expand_variant, AuditComponent, ComponentProp, PropValue};
+
+use serde::Serialize;
+use vize_patina::{Linter, ReportDiagnostic, ReportFile, RuleRegistry, Severity};
+
+use crate::types::ArtDescriptor;
+
+/// A rule violation found in an expanded variant.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct A11yViolation {
+    pub rule: String,
+    pub severity: Severity,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    /// Expanded markup that triggered the violation
+    pub snippet: String,
+}
+
+/// Audit result of a single variant.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantAudit {
+    pub variant: String,
+    /// Line of the `<variant>` block in the art file
+    pub line: u32,
+    pub violations: Vec<A11yViolation>,
+}
+
+/// Audit result of an art file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtAudit {
+    pub title: String,
+    pub path: String,
+    pub variants: Vec<VariantAudit>,
+}
+
+impl ArtAudit {
+    /// Total number of violations across variants.
+    pub fn violation_count(&self) -> usize {
+        self.variants.iter().map(|v| v.violations.len()).sum()
+    }
+}
+
+/// Aggregate counts of an audit run.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditSummary {
+    pub arts: usize,
+    pub variants: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub clean_variants: usize,
+}
+
+/// Audit results of a set of art files.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+    pub summary: AuditSummary,
+    pub results: Vec<ArtAudit>,
+}
+
+/// Create a linter running only the accessibility rules.
+pub fn a11y_linter() -> Linter {
+    Linter::with_registry(RuleRegistry::with_a11y())
+}
+
+/// Audit every variant of an art file.
+///
+/// `component` is the component the art documents; without it variants
+/// are audited as written.
+pub fn audit_art(
+    art: &ArtDescriptor<'_>,
+    component: Option<&AuditComponent>,
+    linter: &Linter,
+) -> ArtAudit {
+    let variants = art
+        .variants
+        .iter()
+        .map(|variant| {
+            let template = expand_variant(variant.template, &variant.args, component);
+            let result = linter.lint_template(&template, art.filename);
+            let violations = result
+                .diagnostics
+                .iter()
+                .map(|d| A11yViolation {
+                    rule: d.rule_name.to_string(),
+                    severity: d.severity,
+                    message: d.message.to_string(),
+                    help: d.help.as_ref().map(|h| h.to_string()),
+                    snippet: template
+                        .get(d.start as usize..d.end as usize)
+                        .unwrap_or_default()
+                        .to_string(),
+                })
+                .collect();
+
+            VariantAudit {
+                variant: variant.name.to_string(),
+                line: variant.loc.as_ref().map(|l| l.start_line).unwrap_or(1),
+                violations,
+            }
+        })
+        .collect();
+
+    ArtAudit {
+        title: art.metadata.title.to_string(),
+        path: art.filename.to_string(),
+        variants,
+    }
+}
+
+impl AuditReport {
+    /// Build a report, computing the summary.
+    pub fn new(results: Vec<ArtAudit>) -> Self {
+        let mut summary = AuditSummary {
+            arts: results.len(),
+            ..Default::default()
+        };
+        for variant in results.iter().flat_map(|r| &r.variants) {
+            summary.variants += 1;
+            if variant.violations.is_empty() {
+                summary.clean_variants += 1;
+            }
+            for violation in &variant.violations {
+                match violation.severity {
+                    Severity::Error => summary.errors += 1,
+                    Severity::Warning => summary.warnings += 1,
+                }
+            }
+        }
+        Self { summary, results }
+    }
+
+    /// Whether any violation is an error.
+    pub fn has_errors(&self) -> bool {
+        self.summary.errors > 0
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Markdown section listing violations per component and variant.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("## Accessibility\n\n");
+        md.push_str(&format!(
+            "{} of {} variants pass ({} errors, {} warnings).\n",
+            self.summary.clean_variants,
+            self.summary.variants,
+            self.summary.errors,
+            self.summary.warnings
+        ));
+
+        for art in &self.results {
+            if art.violation_count() == 0 {
+                continue;
+            }
+            md.push_str(&format!("\n### {}\n\n", art.title));
+            md.push_str("| Variant | Rule | Severity | Message |\n");
+            md.push_str("|---------|------|----------|---------|\n");
+            for variant in &art.variants {
+                for violation in &variant.violations {
+                    let severity = match violation.severity {
+                        Severity::Error => "error",
+                        Severity::Warning => "warning",
+                    };
+                    md.push_str(&format!(
+                        "| {} | `{}` | {} | {} |\n",
+                        variant.variant,
+                        violation.rule,
+                        severity,
+                        violation.message.replace('|', "\\|")
+                    ));
+                }
+            }
+        }
+        md
+    }
+
+    /// Diagnostics in the shared report model, positioned at each variant.
+    pub fn to_report_files(&self) -> Vec<ReportFile> {
+        self.results
+            .iter()
+            .map(|art| {
+                let mut file = ReportFile::new(art.path.clone());
+                for variant in &art.variants {
+                    for violation in &variant.violations {
+                        file.diagnostics.push(ReportDiagnostic {
+                            rule: violation.rule.clone(),
+                            severity: violation.severity,
+                            message: format!("[{}] {}", variant.variant, violation.message),
+                            help: violation.help.clone(),
+                            line: variant.line,
+                            column: 1,
+                            end_line: variant.line,
+                            end_column: 1,
+                        });
+                    }
+                }
+                file
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_art;
+    use crate::types::ArtParseOptions;
+    use vize_carton::Bump;
+
+    const ART: &str = r#"<art title="Avatar" component="./Avatar.vue">
+  <variant name="Described" default>
+    <Self src="/me.png" alt="Me" />
+  </variant>
+  <variant name="Missing alt">
+    <Self src="/me.png" />
+  </variant>
+</art>"#;
+
+    const COMPONENT: &str = r#"<script setup lang="ts">
+defineProps<{ src: string; alt?: string }>()
+</script>
+<template>
+  <img :src="src" :alt="alt" />
+</template>"#;
+
+    fn audit() -> AuditReport {
+        let allocator = Bump::new();
+        let art = parse_art(
+            &allocator,
+            ART,
+            ArtParseOptions {
+                filename: "Avatar.art.vue".to_string(),
+            },
+        )
+        .unwrap();
+        let component = AuditComponent::from_sfc(COMPONENT, "Avatar.vue", "Avatar").unwrap();
+        AuditReport::new(vec![audit_art(&art, Some(&component), &a11y_linter())])
+    }
+
+    #[test]
+    fn test_audit_variants() {
+        let report = audit();
+        let variants = &report.results[0].variants;
+
+        assert!(variants[0].violations.is_empty());
+        assert!(variants[1]
+            .violations
+            .iter()
+            .any(|v| v.rule == "a11y/img-alt" && v.snippet.starts_with("<img")));
+        assert_eq!(report.summary.variants, 2);
+        assert_eq!(report.summary.clean_variants, 1);
+    }
+
+    #[test]
+    fn test_report_outputs() {
+        let report = audit();
+        let files = report.to_report_files();
+
+        assert_eq!(files[0].path, "Avatar.art.vue");
+        assert!(files[0]
+            .diagnostics
+            .iter()
+            .all(|d| d.line == 5 && d.message.starts_with("[Missing alt]")));
+        assert!(report.to_markdown().contains("### Avatar"));
+    }
+}
@@ -4,13 +4,18 @@
 //! Uses intelligent strategies instead of cartesian product to produce
 //! meaningful, manageable variant sets.
 
+pub mod props;
 pub mod strategy;
 pub mod types;
 
+pub use props::props_from_sfc;
 pub use strategy::generate_variants;
 pub use types::{AutogenConfig, AutogenOutput, GeneratedVariant, PropDefinition};
 
+use crate::parse::parse_art;
+use crate::types::{ArtParseError, ArtParseOptions};
 use std::path::Path;
+use vize_carton::Bump;
 
 /// Generate an `.art.vue` file from prop definitions.
 pub fn generate_art_file(
@@ -29,6 +34,100 @@ pub fn generate_art_file(
     }
 }
 
+/// Merge variants for newly added props into an existing `.art.vue` file.
+///
+/// Props already referenced by any variant are left alone, and existing
+/// variants are never rewritten, so hand-written variants survive. Only
+/// variants for the remaining props are appended to the `<art>` block.
+/// The returned `variants` are the ones that were added.
+pub fn update_art_file(
+    existing: &str,
+    component_path: &str,
+    props: &[PropDefinition],
+    config: &AutogenConfig,
+) -> Result<AutogenOutput, ArtParseError> {
+    let component_name = extract_component_name(component_path);
+    let allocator = Bump::new();
+    let descriptor = parse_art(&allocator, existing, ArtParseOptions::default())?;
+
+    let new_props: Vec<PropDefinition> = props
+        .iter()
+        .filter(|prop| {
+            !descriptor
+                .variants
+                .iter()
+                .any(|variant| template_uses_prop(variant.template, &prop.name))
+        })
+        .cloned()
+        .collect();
+
+    let mut variants = Vec::new();
+    if !new_props.is_empty() {
+        let config = AutogenConfig {
+            include_default: descriptor.variants.is_empty() && config.include_default,
+            ..config.clone()
+        };
+        let base_props = strategy::build_default_props(props);
+        for mut variant in generate_variants(&new_props, &component_name, &config) {
+            if descriptor.variants.iter().any(|v| v.name == variant.name) {
+                continue;
+            }
+            for (name, value) in &base_props {
+                variant
+                    .props
+                    .entry(name.clone())
+                    .or_insert_with(|| value.clone());
+            }
+            variants.push(variant);
+        }
+    }
+
+    let mut art_file_content = existing.to_string();
+    if !variants.is_empty() {
+        let close = existing.rfind("</art>").ok_or(ArtParseError::NoArtBlock)?;
+        let mut rendered = String::new();
+        for variant in &variants {
+            render_variant(&mut rendered, &component_name, variant);
+        }
+        art_file_content.insert_str(close, &rendered);
+    }
+
+    Ok(AutogenOutput {
+        variants,
+        art_file_content,
+        component_name,
+    })
+}
+
+/// Check whether a variant template passes the prop, in camelCase or kebab-case.
+fn template_uses_prop(template: &str, prop_name: &str) -> bool {
+    let kebab = to_kebab_case(prop_name);
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+
+    [prop_name, kebab.as_str()].iter().any(|name| {
+        template.match_indices(name).any(|(start, _)| {
+            let before = template[..start].chars().next_back();
+            let after = template[start + name.len()..].chars().next();
+            matches!(before, Some(c) if c.is_whitespace() || c == ':' || c == '.')
+                && !after.is_some_and(is_name_char)
+        })
+    })
+}
+
+/// Convert a camelCase prop name to kebab-case.
+fn to_kebab_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('-');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
 /// Extract component name from file path.
 /// e.g., "./components/MyButton.vue" -> "MyButton"
 fn extract_component_name(component_path: &str) -> String {
@@ -54,25 +153,7 @@ fn render_art_file(
 
     // Variants
     for variant in variants {
-        let attrs = if variant.is_default {
-            format!("name=\"{}\" default", variant.name)
-        } else {
-            format!("name=\"{}\"", variant.name)
-        };
-
-        output.push_str(&format!("  <variant {attrs}>\n"));
-
-        // Build component tag with props
-        let props_str = render_props(&variant.props);
-        if props_str.is_empty() {
-            output.push_str(&format!("    <{component_name} />\n"));
-        } else {
-            output.push_str(&format!("    <{component_name}\n"));
-            output.push_str(&props_str);
-            output.push_str("    />\n");
-        }
-
-        output.push_str("  </variant>\n\n");
+        render_variant(&mut output, component_name, variant);
     }
 
     output.push_str("</art>\n\n");
@@ -87,6 +168,29 @@ fn render_art_file(
     output
 }
 
+/// Render a single `<variant>` block.
+fn render_variant(output: &mut String, component_name: &str, variant: &GeneratedVariant) {
+    let attrs = if variant.is_default {
+        format!("name=\"{}\" default", variant.name)
+    } else {
+        format!("name=\"{}\"", variant.name)
+    };
+
+    output.push_str(&format!("  <variant {attrs}>\n"));
+
+    // Build component tag with props
+    let props_str = render_props(&variant.props);
+    if props_str.is_empty() {
+        output.push_str(&format!("    <{component_name} />\n"));
+    } else {
+        output.push_str(&format!("    <{component_name}\n"));
+        output.push_str(&props_str);
+        output.push_str("    />\n");
+    }
+
+    output.push_str("  </variant>\n\n");
+}
+
 /// Render props as Vue template attributes.
 fn render_props(props: &serde_json::Map<String, serde_json::Value>) -> String {
     let mut lines = Vec::new();
@@ -166,6 +270,80 @@ mod tests {
             .contains("<variant name=\"Default\" default>"));
     }
 
+    #[test]
+    fn test_update_art_file() {
+        let existing = r#"<art title="Button" component="./Button.vue">
+  <variant name="Default" default>
+    <Button label="Hand written" :is-active="true" />
+  </variant>
+</art>
+
+<script setup lang="ts">
+import Button from './Button.vue'
+</script>
+"#;
+        let props = vec![
+            PropDefinition {
+                name: "label".to_string(),
+                prop_type: "string".to_string(),
+                required: true,
+                default_value: None,
+            },
+            PropDefinition {
+                name: "isActive".to_string(),
+                prop_type: "boolean".to_string(),
+                required: false,
+                default_value: None,
+            },
+            PropDefinition {
+                name: "size".to_string(),
+                prop_type: "'sm' | 'lg'".to_string(),
+                required: false,
+                default_value: Some(json!("sm")),
+            },
+        ];
+
+        let output =
+            update_art_file(existing, "./Button.vue", &props, &AutogenConfig::default()).unwrap();
+        let names: Vec<_> = output.variants.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["Sm", "Lg"]);
+        assert!(output
+            .art_file_content
+            .starts_with(&existing[..existing.find("</art>").unwrap()]));
+        assert!(output.art_file_content.contains("<variant name=\"Lg\">"));
+        assert!(output.art_file_content.contains("label=\"Sample text\""));
+        assert!(output.art_file_content.ends_with("</script>\n"));
+    }
+
+    #[test]
+    fn test_update_art_file_up_to_date() {
+        let existing = r#"<art title="Button" component="./Button.vue">
+  <variant name="Default" default>
+    <Button label="Hi" />
+  </variant>
+</art>
+"#;
+        let props = vec![PropDefinition {
+            name: "label".to_string(),
+            prop_type: "string".to_string(),
+            required: true,
+            default_value: None,
+        }];
+
+        let output =
+            update_art_file(existing, "./Button.vue", &props, &AutogenConfig::default()).unwrap();
+        assert!(output.variants.is_empty());
+        assert_eq!(output.art_file_content, existing);
+    }
+
+    #[test]
+    fn test_template_uses_prop() {
+        assert!(template_uses_prop("<A :is-active=\"x\" />", "isActive"));
+        assert!(template_uses_prop("<A\n  label=\"x\" />", "label"));
+        assert!(!template_uses_prop("<A labelText=\"x\" />", "label"));
+        assert!(!template_uses_prop("<A title=\"label\" />", "label"));
+    }
+
     #[test]
     fn test_render_props() {
         let mut props = serde_json::Map::new();
@@ -0,0 +1,171 @@
+//! Prop definitions derived from component analysis.
+//!
+//! Runs the `<script setup>` block of a component through `vize_croquis`
+//! so variants can be generated straight from `defineProps`, both the
+//! type-based and the runtime form, including `withDefaults` values.
+
+use super::types::PropDefinition;
+use serde_json::{json, Value};
+use vize_atelier_sfc::{parse_sfc, SfcError, SfcParseOptions};
+use vize_croquis::{Analyzer, Croquis};
+
+/// Analyze a Vue SFC and collect its props.
+///
+/// Components without `<script setup>` have no props to derive and
+/// yield an empty list.
+pub fn props_from_sfc(source: &str, filename: &str) -> Result<Vec<PropDefinition>, SfcError> {
+    let Some(croquis) = analyze_script_setup(source, filename)? else {
+        return Ok(Vec::new());
+    };
+
+    Ok(croquis
+        .macros
+        .props()
+        .iter()
+        .map(|prop| PropDefinition {
+            name: prop.name.to_string(),
+            prop_type: prop.prop_type.as_deref().unwrap_or("unknown").to_string(),
+            required: prop.required,
+            default_value: prop.default_value.as_deref().and_then(literal_to_json),
+        })
+        .collect())
+}
+
+/// Run croquis over the `<script setup>` block, if the component has one.
+pub(crate) fn analyze_script_setup(
+    source: &str,
+    filename: &str,
+) -> Result<Option<Croquis>, SfcError> {
+    let descriptor = parse_sfc(
+        source,
+        SfcParseOptions {
+            filename: filename.to_string(),
+            ..Default::default()
+        },
+    )?;
+
+    let Some(script_setup) = descriptor.script_setup else {
+        return Ok(None);
+    };
+
+    let mut analyzer = Analyzer::for_lint();
+    analyzer.analyze_script_setup(&script_setup.content);
+    Ok(Some(analyzer.finish()))
+}
+
+/// Convert a JS default value expression into JSON.
+///
+/// Only literals (and factories returning a literal) are understood;
+/// anything computed at runtime yields `None`.
+pub(crate) fn literal_to_json(expr: &str) -> Option<Value> {
+    let expr = expr.trim();
+
+    // Factory defaults: `() => []`, `() => ({ a: 1 })`
+    if let Some(body) = expr.strip_prefix("()").map(str::trim_start) {
+        let body = body.strip_prefix("=>")?.trim();
+        let body = body
+            .strip_prefix('(')
+            .and_then(|b| b.strip_suffix(')'))
+            .unwrap_or(body);
+        return literal_to_json(body);
+    }
+
+    match expr {
+        "true" => return Some(json!(true)),
+        "false" => return Some(json!(false)),
+        "null" => return Some(Value::Null),
+        "undefined" => return None,
+        _ => {}
+    }
+
+    for quote in ['\'', '"', '`'] {
+        if let Some(inner) = expr.strip_prefix(quote).and_then(|e| e.strip_suffix(quote)) {
+            if quote == '`' && inner.contains("${") {
+                return None;
+            }
+            return Some(json!(inner
+                .replace(&format!("\\{quote}"), &quote.to_string())
+                .replace("\\\\", "\\")));
+        }
+    }
+
+    if let Ok(n) = expr.parse::<i64>() {
+        return Some(json!(n));
+    }
+    if let Ok(n) = expr.parse::<f64>() {
+        return Some(json!(n));
+    }
+
+    // Arrays and objects that happen to be valid JSON
+    serde_json::from_str(expr).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_based_props() {
+        let source = r#"<script setup lang="ts">
+interface Props {
+  label: string
+  size?: 'sm' | 'md' | 'lg'
+  variant?: 'primary' | 'secondary'
+  disabled?: boolean
+  items?: string[]
+}
+const props = withDefaults(defineProps<Props>(), {
+  variant: 'primary',
+  disabled: false,
+  items: () => [],
+})
+</script>"#;
+        let props = props_from_sfc(source, "Button.vue").unwrap();
+        let get = |name: &str| props.iter().find(|p| p.name == name).unwrap();
+
+        assert_eq!(props.len(), 5);
+        assert_eq!(get("label").prop_type, "string");
+        assert!(get("label").required);
+        assert_eq!(get("size").prop_type, "'sm' | 'md' | 'lg'");
+        assert_eq!(get("variant").prop_type, "'primary' | 'secondary'");
+        assert_eq!(get("variant").default_value, Some(json!("primary")));
+        assert_eq!(get("disabled").default_value, Some(json!(false)));
+        assert_eq!(get("items").default_value, Some(json!([])));
+    }
+
+    #[test]
+    fn test_runtime_props() {
+        let source = r#"<script setup>
+defineProps({
+  title: { type: String, required: true },
+  count: { type: Number, default: 3 },
+  open: Boolean,
+})
+</script>"#;
+        let props = props_from_sfc(source, "Card.vue").unwrap();
+        let get = |name: &str| props.iter().find(|p| p.name == name).unwrap();
+
+        assert_eq!(get("title").prop_type, "string");
+        assert!(get("title").required);
+        assert_eq!(get("count").prop_type, "number");
+        assert_eq!(get("count").default_value, Some(json!(3)));
+        assert_eq!(get("open").prop_type, "boolean");
+    }
+
+    #[test]
+    fn test_no_script_setup() {
+        let props = props_from_sfc("<template><div /></template>", "A.vue").unwrap();
+        assert!(props.is_empty());
+    }
+
+    #[test]
+    fn test_literal_to_json() {
+        assert_eq!(literal_to_json("'a'"), Some(json!("a")));
+        assert_eq!(literal_to_json("\"it\\\"s\""), Some(json!("it\"s")));
+        assert_eq!(literal_to_json("`x${y}`"), None);
+        assert_eq!(literal_to_json("1.5"), Some(json!(1.5)));
+        assert_eq!(literal_to_json("() => ({})"), Some(json!({})));
+        assert_eq!(literal_to_json("Date.now()"), None);
+        assert_eq!(literal_to_json("undefined"), None);
+    }
+}
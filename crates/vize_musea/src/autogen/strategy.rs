@@ -132,7 +132,7 @@ pub fn generate_variants(
 }
 
 /// Build a props map with all default values.
-fn build_default_props(props: &[PropDefinition]) -> Map<String, Value> {
+pub(crate) fn build_default_props(props: &[PropDefinition]) -> Map<String, Value> {
     let mut map = Map::new();
     for prop in props {
         if let Some(ref default) = prop.default_value {
@@ -0,0 +1,281 @@
+//! Component API documentation.
+//!
+//! Extracts props, emits, slots and exposed members of the documented
+//! component through `vize_croquis` macro analysis, with descriptions
+//! taken from JSDoc comments.
+
+use serde::{Deserialize, Serialize};
+use vize_atelier_sfc::SfcError;
+
+use crate::autogen::props::analyze_script_setup;
+
+/// Public API of a component.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentApi {
+    pub props: Vec<PropDoc>,
+    pub emits: Vec<EmitDoc>,
+    pub slots: Vec<SlotDoc>,
+    pub exposed: Vec<ExposeDoc>,
+}
+
+/// A documented prop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropDoc {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub prop_type: Option<String>,
+    pub required: bool,
+    /// Default value as written in the source
+    pub default_value: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A documented event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmitDoc {
+    pub name: String,
+    pub payload_type: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A documented slot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotDoc {
+    pub name: String,
+    /// Type of the slot props
+    pub props_type: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A member exposed through `defineExpose`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposeDoc {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub expose_type: Option<String>,
+    pub description: Option<String>,
+}
+
+impl ComponentApi {
+    /// Whether the component declares no API at all.
+    pub fn is_empty(&self) -> bool {
+        self.props.is_empty()
+            && self.emits.is_empty()
+            && self.slots.is_empty()
+            && self.exposed.is_empty()
+    }
+}
+
+/// Extract the API of a Vue SFC from its `<script setup>` macros.
+pub fn extract_component_api(source: &str, filename: &str) -> Result<ComponentApi, SfcError> {
+    let Some(croquis) = analyze_script_setup(source, filename)? else {
+        return Ok(ComponentApi::default());
+    };
+    let macros = &croquis.macros;
+    let string = |s: &Option<vize_carton::CompactString>| s.as_ref().map(|s| s.to_string());
+
+    Ok(ComponentApi {
+        props: macros
+            .props()
+            .iter()
+            .map(|p| PropDoc {
+                name: p.name.to_string(),
+                prop_type: string(&p.prop_type),
+                required: p.required,
+                default_value: string(&p.default_value),
+                description: string(&p.description),
+            })
+            .collect(),
+        emits: macros
+            .emits()
+            .iter()
+            .map(|e| EmitDoc {
+                name: e.name.to_string(),
+                payload_type: string(&e.payload_type),
+                description: string(&e.description),
+            })
+            .collect(),
+        slots: macros
+            .slots()
+            .iter()
+            .map(|s| SlotDoc {
+                name: s.name.to_string(),
+                props_type: string(&s.props_type),
+                description: string(&s.description),
+            })
+            .collect(),
+        exposed: macros
+            .exposes()
+            .iter()
+            .map(|e| ExposeDoc {
+                name: e.name.to_string(),
+                expose_type: string(&e.expose_type),
+                description: string(&e.description),
+            })
+            .collect(),
+    })
+}
+
+/// Render the API as Markdown tables (`## Props`, `## Events`, ...).
+pub fn generate_api_doc(api: &ComponentApi) -> String {
+    let mut md = String::with_capacity(1024);
+
+    if !api.props.is_empty() {
+        md.push_str("## Props\n\n");
+        md.push_str("| Name | Type | Default | Required | Description |\n");
+        md.push_str("|------|------|---------|----------|-------------|\n");
+        for prop in &api.props {
+            md.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} |\n",
+                prop.name,
+                code_cell(prop.prop_type.as_deref()),
+                code_cell(prop.default_value.as_deref()),
+                if prop.required { "Yes" } else { "No" },
+                text_cell(prop.description.as_deref()),
+            ));
+        }
+        md.push('\n');
+    }
+
+    if !api.emits.is_empty() {
+        md.push_str("## Events\n\n");
+        md.push_str("| Name | Payload | Description |\n");
+        md.push_str("|------|---------|-------------|\n");
+        for emit in &api.emits {
+            md.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                emit.name,
+                code_cell(emit.payload_type.as_deref()),
+                text_cell(emit.description.as_deref()),
+            ));
+        }
+        md.push('\n');
+    }
+
+    if !api.slots.is_empty() {
+        md.push_str("## Slots\n\n");
+        md.push_str("| Name | Props | Description |\n");
+        md.push_str("|------|-------|-------------|\n");
+        for slot in &api.slots {
+            md.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                slot.name,
+                code_cell(slot.props_type.as_deref()),
+                text_cell(slot.description.as_deref()),
+            ));
+        }
+        md.push('\n');
+    }
+
+    if !api.exposed.is_empty() {
+        md.push_str("## Exposed\n\n");
+        md.push_str("| Name | Type | Description |\n");
+        md.push_str("|------|------|-------------|\n");
+        for expose in &api.exposed {
+            md.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                expose.name,
+                code_cell(expose.expose_type.as_deref()),
+                text_cell(expose.description.as_deref()),
+            ));
+        }
+        md.push('\n');
+    }
+
+    md
+}
+
+/// Table cell with inline code, `-` when absent.
+fn code_cell(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("`{}`", escape_cell(value)),
+        None => "-".to_string(),
+    }
+}
+
+/// Table cell with plain text, `-` when absent.
+fn text_cell(value: Option<&str>) -> String {
+    value.map(escape_cell).unwrap_or_else(|| "-".to_string())
+}
+
+/// Keep a value on one table row.
+fn escape_cell(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUTTON: &str = r#"<script setup lang="ts">
+interface Props {
+  /** Visual style */
+  variant?: 'primary' | 'secondary'
+  label: string
+}
+withDefaults(defineProps<Props>(), { variant: 'primary' })
+defineEmits<{
+  /** Fired when clicked */
+  (e: 'click', event: MouseEvent): void
+}>()
+defineSlots<{
+  /** Button content */
+  default(props: { pressed: boolean }): any
+}>()
+defineExpose({
+  /** Focus the button */
+  focus,
+})
+</script>"#;
+
+    #[test]
+    fn test_extract_component_api() {
+        let api = extract_component_api(BUTTON, "Button.vue").unwrap();
+
+        assert_eq!(api.props.len(), 2);
+        assert_eq!(
+            api.props[0].prop_type.as_deref(),
+            Some("'primary' | 'secondary'")
+        );
+        assert_eq!(api.props[0].default_value.as_deref(), Some("'primary'"));
+        assert_eq!(api.props[0].description.as_deref(), Some("Visual style"));
+        assert!(api.props[1].required);
+        assert_eq!(api.emits[0].payload_type.as_deref(), Some("MouseEvent"));
+        assert_eq!(
+            api.slots[0].props_type.as_deref(),
+            Some("{ pressed: boolean }")
+        );
+        assert_eq!(api.exposed[0].name, "focus");
+    }
+
+    #[test]
+    fn test_generate_api_doc() {
+        let api = extract_component_api(BUTTON, "Button.vue").unwrap();
+        let md = generate_api_doc(&api);
+
+        assert!(md.contains("## Props"));
+        assert!(md.contains(
+            "| `variant` | `'primary' \\| 'secondary'` | `'primary'` | No | Visual style |"
+        ));
+        assert!(md.contains("| `label` | `string` | - | Yes | - |"));
+        assert!(md.contains("| `click` | `MouseEvent` | Fired when clicked |"));
+        assert!(md.contains("| `default` | `{ pressed: boolean }` | Button content |"));
+        assert!(md.contains("| `focus` | - | Focus the button |"));
+    }
+
+    #[test]
+    fn test_empty_api() {
+        let api = extract_component_api("<template><div /></template>", "A.vue").unwrap();
+        assert!(api.is_empty());
+        assert!(generate_api_doc(&api).is_empty());
+    }
+}
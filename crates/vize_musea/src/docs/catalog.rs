@@ -35,6 +35,10 @@ pub struct CatalogEntry {
 
     /// Display order.
     pub order: Option<u32>,
+
+    /// Accessibility violations found by `vize musea audit`, if audited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub a11y_violations: Option<usize>,
 }
 
 impl CatalogEntry {
@@ -57,6 +61,7 @@ impl CatalogEntry {
             doc_path,
             source_path: art.filename.to_string(),
             order: art.metadata.order,
+            a11y_violations: None,
         }
     }
 }
@@ -222,8 +227,15 @@ pub fn generate_tags_index(entries: &[CatalogEntry], _options: &DocOptions) -> C
 fn generate_component_table(entries: &[&CatalogEntry]) -> String {
     let mut md = String::new();
 
-    md.push_str("| Component | Description | Variants | Status |\n");
-    md.push_str("|-----------|-------------|----------|--------|\n");
+    // The A11y column only appears once the catalog has been audited
+    let audited = entries.iter().any(|e| e.a11y_violations.is_some());
+    if audited {
+        md.push_str("| Component | Description | Variants | Status | A11y |\n");
+        md.push_str("|-----------|-------------|----------|--------|------|\n");
+    } else {
+        md.push_str("| Component | Description | Variants | Status |\n");
+        md.push_str("|-----------|-------------|----------|--------|\n");
+    }
 
     // Sort by order, then by title
     let mut sorted: Vec<_> = entries.iter().collect();
@@ -255,9 +267,18 @@ fn generate_component_table(entries: &[&CatalogEntry]) -> String {
         };
 
         md.push_str(&format!(
-            "| [{}]({}) | {} | {} | {} |\n",
+            "| [{}]({}) | {} | {} | {} |",
             entry.title, entry.doc_path, desc, entry.variant_count, status
         ));
+        if audited {
+            let a11y = match entry.a11y_violations {
+                Some(0) => "✅".to_string(),
+                Some(count) => format!("⚠️ {}", count),
+                None => "-".to_string(),
+            };
+            md.push_str(&format!(" {} |", a11y));
+        }
+        md.push('\n');
     }
 
     md.push('\n');
@@ -312,7 +333,7 @@ fn group_by_tag(entries: &[CatalogEntry]) -> FxHashMap<&str, Vec<&CatalogEntry>>
 
 /// Convert a string to a URL-safe slug.
 #[inline]
-fn slugify(s: &str) -> String {
+pub(crate) fn slugify(s: &str) -> String {
     s.chars()
         .map(|c| {
             if c.is_alphanumeric() {
@@ -343,6 +364,7 @@ mod tests {
             doc_path: format!("{}.md", slugify(title)),
             source_path: format!("{}.art.vue", slugify(title)),
             order: None,
+            a11y_violations: None,
         }
     }
 
@@ -0,0 +1,275 @@
+//! HTML rendering of generated documentation.
+//!
+//! Covers the Markdown subset produced by this module (headings, tables,
+//! lists, blockquotes, fenced code, emphasis and links), so pages can be
+//! served without a Markdown toolchain.
+
+use super::catalog::slugify;
+
+/// Render generated Markdown as an HTML fragment.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let mut html = String::with_capacity(markdown.len() * 2);
+    let mut lines = markdown.lines().peekable();
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        let is_block_start = trimmed.is_empty()
+            || trimmed.starts_with("```")
+            || trimmed.starts_with('#')
+            || trimmed.starts_with('>')
+            || trimmed.starts_with("- ")
+            || trimmed.starts_with('|')
+            || trimmed == "---";
+        if is_block_start {
+            flush_paragraph(&mut html, &mut paragraph);
+        }
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            let mut code = String::new();
+            for line in lines.by_ref() {
+                if line.trim() == "```" {
+                    break;
+                }
+                code.push_str(line);
+                code.push('\n');
+            }
+            if lang.is_empty() {
+                html.push_str("<pre><code>");
+            } else {
+                html.push_str(&format!(
+                    "<pre><code class=\"language-{}\">",
+                    escape_html(lang)
+                ));
+            }
+            html.push_str(&escape_html(&code));
+            html.push_str("</code></pre>\n");
+        } else if let Some((level, text)) = heading(trimmed) {
+            html.push_str(&format!(
+                "<h{level} id=\"{}\">{}</h{level}>\n",
+                slugify(&text.replace('`', "")),
+                render_inline(text)
+            ));
+        } else if trimmed == "---" {
+            html.push_str("<hr>\n");
+        } else if trimmed.starts_with('>') {
+            let mut quote = vec![trimmed.trim_start_matches('>').trim()];
+            while let Some(next) = lines.peek().map(|l| l.trim()) {
+                if !next.starts_with('>') {
+                    break;
+                }
+                quote.push(next.trim_start_matches('>').trim());
+                lines.next();
+            }
+            html.push_str(&format!(
+                "<blockquote><p>{}</p></blockquote>\n",
+                render_inline(&quote.join(" "))
+            ));
+        } else if let Some(item) = trimmed.strip_prefix("- ") {
+            html.push_str("<ul>\n");
+            html.push_str(&format!("<li>{}</li>\n", render_inline(item)));
+            while let Some(item) = lines.peek().and_then(|l| l.trim().strip_prefix("- ")) {
+                html.push_str(&format!("<li>{}</li>\n", render_inline(item)));
+                lines.next();
+            }
+            html.push_str("</ul>\n");
+        } else if trimmed.starts_with('|') {
+            let mut rows = vec![trimmed];
+            while let Some(next) = lines.peek().map(|l| l.trim()) {
+                if !next.starts_with('|') {
+                    break;
+                }
+                rows.push(next);
+                lines.next();
+            }
+            render_table(&mut html, &rows);
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    flush_paragraph(&mut html, &mut paragraph);
+
+    html
+}
+
+/// Escape text for HTML content and attribute values.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn flush_paragraph(html: &mut String, paragraph: &mut Vec<&str>) {
+    if !paragraph.is_empty() {
+        html.push_str(&format!("<p>{}</p>\n", render_inline(&paragraph.join(" "))));
+        paragraph.clear();
+    }
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.bytes().take_while(|&b| b == b'#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then_some((level, text.trim()))
+}
+
+fn render_table(html: &mut String, rows: &[&str]) {
+    let is_separator = |row: &str| row.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '));
+
+    html.push_str("<table>\n");
+    let (header, body) = match rows {
+        [header, separator, body @ ..] if is_separator(separator) => (Some(*header), body),
+        _ => (None, rows),
+    };
+    if let Some(header) = header {
+        html.push_str("<thead><tr>");
+        for cell in split_cells(header) {
+            html.push_str(&format!("<th>{}</th>", render_inline(&cell)));
+        }
+        html.push_str("</tr></thead>\n");
+    }
+    html.push_str("<tbody>\n");
+    for row in body {
+        html.push_str("<tr>");
+        for cell in split_cells(row) {
+            html.push_str(&format!("<td>{}</td>", render_inline(&cell)));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n");
+}
+
+/// Split a table row on unescaped pipes.
+fn split_cells(row: &str) -> Vec<String> {
+    let inner = row.trim().trim_start_matches('|');
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+/// Render inline code, emphasis and links.
+fn render_inline(text: &str) -> String {
+    let mut html = String::with_capacity(text.len() + 16);
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('`') {
+            if let Some(end) = after.find('`') {
+                html.push_str(&format!("<code>{}</code>", escape_html(&after[..end])));
+                rest = &after[end + 1..];
+                continue;
+            }
+        }
+        if let Some(after) = rest.strip_prefix("**") {
+            if let Some(end) = after.find("**") {
+                html.push_str(&format!(
+                    "<strong>{}</strong>",
+                    render_inline(&after[..end])
+                ));
+                rest = &after[end + 2..];
+                continue;
+            }
+        }
+        if let Some(after) = rest.strip_prefix('*') {
+            if let Some(end) = after.find('*').filter(|&end| end > 0) {
+                html.push_str(&format!("<em>{}</em>", render_inline(&after[..end])));
+                rest = &after[end + 1..];
+                continue;
+            }
+        }
+        if let Some(after) = rest.strip_prefix('[') {
+            if let Some((label, href, len)) = link(after) {
+                html.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(href),
+                    render_inline(label)
+                ));
+                rest = &after[len..];
+                continue;
+            }
+        }
+
+        let c = rest.chars().next().unwrap_or_default();
+        html.push_str(&escape_html(&rest[..c.len_utf8()]));
+        rest = &rest[c.len_utf8()..];
+    }
+
+    html
+}
+
+/// Parse `label](href)` following a `[`, returning the consumed length.
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find("](")?;
+    let href_start = label_end + 2;
+    let href_end = href_start + text[href_start..].find(')')?;
+    Some((
+        &text[..label_end],
+        &text[href_start..href_end],
+        href_end + 1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let html = markdown_to_html(
+            "# Button `v2`\n\nA *small* **button**.\n\n> **3** components\n\n- [Atoms](#atoms)\n- Two\n\n```vue\n<Button />\n```\n\n---\n",
+        );
+
+        assert!(html.contains("<h1 id=\"button-v2\">Button <code>v2</code></h1>"));
+        assert!(html.contains("<p>A <em>small</em> <strong>button</strong>.</p>"));
+        assert!(html.contains("<blockquote><p><strong>3</strong> components</p></blockquote>"));
+        assert!(html.contains("<li><a href=\"#atoms\">Atoms</a></li>\n<li>Two</li>"));
+        assert!(html.contains("<pre><code class=\"language-vue\">&lt;Button /&gt;\n</code></pre>"));
+        assert!(html.contains("<hr>"));
+    }
+
+    #[test]
+    fn test_table() {
+        let html =
+            markdown_to_html("| Name | Type |\n|------|------|\n| `size` | `'sm' \\| 'lg'` |\n");
+
+        assert!(html.contains("<thead><tr><th>Name</th><th>Type</th></tr></thead>"));
+        assert!(html.contains(
+            "<tr><td><code>size</code></td><td><code>&#39;sm&#39; | &#39;lg&#39;</code></td></tr>"
+        ));
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Markdown generation for individual Art components.
 
+use super::api::{generate_api_doc, ComponentApi};
 use super::{DocOptions, DocOutput};
 use crate::types::{ArtDescriptor, ArtStatus, ArtVariant};
 
@@ -12,6 +13,18 @@ use crate::types::{ArtDescriptor, ArtStatus, ArtVariant};
 /// - Variant documentation with templates
 #[inline]
 pub fn generate_component_doc(art: &ArtDescriptor<'_>, options: &DocOptions) -> DocOutput {
+    generate_component_doc_with_api(art, None, options)
+}
+
+/// Generate Markdown documentation including the component's API.
+///
+/// Props, events, slots and exposed members are rendered between the
+/// variants and the source section.
+pub fn generate_component_doc_with_api(
+    art: &ArtDescriptor<'_>,
+    api: Option<&ComponentApi>,
+    options: &DocOptions,
+) -> DocOutput {
     let mut md = String::with_capacity(4096);
 
     // Title
@@ -48,6 +61,11 @@ pub fn generate_component_doc(art: &ArtDescriptor<'_>, options: &DocOptions) ->
         md.push_str(&generate_variant_doc(variant, options));
     }
 
+    // Component API
+    if let Some(api) = api {
+        md.push_str(&generate_api_doc(api));
+    }
+
     // Component path
     if let Some(component) = art.metadata.component {
         md.push_str("## Source\n\n");
@@ -65,6 +83,7 @@ pub fn generate_component_doc(art: &ArtDescriptor<'_>, options: &DocOptions) ->
         title: art.metadata.title.to_string(),
         category: art.metadata.category.map(|s| s.to_string()),
         variant_count: art.variants.len(),
+        api: api.cloned(),
     }
 }
 
@@ -24,11 +24,18 @@
 //! println!("{}", output.markdown);
 //! ```
 
+mod api;
 mod catalog;
+mod html;
 mod markdown;
 
+pub use api::{
+    extract_component_api, generate_api_doc, ComponentApi, EmitDoc, ExposeDoc, PropDoc, SlotDoc,
+};
+pub(crate) use catalog::slugify;
 pub use catalog::{generate_catalog, generate_category_index, generate_tags_index, CatalogEntry};
-pub use markdown::{generate_component_doc, generate_variant_doc};
+pub use html::{escape_html, markdown_to_html};
+pub use markdown::{generate_component_doc, generate_component_doc_with_api, generate_variant_doc};
 
 use serde::{Deserialize, Serialize};
 
@@ -95,6 +102,10 @@ pub struct DocOutput {
 
     /// Number of variants documented.
     pub variant_count: usize,
+
+    /// API of the documented component, when its source was provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<ComponentApi>,
 }
 
 /// Output of catalog generation.
@@ -147,6 +158,36 @@ mod tests {
         assert_eq!(output.variant_count, 2);
     }
 
+    #[test]
+    fn test_generate_component_doc_with_api() {
+        let allocator = Bump::new();
+        let art_source = r#"
+<art title="Button" component="./Button.vue">
+  <variant name="Primary" default>
+    <Button>Click</Button>
+  </variant>
+</art>
+"#;
+        let component = r#"<script setup lang="ts">
+defineProps<{
+  /** Text shown in the button */
+  label: string
+}>()
+</script>"#;
+
+        let art = parse_art(&allocator, art_source, ArtParseOptions::default()).unwrap();
+        let api = extract_component_api(component, "Button.vue").unwrap();
+        let output = generate_component_doc_with_api(&art, Some(&api), &DocOptions::default());
+
+        let props = output.markdown.find("## Props").unwrap();
+        assert!(output.markdown.find("## Variants").unwrap() < props);
+        assert!(props < output.markdown.find("## Source").unwrap());
+        assert!(output
+            .markdown
+            .contains("| `label` | `string` | - | Yes | Text shown in the button |"));
+        assert_eq!(output.api.unwrap().props.len(), 1);
+    }
+
     #[test]
     fn test_generate_catalog() {
         let allocator = Bump::new();
@@ -0,0 +1,545 @@
+//! Static export of the Musea gallery.
+//!
+//! Builds a self-contained, statically hostable gallery from `*.art.vue`
+//! files without a dev server or bundler:
+//!
+//! - `assets/variants/<art>/<variant>.js` — one ES module entry per
+//!   variant, compiled with `vize_atelier_sfc` together with the
+//!   components it imports
+//! - `variants/<art>/<variant>.html` — a page mounting a single variant
+//! - `index.html`, `tags.html`, `categories/*.html`, `components/*.html`
+//!   — navigation rendered from the catalog and component docs
+//! - `manifest.json` — the gallery structure for downstream tools
+//!   (VRT, MCP server)
+
+mod modules;
+mod pages;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use vize_carton::{Bump, FxHashMap};
+
+use crate::docs::{
+    extract_component_api, generate_catalog, generate_category_index,
+    generate_component_doc_with_api, generate_tags_index, markdown_to_html, slugify, CatalogEntry,
+    ComponentApi, DocOptions,
+};
+use crate::types::{ArtDescriptor, ArtParseOptions, ArtStatus, ArtVariant, ViewportConfig};
+use modules::{write_file, ModuleGraph};
+
+/// Version of the `manifest.json` format.
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Where variant pages load Vue from.
+#[derive(Debug, Clone)]
+pub enum VueRuntime {
+    /// Browser ESM build (`vue.esm-browser.prod.js`) copied into the gallery
+    File(PathBuf),
+    /// URL referenced from the import map
+    Url(String),
+}
+
+/// Options for [`build_gallery`].
+#[derive(Debug, Clone)]
+pub struct GalleryOptions {
+    /// Project root; output mirrors source paths relative to it
+    pub root: PathBuf,
+    /// Output directory
+    pub out_dir: PathBuf,
+    /// Gallery title
+    pub title: String,
+    /// Vue runtime for the variant pages
+    pub vue: VueRuntime,
+}
+
+/// Errors from building the gallery.
+#[derive(Debug, thiserror::Error)]
+pub enum GalleryError {
+    #[error("{path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{path}: {message}")]
+    Art { path: String, message: String },
+
+    #[error("{path}: {message}")]
+    Compile { path: String, message: String },
+}
+
+/// Contents of `manifest.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryManifest {
+    pub version: u32,
+    pub title: String,
+    pub components: Vec<GalleryComponent>,
+    pub categories: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+/// A documented component (one art file).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryComponent {
+    pub title: String,
+    pub slug: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub status: ArtStatus,
+    /// Art file, relative to the root
+    pub art_path: String,
+    /// Documented component, relative to the root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_path: Option<String>,
+    /// Documentation page, relative to the output directory
+    pub page: String,
+    pub variants: Vec<GalleryVariant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<ComponentApi>,
+}
+
+/// A variant with its compiled entry and preview page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryVariant {
+    pub name: String,
+    pub slug: String,
+    pub is_default: bool,
+    pub skip_vrt: bool,
+    pub args: serde_json::Map<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewport: Option<ViewportConfig>,
+    /// ES module exporting the variant component, relative to the output directory
+    pub entry: String,
+    /// Page mounting only this variant, relative to the output directory
+    pub page: String,
+}
+
+/// Result of [`build_gallery`].
+#[derive(Debug, Clone)]
+pub struct GalleryBuild {
+    pub manifest: GalleryManifest,
+    /// Unresolved imports, compiler warnings and bare imports missing
+    /// from the import map
+    pub warnings: Vec<String>,
+}
+
+/// Build the static gallery for `art_files` into `options.out_dir`.
+pub fn build_gallery(
+    art_files: &[PathBuf],
+    options: &GalleryOptions,
+) -> Result<GalleryBuild, GalleryError> {
+    let mut graph = ModuleGraph::new(&options.root, &options.out_dir);
+    let mut components = Vec::with_capacity(art_files.len());
+    let mut entries = Vec::with_capacity(art_files.len());
+    let mut docs = Vec::with_capacity(art_files.len());
+    let mut slugs: FxHashMap<String, usize> = FxHashMap::default();
+
+    for art_path in art_files {
+        let source = read(art_path)?;
+        let allocator = Bump::new();
+        let art = crate::parse_art(
+            &allocator,
+            &source,
+            ArtParseOptions {
+                filename: graph.relative_source(art_path),
+            },
+        )
+        .map_err(|e| GalleryError::Art {
+            path: art_path.display().to_string(),
+            message: e.to_string(),
+        })?;
+
+        let slug = unique_slug(&mut slugs, &slugify(art.metadata.title));
+        let component = build_component(&mut graph, art_path, &art, slug)?;
+
+        let mut entry = CatalogEntry::from_descriptor(&art, "");
+        entry.doc_path = component.page.clone();
+        entry.source_path = component.art_path.clone();
+
+        let doc = generate_component_doc_with_api(
+            &art,
+            component.api.as_ref(),
+            &DocOptions {
+                include_templates: true,
+                include_metadata: true,
+                include_toc: true,
+                toc_threshold: 5,
+                ..Default::default()
+            },
+        );
+
+        entries.push(entry);
+        docs.push(doc.markdown);
+        components.push(component);
+    }
+
+    let doc_options = DocOptions {
+        title: Some(options.title.clone()),
+        include_metadata: true,
+        ..Default::default()
+    };
+    let catalog = generate_catalog(&entries, &doc_options);
+    let tags = generate_tags_index(&entries, &doc_options);
+
+    let manifest = GalleryManifest {
+        version: MANIFEST_VERSION,
+        title: options.title.clone(),
+        components,
+        categories: catalog.categories.clone(),
+        tags: catalog.tags.clone(),
+    };
+    let out_dir = &options.out_dir;
+    let page = |path: &str, title: &str, body: &str| {
+        let html = pages::layout(
+            path,
+            &options.title,
+            title,
+            &manifest.components,
+            &manifest.categories,
+            body,
+        );
+        write_file(&out_dir.join(path), &html)
+    };
+
+    page(
+        "index.html",
+        "Catalog",
+        &markdown_to_html(&catalog.markdown),
+    )?;
+    page(
+        "tags.html",
+        "Tags",
+        &markdown_to_html(&relink(&tags.markdown, "")),
+    )?;
+    for category in &manifest.categories {
+        let index = generate_category_index(&entries, category, &doc_options);
+        let path = pages::category_page(category);
+        page(
+            &path,
+            category,
+            &markdown_to_html(&relink(&index.markdown, "../")),
+        )?;
+    }
+    for (component, markdown) in manifest.components.iter().zip(&docs) {
+        let body = format!(
+            "{}{}",
+            pages::previews(&component.page, component),
+            markdown_to_html(markdown)
+        );
+        page(&component.page, &component.title, &body)?;
+        for variant in &component.variants {
+            let html = pages::variant_page(component, variant, &options.vue);
+            write_file(&out_dir.join(&variant.page), &html)?;
+        }
+    }
+
+    write_file(&out_dir.join(pages::CSS_ASSET), &graph.css)?;
+    if let VueRuntime::File(vue) = &options.vue {
+        let target = out_dir.join(pages::VUE_ASSET);
+        fs::copy(vue, &target).map_err(|source| GalleryError::Io {
+            path: vue.display().to_string(),
+            source,
+        })?;
+    }
+
+    let manifest_json = serde_json::to_string_pretty(&manifest).unwrap_or_default();
+    write_file(&out_dir.join("manifest.json"), &manifest_json)?;
+
+    let mut warnings = graph.warnings;
+    warnings.extend(
+        graph
+            .externals
+            .iter()
+            .filter(|spec| spec.as_str() != "vue")
+            .map(|spec| format!("bare import '{}' is not bundled", spec)),
+    );
+
+    Ok(GalleryBuild { manifest, warnings })
+}
+
+/// Compile the variants of one art file and describe them.
+fn build_component(
+    graph: &mut ModuleGraph<'_>,
+    art_path: &Path,
+    art: &ArtDescriptor<'_>,
+    slug: String,
+) -> Result<GalleryComponent, GalleryError> {
+    let art_dir = art_path.parent().unwrap_or(Path::new(""));
+    let component_file = art.metadata.component.map(|c| art_dir.join(c));
+    let component_name = art
+        .metadata
+        .component
+        .and_then(|c| Path::new(c).file_stem())
+        .map(|stem| stem.to_string_lossy().into_owned());
+
+    let api = match &component_file {
+        Some(file) if file.is_file() => {
+            let source = read(file)?;
+            let filename = graph.relative_source(file);
+            Some(
+                extract_component_api(&source, &filename).map_err(|e| GalleryError::Compile {
+                    path: file.display().to_string(),
+                    message: e.message,
+                })?,
+            )
+        }
+        _ => None,
+    };
+
+    let mut variant_slugs = FxHashMap::default();
+    let mut variants = Vec::with_capacity(art.variants.len());
+    for variant in &art.variants {
+        let variant_slug = unique_slug(&mut variant_slugs, &slugify(variant.name));
+        let entry = format!("assets/variants/{}/{}.js", slug, variant_slug);
+        let page = format!("variants/{}/{}.html", slug, variant_slug);
+
+        let sfc = variant_sfc(art, variant, component_name.as_deref());
+        let virtual_path = art_dir.join(format!("{}--{}.vue", slug, variant_slug));
+        graph.emit_virtual_sfc(&virtual_path, &sfc, &entry)?;
+
+        variants.push(GalleryVariant {
+            name: variant.name.to_string(),
+            slug: variant_slug,
+            is_default: variant.is_default,
+            skip_vrt: variant.skip_vrt,
+            args: variant
+                .args
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+            viewport: variant.viewport,
+            entry,
+            page,
+        });
+    }
+
+    Ok(GalleryComponent {
+        title: art.metadata.title.to_string(),
+        description: art.metadata.description.map(str::to_string),
+        category: art.metadata.category.map(str::to_string),
+        tags: art.metadata.tags.iter().map(|t| t.to_string()).collect(),
+        status: art.metadata.status,
+        art_path: graph.relative_source(art_path),
+        component_path: component_file.map(|file| graph.relative_source(&file)),
+        page: format!("components/{}.html", slug),
+        slug,
+        variants,
+        api,
+    })
+}
+
+/// SFC rendering one variant with the art file's script and styles.
+fn variant_sfc(
+    art: &ArtDescriptor<'_>,
+    variant: &ArtVariant<'_>,
+    component_name: Option<&str>,
+) -> String {
+    let mut sfc = String::new();
+    let script = art.script_setup.as_ref();
+    let script_content = script.map(|s| s.content).unwrap_or_default();
+
+    match script.and_then(|s| s.lang) {
+        Some(lang) => sfc.push_str(&format!("<script setup lang=\"{}\">\n", lang)),
+        None => sfc.push_str("<script setup>\n"),
+    }
+    if let (Some(name), Some(component)) = (component_name, art.metadata.component) {
+        if !script_content.contains(&format!("import {} ", name)) {
+            sfc.push_str(&format!("import {} from '{}'\n", name, component));
+        }
+    }
+    if !variant.args.is_empty() && !script_content.contains("args") {
+        let args: serde_json::Map<_, _> = variant
+            .args
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect();
+        sfc.push_str(&format!(
+            "const args = {}\n",
+            serde_json::Value::Object(args)
+        ));
+    }
+    sfc.push_str(script_content);
+    sfc.push_str("\n</script>\n\n");
+
+    let mut template = variant.template.to_string();
+    if let Some(name) = component_name {
+        template = replace_self(&template, name);
+    }
+    sfc.push_str(&format!(
+        "<template>\n<div class=\"musea-variant\" data-variant=\"{}\">\n{}\n</div>\n</template>\n",
+        crate::docs::escape_html(variant.name),
+        template
+    ));
+
+    for style in &art.styles {
+        sfc.push_str("\n<style");
+        if style.scoped {
+            sfc.push_str(" scoped");
+        }
+        if let Some(lang) = style.lang {
+            sfc.push_str(&format!(" lang=\"{}\"", lang));
+        }
+        sfc.push_str(">\n");
+        sfc.push_str(style.content);
+        sfc.push_str("\n</style>\n");
+    }
+
+    sfc
+}
+
+/// Replace `<Self>` tags with the component name.
+pub(crate) fn replace_self(template: &str, name: &str) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(pos) = rest.find('<') {
+        result.push_str(&rest[..pos + 1]);
+        rest = &rest[pos + 1..];
+        if let Some(slash) = rest.strip_prefix('/') {
+            result.push('/');
+            rest = slash;
+        }
+        let is_self = rest.strip_prefix("Self").is_some_and(|after| {
+            after
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_whitespace() || c == '>' || c == '/')
+        });
+        if is_self {
+            result.push_str(name);
+            rest = &rest[4..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Point catalog links (relative to the output root) at pages in a subdirectory.
+fn relink(markdown: &str, prefix: &str) -> String {
+    if prefix.is_empty() {
+        return markdown.to_string();
+    }
+    markdown.replace("](components/", &format!("]({}components/", prefix))
+}
+
+fn unique_slug(seen: &mut FxHashMap<String, usize>, slug: &str) -> String {
+    let slug = if slug.is_empty() { "untitled" } else { slug };
+    let count = seen.entry(slug.to_string()).or_insert(0);
+    *count += 1;
+    if *count == 1 {
+        slug.to_string()
+    } else {
+        format!("{}-{}", slug, count)
+    }
+}
+
+fn read(path: &Path) -> Result<String, GalleryError> {
+    fs::read_to_string(path).map_err(|source| GalleryError::Io {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_self() {
+        assert_eq!(
+            replace_self("<Self size=\"sm\">Hi</Self><SelfTest /><Self/>", "Button"),
+            "<Button size=\"sm\">Hi</Button><SelfTest /><Button/>"
+        );
+    }
+
+    #[test]
+    fn test_build_gallery() {
+        let root = std::env::temp_dir().join(format!("vize-gallery-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let src = root.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(
+            src.join("Button.vue"),
+            r#"<script setup lang="ts">
+import { label as format } from './format'
+defineProps<{
+  /** Button text */
+  label: string
+}>()
+</script>
+<template><button class="btn">{{ format(label) }}</button></template>
+<style scoped>.btn { color: red; }</style>
+"#,
+        )
+        .unwrap();
+        fs::write(
+            src.join("format.ts"),
+            "export const label = (s: string): string => s.toUpperCase()\n",
+        )
+        .unwrap();
+        fs::write(
+            src.join("Button.art.vue"),
+            r#"<art title="Button" component="./Button.vue" category="atoms" tags="ui">
+  <variant name="Primary" default>
+    <Self label="Go" />
+  </variant>
+  <variant name="Long Label">
+    <Self label="A much longer label" />
+  </variant>
+</art>
+"#,
+        )
+        .unwrap();
+
+        let out_dir = root.join("dist");
+        let build = build_gallery(
+            &[src.join("Button.art.vue")],
+            &GalleryOptions {
+                root: root.clone(),
+                out_dir: out_dir.clone(),
+                title: "Gallery".to_string(),
+                vue: VueRuntime::Url("https://example.com/vue.js".to_string()),
+            },
+        )
+        .unwrap();
+        let file = |path: &str| fs::read_to_string(out_dir.join(path)).unwrap();
+
+        let component = &build.manifest.components[0];
+        assert_eq!(component.art_path, "src/Button.art.vue");
+        assert_eq!(component.component_path.as_deref(), Some("src/Button.vue"));
+        assert_eq!(component.variants[1].slug, "long-label");
+        assert_eq!(component.api.as_ref().unwrap().props[0].name, "label");
+        assert!(build.warnings.is_empty(), "{:?}", build.warnings);
+
+        let entry = file("assets/variants/button/primary.js");
+        assert!(entry.contains("../../modules/src/Button.vue.js"));
+        let module = file("assets/modules/src/Button.vue.js");
+        assert!(module.contains("./format.js"));
+        assert!(!file("assets/modules/src/format.js").contains(": string"));
+        assert!(file("assets/gallery.css").contains("color: red"));
+
+        let page = file("variants/button/primary.html");
+        assert!(page.contains("\"vue\":\"https://example.com/vue.js\""));
+        assert!(page.contains("import Variant from \"../../assets/variants/button/primary.js\""));
+
+        assert!(file("index.html").contains("href=\"components/button.html\""));
+        assert!(file("categories/atoms.html").contains("href=\"../components/button.html\""));
+        let doc = file("components/button.html");
+        assert!(doc.contains("src=\"../variants/button/long-label.html\""));
+        assert!(doc.contains("Button text"));
+
+        let manifest: GalleryManifest = serde_json::from_str(&file("manifest.json")).unwrap();
+        assert_eq!(manifest.version, MANIFEST_VERSION);
+        assert_eq!(manifest.categories, vec!["atoms"]);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
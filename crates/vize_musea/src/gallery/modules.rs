@@ -0,0 +1,391 @@
+//! ES module output for the static gallery.
+//!
+//! Vue SFCs are compiled with `vize_atelier_sfc`, TypeScript is stripped
+//! and relative imports are followed and rewritten to the emitted `.js`
+//! files, so every variant entry resolves without a bundler. Bare
+//! specifiers (`vue`, packages) are left for the page's import map.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use vize_atelier_sfc::compile_script::typescript::transform_typescript_to_js;
+use vize_atelier_sfc::{
+    compile_sfc, parse_sfc, CustomBlockTransforms, ScriptCompileOptions, SfcCompileOptions,
+    SfcParseOptions, StyleCompileOptions, TemplateCompileOptions,
+};
+use vize_carton::FxHashMap;
+
+use super::GalleryError;
+
+/// Extensions tried for extensionless relative imports.
+const RESOLVE_EXTENSIONS: &[&str] = &["ts", "js", "mjs", "vue"];
+
+/// Compiles sources into `out_dir`, each file at most once.
+pub(crate) struct ModuleGraph<'a> {
+    root: &'a Path,
+    out_dir: &'a Path,
+    /// Source path -> output path relative to `out_dir`
+    emitted: FxHashMap<PathBuf, String>,
+    /// CSS collected from styles and `.css` imports
+    pub css: String,
+    /// Bare specifiers the page must provide through its import map
+    pub externals: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl<'a> ModuleGraph<'a> {
+    pub fn new(root: &'a Path, out_dir: &'a Path) -> Self {
+        Self {
+            root,
+            out_dir,
+            emitted: FxHashMap::default(),
+            css: String::new(),
+            externals: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Compile an SFC that only exists in memory (a variant) to `output`.
+    ///
+    /// `path` is where the SFC would live; its relative imports are
+    /// resolved from there.
+    pub fn emit_virtual_sfc(
+        &mut self,
+        path: &Path,
+        source: &str,
+        output: &str,
+    ) -> Result<(), GalleryError> {
+        let code = self.compile_vue(path, source)?;
+        let code = self.rewrite_imports(path, output, &code)?;
+        self.write(output, &code)
+    }
+
+    /// Emit a source file and its relative imports, returning its output path.
+    pub fn emit_file(&mut self, path: &Path) -> Result<String, GalleryError> {
+        if let Some(output) = self.emitted.get(path) {
+            return Ok(output.clone());
+        }
+
+        let output = self.output_path(path);
+        // Register first so import cycles terminate
+        self.emitted.insert(path.to_path_buf(), output.clone());
+
+        let source = fs::read_to_string(path).map_err(|source| GalleryError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        let code = match path.extension().and_then(|e| e.to_str()) {
+            Some("vue") => self.compile_vue(path, &source)?,
+            Some("ts" | "mts") => transform_typescript_to_js(&source),
+            _ => source,
+        };
+        let code = self.rewrite_imports(path, &output, &code)?;
+        self.write(&output, &code)?;
+        Ok(output)
+    }
+
+    fn compile_vue(&mut self, path: &Path, source: &str) -> Result<String, GalleryError> {
+        let filename = self.relative_source(path);
+        let compile_error = |message: String| GalleryError::Compile {
+            path: path.display().to_string(),
+            message,
+        };
+
+        let descriptor = parse_sfc(
+            source,
+            SfcParseOptions {
+                filename: filename.clone(),
+                ..Default::default()
+            },
+        )
+        .map_err(|e| compile_error(e.message))?;
+
+        let has_scoped = descriptor.styles.iter().any(|s| s.scoped);
+        let options = SfcCompileOptions {
+            parse: SfcParseOptions {
+                filename: filename.clone(),
+                ..Default::default()
+            },
+            script: ScriptCompileOptions {
+                id: Some(filename.clone()),
+                ..Default::default()
+            },
+            template: TemplateCompileOptions {
+                id: Some(filename.clone()),
+                scoped: has_scoped,
+                ..Default::default()
+            },
+            style: StyleCompileOptions {
+                id: filename.clone(),
+                scoped: has_scoped,
+                ..Default::default()
+            },
+            scope_id: None,
+            custom_element: false,
+            custom_blocks: CustomBlockTransforms::new(),
+        };
+
+        let result = compile_sfc(&descriptor, options).map_err(|e| compile_error(e.message))?;
+        if let Some(error) = result.errors.first() {
+            return Err(compile_error(error.message.clone()));
+        }
+        for warning in result.warnings {
+            self.warnings
+                .push(format!("{}: {}", path.display(), warning.message));
+        }
+        if let Some(css) = result.css.filter(|css| !css.trim().is_empty()) {
+            self.push_css(&filename, &css);
+        }
+
+        // TypeScript sources compile to TypeScript; browsers need plain JS
+        let is_ts = [&descriptor.script, &descriptor.script_setup]
+            .into_iter()
+            .flatten()
+            .any(|s| matches!(s.lang.as_deref(), Some("ts" | "tsx")));
+        Ok(if is_ts {
+            transform_typescript_to_js(&result.code)
+        } else {
+            result.code
+        })
+    }
+
+    /// Rewrite relative specifiers of `code` (emitted at `output`).
+    fn rewrite_imports(
+        &mut self,
+        path: &Path,
+        output: &str,
+        code: &str,
+    ) -> Result<String, GalleryError> {
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let mut rewritten = String::with_capacity(code.len());
+        let mut last = 0;
+
+        for specifier in find_specifiers(code) {
+            let spec = &code[specifier.start..specifier.end];
+            if !spec.starts_with("./") && !spec.starts_with("../") {
+                if !spec.starts_with('/') && !self.externals.iter().any(|e| e == spec) {
+                    self.externals.push(spec.to_string());
+                }
+                continue;
+            }
+
+            let Some(resolved) = resolve(&dir.join(spec)) else {
+                self.warnings.push(format!(
+                    "{}: cannot resolve import '{}'",
+                    path.display(),
+                    spec
+                ));
+                continue;
+            };
+
+            if resolved.extension().is_some_and(|e| e == "css") {
+                let css = fs::read_to_string(&resolved).map_err(|source| GalleryError::Io {
+                    path: resolved.display().to_string(),
+                    source,
+                })?;
+                let name = self.relative_source(&resolved);
+                self.push_css(&name, &css);
+                // CSS ships in the shared stylesheet; drop side-effect imports
+                if let Some(statement) = specifier.side_effect_start {
+                    rewritten.push_str(&code[last..statement]);
+                    last = specifier.end + 1;
+                }
+                continue;
+            }
+
+            let target = self.emit_file(&resolved)?;
+            rewritten.push_str(&code[last..specifier.start]);
+            rewritten.push_str(&relative_url(output, &target));
+            last = specifier.end;
+        }
+
+        rewritten.push_str(&code[last..]);
+        Ok(rewritten)
+    }
+
+    fn push_css(&mut self, name: &str, css: &str) {
+        self.css
+            .push_str(&format!("/* {} */\n{}\n", name, css.trim_end()));
+    }
+
+    fn write(&self, output: &str, code: &str) -> Result<(), GalleryError> {
+        write_file(&self.out_dir.join(output), code)
+    }
+
+    /// Output path for a source file, mirroring its location under the root.
+    fn output_path(&self, path: &Path) -> String {
+        let relative = self.relative_source(path);
+        let stem = relative
+            .strip_suffix(".vue")
+            .or_else(|| relative.strip_suffix(".mts"))
+            .or_else(|| relative.strip_suffix(".ts"))
+            .or_else(|| relative.strip_suffix(".mjs"))
+            .or_else(|| relative.strip_suffix(".js"))
+            .unwrap_or(&relative);
+        let suffix = if relative.ends_with(".vue") {
+            ".vue.js"
+        } else {
+            ".js"
+        };
+        format!("assets/modules/{}{}", stem, suffix)
+    }
+
+    /// Path relative to the root, with parent directories flattened.
+    pub fn relative_source(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(self.root).unwrap_or(path);
+        relative
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => Some(part.to_string_lossy().into_owned()),
+                Component::ParentDir => Some("_".to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// A module specifier string literal in the code.
+struct Specifier {
+    /// Byte range of the specifier, without quotes
+    start: usize,
+    end: usize,
+    /// Start of the statement for `import 'x'`
+    side_effect_start: Option<usize>,
+}
+
+/// Find specifiers of `import ... from`, `export ... from` and `import 'x'`.
+fn find_specifiers(code: &str) -> Vec<Specifier> {
+    let bytes = code.as_bytes();
+    let mut specifiers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let keyword = if code[i..].starts_with("from") {
+            "from"
+        } else if code[i..].starts_with("import") {
+            "import"
+        } else {
+            i += 1;
+            continue;
+        };
+
+        let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'$';
+        let before = i.checked_sub(1).map(|p| bytes[p]);
+        let after = bytes.get(i + keyword.len()).copied();
+        if before.is_some_and(|b| is_ident(b) || b == b'.') || after.is_some_and(is_ident) {
+            i += keyword.len();
+            continue;
+        }
+
+        let mut j = i + keyword.len();
+        while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+            j += 1;
+        }
+        if let Some(&quote @ (b'\'' | b'"')) = bytes.get(j) {
+            if let Some(len) = code[j + 1..].find(quote as char) {
+                specifiers.push(Specifier {
+                    start: j + 1,
+                    end: j + 1 + len,
+                    side_effect_start: (keyword == "import").then_some(i),
+                });
+                i = j + 1 + len + 1;
+                continue;
+            }
+        }
+        i += keyword.len();
+    }
+
+    specifiers
+}
+
+/// Resolve a relative import to a file on disk.
+fn resolve(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+    let file_name = path.file_name()?.to_string_lossy().into_owned();
+    for ext in RESOLVE_EXTENSIONS {
+        let candidate = path.with_file_name(format!("{}.{}", file_name, ext));
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
+    RESOLVE_EXTENSIONS
+        .iter()
+        .map(|ext| path.join(format!("index.{}", ext)))
+        .find(|candidate| candidate.is_file())
+}
+
+/// URL of `to` relative to the document or module at `from` (both relative
+/// to the output directory, `/`-separated).
+pub(crate) fn relative_url(from: &str, to: &str) -> String {
+    let from_dirs: Vec<&str> = from.split('/').collect();
+    let from_dirs = &from_dirs[..from_dirs.len() - 1];
+    let to_parts: Vec<&str> = to.split('/').collect();
+
+    let common = from_dirs
+        .iter()
+        .zip(&to_parts)
+        .take_while(|(a, b)| a == b)
+        .count()
+        .min(to_parts.len() - 1);
+
+    let mut parts: Vec<&str> = vec![".."; from_dirs.len() - common];
+    if parts.is_empty() {
+        parts.push(".");
+    }
+    parts.extend(&to_parts[common..]);
+    parts.join("/")
+}
+
+pub(crate) fn write_file(path: &Path, contents: &str) -> Result<(), GalleryError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|source| GalleryError::Io {
+            path: parent.display().to_string(),
+            source,
+        })?;
+    }
+    fs::write(path, contents).map_err(|source| GalleryError::Io {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_specifiers() {
+        let code = "import { ref } from 'vue'\nimport './style.css'\nexport { a } from \"./a\"\nconst from = 1; obj.import('x')\n";
+        let specs: Vec<_> = find_specifiers(code)
+            .iter()
+            .map(|s| (&code[s.start..s.end], s.side_effect_start.is_some()))
+            .collect();
+
+        assert_eq!(
+            specs,
+            vec![("vue", false), ("./style.css", true), ("./a", false)]
+        );
+    }
+
+    #[test]
+    fn test_relative_url() {
+        assert_eq!(
+            relative_url(
+                "assets/variants/button/primary.js",
+                "assets/modules/Button.vue.js"
+            ),
+            "../../modules/Button.vue.js"
+        );
+        assert_eq!(
+            relative_url("assets/modules/a.js", "assets/modules/b.js"),
+            "./b.js"
+        );
+        assert_eq!(
+            relative_url("index.html", "assets/vue.js"),
+            "./assets/vue.js"
+        );
+    }
+}
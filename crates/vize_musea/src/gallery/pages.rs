@@ -0,0 +1,157 @@
+//! HTML pages of the static gallery.
+
+use super::modules::relative_url;
+use super::{GalleryComponent, GalleryVariant, VueRuntime};
+use crate::docs::escape_html;
+
+/// Output path of the Vue runtime when it is copied into the gallery.
+pub(crate) const VUE_ASSET: &str = "assets/vue.js";
+
+/// Output path of the stylesheet shared by all variants.
+pub(crate) const CSS_ASSET: &str = "assets/gallery.css";
+
+const LAYOUT_CSS: &str = r#"
+*{box-sizing:border-box}
+body{margin:0;font-family:system-ui,-apple-system,sans-serif;color:#1f2328;display:flex;min-height:100vh}
+nav{width:240px;flex-shrink:0;padding:24px 16px;border-right:1px solid #d0d7de;background:#f6f8fa}
+nav h2{font-size:12px;text-transform:uppercase;color:#656d76;margin:20px 0 6px}
+nav ul{list-style:none;margin:0;padding:0}
+nav a{display:block;padding:3px 6px;border-radius:4px;color:inherit;text-decoration:none}
+nav a:hover{background:#eaeef2}
+main{flex:1;padding:32px 48px;max-width:1100px}
+table{border-collapse:collapse;margin:12px 0}
+th,td{border:1px solid #d0d7de;padding:6px 12px;text-align:left}
+pre{background:#f6f8fa;padding:12px;border-radius:6px;overflow:auto}
+code{font-family:ui-monospace,monospace;font-size:90%}
+blockquote{margin:0;padding:0 12px;border-left:4px solid #d0d7de;color:#656d76}
+.previews{display:grid;grid-template-columns:repeat(auto-fill,minmax(320px,1fr));gap:16px;margin:16px 0 32px}
+.preview{border:1px solid #d0d7de;border-radius:6px;overflow:hidden}
+.preview header{display:flex;justify-content:space-between;padding:6px 12px;border-bottom:1px solid #d0d7de;font-size:13px}
+.preview iframe{width:100%;height:200px;border:0;display:block}
+"#;
+
+/// Full page with the navigation sidebar.
+///
+/// `page` is the output path of the page, used to make links relative.
+pub(crate) fn layout(
+    page: &str,
+    site_title: &str,
+    title: &str,
+    components: &[GalleryComponent],
+    categories: &[String],
+    body: &str,
+) -> String {
+    let link = |target: &str| escape_html(&relative_url(page, target));
+
+    let mut nav = String::new();
+    nav.push_str(&format!(
+        "<a href=\"{}\"><strong>{}</strong></a>\n",
+        link("index.html"),
+        escape_html(site_title)
+    ));
+    nav.push_str(&format!("<a href=\"{}\">Tags</a>\n", link("tags.html")));
+
+    if !categories.is_empty() {
+        nav.push_str("<h2>Categories</h2>\n<ul>\n");
+        for category in categories {
+            nav.push_str(&format!(
+                "<li><a href=\"{}\">{}</a></li>\n",
+                link(&category_page(category)),
+                escape_html(category)
+            ));
+        }
+        nav.push_str("</ul>\n");
+    }
+
+    nav.push_str("<h2>Components</h2>\n<ul>\n");
+    for component in components {
+        nav.push_str(&format!(
+            "<li><a href=\"{}\">{}</a></li>\n",
+            link(&component.page),
+            escape_html(&component.title)
+        ));
+    }
+    nav.push_str("</ul>\n");
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title} - {site_title}</title>
+<style>{LAYOUT_CSS}</style>
+</head>
+<body>
+<nav>
+{nav}</nav>
+<main>
+{body}</main>
+</body>
+</html>
+"#,
+        title = escape_html(title),
+        site_title = escape_html(site_title),
+    )
+}
+
+/// Variant previews embedded on a component page.
+pub(crate) fn previews(page: &str, component: &GalleryComponent) -> String {
+    let mut html = String::from("<section class=\"previews\">\n");
+    for variant in &component.variants {
+        let src = escape_html(&relative_url(page, &variant.page));
+        html.push_str(&format!(
+            "<div class=\"preview\"><header><span>{}</span><a href=\"{}\" target=\"_blank\">Open</a></header><iframe src=\"{}\" title=\"{}\" loading=\"lazy\"></iframe></div>\n",
+            escape_html(&variant.name),
+            src,
+            src,
+            escape_html(&variant.name),
+        ));
+    }
+    html.push_str("</section>\n");
+    html
+}
+
+/// Standalone page mounting a single variant, as loaded by VRT.
+pub(crate) fn variant_page(
+    component: &GalleryComponent,
+    variant: &GalleryVariant,
+    vue: &VueRuntime,
+) -> String {
+    let page = &variant.page;
+    let vue_url = match vue {
+        VueRuntime::File(_) => relative_url(page, VUE_ASSET),
+        VueRuntime::Url(url) => url.clone(),
+    };
+    let import_map = serde_json::json!({ "imports": { "vue": vue_url } });
+
+    format!(
+        r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title} / {variant}</title>
+<link rel="stylesheet" href="{css}">
+<script type="importmap">{import_map}</script>
+</head>
+<body>
+<div id="app"></div>
+<script type="module">
+import {{ createApp }} from "vue";
+import Variant from "{entry}";
+createApp(Variant).mount("#app");
+</script>
+</body>
+</html>
+"##,
+        title = escape_html(&component.title),
+        variant = escape_html(&variant.name),
+        css = escape_html(&relative_url(page, CSS_ASSET)),
+        entry = relative_url(page, &variant.entry),
+    )
+}
+
+pub(crate) fn category_page(category: &str) -> String {
+    format!("categories/{}.html", crate::docs::slugify(category))
+}
@@ -63,8 +63,10 @@
 //! - Visual Regression Testing (VRT) support
 //! - Interactive props palette
 
+pub mod audit;
 pub mod autogen;
 pub mod docs;
+pub mod gallery;
 pub mod palette;
 pub mod parse;
 pub mod transform;
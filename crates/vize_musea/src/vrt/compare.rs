@@ -0,0 +1,506 @@
+//! Pixel comparison engine.
+//!
+//! Compares two screenshots pixel by pixel using the perceptual YIQ color
+//! distance from pixelmatch, ignoring anti-aliased edges when enabled, and
+//! renders diff images in the configured [`DiffStyle`].
+
+use super::config::{ComparisonConfig, DiffStyle, RgbColor, VrtThreshold};
+use super::image::{encode_frames, Image, VrtError};
+
+/// Maximum possible YIQ distance between two colors
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+/// Color of anti-aliased pixels in diff images
+const ANTI_ALIASED_COLOR: [u8; 4] = [255, 255, 0, 255];
+
+/// Delay between frames of animated diffs (ms)
+const ANIMATION_DELAY_MS: u16 = 800;
+
+/// Classification of a single pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelState {
+    /// Identical or below the color sensitivity
+    Same,
+    /// Different only because of anti-aliasing
+    AntiAliased,
+    /// Different
+    Changed,
+}
+
+/// Result of comparing two images.
+#[derive(Debug, Clone)]
+pub struct PixelDiff {
+    /// Compared width (the larger of both images)
+    pub width: u32,
+    /// Compared height (the larger of both images)
+    pub height: u32,
+    /// Whether the images have different dimensions
+    pub size_mismatch: bool,
+    /// Number of compared pixels
+    pub total_pixels: u64,
+    /// Number of changed pixels
+    pub diff_pixels: u64,
+    /// Number of pixels ignored as anti-aliasing
+    pub anti_aliased_pixels: u64,
+    /// Changed pixels as a percentage of all pixels (0-100)
+    pub diff_percentage: f64,
+    /// Per-pixel classification, row-major
+    pub pixels: Vec<PixelState>,
+}
+
+impl PixelDiff {
+    /// Whether the diff stays within the threshold.
+    ///
+    /// Images of different sizes never pass.
+    pub fn passes(&self, threshold: &VrtThreshold) -> bool {
+        !self.size_mismatch
+            && self.diff_percentage <= threshold.percentage
+            && threshold
+                .pixels
+                .is_none_or(|max| self.diff_pixels <= max as u64)
+    }
+
+    /// Whether any pixel changed.
+    pub fn has_diff(&self) -> bool {
+        self.size_mismatch || self.diff_pixels > 0
+    }
+
+    #[inline]
+    fn state(&self, x: u32, y: u32) -> PixelState {
+        self.pixels[(y * self.width + x) as usize]
+    }
+}
+
+/// Compare two images.
+///
+/// Pixels outside the overlap of differently sized images count as changed.
+pub fn compare_images(
+    baseline: &Image,
+    actual: &Image,
+    comparison: &ComparisonConfig,
+    threshold: &VrtThreshold,
+) -> PixelDiff {
+    let width = baseline.width.max(actual.width);
+    let height = baseline.height.max(actual.height);
+    let overlap_width = baseline.width.min(actual.width);
+    let overlap_height = baseline.height.min(actual.height);
+    let max_delta = MAX_YIQ_DELTA * threshold.color_sensitivity * threshold.color_sensitivity;
+
+    let mut pixels = Vec::with_capacity(width as usize * height as usize);
+    let mut diff_pixels = 0;
+    let mut anti_aliased_pixels = 0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let state = if x >= overlap_width || y >= overlap_height {
+                PixelState::Changed
+            } else {
+                let delta = color_delta(
+                    baseline.pixel(x, y),
+                    actual.pixel(x, y),
+                    false,
+                    comparison.alpha,
+                );
+                if delta.abs() <= max_delta {
+                    PixelState::Same
+                } else if comparison.anti_aliasing
+                    && (is_anti_aliased(baseline, actual, x, y, comparison.alpha)
+                        || is_anti_aliased(actual, baseline, x, y, comparison.alpha))
+                {
+                    PixelState::AntiAliased
+                } else {
+                    PixelState::Changed
+                }
+            };
+
+            match state {
+                PixelState::Changed => diff_pixels += 1,
+                PixelState::AntiAliased => anti_aliased_pixels += 1,
+                PixelState::Same => {}
+            }
+            pixels.push(state);
+        }
+    }
+
+    let total_pixels = width as u64 * height as u64;
+    let diff_percentage = if total_pixels == 0 {
+        0.0
+    } else {
+        diff_pixels as f64 / total_pixels as f64 * 100.0
+    };
+
+    PixelDiff {
+        width,
+        height,
+        size_mismatch: baseline.width != actual.width || baseline.height != actual.height,
+        total_pixels,
+        diff_pixels,
+        anti_aliased_pixels,
+        diff_percentage,
+        pixels,
+    }
+}
+
+/// Render the diff as a PNG in the configured style.
+///
+/// `Animated` produces an animated PNG flipping between baseline and actual.
+pub fn render_diff(
+    baseline: &Image,
+    actual: &Image,
+    diff: &PixelDiff,
+    comparison: &ComparisonConfig,
+) -> Result<Vec<u8>, VrtError> {
+    let color = comparison.diff_color.unwrap_or_default();
+    match comparison.diff_style {
+        DiffStyle::Overlay => render_overlay(baseline, diff, color).encode_png(),
+        DiffStyle::DiffOnly => render_diff_only(diff, color).encode_png(),
+        DiffStyle::SideBySide => {
+            let overlay = render_overlay(baseline, diff, color);
+            let mut canvas = Image::new(diff.width * 3, diff.height);
+            canvas.blit(baseline, 0, 0);
+            canvas.blit(&overlay, diff.width, 0);
+            canvas.blit(actual, diff.width * 2, 0);
+            canvas.encode_png()
+        }
+        DiffStyle::Animated => {
+            let mut from = Image::new(diff.width, diff.height);
+            from.blit(baseline, 0, 0);
+            let mut to = Image::new(diff.width, diff.height);
+            to.blit(actual, 0, 0);
+            encode_frames(diff.width, diff.height, &[&from, &to], ANIMATION_DELAY_MS)
+        }
+    }
+}
+
+/// Changed pixels in the diff color on top of the faded, grayscale baseline.
+fn render_overlay(baseline: &Image, diff: &PixelDiff, color: RgbColor) -> Image {
+    let mut image = Image::new(diff.width, diff.height);
+    for y in 0..diff.height {
+        for x in 0..diff.width {
+            let rgba = match diff.state(x, y) {
+                PixelState::Changed => [color.r, color.g, color.b, 255],
+                PixelState::AntiAliased => ANTI_ALIASED_COLOR,
+                PixelState::Same => {
+                    let [r, g, b, a] = baseline.pixel(x, y);
+                    let luma = blend(
+                        rgb_to_y(r as f64, g as f64, b as f64),
+                        0.1 * a as f64 / 255.0,
+                    );
+                    let gray = luma.round() as u8;
+                    [gray, gray, gray, 255]
+                }
+            };
+            image.set_pixel(x, y, rgba);
+        }
+    }
+    image
+}
+
+/// Changed pixels in the diff color on a transparent background.
+fn render_diff_only(diff: &PixelDiff, color: RgbColor) -> Image {
+    let mut image = Image::new(diff.width, diff.height);
+    for y in 0..diff.height {
+        for x in 0..diff.width {
+            match diff.state(x, y) {
+                PixelState::Changed => image.set_pixel(x, y, [color.r, color.g, color.b, 255]),
+                PixelState::AntiAliased => image.set_pixel(x, y, ANTI_ALIASED_COLOR),
+                PixelState::Same => {}
+            }
+        }
+    }
+    image
+}
+
+/// Perceptual color difference in YIQ space.
+///
+/// Semi-transparent pixels are blended over white first. The sign tells
+/// whether the second pixel is lighter (positive) or darker (negative).
+fn color_delta(a: [u8; 4], b: [u8; 4], y_only: bool, alpha: bool) -> f64 {
+    let (a, b) = if alpha {
+        (a, b)
+    } else {
+        ([a[0], a[1], a[2], 255], [b[0], b[1], b[2], 255])
+    };
+    if a == b {
+        return 0.0;
+    }
+
+    let [r1, g1, b1] = blend_rgba(a);
+    let [r2, g2, b2] = blend_rgba(b);
+
+    let y1 = rgb_to_y(r1, g1, b1);
+    let y2 = rgb_to_y(r2, g2, b2);
+    let y = y1 - y2;
+    if y_only {
+        return y;
+    }
+
+    let i = rgb_to_i(r1, g1, b1) - rgb_to_i(r2, g2, b2);
+    let q = rgb_to_q(r1, g1, b1) - rgb_to_q(r2, g2, b2);
+    let delta = 0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q;
+
+    if y1 > y2 {
+        -delta
+    } else {
+        delta
+    }
+}
+
+/// Whether the pixel at `(x, y)` of `image` looks like an anti-aliased edge.
+///
+/// An anti-aliased pixel sits between a darkest and a brightest neighbor
+/// that both belong to flat regions in both images.
+fn is_anti_aliased(image: &Image, other: &Image, x: u32, y: u32, alpha: bool) -> bool {
+    let x0 = x.saturating_sub(1);
+    let y0 = y.saturating_sub(1);
+    let x2 = (x + 1).min(image.width - 1);
+    let y2 = (y + 1).min(image.height - 1);
+    let center = image.pixel(x, y);
+
+    let mut zeroes = u32::from(x == x0 || x == x2 || y == y0 || y == y2);
+    let mut min = 0.0;
+    let mut max = 0.0;
+    let mut darkest = (0, 0);
+    let mut brightest = (0, 0);
+
+    for ny in y0..=y2 {
+        for nx in x0..=x2 {
+            if nx == x && ny == y {
+                continue;
+            }
+            let delta = color_delta(center, image.pixel(nx, ny), true, alpha);
+            if delta == 0.0 {
+                zeroes += 1;
+                if zeroes > 2 {
+                    return false;
+                }
+            } else if delta < min {
+                min = delta;
+                darkest = (nx, ny);
+            } else if delta > max {
+                max = delta;
+                brightest = (nx, ny);
+            }
+        }
+    }
+
+    if min == 0.0 || max == 0.0 {
+        return false;
+    }
+
+    (has_many_siblings(image, darkest) && has_many_siblings(other, darkest))
+        || (has_many_siblings(image, brightest) && has_many_siblings(other, brightest))
+}
+
+/// Whether at least three neighbors have exactly the same color.
+fn has_many_siblings(image: &Image, (x, y): (u32, u32)) -> bool {
+    if x >= image.width || y >= image.height {
+        return false;
+    }
+    let x0 = x.saturating_sub(1);
+    let y0 = y.saturating_sub(1);
+    let x2 = (x + 1).min(image.width - 1);
+    let y2 = (y + 1).min(image.height - 1);
+    let center = image.pixel(x, y);
+
+    let mut zeroes = u32::from(x == x0 || x == x2 || y == y0 || y == y2);
+    for ny in y0..=y2 {
+        for nx in x0..=x2 {
+            if (nx != x || ny != y) && image.pixel(nx, ny) == center {
+                zeroes += 1;
+                if zeroes > 2 {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+#[inline]
+fn blend_rgba([r, g, b, a]: [u8; 4]) -> [f64; 3] {
+    let alpha = a as f64 / 255.0;
+    [
+        blend(r as f64, alpha),
+        blend(g as f64, alpha),
+        blend(b as f64, alpha),
+    ]
+}
+
+/// Blend a channel over white.
+#[inline]
+fn blend(c: f64, alpha: f64) -> f64 {
+    255.0 + (c - 255.0) * alpha
+}
+
+#[inline]
+fn rgb_to_y(r: f64, g: f64, b: f64) -> f64 {
+    r * 0.29889531 + g * 0.58662247 + b * 0.11448223
+}
+
+#[inline]
+fn rgb_to_i(r: f64, g: f64, b: f64) -> f64 {
+    r * 0.59597799 - g * 0.2741761 - b * 0.32180189
+}
+
+#[inline]
+fn rgb_to_q(r: f64, g: f64, b: f64) -> f64 {
+    r * 0.21147017 - g * 0.52261711 + b * 0.31114694
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(width: u32, height: u32, rgba: [u8; 4]) -> Image {
+        let mut image = Image::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                image.set_pixel(x, y, rgba);
+            }
+        }
+        image
+    }
+
+    fn compare(baseline: &Image, actual: &Image) -> PixelDiff {
+        compare_images(
+            baseline,
+            actual,
+            &ComparisonConfig::default(),
+            &VrtThreshold::default(),
+        )
+    }
+
+    #[test]
+    fn test_identical() {
+        let image = filled(4, 4, [12, 34, 56, 255]);
+        let diff = compare(&image, &image);
+        assert_eq!(diff.diff_pixels, 0);
+        assert_eq!(diff.total_pixels, 16);
+        assert!(diff.passes(&VrtThreshold::default()));
+        assert!(!diff.has_diff());
+    }
+
+    #[test]
+    fn test_changed_pixel() {
+        let baseline = filled(10, 10, [255, 255, 255, 255]);
+        let mut actual = baseline.clone();
+        actual.set_pixel(5, 5, [0, 0, 0, 255]);
+
+        let diff = compare(&baseline, &actual);
+        assert_eq!(diff.diff_pixels, 1);
+        assert_eq!(diff.diff_percentage, 1.0);
+        assert!(!diff.passes(&VrtThreshold::default()));
+        assert!(diff.passes(&VrtThreshold {
+            percentage: 1.0,
+            ..Default::default()
+        }));
+        assert!(!diff.passes(&VrtThreshold {
+            percentage: 100.0,
+            pixels: Some(0),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_color_sensitivity() {
+        let baseline = filled(2, 2, [200, 200, 200, 255]);
+        let actual = filled(2, 2, [205, 205, 205, 255]);
+        assert_eq!(compare(&baseline, &actual).diff_pixels, 0);
+
+        let strict = VrtThreshold {
+            color_sensitivity: 0.0,
+            ..Default::default()
+        };
+        let diff = compare_images(&baseline, &actual, &ComparisonConfig::default(), &strict);
+        assert_eq!(diff.diff_pixels, 4);
+    }
+
+    #[test]
+    fn test_alpha() {
+        let baseline = filled(2, 2, [0, 0, 0, 0]);
+        let actual = filled(2, 2, [0, 0, 0, 255]);
+        assert_eq!(compare(&baseline, &actual).diff_pixels, 4);
+
+        let no_alpha = ComparisonConfig {
+            alpha: false,
+            ..Default::default()
+        };
+        let diff = compare_images(&baseline, &actual, &no_alpha, &VrtThreshold::default());
+        assert_eq!(diff.diff_pixels, 0);
+    }
+
+    #[test]
+    fn test_anti_aliasing() {
+        // A black/white vertical edge that gains a gray anti-aliased column
+        let mut baseline = filled(6, 6, [255, 255, 255, 255]);
+        for y in 0..6 {
+            for x in 0..3 {
+                baseline.set_pixel(x, y, [0, 0, 0, 255]);
+            }
+        }
+        let mut actual = baseline.clone();
+        for y in 0..6 {
+            actual.set_pixel(3, y, [128, 128, 128, 255]);
+        }
+
+        let diff = compare(&baseline, &actual);
+        assert_eq!(diff.diff_pixels, 0);
+        assert_eq!(diff.anti_aliased_pixels, 6);
+
+        let strict = ComparisonConfig {
+            anti_aliasing: false,
+            ..Default::default()
+        };
+        let diff = compare_images(&baseline, &actual, &strict, &VrtThreshold::default());
+        assert_eq!(diff.diff_pixels, 6);
+    }
+
+    #[test]
+    fn test_size_mismatch() {
+        let baseline = filled(4, 4, [0, 0, 0, 255]);
+        let actual = filled(4, 6, [0, 0, 0, 255]);
+        let diff = compare(&baseline, &actual);
+        assert!(diff.size_mismatch);
+        assert_eq!(diff.diff_pixels, 8);
+        assert_eq!(diff.total_pixels, 24);
+        assert!(!diff.passes(&VrtThreshold {
+            percentage: 100.0,
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_render_styles() {
+        let baseline = filled(4, 4, [255, 255, 255, 255]);
+        let mut actual = baseline.clone();
+        actual.set_pixel(1, 1, [0, 0, 0, 255]);
+        let diff = compare(&baseline, &actual);
+
+        let render = |diff_style| {
+            let comparison = ComparisonConfig {
+                diff_style,
+                diff_color: Some(RgbColor { r: 0, g: 0, b: 255 }),
+                ..Default::default()
+            };
+            render_diff(&baseline, &actual, &diff, &comparison).unwrap()
+        };
+
+        let overlay = Image::decode_png(&render(DiffStyle::Overlay)).unwrap();
+        assert_eq!(overlay.pixel(1, 1), [0, 0, 255, 255]);
+        assert_eq!(overlay.pixel(0, 0), [255, 255, 255, 255]);
+
+        let diff_only = Image::decode_png(&render(DiffStyle::DiffOnly)).unwrap();
+        assert_eq!(diff_only.pixel(1, 1), [0, 0, 255, 255]);
+        assert_eq!(diff_only.pixel(0, 0), [0, 0, 0, 0]);
+
+        let side_by_side = Image::decode_png(&render(DiffStyle::SideBySide)).unwrap();
+        assert_eq!((side_by_side.width, side_by_side.height), (12, 4));
+        assert_eq!(side_by_side.pixel(5, 1), [0, 0, 255, 255]);
+        assert_eq!(side_by_side.pixel(9, 1), [0, 0, 0, 255]);
+
+        let animated = render(DiffStyle::Animated);
+        let reader = png::Decoder::new(animated.as_slice()).read_info().unwrap();
+        assert_eq!(reader.info().animation_control().unwrap().num_frames, 2);
+    }
+}
@@ -166,17 +166,19 @@ pub struct CiConfig {
 }
 
 /// Diff image style.
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum DiffStyle {
     /// Overlay diff on grayscale background
     #[default]
     Overlay,
-    /// Side-by-side comparison
+    /// Side-by-side comparison (baseline, overlay, actual)
+    #[serde(alias = "sidebyside")]
     SideBySide,
     /// Diff only (no background)
+    #[serde(alias = "diffonly")]
     DiffOnly,
-    /// Animated GIF comparison
+    /// Animated comparison flipping between baseline and actual (APNG)
     Animated,
 }
 
@@ -382,4 +384,16 @@ mod tests {
     fn test_diff_style_default() {
         assert!(matches!(DiffStyle::default(), DiffStyle::Overlay));
     }
+
+    #[test]
+    fn test_diff_style_serde() {
+        let style: DiffStyle = serde_json::from_str("\"sideBySide\"").unwrap();
+        assert_eq!(style, DiffStyle::SideBySide);
+        let style: DiffStyle = serde_json::from_str("\"diffonly\"").unwrap();
+        assert_eq!(style, DiffStyle::DiffOnly);
+        assert_eq!(
+            serde_json::to_string(&DiffStyle::SideBySide).unwrap(),
+            "\"sideBySide\""
+        );
+    }
 }
@@ -0,0 +1,171 @@
+//! RGBA images and PNG encoding for VRT.
+
+use std::io::Cursor;
+
+/// Error type for VRT image comparison.
+#[derive(Debug, thiserror::Error)]
+pub enum VrtError {
+    #[error("Failed to decode PNG: {0}")]
+    Decode(#[from] png::DecodingError),
+
+    #[error("Failed to encode PNG: {0}")]
+    Encode(#[from] png::EncodingError),
+
+    #[error("Unsupported PNG color type: {0:?}")]
+    UnsupportedColor(png::ColorType),
+
+    #[error("{path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+}
+
+/// An 8-bit RGBA image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    /// Row-major RGBA pixels (`width * height * 4` bytes)
+    pub data: Vec<u8>,
+}
+
+impl Image {
+    /// Create a fully transparent image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Decode a PNG into RGBA, expanding palette, grayscale and 16-bit images.
+    pub fn decode_png(bytes: &[u8]) -> Result<Self, VrtError> {
+        let mut decoder = png::Decoder::new(Cursor::new(bytes));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info()?;
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf)?;
+        let pixels = &buf[..info.buffer_size()];
+
+        let data = match info.color_type {
+            png::ColorType::Rgba => pixels.to_vec(),
+            png::ColorType::Rgb => pixels
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => pixels
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => pixels.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+            other => return Err(VrtError::UnsupportedColor(other)),
+        };
+
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            data,
+        })
+    }
+
+    /// Encode as an RGBA PNG.
+    pub fn encode_png(&self) -> Result<Vec<u8>, VrtError> {
+        encode_frames(self.width, self.height, &[self], 0)
+    }
+
+    /// RGBA value at `(x, y)`, transparent outside the image.
+    #[inline]
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        if x >= self.width || y >= self.height {
+            return [0; 4];
+        }
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        [
+            self.data[i],
+            self.data[i + 1],
+            self.data[i + 2],
+            self.data[i + 3],
+        ]
+    }
+
+    /// Set the RGBA value at `(x, y)`.
+    #[inline]
+    pub fn set_pixel(&mut self, x: u32, y: u32, rgba: [u8; 4]) {
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        self.data[i..i + 4].copy_from_slice(&rgba);
+    }
+
+    /// Copy `other` into this image with its top-left corner at `(x, y)`.
+    pub fn blit(&mut self, other: &Image, x: u32, y: u32) {
+        for oy in 0..other.height.min(self.height.saturating_sub(y)) {
+            for ox in 0..other.width.min(self.width.saturating_sub(x)) {
+                self.set_pixel(x + ox, y + oy, other.pixel(ox, oy));
+            }
+        }
+    }
+}
+
+/// Encode images of the same size as an animated PNG that flips between them.
+///
+/// A single frame produces a plain PNG.
+pub(crate) fn encode_frames(
+    width: u32,
+    height: u32,
+    frames: &[&Image],
+    delay_ms: u16,
+) -> Result<Vec<u8>, VrtError> {
+    let mut out = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        if frames.len() > 1 {
+            encoder.set_animated(frames.len() as u32, 0)?;
+            encoder.set_frame_delay(delay_ms, 1000)?;
+        }
+        let mut writer = encoder.write_header()?;
+        for frame in frames {
+            writer.write_image_data(&frame.data)?;
+        }
+        writer.finish()?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_png_roundtrip() {
+        let mut image = Image::new(3, 2);
+        image.set_pixel(1, 1, [10, 20, 30, 255]);
+        let decoded = Image::decode_png(&image.encode_png().unwrap()).unwrap();
+        assert_eq!(decoded, image);
+        assert_eq!(decoded.pixel(1, 1), [10, 20, 30, 255]);
+        assert_eq!(decoded.pixel(5, 5), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_decode_rgb() {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut bytes, 2, 1);
+            encoder.set_color(png::ColorType::Rgb);
+            let mut writer = encoder.write_header().unwrap();
+            writer.write_image_data(&[1, 2, 3, 4, 5, 6]).unwrap();
+        }
+        let image = Image::decode_png(&bytes).unwrap();
+        assert_eq!(image.data, [1, 2, 3, 255, 4, 5, 6, 255]);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(matches!(
+            Image::decode_png(b"not a png"),
+            Err(VrtError::Decode(_))
+        ));
+    }
+}
@@ -1,9 +1,19 @@
 //! VRT (Visual Regression Testing) configuration and types.
 //!
-//! This module provides configuration parsing and types for VRT integration.
+//! This module provides configuration parsing and types for VRT integration,
+//! plus a pure-Rust PNG comparison engine that needs no browser.
 
+mod compare;
 mod config;
+mod image;
 mod preset;
+mod report;
 
-pub use config::{VrtConfig, VrtOptions, VrtThreshold};
+pub use compare::{compare_images, render_diff, PixelDiff, PixelState};
+pub use config::{
+    BrowserConfig, CaptureConfig, CiConfig, ComparisonConfig, DiffStyle, RgbColor, VrtConfig,
+    VrtOptions, VrtThreshold,
+};
+pub use image::{Image, VrtError};
 pub use preset::{ViewportPreset, PRESET_VIEWPORTS};
+pub use report::{compare_dirs, VrtReport, VrtResult, VrtStatus, VrtSummary};
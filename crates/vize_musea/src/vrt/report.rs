@@ -0,0 +1,334 @@
+//! Directory comparison and JSON reports.
+//!
+//! Snapshots follow the `<art>--<variant>--<viewport>.png` naming used by
+//! the Musea VRT runner, so results can be reported per variant/viewport.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::compare::{compare_images, render_diff};
+use super::config::{CiConfig, VrtConfig};
+use super::image::{Image, VrtError};
+
+/// Outcome of a single snapshot comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VrtStatus {
+    /// Within the threshold
+    Passed,
+    /// Exceeds the threshold
+    Failed,
+    /// No baseline yet
+    New,
+    /// Baseline without an actual screenshot
+    Missing,
+    /// Could not be compared (unreadable image)
+    Error,
+}
+
+/// Comparison result for one variant/viewport.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VrtResult {
+    /// Snapshot file name
+    pub snapshot: String,
+    /// Art file base name
+    pub art: String,
+    /// Variant name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    /// Viewport name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewport: Option<String>,
+    pub status: VrtStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actual_path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_pixels: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anti_aliased_pixels: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_pixels: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_percentage: Option<f64>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub size_mismatch: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result counts by status.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VrtSummary {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub new: usize,
+    pub missing: usize,
+    pub errors: usize,
+}
+
+/// Report for a whole snapshot directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VrtReport {
+    pub summary: VrtSummary,
+    pub results: Vec<VrtResult>,
+}
+
+impl VrtReport {
+    /// Whether the run should succeed under the CI policy.
+    pub fn passed(&self, ci: &CiConfig) -> bool {
+        self.summary.errors == 0 && (!ci.fail_on_diff || self.summary.failed == 0)
+    }
+
+    /// Serialize as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Compare every PNG in `baseline_dir` with the same file in `actual_dir`.
+///
+/// Diff images are written to `diff_dir` for snapshots that changed.
+pub fn compare_dirs(
+    baseline_dir: &Path,
+    actual_dir: &Path,
+    diff_dir: Option<&Path>,
+    config: &VrtConfig,
+) -> Result<VrtReport, VrtError> {
+    let baselines = list_pngs(baseline_dir)?;
+    let actuals = list_pngs(actual_dir)?;
+
+    let mut names: Vec<&String> = baselines.iter().chain(&actuals).collect();
+    names.sort();
+    names.dedup();
+
+    let mut report = VrtReport::default();
+    for name in names {
+        let baseline_path = baselines.contains(name).then(|| baseline_dir.join(name));
+        let actual_path = actuals.contains(name).then(|| actual_dir.join(name));
+
+        let mut result = VrtResult::new(name);
+        result.status = match (&baseline_path, &actual_path) {
+            (Some(baseline), Some(actual)) => {
+                compare_files(&mut result, baseline, actual, diff_dir, config)?
+            }
+            (None, _) => VrtStatus::New,
+            (_, None) => VrtStatus::Missing,
+        };
+        result.baseline_path = baseline_path;
+        result.actual_path = actual_path;
+
+        let summary = &mut report.summary;
+        summary.total += 1;
+        match result.status {
+            VrtStatus::Passed => summary.passed += 1,
+            VrtStatus::Failed => summary.failed += 1,
+            VrtStatus::New => summary.new += 1,
+            VrtStatus::Missing => summary.missing += 1,
+            VrtStatus::Error => summary.errors += 1,
+        }
+        report.results.push(result);
+    }
+
+    Ok(report)
+}
+
+/// Compare one baseline/actual pair, recording stats on `result`.
+fn compare_files(
+    result: &mut VrtResult,
+    baseline_path: &Path,
+    actual_path: &Path,
+    diff_dir: Option<&Path>,
+    config: &VrtConfig,
+) -> Result<VrtStatus, VrtError> {
+    let images = read_png(baseline_path).and_then(|b| Ok((b, read_png(actual_path)?)));
+    let (baseline, actual) = match images {
+        Ok(images) => images,
+        Err(e @ VrtError::Io { .. }) => return Err(e),
+        Err(e) => {
+            result.error = Some(e.to_string());
+            return Ok(VrtStatus::Error);
+        }
+    };
+
+    let diff = compare_images(&baseline, &actual, &config.comparison, &config.threshold);
+    result.diff_pixels = Some(diff.diff_pixels);
+    result.anti_aliased_pixels = Some(diff.anti_aliased_pixels);
+    result.total_pixels = Some(diff.total_pixels);
+    result.diff_percentage = Some(diff.diff_percentage);
+    result.size_mismatch = diff.size_mismatch;
+
+    if let Some(diff_dir) = diff_dir.filter(|_| diff.has_diff()) {
+        let bytes = render_diff(&baseline, &actual, &diff, &config.comparison)?;
+        let path = diff_dir.join(&result.snapshot);
+        fs::create_dir_all(diff_dir)
+            .and_then(|_| fs::write(&path, bytes))
+            .map_err(|source| VrtError::Io {
+                path: path.display().to_string(),
+                source,
+            })?;
+        result.diff_path = Some(path);
+    }
+
+    Ok(if diff.passes(&config.threshold) {
+        VrtStatus::Passed
+    } else {
+        VrtStatus::Failed
+    })
+}
+
+impl VrtResult {
+    fn new(snapshot: &str) -> Self {
+        let stem = snapshot.strip_suffix(".png").unwrap_or(snapshot);
+        let mut parts = stem.splitn(3, "--");
+        let art = parts.next().unwrap_or(stem).to_string();
+        let variant = parts.next().map(str::to_string);
+        let viewport = parts.next().map(str::to_string);
+
+        Self {
+            snapshot: snapshot.to_string(),
+            art,
+            variant,
+            viewport,
+            status: VrtStatus::Passed,
+            baseline_path: None,
+            actual_path: None,
+            diff_path: None,
+            diff_pixels: None,
+            anti_aliased_pixels: None,
+            total_pixels: None,
+            diff_percentage: None,
+            size_mismatch: false,
+            error: None,
+        }
+    }
+}
+
+fn read_png(path: &Path) -> Result<Image, VrtError> {
+    let bytes = fs::read(path).map_err(|source| VrtError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+    Image::decode_png(&bytes)
+}
+
+/// File names of the PNGs directly inside `dir` (empty if it does not exist).
+fn list_pngs(dir: &Path) -> Result<Vec<String>, VrtError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(dir).map_err(|source| VrtError::Io {
+        path: dir.display().to_string(),
+        source,
+    })?;
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".png"))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_png(dir: &Path, name: &str, rgba: [u8; 4]) {
+        let mut image = Image::new(4, 4);
+        for y in 0..4 {
+            for x in 0..4 {
+                image.set_pixel(x, y, rgba);
+            }
+        }
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join(name), image.encode_png().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_compare_dirs() {
+        let root = std::env::temp_dir().join(format!("vize-vrt-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let baseline = root.join("baseline");
+        let actual = root.join("actual");
+        let diff = root.join("diff");
+
+        write_png(&baseline, "Button--Primary--desktop.png", [255, 0, 0, 255]);
+        write_png(&actual, "Button--Primary--desktop.png", [255, 0, 0, 255]);
+        write_png(
+            &baseline,
+            "Button--Disabled--mobile.png",
+            [255, 255, 255, 255],
+        );
+        write_png(&actual, "Button--Disabled--mobile.png", [0, 0, 0, 255]);
+        write_png(&actual, "Card--Default--desktop.png", [0, 0, 0, 255]);
+        write_png(&baseline, "Old--Default--desktop.png", [0, 0, 0, 255]);
+        fs::write(actual.join("Old--Default--desktop.png.txt"), "ignored").unwrap();
+        fs::write(baseline.join("Broken.png"), "not a png").unwrap();
+        fs::write(actual.join("Broken.png"), "not a png").unwrap();
+
+        let report = compare_dirs(&baseline, &actual, Some(&diff), &VrtConfig::default()).unwrap();
+        let _ = fs::remove_dir_all(&root);
+
+        let summary = &report.summary;
+        assert_eq!(
+            (
+                summary.total,
+                summary.passed,
+                summary.failed,
+                summary.new,
+                summary.missing,
+                summary.errors
+            ),
+            (5, 1, 1, 1, 1, 1)
+        );
+        assert!(!report.passed(&CiConfig::default()));
+
+        let failed = report
+            .results
+            .iter()
+            .find(|r| r.status == VrtStatus::Failed)
+            .unwrap();
+        assert_eq!(failed.art, "Button");
+        assert_eq!(failed.variant.as_deref(), Some("Disabled"));
+        assert_eq!(failed.viewport.as_deref(), Some("mobile"));
+        assert_eq!(failed.diff_pixels, Some(16));
+        assert_eq!(
+            failed.diff_path.as_deref(),
+            Some(diff.join("Button--Disabled--mobile.png").as_path())
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["summary"]["failed"], 1);
+        assert_eq!(json["results"][0]["status"], "error");
+        assert_eq!(json["results"][1]["status"], "failed");
+        assert_eq!(json["results"][1]["diffPercentage"], 100.0);
+    }
+
+    #[test]
+    fn test_ci_policy() {
+        let report = VrtReport {
+            summary: VrtSummary {
+                total: 1,
+                failed: 1,
+                ..Default::default()
+            },
+            results: Vec::new(),
+        };
+        assert!(!report.passed(&CiConfig::default()));
+        assert!(report.passed(&CiConfig {
+            fail_on_diff: false,
+            ..Default::default()
+        }));
+    }
+}
@@ -107,6 +107,8 @@ pub struct LintContext<'a> {
     ssr_mode: SsrMode,
     /// Help display level
     help_level: HelpLevel,
+    /// Opening `<template ...>` tag of the SFC block, when linting a full SFC
+    template_tag: Option<&'a str>,
 }
 
 impl<'a> LintContext<'a> {
@@ -147,6 +149,7 @@ impl<'a> LintContext<'a> {
             analysis: None,
             ssr_mode: SsrMode::default(),
             help_level: HelpLevel::default(),
+            template_tag: None,
         }
     }
 
@@ -176,6 +179,7 @@ impl<'a> LintContext<'a> {
             analysis: Some(analysis),
             ssr_mode: SsrMode::default(),
             help_level: HelpLevel::default(),
+            template_tag: None,
         }
     }
 
@@ -197,6 +201,21 @@ impl<'a> LintContext<'a> {
         self.analysis.is_some()
     }
 
+    /// Set the opening `<template ...>` tag of the SFC block being linted
+    #[inline]
+    pub fn set_template_tag(&mut self, tag: &'a str) {
+        self.template_tag = Some(tag);
+    }
+
+    /// Get the opening `<template ...>` tag of the SFC block (if known)
+    ///
+    /// Only available through [`crate::Linter::lint_sfc`]; the tag is not
+    /// part of `source`, so it can be inspected but not reported against.
+    #[inline]
+    pub fn template_tag(&self) -> Option<&'a str> {
+        self.template_tag
+    }
+
     /// Set SSR mode
     #[inline]
     pub fn set_ssr_mode(&mut self, mode: SsrMode) {
@@ -4,14 +4,15 @@
 
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use vize_carton::CompactString;
 
 /// Lint diagnostic severity
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     Error,
+    #[serde(alias = "warn")]
     Warning,
 }
 
@@ -82,22 +82,35 @@
 //! ### Script Rules (opt-in, default off)
 //! - `script/prefer-import-from-vue` - Prefer importing from 'vue' instead of internal packages
 //! - `script/no-internal-imports` - Disallow importing from Vue internal modules
+//!
+//! ### Threshold Rules (opt-in, configured through [`Thresholds`])
+//! - `vue/max-template-depth` - Enforce a maximum element nesting depth
+//! - `vue/max-bindings` - Enforce a maximum number of dynamic bindings
+//! - `script/max-props` - Enforce a maximum number of props
 
 mod context;
 mod diagnostic;
 mod linter;
+pub mod metrics;
 pub mod output;
 mod rule;
 pub mod rules;
+pub mod selector;
 pub mod telegraph;
 mod visitor;
 
 pub use context::LintContext;
 pub use diagnostic::{Fix, HelpLevel, LintDiagnostic, LintSummary, Severity, TextEdit};
 pub use linter::{LintResult, Linter};
+pub use metrics::{ScriptMetrics, SfcMetrics, TemplateMetrics, Thresholds};
 pub use output::{format_results, format_summary, OutputFormat};
 pub use rule::{Rule, RuleCategory, RuleMeta, RuleRegistry};
-pub use telegraph::{Emitter, JsonEmitter, LspDiagnostic, LspEmitter, Telegraph, TextEmitter};
+pub use rules::vue::{NoRestrictedSyntax, RestrictedSyntax};
+pub use selector::{Selector, SelectorError};
+pub use telegraph::{
+    CheckstyleEmitter, Emitter, GithubEmitter, JsonEmitter, JunitEmitter, LspDiagnostic,
+    LspEmitter, ReportDiagnostic, ReportFile, SarifEmitter, Telegraph, TextEmitter,
+};
 pub use vize_carton::i18n::Locale;
 
 /// Lint a Vue template source with default rules
@@ -4,7 +4,10 @@
 
 use crate::context::LintContext;
 use crate::diagnostic::{HelpLevel, LintDiagnostic, LintSummary};
+use crate::metrics::Thresholds;
 use crate::rule::RuleRegistry;
+use crate::rules::vue::{MaxBindings, MaxTemplateDepth, NoRestrictedSyntax, RestrictedSyntax};
+use crate::selector::SelectorError;
 use crate::visitor::LintVisitor;
 use vize_armature::Parser;
 use vize_carton::i18n::Locale;
@@ -114,6 +117,32 @@ impl Linter {
         self
     }
 
+    /// Add `vue/no-restricted-syntax` with the given selector entries
+    ///
+    /// Fails if any selector is invalid. An empty list leaves the linter unchanged.
+    pub fn with_restricted_syntax(
+        mut self,
+        entries: Vec<RestrictedSyntax>,
+    ) -> Result<Self, SelectorError> {
+        if !entries.is_empty() {
+            self.registry
+                .register(Box::new(NoRestrictedSyntax::new(entries)?));
+        }
+        Ok(self)
+    }
+
+    /// Add the template threshold rules (`vue/max-template-depth`,
+    /// `vue/max-bindings`) for the limits that are set
+    pub fn with_thresholds(mut self, thresholds: &Thresholds) -> Self {
+        if let Some(max) = thresholds.max_template_depth {
+            self.registry.register(Box::new(MaxTemplateDepth::new(max)));
+        }
+        if let Some(max) = thresholds.max_bindings {
+            self.registry.register(Box::new(MaxBindings::new(max)));
+        }
+        self
+    }
+
     /// Get the current locale
     #[inline]
     pub fn locale(&self) -> Locale {
@@ -145,6 +174,16 @@ impl Linter {
         allocator: &Allocator,
         source: &str,
         filename: &str,
+    ) -> LintResult {
+        self.lint_template_inner(allocator, source, filename, None)
+    }
+
+    fn lint_template_inner(
+        &self,
+        allocator: &Allocator,
+        source: &str,
+        filename: &str,
+        template_tag: Option<&str>,
     ) -> LintResult {
         // Parse the template
         let parser = Parser::new(allocator.as_bump(), source);
@@ -154,6 +193,9 @@ impl Linter {
         let mut ctx = LintContext::with_locale(allocator, source, filename, self.locale);
         ctx.set_enabled_rules(self.enabled_rules.clone());
         ctx.set_help_level(self.help_level);
+        if let Some(tag) = template_tag {
+            ctx.set_template_tag(tag);
+        }
 
         // Run visitor with all rules (filtering happens in context)
         let mut visitor = LintVisitor::new(&mut ctx, self.registry.rules());
@@ -212,7 +254,7 @@ impl Linter {
     #[inline]
     pub fn lint_sfc(&self, source: &str, filename: &str) -> LintResult {
         // Fast template extraction using memchr
-        let (content, byte_offset) = match extract_template_fast(source) {
+        let (content, tag_start, byte_offset) = match extract_template_fast(source) {
             Some(r) => r,
             None => {
                 return LintResult {
@@ -224,7 +266,11 @@ impl Linter {
             }
         };
 
-        let mut result = self.lint_template(&content, filename);
+        let capacity = (content.len() * 4).max(self.initial_capacity);
+        let allocator = Allocator::with_capacity(capacity);
+        let template_tag = &source[tag_start as usize..byte_offset as usize];
+        let mut result =
+            self.lint_template_inner(&allocator, &content, filename, Some(template_tag));
 
         // Adjust byte offsets in diagnostics to match original file positions
         if byte_offset > 0 {
@@ -243,8 +289,11 @@ impl Linter {
 }
 
 /// Ultra-fast template extraction using memchr for SIMD-accelerated search
+///
+/// Returns the template content, the offset of the opening `<template` tag
+/// and the offset of the content.
 #[inline]
-fn extract_template_fast(source: &str) -> Option<(String, u32)> {
+fn extract_template_fast(source: &str) -> Option<(String, u32, u32)> {
     let bytes = source.as_bytes();
 
     // Find <template using memchr (SIMD accelerated)
@@ -269,7 +318,7 @@ fn extract_template_fast(source: &str) -> Option<(String, u32)> {
         };
 
         // Check if it's <template or </template
-        if bytes.len() > next_lt + 9 && &bytes[next_lt..next_lt + 9] == b"<template" {
+        if bytes.len() >= next_lt + 9 && &bytes[next_lt..next_lt + 9] == b"<template" {
             // Check if self-closing
             if let Some(gt) = memchr::memchr(b'>', &bytes[next_lt..]) {
                 let tag_end_pos = next_lt + gt;
@@ -280,11 +329,11 @@ fn extract_template_fast(source: &str) -> Option<(String, u32)> {
             } else {
                 pos = next_lt + 9;
             }
-        } else if bytes.len() > next_lt + 11 && &bytes[next_lt..next_lt + 11] == b"</template>" {
+        } else if bytes.len() >= next_lt + 11 && &bytes[next_lt..next_lt + 11] == b"</template>" {
             depth -= 1;
             if depth == 0 {
                 let content = std::str::from_utf8(&bytes[content_start..next_lt]).ok()?;
-                return Some((content.to_string(), content_start as u32));
+                return Some((content.to_string(), start_idx as u32, content_start as u32));
             }
             pos = next_lt + 11;
         } else {
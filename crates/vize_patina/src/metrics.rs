@@ -0,0 +1,669 @@
+//! Complexity and size metrics for Vue SFCs.
+//!
+//! Template metrics are computed from the template AST, feeding the
+//! croquis [`OptimizationTracker`] the blocks, patch flags and cached
+//! handlers the compiler would produce. These are estimated from the
+//! directives of each element without running the compiler's transforms,
+//! so they can differ from the compiled output (e.g. for bindings to
+//! constant expressions, which the compiler does not patch). Script
+//! metrics come from croquis
+//! [`AnalysisStats`](vize_croquis::analysis::AnalysisStats) and an oxc pass
+//! computing the cyclomatic complexity of the setup function.
+//!
+//! Used by `vize stats` and the threshold rules (`vue/max-template-depth`,
+//! `vue/max-bindings`, `script/max-props`).
+
+use std::collections::BTreeMap;
+
+use oxc_allocator::Allocator as OxcAllocator;
+use oxc_ast::ast::{
+    CatchClause, ConditionalExpression, DoWhileStatement, ForInStatement, ForOfStatement,
+    ForStatement, IfStatement, LogicalExpression, ObjectProperty, SwitchCase, WhileStatement,
+};
+use oxc_ast::visit::walk;
+use oxc_ast::Visit;
+use oxc_parser::Parser as OxcParser;
+use oxc_span::SourceType;
+use serde::{Deserialize, Serialize};
+use vize_armature::Parser;
+use vize_carton::{Bump, CompactString, SmallVec};
+use vize_croquis::hoist::PatchFlags;
+use vize_croquis::optimization::{BlockType, OptimizationTracker};
+use vize_croquis::Analyzer;
+use vize_relief::ast::{
+    DirectiveNode, ElementNode, ElementType, ExpressionNode, PropNode, TemplateChildNode,
+};
+
+/// Limits checked by the threshold rules.
+///
+/// Unset limits are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Thresholds {
+    /// `vue/max-template-depth`: maximum element nesting depth
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_template_depth: Option<usize>,
+    /// `script/max-props`: maximum number of declared props
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_props: Option<usize>,
+    /// `vue/max-bindings`: maximum number of dynamic bindings in the template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_bindings: Option<usize>,
+}
+
+impl Thresholds {
+    /// Whether any limit is set
+    pub fn is_empty(&self) -> bool {
+        self.max_template_depth.is_none() && self.max_props.is_none() && self.max_bindings.is_none()
+    }
+}
+
+/// Metrics of a template.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateMetrics {
+    /// Maximum element nesting depth (root elements are at depth 1)
+    pub depth: usize,
+    /// Elements, interpolations, comments and non-whitespace text
+    pub node_count: usize,
+    pub element_count: usize,
+    /// Interpolations and runtime directives (`v-bind`, `v-on`, `v-model`, ...)
+    pub dynamic_bindings: usize,
+    /// Nodes that can be hoisted or cached as static content
+    pub hoistable_nodes: usize,
+    /// Nodes that are re-created or patched on every render
+    pub unhoistable_nodes: usize,
+    /// Estimated number of nodes carrying each patch flag
+    pub patch_flags: BTreeMap<String, usize>,
+    /// Blocks in the block tree (root, `v-if` branches, `v-for`, ...)
+    pub blocks: usize,
+    /// Event handlers cached by the compiler
+    pub cached_handlers: usize,
+    pub conditionals: usize,
+    pub loops: usize,
+    /// Maximum number of nested `v-if`/`v-else-if`/`v-else` branches
+    pub max_if_nesting: usize,
+    /// Maximum number of nested `v-for` loops
+    pub max_for_nesting: usize,
+}
+
+impl TemplateMetrics {
+    /// Compute metrics of template content (without the `<template>` tag).
+    pub fn from_template(source: &str) -> Self {
+        let allocator = Bump::new();
+        let (root, _) = Parser::new(&allocator, source).parse();
+
+        let mut walker = TemplateWalker {
+            metrics: Self::default(),
+            tracker: OptimizationTracker::new(),
+        };
+        walker
+            .tracker
+            .enter_block(BlockType::Root, 0, source.len() as u32);
+        let elements = root
+            .children
+            .iter()
+            .filter(|child| matches!(child, TemplateChildNode::Element(_)))
+            .count();
+        if elements > 1 {
+            walker.count_flags(PatchFlags::STABLE_FRAGMENT);
+        }
+        walker.walk(&root.children, Nesting::default());
+        walker.tracker.exit_block();
+
+        let mut metrics = walker.metrics;
+        metrics.blocks = walker.tracker.blocks().len();
+        metrics.cached_handlers = walker.tracker.event_cache().len();
+        metrics
+    }
+}
+
+/// Metrics of a `<script>` or `<script setup>` block.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptMetrics {
+    /// Lines of code, without blank and comment-only lines
+    pub loc: usize,
+    /// `ref`, `reactive`, `computed`, ... declarations
+    pub reactive_sources: usize,
+    pub props: usize,
+    pub emits: usize,
+    pub models: usize,
+    /// Bindings exposed to the template
+    pub bindings: usize,
+    /// Cyclomatic complexity of the setup function, including nested
+    /// callbacks. `None` when the block has no setup function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_complexity: Option<usize>,
+}
+
+impl ScriptMetrics {
+    /// Compute metrics of script block content.
+    ///
+    /// `is_setup` selects `<script setup>` analysis, where the whole block is
+    /// the setup function; otherwise the `setup()` option is measured.
+    pub fn from_script(source: &str, is_setup: bool, is_ts: bool) -> Self {
+        let mut analyzer = Analyzer::for_lint();
+        if is_setup {
+            analyzer.analyze_script_setup(source);
+        } else {
+            analyzer.analyze_script_plain(source);
+        }
+        let croquis = analyzer.finish();
+        let stats = croquis.stats();
+
+        Self {
+            loc: count_loc(source),
+            reactive_sources: croquis.reactivity.count(),
+            props: stats.prop_count,
+            emits: stats.emit_count,
+            models: stats.model_count,
+            bindings: stats.binding_count,
+            setup_complexity: setup_complexity(source, is_setup, is_ts),
+        }
+    }
+}
+
+/// Metrics of a whole SFC.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SfcMetrics {
+    pub filename: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<TemplateMetrics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script: Option<ScriptMetrics>,
+}
+
+/// Whether a directive is evaluated at runtime, as counted by
+/// `dynamic_bindings` and `vue/max-bindings`.
+///
+/// Structural and compile-time directives (`v-if`, `v-for`, `v-slot`,
+/// `v-once`, ...) are not bindings.
+pub fn is_dynamic_binding(directive: &DirectiveNode<'_>) -> bool {
+    !matches!(
+        directive.name.as_str(),
+        "if" | "else-if" | "else" | "for" | "slot" | "once" | "memo" | "pre" | "cloak"
+    )
+}
+
+/// Nesting of control-flow directives around a node.
+#[derive(Debug, Clone, Copy, Default)]
+struct Nesting {
+    depth: usize,
+    ifs: usize,
+    fors: usize,
+}
+
+struct TemplateWalker {
+    metrics: TemplateMetrics,
+    tracker: OptimizationTracker,
+}
+
+impl TemplateWalker {
+    /// Walk children, returning whether they are all static.
+    fn walk(&mut self, children: &[TemplateChildNode<'_>], nesting: Nesting) -> bool {
+        let mut all_static = true;
+        for child in children {
+            match child {
+                TemplateChildNode::Element(element) => {
+                    all_static &= self.element(element, nesting);
+                }
+                TemplateChildNode::Interpolation(_) => {
+                    self.metrics.node_count += 1;
+                    self.metrics.dynamic_bindings += 1;
+                    self.metrics.unhoistable_nodes += 1;
+                    all_static = false;
+                }
+                TemplateChildNode::Text(text) if text.content.trim().is_empty() => {}
+                TemplateChildNode::Text(_) | TemplateChildNode::Comment(_) => {
+                    self.metrics.node_count += 1;
+                    self.metrics.hoistable_nodes += 1;
+                }
+                _ => {}
+            }
+        }
+        all_static
+    }
+
+    /// Record an element and its subtree, returning whether it is static.
+    fn element(&mut self, element: &ElementNode<'_>, nesting: Nesting) -> bool {
+        let metrics = &mut self.metrics;
+        metrics.node_count += 1;
+        metrics.element_count += 1;
+
+        let directive = |name: &str| {
+            element.props.iter().find_map(|prop| match prop {
+                PropNode::Directive(dir) if dir.name == name => Some(dir),
+                _ => None,
+            })
+        };
+        let branch = directive("if")
+            .map(|_| BlockType::If)
+            .or_else(|| directive("else-if").map(|_| BlockType::ElseIf))
+            .or_else(|| directive("else").map(|_| BlockType::Else));
+        let is_for = directive("for").is_some();
+
+        let nesting = Nesting {
+            depth: nesting.depth + 1,
+            ifs: nesting.ifs + usize::from(branch.is_some()),
+            fors: nesting.fors + usize::from(is_for),
+        };
+        metrics.depth = metrics.depth.max(nesting.depth);
+        metrics.max_if_nesting = metrics.max_if_nesting.max(nesting.ifs);
+        metrics.max_for_nesting = metrics.max_for_nesting.max(nesting.fors);
+        metrics.conditionals += usize::from(branch.is_some());
+        metrics.loops += usize::from(is_for);
+
+        let start = element.loc.start.offset;
+        let end = element.loc.end.offset;
+        let mut blocks = 0;
+        if is_for {
+            self.tracker.enter_block(BlockType::For, start, end);
+            blocks += 1;
+            let has_key = has_static_or_bound(element, "key");
+            self.count_flags(if has_key {
+                PatchFlags::KEYED_FRAGMENT
+            } else {
+                PatchFlags::UNKEYED_FRAGMENT
+            });
+        }
+        if let Some(branch) = branch {
+            self.tracker.enter_block(branch, start, end);
+            blocks += 1;
+        }
+        let builtin = match element.tag.as_str() {
+            "Suspense" | "suspense" => Some(BlockType::Suspense),
+            "KeepAlive" | "keep-alive" => Some(BlockType::KeepAlive),
+            "Teleport" | "teleport" => Some(BlockType::Teleport),
+            "component" => Some(BlockType::Component),
+            _ => None,
+        };
+        if let Some(block_type) = builtin {
+            self.tracker.enter_block(block_type, start, end);
+            blocks += 1;
+        }
+
+        let (flags, dynamic_props, has_directives) = self.element_flags(element);
+        if !flags.is_empty() {
+            self.count_flags(flags);
+            self.tracker
+                .record_patch_info(start, end, flags, dynamic_props);
+            self.tracker.add_dynamic_child();
+        }
+
+        let children_static = self.walk(&element.children, nesting);
+        for _ in 0..blocks {
+            self.tracker.exit_block();
+        }
+
+        let is_static = element.tag_type == ElementType::Element
+            && !has_directives
+            && !element.props.iter().any(|prop| {
+                matches!(prop, PropNode::Attribute(attr) if attr.name == "ref" || attr.name == "key")
+            })
+            && children_static;
+        if is_static {
+            self.metrics.hoistable_nodes += 1;
+        } else {
+            self.metrics.unhoistable_nodes += 1;
+        }
+        is_static
+    }
+
+    /// Estimate the patch flags the compiler assigns to an element, from
+    /// its directives and children.
+    fn element_flags(
+        &mut self,
+        element: &ElementNode<'_>,
+    ) -> (PatchFlags, SmallVec<[CompactString; 4]>, bool) {
+        let is_component = element.tag_type == ElementType::Component;
+        let mut flags = PatchFlags::empty();
+        let mut dynamic_props = SmallVec::new();
+        let mut needs_patch = false;
+        let mut has_directives = false;
+
+        for prop in &element.props {
+            let dir = match prop {
+                PropNode::Attribute(attr) => {
+                    needs_patch |= attr.name == "ref";
+                    continue;
+                }
+                PropNode::Directive(dir) => dir,
+            };
+            has_directives = true;
+            if is_dynamic_binding(dir) {
+                self.metrics.dynamic_bindings += 1;
+            }
+
+            let arg = static_arg(dir);
+            match (dir.name.as_str(), arg) {
+                ("bind", None) => flags |= PatchFlags::FULL_PROPS,
+                ("bind", Some("class")) => flags |= PatchFlags::CLASS,
+                ("bind", Some("style")) => flags |= PatchFlags::STYLE,
+                ("bind", Some("key")) => {}
+                ("bind", Some("ref")) => needs_patch = true,
+                ("bind", Some(name)) => {
+                    flags |= PatchFlags::PROPS;
+                    dynamic_props.push(CompactString::new(name));
+                }
+                ("on", None) => flags |= PatchFlags::FULL_PROPS,
+                ("on", Some(event)) => {
+                    let handler = match &dir.exp {
+                        Some(ExpressionNode::Simple(exp)) => exp.content.as_str(),
+                        _ => "",
+                    };
+                    let is_inline = !handler
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '.'));
+                    self.tracker.cache_event(
+                        CompactString::new(event),
+                        CompactString::new(handler),
+                        is_inline,
+                        is_component,
+                        dir.loc.start.offset,
+                        dir.loc.end.offset,
+                    );
+                    if !is_component && event != "click" {
+                        flags |= PatchFlags::NEED_HYDRATION;
+                    }
+                }
+                ("model", _) if is_component => {
+                    flags |= PatchFlags::PROPS;
+                    let name = arg.unwrap_or("modelValue");
+                    dynamic_props.push(CompactString::new(name));
+                }
+                ("model" | "show", _) => needs_patch = true,
+                ("html", _) => {
+                    flags |= PatchFlags::PROPS;
+                    dynamic_props.push(CompactString::new("innerHTML"));
+                }
+                ("text", _) => {
+                    flags |= PatchFlags::PROPS;
+                    dynamic_props.push(CompactString::new("textContent"));
+                }
+                (
+                    "if" | "else-if" | "else" | "for" | "slot" | "once" | "memo" | "pre" | "cloak",
+                    _,
+                ) => {}
+                // Custom directives
+                _ => needs_patch = true,
+            }
+        }
+
+        let has_text = element
+            .children
+            .iter()
+            .any(|child| matches!(child, TemplateChildNode::Interpolation(_)));
+        if has_text && !is_component {
+            flags |= PatchFlags::TEXT;
+        }
+        if is_component && has_dynamic_slots(element) {
+            flags |= PatchFlags::DYNAMIC_SLOTS;
+        }
+        if flags.is_empty() && needs_patch {
+            flags |= PatchFlags::NEED_PATCH;
+        }
+
+        (flags, dynamic_props, has_directives)
+    }
+
+    fn count_flags(&mut self, flags: PatchFlags) {
+        for name in flags.flag_names() {
+            *self
+                .metrics
+                .patch_flags
+                .entry(name.to_string())
+                .or_default() += 1;
+        }
+    }
+}
+
+fn static_arg<'d>(dir: &'d DirectiveNode<'_>) -> Option<&'d str> {
+    match &dir.arg {
+        Some(ExpressionNode::Simple(arg)) if arg.is_static => Some(arg.content.as_str()),
+        _ => None,
+    }
+}
+
+fn has_static_or_bound(element: &ElementNode<'_>, name: &str) -> bool {
+    element.props.iter().any(|prop| match prop {
+        PropNode::Attribute(attr) => attr.name == name,
+        PropNode::Directive(dir) => dir.name == "bind" && static_arg(dir) == Some(name),
+    })
+}
+
+/// Whether slots passed to a component change at runtime.
+fn has_dynamic_slots(element: &ElementNode<'_>) -> bool {
+    element.children.iter().any(|child| {
+        let TemplateChildNode::Element(template) = child else {
+            return false;
+        };
+        let mut is_slot = false;
+        let mut is_dynamic = false;
+        for prop in &template.props {
+            if let PropNode::Directive(dir) = prop {
+                match dir.name.as_str() {
+                    "slot" => {
+                        is_slot = true;
+                        is_dynamic |= dir.arg.is_some() && static_arg(dir).is_none();
+                    }
+                    "if" | "else-if" | "else" | "for" => is_dynamic = true,
+                    _ => {}
+                }
+            }
+        }
+        is_slot && is_dynamic
+    })
+}
+
+/// Count lines that are neither blank nor comment-only.
+fn count_loc(source: &str) -> usize {
+    let mut in_block_comment = false;
+    source
+        .lines()
+        .filter(|line| {
+            let line = line.trim();
+            if in_block_comment {
+                if line.contains("*/") {
+                    in_block_comment = false;
+                }
+                return false;
+            }
+            if line.starts_with("/*") {
+                in_block_comment = !line.contains("*/");
+                return false;
+            }
+            !line.is_empty() && !line.starts_with("//")
+        })
+        .count()
+}
+
+/// Cyclomatic complexity of the setup function.
+fn setup_complexity(source: &str, is_setup: bool, is_ts: bool) -> Option<usize> {
+    let allocator = OxcAllocator::default();
+    let source_type = SourceType::mjs().with_typescript(is_ts);
+    let parsed = OxcParser::new(&allocator, source, source_type).parse();
+    if parsed.panicked {
+        return None;
+    }
+
+    let mut counter = ComplexityCounter {
+        in_setup: usize::from(is_setup),
+        found: is_setup,
+        decisions: 0,
+    };
+    counter.visit_program(&parsed.program);
+    counter.found.then_some(counter.decisions + 1)
+}
+
+/// Counts decision points inside the setup function.
+struct ComplexityCounter {
+    /// Greater than zero while inside the setup function
+    in_setup: usize,
+    found: bool,
+    decisions: usize,
+}
+
+impl ComplexityCounter {
+    fn decision(&mut self) {
+        if self.in_setup > 0 {
+            self.decisions += 1;
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ComplexityCounter {
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        let is_setup = it.key.static_name().is_some_and(|name| name == "setup");
+        if is_setup {
+            self.found = true;
+            self.in_setup += 1;
+        }
+        walk::walk_object_property(self, it);
+        if is_setup {
+            self.in_setup -= 1;
+        }
+    }
+
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.decision();
+        walk::walk_if_statement(self, it);
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.decision();
+        walk::walk_conditional_expression(self, it);
+    }
+
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        self.decision();
+        walk::walk_logical_expression(self, it);
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.decision();
+        walk::walk_for_statement(self, it);
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.decision();
+        walk::walk_for_in_statement(self, it);
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.decision();
+        walk::walk_for_of_statement(self, it);
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.decision();
+        walk::walk_while_statement(self, it);
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.decision();
+        walk::walk_do_while_statement(self, it);
+    }
+
+    fn visit_switch_case(&mut self, it: &SwitchCase<'a>) {
+        if it.test.is_some() {
+            self.decision();
+        }
+        walk::walk_switch_case(self, it);
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        self.decision();
+        walk::walk_catch_clause(self, it);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_metrics() {
+        let metrics = TemplateMetrics::from_template(
+            r#"<header><h1>Title</h1></header>
+<ul v-if="items.length">
+  <li v-for="item in items" :key="item.id" :class="{ active: item.active }" @click="select(item)">{{ item.name }}</li>
+</ul>
+<p v-else>Empty</p>"#,
+        );
+
+        assert_eq!(metrics.depth, 2);
+        assert_eq!(metrics.element_count, 5);
+        // :key, :class, @click and the interpolation
+        assert_eq!(metrics.dynamic_bindings, 4);
+        assert_eq!(metrics.conditionals, 2);
+        assert_eq!(metrics.loops, 1);
+        assert_eq!(metrics.max_if_nesting, 1);
+        assert_eq!(metrics.max_for_nesting, 1);
+        assert_eq!(metrics.blocks, 4);
+        assert_eq!(metrics.cached_handlers, 1);
+        assert_eq!(metrics.patch_flags.get("CLASS"), Some(&1));
+        assert_eq!(metrics.patch_flags.get("TEXT"), Some(&1));
+        assert_eq!(metrics.patch_flags.get("KEYED_FRAGMENT"), Some(&1));
+        assert_eq!(metrics.patch_flags.get("STABLE_FRAGMENT"), Some(&1));
+        // header, h1, "Title" and "Empty"
+        assert_eq!(metrics.hoistable_nodes, 4);
+    }
+
+    #[test]
+    fn test_component_flags() {
+        let metrics = TemplateMetrics::from_template(
+            r#"<MyList v-model="value" :items="items"><template v-if="x" #footer>x</template></MyList>"#,
+        );
+
+        assert_eq!(metrics.patch_flags.get("PROPS"), Some(&1));
+        assert_eq!(metrics.patch_flags.get("DYNAMIC_SLOTS"), Some(&1));
+        assert_eq!(metrics.hoistable_nodes, 1);
+    }
+
+    #[test]
+    fn test_script_setup_metrics() {
+        let metrics = ScriptMetrics::from_script(
+            r#"import { ref, computed } from 'vue'
+
+// Props
+const props = defineProps<{ items: string[]; limit?: number }>()
+const emit = defineEmits<{ select: [string] }>()
+const query = ref('')
+/*
+ * Filtered items
+ */
+const visible = computed(() =>
+  props.items.filter((item) => item.includes(query.value) || !query.value)
+)
+function select(item: string) {
+  if (props.limit && visible.value.length > props.limit) return
+  emit('select', item)
+}
+"#,
+            true,
+            true,
+        );
+
+        assert_eq!(metrics.loc, 11);
+        assert_eq!(metrics.props, 2);
+        assert_eq!(metrics.emits, 1);
+        assert_eq!(metrics.reactive_sources, 2);
+        // if, ||, &&
+        assert_eq!(metrics.setup_complexity, Some(4));
+    }
+
+    #[test]
+    fn test_options_setup_complexity() {
+        let source = r#"export default {
+  methods: { a() { return this.x ? 1 : 2 } },
+  setup(props) {
+    for (const key of Object.keys(props)) {}
+    return {}
+  }
+}"#;
+        assert_eq!(setup_complexity(source, false, false), Some(2));
+        assert_eq!(setup_complexity("export default {}", false, false), None);
+    }
+}
@@ -5,6 +5,7 @@ mod text;
 pub use text::*;
 
 use crate::linter::LintResult;
+use crate::telegraph::{CheckstyleEmitter, GithubEmitter, JunitEmitter, ReportFile, SarifEmitter};
 use serde::Serialize;
 
 /// Output format for lint results
@@ -15,6 +16,51 @@ pub enum OutputFormat {
     Text,
     /// JSON output for tooling integration
     Json,
+    /// SARIF 2.1.0 log for code scanning
+    Sarif,
+    /// JUnit XML test report
+    Junit,
+    /// Checkstyle XML report
+    Checkstyle,
+    /// GitHub Actions workflow annotations
+    Github,
+}
+
+impl OutputFormat {
+    /// Names accepted by `--format`
+    pub const NAMES: &'static [&'static str] =
+        &["text", "json", "sarif", "junit", "checkstyle", "github"];
+
+    /// Parse a `--format` value (case-insensitive)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "sarif" => Some(Self::Sarif),
+            "junit" => Some(Self::Junit),
+            "checkstyle" => Some(Self::Checkstyle),
+            "github" => Some(Self::Github),
+            _ => None,
+        }
+    }
+
+    /// Whether this format should be printed without human-readable summaries
+    pub fn is_machine_readable(self) -> bool {
+        !matches!(self, Self::Text)
+    }
+
+    /// Render tool-neutral reports in a document format.
+    ///
+    /// Returns `None` for `Text` and `Json`, whose layout is tool specific.
+    pub fn render_reports(self, tool_name: &'static str, files: &[ReportFile]) -> Option<String> {
+        match self {
+            Self::Text | Self::Json => None,
+            Self::Sarif => Some(SarifEmitter::new(tool_name).render(files)),
+            Self::Junit => Some(JunitEmitter::new(tool_name).render(files)),
+            Self::Checkstyle => Some(CheckstyleEmitter::render(files)),
+            Self::Github => Some(GithubEmitter::render(files)),
+        }
+    }
 }
 
 /// Format lint results according to the specified format
@@ -26,6 +72,22 @@ pub fn format_results(
     match format {
         OutputFormat::Text => format_text(results, sources),
         OutputFormat::Json => format_json(results),
+        _ => {
+            let files: Vec<ReportFile> = results
+                .iter()
+                .map(|r| {
+                    let source = sources
+                        .iter()
+                        .find(|(name, _)| name == &r.filename)
+                        .map(|(_, s)| s.as_str())
+                        .unwrap_or_default();
+                    ReportFile::from_lint(r, source)
+                })
+                .collect();
+            format
+                .render_reports("vize-patina", &files)
+                .unwrap_or_default()
+        }
     }
 }
 
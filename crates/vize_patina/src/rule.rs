@@ -21,6 +21,8 @@ pub enum RuleCategory {
     Accessibility,
     /// Type-aware rules (require semantic analysis)
     TypeAware,
+    /// Vue 2 → Vue 3 migration rules (deprecated syntax and APIs)
+    Migration,
 }
 
 /// Rule metadata
@@ -212,30 +214,7 @@ impl RuleRegistry {
         // These rules help ensure Vue templates are accessible to all users.
         // Based on eslint-plugin-vuejs-accessibility.
 
-        registry.register(Box::new(crate::rules::a11y::ImgAlt));
-        registry.register(Box::new(crate::rules::a11y::AnchorHasContent));
-        registry.register(Box::new(crate::rules::a11y::HeadingHasContent));
-        registry.register(Box::new(crate::rules::a11y::IframeHasTitle));
-        registry.register(Box::new(crate::rules::a11y::NoDistractingElements));
-        registry.register(Box::new(crate::rules::a11y::TabindexNoPositive));
-        registry.register(Box::new(crate::rules::a11y::ClickEventsHaveKeyEvents));
-        registry.register(Box::new(crate::rules::a11y::FormControlHasLabel));
-        registry.register(Box::new(crate::rules::a11y::AriaProps));
-        registry.register(Box::new(crate::rules::a11y::AriaRole::default()));
-        registry.register(Box::new(crate::rules::a11y::NoAriaHiddenOnFocusable));
-        registry.register(Box::new(crate::rules::a11y::NoAccessKey));
-        registry.register(Box::new(crate::rules::a11y::NoAutofocus));
-        registry.register(Box::new(crate::rules::a11y::NoRolePresentationOnFocusable));
-        registry.register(Box::new(crate::rules::a11y::AriaUnsupportedElements));
-        registry.register(Box::new(crate::rules::a11y::NoRedundantRoles));
-        registry.register(Box::new(crate::rules::a11y::MouseEventsHaveKeyEvents));
-        registry.register(Box::new(crate::rules::a11y::AltText));
-        registry.register(Box::new(crate::rules::a11y::AnchorIsValid));
-        registry.register(Box::new(crate::rules::a11y::LabelHasFor));
-        registry.register(Box::new(crate::rules::a11y::InteractiveSupportsFocus));
-        registry.register(Box::new(crate::rules::a11y::RoleHasRequiredAriaProps));
-        registry.register(Box::new(crate::rules::a11y::MediaHasCaption));
-        registry.register(Box::new(crate::rules::a11y::NoStaticElementInteractions));
+        registry.register_a11y();
         registry.register(Box::new(crate::rules::vue::UseUniqueElementIds::default()));
 
         // ============================================
@@ -258,6 +237,43 @@ impl RuleRegistry {
         registry
     }
 
+    /// Create registry with only the accessibility rules
+    ///
+    /// Used to audit rendered markup, e.g. Musea variants expanded with
+    /// their args.
+    pub fn with_a11y() -> Self {
+        let mut registry = Self::new();
+        registry.register_a11y();
+        registry
+    }
+
+    fn register_a11y(&mut self) {
+        self.register(Box::new(crate::rules::a11y::ImgAlt));
+        self.register(Box::new(crate::rules::a11y::AnchorHasContent));
+        self.register(Box::new(crate::rules::a11y::HeadingHasContent));
+        self.register(Box::new(crate::rules::a11y::IframeHasTitle));
+        self.register(Box::new(crate::rules::a11y::NoDistractingElements));
+        self.register(Box::new(crate::rules::a11y::TabindexNoPositive));
+        self.register(Box::new(crate::rules::a11y::ClickEventsHaveKeyEvents));
+        self.register(Box::new(crate::rules::a11y::FormControlHasLabel));
+        self.register(Box::new(crate::rules::a11y::AriaProps));
+        self.register(Box::new(crate::rules::a11y::AriaRole::default()));
+        self.register(Box::new(crate::rules::a11y::NoAriaHiddenOnFocusable));
+        self.register(Box::new(crate::rules::a11y::NoAccessKey));
+        self.register(Box::new(crate::rules::a11y::NoAutofocus));
+        self.register(Box::new(crate::rules::a11y::NoRolePresentationOnFocusable));
+        self.register(Box::new(crate::rules::a11y::AriaUnsupportedElements));
+        self.register(Box::new(crate::rules::a11y::NoRedundantRoles));
+        self.register(Box::new(crate::rules::a11y::MouseEventsHaveKeyEvents));
+        self.register(Box::new(crate::rules::a11y::AltText));
+        self.register(Box::new(crate::rules::a11y::AnchorIsValid));
+        self.register(Box::new(crate::rules::a11y::LabelHasFor));
+        self.register(Box::new(crate::rules::a11y::InteractiveSupportsFocus));
+        self.register(Box::new(crate::rules::a11y::RoleHasRequiredAriaProps));
+        self.register(Box::new(crate::rules::a11y::MediaHasCaption));
+        self.register(Box::new(crate::rules::a11y::NoStaticElementInteractions));
+    }
+
     /// Create registry with only essential rules (errors only)
     ///
     /// Use this for minimal checking that only catches definite errors.
@@ -334,6 +350,47 @@ impl RuleRegistry {
         registry
     }
 
+    /// Create registry with recommended rules plus Vue 2 → Vue 3 migration rules
+    ///
+    /// Use this while porting a Vue 2 / 2.7 codebase. Most migration rules
+    /// carry a fix for the mechanical part of the rewrite.
+    pub fn with_migration() -> Self {
+        let mut registry = Self::with_recommended();
+
+        // ============================================
+        // Migration Rules (Error)
+        // ============================================
+        // Syntax and APIs removed or changed in Vue 3.
+
+        registry.register(Box::new(crate::rules::migration::NoDeprecatedFilter));
+        registry.register(Box::new(crate::rules::migration::NoDeprecatedVBindSync));
+        registry.register(Box::new(
+            crate::rules::migration::NoDeprecatedVOnNativeModifier,
+        ));
+        registry.register(Box::new(
+            crate::rules::migration::NoDeprecatedDollarListenersApi,
+        ));
+        registry.register(Box::new(crate::rules::migration::NoDeprecatedVOnListeners));
+        registry.register(Box::new(
+            crate::rules::migration::NoDeprecatedVBindObjectOrder,
+        ));
+        registry.register(Box::new(crate::rules::migration::NoDeprecatedSlotAttribute));
+        registry.register(Box::new(
+            crate::rules::migration::NoDeprecatedSlotScopeAttribute,
+        ));
+        registry.register(Box::new(
+            crate::rules::migration::NoDeprecatedDollarScopedslotsApi,
+        ));
+        registry.register(Box::new(
+            crate::rules::migration::NoDeprecatedFunctionalTemplate,
+        ));
+        registry.register(Box::new(
+            crate::rules::migration::NoDeprecatedVOnNumberModifiers,
+        ));
+
+        registry
+    }
+
     /// Create registry with Nuxt-friendly rules (auto-imports enabled)
     pub fn with_nuxt() -> Self {
         let mut registry = Self::with_recommended();
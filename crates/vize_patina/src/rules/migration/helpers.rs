@@ -0,0 +1,301 @@
+//! Shared helper functions for migration rules.
+
+use vize_relief::ast::{
+    AttributeNode, DirectiveNode, ElementNode, ExpressionNode, PropNode, SimpleExpressionNode,
+    SourceLocation,
+};
+
+/// Get the simple expression node of a directive argument or value
+#[inline]
+pub fn simple<'a, 'b>(exp: Option<&'b ExpressionNode<'a>>) -> Option<&'b SimpleExpressionNode<'a>> {
+    match exp? {
+        ExpressionNode::Simple(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Static argument of a directive (`click` in `@click`), if any
+#[inline]
+pub fn static_arg<'b>(directive: &'b DirectiveNode<'_>) -> Option<&'b str> {
+    simple(directive.arg.as_ref())
+        .filter(|arg| arg.is_static)
+        .map(|arg| arg.content.as_str())
+}
+
+/// Byte range of a whole attribute or directive in the template source.
+///
+/// Prop locations stop before the closing quote of a quoted value, so the
+/// quote is included here to allow replacing the prop as a whole.
+pub fn prop_range(source: &str, loc: &SourceLocation) -> (u32, u32) {
+    let start = loc.start.offset;
+    let mut end = loc.end.offset;
+    let text = source.get(start as usize..end as usize).unwrap_or("");
+    if let Some(eq) = text.find('=') {
+        let opening = text[eq + 1..].trim_start().as_bytes().first().copied();
+        if matches!(opening, Some(b'"' | b'\''))
+            && source.as_bytes().get(end as usize).copied() == opening
+        {
+            end += 1;
+        }
+    }
+    (start, end)
+}
+
+/// Byte range of the name part of an attribute or directive (`:foo.sync`)
+pub fn prop_name_range(source: &str, loc: &SourceLocation) -> (u32, u32) {
+    let start = loc.start.offset;
+    let text = source
+        .get(start as usize..loc.end.offset as usize)
+        .unwrap_or("");
+    let len = text.find('=').unwrap_or(text.len());
+    (start, start + text[..len].trim_end().len() as u32)
+}
+
+/// Byte range to delete a prop, including the whitespace before it
+pub fn removal_range(source: &str, loc: &SourceLocation) -> (u32, u32) {
+    let (start, end) = prop_range(source, loc);
+    let leading = source[..start as usize]
+        .bytes()
+        .rev()
+        .take_while(|b| b.is_ascii_whitespace())
+        .count();
+    (start - leading as u32, end)
+}
+
+/// Find a static attribute by name
+pub fn find_attribute<'b>(element: &'b ElementNode<'_>, name: &str) -> Option<&'b AttributeNode> {
+    element.props.iter().find_map(|prop| match prop {
+        PropNode::Attribute(attr) if attr.name == name => Some(&**attr),
+        _ => None,
+    })
+}
+
+/// Whether a byte can be part of a JavaScript identifier
+#[inline]
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$'
+}
+
+/// Find occurrences of a free identifier in an expression.
+///
+/// Property accesses (`a.name`, but not spreads) and string literals are skipped. Returns
+/// byte offsets into `expr`.
+pub fn find_identifier(expr: &str, name: &str) -> Vec<usize> {
+    let bytes = expr.as_bytes();
+    let mut found = Vec::new();
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == b'\\' {
+                i += 2;
+                continue;
+            }
+            if b == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+        match b {
+            b'"' | b'\'' | b'`' => quote = Some(b),
+            _ if bytes[i..].starts_with(name.as_bytes()) => {
+                let before = i.checked_sub(1).map(|p| bytes[p]);
+                let after = bytes.get(i + name.len()).copied();
+                let free = match before {
+                    Some(b'.') => bytes[..i].ends_with(b"..."),
+                    Some(p) => !is_ident_byte(p),
+                    None => true,
+                };
+                if free && !after.is_some_and(is_ident_byte) {
+                    found.push(i);
+                    i += name.len();
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    found
+}
+
+/// Split an expression on Vue 2 filter pipes.
+///
+/// Only top-level single `|` characters count (not `||` or `|=`), and only
+/// when every filter is a plain name or call, so bitwise ORs such as
+/// `flags | 0` are left alone. Returns `None` when the expression has no
+/// filter.
+pub fn split_filters(expr: &str) -> Option<Vec<&str>> {
+    let bytes = expr.as_bytes();
+    let mut segments = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<u8> = None;
+    let mut last = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == b'\\' {
+                i += 2;
+                continue;
+            }
+            if b == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+        match b {
+            b'"' | b'\'' | b'`' => quote = Some(b),
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b'|' if depth == 0 => {
+                let prev = i.checked_sub(1).map(|p| bytes[p]);
+                let next = bytes.get(i + 1).copied();
+                if prev != Some(b'|') && !matches!(next, Some(b'|' | b'=')) {
+                    segments.push(expr[last..i].trim());
+                    last = i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    if segments.is_empty() {
+        return None;
+    }
+    segments.push(expr[last..].trim());
+    if !segments[1..]
+        .iter()
+        .all(|filter| parse_filter(filter).is_some())
+    {
+        return None;
+    }
+    Some(segments)
+}
+
+/// Split a filter into its name and call arguments: `wrap('x', 2)` ->
+/// `("wrap", "'x', 2")`, `upper` -> `("upper", "")`.
+///
+/// Returns `None` when the filter is not a plain name or call.
+fn parse_filter(filter: &str) -> Option<(&str, &str)> {
+    let (name, args) = match filter.find('(') {
+        Some(open) if filter.ends_with(')') => {
+            (filter[..open].trim(), &filter[open + 1..filter.len() - 1])
+        }
+        _ => (filter, ""),
+    };
+    let valid_name = name.bytes().all(|b| is_ident_byte(b) || b == b'.')
+        && name
+            .bytes()
+            .next()
+            .is_some_and(|b| !b.is_ascii_digit() && b != b'.');
+    if !valid_name {
+        return None;
+    }
+
+    // The arguments must be enclosed by a single pair of parentheses
+    // (`f(a)`, not `f(a) + g(b)`)
+    let bytes = args.as_bytes();
+    let mut depth = 0i32;
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == b'\\' {
+                i += 2;
+                continue;
+            }
+            if b == q {
+                quote = None;
+            }
+        } else {
+            match b {
+                b'"' | b'\'' | b'`' => quote = Some(b),
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => {
+                    depth -= 1;
+                    if depth < 0 {
+                        return None;
+                    }
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+
+    Some((name, args.trim()))
+}
+
+/// Rewrite filter segments (`value`, `a`, `b(x)`) into calls: `b(a(value), x)`.
+///
+/// Returns `None` when a filter is not a plain name or call.
+pub fn rewrite_filters(segments: &[&str]) -> Option<String> {
+    let (value, filters) = segments.split_first()?;
+    if value.is_empty() {
+        return None;
+    }
+
+    let mut acc = value.to_string();
+    for filter in filters {
+        let (name, args) = parse_filter(filter)?;
+        acc = if args.is_empty() {
+            format!("{}({})", name, acc)
+        } else {
+            format!("{}({}, {})", name, acc, args)
+        };
+    }
+
+    Some(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_identifier() {
+        assert_eq!(find_identifier("$listeners", "$listeners"), vec![0]);
+        assert_eq!(
+            find_identifier("{ ...$listeners, a }", "$listeners"),
+            vec![5]
+        );
+        assert!(find_identifier("vm.$listeners", "$listeners").is_empty());
+        assert!(find_identifier("'$listeners'", "$listeners").is_empty());
+        assert!(find_identifier("$listenersX", "$listeners").is_empty());
+    }
+
+    #[test]
+    fn test_split_filters() {
+        assert_eq!(split_filters("a | b"), Some(vec!["a", "b"]));
+        assert_eq!(
+            split_filters("msg | upper | wrap('|')"),
+            Some(vec!["msg", "upper", "wrap('|')"])
+        );
+        assert_eq!(split_filters("a || b"), None);
+        assert_eq!(split_filters("fn(a | b)"), None);
+        assert_eq!(split_filters("x |= 1"), None);
+        assert_eq!(split_filters("flags | 0"), None);
+        assert_eq!(split_filters("a | b + c"), None);
+        assert_eq!(split_filters("a | f(x) + g(y)"), None);
+    }
+
+    #[test]
+    fn test_rewrite_filters() {
+        assert_eq!(
+            rewrite_filters(&["msg", "upper", "wrap('x', 2)"]).as_deref(),
+            Some("wrap(upper(msg), 'x', 2)")
+        );
+        assert_eq!(rewrite_filters(&["msg", "a + b"]), None);
+        assert_eq!(rewrite_filters(&["msg", "f(a) + g(b)"]), None);
+        assert_eq!(rewrite_filters(&["", "upper"]), None);
+    }
+}
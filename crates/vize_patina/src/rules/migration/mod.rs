@@ -0,0 +1,31 @@
+//! Vue 2 → Vue 3 migration lint rules.
+//!
+//! These rules flag template syntax and APIs that were deprecated or removed
+//! in Vue 3, and carry a fix wherever the rewrite is mechanical.
+//!
+//! Based on the `no-deprecated-*` rules of [eslint-plugin-vue](https://eslint.vuejs.org/rules/).
+
+mod helpers;
+mod no_deprecated_dollar_listeners_api;
+mod no_deprecated_dollar_scopedslots_api;
+mod no_deprecated_filter;
+mod no_deprecated_functional_template;
+mod no_deprecated_slot_attribute;
+mod no_deprecated_slot_scope_attribute;
+mod no_deprecated_v_bind_object_order;
+mod no_deprecated_v_bind_sync;
+mod no_deprecated_v_on_listeners;
+mod no_deprecated_v_on_native_modifier;
+mod no_deprecated_v_on_number_modifiers;
+
+pub use no_deprecated_dollar_listeners_api::NoDeprecatedDollarListenersApi;
+pub use no_deprecated_dollar_scopedslots_api::NoDeprecatedDollarScopedslotsApi;
+pub use no_deprecated_filter::NoDeprecatedFilter;
+pub use no_deprecated_functional_template::NoDeprecatedFunctionalTemplate;
+pub use no_deprecated_slot_attribute::NoDeprecatedSlotAttribute;
+pub use no_deprecated_slot_scope_attribute::NoDeprecatedSlotScopeAttribute;
+pub use no_deprecated_v_bind_object_order::NoDeprecatedVBindObjectOrder;
+pub use no_deprecated_v_bind_sync::NoDeprecatedVBindSync;
+pub use no_deprecated_v_on_listeners::NoDeprecatedVOnListeners;
+pub use no_deprecated_v_on_native_modifier::NoDeprecatedVOnNativeModifier;
+pub use no_deprecated_v_on_number_modifiers::NoDeprecatedVOnNumberModifiers;
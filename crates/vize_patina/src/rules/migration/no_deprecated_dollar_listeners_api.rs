@@ -0,0 +1,119 @@
+//! vue/no-deprecated-dollar-listeners-api
+//!
+//! Disallow `$listeners`, removed in Vue 3.
+//!
+//! Listeners are now part of `$attrs`. `v-on="$listeners"` is reported by
+//! `vue/no-deprecated-v-on-listeners`, which can fix it mechanically.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <MyInput v-on="{ ...$listeners, input: onInput }" />
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <MyInput v-bind="$attrs" @input="onInput" />
+//! ```
+
+use super::helpers::{find_identifier, simple};
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode, InterpolationNode, SimpleExpressionNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-dollar-listeners-api",
+    description: "Disallow `$listeners` (merged into `$attrs` in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: false,
+    default_severity: Severity::Error,
+};
+
+const LISTENERS: &str = "$listeners";
+
+/// Disallow `$listeners`
+pub struct NoDeprecatedDollarListenersApi;
+
+impl NoDeprecatedDollarListenersApi {
+    fn check(ctx: &mut LintContext<'_>, exp: &SimpleExpressionNode<'_>) {
+        for offset in find_identifier(&exp.content, LISTENERS) {
+            let start = exp.loc.start.offset + offset as u32;
+            ctx.report(
+                LintDiagnostic::error(
+                    META.name,
+                    "`$listeners` is removed in Vue 3",
+                    start,
+                    start + LISTENERS.len() as u32,
+                )
+                .with_help("Listeners are included in `$attrs` in Vue 3"),
+            );
+        }
+    }
+}
+
+impl Rule for NoDeprecatedDollarListenersApi {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_interpolation<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        interpolation: &InterpolationNode<'a>,
+    ) {
+        if let Some(exp) = simple(Some(&interpolation.content)) {
+            Self::check(ctx, exp);
+        }
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        let Some(exp) = simple(directive.exp.as_ref()) else {
+            return;
+        };
+        // Covered by vue/no-deprecated-v-on-listeners
+        if directive.name == "on" && directive.arg.is_none() && exp.content.trim() == LISTENERS {
+            return;
+        }
+        Self::check(ctx, exp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedDollarListenersApi));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<MyInput v-bind="$attrs" v-on="$listeners" :label="'$listeners'" />"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<MyInput v-on="{ ...$listeners, input: onInput }" :has="!!$listeners.click" />"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 2);
+    }
+}
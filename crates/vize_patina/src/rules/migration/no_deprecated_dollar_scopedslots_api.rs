@@ -0,0 +1,111 @@
+//! vue/no-deprecated-dollar-scopedslots-api
+//!
+//! Disallow `$scopedSlots`, unified into `$slots` in Vue 3.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <div v-if="$scopedSlots.header"><slot name="header" /></div>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <div v-if="$slots.header"><slot name="header" /></div>
+//! ```
+
+use super::helpers::{find_identifier, simple};
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode, InterpolationNode, SimpleExpressionNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-dollar-scopedslots-api",
+    description: "Disallow `$scopedSlots` (use `$slots` in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+const SCOPED_SLOTS: &str = "$scopedSlots";
+
+/// Disallow `$scopedSlots`
+pub struct NoDeprecatedDollarScopedslotsApi;
+
+impl NoDeprecatedDollarScopedslotsApi {
+    fn check(ctx: &mut LintContext<'_>, exp: &SimpleExpressionNode<'_>) {
+        for offset in find_identifier(&exp.content, SCOPED_SLOTS) {
+            let start = exp.loc.start.offset + offset as u32;
+            let end = start + SCOPED_SLOTS.len() as u32;
+            ctx.report(
+                LintDiagnostic::error(META.name, "`$scopedSlots` is removed in Vue 3", start, end)
+                    .with_help("All slots are exposed as functions on `$slots` in Vue 3")
+                    .with_fix(Fix::new(
+                        "Replace with `$slots`",
+                        TextEdit::replace(start, end, "$slots"),
+                    )),
+            );
+        }
+    }
+}
+
+impl Rule for NoDeprecatedDollarScopedslotsApi {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_interpolation<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        interpolation: &InterpolationNode<'a>,
+    ) {
+        if let Some(exp) = simple(Some(&interpolation.content)) {
+            Self::check(ctx, exp);
+        }
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        if let Some(exp) = simple(directive.exp.as_ref()) {
+            Self::check(ctx, exp);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedDollarScopedslotsApi));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div v-if="$slots.header">{{ a.$scopedSlots }}</div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_fix() {
+        let linter = create_linter();
+        let source = r#"<div v-if="$scopedSlots.header"></div>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(fixed, r#"<div v-if="$slots.header"></div>"#);
+    }
+}
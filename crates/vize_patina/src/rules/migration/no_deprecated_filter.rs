@@ -0,0 +1,148 @@
+//! vue/no-deprecated-filter
+//!
+//! Disallow Vue 2 filters (`{{ value | filter }}`), removed in Vue 3.
+//!
+//! In Vue 3 the pipe is parsed as a bitwise OR, so filters silently produce
+//! wrong output instead of failing to compile. Only pipes followed by a
+//! name or call are reported, so intended bitwise ORs (`flags | 0`) are
+//! allowed.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <p>{{ price | currency('USD') }}</p>
+//! <a :title="name | capitalize"></a>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <p>{{ currency(price, 'USD') }}</p>
+//! <a :title="capitalize(name)"></a>
+//! ```
+
+use super::helpers::{rewrite_filters, simple, split_filters};
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode, InterpolationNode, SimpleExpressionNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-filter",
+    description: "Disallow Vue 2 filters (removed in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// Disallow Vue 2 filters
+pub struct NoDeprecatedFilter;
+
+impl NoDeprecatedFilter {
+    fn check(ctx: &mut LintContext<'_>, exp: &SimpleExpressionNode<'_>) {
+        let Some(segments) = split_filters(&exp.content) else {
+            return;
+        };
+
+        let mut diagnostic = LintDiagnostic::error(
+            META.name,
+            "Filters are removed in Vue 3",
+            exp.loc.start.offset,
+            exp.loc.end.offset,
+        )
+        .with_help("Call the filter as a method or computed property instead");
+
+        if let Some(rewritten) = rewrite_filters(&segments) {
+            diagnostic = diagnostic.with_fix(Fix::new(
+                "Convert filters to function calls",
+                TextEdit::replace(exp.loc.start.offset, exp.loc.end.offset, rewritten),
+            ));
+        }
+
+        ctx.report(diagnostic);
+    }
+}
+
+impl Rule for NoDeprecatedFilter {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_interpolation<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        interpolation: &InterpolationNode<'a>,
+    ) {
+        if let Some(exp) = simple(Some(&interpolation.content)) {
+            Self::check(ctx, exp);
+        }
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        // Vue 2 only allowed filters in mustaches and v-bind
+        if directive.name != "bind" {
+            return;
+        }
+        if let Some(exp) = simple(directive.exp.as_ref()) {
+            Self::check(ctx, exp);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedFilter));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<p :class="a || b" @click="x |= 1">{{ currency(price) }}</p>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_bitwise_or() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<p :data-mask="mask | 0x4">{{ flags | 0 }} {{ a | b + 1 }}</p>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_interpolation_fix() {
+        let linter = create_linter();
+        let source = r#"<p>{{ price | round | currency('USD') }}</p>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(fixed, r#"<p>{{ currency(round(price), 'USD') }}</p>"#);
+    }
+
+    #[test]
+    fn test_v_bind_fix() {
+        let linter = create_linter();
+        let source = r#"<a :title="name | capitalize"></a>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(fixed, r#"<a :title="capitalize(name)"></a>"#);
+    }
+}
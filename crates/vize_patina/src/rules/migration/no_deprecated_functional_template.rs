@@ -0,0 +1,113 @@
+//! vue/no-deprecated-functional-template
+//!
+//! Disallow `<template functional>`, removed in Vue 3.
+//!
+//! Stateful components are as fast as functional ones in Vue 3. Drop the
+//! attribute and replace `props.x` / `listeners` with regular props and
+//! `$attrs`. There is no mechanical fix because the template body has to be
+//! rewritten as well.
+//!
+//! Only detected when linting a full SFC, since the attribute sits on the
+//! block's opening tag.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <template functional>
+//!   <h1>{{ props.title }}</h1>
+//! </template>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <template>
+//!   <h1>{{ title }}</h1>
+//! </template>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::RootNode;
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-functional-template",
+    description: "Disallow `<template functional>` (removed in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: false,
+    default_severity: Severity::Error,
+};
+
+/// Disallow functional templates
+pub struct NoDeprecatedFunctionalTemplate;
+
+impl Rule for NoDeprecatedFunctionalTemplate {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, _root: &RootNode<'a>) {
+        let Some(tag) = ctx.template_tag() else {
+            return;
+        };
+        let attrs = tag
+            .trim_start_matches("<template")
+            .trim_end_matches('>')
+            .trim_end_matches('/');
+        if !attrs
+            .split_whitespace()
+            .any(|attr| attr == "functional" || attr.starts_with("functional="))
+        {
+            return;
+        }
+
+        // The tag itself is outside the linted source; point at its end
+        ctx.report(
+            LintDiagnostic::error(
+                META.name,
+                "`<template functional>` is removed in Vue 3",
+                0,
+                0,
+            )
+            .with_help(
+                "Remove `functional` and use regular props; `listeners` are available as `$attrs`",
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedFunctionalTemplate));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_sfc(
+            "<template>\n  <h1>{{ title }}</h1>\n</template>",
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let linter = create_linter();
+        let source = "<template functional>\n  <h1>{{ props.title }}</h1>\n</template>";
+        let result = linter.lint_sfc(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        assert_eq!(
+            result.diagnostics[0].start,
+            "<template functional>".len() as u32
+        );
+    }
+}
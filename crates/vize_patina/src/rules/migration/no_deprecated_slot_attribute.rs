@@ -0,0 +1,161 @@
+//! vue/no-deprecated-slot-attribute
+//!
+//! Disallow the `slot` attribute, replaced by `v-slot` in Vue 3.
+//!
+//! On `<template>` elements the attribute is rewritten to `#name`, folding a
+//! sibling `slot-scope` into the same directive. Other elements have to be
+//! wrapped in a `<template #name>` by hand.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <MyList>
+//!   <template slot="item" slot-scope="{ item }">{{ item }}</template>
+//! </MyList>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <MyList>
+//!   <template #item="{ item }">{{ item }}</template>
+//! </MyList>
+//! ```
+
+use super::helpers::{find_attribute, prop_range, removal_range, simple, static_arg};
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{ElementNode, PropNode, SourceLocation};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-slot-attribute",
+    description: "Disallow `slot` attribute (use `v-slot` in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// Disallow the `slot` attribute
+pub struct NoDeprecatedSlotAttribute;
+
+impl Rule for NoDeprecatedSlotAttribute {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn enter_element<'a>(&self, ctx: &mut LintContext<'a>, element: &ElementNode<'a>) {
+        // `slot="name"` or `:slot="expr"`
+        let slot: Option<(&SourceLocation, String)> =
+            element.props.iter().find_map(|prop| match prop {
+                PropNode::Attribute(attr) if attr.name == "slot" => Some((
+                    &attr.loc,
+                    attr.value
+                        .as_ref()
+                        .map(|v| v.content.to_string())
+                        .unwrap_or_else(|| "default".to_string()),
+                )),
+                PropNode::Directive(dir)
+                    if dir.name == "bind" && static_arg(dir) == Some("slot") =>
+                {
+                    let exp = simple(dir.exp.as_ref())?;
+                    Some((&dir.loc, format!("[{}]", exp.content)))
+                }
+                _ => None,
+            });
+        let Some((loc, name)) = slot else {
+            return;
+        };
+
+        let mut diagnostic = LintDiagnostic::error(
+            META.name,
+            "`slot` attribute is removed in Vue 3",
+            loc.start.offset,
+            loc.end.offset,
+        )
+        .with_help(format!("Use `<template #{}>` instead", name));
+
+        if element.tag == "template" {
+            let (start, end) = prop_range(ctx.source, loc);
+            let fix = match find_attribute(element, "slot-scope") {
+                Some(scope) => {
+                    let value = scope
+                        .value
+                        .as_ref()
+                        .map(|v| v.content.as_str())
+                        .unwrap_or("");
+                    let (scope_start, scope_end) = removal_range(ctx.source, &scope.loc);
+                    Fix::with_edits(
+                        "Replace with `v-slot`",
+                        vec![
+                            TextEdit::replace(start, end, format!("#{}=\"{}\"", name, value)),
+                            TextEdit::delete(scope_start, scope_end),
+                        ],
+                    )
+                }
+                None => Fix::new(
+                    "Replace with `v-slot`",
+                    TextEdit::replace(start, end, format!("#{}", name)),
+                ),
+            };
+            diagnostic = diagnostic.with_fix(fix);
+        }
+
+        ctx.report(diagnostic);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedSlotAttribute));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<MyList><template #item="{ item }">{{ item }}</template></MyList>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_fix_with_scope() {
+        let linter = create_linter();
+        let source =
+            r#"<MyList><template slot="item" slot-scope="{ item }">{{ item }}</template></MyList>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(
+            fixed,
+            r#"<MyList><template #item="{ item }">{{ item }}</template></MyList>"#
+        );
+    }
+
+    #[test]
+    fn test_dynamic_slot() {
+        let linter = create_linter();
+        let source = r#"<MyList><template :slot="name">x</template></MyList>"#;
+        let result = linter.lint_template(source, "test.vue");
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(fixed, r#"<MyList><template #[name]>x</template></MyList>"#);
+    }
+
+    #[test]
+    fn test_non_template_not_fixed() {
+        let linter = create_linter();
+        let result =
+            linter.lint_template(r#"<MyCard><h1 slot="header">Hi</h1></MyCard>"#, "test.vue");
+        assert_eq!(result.error_count, 1);
+        assert!(result.diagnostics[0].fix.is_none());
+    }
+}
@@ -0,0 +1,126 @@
+//! vue/no-deprecated-slot-scope-attribute
+//!
+//! Disallow the `slot-scope` attribute, replaced by `v-slot` in Vue 3.
+//!
+//! When a `slot` attribute is also present, `vue/no-deprecated-slot-attribute`
+//! rewrites both at once, so this rule only fixes the default slot case.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <MyList>
+//!   <template slot-scope="{ item }">{{ item }}</template>
+//! </MyList>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <MyList>
+//!   <template #default="{ item }">{{ item }}</template>
+//! </MyList>
+//! ```
+
+use super::helpers::{find_attribute, prop_range, static_arg};
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{ElementNode, PropNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-slot-scope-attribute",
+    description: "Disallow `slot-scope` attribute (use `v-slot` in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// Disallow the `slot-scope` attribute
+pub struct NoDeprecatedSlotScopeAttribute;
+
+impl Rule for NoDeprecatedSlotScopeAttribute {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn enter_element<'a>(&self, ctx: &mut LintContext<'a>, element: &ElementNode<'a>) {
+        let Some(scope) = find_attribute(element, "slot-scope") else {
+            return;
+        };
+
+        let mut diagnostic = LintDiagnostic::error(
+            META.name,
+            "`slot-scope` attribute is removed in Vue 3",
+            scope.loc.start.offset,
+            scope.loc.end.offset,
+        )
+        .with_help("Use `<template #default=\"props\">` instead");
+
+        let has_slot = element.props.iter().any(|prop| match prop {
+            PropNode::Attribute(attr) => attr.name == "slot",
+            PropNode::Directive(dir) => dir.name == "bind" && static_arg(dir) == Some("slot"),
+        });
+
+        if element.tag == "template" && !has_slot {
+            let value = scope
+                .value
+                .as_ref()
+                .map(|v| v.content.as_str())
+                .unwrap_or("");
+            let (start, end) = prop_range(ctx.source, &scope.loc);
+            diagnostic = diagnostic.with_fix(Fix::new(
+                "Replace with `v-slot`",
+                TextEdit::replace(start, end, format!("#default=\"{}\"", value)),
+            ));
+        }
+
+        ctx.report(diagnostic);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedSlotScopeAttribute));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<MyList><template #default="{ item }">{{ item }}</template></MyList>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_fix_default_slot() {
+        let linter = create_linter();
+        let source = r#"<MyList><template slot-scope="{ item }">{{ item }}</template></MyList>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(
+            fixed,
+            r#"<MyList><template #default="{ item }">{{ item }}</template></MyList>"#
+        );
+    }
+
+    #[test]
+    fn test_named_slot_left_to_slot_rule() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<MyList><template slot="item" slot-scope="p">x</template></MyList>"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 1);
+        assert!(result.diagnostics[0].fix.is_none());
+    }
+}
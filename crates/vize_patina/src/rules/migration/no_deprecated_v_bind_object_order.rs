@@ -0,0 +1,146 @@
+//! vue/no-deprecated-v-bind-object-order
+//!
+//! Disallow `v-bind="object"` after attributes it may override.
+//!
+//! In Vue 2 individual attributes always won over `v-bind="object"`. In
+//! Vue 3 the binding declared last wins, so an object binding placed after
+//! `id="x"` now overrides it. Moving the object binding first keeps the
+//! Vue 2 behavior.
+//!
+//! `class` and `style` are merged in both versions and are not reported.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <div id="red" v-bind="attrs"></div>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <div v-bind="attrs" id="red"></div>
+//! ```
+
+use super::helpers::{prop_range, removal_range, static_arg};
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{ElementNode, PropNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-v-bind-object-order",
+    description: "Disallow `v-bind=\"object\"` after attributes it may override",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Warning,
+};
+
+/// Disallow `v-bind="object"` after individual attributes
+pub struct NoDeprecatedVBindObjectOrder;
+
+/// Name of an attribute or static binding that `v-bind="object"` could override
+fn overridable_name<'b>(prop: &'b PropNode<'_>) -> Option<&'b str> {
+    let name = match prop {
+        PropNode::Attribute(attr) => attr.name.as_str(),
+        PropNode::Directive(dir) if dir.name == "bind" => static_arg(dir)?,
+        PropNode::Directive(_) => return None,
+    };
+    (!matches!(name, "class" | "style")).then_some(name)
+}
+
+impl Rule for NoDeprecatedVBindObjectOrder {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn enter_element<'a>(&self, ctx: &mut LintContext<'a>, element: &ElementNode<'a>) {
+        let Some(index) = element.props.iter().position(|prop| {
+            matches!(prop, PropNode::Directive(dir) if dir.name == "bind" && dir.arg.is_none())
+        }) else {
+            return;
+        };
+        let PropNode::Directive(object) = &element.props[index] else {
+            return;
+        };
+
+        let overridden: Vec<&PropNode> = element.props[..index]
+            .iter()
+            .filter(|prop| overridable_name(prop).is_some())
+            .collect();
+        let Some(first) = overridden.first() else {
+            return;
+        };
+        let names: Vec<String> = overridden
+            .iter()
+            .filter_map(|prop| overridable_name(prop))
+            .map(|name| format!("`{}`", name))
+            .collect();
+
+        let first_start = match first {
+            PropNode::Attribute(attr) => attr.loc.start.offset,
+            PropNode::Directive(dir) => dir.loc.start.offset,
+        };
+        let (start, end) = prop_range(ctx.source, &object.loc);
+        let object_text = ctx.source[start as usize..end as usize].to_string();
+        let (remove_start, remove_end) = removal_range(ctx.source, &object.loc);
+
+        ctx.report(
+            LintDiagnostic::warn(
+                META.name,
+                format!(
+                    "`v-bind=\"object\"` overrides {} declared before it in Vue 3",
+                    names.join(", ")
+                ),
+                object.loc.start.offset,
+                object.loc.end.offset,
+            )
+            .with_help(
+                "Move `v-bind=\"object\"` before individual attributes to keep Vue 2 behavior",
+            )
+            .with_fix(Fix::with_edits(
+                "Move `v-bind` object before individual attributes",
+                vec![
+                    TextEdit::insert(first_start, format!("{} ", object_text)),
+                    TextEdit::delete(remove_start, remove_end),
+                ],
+            )),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedVBindObjectOrder));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<div class="a" :style="s" @click="go" v-bind="attrs" id="red"></div>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_fix() {
+        let linter = create_linter();
+        let source = r#"<div class="a" id="red" :title="t" v-bind="attrs"></div>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 1);
+        assert!(result.diagnostics[0].message.contains("`id`, `title`"));
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(
+            fixed,
+            r#"<div class="a" v-bind="attrs" id="red" :title="t"></div>"#
+        );
+    }
+}
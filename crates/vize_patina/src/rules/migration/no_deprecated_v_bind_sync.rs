@@ -0,0 +1,121 @@
+//! vue/no-deprecated-v-bind-sync
+//!
+//! Disallow the `.sync` modifier on `v-bind`, replaced by `v-model:prop` in Vue 3.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <MyDialog :visible.sync="open" />
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <MyDialog v-model:visible="open" />
+//! ```
+
+use super::helpers::{prop_name_range, static_arg};
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-v-bind-sync",
+    description: "Disallow `.sync` modifier on `v-bind` (use `v-model:prop` in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// Disallow `v-bind.sync`
+pub struct NoDeprecatedVBindSync;
+
+impl Rule for NoDeprecatedVBindSync {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        if directive.name != "bind" || !directive.modifiers.iter().any(|m| m.content == "sync") {
+            return;
+        }
+
+        let mut diagnostic = LintDiagnostic::error(
+            META.name,
+            "`.sync` modifier on `v-bind` is removed in Vue 3",
+            directive.loc.start.offset,
+            directive.loc.end.offset,
+        )
+        .with_help("Use `v-model:prop=\"value\"` instead of `:prop.sync=\"value\"`");
+
+        // Only rewrite the plain form; other modifiers have no v-model equivalent
+        if let (Some(arg), 1, Some(_)) = (
+            static_arg(directive),
+            directive.modifiers.len(),
+            directive.exp.as_ref(),
+        ) {
+            let (start, end) = prop_name_range(ctx.source, &directive.loc);
+            diagnostic = diagnostic.with_fix(Fix::new(
+                "Replace with `v-model`",
+                TextEdit::replace(start, end, format!("v-model:{}", arg)),
+            ));
+        }
+
+        ctx.report(diagnostic);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedVBindSync));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(
+            r#"<MyDialog v-model:visible="open" :title.camel="t" />"#,
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_fix() {
+        let linter = create_linter();
+        let source = r#"<MyDialog :visible.sync="open" v-bind:title.sync='t' />"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 2);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(
+            fixed,
+            r#"<MyDialog v-model:visible="open" v-bind:title.sync='t' />"#
+        );
+        let fixed = result.diagnostics[1].fix.as_ref().unwrap().apply(source);
+        assert_eq!(
+            fixed,
+            r#"<MyDialog :visible.sync="open" v-model:title='t' />"#
+        );
+    }
+
+    #[test]
+    fn test_dynamic_argument_not_fixed() {
+        let linter = create_linter();
+        let result = linter.lint_template(r#"<MyDialog :[name].sync="open" />"#, "test.vue");
+        assert_eq!(result.error_count, 1);
+        assert!(result.diagnostics[0].fix.is_none());
+    }
+}
@@ -0,0 +1,122 @@
+//! vue/no-deprecated-v-on-listeners
+//!
+//! Disallow `v-on="$listeners"`, replaced by `v-bind="$attrs"` in Vue 3.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <input v-bind="$attrs" v-on="$listeners">
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <input v-bind="$attrs">
+//! ```
+
+use super::helpers::{prop_range, removal_range, simple};
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode, PropNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-v-on-listeners",
+    description: "Disallow `v-on=\"$listeners\"` (use `v-bind=\"$attrs\"` in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// Disallow `v-on="$listeners"`
+pub struct NoDeprecatedVOnListeners;
+
+fn is_object_binding(directive: &DirectiveNode<'_>, name: &str, value: &str) -> bool {
+    directive.name == name
+        && directive.arg.is_none()
+        && simple(directive.exp.as_ref()).is_some_and(|e| e.content.trim() == value)
+}
+
+impl Rule for NoDeprecatedVOnListeners {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        if !is_object_binding(directive, "on", "$listeners") {
+            return;
+        }
+
+        let binds_attrs = element.props.iter().any(|prop| match prop {
+            PropNode::Directive(d) => is_object_binding(d, "bind", "$attrs"),
+            _ => false,
+        });
+
+        // `$attrs` already carries the listeners, so the binding can go
+        let fix = if binds_attrs {
+            let (start, end) = removal_range(ctx.source, &directive.loc);
+            Fix::new("Remove `v-on=\"$listeners\"`", TextEdit::delete(start, end))
+        } else {
+            let (start, end) = prop_range(ctx.source, &directive.loc);
+            Fix::new(
+                "Replace with `v-bind=\"$attrs\"`",
+                TextEdit::replace(start, end, "v-bind=\"$attrs\""),
+            )
+        };
+
+        ctx.report(
+            LintDiagnostic::error(
+                META.name,
+                "`v-on=\"$listeners\"` is removed in Vue 3",
+                directive.loc.start.offset,
+                directive.loc.end.offset,
+            )
+            .with_help("Listeners are included in `$attrs`; use `v-bind=\"$attrs\"`")
+            .with_fix(fix),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedVOnListeners));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(r#"<input v-bind="$attrs" v-on="handlers">"#, "test.vue");
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_replace() {
+        let linter = create_linter();
+        let source = r#"<input v-on="$listeners">"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(fixed, r#"<input v-bind="$attrs">"#);
+    }
+
+    #[test]
+    fn test_remove_when_attrs_bound() {
+        let linter = create_linter();
+        let source = r#"<input v-bind="$attrs" v-on="$listeners">"#;
+        let result = linter.lint_template(source, "test.vue");
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(fixed, r#"<input v-bind="$attrs">"#);
+    }
+}
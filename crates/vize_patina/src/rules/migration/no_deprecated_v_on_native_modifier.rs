@@ -0,0 +1,104 @@
+//! vue/no-deprecated-v-on-native-modifier
+//!
+//! Disallow the `.native` modifier on `v-on`, removed in Vue 3.
+//!
+//! Listeners not declared in a component's `emits` option are added to the
+//! root element as native listeners, so `.native` can simply be dropped.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <MyButton @click.native="submit" />
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <MyButton @click="submit" />
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-v-on-native-modifier",
+    description: "Disallow `.native` modifier on `v-on` (removed in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// Disallow `v-on.native`
+pub struct NoDeprecatedVOnNativeModifier;
+
+impl Rule for NoDeprecatedVOnNativeModifier {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        if directive.name != "on" {
+            return;
+        }
+        let Some(modifier) = directive.modifiers.iter().find(|m| m.content == "native") else {
+            return;
+        };
+
+        // Remove the modifier together with its leading dot
+        let start = modifier.loc.start.offset.saturating_sub(1);
+        let end = modifier.loc.end.offset;
+        let mut diagnostic = LintDiagnostic::error(
+            META.name,
+            "`.native` modifier on `v-on` is removed in Vue 3",
+            start,
+            end,
+        )
+        .with_help("Remove `.native`; undeclared listeners fall through to the root element");
+
+        if ctx.source.as_bytes().get(start as usize) == Some(&b'.') {
+            diagnostic = diagnostic.with_fix(Fix::new(
+                "Remove `.native` modifier",
+                TextEdit::delete(start, end),
+            ));
+        }
+
+        ctx.report(diagnostic);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedVOnNativeModifier));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(r#"<MyButton @click.stop="submit" />"#, "test.vue");
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_fix() {
+        let linter = create_linter();
+        let source = r#"<MyButton @click.native.prevent="submit" />"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert_eq!(fixed, r#"<MyButton @click.prevent="submit" />"#);
+    }
+}
@@ -0,0 +1,143 @@
+//! vue/no-deprecated-v-on-number-modifiers
+//!
+//! Disallow numeric keyCode modifiers on `v-on`, removed in Vue 3.
+//!
+//! Vue 3 matches key modifiers against `KeyboardEvent.key` in kebab-case,
+//! so `@keyup.13` must become `@keyup.enter`.
+//!
+//! ## Examples
+//!
+//! ### Invalid
+//! ```vue
+//! <input @keyup.13="submit">
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <input @keyup.enter="submit">
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{DirectiveNode, ElementNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-deprecated-v-on-number-modifiers",
+    description: "Disallow numeric keyCode modifiers on `v-on` (removed in Vue 3)",
+    category: RuleCategory::Migration,
+    fixable: true,
+    default_severity: Severity::Error,
+};
+
+/// Disallow `v-on` keyCode modifiers
+pub struct NoDeprecatedVOnNumberModifiers;
+
+/// Map a keyCode to its Vue 3 key modifier name
+fn key_name(code: u32) -> Option<String> {
+    let name = match code {
+        8 => "backspace",
+        9 => "tab",
+        13 => "enter",
+        27 => "esc",
+        32 => "space",
+        33 => "page-up",
+        34 => "page-down",
+        35 => "end",
+        36 => "home",
+        37 => "left",
+        38 => "up",
+        39 => "right",
+        40 => "down",
+        45 => "insert",
+        46 => "delete",
+        65..=90 => return char::from_u32(code + 32).map(String::from),
+        112..=123 => return Some(format!("f{}", code - 111)),
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+impl Rule for NoDeprecatedVOnNumberModifiers {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn check_directive<'a>(
+        &self,
+        ctx: &mut LintContext<'a>,
+        _element: &ElementNode<'a>,
+        directive: &DirectiveNode<'a>,
+    ) {
+        if directive.name != "on" {
+            return;
+        }
+
+        for modifier in directive.modifiers.iter() {
+            let Ok(code) = modifier.content.parse::<u32>() else {
+                continue;
+            };
+
+            let start = modifier.loc.start.offset;
+            let end = modifier.loc.end.offset;
+            let mut diagnostic = LintDiagnostic::error(
+                META.name,
+                format!("keyCode modifier `.{}` is removed in Vue 3", code),
+                start,
+                end,
+            );
+
+            diagnostic = match key_name(code) {
+                Some(name) => diagnostic
+                    .with_help(format!("Use `.{}` instead", name))
+                    .with_fix(Fix::new(
+                        format!("Replace with `.{}`", name),
+                        TextEdit::replace(start, end, name),
+                    )),
+                None => diagnostic.with_help("Use the kebab-case `KeyboardEvent.key` name instead"),
+            };
+
+            ctx.report(diagnostic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter() -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoDeprecatedVOnNumberModifiers));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter();
+        let result = linter.lint_template(r#"<input @keyup.enter="submit">"#, "test.vue");
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_fix() {
+        let linter = create_linter();
+        let source = r#"<input @keyup.ctrl.13="submit" @keydown.65="a" @keydown.999="b">"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 3);
+        let fixed = result.diagnostics[0].fix.as_ref().unwrap().apply(source);
+        assert!(fixed.contains(r#"@keyup.ctrl.enter="submit""#));
+        let fixed = result.diagnostics[1].fix.as_ref().unwrap().apply(source);
+        assert!(fixed.contains(r#"@keydown.a="a""#));
+        assert!(result.diagnostics[2].fix.is_none());
+    }
+
+    #[test]
+    fn test_key_name() {
+        assert_eq!(key_name(27).as_deref(), Some("esc"));
+        assert_eq!(key_name(113).as_deref(), Some("f2"));
+        assert_eq!(key_name(1), None);
+    }
+}
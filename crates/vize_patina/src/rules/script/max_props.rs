@@ -0,0 +1,112 @@
+//! script/max-props
+//!
+//! Enforce a maximum number of props declared with `defineProps`.
+//!
+//! Components with many props tend to mix several responsibilities.
+//! Opt-in through `lint.thresholds.maxProps`.
+//!
+//! ## Examples
+//!
+//! With a maximum of 2 props:
+//!
+//! ### Invalid
+//! ```ts
+//! defineProps<{ title: string; subtitle: string; icon: string }>()
+//! ```
+//!
+//! ### Valid
+//! ```ts
+//! defineProps<{ title: string; subtitle: string }>()
+//! ```
+
+use vize_croquis::Analyzer;
+
+use crate::diagnostic::{LintDiagnostic, Severity};
+
+use super::{ScriptLintResult, ScriptRule, ScriptRuleMeta};
+
+static META: ScriptRuleMeta = ScriptRuleMeta {
+    name: "script/max-props",
+    description: "Enforce a maximum number of props per component",
+    default_severity: Severity::Warning,
+};
+
+/// Enforce a maximum number of props
+pub struct MaxProps {
+    max: usize,
+}
+
+impl MaxProps {
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+}
+
+impl ScriptRule for MaxProps {
+    fn meta(&self) -> &'static ScriptRuleMeta {
+        &META
+    }
+
+    fn check(&self, source: &str, offset: usize, result: &mut ScriptLintResult) {
+        // Fast bailout
+        if !source.contains("defineProps") {
+            return;
+        }
+
+        let mut analyzer = Analyzer::for_lint();
+        analyzer.analyze_script_setup(source);
+        let croquis = analyzer.finish();
+        let count = croquis.macros.props().len();
+        if count <= self.max {
+            return;
+        }
+        let Some(call) = croquis.macros.define_props() else {
+            return;
+        };
+
+        result.add_diagnostic(
+            LintDiagnostic::warn(
+                META.name,
+                format!("Component declares {} props (maximum {})", count, self.max),
+                offset as u32 + call.start,
+                offset as u32 + call.end,
+            )
+            .with_help("Group related props into an object or split the component"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::script::ScriptLinter;
+
+    fn create_linter(max: usize) -> ScriptLinter {
+        let mut linter = ScriptLinter::new();
+        linter.add_rule(Box::new(MaxProps::new(max)));
+        linter
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter(2);
+        let result = linter.lint("defineProps<{ title: string; subtitle?: string }>()", 0);
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let linter = create_linter(2);
+        let source = "const props = defineProps(['title', 'subtitle', 'icon'])";
+        let result = linter.lint(source, 10);
+        assert_eq!(result.warning_count, 1);
+        assert_eq!(
+            result.diagnostics[0].message,
+            "Component declares 3 props (maximum 2)"
+        );
+        assert_eq!(
+            result.diagnostics[0].start as usize,
+            10 + source.find("defineProps").unwrap()
+        );
+    }
+}
@@ -20,6 +20,7 @@
 //! - `script/no-options-api` - Disallow Options API patterns
 //! - `script/no-get-current-instance` - Disallow getCurrentInstance() calls
 
+mod max_props;
 mod no_async_in_computed;
 mod no_deep_destructure_in_props;
 mod no_get_current_instance;
@@ -44,6 +45,7 @@ use memchr::memmem;
 
 use crate::diagnostic::{LintDiagnostic, Severity};
 
+pub use max_props::MaxProps;
 pub use no_async_in_computed::NoAsyncInComputed;
 pub use no_deep_destructure_in_props::NoDeepDestructureInProps;
 pub use no_get_current_instance::NoGetCurrentInstance;
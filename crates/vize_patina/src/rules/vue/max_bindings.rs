@@ -0,0 +1,135 @@
+//! vue/max-bindings
+//!
+//! Enforce a maximum number of dynamic bindings in a template.
+//!
+//! Every interpolation and runtime directive (`v-bind`, `v-on`, `v-model`,
+//! `v-show`, custom directives, ...) is work done on each render; a large
+//! count is a sign the component does too much. Structural directives
+//! (`v-if`, `v-for`, `v-slot`) are not counted. Opt-in through
+//! `lint.thresholds.maxBindings`.
+//!
+//! ## Examples
+//!
+//! With a maximum of 2 bindings:
+//!
+//! ### Invalid
+//! ```vue
+//! <a :href="url" :title="title">{{ label }}</a>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <a :href="url">{{ label }}</a>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::metrics::is_dynamic_binding;
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{PropNode, RootNode, SourceLocation, TemplateChildNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/max-bindings",
+    description: "Enforce a maximum number of dynamic bindings in templates",
+    category: RuleCategory::Recommended,
+    fixable: false,
+    default_severity: Severity::Warning,
+};
+
+/// Enforce a maximum number of template bindings
+pub struct MaxBindings {
+    max: usize,
+}
+
+impl MaxBindings {
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+}
+
+/// Collect binding locations in source order
+fn collect_bindings<'r>(children: &'r [TemplateChildNode<'_>], out: &mut Vec<&'r SourceLocation>) {
+    for child in children {
+        match child {
+            TemplateChildNode::Element(element) => {
+                for prop in &element.props {
+                    if let PropNode::Directive(dir) = prop {
+                        if is_dynamic_binding(dir) {
+                            out.push(&dir.loc);
+                        }
+                    }
+                }
+                collect_bindings(&element.children, out);
+            }
+            TemplateChildNode::Interpolation(interpolation) => out.push(&interpolation.loc),
+            _ => {}
+        }
+    }
+}
+
+impl Rule for MaxBindings {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, root: &RootNode<'a>) {
+        let mut bindings = Vec::new();
+        collect_bindings(&root.children, &mut bindings);
+        let Some(first_over) = bindings.get(self.max) else {
+            return;
+        };
+
+        ctx.report(
+            LintDiagnostic::warn(
+                META.name,
+                format!(
+                    "Template has {} dynamic bindings (maximum {})",
+                    bindings.len(),
+                    self.max
+                ),
+                first_over.start.offset,
+                first_over.end.offset,
+            )
+            .with_help("Split the template into smaller components or derive values in computed properties"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter(max: usize) -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(MaxBindings::new(max)));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter(2);
+        let result = linter.lint_template(
+            r#"<ul v-if="ok"><li v-for="i in items" :key="i">{{ i }}</li></ul>"#,
+            "test.vue",
+        );
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let linter = create_linter(2);
+        let source = r#"<a :href="url" :title="title">{{ label }}</a>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.warning_count, 1);
+        assert_eq!(
+            result.diagnostics[0].start as usize,
+            source.find("{{").unwrap()
+        );
+        assert_eq!(
+            result.diagnostics[0].message,
+            "Template has 3 dynamic bindings (maximum 2)"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! vue/max-template-depth
+//!
+//! Enforce a maximum element nesting depth in templates.
+//!
+//! Deeply nested templates are hard to read and usually hide components
+//! waiting to be extracted. Opt-in through `lint.thresholds.maxTemplateDepth`.
+//!
+//! ## Examples
+//!
+//! With a maximum depth of 2:
+//!
+//! ### Invalid
+//! ```vue
+//! <div><ul><li>Item</li></ul></div>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <ul><li>Item</li></ul>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use vize_relief::ast::{ElementNode, RootNode, TemplateChildNode};
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/max-template-depth",
+    description: "Enforce a maximum element nesting depth in templates",
+    category: RuleCategory::Recommended,
+    fixable: false,
+    default_severity: Severity::Warning,
+};
+
+/// Enforce a maximum template depth
+pub struct MaxTemplateDepth {
+    max: usize,
+}
+
+impl MaxTemplateDepth {
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+
+    /// First element nested deeper than the maximum, with its depth
+    fn find_too_deep<'r, 'a>(
+        &self,
+        children: &'r [TemplateChildNode<'a>],
+        depth: usize,
+    ) -> Option<(&'r ElementNode<'a>, usize)> {
+        children.iter().find_map(|child| match child {
+            TemplateChildNode::Element(element) if depth > self.max => Some((&**element, depth)),
+            TemplateChildNode::Element(element) => self.find_too_deep(&element.children, depth + 1),
+            _ => None,
+        })
+    }
+}
+
+impl Rule for MaxTemplateDepth {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, root: &RootNode<'a>) {
+        let Some((element, _)) = self.find_too_deep(&root.children, 1) else {
+            return;
+        };
+        let depth = max_depth(&root.children);
+        ctx.report(
+            LintDiagnostic::warn(
+                META.name,
+                format!(
+                    "Template is nested {} levels deep (maximum {})",
+                    depth, self.max
+                ),
+                element.loc.start.offset,
+                element.loc.end.offset,
+            )
+            .with_help("Extract deeply nested markup into child components"),
+        );
+    }
+}
+
+fn max_depth(children: &[TemplateChildNode<'_>]) -> usize {
+    children
+        .iter()
+        .map(|child| match child {
+            TemplateChildNode::Element(element) => 1 + max_depth(&element.children),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter(max: usize) -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(MaxTemplateDepth::new(max)));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter(2);
+        let result = linter.lint_template("<ul><li>Item</li></ul><p>Text</p>", "test.vue");
+        assert_eq!(result.warning_count, 0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let linter = create_linter(2);
+        let result = linter.lint_template("<div><ul><li><a>Item</a></li></ul></div>", "test.vue");
+        assert_eq!(result.warning_count, 1);
+        assert_eq!(result.diagnostics[0].start, 9);
+        assert_eq!(
+            result.diagnostics[0].message,
+            "Template is nested 4 levels deep (maximum 2)"
+        );
+    }
+}
@@ -51,8 +51,11 @@ mod v_slot_style;
 // Recommended rules
 mod attribute_order;
 mod component_name_in_template_casing;
+mod max_bindings;
+mod max_template_depth;
 mod no_inline_style;
 mod no_lone_template;
+mod no_restricted_syntax;
 mod prefer_props_shorthand;
 mod require_component_registration;
 mod scoped_event_names;
@@ -126,8 +129,11 @@ pub use v_slot_style::VSlotStyle;
 // Recommended rules exports
 pub use attribute_order::AttributeOrder;
 pub use component_name_in_template_casing::ComponentNameInTemplateCasing;
+pub use max_bindings::MaxBindings;
+pub use max_template_depth::MaxTemplateDepth;
 pub use no_inline_style::NoInlineStyle;
 pub use no_lone_template::NoLoneTemplate;
+pub use no_restricted_syntax::{NoRestrictedSyntax, RestrictedSyntax};
 pub use prefer_props_shorthand::PreferPropsShorthand;
 pub use require_component_registration::RequireComponentRegistration;
 pub use scoped_event_names::ScopedEventNames;
@@ -13,6 +13,10 @@
 //! - Improves build performance
 //! - Enables better tooling support (lightning-css, etc.)
 //! - Makes styles more portable
+//!
+//! Vize compiles SCSS, Sass and Less blocks itself (the `preprocess` feature
+//! of `vize_atelier_sfc`), so this rule is a style preference rather than a
+//! compatibility requirement.
 
 use crate::context::LintContext;
 use crate::diagnostic::{Fix, LintDiagnostic, Severity, TextEdit};
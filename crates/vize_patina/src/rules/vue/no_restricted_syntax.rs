@@ -0,0 +1,178 @@
+//! vue/no-restricted-syntax
+//!
+//! Disallow template syntax matched by configured selectors.
+//!
+//! Each entry pairs a [`Selector`] with the message to report, so project
+//! conventions can be enforced from config instead of a custom `Rule`.
+//! See [`crate::selector`] for the selector syntax.
+//!
+//! ## Examples
+//!
+//! With the entry
+//! `{ "selector": "element[tag=router-link]", "message": "Use <AppLink> instead" }`:
+//!
+//! ### Invalid
+//! ```vue
+//! <router-link to="/">Home</router-link>
+//! ```
+//!
+//! ### Valid
+//! ```vue
+//! <AppLink to="/">Home</AppLink>
+//! ```
+
+use crate::context::LintContext;
+use crate::diagnostic::{LintDiagnostic, Severity};
+use crate::rule::{Rule, RuleCategory, RuleMeta};
+use crate::selector::{self, Selector, SelectorError};
+use serde::{Deserialize, Serialize};
+use vize_relief::ast::RootNode;
+
+static META: RuleMeta = RuleMeta {
+    name: "vue/no-restricted-syntax",
+    description: "Disallow template syntax matched by configured selectors",
+    category: RuleCategory::Recommended,
+    fixable: false,
+    default_severity: Severity::Error,
+};
+
+/// A single restricted syntax entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestrictedSyntax {
+    /// Template AST selector (e.g. `element[tag=img]:not([alt])`)
+    pub selector: String,
+    /// Message reported for each match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Severity of the diagnostic (defaults to error)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+}
+
+impl RestrictedSyntax {
+    /// Create an entry reported as an error
+    pub fn new(selector: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            selector: selector.into(),
+            message: Some(message.into()),
+            severity: None,
+        }
+    }
+}
+
+/// Disallow syntax matched by configured selectors
+pub struct NoRestrictedSyntax {
+    entries: Vec<(Selector, RestrictedSyntax)>,
+}
+
+impl NoRestrictedSyntax {
+    /// Compile the selectors of all entries
+    pub fn new(entries: Vec<RestrictedSyntax>) -> Result<Self, SelectorError> {
+        let entries = entries
+            .into_iter()
+            .map(|entry| Ok((Selector::parse(&entry.selector)?, entry)))
+            .collect::<Result<_, SelectorError>>()?;
+        Ok(Self { entries })
+    }
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn meta(&self) -> &'static RuleMeta {
+        &META
+    }
+
+    fn run_on_template<'a>(&self, ctx: &mut LintContext<'a>, root: &RootNode<'a>) {
+        if self.entries.is_empty() {
+            return;
+        }
+
+        selector::walk(&root.children, &mut |node, ancestors| {
+            for (selector, entry) in &self.entries {
+                if !selector.matches(node, ancestors) {
+                    continue;
+                }
+                let (start, end) = node.range();
+                let message = match &entry.message {
+                    Some(message) => message.clone(),
+                    None => format!("Using `{}` is not allowed", selector.as_str()),
+                };
+                let diagnostic = match entry.severity.unwrap_or(META.default_severity) {
+                    Severity::Error => LintDiagnostic::error(META.name, message, start, end),
+                    Severity::Warning => LintDiagnostic::warn(META.name, message, start, end),
+                };
+                ctx.report(
+                    diagnostic.with_help(format!("Restricted by selector `{}`", selector.as_str())),
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::Linter;
+    use crate::rule::RuleRegistry;
+
+    fn create_linter(entries: Vec<RestrictedSyntax>) -> Linter {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoRestrictedSyntax::new(entries).unwrap()));
+        Linter::with_registry(registry)
+    }
+
+    #[test]
+    fn test_valid() {
+        let linter = create_linter(vec![RestrictedSyntax::new(
+            "element[tag=router-link]",
+            "Use <AppLink> instead",
+        )]);
+        let result = linter.lint_template(r#"<AppLink to="/">Home</AppLink>"#, "test.vue");
+        assert_eq!(result.error_count, 0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let linter = create_linter(vec![
+            RestrictedSyntax::new("element[tag=router-link]", "Use <AppLink> instead"),
+            RestrictedSyntax {
+                selector: "element[tag=img]:not([alt])".to_string(),
+                message: None,
+                severity: Some(Severity::Warning),
+            },
+        ]);
+        let source = r#"<nav><router-link to="/">Home</router-link><img src="a.png"></nav>"#;
+        let result = linter.lint_template(source, "test.vue");
+        assert_eq!(result.error_count, 1);
+        assert_eq!(result.warning_count, 1);
+        assert_eq!(result.diagnostics[0].message, "Use <AppLink> instead");
+        assert_eq!(result.diagnostics[0].start, 5);
+        assert_eq!(
+            result.diagnostics[1].message,
+            "Using `element[tag=img]:not([alt])` is not allowed"
+        );
+    }
+
+    #[test]
+    fn test_disable_comment() {
+        let linter = create_linter(vec![RestrictedSyntax::new("directive[name=html]", "No")]);
+        let result = linter.lint_template(
+            "<div>\n<!-- vize-disable-next-line vue/no-restricted-syntax -->\n<p v-html=\"a\"></p>\n<p v-html=\"b\"></p>\n</div>",
+            "test.vue",
+        );
+        assert_eq!(result.error_count, 1);
+    }
+
+    #[test]
+    fn test_invalid_selector() {
+        assert!(NoRestrictedSyntax::new(vec![RestrictedSyntax::new("element[", "x")]).is_err());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let entry: RestrictedSyntax =
+            serde_json::from_str(r#"{ "selector": "directive[name=html]", "severity": "warn" }"#)
+                .unwrap();
+        assert_eq!(entry.severity, Some(Severity::Warning));
+        assert_eq!(entry.message, None);
+    }
+}
@@ -0,0 +1,643 @@
+//! Template AST selectors.
+//!
+//! A small CSS-like query language over the `vize_relief` template AST, used
+//! by `vue/no-restricted-syntax` to turn project conventions into config.
+//!
+//! ## Syntax
+//!
+//! ```text
+//! element[tag=img]:not([alt])      <img> without `alt` / `:alt`
+//! directive[name=html]             any `v-html`
+//! element[tag=/^El/] > text        text directly inside `<El...>` components
+//! element[tag=form] directive[name=on][arg=submit]:not([modifiers=prevent])
+//! ```
+//!
+//! - Node types: `element`, `directive`, `attribute`, `text`, `interpolation`,
+//!   `comment` and `*`.
+//! - Filters: `[key]`, `[key=value]`, `[key!=value]`, `[key^=value]`,
+//!   `[key$=value]`, `[key*=value]`. Values may be bare, quoted, or a
+//!   `/regex/` (with an optional `i` flag) for `=` and `!=`.
+//! - `:not(selector)` negates a selector list.
+//! - Combinators: descendant (whitespace) and child (`>`). Ancestors are
+//!   always elements; directives and attributes are children of the element
+//!   they are declared on.
+//! - `a, b` matches either selector.
+//!
+//! ## Keys
+//!
+//! | Node | Keys |
+//! |------|------|
+//! | `element` | `tag`, otherwise the value of a static attribute or a `v-bind` with that argument |
+//! | `directive` | `name`, `arg`, `modifiers` (matches any modifier), `exp` |
+//! | `attribute` | `name`, `value` |
+//! | `text`, `comment`, `interpolation` | `content` |
+
+use regex::{Regex, RegexBuilder};
+use thiserror::Error;
+use vize_relief::ast::{
+    AttributeNode, CommentNode, DirectiveNode, ElementNode, ExpressionNode, InterpolationNode,
+    PropNode, TemplateChildNode, TextNode,
+};
+
+/// Error produced when a selector fails to parse
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid selector at offset {offset}: {message}")]
+pub struct SelectorError {
+    /// Byte offset in the selector source
+    pub offset: usize,
+    /// Description of the problem
+    pub message: String,
+}
+
+/// Kind of template node a compound selector is restricted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Element,
+    Directive,
+    Attribute,
+    Text,
+    Interpolation,
+    Comment,
+}
+
+impl NodeKind {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "element" => Self::Element,
+            "directive" => Self::Directive,
+            "attribute" => Self::Attribute,
+            "text" => Self::Text,
+            "interpolation" => Self::Interpolation,
+            "comment" => Self::Comment,
+            _ => return None,
+        })
+    }
+}
+
+/// A template node that a selector can be matched against
+#[derive(Debug, Clone, Copy)]
+pub enum SelectorNode<'n, 'a> {
+    Element(&'n ElementNode<'a>),
+    Directive(&'n DirectiveNode<'a>),
+    Attribute(&'n AttributeNode),
+    Text(&'n TextNode),
+    Interpolation(&'n InterpolationNode<'a>),
+    Comment(&'n CommentNode),
+}
+
+impl<'n, 'a> SelectorNode<'n, 'a> {
+    /// Kind of this node
+    pub fn kind(&self) -> NodeKind {
+        match self {
+            Self::Element(_) => NodeKind::Element,
+            Self::Directive(_) => NodeKind::Directive,
+            Self::Attribute(_) => NodeKind::Attribute,
+            Self::Text(_) => NodeKind::Text,
+            Self::Interpolation(_) => NodeKind::Interpolation,
+            Self::Comment(_) => NodeKind::Comment,
+        }
+    }
+
+    /// Source range of this node
+    pub fn range(&self) -> (u32, u32) {
+        let loc = match self {
+            Self::Element(el) => &el.loc,
+            Self::Directive(dir) => &dir.loc,
+            Self::Attribute(attr) => &attr.loc,
+            Self::Text(text) => &text.loc,
+            Self::Interpolation(interp) => &interp.loc,
+            Self::Comment(comment) => &comment.loc,
+        };
+        (loc.start.offset, loc.end.offset)
+    }
+
+    /// Test `key` against this node, calling `test` on each candidate value
+    fn test_key(&self, key: &str, test: impl Fn(&str) -> bool) -> bool {
+        match self {
+            Self::Element(el) => {
+                if key == "tag" {
+                    return test(&el.tag);
+                }
+                el.props.iter().any(|prop| match prop {
+                    PropNode::Attribute(attr) => {
+                        attr.name == key
+                            && test(attr.value.as_ref().map_or("", |v| v.content.as_str()))
+                    }
+                    PropNode::Directive(dir) => {
+                        dir.name == "bind"
+                            && expression(dir.arg.as_ref()) == Some(key)
+                            && test(expression(dir.exp.as_ref()).unwrap_or(""))
+                    }
+                })
+            }
+            Self::Directive(dir) => match key {
+                "name" => test(&dir.name),
+                "arg" => expression(dir.arg.as_ref()).is_some_and(test),
+                "modifiers" => dir.modifiers.iter().any(|m| test(&m.content)),
+                "exp" => expression(dir.exp.as_ref()).is_some_and(test),
+                _ => false,
+            },
+            Self::Attribute(attr) => match key {
+                "name" => test(&attr.name),
+                "value" => test(attr.value.as_ref().map_or("", |v| v.content.as_str())),
+                _ => false,
+            },
+            Self::Text(text) => key == "content" && test(&text.content),
+            Self::Comment(comment) => key == "content" && test(&comment.content),
+            Self::Interpolation(interp) => {
+                key == "content" && expression(Some(&interp.content)).is_some_and(test)
+            }
+        }
+    }
+}
+
+/// Source text of a simple expression
+fn expression<'n>(exp: Option<&'n ExpressionNode<'_>>) -> Option<&'n str> {
+    match exp? {
+        ExpressionNode::Simple(s) => Some(s.content.as_str()),
+        ExpressionNode::Compound(c) => Some(c.loc.source.as_str()),
+    }
+}
+
+/// Visit every node under `children` in document order, together with its
+/// element ancestors (outermost first)
+pub fn walk<'n, 'a>(
+    children: &'n [TemplateChildNode<'a>],
+    f: &mut impl FnMut(SelectorNode<'n, 'a>, &[&'n ElementNode<'a>]),
+) {
+    let mut ancestors = Vec::new();
+    walk_children(children, &mut ancestors, f);
+}
+
+fn walk_children<'n, 'a>(
+    children: &'n [TemplateChildNode<'a>],
+    ancestors: &mut Vec<&'n ElementNode<'a>>,
+    f: &mut impl FnMut(SelectorNode<'n, 'a>, &[&'n ElementNode<'a>]),
+) {
+    for child in children {
+        match child {
+            TemplateChildNode::Element(el) => {
+                f(SelectorNode::Element(el), ancestors);
+                ancestors.push(el);
+                for prop in el.props.iter() {
+                    match prop {
+                        PropNode::Attribute(attr) => f(SelectorNode::Attribute(attr), ancestors),
+                        PropNode::Directive(dir) => f(SelectorNode::Directive(dir), ancestors),
+                    }
+                }
+                walk_children(&el.children, ancestors, f);
+                ancestors.pop();
+            }
+            TemplateChildNode::Text(text) => f(SelectorNode::Text(text), ancestors),
+            TemplateChildNode::Comment(comment) => f(SelectorNode::Comment(comment), ancestors),
+            TemplateChildNode::Interpolation(interp) => {
+                f(SelectorNode::Interpolation(interp), ancestors)
+            }
+            TemplateChildNode::If(if_node) => {
+                for branch in if_node.branches.iter() {
+                    walk_children(&branch.children, ancestors, f);
+                }
+            }
+            TemplateChildNode::For(for_node) => walk_children(&for_node.children, ancestors, f),
+            _ => {}
+        }
+    }
+}
+
+/// A parsed selector list
+#[derive(Debug, Clone)]
+pub struct Selector {
+    source: String,
+    alternatives: Vec<Complex>,
+}
+
+#[derive(Debug, Clone)]
+struct Complex {
+    /// Compounds from left to right
+    compounds: Vec<Compound>,
+    /// `combinators[i]` joins `compounds[i]` and `compounds[i + 1]`
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Compound {
+    kind: Option<NodeKind>,
+    filters: Vec<Filter>,
+}
+
+#[derive(Debug, Clone)]
+enum Filter {
+    Has(String),
+    Attr {
+        key: String,
+        op: AttrOp,
+        value: Value,
+    },
+    Not(Selector),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttrOp {
+    Eq,
+    NotEq,
+    Prefix,
+    Suffix,
+    Contains,
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Str(String),
+    Regex(Regex),
+}
+
+impl Selector {
+    /// Parse a selector list
+    pub fn parse(source: &str) -> Result<Self, SelectorError> {
+        let mut parser = Parser { source, pos: 0 };
+        let selector = parser.selector_list()?;
+        parser.skip_whitespace();
+        if parser.pos < source.len() {
+            return Err(parser.error("unexpected character"));
+        }
+        Ok(selector)
+    }
+
+    /// Original selector text
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether `node` matches, given its element ancestors (outermost first)
+    pub fn matches(&self, node: SelectorNode<'_, '_>, ancestors: &[&ElementNode<'_>]) -> bool {
+        self.alternatives
+            .iter()
+            .any(|complex| complex.matches(node, ancestors))
+    }
+}
+
+impl Complex {
+    fn matches(&self, node: SelectorNode<'_, '_>, ancestors: &[&ElementNode<'_>]) -> bool {
+        let last = self.compounds.len() - 1;
+        self.compounds[last].matches(node, ancestors) && self.matches_ancestors(last, ancestors)
+    }
+
+    /// Match `compounds[..index]` against `ancestors`, given that
+    /// `compounds[index]` matched the node just below them
+    fn matches_ancestors(&self, index: usize, ancestors: &[&ElementNode<'_>]) -> bool {
+        if index == 0 {
+            return true;
+        }
+        let compound = &self.compounds[index - 1];
+        match self.combinators[index - 1] {
+            Combinator::Child => match ancestors.split_last() {
+                Some((parent, rest)) => {
+                    compound.matches(SelectorNode::Element(parent), rest)
+                        && self.matches_ancestors(index - 1, rest)
+                }
+                None => false,
+            },
+            Combinator::Descendant => (0..ancestors.len()).rev().any(|i| {
+                let rest = &ancestors[..i];
+                compound.matches(SelectorNode::Element(ancestors[i]), rest)
+                    && self.matches_ancestors(index - 1, rest)
+            }),
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, node: SelectorNode<'_, '_>, ancestors: &[&ElementNode<'_>]) -> bool {
+        if self.kind.is_some_and(|kind| kind != node.kind()) {
+            return false;
+        }
+        self.filters.iter().all(|filter| match filter {
+            Filter::Has(key) => node.test_key(key, |_| true),
+            Filter::Attr { key, op, value } => match op {
+                AttrOp::NotEq => {
+                    node.test_key(key, |_| true) && !node.test_key(key, |v| value.matches(v))
+                }
+                _ => node.test_key(key, |v| match (op, value) {
+                    (AttrOp::Prefix, Value::Str(s)) => v.starts_with(s.as_str()),
+                    (AttrOp::Suffix, Value::Str(s)) => v.ends_with(s.as_str()),
+                    (AttrOp::Contains, Value::Str(s)) => v.contains(s.as_str()),
+                    _ => value.matches(v),
+                }),
+            },
+            Filter::Not(selector) => !selector.matches(node, ancestors),
+        })
+    }
+}
+
+impl Value {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Str(s) => s == text,
+            Self::Regex(re) => re.is_match(text),
+        }
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn error(&self, message: impl Into<String>) -> SelectorError {
+        SelectorError {
+            offset: self.pos,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SelectorError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", c)))
+        }
+    }
+
+    /// Skip whitespace, returning whether any was skipped
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+        self.pos > start
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'s str {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if !f(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn selector_list(&mut self) -> Result<Selector, SelectorError> {
+        let start = self.pos;
+        let mut alternatives = vec![self.complex()?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+            alternatives.push(self.complex()?);
+        }
+        Ok(Selector {
+            source: self.source[start..self.pos].trim().to_string(),
+            alternatives,
+        })
+    }
+
+    fn complex(&mut self) -> Result<Complex, SelectorError> {
+        self.skip_whitespace();
+        let mut compounds = vec![self.compound()?];
+        let mut combinators = Vec::new();
+        loop {
+            let had_space = self.skip_whitespace();
+            let combinator = if self.eat('>') {
+                self.skip_whitespace();
+                Combinator::Child
+            } else if had_space && !matches!(self.peek(), None | Some(',' | ')')) {
+                Combinator::Descendant
+            } else {
+                break;
+            };
+            combinators.push(combinator);
+            compounds.push(self.compound()?);
+        }
+        Ok(Complex {
+            compounds,
+            combinators,
+        })
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let start = self.pos;
+        let mut compound = Compound::default();
+
+        if self.eat('*') {
+            // Any node
+        } else {
+            let name = self.take_while(|c| c.is_ascii_alphabetic());
+            if !name.is_empty() {
+                compound.kind = Some(NodeKind::parse(name).ok_or_else(|| SelectorError {
+                    offset: start,
+                    message: format!("unknown node type `{}`", name),
+                })?);
+            }
+        }
+
+        loop {
+            if self.eat('[') {
+                compound.filters.push(self.attribute()?);
+            } else if self.source[self.pos..].starts_with(":not(") {
+                self.pos += ":not(".len();
+                let selector = self.selector_list()?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                compound.filters.push(Filter::Not(selector));
+            } else {
+                break;
+            }
+        }
+
+        if self.pos == start {
+            return Err(self.error("expected a node type, `*`, `[` or `:not(`"));
+        }
+        Ok(compound)
+    }
+
+    fn attribute(&mut self) -> Result<Filter, SelectorError> {
+        self.skip_whitespace();
+        let key = self
+            .take_while(|c| !c.is_whitespace() && !matches!(c, '=' | '!' | '^' | '$' | '*' | ']'));
+        if key.is_empty() {
+            return Err(self.error("expected an attribute key"));
+        }
+        let key = key.to_string();
+        self.skip_whitespace();
+        if self.eat(']') {
+            return Ok(Filter::Has(key));
+        }
+
+        let op = match self.peek() {
+            Some('=') => AttrOp::Eq,
+            Some('!') => AttrOp::NotEq,
+            Some('^') => AttrOp::Prefix,
+            Some('$') => AttrOp::Suffix,
+            Some('*') => AttrOp::Contains,
+            _ => return Err(self.error("expected `]` or an operator")),
+        };
+        if op != AttrOp::Eq {
+            self.pos += 1;
+        }
+        self.expect('=')?;
+        self.skip_whitespace();
+
+        let value = self.value()?;
+        if matches!(value, Value::Regex(_)) && !matches!(op, AttrOp::Eq | AttrOp::NotEq) {
+            return Err(self.error("regular expressions are only supported with `=` and `!=`"));
+        }
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Filter::Attr { key, op, value })
+    }
+
+    fn value(&mut self) -> Result<Value, SelectorError> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let text = self.take_while(|c| c != quote).to_string();
+                self.expect(quote)?;
+                Ok(Value::Str(text))
+            }
+            Some('/') => {
+                let start = self.pos;
+                self.pos += 1;
+                let mut pattern = String::new();
+                loop {
+                    match self.peek() {
+                        None => {
+                            return Err(SelectorError {
+                                offset: start,
+                                message: "unterminated regular expression".to_string(),
+                            })
+                        }
+                        Some('/') => break,
+                        Some('\\') if self.source[self.pos + 1..].starts_with('/') => {
+                            pattern.push('/');
+                            self.pos += 2;
+                        }
+                        Some(c) => {
+                            pattern.push(c);
+                            self.pos += c.len_utf8();
+                        }
+                    }
+                }
+                self.pos += 1;
+                let flags = self.take_while(|c| c.is_ascii_alphabetic());
+                if let Some(flag) = flags.chars().find(|&c| c != 'i') {
+                    return Err(
+                        self.error(format!("unsupported regular expression flag `{}`", flag))
+                    );
+                }
+                RegexBuilder::new(&pattern)
+                    .case_insensitive(!flags.is_empty())
+                    .build()
+                    .map(Value::Regex)
+                    .map_err(|e| SelectorError {
+                        offset: start,
+                        message: e.to_string(),
+                    })
+            }
+            _ => {
+                let text = self.take_while(|c| !c.is_whitespace() && c != ']');
+                if text.is_empty() {
+                    return Err(self.error("expected a value"));
+                }
+                Ok(Value::Str(text.to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vize_armature::parse;
+    use vize_carton::Bump;
+
+    /// Collect the source text of every node matching `selector`
+    fn query(source: &str, selector: &str) -> Vec<String> {
+        let allocator = Bump::new();
+        let (root, _) = parse(&allocator, source);
+        let selector = Selector::parse(selector).unwrap();
+        let mut found = Vec::new();
+        walk(&root.children, &mut |node, ancestors| {
+            if selector.matches(node, ancestors) {
+                let (start, end) = node.range();
+                found.push(source[start as usize..end as usize].to_string());
+            }
+        });
+        found
+    }
+
+    #[test]
+    fn test_type_and_filters() {
+        let source = r#"<img src="a.png"><img src="b.png" alt="b"><img :alt="c">"#;
+        assert_eq!(
+            query(source, "element[tag=img]:not([alt])"),
+            vec![r#"<img src="a.png">"#]
+        );
+        assert_eq!(query(source, "attribute[name=src][value$=b.png]").len(), 1);
+        assert_eq!(query(source, "[alt]").len(), 2);
+    }
+
+    #[test]
+    fn test_directive_keys() {
+        let source = r#"<div v-html="raw" @click.stop="go" :title="t"></div>"#;
+        let found = query(source, "directive[name=html]");
+        assert_eq!(found.len(), 1);
+        assert!(found[0].starts_with("v-html"));
+        assert_eq!(query(source, "directive[name=on][modifiers=stop]").len(), 1);
+        assert_eq!(query(source, "directive[arg=title][exp='t']").len(), 1);
+        assert_eq!(query(source, "directive[name!=bind]").len(), 2);
+    }
+
+    #[test]
+    fn test_combinators() {
+        let source = "<ElCard><p>deep</p>direct</ElCard><div>plain</div>";
+        assert_eq!(query(source, "element[tag=/^El/] > text"), vec!["direct"]);
+        assert_eq!(query(source, "element[tag=/^el/i] text").len(), 2);
+        assert_eq!(query(source, "element[tag=div] > text"), vec!["plain"]);
+        assert!(query(source, "element[tag=div] > element > text").is_empty());
+    }
+
+    #[test]
+    fn test_selector_list_and_interpolation() {
+        let source = "<router-link to='/'>{{ $route.path }}</router-link><RouterLink />";
+        assert_eq!(
+            query(source, "element[tag=router-link], element[tag=RouterLink]").len(),
+            2
+        );
+        assert_eq!(query(source, "interpolation[content*=$route]").len(), 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Selector::parse("element[tag=img").is_err());
+        assert!(Selector::parse("widget").is_err());
+        assert!(Selector::parse("element[tag=/(/]").is_err());
+        assert!(Selector::parse("element[tag^=/a/]").is_err());
+        assert!(Selector::parse("element >").is_err());
+        assert!(Selector::parse("").is_err());
+        let err = Selector::parse("element:has(text)").unwrap_err();
+        assert_eq!(err.offset, 7);
+    }
+}
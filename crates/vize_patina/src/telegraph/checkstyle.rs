@@ -0,0 +1,87 @@
+//! Checkstyle XML emitter (the format read by reviewdog, Jenkins, etc.).
+
+use super::report::{escape_xml, ReportFile};
+use super::Emitter;
+use crate::diagnostic::Severity;
+use crate::linter::LintResult;
+use std::fmt::Write;
+
+/// Checkstyle 4.3 XML emitter.
+pub struct CheckstyleEmitter;
+
+impl CheckstyleEmitter {
+    /// Render all files as one Checkstyle document
+    pub fn render(files: &[ReportFile]) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<checkstyle version=\"4.3\">\n");
+
+        for file in files {
+            let _ = writeln!(out, "  <file name=\"{}\">", escape_xml(&file.path));
+            for d in &file.diagnostics {
+                let _ = writeln!(
+                    out,
+                    "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\" />",
+                    d.line,
+                    d.column,
+                    match d.severity {
+                        Severity::Error => "error",
+                        Severity::Warning => "warning",
+                    },
+                    escape_xml(&d.full_message()),
+                    escape_xml(&d.rule)
+                );
+            }
+            out.push_str("  </file>\n");
+        }
+
+        out.push_str("</checkstyle>\n");
+        out
+    }
+}
+
+impl Emitter for CheckstyleEmitter {
+    fn name(&self) -> &'static str {
+        "checkstyle"
+    }
+
+    fn emit(&self, result: &LintResult, source: &str) -> String {
+        Self::render(&[ReportFile::from_lint(result, source)])
+    }
+
+    fn emit_summary(&self, _results: &[LintResult]) -> String {
+        String::new()
+    }
+
+    fn emit_all(&self, results: &[(LintResult, String)]) -> String {
+        Self::render(&ReportFile::from_lint_results(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::LintDiagnostic;
+
+    #[test]
+    fn test_checkstyle_document() {
+        let source = "<template>\n  <div v-html=\"raw\" />\n</template>";
+        let result = LintResult {
+            filename: "src/A&B.vue".to_string(),
+            diagnostics: vec![LintDiagnostic::warn(
+                "vue/no-v-html",
+                "Avoid <v-html>",
+                18,
+                30,
+            )],
+            error_count: 0,
+            warning_count: 1,
+        };
+
+        let output = CheckstyleEmitter.emit_all(&[(result, source.to_string())]);
+        assert!(output.contains("<checkstyle version=\"4.3\">"));
+        assert!(output.contains("<file name=\"src/A&amp;B.vue\">"));
+        assert!(output.contains(
+            "<error line=\"2\" column=\"8\" severity=\"warning\" message=\"Avoid &lt;v-html&gt;\" source=\"vue/no-v-html\" />"
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+//! GitHub Actions workflow command emitter.
+//!
+//! Prints one `::error file=...::message` / `::warning ...` line per
+//! diagnostic, which GitHub turns into inline pull request annotations.
+
+use super::report::ReportFile;
+use super::Emitter;
+use crate::diagnostic::Severity;
+use crate::linter::LintResult;
+use std::fmt::Write;
+
+/// GitHub Actions annotation emitter.
+pub struct GithubEmitter;
+
+impl GithubEmitter {
+    /// Render all files as workflow commands
+    pub fn render(files: &[ReportFile]) -> String {
+        let mut out = String::new();
+        for file in files {
+            for d in &file.diagnostics {
+                let command = match d.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                let _ = writeln!(
+                    out,
+                    "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}",
+                    command,
+                    escape_property(&file.path),
+                    d.line,
+                    d.column,
+                    d.end_line,
+                    d.end_column,
+                    escape_property(&d.rule),
+                    escape_data(&d.full_message())
+                );
+            }
+        }
+        out
+    }
+}
+
+/// Escape a workflow command message.
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value.
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+impl Emitter for GithubEmitter {
+    fn name(&self) -> &'static str {
+        "github"
+    }
+
+    fn emit(&self, result: &LintResult, source: &str) -> String {
+        Self::render(&[ReportFile::from_lint(result, source)])
+    }
+
+    fn emit_summary(&self, _results: &[LintResult]) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telegraph::ReportDiagnostic;
+
+    #[test]
+    fn test_github_annotations() {
+        let files = vec![ReportFile {
+            path: "src/App.vue".to_string(),
+            diagnostics: vec![
+                ReportDiagnostic {
+                    rule: "vue/require-v-for-key".to_string(),
+                    severity: Severity::Error,
+                    message: "Missing key".to_string(),
+                    help: Some("Add :key, e.g. 100%".to_string()),
+                    line: 2,
+                    column: 8,
+                    end_line: 2,
+                    end_column: 20,
+                },
+                ReportDiagnostic {
+                    rule: "TS6133".to_string(),
+                    severity: Severity::Warning,
+                    message: "'x' is declared but never used".to_string(),
+                    help: None,
+                    line: 5,
+                    column: 1,
+                    end_line: 5,
+                    end_column: 2,
+                },
+            ],
+        }];
+
+        let output = GithubEmitter::render(&files);
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(
+            lines[0],
+            "::error file=src/App.vue,line=2,col=8,endLine=2,endColumn=20,title=vue/require-v-for-key::Missing key%0AAdd :key, e.g. 100%25"
+        );
+        assert!(lines[1].starts_with("::warning file=src/App.vue,line=5,col=1,"));
+    }
+
+    #[test]
+    fn test_escape_property() {
+        assert_eq!(escape_property("C:\\a,b"), "C%3A\\a%2Cb");
+    }
+}
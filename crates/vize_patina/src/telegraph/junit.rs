@@ -0,0 +1,151 @@
+//! JUnit XML emitter for CI test report viewers.
+//!
+//! Each file becomes a `<testsuite>`; each diagnostic a failing `<testcase>`.
+//! Files without diagnostics get a single passing testcase so that they
+//! show up as green in the report instead of disappearing.
+
+use super::report::{escape_xml, ReportFile};
+use super::Emitter;
+use crate::diagnostic::Severity;
+use crate::linter::LintResult;
+use std::fmt::Write;
+
+/// JUnit XML emitter.
+pub struct JunitEmitter {
+    /// Name of the top-level `<testsuites>` element
+    pub suite_name: &'static str,
+}
+
+impl JunitEmitter {
+    pub fn new(suite_name: &'static str) -> Self {
+        Self { suite_name }
+    }
+
+    /// Render all files as one JUnit document
+    pub fn render(&self, files: &[ReportFile]) -> String {
+        let total_tests: usize = files.iter().map(|f| f.diagnostics.len().max(1)).sum();
+        let total_failures: usize = files.iter().map(|f| f.diagnostics.len()).sum();
+
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            out,
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\">",
+            escape_xml(self.suite_name),
+            total_tests,
+            total_failures
+        );
+
+        for file in files {
+            let path = escape_xml(&file.path);
+            let _ = writeln!(
+                out,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">",
+                path,
+                file.diagnostics.len().max(1),
+                file.diagnostics.len()
+            );
+
+            if file.diagnostics.is_empty() {
+                let _ = writeln!(
+                    out,
+                    "    <testcase name=\"{}\" classname=\"{}\" />",
+                    path, path
+                );
+            }
+
+            for d in &file.diagnostics {
+                let kind = match d.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                let _ = writeln!(
+                    out,
+                    "    <testcase name=\"{}:{}:{} {}\" classname=\"{}\">",
+                    path,
+                    d.line,
+                    d.column,
+                    escape_xml(&d.rule),
+                    path
+                );
+                let _ = writeln!(
+                    out,
+                    "      <failure message=\"{}\" type=\"{}\">{}:{}:{} {}: {} [{}]</failure>",
+                    escape_xml(&d.message),
+                    kind,
+                    path,
+                    d.line,
+                    d.column,
+                    kind,
+                    escape_xml(&d.full_message()),
+                    escape_xml(&d.rule)
+                );
+                out.push_str("    </testcase>\n");
+            }
+
+            out.push_str("  </testsuite>\n");
+        }
+
+        out.push_str("</testsuites>\n");
+        out
+    }
+}
+
+impl Default for JunitEmitter {
+    fn default() -> Self {
+        Self::new("vize-patina")
+    }
+}
+
+impl Emitter for JunitEmitter {
+    fn name(&self) -> &'static str {
+        "junit"
+    }
+
+    fn emit(&self, result: &LintResult, source: &str) -> String {
+        self.render(&[ReportFile::from_lint(result, source)])
+    }
+
+    fn emit_summary(&self, _results: &[LintResult]) -> String {
+        String::new()
+    }
+
+    fn emit_all(&self, results: &[(LintResult, String)]) -> String {
+        self.render(&ReportFile::from_lint_results(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telegraph::ReportDiagnostic;
+
+    #[test]
+    fn test_junit_document() {
+        let files = vec![
+            ReportFile {
+                path: "src/App.vue".to_string(),
+                diagnostics: vec![ReportDiagnostic {
+                    rule: "TS2322".to_string(),
+                    severity: Severity::Error,
+                    message: "Type 'string' is not assignable to type 'number'".to_string(),
+                    help: None,
+                    line: 4,
+                    column: 7,
+                    end_line: 4,
+                    end_column: 12,
+                }],
+            },
+            ReportFile::new("src/Clean.vue"),
+        ];
+
+        let output = JunitEmitter::new("vize-canon").render(&files);
+        assert!(output.starts_with("<?xml"));
+        assert!(output.contains("<testsuites name=\"vize-canon\" tests=\"2\" failures=\"1\">"));
+        assert!(output.contains("<testcase name=\"src/App.vue:4:7 TS2322\""));
+        assert!(output.contains(
+            "message=\"Type &apos;string&apos; is not assignable to type &apos;number&apos;\" type=\"error\""
+        ));
+        assert!(output.contains("<testcase name=\"src/Clean.vue\" classname=\"src/Clean.vue\" />"));
+        assert!(output.trim_end().ends_with("</testsuites>"));
+    }
+}
@@ -18,11 +18,32 @@
 //!                              +-- TextEmitter  --> stdout (rich terminal)
 //!                              +-- JsonEmitter  --> JSON format
 //!                              +-- LspEmitter   --> LSP diagnostics
+//!                              +-- SarifEmitter      --> SARIF 2.1.0
+//!                              +-- JunitEmitter      --> JUnit XML
+//!                              +-- CheckstyleEmitter --> Checkstyle XML
+//!                              +-- GithubEmitter     --> GitHub Actions annotations
 //!                              +-- OxlintBridge --> oxlint (future)
 //! ```
+//!
+//! The document formats (SARIF, JUnit, Checkstyle, GitHub) render from the
+//! tool-neutral [`ReportFile`] model, so `vize check` can reuse them for
+//! type-check diagnostics.
+
+mod checkstyle;
+mod github;
+mod junit;
+mod report;
+mod sarif;
+
+pub use checkstyle::CheckstyleEmitter;
+pub use github::GithubEmitter;
+pub use junit::JunitEmitter;
+pub use report::{ReportDiagnostic, ReportFile};
+pub use sarif::SarifEmitter;
 
 use crate::diagnostic::Severity;
 use crate::linter::LintResult;
+use crate::output::OutputFormat;
 
 /// An emitter that can transmit lint diagnostics to a destination.
 ///
@@ -37,6 +58,21 @@ pub trait Emitter: Send + Sync {
 
     /// Name of this emitter for identification
     fn name(&self) -> &'static str;
+
+    /// Emit all results at once.
+    ///
+    /// Formats that produce a single document (SARIF, JUnit, ...) override
+    /// this; the default concatenates per-file output and the summary.
+    fn emit_all(&self, results: &[(LintResult, String)]) -> String {
+        let mut output = String::new();
+        for (result, source) in results {
+            output.push_str(&self.emit(result, source));
+        }
+        output.push_str(
+            &self.emit_summary(&results.iter().map(|(r, _)| r.clone()).collect::<Vec<_>>()),
+        );
+        output
+    }
 }
 
 /// Telegraph coordinates the delivery of lint results to emitters.
@@ -69,6 +105,21 @@ impl Telegraph {
         telegraph
     }
 
+    /// Create Telegraph with the emitter for the given output format
+    pub fn with_format(format: OutputFormat) -> Self {
+        let mut telegraph = Self::new();
+        let emitter: Box<dyn Emitter> = match format {
+            OutputFormat::Text => Box::new(TextEmitter::default()),
+            OutputFormat::Json => Box::new(JsonEmitter),
+            OutputFormat::Sarif => Box::new(SarifEmitter::default()),
+            OutputFormat::Junit => Box::new(JunitEmitter::default()),
+            OutputFormat::Checkstyle => Box::new(CheckstyleEmitter),
+            OutputFormat::Github => Box::new(GithubEmitter),
+        };
+        telegraph.add_emitter(emitter);
+        telegraph
+    }
+
     /// Add an emitter to the telegraph
     pub fn add_emitter(&mut self, emitter: Box<dyn Emitter>) {
         self.emitters.push(emitter);
@@ -84,19 +135,7 @@ impl Telegraph {
 
     /// Transmit multiple results through all emitters
     pub fn transmit_all(&self, results: &[(LintResult, String)]) -> Vec<String> {
-        self.emitters
-            .iter()
-            .map(|e| {
-                let mut output = String::new();
-                for (result, source) in results {
-                    output.push_str(&e.emit(result, source));
-                }
-                output.push_str(
-                    &e.emit_summary(&results.iter().map(|(r, _)| r.clone()).collect::<Vec<_>>()),
-                );
-                output
-            })
-            .collect()
+        self.emitters.iter().map(|e| e.emit_all(results)).collect()
     }
 }
 
@@ -345,6 +384,25 @@ mod tests {
         assert_eq!(telegraph.emitters.len(), 1);
     }
 
+    #[test]
+    fn test_transmit_all_single_document() {
+        let result = LintResult {
+            filename: "test.vue".to_string(),
+            diagnostics: vec![LintDiagnostic::error("vue/no-v-html", "Avoid v-html", 0, 4)],
+            error_count: 1,
+            warning_count: 0,
+        };
+        let results = vec![
+            (result.clone(), "<div>".to_string()),
+            (result, "<p>".to_string()),
+        ];
+
+        let outputs = Telegraph::with_format(OutputFormat::Junit).transmit_all(&results);
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].matches("<?xml").count(), 1);
+        assert_eq!(outputs[0].matches("<testsuite ").count(), 2);
+    }
+
     #[test]
     fn test_lsp_diagnostic_conversion() {
         let result = LintResult {
@@ -0,0 +1,169 @@
+//! Tool-neutral report model shared by the document emitters.
+//!
+//! Lint rules are named by `&'static str`, while type-check diagnostics carry
+//! codes produced at runtime (`TS2322`, ...). Both are lowered into
+//! [`ReportFile`] so SARIF, JUnit, Checkstyle and GitHub output only need to
+//! be written once for `vize lint` and `vize check`.
+
+use crate::diagnostic::Severity;
+use crate::linter::LintResult;
+
+/// All diagnostics reported for a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportFile {
+    /// File path as shown to the user
+    pub path: String,
+    /// Diagnostics in source order
+    pub diagnostics: Vec<ReportDiagnostic>,
+}
+
+/// A single diagnostic with 1-based line/column positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportDiagnostic {
+    /// Rule name or diagnostic code (e.g. `vue/require-v-for-key`, `TS2322`)
+    pub rule: String,
+    pub severity: Severity,
+    pub message: String,
+    pub help: Option<String>,
+    pub line: u32,
+    pub column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
+
+impl ReportFile {
+    /// Create an empty report for a file
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Lower a lint result, resolving byte offsets against `source`
+    pub fn from_lint(result: &LintResult, source: &str) -> Self {
+        let diagnostics = result
+            .diagnostics
+            .iter()
+            .map(|d| {
+                let (line, column) = line_col(source, d.start as usize);
+                let (end_line, end_column) = line_col(source, d.end as usize);
+                ReportDiagnostic {
+                    rule: d.rule_name.to_string(),
+                    severity: d.severity,
+                    message: d.message.to_string(),
+                    help: d.help.as_ref().map(|h| h.to_string()),
+                    line,
+                    column,
+                    end_line,
+                    end_column,
+                }
+            })
+            .collect();
+
+        Self {
+            path: result.filename.clone(),
+            diagnostics,
+        }
+    }
+
+    /// Lower a batch of lint results paired with their sources
+    pub fn from_lint_results(results: &[(LintResult, String)]) -> Vec<Self> {
+        results
+            .iter()
+            .map(|(result, source)| Self::from_lint(result, source))
+            .collect()
+    }
+
+    /// Number of error-level diagnostics
+    pub fn error_count(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    /// Number of warning-level diagnostics
+    pub fn warning_count(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+}
+
+impl ReportDiagnostic {
+    /// Message with the help text appended on its own line, if any
+    pub fn full_message(&self) -> String {
+        match &self.help {
+            Some(help) => format!("{}\n{}", self.message, help),
+            None => self.message.clone(),
+        }
+    }
+}
+
+/// Convert a byte offset to a 1-based (line, column) pair
+fn line_col(source: &str, offset: usize) -> (u32, u32) {
+    let (line, col) = super::offset_to_line_col(source, offset);
+    (line + 1, col + 1)
+}
+
+/// Escape text for use in XML attribute values and character data.
+pub(crate) fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            '\r' => out.push_str("&#13;"),
+            '\t' => out.push_str("&#9;"),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::LintDiagnostic;
+
+    #[test]
+    fn test_from_lint_positions() {
+        let source = "<template>\n  <div v-for=\"x in xs\" />\n</template>";
+        let result = LintResult {
+            filename: "App.vue".to_string(),
+            diagnostics: vec![
+                LintDiagnostic::warn("vue/require-v-for-key", "Missing key", 18, 33)
+                    .with_help("Add :key"),
+            ],
+            error_count: 0,
+            warning_count: 1,
+        };
+
+        let report = ReportFile::from_lint(&result, source);
+        let d = &report.diagnostics[0];
+        assert_eq!(report.path, "App.vue");
+        assert_eq!((d.line, d.column), (2, 8));
+        assert_eq!(d.end_line, 2);
+        assert_eq!(d.rule, "vue/require-v-for-key");
+        assert_eq!(d.full_message(), "Missing key\nAdd :key");
+        assert_eq!(report.warning_count(), 1);
+        assert_eq!(report.error_count(), 0);
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(
+            escape_xml("a < b && \"c\"\n"),
+            "a &lt; b &amp;&amp; &quot;c&quot;&#10;"
+        );
+        assert_eq!(escape_xml("bell\u{7}"), "bell");
+    }
+}
@@ -0,0 +1,160 @@
+//! SARIF 2.1.0 emitter for code scanning dashboards.
+
+use super::report::ReportFile;
+use super::Emitter;
+use crate::diagnostic::Severity;
+use crate::linter::LintResult;
+use serde_json::{json, Value};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF emitter producing a single run with one result per diagnostic.
+pub struct SarifEmitter {
+    /// `tool.driver.name` of the run (e.g. `vize-patina`, `vize-canon`)
+    pub tool_name: &'static str,
+}
+
+impl SarifEmitter {
+    pub fn new(tool_name: &'static str) -> Self {
+        Self { tool_name }
+    }
+
+    /// Render all files as one SARIF log
+    pub fn render(&self, files: &[ReportFile]) -> String {
+        let mut rules: Vec<&str> = files
+            .iter()
+            .flat_map(|f| f.diagnostics.iter().map(|d| d.rule.as_str()))
+            .collect();
+        rules.sort_unstable();
+        rules.dedup();
+        let rule_ids = &rules;
+
+        let results: Vec<Value> = files
+            .iter()
+            .flat_map(|f| {
+                f.diagnostics.iter().map(move |d| {
+                    json!({
+                        "ruleId": d.rule,
+                        "ruleIndex": rule_ids.binary_search(&d.rule.as_str()).unwrap_or_default(),
+                        "level": match d.severity {
+                            Severity::Error => "error",
+                            Severity::Warning => "warning",
+                        },
+                        "message": { "text": d.full_message() },
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": artifact_uri(&f.path) },
+                                "region": {
+                                    "startLine": d.line,
+                                    "startColumn": d.column,
+                                    "endLine": d.end_line,
+                                    "endColumn": d.end_column,
+                                }
+                            }
+                        }]
+                    })
+                })
+            })
+            .collect();
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": self.tool_name,
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+                    }
+                },
+                "results": results,
+            }]
+        });
+
+        serde_json::to_string_pretty(&log).unwrap_or_default()
+    }
+}
+
+impl Default for SarifEmitter {
+    fn default() -> Self {
+        Self::new("vize-patina")
+    }
+}
+
+/// SARIF expects URI references; normalize Windows separators and drop `./`.
+fn artifact_uri(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.strip_prefix("./").map(str::to_string).unwrap_or(path)
+}
+
+impl Emitter for SarifEmitter {
+    fn name(&self) -> &'static str {
+        "sarif"
+    }
+
+    fn emit(&self, result: &LintResult, source: &str) -> String {
+        self.render(&[ReportFile::from_lint(result, source)])
+    }
+
+    fn emit_summary(&self, _results: &[LintResult]) -> String {
+        String::new()
+    }
+
+    fn emit_all(&self, results: &[(LintResult, String)]) -> String {
+        self.render(&ReportFile::from_lint_results(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telegraph::ReportDiagnostic;
+
+    fn diagnostic(rule: &str, severity: Severity) -> ReportDiagnostic {
+        ReportDiagnostic {
+            rule: rule.to_string(),
+            severity,
+            message: "Something is wrong".to_string(),
+            help: None,
+            line: 3,
+            column: 5,
+            end_line: 3,
+            end_column: 9,
+        }
+    }
+
+    #[test]
+    fn test_sarif_log() {
+        let files = vec![
+            ReportFile {
+                path: "./src/App.vue".to_string(),
+                diagnostics: vec![
+                    diagnostic("vue/no-v-html", Severity::Warning),
+                    diagnostic("TS2322", Severity::Error),
+                ],
+            },
+            ReportFile::new("src/Clean.vue"),
+        ];
+
+        let output = SarifEmitter::new("vize-canon").render(&files);
+        let log: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "vize-canon");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "TS2322");
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "vue/no-v-html");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["level"], "warning");
+        assert_eq!(results[0]["ruleIndex"], 1);
+        assert_eq!(results[1]["level"], "error");
+        let location = &results[1]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/App.vue");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 5);
+    }
+}
@@ -23,6 +23,9 @@ impl<'a, 'ctx, 'rules> LintVisitor<'a, 'ctx, 'rules> {
     /// Visit the root node and traverse the AST
     #[inline]
     pub fn visit_root(&mut self, root: &RootNode<'a>) {
+        // Collect disable comments first so template-level checks respect them
+        self.collect_disable_comments(&root.children);
+
         // Run template-level checks
         for rule in self.rules.iter() {
             self.ctx.current_rule = rule.meta().name;
@@ -47,14 +50,34 @@ impl<'a, 'ctx, 'rules> LintVisitor<'a, 'ctx, 'rules> {
             }
             TemplateChildNode::If(if_node) => self.visit_if(if_node),
             TemplateChildNode::For(for_node) => self.visit_for(for_node),
-            TemplateChildNode::Comment(comment) => {
-                self.process_disable_comment(&comment.content, comment.loc.start.line);
-            }
-            TemplateChildNode::Text(_) => {}
+            TemplateChildNode::Text(_) | TemplateChildNode::Comment(_) => {}
             _ => {}
         }
     }
 
+    /// Walk the tree and register every disable comment
+    fn collect_disable_comments(&mut self, children: &[TemplateChildNode<'a>]) {
+        for child in children {
+            match child {
+                TemplateChildNode::Comment(comment) => {
+                    // Parser positions don't track lines, so derive it from the offset
+                    let line = self.ctx.offset_to_line(comment.loc.start.offset);
+                    self.process_disable_comment(&comment.content, line);
+                }
+                TemplateChildNode::Element(el) => self.collect_disable_comments(&el.children),
+                TemplateChildNode::If(if_node) => {
+                    for branch in if_node.branches.iter() {
+                        self.collect_disable_comments(&branch.children);
+                    }
+                }
+                TemplateChildNode::For(for_node) => {
+                    self.collect_disable_comments(&for_node.children)
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Process disable comments like `vize-disable` or `vize-disable-next-line`
     fn process_disable_comment(&mut self, content: &str, line: u32) {
         let content = content.trim();
@@ -461,6 +461,7 @@ pub struct ElementNode<'a> {
     pub children: Vec<'a, TemplateChildNode<'a>>,
     pub is_self_closing: bool,
     pub loc: SourceLocation,
+    /// Span between the opening and closing tags, without `source`
     pub inner_loc: Option<SourceLocation>,
     pub codegen_node: Option<ElementCodegenNode<'a>>,
     /// If props are hoisted, this is the index into the hoists array (1-based for _hoisted_N)
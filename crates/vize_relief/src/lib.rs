@@ -17,9 +17,11 @@
 //! - Parser, transform, and codegen options
 //! - Arena-allocated nodes for zero-copy JavaScript interop
 //! - Serialization support with serde
+//! - Mutation helpers for codemods
 
 pub mod ast;
 pub mod errors;
+mod mutate;
 pub mod options;
 
 pub use ast::*;
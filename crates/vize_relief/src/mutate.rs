@@ -0,0 +1,307 @@
+//! Mutation helpers for template codemods.
+//!
+//! These keep the AST consistent with what the printer expects: nodes
+//! created here have no source location and are printed from their fields,
+//! and directive style changes go through `raw_name`, which is combined with
+//! the argument and modifiers when printing.
+
+use vize_carton::{Box, Bump, String, Vec};
+
+use crate::ast::*;
+
+impl<'a> RootNode<'a> {
+    /// Visit every element in document order, parents before children.
+    pub fn for_each_element_mut(&mut self, f: &mut impl FnMut(&mut ElementNode<'a>)) {
+        for_each_element_mut(&mut self.children, f);
+    }
+}
+
+fn for_each_element_mut<'a>(
+    children: &mut Vec<'a, TemplateChildNode<'a>>,
+    f: &mut impl FnMut(&mut ElementNode<'a>),
+) {
+    for child in children.iter_mut() {
+        if let TemplateChildNode::Element(el) = child {
+            f(el);
+            for_each_element_mut(&mut el.children, f);
+        }
+    }
+}
+
+impl<'a> ElementNode<'a> {
+    /// Change the tag name; the closing tag follows.
+    pub fn rename(&mut self, tag: impl Into<String>) {
+        self.tag = tag.into();
+    }
+
+    /// Static attribute by name.
+    pub fn attribute(&self, name: &str) -> Option<&AttributeNode> {
+        self.props.iter().find_map(|p| match p {
+            PropNode::Attribute(attr) if attr.name == name => Some(&**attr),
+            _ => None,
+        })
+    }
+
+    /// Mutable static attribute by name.
+    pub fn attribute_mut(&mut self, name: &str) -> Option<&mut AttributeNode> {
+        self.props.iter_mut().find_map(|p| match p {
+            PropNode::Attribute(attr) if attr.name == name => Some(&mut **attr),
+            _ => None,
+        })
+    }
+
+    /// Set a static attribute, appending it after the existing props when
+    /// missing. `None` makes it a boolean attribute.
+    pub fn set_attribute(&mut self, allocator: &'a Bump, name: &str, value: Option<&str>) {
+        if let Some(attr) = self.attribute_mut(name) {
+            attr.set_value(value);
+            return;
+        }
+        let mut attr = AttributeNode::new(name, SourceLocation::STUB);
+        attr.set_value(value);
+        self.props
+            .push(PropNode::Attribute(Box::new_in(attr, allocator)));
+    }
+
+    /// Directive by normalized name and static argument, e.g. `("bind", Some("foo"))`.
+    pub fn directive(&self, name: &str, arg: Option<&str>) -> Option<&DirectiveNode<'a>> {
+        self.props.iter().find_map(|p| match p {
+            PropNode::Directive(dir) if dir.name == name && dir.arg_name() == arg => Some(&**dir),
+            _ => None,
+        })
+    }
+
+    /// Mutable directive by normalized name and static argument.
+    pub fn directive_mut(
+        &mut self,
+        name: &str,
+        arg: Option<&str>,
+    ) -> Option<&mut DirectiveNode<'a>> {
+        self.props.iter_mut().find_map(|p| match p {
+            PropNode::Directive(dir) if dir.name == name && dir.arg_name() == arg => {
+                Some(&mut **dir)
+            }
+            _ => None,
+        })
+    }
+
+    /// Append a directive after the existing props.
+    pub fn add_directive(&mut self, allocator: &'a Bump, directive: DirectiveNode<'a>) {
+        self.props
+            .push(PropNode::Directive(Box::new_in(directive, allocator)));
+    }
+
+    /// Remove the props matching `f`, returning how many were removed.
+    pub fn remove_props(&mut self, mut f: impl FnMut(&PropNode<'a>) -> bool) -> usize {
+        let before = self.props.len();
+        self.props.retain(|p| !f(p));
+        before - self.props.len()
+    }
+}
+
+impl AttributeNode {
+    /// Replace the value; `None` makes it a boolean attribute.
+    pub fn set_value(&mut self, value: Option<&str>) {
+        match (value, &mut self.value) {
+            (Some(value), Some(text)) => text.content = value.into(),
+            (Some(value), None) => {
+                self.value = Some(TextNode::new(value, SourceLocation::STUB));
+            }
+            (None, _) => self.value = None,
+        }
+    }
+}
+
+impl<'a> DirectiveNode<'a> {
+    /// Create a directive with a static argument and an expression,
+    /// e.g. `DirectiveNode::with_arg(allocator, "bind", "title", Some("msg"))`.
+    pub fn with_arg(allocator: &'a Bump, name: &str, arg: &str, exp: Option<&str>) -> Self {
+        let mut dir = Self::new(allocator, name, SourceLocation::STUB);
+        dir.set_arg(allocator, Some(arg));
+        if let Some(exp) = exp {
+            dir.set_exp(allocator, exp);
+        }
+        dir
+    }
+
+    /// Static argument, `None` for dynamic (`:[key]`) or missing arguments.
+    pub fn arg_name(&self) -> Option<&str> {
+        match &self.arg {
+            Some(ExpressionNode::Simple(arg)) if arg.is_static => Some(arg.content.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Expression source, if any.
+    pub fn exp_content(&self) -> Option<&str> {
+        match &self.exp {
+            Some(ExpressionNode::Simple(exp)) => Some(exp.content.as_str()),
+            Some(ExpressionNode::Compound(exp)) => Some(exp.loc.source.as_str()),
+            None => None,
+        }
+    }
+
+    /// Replace the expression.
+    pub fn set_exp(&mut self, allocator: &'a Bump, content: &str) {
+        match &mut self.exp {
+            Some(ExpressionNode::Simple(exp)) => exp.content = content.into(),
+            _ => {
+                let exp = SimpleExpressionNode::new(content, false, SourceLocation::STUB);
+                self.exp = Some(ExpressionNode::Simple(Box::new_in(exp, allocator)));
+            }
+        }
+    }
+
+    /// Replace the argument with a static one, keeping the shorthand style
+    /// where the directive still has one.
+    pub fn set_arg(&mut self, allocator: &'a Bump, arg: Option<&str>) {
+        self.arg = arg.map(|arg| {
+            let arg = SimpleExpressionNode::new(arg, true, SourceLocation::STUB);
+            ExpressionNode::Simple(Box::new_in(arg, allocator))
+        });
+        if self.is_shorthand() && self.shorthand_prefix().is_none() {
+            self.use_longhand();
+        }
+    }
+
+    /// Whether the directive is written with `:`, `@`, `#` or `.`.
+    pub fn is_shorthand(&self) -> bool {
+        self.raw_name
+            .as_deref()
+            .is_some_and(|raw| raw.starts_with([':', '@', '#', '.']))
+    }
+
+    /// Full attribute name as written, e.g. `:foo.camel` or `v-on:click`.
+    /// `raw_name` only holds the part before the argument.
+    pub fn attribute_name(&self) -> String {
+        let mut name = match &self.raw_name {
+            Some(raw) => raw.clone(),
+            None => self.longhand_prefix(),
+        };
+        match &self.arg {
+            Some(arg) => {
+                if !self.is_shorthand() {
+                    name.push(':');
+                }
+                self.push_arg(&mut name, arg);
+            }
+            None if name == "#" => name.push_str("default"),
+            None => {}
+        }
+        self.push_modifiers(&mut name);
+        name
+    }
+
+    /// Switch to the shorthand form, returning whether the name changed.
+    /// Directives without a shorthand are left alone.
+    pub fn use_shorthand(&mut self) -> bool {
+        let Some(prefix) = self.shorthand_prefix() else {
+            return false;
+        };
+        if self.raw_name.as_deref() == Some(prefix) {
+            return false;
+        }
+        self.raw_name = Some(prefix.into());
+        true
+    }
+
+    /// Switch to the `v-` form, returning whether the name changed.
+    pub fn use_longhand(&mut self) -> bool {
+        let prefix = self.longhand_prefix();
+        if self.raw_name.as_ref() == Some(&prefix) {
+            return false;
+        }
+        self.raw_name = Some(prefix);
+        true
+    }
+
+    fn longhand_prefix(&self) -> String {
+        let mut prefix = String::from("v-");
+        prefix.push_str(&self.name);
+        prefix
+    }
+
+    fn shorthand_prefix(&self) -> Option<&'static str> {
+        match (self.name.as_str(), &self.arg) {
+            ("bind", Some(_)) => Some(":"),
+            ("on", Some(_)) => Some("@"),
+            ("slot", _) => Some("#"),
+            _ => None,
+        }
+    }
+
+    fn push_arg(&self, name: &mut String, arg: &ExpressionNode<'a>) {
+        match arg {
+            ExpressionNode::Simple(arg) if arg.is_static => name.push_str(&arg.content),
+            ExpressionNode::Simple(arg) => {
+                name.push('[');
+                name.push_str(&arg.content);
+                name.push(']');
+            }
+            ExpressionNode::Compound(arg) => name.push_str(&arg.loc.source),
+        }
+    }
+
+    fn push_modifiers(&self, name: &mut String) {
+        for modifier in self.modifiers.iter() {
+            name.push('.');
+            name.push_str(&modifier.content);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bind_foo(allocator: &Bump) -> DirectiveNode<'_> {
+        let mut dir = DirectiveNode::with_arg(allocator, "bind", "foo", Some("bar"));
+        dir.raw_name = Some("v-bind".into());
+        dir
+    }
+
+    #[test]
+    fn test_directive_names() {
+        let allocator = Bump::new();
+        let mut dir = bind_foo(&allocator);
+        dir.modifiers.push(SimpleExpressionNode::new(
+            "camel",
+            true,
+            SourceLocation::STUB,
+        ));
+
+        assert_eq!(dir.attribute_name(), "v-bind:foo.camel");
+        assert!(dir.use_shorthand());
+        assert!(!dir.use_shorthand());
+        assert_eq!(dir.attribute_name(), ":foo.camel");
+
+        dir.set_arg(&allocator, Some("baz"));
+        assert_eq!(dir.attribute_name(), ":baz.camel");
+        dir.set_arg(&allocator, None);
+        assert_eq!(dir.attribute_name(), "v-bind.camel");
+        assert_eq!(dir.exp_content(), Some("bar"));
+    }
+
+    #[test]
+    fn test_element_props() {
+        let allocator = Bump::new();
+        let mut el = ElementNode::new(&allocator, "div", SourceLocation::STUB);
+        el.set_attribute(&allocator, "id", Some("a"));
+        el.set_attribute(&allocator, "id", Some("b"));
+        el.set_attribute(&allocator, "hidden", None);
+        el.add_directive(&allocator, bind_foo(&allocator));
+
+        assert_eq!(el.props.len(), 3);
+        assert_eq!(
+            el.attribute("id")
+                .and_then(|a| a.value.as_ref())
+                .map(|v| v.content.as_str()),
+            Some("b")
+        );
+        assert!(el.directive("bind", Some("foo")).is_some());
+
+        let removed = el.remove_props(|p| matches!(p, PropNode::Attribute(_)));
+        assert_eq!(removed, 2);
+        assert_eq!(el.props.len(), 1);
+    }
+}
@@ -150,6 +150,14 @@ pub struct SfcCompileOptionsNapi {
     pub is_ts: Option<bool>,
     /// Scope ID for scoped CSS (e.g., "data-v-abc123")
     pub scope_id: Option<String>,
+    /// Compile for `defineCustomElement` (always on for `*.ce.vue`)
+    pub custom_element: Option<bool>,
+    /// Apply the built-in `<i18n>` and `<docs>` custom block transforms
+    pub custom_blocks: Option<bool>,
+    /// Code prepended to SCSS/Sass style blocks
+    pub scss_additional_data: Option<String>,
+    /// Code prepended to Less style blocks
+    pub less_additional_data: Option<String>,
 }
 
 /// SFC compile result for NAPI
@@ -163,6 +171,23 @@ pub struct SfcCompileResultNapi {
     pub errors: Vec<String>,
     /// Compilation warnings
     pub warnings: Vec<String>,
+    /// Files loaded through `src` attributes and style imports
+    pub dependencies: Vec<String>,
+}
+
+/// Load `src` blocks and preprocess styles, returning the files read
+fn load_external_blocks(
+    descriptor: &mut vize_atelier_sfc::SfcDescriptor<'_>,
+    preprocess: &vize_atelier_sfc::PreprocessOptions,
+    fs: &dyn vize_atelier_sfc::SfcFileSystem,
+) -> std::result::Result<Vec<String>, vize_atelier_sfc::SfcError> {
+    let mut dependencies = vize_atelier_sfc::resolve_src_blocks(descriptor, fs)?;
+    for import in vize_atelier_sfc::preprocess_styles(descriptor, preprocess, fs)? {
+        if !dependencies.contains(&import) {
+            dependencies.push(import);
+        }
+    }
+    Ok(dependencies)
 }
 
 /// Parse SFC (.vue file) - returns lightweight result for speed
@@ -245,14 +270,20 @@ pub fn parse_sfc(env: Env, source: String, options: Option<SfcParseOptionsNapi>)
 }
 
 /// Compile SFC (.vue file) to JavaScript - main use case
+///
+/// `src` blocks are loaded with `readFile(path)` when given, and from the
+/// file system otherwise.
 #[napi(js_name = "compileSfc")]
 pub fn compile_sfc(
+    env: Env,
     source: String,
     options: Option<SfcCompileOptionsNapi>,
+    read_file: Option<JsFunction>,
 ) -> Result<SfcCompileResultNapi> {
     use vize_atelier_sfc::{
-        compile_sfc as sfc_compile, parse_sfc as sfc_parse, ScriptCompileOptions,
-        SfcCompileOptions, SfcParseOptions, StyleCompileOptions, TemplateCompileOptions,
+        compile_sfc as sfc_compile, parse_sfc as sfc_parse, CustomBlockTransforms,
+        NativeFileSystem, PreprocessOptions, ScriptCompileOptions, SfcCompileOptions,
+        SfcParseOptions, StyleCompileOptions, TemplateCompileOptions,
     };
 
     let opts = options.unwrap_or_default();
@@ -264,7 +295,7 @@ pub fn compile_sfc(
         ..Default::default()
     };
 
-    let descriptor = match sfc_parse(&source, parse_opts) {
+    let mut descriptor = match sfc_parse(&source, parse_opts) {
         Ok(d) => d,
         Err(e) => {
             return Ok(SfcCompileResultNapi {
@@ -272,6 +303,42 @@ pub fn compile_sfc(
                 css: None,
                 errors: vec![e.message],
                 warnings: vec![],
+                dependencies: vec![],
+            });
+        }
+    };
+
+    // Load `src` blocks and compile SCSS/Sass/Less styles
+    let preprocess = PreprocessOptions {
+        scss_additional_data: opts.scss_additional_data.clone(),
+        less_additional_data: opts.less_additional_data.clone(),
+        ..Default::default()
+    };
+    let resolved = match &read_file {
+        Some(read_file) => {
+            let fs = |path: &str| -> std::result::Result<String, String> {
+                let call = || -> Result<String> {
+                    read_file
+                        .call(None, &[env.create_string(path)?])?
+                        .coerce_to_string()?
+                        .into_utf8()?
+                        .into_owned()
+                };
+                call().map_err(|e| e.reason)
+            };
+            load_external_blocks(&mut descriptor, &preprocess, &fs)
+        }
+        None => load_external_blocks(&mut descriptor, &preprocess, &NativeFileSystem),
+    };
+    let dependencies = match resolved {
+        Ok(dependencies) => dependencies,
+        Err(e) => {
+            return Ok(SfcCompileResultNapi {
+                code: String::new(),
+                css: None,
+                errors: vec![e.message],
+                warnings: vec![],
+                dependencies: vec![],
             });
         }
     };
@@ -315,6 +382,13 @@ pub fn compile_sfc(
             scoped: has_scoped,
             ..Default::default()
         },
+        scope_id: None,
+        custom_element: opts.custom_element.unwrap_or(false),
+        custom_blocks: if opts.custom_blocks.unwrap_or(false) {
+            CustomBlockTransforms::builtin()
+        } else {
+            CustomBlockTransforms::new()
+        },
     };
 
     match sfc_compile(&descriptor, compile_opts) {
@@ -323,12 +397,14 @@ pub fn compile_sfc(
             css: result.css,
             errors: result.errors.into_iter().map(|e| e.message).collect(),
             warnings: result.warnings.into_iter().map(|e| e.message).collect(),
+            dependencies,
         }),
         Err(e) => Ok(SfcCompileResultNapi {
             code: String::new(),
             css: None,
             errors: vec![e.message],
             warnings: vec![],
+            dependencies,
         }),
     }
 }
@@ -341,6 +417,10 @@ pub struct BatchCompileOptionsNapi {
     /// Preserve TypeScript in output when true
     pub is_ts: Option<bool>,
     pub threads: Option<u32>,
+    /// Directory of a persistent compile cache (e.g. `node_modules/.vize/cache`)
+    pub cache_dir: Option<String>,
+    /// Apply the built-in `<i18n>` and `<docs>` custom block transforms
+    pub custom_blocks: Option<bool>,
 }
 
 /// Batch compile result for NAPI
@@ -354,6 +434,8 @@ pub struct BatchCompileResultNapi {
     pub input_bytes: u32,
     /// Total output bytes
     pub output_bytes: u32,
+    /// Number of files served from the compile cache
+    pub cache_hits: u32,
     /// Compilation time in milliseconds
     pub time_ms: f64,
 }
@@ -401,6 +483,8 @@ pub struct BatchCompileResultWithFilesNapi {
     pub success_count: u32,
     /// Number of files that failed
     pub failed_count: u32,
+    /// Number of files served from the compile cache
+    pub cache_hits: u32,
     /// Compilation time in milliseconds
     pub time_ms: f64,
 }
@@ -413,13 +497,20 @@ pub fn compile_sfc_batch(
 ) -> Result<BatchCompileResultNapi> {
     use std::time::Instant;
     use vize_atelier_sfc::{
-        compile_sfc as sfc_compile, parse_sfc as sfc_parse, ScriptCompileOptions,
-        SfcCompileOptions, SfcParseOptions, StyleCompileOptions, TemplateCompileOptions,
+        compile_sfc as sfc_compile, parse_sfc as sfc_parse, CompileCache, CustomBlockTransforms,
+        ScriptCompileOptions, SfcCompileOptions, SfcParseOptions, StyleCompileOptions,
+        TemplateCompileOptions,
     };
 
     let opts = options.unwrap_or_default();
     let ssr = opts.ssr.unwrap_or(false);
     let is_ts = opts.is_ts.unwrap_or(false);
+    let cache = opts.cache_dir.map(CompileCache::new);
+    let custom_blocks = if opts.custom_blocks.unwrap_or(false) {
+        CustomBlockTransforms::builtin()
+    } else {
+        CustomBlockTransforms::new()
+    };
 
     // Configure thread pool if specified
     if let Some(threads) = opts.threads {
@@ -511,9 +602,16 @@ pub fn compile_sfc_batch(
                 scoped: has_scoped,
                 ..Default::default()
             },
+            scope_id: None,
+            custom_element: false,
+            custom_blocks: custom_blocks.clone(),
         };
 
-        match sfc_compile(&descriptor, compile_opts) {
+        let compiled = match &cache {
+            Some(cache) => cache.compile(&source, &descriptor, compile_opts),
+            None => sfc_compile(&descriptor, compile_opts),
+        };
+        match compiled {
             Ok(result) => {
                 success.fetch_add(1, Ordering::Relaxed);
                 output_bytes.fetch_add(result.code.len(), Ordering::Relaxed);
@@ -531,6 +629,7 @@ pub fn compile_sfc_batch(
         failed: failed.load(Ordering::Relaxed) as u32,
         input_bytes: input_bytes.load(Ordering::Relaxed) as u32,
         output_bytes: output_bytes.load(Ordering::Relaxed) as u32,
+        cache_hits: cache.as_ref().map_or(0, |cache| cache.hits() as u32),
         time_ms: elapsed.as_secs_f64() * 1000.0,
     })
 }
@@ -544,13 +643,20 @@ pub fn compile_sfc_batch_with_results(
     use std::sync::Mutex;
     use std::time::Instant;
     use vize_atelier_sfc::{
-        compile_sfc as sfc_compile, parse_sfc as sfc_parse, ScriptCompileOptions,
-        SfcCompileOptions, SfcParseOptions, StyleCompileOptions, TemplateCompileOptions,
+        compile_sfc as sfc_compile, parse_sfc as sfc_parse, CompileCache, CustomBlockTransforms,
+        ScriptCompileOptions, SfcCompileOptions, SfcParseOptions, StyleCompileOptions,
+        TemplateCompileOptions,
     };
 
     let opts = options.unwrap_or_default();
     let ssr = opts.ssr.unwrap_or(false);
     let is_ts = opts.is_ts.unwrap_or(false);
+    let cache = opts.cache_dir.map(CompileCache::new);
+    let custom_blocks = if opts.custom_blocks.unwrap_or(false) {
+        CustomBlockTransforms::builtin()
+    } else {
+        CustomBlockTransforms::new()
+    };
 
     // Configure thread pool if specified
     if let Some(threads) = opts.threads {
@@ -650,9 +756,16 @@ pub fn compile_sfc_batch_with_results(
                 scoped: actual_has_scoped,
                 ..Default::default()
             },
+            scope_id: None,
+            custom_element: false,
+            custom_blocks: custom_blocks.clone(),
         };
 
-        match sfc_compile(&descriptor, compile_opts) {
+        let compiled = match &cache {
+            Some(cache) => cache.compile(source, &descriptor, compile_opts),
+            None => sfc_compile(&descriptor, compile_opts),
+        };
+        match compiled {
             Ok(result) => {
                 success_count.fetch_add(1, Ordering::Relaxed);
                 let mut guard = results.lock().unwrap();
@@ -695,10 +808,63 @@ pub fn compile_sfc_batch_with_results(
         results: final_results,
         success_count: success_count.load(Ordering::Relaxed) as u32,
         failed_count: failed_count.load(Ordering::Relaxed) as u32,
+        cache_hits: cache.as_ref().map_or(0, |cache| cache.hits() as u32),
         time_ms: elapsed.as_secs_f64() * 1000.0,
     })
 }
 
+/// Hot update classification for NAPI
+#[napi(object)]
+pub struct SfcHmrUpdateNapi {
+    /// `full-reload`, `template-only`, `style-only`, `custom-block` or `none`
+    #[napi(js_name = "type")]
+    pub kind: String,
+    /// Why a full reload is needed (e.g. `script`, `bindings`, `css-vars`)
+    pub reason: Option<String>,
+    /// Whether the template changed
+    pub rerender: bool,
+    /// Indices of the changed style blocks
+    pub styles: Vec<u32>,
+    /// Indices of the changed custom blocks
+    pub custom_blocks: Vec<u32>,
+}
+
+/// Classify the edit between two versions of an SFC for HMR
+#[napi(js_name = "diffSfcDescriptors")]
+pub fn diff_sfc_descriptors(
+    prev_source: String,
+    next_source: String,
+    options: Option<SfcParseOptionsNapi>,
+) -> Result<SfcHmrUpdateNapi> {
+    use vize_atelier_sfc::{diff_descriptors, parse_sfc as sfc_parse, SfcParseOptions};
+
+    let opts = options.unwrap_or_default();
+    let parse = |source: &str| {
+        let parse_opts = SfcParseOptions {
+            filename: opts
+                .filename
+                .clone()
+                .unwrap_or_else(|| "anonymous.vue".to_string()),
+            ..Default::default()
+        };
+        sfc_parse(source, parse_opts)
+            .map(|descriptor| descriptor.into_owned())
+            .map_err(|e| Error::new(Status::GenericFailure, e.message))
+    };
+    let prev = parse(&prev_source)?;
+    let next = parse(&next_source)?;
+    let update = diff_descriptors(&prev, &next);
+
+    let name = |value: serde_json::Value| value.as_str().unwrap_or_default().to_string();
+    Ok(SfcHmrUpdateNapi {
+        kind: name(serde_json::json!(update.kind())),
+        reason: update.reload.map(|reason| name(serde_json::json!(reason))),
+        rerender: update.rerender,
+        styles: update.styles.iter().map(|&i| i as u32).collect(),
+        custom_blocks: update.custom_blocks.iter().map(|&i| i as u32).collect(),
+    })
+}
+
 // ============================================================================
 // Musea (Art file) bindings
 // ============================================================================
@@ -861,6 +1027,21 @@ pub struct DocOutputNapi {
     pub title: String,
     pub category: Option<String>,
     pub variant_count: u32,
+    /// Component API (props, emits, slots, exposed) when the component source was given
+    pub api: Option<serde_json::Value>,
+}
+
+impl From<vize_musea::docs::DocOutput> for DocOutputNapi {
+    fn from(output: vize_musea::docs::DocOutput) -> Self {
+        Self {
+            markdown: output.markdown,
+            filename: output.filename,
+            title: output.title,
+            category: output.category,
+            variant_count: output.variant_count as u32,
+            api: output.api.and_then(|api| serde_json::to_value(api).ok()),
+        }
+    }
 }
 
 /// Catalog entry for NAPI
@@ -887,13 +1068,17 @@ pub struct CatalogOutputNapi {
 }
 
 /// Generate component documentation from Art source
+///
+/// When `component_source` (the `.vue` file the art documents) is given,
+/// its props, emits, slots and exposed members are documented too.
 #[napi(js_name = "generateArtDoc")]
 pub fn generate_art_doc(
     source: String,
     art_options: Option<ArtParseOptionsNapi>,
     doc_options: Option<DocOptionsNapi>,
+    component_source: Option<String>,
 ) -> Result<DocOutputNapi> {
-    use vize_musea::docs::{generate_component_doc, DocOptions};
+    use vize_musea::docs::{extract_component_api, generate_component_doc_with_api, DocOptions};
     use vize_musea::{parse_art as musea_parse, ArtParseOptions, Bump};
 
     let allocator = Bump::new();
@@ -919,15 +1104,17 @@ pub fn generate_art_doc(
         include_timestamp: false,
     };
 
-    let output = generate_component_doc(&descriptor, &opts);
+    let api = component_source
+        .map(|component| {
+            let filename = descriptor.metadata.component.unwrap_or("component.vue");
+            extract_component_api(&component, filename)
+                .map_err(|e| Error::new(Status::GenericFailure, e.message))
+        })
+        .transpose()?;
 
-    Ok(DocOutputNapi {
-        markdown: output.markdown,
-        filename: output.filename,
-        title: output.title,
-        category: output.category,
-        variant_count: output.variant_count as u32,
-    })
+    let output = generate_component_doc_with_api(&descriptor, api.as_ref(), &opts);
+
+    Ok(output.into())
 }
 
 /// Generate catalog from multiple Art sources (high-performance batch)
@@ -978,12 +1165,16 @@ pub fn generate_art_catalog(
 }
 
 /// Batch generate docs with parallel processing
+///
+/// `component_sources` is index-aligned with `sources`; an entry holds the
+/// source of the component documented by that art file, if known.
 #[napi(js_name = "generateArtDocsBatch")]
 pub fn generate_art_docs_batch(
     sources: Vec<String>,
     doc_options: Option<DocOptionsNapi>,
+    component_sources: Option<Vec<Option<String>>>,
 ) -> Result<Vec<DocOutputNapi>> {
-    use vize_musea::docs::{generate_component_doc, DocOptions};
+    use vize_musea::docs::{extract_component_api, generate_component_doc_with_api, DocOptions};
     use vize_musea::{parse_art as musea_parse, ArtParseOptions, Bump};
 
     let doc_opts = doc_options.unwrap_or_default();
@@ -1011,14 +1202,15 @@ pub fn generate_art_docs_batch(
             musea_parse(&allocator, source, parse_opts)
                 .ok()
                 .map(|descriptor| {
-                    let output = generate_component_doc(&descriptor, &opts);
-                    DocOutputNapi {
-                        markdown: output.markdown,
-                        filename: output.filename,
-                        title: output.title,
-                        category: output.category,
-                        variant_count: output.variant_count as u32,
-                    }
+                    // A component that fails to parse only loses its API section
+                    let api = component_sources
+                        .as_ref()
+                        .and_then(|components| components.get(idx)?.as_deref())
+                        .and_then(|component| {
+                            let filename = descriptor.metadata.component.unwrap_or("component.vue");
+                            extract_component_api(component, filename).ok()
+                        });
+                    generate_component_doc_with_api(&descriptor, api.as_ref(), &opts).into()
                 })
         })
         .collect();
@@ -15,6 +15,7 @@ pub struct TypeCheckOptionsNapi {
     pub check_props: Option<bool>,
     pub check_emits: Option<bool>,
     pub check_template_bindings: Option<bool>,
+    pub check_template_types: Option<bool>,
     pub check_reactivity: Option<bool>,
     pub check_setup_context: Option<bool>,
     pub check_invalid_exports: Option<bool>,
@@ -59,6 +60,7 @@ fn apply_napi_options(opts: &TypeCheckOptionsNapi, check_opts: &mut TypeCheckOpt
     check_opts.check_props = opts.check_props.unwrap_or(true);
     check_opts.check_emits = opts.check_emits.unwrap_or(true);
     check_opts.check_template_bindings = opts.check_template_bindings.unwrap_or(true);
+    check_opts.check_template_types = opts.check_template_types.unwrap_or(true);
     check_opts.check_reactivity = opts.check_reactivity.unwrap_or(true);
     check_opts.check_setup_context = opts.check_setup_context.unwrap_or(true);
     check_opts.check_invalid_exports = opts.check_invalid_exports.unwrap_or(true);
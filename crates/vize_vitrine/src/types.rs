@@ -44,6 +44,9 @@ pub struct CompilerOptions {
     /// Defaults to "downcompile"
     #[serde(default)]
     pub script_ext: Option<String>,
+    /// Apply the built-in `<i18n>` and `<docs>` custom block transforms
+    #[serde(default)]
+    pub custom_blocks: Option<bool>,
 }
 
 /// Compile result
@@ -346,6 +346,7 @@ fn parse_cross_file_options(options: &JsValue) -> vize_croquis::cross_file::Cros
         error_suspense_boundary: get_bool("errorSuspenseBoundary"),
         reactivity_tracking: get_bool("reactivityTracking"),
         setup_context: get_bool("setupContext"),
+        hydration: get_bool("hydration"),
         circular_dependencies: get_bool("circularDependencies"),
         max_import_depth: js_sys::Reflect::get(options, &JsValue::from_str("maxImportDepth"))
             .ok()
@@ -385,6 +386,8 @@ fn diagnostic_kind_to_string(
         BrowserApiInSsr { .. } => "ssr-boundary",
         AsyncWithoutSuspense { .. } => "ssr-boundary",
         HydrationMismatchRisk { .. } => "ssr-boundary",
+        NondeterministicPropFlow { .. } => "hydration",
+        InvalidHtmlNesting { .. } => "hydration",
         // Error boundary
         UncaughtErrorBoundary => "error-boundary",
         MissingSuspenseBoundary => "error-boundary",
@@ -470,6 +473,8 @@ fn is_template_related_diagnostic(
             | UnusedFallthroughAttrs { .. }
             | MultiRootMissingAttrs
             | InheritAttrsDisabledUnused
+            | NondeterministicPropFlow { .. }
+            | InvalidHtmlNesting { .. }
     )
 }
 
@@ -3,6 +3,16 @@
 use super::to_js_value;
 use wasm_bindgen::prelude::*;
 
+/// Parse `restrictedSyntax` from options (array of `{ selector, message, severity }`)
+fn restricted_syntax(options: &JsValue) -> Result<Vec<vize_patina::RestrictedSyntax>, JsValue> {
+    let value = js_sys::Reflect::get(options, &JsValue::from_str("restrictedSyntax"))
+        .unwrap_or(JsValue::UNDEFINED);
+    if value.is_undefined() || value.is_null() {
+        return Ok(Vec::new());
+    }
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
 /// Lint Vue SFC template
 #[wasm_bindgen(js_name = "lintTemplate")]
 pub fn lint_template_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
@@ -36,7 +46,9 @@ pub fn lint_template_wasm(source: &str, options: JsValue) -> Result<JsValue, JsV
 
     let linter = Linter::new()
         .with_locale(locale)
-        .with_enabled_rules(enabled_rules);
+        .with_enabled_rules(enabled_rules)
+        .with_restricted_syntax(restricted_syntax(&options)?)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
     let result = linter.lint_template(source, &filename);
 
     // Use LspEmitter for accurate line/column conversion
@@ -122,7 +134,9 @@ pub fn lint_sfc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue>
 
     let linter = Linter::new()
         .with_locale(locale)
-        .with_enabled_rules(enabled_rules);
+        .with_enabled_rules(enabled_rules)
+        .with_restricted_syntax(restricted_syntax(&options)?)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
     let result = linter.lint_sfc(source, &filename);
 
     // Use LspEmitter for accurate line/column conversion
@@ -27,8 +27,9 @@ use vize_atelier_core::options::CodegenMode;
 use vize_atelier_core::parser::parse;
 use vize_atelier_dom::{compile_template_with_options, DomCompilerOptions};
 use vize_atelier_sfc::{
-    compile_sfc as sfc_compile, parse_sfc, ScriptCompileOptions, SfcCompileOptions, SfcDescriptor,
-    SfcParseOptions, StyleCompileOptions, TemplateCompileOptions,
+    compile_sfc as sfc_compile, parse_sfc, resolve_src_blocks, CustomBlockTransforms,
+    ScriptCompileOptions, SfcCompileOptions, SfcDescriptor, SfcParseOptions, StyleCompileOptions,
+    TemplateCompileOptions,
 };
 use vize_atelier_ssr::compile_ssr as ssr_compile;
 use vize_atelier_vapor::{compile_vapor as vapor_compile, VaporCompilerOptions};
@@ -66,6 +67,9 @@ pub struct SfcWasmResult {
     pub warnings: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "bindingMetadata")]
     pub binding_metadata: Option<serde_json::Value>,
+    /// Files loaded through `src` attributes
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
 }
 
 /// Script compilation result
@@ -173,11 +177,30 @@ impl Compiler {
         };
 
         // Parse SFC
-        let descriptor = match parse_sfc(source, parse_opts) {
+        let mut descriptor = match parse_sfc(source, parse_opts) {
             Ok(d) => d,
             Err(e) => return Err(JsValue::from_str(&e.message)),
         };
 
+        // Load `src` blocks through the host's `readFile(path)` callback
+        let read_file = js_sys::Reflect::get(&options, &JsValue::from_str("readFile"))
+            .ok()
+            .and_then(|v| v.dyn_into::<js_sys::Function>().ok());
+        let dependencies = match read_file {
+            Some(read_file) => {
+                let fs = |path: &str| -> Result<String, String> {
+                    read_file
+                        .call1(&JsValue::NULL, &JsValue::from_str(path))
+                        .map_err(|e| e.as_string().unwrap_or_else(|| format!("{:?}", e)))?
+                        .as_string()
+                        .ok_or_else(|| "readFile did not return a string".to_string())
+                };
+                resolve_src_blocks(&mut descriptor, &fs)
+                    .map_err(|e| JsValue::from_str(&e.message))?
+            }
+            None => Vec::new(),
+        };
+
         // Detect vapor mode from script setup attrs or options
         let has_vapor_attr = descriptor
             .script_setup
@@ -253,6 +276,13 @@ impl Compiler {
                 scoped: descriptor.styles.iter().any(|s| s.scoped),
                 ..Default::default()
             },
+            scope_id: None,
+            custom_element: false,
+            custom_blocks: if opts.custom_blocks.unwrap_or(false) {
+                CustomBlockTransforms::builtin()
+            } else {
+                CustomBlockTransforms::new()
+            },
         };
 
         // Compile the full SFC
@@ -281,6 +311,7 @@ impl Compiler {
             errors: sfc_result.errors.into_iter().map(|e| e.message).collect(),
             warnings: sfc_result.warnings.into_iter().map(|e| e.message).collect(),
             binding_metadata,
+            dependencies,
         };
 
         to_js_value(&result)
@@ -83,7 +83,7 @@ pub fn art_to_csf_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValu
 /// Generate component documentation from Art source
 #[wasm_bindgen(js_name = "generateArtDoc")]
 pub fn generate_art_doc_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
-    use vize_musea::docs::{generate_component_doc, DocOptions};
+    use vize_musea::docs::{extract_component_api, generate_component_doc_with_api, DocOptions};
     use vize_musea::{parse_art, ArtParseOptions, Bump};
 
     let allocator = Bump::new();
@@ -130,7 +130,17 @@ pub fn generate_art_doc_wasm(source: &str, options: JsValue) -> Result<JsValue,
         include_timestamp: false,
     };
 
-    let output = generate_component_doc(&descriptor, &doc_opts);
+    // Source of the documented component, for its API section
+    let api = js_sys::Reflect::get(&options, &JsValue::from_str("componentSource"))
+        .ok()
+        .and_then(|v| v.as_string())
+        .map(|component| {
+            let filename = descriptor.metadata.component.unwrap_or("component.vue");
+            extract_component_api(&component, filename).map_err(|e| JsValue::from_str(&e.message))
+        })
+        .transpose()?;
+
+    let output = generate_component_doc_with_api(&descriptor, api.as_ref(), &doc_opts);
 
     let result = serde_json::json!({
         "markdown": output.markdown,
@@ -138,6 +148,7 @@ pub fn generate_art_doc_wasm(source: &str, options: JsValue) -> Result<JsValue,
         "title": output.title,
         "category": output.category,
         "variantCount": output.variant_count,
+        "api": output.api,
     });
 
     to_js_value(&result)
@@ -53,12 +53,19 @@ pub fn type_check_wasm(source: &str, options: JsValue) -> Result<JsValue, JsValu
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
 
+    let check_template_types =
+        js_sys::Reflect::get(&options, &JsValue::from_str("checkTemplateTypes"))
+            .ok()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
     let mut opts = TypeCheckOptions::new(filename);
     opts.strict = strict;
     opts.include_virtual_ts = include_virtual_ts;
     opts.check_props = check_props;
     opts.check_emits = check_emits;
     opts.check_template_bindings = check_template_bindings;
+    opts.check_template_types = check_template_types;
 
     let result = type_check_sfc(source, &opts);
 
@@ -3,7 +3,7 @@
  */
 
 import { type Component, type App as VueApp, ref, type Ref } from "@vue/runtime-core";
-import { createRenderer, treeToRenderNodes, type FrescoElement } from "./renderer.js";
+import { createRenderer, getNode, treeToRenderNodes, type FrescoElement } from "./renderer.js";
 
 // Event types
 export interface KeyEvent {
@@ -23,9 +23,18 @@ export interface ResizeEvent {
 
 export type InputEvent = KeyEvent | ResizeEvent;
 
+/** Focus change reported by the native focus manager */
+export interface FocusEvent {
+  eventType: string;
+  nodeId: number;
+}
+
 // Global event state
 export const lastKeyEvent: Ref<KeyEvent | null> = ref(null);
 
+/** ID of the node focused by the native focus manager */
+export const focusedNodeId: Ref<number | null> = ref(null);
+
 // Import native bindings
 // eslint-disable-next-line typescript-eslint/no-redundant-type-constituents -- index.d.ts is not yet generated
 let native: typeof import("@vizejs/fresco-native") | null = null;
@@ -37,6 +46,46 @@ async function loadNative() {
   return native;
 }
 
+/**
+ * Update the focused node and call its `onFocus` / `onBlur` handlers
+ */
+function dispatchFocusEvents(events: FocusEvent[]) {
+  for (const event of events) {
+    const isFocus = event.eventType === "focus";
+    if (isFocus) {
+      focusedNodeId.value = event.nodeId;
+    } else if (focusedNodeId.value === event.nodeId) {
+      focusedNodeId.value = null;
+    }
+
+    const handler = getNode(event.nodeId)?.props[isFocus ? "onFocus" : "onBlur"];
+    const handlers = Array.isArray(handler) ? handler : [handler];
+    for (const h of handlers) {
+      if (typeof h === "function") {
+        h(event);
+      }
+    }
+  }
+}
+
+/**
+ * Focus a rendered node (ignored if it is not focusable)
+ */
+export function focusNode(id: number) {
+  if (native) {
+    dispatchFocusEvents(native.focusNode(id));
+  }
+}
+
+/**
+ * Remove focus from the focused node
+ */
+export function blurFocus() {
+  if (native) {
+    dispatchFocusEvents(native.blurFocus());
+  }
+}
+
 /**
  * App options
  */
@@ -144,6 +193,7 @@ export function createApp(rootComponent: Component, options: AppOptions = {}): A
 
     rootElement = null;
     mounted = false;
+    focusedNodeId.value = null;
 
     if (exitResolve) {
       exitResolve();
@@ -172,6 +222,9 @@ export function createApp(rootComponent: Component, options: AppOptions = {}): A
 
         // Flush to display
         native.flushTerminal();
+
+        // Focus changes from the new tree (focus requests, removed nodes)
+        dispatchFocusEvents(native.takeFocusEvents());
       }
     } catch (error) {
       if (onError) {
@@ -214,8 +267,11 @@ export function createApp(rootComponent: Component, options: AppOptions = {}): A
             break;
           }
 
-          // Dispatch key events
-          if (event.eventType === "key") {
+          // Tab / Shift-Tab move focus; other keys go to the app
+          const focusEvents = n.handleFocusKey(event);
+          if (focusEvents) {
+            dispatchFocusEvents(focusEvents);
+          } else if (event.eventType === "key") {
             lastKeyEvent.value = {
               type: "key",
               key: event.key ?? undefined,
@@ -4,13 +4,14 @@
 
 import { defineComponent, h, ref, watch, computed, type PropType } from "@vue/runtime-core";
 import { useInput } from "../composables/useInput.js";
+import { useFocus } from "../composables/useFocus.js";
 
 export interface TextInputProps {
   /** Input value (v-model) */
   modelValue?: string;
   /** Placeholder text */
   placeholder?: string;
-  /** Whether input is focused */
+  /** Whether input requests focus (Tab / Shift-Tab move it afterwards) */
   focus?: boolean;
   /** Password mode (mask input) */
   mask?: boolean;
@@ -131,8 +132,9 @@ export const TextInput = defineComponent({
       cursorPos.value = internalValue.value.length;
     };
 
-    // Use focus prop to control input handling
-    const isActive = computed(() => props.focus);
+    // Handle input while the native focus manager has this input focused
+    const { isFocused, focusProps } = useFocus();
+    const isActive = computed(() => isFocused.value);
 
     // Handle keyboard input when focused
     useInput({
@@ -173,6 +175,7 @@ export const TextInput = defineComponent({
       }
 
       return h("input", {
+        ...focusProps.value,
         value: internalValue.value,
         placeholder: props.placeholder,
         focused: props.focus,
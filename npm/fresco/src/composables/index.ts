@@ -3,6 +3,6 @@
  */
 
 export { useInput, type UseInputOptions, type KeyHandler } from "./useInput.js";
-export { useFocus, type UseFocusOptions, type FocusManager } from "./useFocus.js";
+export { useFocus, type UseFocusOptions } from "./useFocus.js";
 export { useApp, type UseAppReturn } from "./useApp.js";
 export { useIme, type UseImeOptions, type ImeManager } from "./useIme.js";
@@ -5,7 +5,17 @@
  */
 
 // Core
-export { createApp, type App, type AppOptions, lastKeyEvent, type KeyEvent } from "./app.js";
+export {
+  createApp,
+  type App,
+  type AppOptions,
+  lastKeyEvent,
+  type KeyEvent,
+  focusedNodeId,
+  focusNode,
+  blurFocus,
+  type FocusEvent,
+} from "./app.js";
 export { createRenderer } from "./renderer.js";
 
 // Components
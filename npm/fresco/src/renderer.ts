@@ -28,14 +28,33 @@ export interface FrescoElement extends FrescoNode, RendererElement {}
 
 let nextId = 0;
 
+/** Mounted nodes by ID, to dispatch native events to them */
+const nodesById = new Map<number, FrescoNode>();
+
 function createNode(type: FrescoNode["type"]): FrescoNode {
-  return {
+  const node: FrescoNode = {
     id: nextId++,
     type,
     props: {},
     children: [],
     parent: null,
   };
+  nodesById.set(node.id, node);
+  return node;
+}
+
+function forgetNode(node: FrescoNode) {
+  nodesById.delete(node.id);
+  for (const child of node.children) {
+    forgetNode(child);
+  }
+}
+
+/**
+ * Get a node by ID
+ */
+export function getNode(id: number): FrescoNode | undefined {
+  return nodesById.get(id);
 }
 
 /**
@@ -66,6 +85,7 @@ const rendererOptions: RendererOptions<FrescoNode, FrescoElement> = {
       }
       child.parent = null;
     }
+    forgetNode(child);
   },
 
   createElement(type) {
@@ -90,6 +110,9 @@ const rendererOptions: RendererOptions<FrescoNode, FrescoElement> = {
 
   setElementText(el, text) {
     el.text = text;
+    for (const child of el.children) {
+      forgetNode(child);
+    }
     el.children = [];
   },
 
@@ -142,6 +165,7 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
   value?: string;
   placeholder?: string;
   focused?: boolean;
+  tabIndex?: number;
   mask?: boolean;
   style?: Record<string, unknown>;
   appearance?: Record<string, unknown>;
@@ -156,6 +180,7 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
     value?: string;
     placeholder?: string;
     focused?: boolean;
+    tabIndex?: number;
     mask?: boolean;
     style?: Record<string, unknown>;
     appearance?: Record<string, unknown>;
@@ -187,6 +212,9 @@ export function treeToRenderNodes(root: FrescoNode): Array<{
     if (node.props.focused !== undefined) {
       renderNode.focused = Boolean(node.props.focused);
     }
+    if (node.props.tabIndex !== undefined) {
+      renderNode.tabIndex = Number(node.props.tabIndex);
+    }
     if (node.props.cursor !== undefined) {
       (renderNode as any).cursor = Number(node.props.cursor);
     }
@@ -176,12 +176,25 @@ interface NativeBinding {
       include_templates?: boolean;
       include_metadata?: boolean;
     },
+    componentSource?: string,
   ) => {
     markdown: string;
     filename: string;
     title: string;
     category?: string;
     variant_count: number;
+    api?: {
+      props: Array<{
+        name: string;
+        type?: string;
+        required: boolean;
+        defaultValue?: string;
+        description?: string;
+      }>;
+      emits: Array<{ name: string; payloadType?: string; description?: string }>;
+      slots: Array<{ name: string; propsType?: string; description?: string }>;
+      exposed: Array<{ name: string; type?: string; description?: string }>;
+    };
   };
   analyzeSfc?: (
     source: string,
@@ -861,7 +874,23 @@ export function musea(options: MuseaOptions = {}): Plugin[] {
               const source = await fs.promises.readFile(artPath, "utf-8");
               const binding = loadNative();
               if (binding.generateArtDoc) {
-                const doc = binding.generateArtDoc(source, { filename: artPath });
+                // Document the component's props, events, slots and exposed members too
+                let componentSource: string | undefined;
+                if (art.metadata.component) {
+                  const componentPath = path.resolve(
+                    path.dirname(artPath),
+                    art.metadata.component,
+                  );
+                  componentSource = await fs.promises
+                    .readFile(componentPath, "utf-8")
+                    .catch(() => undefined);
+                }
+                const doc = binding.generateArtDoc(
+                  source,
+                  { filename: artPath },
+                  undefined,
+                  componentSource,
+                );
                 // Replace Self with component name and format indentation
                 let markdown = doc.markdown || "";
                 const componentName = art.metadata.title || "Component";
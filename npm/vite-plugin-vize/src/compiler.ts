@@ -38,6 +38,8 @@ export function compileFile(
     css: result.css,
     scopeId,
     hasScoped,
+    source: content,
+    dependencies: result.dependencies,
   };
 
   cache.set(filePath, compiled);
@@ -61,6 +63,7 @@ export function compileBatch(
   const result = compileSfcBatchWithResults(inputs, {
     ssr: options.ssr,
   });
+  const sources = new Map(files.map((f) => [f.path, f.source]));
 
   // Update cache with results
   for (const fileResult of result.results) {
@@ -73,6 +76,7 @@ export function compileBatch(
         templateHash: fileResult.templateHash,
         styleHash: fileResult.styleHash,
         scriptHash: fileResult.scriptHash,
+        source: sources.get(fileResult.path),
       });
     }
 
@@ -1,16 +1,26 @@
+import * as native from "@vizejs/native";
 import type { CompiledModule } from "./types.js";
 
+const { diffSfcDescriptors } = native;
+
 /**
  * HMR update types for granular hot module replacement.
  *
  * - 'template-only': Only template changed, use rerender (preserves state)
  * - 'style-only': Only styles changed, inject CSS without component remount
- * - 'full-reload': Script changed, full component reload required
+ * - 'custom-block': Only custom blocks changed
+ * - 'full-reload': Script, template bindings or CSS vars changed, full component reload required
+ * - 'none': Nothing that affects the output changed
  */
-export type HmrUpdateType = "template-only" | "style-only" | "full-reload";
+export type HmrUpdateType =
+  | "template-only"
+  | "style-only"
+  | "custom-block"
+  | "full-reload"
+  | "none";
 
 /**
- * Detect the type of HMR update needed based on content hash changes.
+ * Detect the type of HMR update needed by diffing the SFC sources natively.
  *
  * @param prev - Previously compiled module (undefined if first compile)
  * @param next - Newly compiled module
@@ -21,34 +31,11 @@ export function detectHmrUpdateType(
   next: CompiledModule,
 ): HmrUpdateType {
   // First compile always requires full reload
-  if (!prev) {
+  if (prev?.source === undefined || next.source === undefined) {
     return "full-reload";
   }
 
-  // Check for script changes (requires full reload)
-  const scriptChanged = prev.scriptHash !== next.scriptHash;
-  if (scriptChanged) {
-    return "full-reload";
-  }
-
-  // Check for template changes (can use rerender)
-  const templateChanged = prev.templateHash !== next.templateHash;
-
-  // Check for style changes
-  const styleChanged = prev.styleHash !== next.styleHash;
-
-  // If only style changed, we can do style-only update
-  if (styleChanged && !templateChanged) {
-    return "style-only";
-  }
-
-  // If only template changed (or template + style), use rerender
-  if (templateChanged) {
-    return "template-only";
-  }
-
-  // No changes detected (shouldn't happen in practice)
-  return "full-reload";
+  return diffSfcDescriptors(prev.source, next.source).type as HmrUpdateType;
 }
 
 /**
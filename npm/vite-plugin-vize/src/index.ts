@@ -672,6 +672,9 @@ export function vize(options: VizeOptions = {}): Plugin[] {
         }
 
         if (compiled) {
+          for (const dependency of compiled.dependencies ?? []) {
+            this.addWatchFile(dependency);
+          }
           const output = rewriteStaticAssetUrls(
             rewriteDynamicTemplateImports(
               generateOutput(compiled, {
@@ -742,6 +745,26 @@ export function vize(options: VizeOptions = {}): Plugin[] {
     async handleHotUpdate(ctx: HmrContext) {
       const { file, server, read } = ctx;
 
+      // Files loaded through `src` attributes: recompile the components using them
+      const dependents = [...cache]
+        .filter(([, compiled]) => compiled.dependencies?.includes(file))
+        .map(([dependent]) => dependent);
+      if (dependents.length > 0) {
+        return dependents.flatMap((dependent) => {
+          compileFile(dependent, cache, {
+            sourceMap: mergedOptions.sourceMap ?? !isProduction,
+            ssr: mergedOptions.ssr ?? false,
+          });
+          logger.log(
+            `Re-compiled: ${path.relative(root, dependent)} (${path.basename(file)} changed)`,
+          );
+          const modules =
+            server.moduleGraph.getModulesByFile(toVirtualId(dependent)) ??
+            server.moduleGraph.getModulesByFile(dependent);
+          return modules ? [...modules] : [];
+        });
+      }
+
       if (file.endsWith(".vue") && filter(file)) {
         try {
           const source = await read();
@@ -10,6 +10,7 @@ export interface SfcCompileOptionsNapi {
   sourceMap?: boolean;
   ssr?: boolean;
   scopeId?: string;
+  customBlocks?: boolean;
 }
 
 export interface SfcCompileResultNapi {
@@ -117,6 +118,10 @@ export interface CompiledModule {
   templateHash?: string;
   styleHash?: string;
   scriptHash?: string;
+  /** Source the module was compiled from, diffed on the next HMR update */
+  source?: string;
+  /** Files loaded through `src` attributes */
+  dependencies?: string[];
 }
 
 export interface BatchFileInput {
@@ -140,12 +145,15 @@ export interface BatchFileResult {
 export interface BatchCompileOptionsNapi {
   ssr?: boolean;
   threads?: number;
+  cacheDir?: string;
+  customBlocks?: boolean;
 }
 
 export interface BatchCompileResultWithFiles {
   results: BatchFileResult[];
   successCount: number;
   failedCount: number;
+  cacheHits: number;
   timeMs: number;
 }
 
@@ -239,6 +239,12 @@ export interface TypeCheckerConfig {
    */
   checkTemplateBindings?: boolean;
 
+  /**
+   * Check template expression types
+   * @default true
+   */
+  checkTemplateTypes?: boolean;
+
   /**
    * Path to tsconfig.json
    * @default auto-detected
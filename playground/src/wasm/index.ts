@@ -58,6 +58,7 @@ export interface SfcCompileResult {
   errors?: string[];
   warnings?: string[];
   bindingMetadata?: object;
+  dependencies?: string[];
 }
 
 export interface CssCompileOptions {
@@ -136,6 +137,15 @@ export interface LintOptions {
   severityOverrides?: Record<string, 'error' | 'warning' | 'off'>;
   /** Locale for i18n messages (default: 'en') */
   locale?: 'en' | 'ja' | 'zh';
+  /** Template syntax reported by vue/no-restricted-syntax */
+  restrictedSyntax?: RestrictedSyntax[];
+}
+
+export interface RestrictedSyntax {
+  /** Template AST selector, e.g. `element[tag=img]:not([alt])` */
+  selector: string;
+  message?: string;
+  severity?: 'error' | 'warning';
 }
 
 export interface LocaleInfo {
@@ -398,6 +408,7 @@ export interface TypeCheckOptions {
   checkProps?: boolean;
   checkEmits?: boolean;
   checkTemplateBindings?: boolean;
+  checkTemplateTypes?: boolean;
 }
 
 export interface TypeCheckRelatedLocation {
@@ -450,6 +461,7 @@ export interface CrossFileOptions {
   errorSuspenseBoundary?: boolean;
   reactivityTracking?: boolean;
   setupContext?: boolean;
+  hydration?: boolean;
   circularDependencies?: boolean;
   maxImportDepth?: number;
   componentResolution?: boolean;